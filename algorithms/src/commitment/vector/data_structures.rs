@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    fft::EvaluationDomain,
+    polycommit::kzg10::{KZGCommitment, KZGProof, Powers, VerifierKey},
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+/// `VectorCommitterKey` is used to commit to, and open, vectors of up to `domain.size()` entries.
+#[derive(Clone, Debug)]
+pub struct VectorCommitterKey<E: PairingEngine> {
+    /// The powers used to commit to the polynomial that interpolates the vector.
+    pub powers: Powers<'static, E>,
+    /// The domain over which the vector is interpolated, so the entry at index `i` is the evaluation at `ω^i`.
+    pub domain: EvaluationDomain<E::Fr>,
+}
+
+impl<E: PairingEngine> VectorCommitterKey<E> {
+    /// The maximum number of entries in a committed vector.
+    pub fn max_len(&self) -> usize {
+        self.domain.size()
+    }
+}
+
+/// `VectorVerifierKey` is used to check openings of a vector commitment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VectorVerifierKey<E: PairingEngine> {
+    /// The verifier key of the underlying polynomial commitment.
+    pub vk: VerifierKey<E>,
+    /// The domain over which the vector is interpolated, so the entry at index `i` is the evaluation at `ω^i`.
+    pub domain: EvaluationDomain<E::Fr>,
+}
+
+impl<E: PairingEngine> VectorVerifierKey<E> {
+    /// The maximum number of entries in a committed vector.
+    pub fn max_len(&self) -> usize {
+        self.domain.size()
+    }
+}
+
+/// `VectorBatchProof` is a proof of the entries at multiple indices, output by `KZGVectorCommitment::batch_open`.
+/// Its size is independent of the number of indices.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct VectorBatchProof<E: PairingEngine> {
+    /// The commitment to the quotient of the polynomial, minus the interpolation of the opened entries,
+    /// by the vanishing polynomial of the opened indices.
+    pub w: KZGCommitment<E>,
+    /// The proof that the linearized polynomial vanishes at the challenge point.
+    pub w_prime: KZGProof<E>,
+}
@@ -0,0 +1,289 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Here we construct a positional vector commitment from [`KZG10`]. A vector of up to `n` entries is
+//! interpolated over the multiplicative subgroup of size `n`, such that the entry at index `i` is the
+//! evaluation of the polynomial at `ω^i`. Opening an index is then a KZG evaluation proof at `ω^i`,
+//! and opening several indices at once follows the single-polynomial case of
+//! Boneh, Drake, Fisch, and Gabizon ([BDFG20](https://eprint.iacr.org/2020/081)), with a proof of two group
+//! elements, irrespective of the number of indices.
+
+use crate::{
+    fft::{DensePolynomial, EvaluationDomain},
+    polycommit::{
+        kzg10::{KZGCommitment, KZGProof, KZG10},
+        PCError,
+    },
+    AlgebraicSponge,
+};
+use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, Zero};
+
+use anyhow::anyhow;
+use core::{marker::PhantomData, ops::Mul};
+use itertools::Itertools;
+use num_traits::CheckedDiv;
+
+mod data_structures;
+pub use data_structures::*;
+
+/// A positional vector commitment based on [\[KZG10\]][kzg], whose batched openings follow [[BDFG20]][bdfg].
+/// The challenge of a batched opening is drawn from the given sponge.
+///
+/// [kzg]: http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf
+/// [bdfg]: https://eprint.iacr.org/2020/081
+#[derive(Clone, Debug)]
+pub struct KZGVectorCommitment<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> {
+    _engine: PhantomData<(E, S)>,
+}
+
+impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> KZGVectorCommitment<E, S> {
+    /// Returns the committer and verifier keys for vectors of up to `max_len` entries.
+    pub fn setup(max_len: usize) -> Result<(VectorCommitterKey<E>, VectorVerifierKey<E>), PCError> {
+        if max_len == 0 {
+            return Err(anyhow!("The maximum length of a vector must be greater than 0").into());
+        }
+        let domain = EvaluationDomain::new(max_len).ok_or(PCError::UnsupportedDegreeBound(max_len))?;
+        let (powers, vk) = KZG10::setup(domain.size())?;
+        Ok((VectorCommitterKey { powers, domain }, VectorVerifierKey { vk, domain }))
+    }
+
+    /// Outputs a commitment to `values`.
+    pub fn commit(ck: &VectorCommitterKey<E>, values: &[E::Fr]) -> Result<KZGCommitment<E>, PCError> {
+        let polynomial = Self::interpolate(&ck.domain, values)?;
+        KZG10::commit_polynomial(&ck.powers, &polynomial)
+    }
+
+    /// Outputs a proof that the entry at `index` of `values` is `values[index]`.
+    pub fn open(ck: &VectorCommitterKey<E>, values: &[E::Fr], index: usize) -> Result<KZGProof<E>, PCError> {
+        Self::check_index(index, values.len())?;
+        let polynomial = Self::interpolate(&ck.domain, values)?;
+        let (_, proof) = KZG10::open_at(&ck.powers, &polynomial, Self::point(&ck.domain, index))?;
+        Ok(proof)
+    }
+
+    /// Verifies that `value` is the entry at `index` of the vector committed inside `commitment`.
+    pub fn verify(
+        vk: &VectorVerifierKey<E>,
+        commitment: &KZGCommitment<E>,
+        index: usize,
+        value: E::Fr,
+        proof: &KZGProof<E>,
+    ) -> Result<bool, PCError> {
+        Self::check_index(index, vk.max_len())?;
+        KZG10::verify(&vk.vk, commitment, Self::point(&vk.domain, index), value, proof)
+    }
+
+    /// Outputs a single proof of the entries at each of `indices` of `values`,
+    /// for the given commitment to `values`.
+    pub fn batch_open(
+        ck: &VectorCommitterKey<E>,
+        commitment: &KZGCommitment<E>,
+        values: &[E::Fr],
+        indices: &[usize],
+        sponge: &mut S,
+    ) -> Result<VectorBatchProof<E>, PCError> {
+        Self::check_indices(indices, values.len())?;
+        let polynomial = Self::interpolate(&ck.domain, values)?;
+
+        // Compute the quotient `h(X) = (p(X) - r(X)) / Z(X)`, where `r(X)` interpolates the opened entries
+        // at their points, and `Z(X)` vanishes on those points.
+        let points = indices.iter().map(|index| Self::point(&ck.domain, *index)).collect_vec();
+        let evaluations = indices.iter().map(|index| values[*index]).collect_vec();
+        let interpolation = Self::interpolate_points(&points, &evaluations);
+        let vanishing = Self::vanishing_polynomial(&points);
+        let quotient = (&polynomial - &interpolation)
+            .checked_div(&vanishing)
+            .ok_or_else(|| anyhow!("The interpolation does not match the vector at the opened indices"))?;
+        let w = KZG10::commit_polynomial(&ck.powers, &quotient)?;
+
+        // Open the linearized polynomial `L(X) = p(X) - r(z) - Z(z) h(X)`, which vanishes at the challenge `z`.
+        let z = Self::challenge(sponge, commitment, &w, &points, &evaluations);
+        let mut linearized = &polynomial - &(&quotient * vanishing.evaluate(z));
+        linearized -= &DensePolynomial::from_coefficients_vec(vec![interpolation.evaluate(z)]);
+        let (_, w_prime) = KZG10::open_at(&ck.powers, &linearized, z)?;
+
+        Ok(VectorBatchProof { w, w_prime })
+    }
+
+    /// Verifies that each `value_i` in `values` is the entry at `index_i` of the vector committed
+    /// inside `commitment`.
+    pub fn batch_verify(
+        vk: &VectorVerifierKey<E>,
+        commitment: &KZGCommitment<E>,
+        indices: &[usize],
+        values: &[E::Fr],
+        proof: &VectorBatchProof<E>,
+        sponge: &mut S,
+    ) -> Result<bool, PCError> {
+        Self::check_indices(indices, vk.max_len())?;
+        if indices.len() != values.len() {
+            let (num_indices, num_values) = (indices.len(), values.len());
+            return Err(anyhow!("Mismatched number of indices ({num_indices}) and values ({num_values})").into());
+        }
+
+        let points = indices.iter().map(|index| Self::point(&vk.domain, *index)).collect_vec();
+        let z = Self::challenge(sponge, commitment, &proof.w, &points, values);
+
+        // Compute the commitment to the linearized polynomial `L(X) = p(X) - r(z) - Z(z) h(X)`.
+        let interpolation_at_z = Self::interpolate_points(&points, values).evaluate(z);
+        let vanishing_at_z = Self::vanishing_polynomial(&points).evaluate(z);
+        let mut linearized = commitment.0.to_projective();
+        linearized -= &vk.vk.g.mul(interpolation_at_z);
+        linearized -= &proof.w.0.mul(vanishing_at_z);
+
+        // Check that `L(X)` vanishes at `z`.
+        KZG10::verify(&vk.vk, &KZGCommitment(linearized.to_affine()), z, E::Fr::zero(), &proof.w_prime)
+    }
+}
+
+impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> KZGVectorCommitment<E, S> {
+    /// Returns the polynomial whose evaluation at `ω^i` is `values[i]`, and zero beyond the vector.
+    fn interpolate(domain: &EvaluationDomain<E::Fr>, values: &[E::Fr]) -> Result<DensePolynomial<E::Fr>, PCError> {
+        if values.len() > domain.size() {
+            let (len, max_len) = (values.len(), domain.size());
+            return Err(anyhow!("The vector has {len} entries, but at most {max_len} are supported").into());
+        }
+        let mut evaluations = values.to_vec();
+        evaluations.resize(domain.size(), E::Fr::zero());
+        Ok(DensePolynomial::from_coefficients_vec(domain.ifft(&evaluations)))
+    }
+
+    /// Returns the point at which the entry at `index` is evaluated, i.e. `ω^index`.
+    fn point(domain: &EvaluationDomain<E::Fr>, index: usize) -> E::Fr {
+        domain.group_gen.pow([index as u64])
+    }
+
+    /// Ensures `index` is within a vector of `len` entries.
+    fn check_index(index: usize, len: usize) -> Result<(), PCError> {
+        if index >= len {
+            return Err(anyhow!("The index {index} is out of bounds for a vector of {len} entries").into());
+        }
+        Ok(())
+    }
+
+    /// Ensures `indices` is a nonempty list of distinct indices within a vector of `len` entries.
+    fn check_indices(indices: &[usize], len: usize) -> Result<(), PCError> {
+        if indices.is_empty() {
+            return Err(anyhow!("Expected at least one index").into());
+        }
+        if !indices.iter().all_unique() {
+            return Err(anyhow!("Expected distinct indices").into());
+        }
+        indices.iter().try_for_each(|index| Self::check_index(*index, len))
+    }
+
+    /// Absorbs the claims and the quotient commitment into the sponge, and returns the challenge point.
+    fn challenge(
+        sponge: &mut S,
+        commitment: &KZGCommitment<E>,
+        w: &KZGCommitment<E>,
+        points: &[E::Fr],
+        evaluations: &[E::Fr],
+    ) -> E::Fr {
+        sponge.absorb_native_field_elements(&[*commitment, *w]);
+        sponge.absorb_nonnative_field_elements(points.iter().chain(evaluations).copied());
+        sponge.squeeze_nonnative_field_elements::<E::Fr>(1)[0]
+    }
+
+    /// Returns the vanishing polynomial of `points`, i.e. `Z(X) = Π (X - x_i)`.
+    fn vanishing_polynomial(points: &[E::Fr]) -> DensePolynomial<E::Fr> {
+        let mut coeffs = vec![E::Fr::one()];
+        for point in points {
+            // Multiply the polynomial by `(X - point)`.
+            coeffs.insert(0, E::Fr::zero());
+            for i in 0..coeffs.len() - 1 {
+                let term = coeffs[i + 1] * point;
+                coeffs[i] -= term;
+            }
+        }
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+
+    /// Returns the polynomial of degree less than `points.len()` whose evaluation at each `x_i` in `points`
+    /// is the corresponding `y_i` in `evaluations`.
+    fn interpolate_points(points: &[E::Fr], evaluations: &[E::Fr]) -> DensePolynomial<E::Fr> {
+        let mut interpolation = DensePolynomial::zero();
+        for (i, (x_i, y_i)) in points.iter().zip_eq(evaluations).enumerate() {
+            // Compute the Lagrange basis polynomial for `x_i`.
+            let others = points.iter().enumerate().filter(|(j, _)| *j != i).map(|(_, x_j)| *x_j).collect_vec();
+            let numerator = Self::vanishing_polynomial(&others);
+            let denominator = others.iter().map(|x_j| *x_i - x_j).product::<E::Fr>();
+            let denominator_inverse = denominator.inverse().expect("The points must be distinct");
+            interpolation += (*y_i * denominator_inverse, &numerator);
+        }
+        interpolation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto_hash::PoseidonSponge;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    type Sponge = PoseidonSponge<Fq, 2, 1>;
+    type VC = KZGVectorCommitment<Bls12_377, Sponge>;
+
+    #[test]
+    fn test_open_and_verify() {
+        let rng = &mut TestRng::default();
+        let (ck, vk) = VC::setup(16).unwrap();
+
+        let values = (0..13).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let commitment = VC::commit(&ck, &values).unwrap();
+        for (index, value) in values.iter().enumerate() {
+            let proof = VC::open(&ck, &values, index).unwrap();
+            assert!(VC::verify(&vk, &commitment, index, *value, &proof).unwrap());
+            // Ensure the proof does not verify for another value or index.
+            assert!(!VC::verify(&vk, &commitment, index, *value + Fr::one(), &proof).unwrap());
+            assert!(!VC::verify(&vk, &commitment, (index + 1) % values.len(), *value, &proof).unwrap());
+        }
+
+        // Ensure an out-of-bounds index is rejected.
+        assert!(VC::open(&ck, &values, values.len()).is_err());
+        let proof = VC::open(&ck, &values, 0).unwrap();
+        assert!(VC::verify(&vk, &commitment, vk.max_len(), values[0], &proof).is_err());
+    }
+
+    #[test]
+    fn test_batch_open_and_verify() {
+        let rng = &mut TestRng::default();
+        let (ck, vk) = VC::setup(32).unwrap();
+
+        let values = (0..32).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let commitment = VC::commit(&ck, &values).unwrap();
+        for indices in [vec![5], vec![0, 31], vec![3, 17, 8, 22, 1]] {
+            let mut opened = indices.iter().map(|index| values[*index]).collect::<Vec<_>>();
+            let proof = VC::batch_open(&ck, &commitment, &values, &indices, &mut Sponge::new()).unwrap();
+            assert!(VC::batch_verify(&vk, &commitment, &indices, &opened, &proof, &mut Sponge::new()).unwrap());
+
+            // Ensure the proof does not verify for a wrong value.
+            opened[0] += Fr::one();
+            assert!(!VC::batch_verify(&vk, &commitment, &indices, &opened, &proof, &mut Sponge::new()).unwrap());
+        }
+
+        // Ensure duplicate indices are rejected.
+        assert!(VC::batch_open(&ck, &commitment, &values, &[2, 2], &mut Sponge::new()).is_err());
+    }
+
+    #[test]
+    fn test_vector_too_long() {
+        let rng = &mut TestRng::default();
+        let (ck, _) = VC::setup(8).unwrap();
+        let values = (0..9).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        assert!(VC::commit(&ck, &values).is_err());
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{crypto_hash::duplex, AlgebraicSponge, DuplexSpongeMode};
+use snarkvm_fields::{AnemoiParameters, PrimeField, ToConstraintField};
+
+use smallvec::SmallVec;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Anemoi<F: PrimeField> {
+    parameters: Arc<AnemoiParameters<F>>,
+}
+
+impl<F: PrimeField> Anemoi<F> {
+    /// Initializes a new instance of the cryptographic hash function.
+    pub fn setup() -> Self {
+        Self { parameters: Arc::new(AnemoiParameters::default_parameters().unwrap()) }
+    }
+
+    /// Evaluate the cryptographic hash function over a list of field elements as input.
+    pub fn evaluate(&self, input: &[F]) -> F {
+        self.evaluate_many(input, 1)[0]
+    }
+
+    /// Evaluate the cryptographic hash function over a list of field elements as input,
+    /// and returns the specified number of field elements as output.
+    pub fn evaluate_many(&self, input: &[F], num_outputs: usize) -> Vec<F> {
+        let mut sponge = AnemoiSponge::<F>::new_with_parameters(&self.parameters);
+        sponge.absorb_native_field_elements(input);
+        sponge.squeeze_native_field_elements(num_outputs).to_vec()
+    }
+
+    /// Evaluate the cryptographic hash function over a non-fixed-length vector,
+    /// in which the length also needs to be hashed.
+    pub fn evaluate_with_len(&self, input: &[F]) -> F {
+        self.evaluate(&[vec![F::from(input.len() as u128)], input.to_vec()].concat())
+    }
+
+    /// Compresses two field elements into one, with the Jive mode of Anemoi:
+    /// `left + right + u + v`, where `(u, v)` is the permutation of `(left, right)`.
+    pub fn compress(&self, left: F, right: F) -> F {
+        let (u, v) = permute(&self.parameters, left, right);
+        left + right + u + v
+    }
+
+    pub fn parameters(&self) -> &Arc<AnemoiParameters<F>> {
+        &self.parameters
+    }
+}
+
+/// A duplex sponge using the Anemoi permutation over a single column.
+///
+/// The rate is `x` and the capacity is `y`, with the duplex construction of `PoseidonSponge`.
+#[derive(Clone, Debug)]
+pub struct AnemoiSponge<F: PrimeField> {
+    /// Sponge Parameters
+    parameters: Arc<AnemoiParameters<F>>,
+    /// Current sponge's state (current elements in the permutation block), as `[y, x]`.
+    state: [F; 2],
+    /// Current mode (whether its absorbing or squeezing)
+    pub mode: DuplexSpongeMode,
+}
+
+impl<F: PrimeField> AlgebraicSponge<F, 1> for AnemoiSponge<F> {
+    type Parameters = Arc<AnemoiParameters<F>>;
+
+    fn sample_parameters() -> Self::Parameters {
+        Arc::new(AnemoiParameters::default_parameters().unwrap())
+    }
+
+    fn new_with_parameters(parameters: &Self::Parameters) -> Self {
+        Self {
+            parameters: parameters.clone(),
+            state: [F::zero(); 2],
+            mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+        }
+    }
+
+    /// Takes in field elements.
+    fn absorb_native_field_elements<T: ToConstraintField<F>>(&mut self, elements: &[T]) {
+        let input = elements.iter().flat_map(|e| e.to_field_elements().unwrap()).collect::<Vec<_>>();
+        let parameters = self.parameters.clone();
+        duplex::absorb::<F, 1>(&mut self.state, &mut self.mode, &input, |state| permute_state(&parameters, state));
+    }
+
+    /// Takes in field elements, as limbs of the native field.
+    fn absorb_nonnative_field_elements<Target: PrimeField>(&mut self, elements: impl IntoIterator<Item = Target>) {
+        self.absorb_native_field_elements(&duplex::to_limbs::<F, Target>(elements));
+    }
+
+    fn squeeze_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze_nonnative(num, Target::size_in_bits() - 1)
+    }
+
+    fn squeeze_native_field_elements(&mut self, num_elements: usize) -> SmallVec<[F; 10]> {
+        let parameters = self.parameters.clone();
+        duplex::squeeze::<F, 1>(&mut self.state, &mut self.mode, num_elements, |state| {
+            permute_state(&parameters, state)
+        })
+    }
+
+    /// Takes out field elements of 168 bits.
+    fn squeeze_short_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze_nonnative(num, 168)
+    }
+}
+
+impl<F: PrimeField> AnemoiSponge<F> {
+    /// Squeezes the given number of non-native field elements, each of the given number of bits.
+    fn squeeze_nonnative<Target: PrimeField>(
+        &mut self,
+        num_elements: usize,
+        bits_per_element: usize,
+    ) -> SmallVec<[Target; 10]> {
+        let parameters = self.parameters.clone();
+        duplex::squeeze_nonnative::<F, Target, 1>(
+            &mut self.state,
+            &mut self.mode,
+            num_elements,
+            bits_per_element,
+            |state| permute_state(&parameters, state),
+        )
+    }
+}
+
+/// Applies the Anemoi permutation to the given sponge state `[y, x]`.
+#[inline]
+fn permute_state<F: PrimeField>(parameters: &AnemoiParameters<F>, state: &mut [F]) {
+    let (x, y) = permute(parameters, state[1], state[0]);
+    state[0] = y;
+    state[1] = x;
+}
+
+/// Returns the Anemoi permutation of `(x, y)`.
+pub(crate) fn permute<F: PrimeField>(parameters: &AnemoiParameters<F>, mut x: F, mut y: F) -> (F, F) {
+    for (c, d) in parameters.c.iter().zip(&parameters.d) {
+        // Add the round constants.
+        x += c;
+        y += d;
+        // Apply the linear layer.
+        y += x;
+        x += y;
+        // Apply the open Flystel.
+        x -= parameters.beta * y.square();
+        y -= x.pow(&parameters.alpha_inv);
+        x += parameters.beta * y.square() + parameters.delta;
+    }
+    // Apply the final linear layer.
+    y += x;
+    x += y;
+    (x, y)
+}
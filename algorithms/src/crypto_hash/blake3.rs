@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Native BLAKE3 hashing, for out-of-circuit uses such as parameter checksums and identifiers,
+//! where an arithmetization-friendly hash is needlessly slow.
+
+/// Returns the BLAKE3 hash of the given data.
+pub fn blake3(data: &[u8]) -> [u8; 32] {
+    *::blake3::hash(data).as_bytes()
+}
+
+/// Returns the BLAKE3 hash of the given data, separated by the given domain.
+///
+/// This is a collision-resistant hash, which uses the key derivation mode of BLAKE3 with the domain as its context,
+/// so the hashes of distinct domains are independent. The domain should be a hardcoded, globally unique string.
+pub fn blake3_crh(domain: &str, data: &[u8]) -> [u8; 32] {
+    ::blake3::derive_key(domain, data)
+}
+
+/// Returns the BLAKE3 pseudorandom function of the given input, under the given seed.
+///
+/// This uses the keyed mode of BLAKE3, so the seed must be uniformly random and kept secret.
+pub fn blake3_prf(seed: &[u8; 32], input: &[u8]) -> [u8; 32] {
+    *::blake3::keyed_hash(seed, input).as_bytes()
+}
+
+/// Fills the given output with the BLAKE3 pseudorandom function of the given input, under the given seed.
+pub fn blake3_prf_xof(seed: &[u8; 32], input: &[u8], output: &mut [u8]) {
+    let mut hasher = ::blake3::Hasher::new_keyed(seed);
+    hasher.update(input);
+    hasher.finalize_xof().fill(output);
+}
+
+/// Returns the first 8 bytes of the BLAKE3 hash of the given data, as a little-endian `u64`.
+pub fn blake3_to_u64(data: &[u8]) -> u64 {
+    let hash_slice = blake3(data);
+    let mut hash = [0u8; 8];
+    hash[..].copy_from_slice(&hash_slice[..8]);
+    u64::from_le_bytes(hash)
+}
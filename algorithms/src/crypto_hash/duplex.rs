@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The duplex construction of the sponges whose state is a slice of field elements, with the capacity element
+//! first, so the sponges differ only in their permutation.
+
+use crate::{crypto_hash::PoseidonSponge, nonnative_params::*, DuplexSpongeMode};
+use snarkvm_fields::{FieldParameters, PrimeField};
+use snarkvm_utilities::{FromBits, ToBits};
+
+use smallvec::SmallVec;
+
+/// Absorbs the given elements into the rate of the state, permuting the state whenever the rate is full.
+pub(super) fn absorb<F: PrimeField, const RATE: usize>(
+    state: &mut [F],
+    mode: &mut DuplexSpongeMode,
+    input: &[F],
+    mut permute: impl FnMut(&mut [F]),
+) {
+    for element in input {
+        let next_absorb_index = match *mode {
+            DuplexSpongeMode::Absorbing { next_absorb_index } if next_absorb_index < RATE => next_absorb_index,
+            _ => {
+                permute(state);
+                0
+            }
+        };
+        state[1 + next_absorb_index] += element;
+        *mode = DuplexSpongeMode::Absorbing { next_absorb_index: next_absorb_index + 1 };
+    }
+}
+
+/// Squeezes the given number of elements from the rate of the state, permuting the state whenever the rate is
+/// exhausted.
+pub(super) fn squeeze<F: PrimeField, const RATE: usize>(
+    state: &mut [F],
+    mode: &mut DuplexSpongeMode,
+    num_elements: usize,
+    mut permute: impl FnMut(&mut [F]),
+) -> SmallVec<[F; 10]> {
+    let mut output = SmallVec::with_capacity(num_elements);
+    for _ in 0..num_elements {
+        let next_squeeze_index = match *mode {
+            DuplexSpongeMode::Squeezing { next_squeeze_index } if next_squeeze_index < RATE => next_squeeze_index,
+            _ => {
+                permute(state);
+                0
+            }
+        };
+        output.push(state[1 + next_squeeze_index]);
+        *mode = DuplexSpongeMode::Squeezing { next_squeeze_index: next_squeeze_index + 1 };
+    }
+    output
+}
+
+/// Returns the limbs of the given non-native field elements, in the native field.
+pub(super) fn to_limbs<F: PrimeField, Target: PrimeField>(elements: impl IntoIterator<Item = Target>) -> Vec<F> {
+    elements
+        .into_iter()
+        .flat_map(|element| PoseidonSponge::<F, 2, 1>::get_limbs_representations(&element, OptimizationType::Weight))
+        .collect()
+}
+
+/// Squeezes the given number of non-native field elements, each of the given number of bits.
+/// The elements are not guaranteed to be uniformly distributed.
+pub(super) fn squeeze_nonnative<F: PrimeField, Target: PrimeField, const RATE: usize>(
+    state: &mut [F],
+    mode: &mut DuplexSpongeMode,
+    num_elements: usize,
+    bits_per_element: usize,
+    permute: impl FnMut(&mut [F]),
+) -> SmallVec<[Target; 10]> {
+    // Squeeze the bits, omitting the highest bit of each native field element.
+    let bits_per_native = F::size_in_bits() - 1;
+    let num_native = (num_elements * bits_per_element + bits_per_native - 1) / bits_per_native;
+    let skip = (F::Parameters::REPR_SHAVE_BITS + 1) as usize;
+    let bits = squeeze::<F, RATE>(state, mode, num_native, permute)
+        .iter()
+        .flat_map(|element| element.to_bigint().to_bits_be()[skip..].to_vec())
+        .collect::<Vec<_>>();
+
+    bits.chunks(bits_per_element)
+        .take(num_elements)
+        .map(|bits| Target::from_bigint(Target::BigInteger::from_bits_be(bits).unwrap()).unwrap())
+        .collect()
+}
@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::traits::AffineCurve;
+
+use blake2::{Blake2s256, Digest};
+
+/// Hashes the given input to a nonzero element of the prime-order subgroup, with try-and-increment.
+pub fn hash_to_curve<G: AffineCurve>(domain: &[u8], input: &[u8]) -> G {
+    let size = G::prime_subgroup_generator().compressed_size();
+    let input_hash = Blake2s256::new().chain_update(domain).chain_update(input).finalize();
+
+    // The probability that all attempts fail is negligible.
+    for attempt in 0u32.. {
+        // Expand the input hash into a candidate encoding of a point.
+        let bytes = (0u32..)
+            .flat_map(|block| {
+                Blake2s256::new()
+                    .chain_update(input_hash)
+                    .chain_update(attempt.to_le_bytes())
+                    .chain_update(block.to_le_bytes())
+                    .finalize()
+            })
+            .take(size)
+            .collect::<Vec<_>>();
+
+        if let Some(point) = G::from_random_bytes(&bytes) {
+            let point = point.mul_by_cofactor();
+            if !point.is_zero() {
+                return point;
+            }
+        }
+    }
+    unreachable!("Unable to hash to the curve")
+}
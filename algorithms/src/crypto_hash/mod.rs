@@ -12,9 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod anemoi;
+pub use anemoi::*;
+
+pub mod blake3;
+pub use self::blake3::*;
+
+mod duplex;
+
+pub mod hash_to_curve;
+pub use hash_to_curve::*;
+
+pub mod keccak;
+pub use keccak::*;
+
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod rescue;
+pub use rescue::*;
+
 pub mod sha256;
 pub use sha256::*;
 
@@ -0,0 +1,268 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{crypto_hash::duplex, AlgebraicSponge, DuplexSpongeMode};
+use snarkvm_fields::{inverse_sbox_exponent, FieldParameters, PrimeField, ToConstraintField};
+use snarkvm_utilities::BigInteger;
+
+use anyhow::{bail, Result};
+use smallvec::SmallVec;
+use std::sync::Arc;
+use tiny_keccak::{Hasher, Shake};
+
+/// The security level of the default Rescue-Prime parameters, in bits.
+const SECURITY_LEVEL: usize = 128;
+
+/// The S-box exponents of the default Rescue-Prime parameters, in order of preference.
+const ALPHAS: [u64; 6] = [3, 5, 7, 11, 13, 17];
+
+/// The number of rounds of the default Rescue-Prime parameters, for each S-box exponent, and for the
+/// rates `2` to `8` with a capacity of `1`.
+///
+/// The rounds are those of `get_number_of_rounds` in the Rescue-Prime reference implementation for a security
+/// level of 128 bits, including its security margin of 50%.
+const ROUNDS: [[usize; 7]; 6] = [
+    [18, 14, 12, 11, 9, 8, 8],
+    [14, 11, 9, 8, 8, 8, 8],
+    [12, 9, 8, 8, 8, 8, 8],
+    [11, 8, 8, 8, 8, 8, 8],
+    [9, 8, 8, 8, 8, 8, 8],
+    [9, 8, 8, 8, 8, 8, 8],
+];
+
+/// The parameters of the Rescue-Prime permutation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RescueParameters<F: PrimeField, const RATE: usize, const CAPACITY: usize> {
+    /// The number of rounds, each of which applies the S-box and its inverse.
+    pub rounds: usize,
+    /// The exponent of the S-box.
+    pub alpha: u64,
+    /// The exponent of the inverse S-box, `alpha^{-1} mod (p - 1)`, as little-endian limbs.
+    pub alpha_inv: Vec<u64>,
+    /// The round constants, two per round, indexed by `round_constants[2 * round + step][state_element_index]`.
+    pub round_constants: Vec<Vec<F>>,
+    /// The Maximally Distance Separating Matrix.
+    pub mds: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField, const RATE: usize> RescueParameters<F, RATE, 1> {
+    /// Returns the default Rescue-Prime parameters for the field and rate, as generated by the reference
+    /// implementation of [SAD20][sad] for a capacity of `1` and a security level of 128 bits.
+    ///
+    /// The S-box exponent is the smallest exponent that is coprime to `p - 1`. The round constants are read
+    /// from SHAKE256 of the seed `Rescue-XLIX(p, m, 1, 128)`, and the MDS matrix is the transposed right half
+    /// of the reduced echelon form of the `m x 2m` Vandermonde matrix of the smallest primitive element.
+    ///
+    /// [sad]: https://eprint.iacr.org/2020/1143
+    pub fn default_parameters() -> Result<Self> {
+        let Some(rate_index) = RATE.checked_sub(2).filter(|index| *index < 7) else {
+            bail!("No Rescue-Prime parameters were found for rate {RATE}")
+        };
+        let Some((alpha_index, alpha_inv)) =
+            ALPHAS.iter().enumerate().find_map(|(index, alpha)| Some((index, inverse_sbox_exponent::<F>(*alpha)?)))
+        else {
+            bail!("No Rescue-Prime S-box exponent was found for this field")
+        };
+        let (alpha, rounds) = (ALPHAS[alpha_index], ROUNDS[alpha_index][rate_index]);
+
+        let round_constants = round_constants::<F>(RATE + 1, rounds);
+        let mds = mds_matrix(F::from(F::Parameters::SMALLEST_PRIMITIVE_ELEMENT), RATE + 1)?;
+
+        Ok(Self { rounds, alpha, alpha_inv, round_constants, mds })
+    }
+}
+
+/// Returns the round constants of the reference implementation for a state of `m` elements,
+/// indexed by `round_constants[2 * round + step][state_element_index]`.
+fn round_constants<F: PrimeField>(m: usize, rounds: usize) -> Vec<Vec<F>> {
+    // Each constant is read from one more byte than the modulus, to reduce the bias of the reduction.
+    let bytes_per_constant = (F::size_in_bits() + 7) / 8 + 1;
+    let seed = format!("Rescue-XLIX({},{m},1,{SECURITY_LEVEL})", F::modulus().to_biguint());
+
+    let mut bytes = vec![0u8; bytes_per_constant * 2 * m * rounds];
+    let mut shake = Shake::v256();
+    shake.update(seed.as_bytes());
+    shake.finalize(&mut bytes);
+
+    let constants = bytes.chunks(bytes_per_constant).map(F::from_bytes_le_mod_order).collect::<Vec<_>>();
+    constants.chunks(m).map(|constants| constants.to_vec()).collect()
+}
+
+/// Returns the MDS matrix of the reference implementation for a state of `m` elements, which is the transposed
+/// right half of the reduced echelon form of the `m x 2m` Vandermonde matrix `V[i][j] = g^(i * j)`.
+fn mds_matrix<F: PrimeField>(primitive_element: F, m: usize) -> Result<Vec<Vec<F>>> {
+    let mut matrix = (0..m)
+        .map(|i| {
+            let g_i = primitive_element.pow([i as u64]);
+            (0..2 * m).map(|j| g_i.pow([j as u64])).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Reduce the matrix to its echelon form, with a pivot in each of the first `m` columns.
+    for column in 0..m {
+        let Some((pivot, inverse)) = (column..m).find_map(|row| Some((row, matrix[row][column].inverse()?))) else {
+            bail!("The Rescue-Prime Vandermonde matrix is singular")
+        };
+        matrix.swap(column, pivot);
+        matrix[column].iter_mut().for_each(|element| *element *= inverse);
+        for row in (0..m).filter(|row| *row != column) {
+            let factor = matrix[row][column];
+            for j in column..2 * m {
+                let element = matrix[column][j];
+                matrix[row][j] -= factor * element;
+            }
+        }
+    }
+
+    Ok((0..m).map(|i| (0..m).map(|j| matrix[j][m + i]).collect()).collect())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rescue<F: PrimeField, const RATE: usize> {
+    parameters: Arc<RescueParameters<F, RATE, 1>>,
+}
+
+impl<F: PrimeField, const RATE: usize> Rescue<F, RATE> {
+    /// Initializes a new instance of the cryptographic hash function.
+    pub fn setup() -> Self {
+        Self { parameters: Arc::new(RescueParameters::default_parameters().unwrap()) }
+    }
+}
+
+impl<F: PrimeField, const RATE: usize> Rescue<F, RATE> {
+    /// Evaluate the cryptographic hash function over a list of field elements as input.
+    pub fn evaluate(&self, input: &[F]) -> F {
+        self.evaluate_many(input, 1)[0]
+    }
+
+    /// Evaluate the cryptographic hash function over a list of field elements as input,
+    /// and returns the specified number of field elements as output.
+    pub fn evaluate_many(&self, input: &[F], num_outputs: usize) -> Vec<F> {
+        let mut sponge = RescueSponge::<F, RATE, 1>::new_with_parameters(&self.parameters);
+        sponge.absorb_native_field_elements(input);
+        sponge.squeeze_native_field_elements(num_outputs).to_vec()
+    }
+
+    /// Evaluate the cryptographic hash function over a non-fixed-length vector,
+    /// in which the length also needs to be hashed.
+    pub fn evaluate_with_len(&self, input: &[F]) -> F {
+        self.evaluate(&[vec![F::from(input.len() as u128)], input.to_vec()].concat())
+    }
+
+    pub fn parameters(&self) -> &Arc<RescueParameters<F, RATE, 1>> {
+        &self.parameters
+    }
+}
+
+/// A duplex sponge using the Rescue-Prime permutation.
+///
+/// The permutation follows the Rescue-Prime specification in [SAD20][sad], while the sponge is the duplex
+/// construction of `PoseidonSponge`, rather than the padded sponge of the reference hash.
+/// The S-box and its inverse are applied to the full state in every round, so a round has a lower multiplicative
+/// depth than a full round of Poseidon, at the cost of an inverse S-box of high degree when evaluated natively.
+///
+/// [sad]: https://eprint.iacr.org/2020/1143
+#[derive(Clone, Debug)]
+pub struct RescueSponge<F: PrimeField, const RATE: usize, const CAPACITY: usize> {
+    /// Sponge Parameters
+    parameters: Arc<RescueParameters<F, RATE, CAPACITY>>,
+    /// Current sponge's state, with the capacity elements first.
+    state: Vec<F>,
+    /// Current mode (whether its absorbing or squeezing)
+    pub mode: DuplexSpongeMode,
+}
+
+impl<F: PrimeField, const RATE: usize> AlgebraicSponge<F, RATE> for RescueSponge<F, RATE, 1> {
+    type Parameters = Arc<RescueParameters<F, RATE, 1>>;
+
+    fn sample_parameters() -> Self::Parameters {
+        Arc::new(RescueParameters::default_parameters().unwrap())
+    }
+
+    fn new_with_parameters(parameters: &Self::Parameters) -> Self {
+        Self {
+            parameters: parameters.clone(),
+            state: vec![F::zero(); RATE + 1],
+            mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+        }
+    }
+
+    /// Takes in field elements.
+    fn absorb_native_field_elements<T: ToConstraintField<F>>(&mut self, elements: &[T]) {
+        let input = elements.iter().flat_map(|e| e.to_field_elements().unwrap()).collect::<Vec<_>>();
+        let parameters = self.parameters.clone();
+        duplex::absorb::<F, RATE>(&mut self.state, &mut self.mode, &input, |state| permute(&parameters, state));
+    }
+
+    /// Takes in field elements, as limbs of the native field.
+    fn absorb_nonnative_field_elements<Target: PrimeField>(&mut self, elements: impl IntoIterator<Item = Target>) {
+        self.absorb_native_field_elements(&duplex::to_limbs::<F, Target>(elements));
+    }
+
+    fn squeeze_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze_nonnative(num, Target::size_in_bits() - 1)
+    }
+
+    fn squeeze_native_field_elements(&mut self, num_elements: usize) -> SmallVec<[F; 10]> {
+        let parameters = self.parameters.clone();
+        duplex::squeeze::<F, RATE>(&mut self.state, &mut self.mode, num_elements, |state| permute(&parameters, state))
+    }
+
+    /// Takes out field elements of 168 bits.
+    fn squeeze_short_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze_nonnative(num, 168)
+    }
+}
+
+impl<F: PrimeField, const RATE: usize> RescueSponge<F, RATE, 1> {
+    /// Squeezes the given number of non-native field elements, each of the given number of bits.
+    fn squeeze_nonnative<Target: PrimeField>(
+        &mut self,
+        num_elements: usize,
+        bits_per_element: usize,
+    ) -> SmallVec<[Target; 10]> {
+        let parameters = self.parameters.clone();
+        duplex::squeeze_nonnative::<F, Target, RATE>(
+            &mut self.state,
+            &mut self.mode,
+            num_elements,
+            bits_per_element,
+            |state| permute(&parameters, state),
+        )
+    }
+}
+
+/// Multiplies the state by the MDS matrix, and adds the given round constants.
+#[inline]
+fn apply_mds_and_constants<F: PrimeField>(mds: &[Vec<F>], state: &mut [F], round_constants: &[F]) {
+    let result = mds
+        .iter()
+        .zip(round_constants)
+        .map(|(mds_row, constant)| F::sum_of_products(state.iter(), mds_row.iter()) + constant)
+        .collect::<Vec<_>>();
+    state.copy_from_slice(&result);
+}
+
+/// Applies the Rescue permutation to the given state.
+#[inline]
+pub(crate) fn permute<F: PrimeField, const RATE: usize>(parameters: &RescueParameters<F, RATE, 1>, state: &mut [F]) {
+    for round_constants in parameters.round_constants.chunks(2) {
+        // The first step applies the S-box (x^alpha) to every element of the state.
+        state.iter_mut().for_each(|element| *element = element.pow([parameters.alpha]));
+        apply_mds_and_constants(&parameters.mds, state, &round_constants[0]);
+        // The second step applies the inverse S-box (x^{1/alpha}) to every element of the state.
+        state.iter_mut().for_each(|element| *element = element.pow(&parameters.alpha_inv));
+        apply_mds_and_constants(&parameters.mds, state, &round_constants[1]);
+    }
+}
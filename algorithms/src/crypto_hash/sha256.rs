@@ -34,3 +34,44 @@ pub fn sha256d_to_u64(data: &[u8]) -> u64 {
     hash[..].copy_from_slice(&hash_slice[..8]);
     u64::from_le_bytes(hash)
 }
+
+/// A writer that hashes everything written to it with SHA-256, without buffering the input.
+#[derive(Clone, Default)]
+pub struct Sha256Writer(Sha256);
+
+impl Sha256Writer {
+    /// Returns the SHA-256 digest of the bytes written so far.
+    pub fn finalize(self) -> [u8; 32] {
+        let mut ret = [0u8; 32];
+        ret.copy_from_slice(&self.0.finalize());
+        ret
+    }
+}
+
+impl std::io::Write for Sha256Writer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_sha256_writer() {
+        let data = (0..10_000u32).flat_map(|i| i.to_le_bytes()).collect::<Vec<_>>();
+
+        let mut writer = Sha256Writer::default();
+        for chunk in data.chunks(7) {
+            writer.write_all(chunk).unwrap();
+        }
+        assert_eq!(writer.finalize(), sha256(&data));
+    }
+}
@@ -12,12 +12,41 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{crypto_hash::PoseidonSponge, AlgebraicSponge, DuplexSpongeMode};
-use snarkvm_curves::bls12_377::Fr;
-use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR};
+use crate::{
+    crypto_hash::{
+        anemoi,
+        blake3,
+        blake3_crh,
+        blake3_prf,
+        blake3_prf_xof,
+        keccak256,
+        rescue,
+        Anemoi,
+        AnemoiSponge,
+        PoseidonSponge,
+        Rescue,
+        RescueParameters,
+        RescueSponge,
+    },
+    AlgebraicSponge,
+    DuplexSpongeMode,
+};
+use snarkvm_curves::bls12_377::{Fq, Fr};
+use snarkvm_fields::{
+    is_secure_poseidon_mds,
+    AnemoiParameters,
+    One,
+    PoseidonDefaultField,
+    PoseidonGrainLFSR,
+    PoseidonParameters,
+    PrimeField,
+    Zero,
+};
+use snarkvm_utilities::{BigInteger, TestRng, Uniform};
 
 use anyhow::Result;
 use itertools::Itertools;
+use rand::Rng;
 use std::{path::PathBuf, sync::Arc};
 
 #[track_caller]
@@ -86,3 +115,339 @@ fn bls12_377_fr_poseidon_default_parameters_test() {
     single_rate_test::<7>();
     single_rate_test::<8>();
 }
+
+#[test]
+fn test_poseidon_mds_subspace_checks() {
+    fn check_default_mds<const RATE: usize>() {
+        let parameters = Fr::default_poseidon_parameters::<RATE>().unwrap();
+        assert!(is_secure_poseidon_mds(&parameters.mds));
+    }
+    // The baked-in matrices were selected by the reference implementation, so they pass its checks.
+    check_default_mds::<2>();
+    check_default_mds::<3>();
+    check_default_mds::<4>();
+    check_default_mds::<5>();
+    check_default_mds::<6>();
+    check_default_mds::<7>();
+    check_default_mds::<8>();
+
+    let matrix = |entries: [[u64; 3]; 3]| {
+        entries.iter().map(|row| row.iter().map(|entry| Fr::from(*entry)).collect()).collect::<Vec<Vec<_>>>()
+    };
+    // A multiple of the identity keeps every subspace invariant.
+    assert!(!is_secure_poseidon_mds(&matrix([[2, 0, 0], [0, 2, 0], [0, 0, 2]])));
+    // The first unit vector is an eigenvector, so it does not generate the whole state.
+    assert!(!is_secure_poseidon_mds(&matrix([[1, 1, 1], [0, 2, 1], [0, 1, 3]])));
+    // The subspace of states whose first element is zero is invariant.
+    assert!(!is_secure_poseidon_mds(&matrix([[1, 0, 0], [1, 2, 1], [1, 1, 3]])));
+}
+
+#[test]
+fn test_poseidon_generated_parameters() -> Result<()> {
+    fn check_poseidon_parameters<F: PrimeField, const RATE: usize>(
+        alpha: u64,
+        full_rounds: usize,
+        partial_rounds: usize,
+    ) -> Result<()> {
+        let parameters = PoseidonParameters::<F, RATE, 1>::generate()?;
+        assert_eq!(parameters.alpha, alpha);
+        assert_eq!(parameters.full_rounds, full_rounds);
+        assert_eq!(parameters.partial_rounds, partial_rounds);
+        assert_eq!(parameters.ark.len(), full_rounds + partial_rounds);
+        assert!(parameters.ark.iter().all(|constants| constants.len() == RATE + 1));
+        assert_eq!(parameters.mds.len(), RATE + 1);
+        assert!(parameters.mds.iter().flatten().all(|entry| !entry.is_zero()));
+        assert!(is_secure_poseidon_mds(&parameters.mds));
+
+        // Ensure the generation is deterministic.
+        assert_eq!(parameters, PoseidonParameters::<F, RATE, 1>::generate()?);
+
+        // Ensure the sponge can be instantiated with the generated parameters.
+        let mut sponge = PoseidonSponge::<F, RATE, 1>::new_with_parameters(&Arc::new(parameters));
+        sponge.absorb_native_field_elements(&[F::one(), F::zero()]);
+        assert_eq!(sponge.squeeze_native_field_elements(RATE).len(), RATE);
+        Ok(())
+    }
+
+    // The scalar field uses `alpha = 11`, and the base field uses `alpha = 5`.
+    check_poseidon_parameters::<Fr, 2>(11, 8, 37)?;
+    check_poseidon_parameters::<Fr, 4>(11, 8, 37)?;
+    check_poseidon_parameters::<Fr, 8>(11, 8, 37)?;
+    check_poseidon_parameters::<Fq, 2>(5, 8, 56)?;
+    check_poseidon_parameters::<Fq, 4>(5, 8, 56)?;
+    check_poseidon_parameters::<Fq, 8>(5, 8, 57)?;
+    Ok(())
+}
+
+#[test]
+fn test_rescue_default_parameters() -> Result<()> {
+    fn check_rescue_parameters<F: PrimeField, const RATE: usize>(alpha: u64, rounds: usize) -> Result<()> {
+        let parameters = RescueParameters::<F, RATE, 1>::default_parameters()?;
+        assert_eq!(parameters.alpha, alpha);
+        assert_eq!(parameters.rounds, rounds);
+        assert_eq!(parameters.round_constants.len(), 2 * rounds);
+        assert!(parameters.round_constants.iter().all(|constants| constants.len() == RATE + 1));
+        assert_eq!(parameters.mds.len(), RATE + 1);
+
+        // Ensure the inverse S-box inverts the S-box.
+        let rng = &mut TestRng::default();
+        for _ in 0..10 {
+            let element = F::rand(rng);
+            assert_eq!(element.pow([parameters.alpha]).pow(&parameters.alpha_inv), element);
+        }
+        Ok(())
+    }
+
+    // The smallest S-box exponent that is coprime to `p - 1` is 11 for the scalar field, and 5 for the base field.
+    check_rescue_parameters::<Fr, 2>(11, 11)?;
+    check_rescue_parameters::<Fr, 4>(11, 8)?;
+    check_rescue_parameters::<Fr, 8>(11, 8)?;
+    check_rescue_parameters::<Fq, 2>(5, 14)?;
+    check_rescue_parameters::<Fq, 4>(5, 9)?;
+    check_rescue_parameters::<Fq, 8>(5, 8)?;
+
+    // Ensure an unsupported rate is rejected.
+    assert!(RescueParameters::<Fr, 1, 1>::default_parameters().is_err());
+    assert!(RescueParameters::<Fr, 9, 1>::default_parameters().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_rescue_permutation_vectors() -> Result<()> {
+    /// Checks the permutation of the state `[0, 1, ..., RATE]` against the expected state.
+    fn check_rescue_permutation<F: PrimeField, const RATE: usize>(expected: &[&str]) -> Result<()> {
+        let parameters = RescueParameters::<F, RATE, 1>::default_parameters()?;
+        let mut state = (0..=RATE as u64).map(F::from).collect::<Vec<_>>();
+        rescue::permute(&parameters, &mut state);
+        let expected = expected.iter().map(|element| F::from_str(element)).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(state, expected);
+        Ok(())
+    }
+
+    // The expected states were computed with the reference algorithms of the Rescue-Prime specification
+    // (https://eprint.iacr.org/2020/1143), for a capacity of 1 and a security level of 128 bits.
+    check_rescue_permutation::<Fr, 2>(&[
+        "7440479546399644654209174587982400383281606968977568871151553572268084857377",
+        "1529233308784379756927808940345466122578522376546610044729127327489457560468",
+        "5680049028888272925558065028584524603260891508883862133819771698721589645747",
+    ])?;
+    check_rescue_permutation::<Fr, 4>(&[
+        "1203730839720990016593489988495884309928377193284549991154767516210524702728",
+        "1973623874962064827841296039306206041386048027413918420164429143836842082708",
+        "7591041286397537159524072366109937583568020014901147740065576185669926366474",
+        "3210219870032217799995664173786054995486240380899623803449667601798579220514",
+        "3489659083281118406852628847792176678079056532394095731468073416733415119275",
+    ])?;
+    check_rescue_permutation::<Fq, 2>(&[
+        "82443081744151581831653444761394548037899652537683907133609167738415912713859510886106638467344857777333953576180",
+        "139020452925183731034554922768098164644396106180090689284286419842765951585697420783715544732573518609805861546329",
+        "155408009610613536801809509381022153876862989885527479051122973077530848384849541675523959478553122848817406176672",
+    ])?;
+    check_rescue_permutation::<Fq, 4>(&[
+        "44564426342555939778099426364489097194095991690527206606713675461513879655108834071733400195919927510060271288800",
+        "65394989744888211617851269281421531298426949785222291226611301366221689009632979132661685881424037568627508494890",
+        "23171304108312393690611864737155090963319176306039272919579206707493482590493775680782536996941487585551447212578",
+        "37189849171062619790659313049627158109427707863865929741269686482476730834593465470693902934554917024256296474018",
+        "128221767497599128435020517105770587395029165464995016026367167772783432261614101008954767141075669640639133233943",
+    ])?;
+    Ok(())
+}
+
+#[test]
+fn test_rescue_sponge_modes() {
+    const RATE: usize = 2;
+    let sponge_param = RescueSponge::<Fr, RATE, 1>::sample_parameters();
+    for absorb in 0..10 {
+        for squeeze in 0..10 {
+            let iteration_name = format!("Absorb {absorb} and Squeeze {squeeze}");
+            let mut sponge = RescueSponge::<Fr, RATE, 1>::new_with_parameters(&sponge_param);
+            sponge.absorb_native_field_elements(&vec![Fr::from(1237812u64); absorb]);
+            let next_absorb_index = if absorb % RATE != 0 || absorb == 0 { absorb % RATE } else { RATE };
+            assert_eq!(sponge.mode, DuplexSpongeMode::Absorbing { next_absorb_index }, "{iteration_name}");
+            assert_eq!(sponge.squeeze_native_field_elements(squeeze).len(), squeeze, "{iteration_name}");
+            let next_squeeze_index = if squeeze % RATE != 0 || squeeze == 0 { squeeze % RATE } else { RATE };
+            if squeeze == 0 {
+                assert_eq!(sponge.mode, DuplexSpongeMode::Absorbing { next_absorb_index }, "{iteration_name}");
+            } else {
+                assert_eq!(sponge.mode, DuplexSpongeMode::Squeezing { next_squeeze_index }, "{iteration_name}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rescue_hash() {
+    let rng = &mut TestRng::default();
+    let rescue = Rescue::<Fq, 4>::setup();
+
+    let input = (0..10).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+    let output = rescue.evaluate_many(&input, 6);
+    assert_eq!(output, rescue.evaluate_many(&input, 6));
+    assert_eq!(output[0], rescue.evaluate(&input));
+
+    // Ensure absorbing the input incrementally and squeezing it incrementally matches the hash.
+    let mut sponge = RescueSponge::<Fq, 4, 1>::new_with_parameters(rescue.parameters());
+    sponge.absorb_native_field_elements(&input[..3]);
+    sponge.absorb_native_field_elements(&input[3..]);
+    let mut squeezed = sponge.squeeze_native_field_elements(1).to_vec();
+    squeezed.extend(sponge.squeeze_native_field_elements(5));
+    assert_eq!(output, squeezed);
+
+    // Ensure the hash depends on every input element, and on the length of the input.
+    for i in 0..input.len() {
+        let mut modified = input.clone();
+        modified[i] += Fq::one();
+        assert_ne!(output[0], rescue.evaluate(&modified));
+    }
+    assert_ne!(rescue.evaluate_with_len(&input), rescue.evaluate_with_len(&[input.clone(), vec![Fq::zero()]].concat()));
+
+    // Ensure the non-native squeezes are in range.
+    let mut sponge = RescueSponge::<Fq, 4, 1>::new_with_parameters(rescue.parameters());
+    sponge.absorb_nonnative_field_elements(input.iter().map(|_| Fr::rand(rng)));
+    assert_eq!(sponge.squeeze_nonnative_field_elements::<Fr>(3).len(), 3);
+    let short = sponge.squeeze_short_nonnative_field_element::<Fr>();
+    assert!(short.to_bigint().to_biguint().bits() <= 168);
+}
+
+#[test]
+fn test_anemoi_default_parameters() -> Result<()> {
+    fn check_anemoi_parameters<F: PrimeField>(alpha: u64, rounds: usize) -> Result<()> {
+        let parameters = AnemoiParameters::<F>::default_parameters()?;
+        assert_eq!(parameters.alpha, alpha);
+        assert_eq!(parameters.rounds, rounds);
+        assert_eq!(parameters.c.len(), rounds);
+        assert_eq!(parameters.d.len(), rounds);
+        assert_eq!(parameters.beta * parameters.delta, F::one());
+
+        // Ensure the inverse S-box inverts the S-box.
+        let rng = &mut TestRng::default();
+        for _ in 0..10 {
+            let element = F::rand(rng);
+            assert_eq!(element.pow([parameters.alpha]).pow(&parameters.alpha_inv), element);
+        }
+        Ok(())
+    }
+
+    // The smallest S-box exponent that is coprime to `p - 1` is 11 for the scalar field, and 5 for the base field.
+    check_anemoi_parameters::<Fr>(11, 19)?;
+    check_anemoi_parameters::<Fq>(5, 21)?;
+    Ok(())
+}
+
+#[test]
+fn test_anemoi_permutation_vectors() -> Result<()> {
+    /// Checks the permutation of `(x, y)`, and the Jive compression of `(x, y)`, against the expected values.
+    fn check_anemoi_permutation<F: PrimeField>(
+        input: (u64, u64),
+        expected: (&str, &str),
+        compressed: &str,
+    ) -> Result<()> {
+        let anemoi = Anemoi::<F>::setup();
+        let (x, y) = (F::from(input.0), F::from(input.1));
+        assert_eq!(anemoi::permute(anemoi.parameters(), x, y), (F::from_str(expected.0)?, F::from_str(expected.1)?));
+        assert_eq!(anemoi.compress(x, y), F::from_str(compressed)?);
+        Ok(())
+    }
+
+    // The expected values were computed with a transcription of the reference implementation of Anemoi
+    // (https://eprint.iacr.org/2022/840), for a single column and a security level of 128 bits.
+    check_anemoi_permutation::<Fr>(
+        (0, 0),
+        (
+            "5183955382915943001397125634861005473127503504067129379712447554204781977391",
+            "3937059489775384193683111515736995102088034484459570779556627640972253729145",
+        ),
+        "676553123262956770831412211816454043839638653372636331333841739259626467495",
+    )?;
+    check_anemoi_permutation::<Fr>(
+        (1, 2),
+        (
+            "4164107631603305188060857147913097497286917160868238341372477513563088211886",
+            "1734407550776958409089687841383477366648426339131675129558943804386600990310",
+        ),
+        "5898515182380263597150544989296574863935343499999913470931421317949689202199",
+    )?;
+    check_anemoi_permutation::<Fq>(
+        (1, 2),
+        (
+            "14848819897228326561795040598428515389070611759154250941785053569008496450011846273979734348361931276186884899345",
+            "224978164429063725285712262384810915848103536994390145401900168304109180828018736290657449008886756847907985996754",
+        ),
+        "239826984326292051847507302983239431237174148753544396343685221873117677278030582564637183357248688124094870896102",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn test_anemoi_hash() {
+    let rng = &mut TestRng::default();
+    let anemoi = Anemoi::<Fq>::setup();
+
+    let input = (0..10).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+    let output = anemoi.evaluate_many(&input, 3);
+    assert_eq!(output, anemoi.evaluate_many(&input, 3));
+    assert_eq!(output[0], anemoi.evaluate(&input));
+
+    // Ensure absorbing the input incrementally and squeezing it incrementally matches the hash.
+    let mut sponge = AnemoiSponge::<Fq>::new_with_parameters(anemoi.parameters());
+    sponge.absorb_native_field_elements(&input[..3]);
+    sponge.absorb_native_field_elements(&input[3..]);
+    let mut squeezed = sponge.squeeze_native_field_elements(1).to_vec();
+    squeezed.extend(sponge.squeeze_native_field_elements(2));
+    assert_eq!(output, squeezed);
+    assert_eq!(sponge.mode, DuplexSpongeMode::Squeezing { next_squeeze_index: 1 });
+
+    // Ensure the hash depends on every input element, and on the length of the input.
+    for i in 0..input.len() {
+        let mut modified = input.clone();
+        modified[i] += Fq::one();
+        assert_ne!(output[0], anemoi.evaluate(&modified));
+    }
+    assert_ne!(anemoi.evaluate_with_len(&input), anemoi.evaluate_with_len(&[input.clone(), vec![Fq::zero()]].concat()));
+
+    // Ensure the compression depends on the order of its inputs.
+    let (left, right) = (Fq::rand(rng), Fq::rand(rng));
+    assert_eq!(anemoi.compress(left, right), anemoi.compress(left, right));
+    assert_ne!(anemoi.compress(left, right), anemoi.compress(right, left));
+
+    // Ensure the non-native squeezes are in range.
+    let mut sponge = AnemoiSponge::<Fq>::new_with_parameters(anemoi.parameters());
+    sponge.absorb_nonnative_field_elements(input.iter().map(|_| Fr::rand(rng)));
+    assert_eq!(sponge.squeeze_nonnative_field_elements::<Fr>(3).len(), 3);
+    let short = sponge.squeeze_short_nonnative_field_element::<Fr>();
+    assert!(short.to_bigint().to_biguint().bits() <= 168);
+}
+
+#[test]
+fn test_blake3() {
+    // The BLAKE3 hash of the empty input, from the BLAKE3 specification.
+    let expected = "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262";
+    assert_eq!(hex::encode(blake3(&[])), expected);
+
+    // Ensure the domains separate the hashes.
+    let data = b"snarkVM";
+    assert_eq!(blake3_crh("AleoBlake3Test0", data), blake3_crh("AleoBlake3Test0", data));
+    assert_ne!(blake3_crh("AleoBlake3Test0", data), blake3_crh("AleoBlake3Test1", data));
+    assert_ne!(blake3_crh("AleoBlake3Test0", data), blake3(data));
+
+    // Ensure the seeds separate the PRF outputs, and the extended output begins with the output.
+    let rng = &mut TestRng::default();
+    let (seed, other_seed) = (rng.gen::<[u8; 32]>(), rng.gen::<[u8; 32]>());
+    assert_ne!(blake3_prf(&seed, data), blake3_prf(&other_seed, data));
+    assert_ne!(blake3_prf(&seed, data), blake3(data));
+    let mut output = [0u8; 100];
+    blake3_prf_xof(&seed, data, &mut output);
+    assert_eq!(output[..32], blake3_prf(&seed, data));
+}
+
+#[test]
+fn test_keccak256() {
+    // The Keccak-256 hashes of Ethereum, which use the original Keccak padding.
+    let vectors = [
+        ("", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+        ("abc", "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+    ];
+    for (input, expected) in vectors {
+        assert_eq!(hex::encode(keccak256(input.as_bytes())), expected);
+    }
+}
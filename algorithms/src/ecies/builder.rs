@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::marker::PhantomData;
+
+/// A builder of `ECIESPoseidon`, which selects the rate of the Poseidon sponge that squeezes the key stream.
+///
+/// A higher rate squeezes more randomizers per permutation, which raises the throughput of native encryption,
+/// while a lower rate uses fewer constraints per permutation in a circuit. The capacity of the default Poseidon
+/// parameters is `1` for every rate. A ciphertext is only decrypted by a scheme with the rate that encrypted it.
+#[derive(Copy, Clone, Debug)]
+pub struct ECIESPoseidonBuilder<G: AffineCurve>
+where
+    G::BaseField: PrimeField,
+{
+    /// The rate of the Poseidon sponge.
+    rate: usize,
+    /// The curve of the encryption scheme.
+    _curve: PhantomData<G>,
+}
+
+impl<G: AffineCurve> ECIESPoseidonBuilder<G>
+where
+    G::BaseField: PrimeField,
+{
+    /// Initializes a builder with the default rate.
+    pub fn new() -> Self {
+        Self { rate: DEFAULT_RATE, _curve: PhantomData }
+    }
+
+    /// Sets the rate of the Poseidon sponge, which is one of `2`, `4`, or `8`.
+    pub fn rate(mut self, rate: usize) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Initializes the encryption scheme with the Poseidon parameters of the selected rate.
+    pub fn build(self) -> Result<ECIESPoseidon<G>, ECIESError> {
+        let poseidon = match self.rate {
+            2 => PoseidonWithRate::Rate2(Poseidon::setup()),
+            4 => PoseidonWithRate::Rate4(Poseidon::setup()),
+            8 => PoseidonWithRate::Rate8(Poseidon::setup()),
+            rate => return Err(ECIESError::UnsupportedRate(rate)),
+        };
+        Ok(ECIESPoseidon::with_poseidon(poseidon))
+    }
+}
+
+impl<G: AffineCurve> Default for ECIESPoseidonBuilder<G>
+where
+    G::BaseField: PrimeField,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::AffineCurve;
+use snarkvm_utilities::{
+    FromBytes,
+    ToBytes,
+    error,
+    io::{Read, Write},
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+};
+
+use std::io;
+
+/// `ECIESCiphertext` is a message encrypted to a public key.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ECIESCiphertext<G: AffineCurve> {
+    /// The nonce, `r * G` for the generator `G` of the curve.
+    pub nonce: G,
+    /// The message, masked with the key stream.
+    pub ciphertext: Vec<G::BaseField>,
+}
+
+impl<G: AffineCurve> FromBytes for ECIESCiphertext<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize ECIESCiphertext"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for ECIESCiphertext<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer)
+            .map_err(|_| error("could not serialize ECIESCiphertext"))
+    }
+}
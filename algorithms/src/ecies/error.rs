@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The error type for `ECIESPoseidon`.
+#[derive(Debug, Error)]
+pub enum ECIESError {
+    #[error("The public key is not a valid element of the prime-order subgroup")]
+    InvalidPublicKey,
+
+    #[error("The nonce is not a valid element of the prime-order subgroup")]
+    InvalidNonce,
+
+    #[error("The randomness must not be zero")]
+    InvalidRandomness,
+
+    #[error("The Poseidon rate {0} is not supported, as it is not one of 2, 4, or 8")]
+    UnsupportedRate(usize),
+}
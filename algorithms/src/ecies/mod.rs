@@ -0,0 +1,240 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Here we construct the ECIES Poseidon encryption scheme over any elliptic curve, including the twisted
+//! Edwards curves and the short Weierstrass curves of `snarkvm-curves`. A message of base field elements is
+//! encrypted by adding a Poseidon key stream, seeded with the x-coordinate of a Diffie-Hellman shared point,
+//! to each of its elements. The key stream uses its own domain separator and sponge rate, so its ciphertexts
+//! are not compatible with the record and plaintext encryption of `snarkvm-console`.
+//!
+//! The ciphertext is malleable, so it must be authenticated by the protocol that consumes it.
+
+use crate::crypto_hash::Poseidon;
+use snarkvm_curves::traits::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::rand::Uniform;
+
+use rand_core::RngCore;
+
+mod builder;
+pub use builder::*;
+
+mod data_structures;
+pub use data_structures::*;
+
+mod error;
+pub use error::*;
+
+#[cfg(test)]
+mod tests;
+
+/// The domain separator of the key stream.
+const KEY_STREAM_DOMAIN: &[u8] = b"AleoSymmetricEncryption0";
+/// The domain separator of the symmetric key commitment.
+const SYMMETRIC_KEY_COMMITMENT_DOMAIN: &[u8] = b"AleoSymmetricKeyCommitment0";
+/// The default rate of the Poseidon sponge that squeezes the key stream.
+const DEFAULT_RATE: usize = 4;
+
+/// `ECIESPoseidon` is an implementation of ECIES over any elliptic curve whose base field has Poseidon parameters.
+#[derive(Clone, Debug)]
+pub struct ECIESPoseidon<G: AffineCurve>
+where
+    G::BaseField: PrimeField,
+{
+    /// The Poseidon hash function over the base field.
+    poseidon: PoseidonWithRate<G::BaseField>,
+    /// The domain separator of the key stream.
+    domain: G::BaseField,
+    /// The domain separator of the symmetric key commitment.
+    commitment_domain: G::BaseField,
+}
+
+impl<G: AffineCurve> ECIESPoseidon<G>
+where
+    G::BaseField: PrimeField,
+{
+    /// Initializes the encryption scheme with the default Poseidon parameters of the base field.
+    pub fn setup() -> Self {
+        Self::with_poseidon(PoseidonWithRate::Rate4(Poseidon::setup()))
+    }
+
+    /// Returns a builder of the encryption scheme, which selects the rate of the Poseidon sponge.
+    pub fn builder() -> ECIESPoseidonBuilder<G> {
+        ECIESPoseidonBuilder::new()
+    }
+
+    /// Returns the rate of the Poseidon sponge that squeezes the key stream.
+    pub fn rate(&self) -> usize {
+        self.poseidon.rate()
+    }
+
+    /// Initializes the encryption scheme with the given Poseidon hash function.
+    fn with_poseidon(poseidon: PoseidonWithRate<G::BaseField>) -> Self {
+        Self {
+            poseidon,
+            domain: G::BaseField::from_bytes_le_mod_order(KEY_STREAM_DOMAIN),
+            commitment_domain: G::BaseField::from_bytes_le_mod_order(SYMMETRIC_KEY_COMMITMENT_DOMAIN),
+        }
+    }
+
+    /// Samples a new key pair, and returns the `(private key, public key)`.
+    pub fn generate_asymmetric_key<R: RngCore>(rng: &mut R) -> (G::ScalarField, G) {
+        let private_key = G::ScalarField::rand(rng);
+        (private_key, (G::prime_subgroup_generator() * private_key).to_affine())
+    }
+
+    /// Returns a fresh nonce to the given public key, along with the symmetric key.
+    pub fn encapsulate<R: RngCore>(public_key: &G, rng: &mut R) -> Result<(G, G::BaseField), ECIESError> {
+        Self::encapsulate_from_scalar(public_key, &G::ScalarField::rand(rng))
+    }
+
+    /// Returns the nonce to the given public key for the given randomness, along with the symmetric key.
+    ///
+    /// This is the deterministic counterpart of `encapsulate`, for randomness that is derived by the caller,
+    /// e.g. in a circuit or from a transcript. The randomness must be uniform and never reused.
+    pub fn encapsulate_from_scalar(
+        public_key: &G,
+        randomness: &G::ScalarField,
+    ) -> Result<(G, G::BaseField), ECIESError> {
+        if !is_valid_point(public_key) {
+            return Err(ECIESError::InvalidPublicKey);
+        }
+        // Ensure the randomness is not zero, which would fix the symmetric key.
+        if randomness.is_zero() {
+            return Err(ECIESError::InvalidRandomness);
+        }
+        let nonce = (G::prime_subgroup_generator() * *randomness).to_affine();
+        Ok((nonce, (*public_key * *randomness).to_affine().to_x_coordinate()))
+    }
+
+    /// Returns the symmetric key of the given nonce.
+    pub fn decapsulate(private_key: &G::ScalarField, nonce: &G) -> Result<G::BaseField, ECIESError> {
+        if !is_valid_point(nonce) {
+            return Err(ECIESError::InvalidNonce);
+        }
+        Ok((*nonce * *private_key).to_affine().to_x_coordinate())
+    }
+
+    /// Encrypts the given message to the given public key.
+    pub fn encrypt<R: RngCore>(
+        &self,
+        public_key: &G,
+        message: &[G::BaseField],
+        rng: &mut R,
+    ) -> Result<ECIESCiphertext<G>, ECIESError> {
+        let (nonce, symmetric_key) = Self::encapsulate(public_key, rng)?;
+        Ok(ECIESCiphertext { nonce, ciphertext: self.encrypt_symmetric(&symmetric_key, message) })
+    }
+
+    /// Encrypts the given message to the given public key, with the given randomness.
+    ///
+    /// The ciphertext is identical to the output of `encrypt` when its RNG samples the same randomness.
+    pub fn encrypt_deterministic(
+        &self,
+        public_key: &G,
+        message: &[G::BaseField],
+        randomness: &G::ScalarField,
+    ) -> Result<ECIESCiphertext<G>, ECIESError> {
+        let (nonce, symmetric_key) = Self::encapsulate_from_scalar(public_key, randomness)?;
+        Ok(ECIESCiphertext { nonce, ciphertext: self.encrypt_symmetric(&symmetric_key, message) })
+    }
+
+    /// Decrypts the given ciphertext with the given private key.
+    pub fn decrypt(
+        &self,
+        private_key: &G::ScalarField,
+        ciphertext: &ECIESCiphertext<G>,
+    ) -> Result<Vec<G::BaseField>, ECIESError> {
+        let symmetric_key = Self::decapsulate(private_key, &ciphertext.nonce)?;
+        Ok(self.decrypt_symmetric(&symmetric_key, &ciphertext.ciphertext))
+    }
+
+    /// Encrypts the given message under the given symmetric key.
+    pub fn encrypt_symmetric(&self, symmetric_key: &G::BaseField, message: &[G::BaseField]) -> Vec<G::BaseField> {
+        let randomizers = self.key_stream(symmetric_key, message.len());
+        message.iter().zip(randomizers).map(|(message, randomizer)| *message + randomizer).collect()
+    }
+
+    /// Decrypts the given ciphertext under the given symmetric key.
+    pub fn decrypt_symmetric(&self, symmetric_key: &G::BaseField, ciphertext: &[G::BaseField]) -> Vec<G::BaseField> {
+        let randomizers = self.key_stream(symmetric_key, ciphertext.len());
+        ciphertext.iter().zip(randomizers).map(|(ciphertext, randomizer)| *ciphertext - randomizer).collect()
+    }
+
+    /// Returns a commitment to the given symmetric key.
+    ///
+    /// The length of the input is absorbed, so the commitment differs from every commitment with a binding.
+    pub fn generate_symmetric_key_commitment(&self, symmetric_key: &G::BaseField) -> G::BaseField {
+        self.poseidon.evaluate_with_len(&[self.commitment_domain, *symmetric_key])
+    }
+
+    /// Returns a commitment to the given symmetric key, bound to the given payload.
+    ///
+    /// The payload (e.g. a record commitment or transition ID) is absorbed with the symmetric key, so the
+    /// commitment of a key that is reused for another payload does not match.
+    pub fn generate_symmetric_key_commitment_with_binding(
+        &self,
+        symmetric_key: &G::BaseField,
+        binding: &G::BaseField,
+    ) -> G::BaseField {
+        self.poseidon.evaluate_with_len(&[self.commitment_domain, *symmetric_key, *binding])
+    }
+
+    /// Returns the first `num_randomizers` elements of the key stream of the given symmetric key.
+    fn key_stream(&self, symmetric_key: &G::BaseField, num_randomizers: usize) -> Vec<G::BaseField> {
+        self.poseidon.evaluate_many(&[self.domain, *symmetric_key], num_randomizers)
+    }
+}
+
+/// The Poseidon hash function with one of the rates that `ECIESPoseidonBuilder` selects at runtime.
+#[derive(Clone, Debug)]
+enum PoseidonWithRate<F: PrimeField> {
+    Rate2(Poseidon<F, 2>),
+    Rate4(Poseidon<F, 4>),
+    Rate8(Poseidon<F, 8>),
+}
+
+impl<F: PrimeField> PoseidonWithRate<F> {
+    /// Returns the rate of the Poseidon sponge.
+    const fn rate(&self) -> usize {
+        match self {
+            Self::Rate2(..) => 2,
+            Self::Rate4(..) => 4,
+            Self::Rate8(..) => 8,
+        }
+    }
+
+    /// Evaluates the hash function over the given input, and returns the given number of field elements.
+    fn evaluate_many(&self, input: &[F], num_outputs: usize) -> Vec<F> {
+        match self {
+            Self::Rate2(poseidon) => poseidon.evaluate_many(input, num_outputs),
+            Self::Rate4(poseidon) => poseidon.evaluate_many(input, num_outputs),
+            Self::Rate8(poseidon) => poseidon.evaluate_many(input, num_outputs),
+        }
+    }
+
+    /// Evaluates the hash function over the given input, with its length.
+    fn evaluate_with_len(&self, input: &[F]) -> F {
+        match self {
+            Self::Rate2(poseidon) => poseidon.evaluate_with_len(input),
+            Self::Rate4(poseidon) => poseidon.evaluate_with_len(input),
+            Self::Rate8(poseidon) => poseidon.evaluate_with_len(input),
+        }
+    }
+}
+
+/// Returns `true` if the given point is a non-zero element of the prime-order subgroup.
+fn is_valid_point<G: AffineCurve>(point: &G) -> bool {
+    !point.is_zero() && point.is_in_correct_subgroup_assuming_on_curve()
+}
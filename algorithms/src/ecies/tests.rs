@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_curves::{bls12_377::G1Affine, edwards_bls12::EdwardsAffine};
+use snarkvm_utilities::{FromBytes, ToBytes, rand::TestRng};
+
+const ITERATIONS: usize = 10;
+
+fn check_encrypt_and_decrypt<G: AffineCurve>(rng: &mut TestRng)
+where
+    G::BaseField: PrimeField,
+{
+    let ecies = ECIESPoseidon::<G>::setup();
+
+    for length in [0, 1, 4, 5, 20] {
+        for _ in 0..ITERATIONS {
+            let (private_key, public_key) = ECIESPoseidon::<G>::generate_asymmetric_key(rng);
+            let message = (0..length).map(|_| G::BaseField::rand(rng)).collect::<Vec<_>>();
+
+            let ciphertext = ecies.encrypt(&public_key, &message, rng).unwrap();
+            assert_eq!(ciphertext.ciphertext.len(), length);
+            assert_eq!(ecies.decrypt(&private_key, &ciphertext).unwrap(), message);
+
+            // Ensure a different private key does not decrypt the message.
+            if length > 0 {
+                let (other, _) = ECIESPoseidon::<G>::generate_asymmetric_key(rng);
+                assert_ne!(ecies.decrypt(&other, &ciphertext).unwrap(), message);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_encrypt_and_decrypt_edwards() {
+    check_encrypt_and_decrypt::<EdwardsAffine>(&mut TestRng::default());
+}
+
+#[test]
+fn test_encrypt_and_decrypt_weierstrass() {
+    check_encrypt_and_decrypt::<G1Affine>(&mut TestRng::default());
+}
+
+#[test]
+fn test_builder_rates() {
+    let rng = &mut TestRng::default();
+
+    let (private_key, public_key) = ECIESPoseidon::<EdwardsAffine>::generate_asymmetric_key(rng);
+    let message = (0..20).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+    let (nonce, symmetric_key) = ECIESPoseidon::<EdwardsAffine>::encapsulate(&public_key, rng).unwrap();
+
+    let mut ciphertexts = Vec::new();
+    for rate in [2, 4, 8] {
+        let ecies = ECIESPoseidon::<EdwardsAffine>::builder().rate(rate).build().unwrap();
+        assert_eq!(ecies.rate(), rate);
+
+        let ciphertext = ECIESCiphertext { nonce, ciphertext: ecies.encrypt_symmetric(&symmetric_key, &message) };
+        assert_eq!(ecies.decrypt(&private_key, &ciphertext).unwrap(), message);
+        ciphertexts.push(ciphertext.ciphertext);
+    }
+    // Ensure each rate squeezes its own key stream.
+    assert_ne!(ciphertexts[0], ciphertexts[1]);
+    assert_ne!(ciphertexts[1], ciphertexts[2]);
+
+    // Ensure the default rate is the rate of `setup`.
+    let ecies = ECIESPoseidon::<EdwardsAffine>::setup();
+    assert_eq!(ecies.rate(), ECIESPoseidonBuilder::<EdwardsAffine>::default().build().unwrap().rate());
+    assert_eq!(ecies.encrypt_symmetric(&symmetric_key, &message), ciphertexts[1]);
+
+    // Ensure an unsupported rate is rejected.
+    assert!(matches!(ECIESPoseidon::<EdwardsAffine>::builder().rate(3).build(), Err(ECIESError::UnsupportedRate(3))));
+}
+
+#[test]
+fn test_invalid_points() {
+    let rng = &mut TestRng::default();
+
+    let (private_key, _) = ECIESPoseidon::<G1Affine>::generate_asymmetric_key(rng);
+    assert!(matches!(
+        ECIESPoseidon::<G1Affine>::encapsulate(&G1Affine::zero(), rng),
+        Err(ECIESError::InvalidPublicKey)
+    ));
+    assert!(matches!(
+        ECIESPoseidon::<G1Affine>::decapsulate(&private_key, &G1Affine::zero()),
+        Err(ECIESError::InvalidNonce)
+    ));
+}
+
+#[test]
+fn test_encrypt_deterministic() {
+    let rng = &mut TestRng::default();
+
+    let ecies = ECIESPoseidon::<EdwardsAffine>::setup();
+    let (private_key, public_key) = ECIESPoseidon::<EdwardsAffine>::generate_asymmetric_key(rng);
+    let message = (0..5).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+
+    // Ensure the ciphertext matches the ciphertext of an RNG that samples the same randomness.
+    let randomness = <EdwardsAffine as AffineCurve>::ScalarField::rand(&mut TestRng::fixed(1));
+    let ciphertext = ecies.encrypt_deterministic(&public_key, &message, &randomness).unwrap();
+    assert_eq!(ciphertext, ecies.encrypt(&public_key, &message, &mut TestRng::fixed(1)).unwrap());
+    assert_eq!(ciphertext, ecies.encrypt_deterministic(&public_key, &message, &randomness).unwrap());
+    assert_eq!(ecies.decrypt(&private_key, &ciphertext).unwrap(), message);
+
+    // Ensure zero randomness is rejected.
+    assert!(matches!(
+        ecies.encrypt_deterministic(&public_key, &message, &Zero::zero()),
+        Err(ECIESError::InvalidRandomness)
+    ));
+}
+
+#[test]
+fn test_symmetric_key_commitment() {
+    let rng = &mut TestRng::default();
+
+    let ecies = ECIESPoseidon::<EdwardsAffine>::setup();
+    let (_, public_key) = ECIESPoseidon::<EdwardsAffine>::generate_asymmetric_key(rng);
+    let (_, symmetric_key) = ECIESPoseidon::<EdwardsAffine>::encapsulate(&public_key, rng).unwrap();
+    let (binding, other_binding) = (Uniform::rand(rng), Uniform::rand(rng));
+
+    let commitment = ecies.generate_symmetric_key_commitment_with_binding(&symmetric_key, &binding);
+    assert_eq!(commitment, ecies.generate_symmetric_key_commitment_with_binding(&symmetric_key, &binding));
+    // Ensure the commitment is bound to the payload.
+    assert_ne!(commitment, ecies.generate_symmetric_key_commitment_with_binding(&symmetric_key, &other_binding));
+    assert_ne!(commitment, ecies.generate_symmetric_key_commitment(&symmetric_key));
+    assert_ne!(
+        ecies.generate_symmetric_key_commitment(&symmetric_key),
+        ecies.generate_symmetric_key_commitment_with_binding(&symmetric_key, &Zero::zero())
+    );
+    // Ensure the commitment is bound to the symmetric key.
+    let (_, other_key) = ECIESPoseidon::<EdwardsAffine>::encapsulate(&public_key, rng).unwrap();
+    assert_ne!(commitment, ecies.generate_symmetric_key_commitment_with_binding(&other_key, &binding));
+}
+
+#[test]
+fn test_serialization() {
+    let rng = &mut TestRng::default();
+
+    let ecies = ECIESPoseidon::<EdwardsAffine>::setup();
+    let (_, public_key) = ECIESPoseidon::<EdwardsAffine>::generate_asymmetric_key(rng);
+    let message = (0..5).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+    let ciphertext = ecies.encrypt(&public_key, &message, rng).unwrap();
+
+    let bytes = ciphertext.to_bytes_le().unwrap();
+    assert_eq!(ECIESCiphertext::<EdwardsAffine>::read_le(&bytes[..]).unwrap(), ciphertext);
+}
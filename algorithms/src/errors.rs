@@ -12,9 +12,82 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{r1cs::SynthesisError, snark::varuna::ahp::AHPError};
+use crate::{polycommit::PCError, r1cs::SynthesisError, snark::varuna::ahp::AHPError};
 use snarkvm_fields::ConstraintFieldError;
 
+/// The class of an error, which determines how a caller should react to it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorClass {
+    /// The input (e.g. a circuit, proof, or public input) is malformed.
+    InvalidInput,
+    /// The input is well-formed, but failed verification.
+    VerificationFailed,
+    /// The input exceeds the limits of the parameters.
+    ResourceLimit,
+    /// An I/O operation failed.
+    Io,
+    /// An unexpected internal failure.
+    Internal,
+}
+
+impl ErrorClass {
+    /// Returns `true` if the failed operation may succeed when retried with the same input.
+    pub const fn is_retryable(&self) -> bool {
+        matches!(self, Self::Io)
+    }
+}
+
+/// An error with a stable, machine-readable code, which callers can map to their own error codes
+/// (e.g. in RPC responses) without parsing the error message.
+///
+/// Codes are grouped by error type: `1xxx` for `SNARKError`, `2xxx` for `AHPError`,
+/// `3xxx` for `PCError`, `4xxx` for `SynthesisError`, `5xxx` for `ThresholdEncryptionError`,
+/// and `6xxx` for `TimelockError`. A code is never reused for a different variant, so the codes of
+/// removed variants (e.g. `1002` and `1004`) stay retired. Errors that wrap another error return the
+/// code of the wrapped error.
+pub trait ErrorCode: std::error::Error {
+    /// Returns the code of the error.
+    fn code(&self) -> u16;
+
+    /// Returns the class of the error.
+    fn class(&self) -> ErrorClass;
+}
+
+/// Returns the code and class of the first typed error in the source chain of the given error, if any.
+///
+/// Most proving and verifying APIs return an `anyhow::Error`, which wraps the typed errors above,
+/// so callers can recover the code without parsing the error message.
+pub fn error_code(error: &anyhow::Error) -> Option<(u16, ErrorClass)> {
+    error.chain().find_map(|error| {
+        let error: &dyn ErrorCode = if let Some(error) = error.downcast_ref::<SNARKError>() {
+            error
+        } else if let Some(error) = error.downcast_ref::<AHPError>() {
+            error
+        } else if let Some(error) = error.downcast_ref::<PCError>() {
+            error
+        } else if let Some(error) = error.downcast_ref::<SynthesisError>() {
+            error
+        } else {
+            return typed_feature_error(error);
+        };
+        Some((error.code(), error.class()))
+    })
+}
+
+/// Returns the code and class of the given error, if it is one of the feature-gated typed errors.
+#[allow(unused_variables)]
+fn typed_feature_error(error: &(dyn std::error::Error + 'static)) -> Option<(u16, ErrorClass)> {
+    #[cfg(feature = "threshold_encryption")]
+    if let Some(error) = error.downcast_ref::<crate::threshold_encryption::ThresholdEncryptionError>() {
+        return Some((error.code(), error.class()));
+    }
+    #[cfg(feature = "timelock")]
+    if let Some(error) = error.downcast_ref::<crate::timelock::TimelockError>() {
+        return Some((error.code(), error.class()));
+    }
+    None
+}
+
 #[derive(Debug, Error)]
 pub enum SNARKError {
     #[error("{}", _0)]
@@ -23,15 +96,9 @@ pub enum SNARKError {
     #[error("{}", _0)]
     ConstraintFieldError(#[from] ConstraintFieldError),
 
-    #[error("{}: {}", _0, _1)]
-    Crate(&'static str, String),
-
     #[error("Expected a circuit-specific SRS in SNARK")]
     ExpectedCircuitSpecificSRS,
 
-    #[error("{}", _0)]
-    Message(String),
-
     #[error("{}", _0)]
     SynthesisError(#[from] SynthesisError),
 
@@ -43,10 +110,215 @@ pub enum SNARKError {
 
     #[error("Circuit not found")]
     CircuitNotFound,
+
+    #[error("{}", _0)]
+    AHPError(#[from] AHPError),
 }
 
-impl From<AHPError> for SNARKError {
-    fn from(err: AHPError) -> Self {
-        SNARKError::Crate("AHPError", format!("{err:?}"))
+impl ErrorCode for SNARKError {
+    fn code(&self) -> u16 {
+        match self {
+            Self::AnyhowError(error) => error_code(error).map_or(1000, |(code, _)| code),
+            Self::ConstraintFieldError(..) => 1001,
+            Self::ExpectedCircuitSpecificSRS => 1003,
+            Self::SynthesisError(error) => error.code(),
+            Self::EmptyBatch => 1005,
+            Self::BatchSizeMismatch => 1006,
+            Self::CircuitNotFound => 1007,
+            Self::AHPError(error) => error.code(),
+        }
+    }
+
+    fn class(&self) -> ErrorClass {
+        match self {
+            Self::AnyhowError(error) => error_code(error).map_or(ErrorClass::Internal, |(_, class)| class),
+            Self::SynthesisError(error) => error.class(),
+            Self::AHPError(error) => error.class(),
+            Self::ConstraintFieldError(..)
+            | Self::ExpectedCircuitSpecificSRS
+            | Self::EmptyBatch
+            | Self::BatchSizeMismatch
+            | Self::CircuitNotFound => ErrorClass::InvalidInput,
+        }
+    }
+}
+
+impl ErrorCode for AHPError {
+    fn code(&self) -> u16 {
+        match self {
+            Self::AnyhowError(..) => 2000,
+            Self::BatchSizeIsZero => 2001,
+            Self::ConstraintSystemError(error) => error.code(),
+            Self::InstanceDoesNotMatchIndex => 2002,
+            Self::InvalidPublicInputLength => 2003,
+            Self::MissingEval(..) => 2004,
+            Self::NonSquareMatrix => 2005,
+            Self::PolyTooLarge => 2006,
+        }
+    }
+
+    fn class(&self) -> ErrorClass {
+        match self {
+            Self::AnyhowError(..) => ErrorClass::Internal,
+            Self::ConstraintSystemError(error) => error.class(),
+            Self::MissingEval(..) => ErrorClass::VerificationFailed,
+            Self::PolyTooLarge => ErrorClass::ResourceLimit,
+            Self::BatchSizeIsZero
+            | Self::InstanceDoesNotMatchIndex
+            | Self::InvalidPublicInputLength
+            | Self::NonSquareMatrix => ErrorClass::InvalidInput,
+        }
+    }
+}
+
+impl ErrorCode for PCError {
+    fn code(&self) -> u16 {
+        match self {
+            Self::AnyhowError(..) => 3000,
+            Self::MissingPolynomial { .. } => 3001,
+            Self::MissingEvaluation { .. } => 3002,
+            Self::MissingRng => 3003,
+            Self::DegreeIsZero => 3004,
+            Self::TooManyCoefficients { .. } => 3005,
+            Self::HidingBoundIsZero => 3006,
+            Self::HidingBoundToolarge { .. } => 3007,
+            Self::LagrangeBasisSizeIsNotPowerOfTwo => 3008,
+            Self::LagrangeBasisSizeIsTooLarge => 3009,
+            Self::TrimmingDegreeTooLarge => 3010,
+            Self::EquationHasDegreeBounds(..) => 3011,
+            Self::UnsupportedDegreeBound(..) => 3012,
+            Self::UnsupportedLagrangeBasisSize(..) => 3013,
+            Self::IncorrectDegreeBound { .. } => 3014,
+        }
+    }
+
+    fn class(&self) -> ErrorClass {
+        match self {
+            Self::AnyhowError(..) => ErrorClass::Internal,
+            Self::MissingEvaluation { .. } => ErrorClass::VerificationFailed,
+            Self::TooManyCoefficients { .. }
+            | Self::HidingBoundToolarge { .. }
+            | Self::LagrangeBasisSizeIsTooLarge
+            | Self::TrimmingDegreeTooLarge
+            | Self::UnsupportedDegreeBound(..)
+            | Self::UnsupportedLagrangeBasisSize(..) => ErrorClass::ResourceLimit,
+            Self::MissingPolynomial { .. }
+            | Self::MissingRng
+            | Self::DegreeIsZero
+            | Self::HidingBoundIsZero
+            | Self::LagrangeBasisSizeIsNotPowerOfTwo
+            | Self::EquationHasDegreeBounds(..)
+            | Self::IncorrectDegreeBound { .. } => ErrorClass::InvalidInput,
+        }
+    }
+}
+
+impl ErrorCode for SynthesisError {
+    fn code(&self) -> u16 {
+        match self {
+            Self::AnyhowError(..) => 4000,
+            Self::AssignmentMissing => 4001,
+            Self::ConstraintFieldError(..) => 4002,
+            Self::DivisionByZero => 4003,
+            Self::Unsatisfiable => 4004,
+            Self::PolyTooLarge => 4005,
+            Self::UnexpectedIdentity => 4006,
+            Self::IoError(..) => 4007,
+            Self::MalformedVerifyingKey(..) => 4008,
+            Self::UnconstrainedVariable => 4009,
+        }
+    }
+
+    fn class(&self) -> ErrorClass {
+        match self {
+            Self::AnyhowError(..) => ErrorClass::Internal,
+            Self::IoError(..) => ErrorClass::Io,
+            Self::PolyTooLarge => ErrorClass::ResourceLimit,
+            Self::AssignmentMissing
+            | Self::ConstraintFieldError(..)
+            | Self::DivisionByZero
+            | Self::Unsatisfiable
+            | Self::UnexpectedIdentity
+            | Self::MalformedVerifyingKey(..)
+            | Self::UnconstrainedVariable => ErrorClass::InvalidInput,
+        }
+    }
+}
+
+#[cfg(feature = "threshold_encryption")]
+impl ErrorCode for crate::threshold_encryption::ThresholdEncryptionError {
+    fn code(&self) -> u16 {
+        match self {
+            Self::InvalidThreshold { .. } => 5000,
+            Self::InvalidCiphertext => 5001,
+            Self::InvalidDecryptionShare(..) => 5002,
+            Self::UnknownParty(..) => 5003,
+            Self::DuplicateDecryptionShare(..) => 5004,
+            Self::NotEnoughDecryptionShares { .. } => 5005,
+        }
+    }
+
+    fn class(&self) -> ErrorClass {
+        match self {
+            Self::InvalidCiphertext | Self::InvalidDecryptionShare(..) => ErrorClass::VerificationFailed,
+            Self::InvalidThreshold { .. }
+            | Self::UnknownParty(..)
+            | Self::DuplicateDecryptionShare(..)
+            | Self::NotEnoughDecryptionShares { .. } => ErrorClass::InvalidInput,
+        }
+    }
+}
+
+#[cfg(feature = "timelock")]
+impl ErrorCode for crate::timelock::TimelockError {
+    fn code(&self) -> u16 {
+        match self {
+            Self::InvalidCiphertext(..) => 6000,
+            Self::InvalidRoundSignature(..) => 6001,
+            Self::InvalidSignatureShare(..) => 6002,
+            Self::UnknownParty(..) => 6003,
+            Self::DuplicateSignatureShare(..) => 6004,
+            Self::NotEnoughSignatureShares { .. } => 6005,
+        }
+    }
+
+    fn class(&self) -> ErrorClass {
+        match self {
+            Self::InvalidCiphertext(..) | Self::InvalidRoundSignature(..) | Self::InvalidSignatureShare(..) => {
+                ErrorClass::VerificationFailed
+            }
+            Self::UnknownParty(..) | Self::DuplicateSignatureShare(..) | Self::NotEnoughSignatureShares { .. } => {
+                ErrorClass::InvalidInput
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_error_codes() {
+        // Check that a wrapped error keeps its code, class, and source.
+        let error = SNARKError::from(AHPError::from(SynthesisError::Unsatisfiable));
+        assert_eq!(error.code(), 4004);
+        assert_eq!(error.class(), ErrorClass::InvalidInput);
+        assert!(error.source().is_some());
+
+        let error = SNARKError::from(SynthesisError::from(std::io::Error::from(std::io::ErrorKind::TimedOut)));
+        assert_eq!(error.code(), 4007);
+        assert!(error.class().is_retryable());
+
+        assert_eq!(SNARKError::CircuitNotFound.code(), 1007);
+
+        // Check that a typed error is recovered from an `anyhow` chain.
+        let error = anyhow::Error::from(SNARKError::EmptyBatch).context("Failed to prove");
+        assert_eq!(error_code(&error), Some((1005, ErrorClass::InvalidInput)));
+        assert_eq!(SNARKError::from(error).code(), 1005);
+        assert_eq!(error_code(&anyhow::anyhow!("Untyped error")), None);
+        assert_eq!(SNARKError::from(anyhow::anyhow!("Untyped error")).class(), ErrorClass::Internal);
+        assert_eq!(PCError::MissingEvaluation { label: "g_1".to_string() }.class(), ErrorClass::VerificationFailed);
     }
 }
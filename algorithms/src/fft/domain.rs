@@ -34,7 +34,7 @@ use crate::{
 use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
 #[cfg(not(feature = "serial"))]
 use snarkvm_utilities::max_available_threads;
-use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
+use snarkvm_utilities::{execute_in, execute_with_max_available_threads, serialize::*, Subsystem};
 
 use rand::Rng;
 use std::{borrow::Cow, fmt};
@@ -166,7 +166,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an FFT, modifying the vector in place.
     pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
+        execute_in(Subsystem::Fft, self.size(), || {
             coeffs.resize(self.size(), T::zero());
             self.in_order_fft_in_place(&mut *coeffs);
         });
@@ -182,7 +182,7 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Compute an IFFT, modifying the vector in place.
     #[inline]
     pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
+        execute_in(Subsystem::Fft, self.size(), || {
             evals.resize(self.size(), T::zero());
             self.in_order_ifft_in_place(&mut *evals);
         });
@@ -198,7 +198,7 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Compute an FFT over a coset of the domain, modifying the input vector
     /// in place.
     pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
+        execute_in(Subsystem::Fft, self.size(), || {
             Self::distribute_powers(coeffs, F::multiplicative_generator());
             self.fft_in_place(coeffs);
         });
@@ -213,7 +213,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an IFFT over a coset of the domain, modifying the input vector in place.
     pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
+        execute_in(Subsystem::Fft, self.size(), || {
             evals.resize(self.size(), T::zero());
             self.in_order_coset_ifft_in_place(&mut *evals);
         });
@@ -939,11 +939,11 @@ mod tests {
     use rand::Rng;
     use snarkvm_curves::bls12_377::Fr;
     use snarkvm_fields::{FftField, Field, One, Zero};
-    use snarkvm_utilities::{TestRng, Uniform};
+    use snarkvm_utilities::{SeededRng, Uniform};
 
     #[test]
     fn vanishing_polynomial_evaluation() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         for coeffs in 0..10 {
             let domain = EvaluationDomain::<Fr>::new(coeffs).unwrap();
             let z = domain.vanishing_polynomial();
@@ -989,7 +989,7 @@ mod tests {
     /// Test that lagrange interpolation for a random polynomial at a random point works.
     #[test]
     fn non_systematic_lagrange_coefficients_test() {
-        let mut rng = TestRng::default();
+        let mut rng = SeededRng::default();
         for domain_dimension in 1..10 {
             let domain_size = 1 << domain_dimension;
             let domain = EvaluationDomain::<Fr>::new(domain_size).unwrap();
@@ -1060,7 +1060,7 @@ mod tests {
         // It tests consistency of FFT/IFFT, and coset_fft/coset_ifft,
         // along with testing that each individual evaluation is correct.
 
-        let mut rng = TestRng::default();
+        let mut rng = SeededRng::default();
 
         // Runs in time O(degree^2)
         let log_degree = 5;
@@ -1139,7 +1139,7 @@ mod tests {
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_fft_correctness_cuda() {
-        let mut rng = TestRng::default();
+        let mut rng = SeededRng::default();
         for log_domain in 2..20 {
             println!("Testing domain size {log_domain}");
             let domain_size = 1 << log_domain;
@@ -512,13 +512,13 @@ mod tests {
     use num_traits::CheckedDiv;
     use snarkvm_curves::bls12_377::Fr;
     use snarkvm_fields::{Field, One, Zero};
-    use snarkvm_utilities::rand::{TestRng, Uniform};
+    use snarkvm_utilities::rand::{SeededRng, Uniform};
 
     use rand::RngCore;
 
     #[test]
     fn double_polynomials_random() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         for degree in 0..70 {
             let p = DensePolynomial::<Fr>::rand(degree, rng);
             let p_double = &p + &p;
@@ -529,7 +529,7 @@ mod tests {
 
     #[test]
     fn add_polynomials() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         for a_degree in 0..70 {
             for b_degree in 0..70 {
                 let p1 = DensePolynomial::<Fr>::rand(a_degree, rng);
@@ -543,7 +543,7 @@ mod tests {
 
     #[test]
     fn add_polynomials_with_mul() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         for a_degree in 0..70 {
             for b_degree in 0..70 {
                 let mut p1 = DensePolynomial::rand(a_degree, rng);
@@ -560,7 +560,7 @@ mod tests {
 
     #[test]
     fn sub_polynomials() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         let p1 = DensePolynomial::<Fr>::rand(5, rng);
         let p2 = DensePolynomial::<Fr>::rand(3, rng);
         let res1 = &p1 - &p2;
@@ -590,7 +590,7 @@ mod tests {
     #[test]
     #[allow(clippy::needless_borrow)]
     fn divide_polynomials_random() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
 
         for a_degree in 0..70 {
             for b_degree in 0..70 {
@@ -605,7 +605,7 @@ mod tests {
 
     #[test]
     fn evaluate_polynomials() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         for a_degree in 0..70 {
             let p = DensePolynomial::rand(a_degree, rng);
             let point: Fr = Fr::from(10u64);
@@ -626,7 +626,7 @@ mod tests {
 
     #[test]
     fn mul_polynomials_random() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         for a_degree in 0..70 {
             for b_degree in 0..70 {
                 dbg!(a_degree);
@@ -640,7 +640,7 @@ mod tests {
 
     #[test]
     fn mul_polynomials_n_random() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
 
         let max_degree = 1 << 8;
 
@@ -689,7 +689,7 @@ mod tests {
 
     #[test]
     fn mul_polynomials_corner_cases() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
 
         let a_degree = 70;
 
@@ -705,7 +705,7 @@ mod tests {
 
     #[test]
     fn mul_by_vanishing_poly() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         for size in 1..10 {
             let domain = EvaluationDomain::new(1 << size).unwrap();
             for degree in 0..70 {
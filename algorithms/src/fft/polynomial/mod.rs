@@ -31,6 +31,9 @@ pub use dense::DensePolynomial;
 mod sparse;
 pub use sparse::SparsePolynomial;
 
+mod multilinear;
+pub use multilinear::MultilinearPolynomial;
+
 mod multiplier;
 pub use multiplier::*;
 
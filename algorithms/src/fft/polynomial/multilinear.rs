@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A multilinear polynomial represented by its evaluations over the Boolean hypercube.
+
+use snarkvm_fields::Field;
+use snarkvm_utilities::serialize::*;
+
+use anyhow::{ensure, Result};
+use rand::Rng;
+
+/// Stores a multilinear polynomial in `num_vars` variables by its evaluations over `{0, 1}^num_vars`.
+/// The evaluation at `(b_1, ..., b_n)` is stored at the index whose `i`-th least significant bit is `b_{i+1}`.
+/// There are always `2^num_vars` evaluations, which is checked on construction and on deserialization.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize)]
+#[must_use]
+pub struct MultilinearPolynomial<F: Field> {
+    /// The number of variables of the polynomial.
+    num_vars: usize,
+    /// The evaluations of the polynomial over the Boolean hypercube.
+    evaluations: Vec<F>,
+}
+
+impl<F: Field> MultilinearPolynomial<F> {
+    /// Constructs a new polynomial in `num_vars` variables from its evaluations over the Boolean hypercube.
+    pub fn from_evaluations_vec(num_vars: usize, evaluations: Vec<F>) -> Result<Self> {
+        ensure!(num_vars < usize::BITS as usize, "The number of variables ({num_vars}) is too large");
+        ensure!(
+            evaluations.len() == 1 << num_vars,
+            "Expected {} evaluations for {num_vars} variables, found {}",
+            1usize << num_vars,
+            evaluations.len()
+        );
+        Ok(Self { num_vars, evaluations })
+    }
+
+    /// Outputs a polynomial in `num_vars` variables with random evaluations.
+    pub fn rand<R: Rng>(num_vars: usize, rng: &mut R) -> Result<Self> {
+        ensure!(num_vars < usize::BITS as usize, "The number of variables ({num_vars}) is too large");
+        Ok(Self { num_vars, evaluations: (0..1usize << num_vars).map(|_| F::rand(rng)).collect() })
+    }
+
+    /// Returns the multilinear extension of the equality function `eq(X, point)`, i.e. the polynomial
+    /// whose evaluation at `b` is `Π (b_i point_i + (1 - b_i)(1 - point_i))`.
+    pub fn eq(point: &[F]) -> Self {
+        let mut evaluations = Vec::with_capacity(1 << point.len());
+        evaluations.push(F::one());
+        for x_i in point {
+            // Each new variable is the most significant bit of the index.
+            let upper = evaluations.iter().map(|e| *e * x_i).collect::<Vec<_>>();
+            evaluations.iter_mut().zip(&upper).for_each(|(e, u)| *e -= u);
+            evaluations.extend(upper);
+        }
+        Self { num_vars: point.len(), evaluations }
+    }
+
+    /// Returns the number of variables of the polynomial.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Returns the evaluations of the polynomial over the Boolean hypercube.
+    pub fn evaluations(&self) -> &[F] {
+        &self.evaluations
+    }
+
+    /// Returns the polynomial in `num_vars - 1` variables obtained by fixing the first variable to `value`.
+    pub fn fix_first_variable(&self, value: F) -> Result<Self> {
+        ensure!(self.num_vars > 0, "Cannot fix a variable of a constant polynomial");
+        let evaluations = self.evaluations.chunks(2).map(|pair| pair[0] + (pair[1] - pair[0]) * value).collect();
+        Ok(Self { num_vars: self.num_vars - 1, evaluations })
+    }
+
+    /// Evaluates `self` at the given `point`.
+    pub fn evaluate(&self, point: &[F]) -> Result<F> {
+        ensure!(
+            point.len() == self.num_vars,
+            "Expected a point with {} coordinates, found {}",
+            self.num_vars,
+            point.len()
+        );
+        let mut evaluations = self.evaluations.clone();
+        for (i, x_i) in point.iter().enumerate() {
+            // Fold the evaluations in half, fixing the current first variable to `x_i`.
+            for j in 0..evaluations.len() >> 1 {
+                evaluations[j] = evaluations[2 * j] + (evaluations[2 * j + 1] - evaluations[2 * j]) * x_i;
+            }
+            evaluations.truncate(1 << (self.num_vars - i - 1));
+        }
+        Ok(evaluations[0])
+    }
+}
+
+impl<F: Field> Valid for MultilinearPolynomial<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        // Ensure there are `2^num_vars` evaluations.
+        match self.num_vars < usize::BITS as usize && self.evaluations.len() == 1 << self.num_vars {
+            true => Valid::check(&self.evaluations),
+            false => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for MultilinearPolynomial<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let num_vars = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let evaluations = Vec::<F>::deserialize_with_mode(&mut reader, compress, validate)?;
+        // Ensure the number of evaluations matches the number of variables, even if validation is disabled,
+        // as the other methods of the polynomial rely on it.
+        Self::from_evaluations_vec(num_vars, evaluations).map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    #[test]
+    fn test_evaluate_on_hypercube() {
+        let rng = &mut TestRng::default();
+        for num_vars in 0..6 {
+            let polynomial = MultilinearPolynomial::<Fr>::rand(num_vars, rng).unwrap();
+            for (index, expected) in polynomial.evaluations().iter().enumerate() {
+                let point = (0..num_vars)
+                    .map(|i| if (index >> i) & 1 == 1 { Fr::one() } else { Fr::zero() })
+                    .collect::<Vec<_>>();
+                assert_eq!(polynomial.evaluate(&point).unwrap(), *expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_evaluate_matches_eq() {
+        let rng = &mut TestRng::default();
+        for num_vars in 0..6 {
+            let polynomial = MultilinearPolynomial::<Fr>::rand(num_vars, rng).unwrap();
+            let point = (0..num_vars).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+            // The evaluation is the inner product of the evaluations with those of `eq(X, point)`.
+            let eq = MultilinearPolynomial::eq(&point);
+            let expected = polynomial.evaluations().iter().zip(eq.evaluations()).map(|(a, b)| *a * b).sum::<Fr>();
+            assert_eq!(polynomial.evaluate(&point).unwrap(), expected);
+
+            // Fixing the first variable and evaluating at the remaining coordinates gives the same result.
+            if num_vars > 0 {
+                let fixed = polynomial.fix_first_variable(point[0]).unwrap();
+                assert_eq!(fixed.evaluate(&point[1..]).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_evaluations_vec() {
+        assert!(MultilinearPolynomial::<Fr>::from_evaluations_vec(2, vec![Fr::one(); 4]).is_ok());
+        assert!(MultilinearPolynomial::<Fr>::from_evaluations_vec(2, vec![Fr::one(); 3]).is_err());
+        assert!(MultilinearPolynomial::<Fr>::from_evaluations_vec(0, vec![Fr::one()]).is_ok());
+        assert!(MultilinearPolynomial::<Fr>::rand(usize::BITS as usize, &mut TestRng::default()).is_err());
+        let constant = MultilinearPolynomial::<Fr>::from_evaluations_vec(0, vec![Fr::one()]).unwrap();
+        assert!(constant.fix_first_variable(Fr::one()).is_err());
+    }
+
+    #[test]
+    fn test_serialization() {
+        let rng = &mut TestRng::default();
+        let polynomial = MultilinearPolynomial::<Fr>::rand(3, rng).unwrap();
+
+        let mut bytes = vec![];
+        polynomial.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(polynomial, MultilinearPolynomial::deserialize_compressed(&bytes[..]).unwrap());
+
+        // Ensure a number of evaluations that does not match the number of variables is rejected.
+        for (num_vars, num_evaluations) in [(2usize, 8usize), (3, 4), (usize::BITS as usize, 1)] {
+            let mut bytes = vec![];
+            num_vars.serialize_compressed(&mut bytes).unwrap();
+            vec![Fr::one(); num_evaluations].serialize_compressed(&mut bytes).unwrap();
+            assert!(MultilinearPolynomial::<Fr>::deserialize_compressed(&bytes[..]).is_err());
+            assert!(MultilinearPolynomial::<Fr>::deserialize_compressed_unchecked(&bytes[..]).is_err());
+        }
+    }
+}
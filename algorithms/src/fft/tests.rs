@@ -16,11 +16,11 @@ use crate::fft::{domain::*, DensePolynomial};
 use rand::Rng;
 use snarkvm_curves::bls12_377::{Fr, G1Projective};
 use snarkvm_fields::{FftField, Field, One, Zero};
-use snarkvm_utilities::rand::{TestRng, Uniform};
+use snarkvm_utilities::rand::{SeededRng, Uniform};
 
 #[test]
 fn vanishing_polynomial_evaluation() {
-    let rng = &mut TestRng::default();
+    let rng = &mut SeededRng::default();
 
     for coeffs in 0..10 {
         let domain = EvaluationDomain::<Fr>::new(coeffs).unwrap();
@@ -68,7 +68,7 @@ fn elements_contents() {
 /// point works.
 #[test]
 fn non_systematic_lagrange_coefficients_test() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for domain_dim in 1..10 {
         let domain_size = 1 << domain_dim;
@@ -125,7 +125,7 @@ fn test_fft_correctness() {
     // Runs in time O(degree^2)
     let log_degree = 5;
     let degree = 1 << log_degree;
-    let rand_poly = DensePolynomial::<Fr>::rand(degree - 1, &mut TestRng::default());
+    let rand_poly = DensePolynomial::<Fr>::rand(degree - 1, &mut SeededRng::default());
 
     for log_domain_size in log_degree..(log_degree + 2) {
         let domain_size = 1 << log_domain_size;
@@ -278,7 +278,7 @@ fn parallel_fft_consistency() {
         }
     }
 
-    let rng = &mut TestRng::default();
+    let rng = &mut SeededRng::default();
 
     test_consistency(rng, 10);
 }
@@ -320,7 +320,7 @@ fn fft_composition() {
         }
     }
 
-    let rng = &mut TestRng::default();
+    let rng = &mut SeededRng::default();
 
     test_fft_composition::<Fr, Fr, _>(rng, 10);
     test_fft_composition::<Fr, G1Projective, _>(rng, 10);
@@ -328,7 +328,7 @@ fn fft_composition() {
 
 #[test]
 fn evaluate_over_domain() {
-    let rng = &mut TestRng::default();
+    let rng = &mut SeededRng::default();
 
     for domain_size in (1..10).map(|i| 2usize.pow(i)) {
         let domain = EvaluationDomain::<Fr>::new(domain_size).unwrap();
@@ -29,8 +29,12 @@ extern crate thiserror;
 
 pub use snarkvm_utilities::{cfg_chunks, cfg_chunks_mut, cfg_into_iter, cfg_iter, cfg_iter_mut, cfg_reduce};
 
+#[cfg(feature = "commitment")]
+pub mod commitment;
 #[cfg(feature = "crypto_hash")]
 pub mod crypto_hash;
+#[cfg(feature = "ecies")]
+pub mod ecies;
 #[cfg(feature = "fft")]
 pub mod fft;
 #[cfg(feature = "msm")]
@@ -41,6 +45,10 @@ pub mod polycommit;
 pub mod r1cs;
 #[cfg(feature = "snark")]
 pub mod snark;
+#[cfg(feature = "threshold_encryption")]
+pub mod threshold_encryption;
+#[cfg(feature = "timelock")]
+pub mod timelock;
 
 pub mod srs;
 
@@ -53,8 +61,14 @@ pub use traits::*;
 pub mod prelude {
     pub use crate::{errors::*, traits::*};
 
+    #[cfg(feature = "ecies")]
+    pub use crate::ecies::ECIESError;
     #[cfg(feature = "polycommit")]
     pub use crate::polycommit::error::*;
     #[cfg(feature = "r1cs")]
     pub use crate::r1cs::errors::*;
+    #[cfg(feature = "threshold_encryption")]
+    pub use crate::threshold_encryption::ThresholdEncryptionError;
+    #[cfg(feature = "timelock")]
+    pub use crate::timelock::TimelockError;
 }
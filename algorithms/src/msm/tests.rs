@@ -19,7 +19,7 @@ use snarkvm_curves::{
 };
 use snarkvm_fields::{PrimeField, Zero};
 use snarkvm_utilities::{
-    rand::{TestRng, Uniform},
+    rand::{SeededRng, Uniform},
     BitIteratorBE,
 };
 
@@ -39,7 +39,7 @@ fn naive_variable_base_msm<G: AffineCurve>(
 fn variable_base_test_with_bls12() {
     const SAMPLES: usize = 1 << 10;
 
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     let v = (0..SAMPLES).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
     let g = (0..SAMPLES).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
@@ -54,7 +54,7 @@ fn variable_base_test_with_bls12() {
 fn variable_base_test_with_bls12_unequal_numbers() {
     const SAMPLES: usize = 1 << 10;
 
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     let v = (0..SAMPLES - 100).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
     let g = (0..SAMPLES).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
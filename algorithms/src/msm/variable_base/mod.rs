@@ -20,6 +20,7 @@ pub mod prefetch;
 
 use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{execute_in, Subsystem};
 
 use core::any::TypeId;
 
@@ -39,11 +40,11 @@ impl VariableBase {
                     return result;
                 }
             }
-            batched::msm(bases, scalars)
+            execute_in(Subsystem::Msm, scalars.len(), || batched::msm(bases, scalars))
         }
         // For all other curves, we perform variable base MSM using Pippenger's algorithm.
         else {
-            standard::msm(bases, scalars)
+            execute_in(Subsystem::Msm, scalars.len(), || standard::msm(bases, scalars))
         }
     }
 
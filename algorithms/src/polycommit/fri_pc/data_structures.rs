@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{crypto_hash::Poseidon, fft::EvaluationDomain};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+/// `FriCommitterKey` is used to commit to, and create evaluation proofs for, a given polynomial.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FriCommitterKey<F: PrimeField> {
+    /// The hash function used to build the Merkle trees over the evaluations.
+    pub hasher: Poseidon<F, 2>,
+    /// The domain whose coset the evaluations are computed over.
+    /// Its size is the degree bound times the blowup factor.
+    pub domain: EvaluationDomain<F>,
+    /// The number of folding rounds, i.e. the base-2 logarithm of the degree bound.
+    pub num_rounds: usize,
+    /// The number of queries made by the verifier.
+    pub num_queries: usize,
+}
+
+impl<F: PrimeField> FriCommitterKey<F> {
+    /// The maximum number of coefficients of a polynomial that can be committed to with `self`.
+    pub fn size(&self) -> usize {
+        1 << self.num_rounds
+    }
+
+    /// The maximum degree of a polynomial that can be committed to with `self`.
+    pub fn max_degree(&self) -> usize {
+        self.size() - 1
+    }
+}
+
+/// `FriVerifierKey` is used to check evaluation proofs for a given commitment.
+/// As the scheme is transparent, it consists of the same parameters as the committer key.
+pub type FriVerifierKey<F> = FriCommitterKey<F>;
+
+/// A commitment to a polynomial, as output by `FriPC::commit`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct FriCommitment<F: PrimeField>(
+    /// The commitment is the root of the Merkle tree over the evaluations of the polynomial.
+    pub F,
+);
+
+/// The openings of the committed polynomials and of each folded layer at a single query.
+#[derive(Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct FriQueryProof<F: PrimeField> {
+    /// For each committed polynomial, the evaluations at the queried point `x` and at `-x`.
+    pub polynomial_evaluations: Vec<(F, F)>,
+    /// For each committed polynomial, the Merkle path of the evaluations.
+    pub polynomial_paths: Vec<Vec<F>>,
+    /// For each folded layer, excluding the final layer, the evaluations at the queried point `x` and at `-x`.
+    pub evaluations: Vec<(F, F)>,
+    /// For each folded layer, excluding the final layer, the Merkle path of the evaluations.
+    pub paths: Vec<Vec<F>>,
+}
+
+/// `FriProof` is an evaluation proof that is output by `FriPC::batch_open`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct FriProof<F: PrimeField> {
+    /// The Merkle roots of the folded layers, excluding the final layer.
+    pub layer_roots: Vec<F>,
+    /// The constant value of the final layer.
+    pub final_value: F,
+    /// The openings at each query.
+    pub queries: Vec<FriQueryProof<F>>,
+}
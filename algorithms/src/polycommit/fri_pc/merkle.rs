@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::crypto_hash::Poseidon;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::cfg_into_iter;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A Merkle tree over the evaluations of a folding layer, whose `j`-th leaf is the hash of
+/// the evaluations at `x_j` and `-x_j`, i.e. at the indices `j` and `j + n / 2`.
+pub(super) struct MerkleTree<F: PrimeField> {
+    /// The layers of the tree, from the leaves to the root.
+    layers: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> MerkleTree<F> {
+    /// Builds the tree over the given evaluations, whose number must be a power of two.
+    pub(super) fn new(hasher: &Poseidon<F, 2>, evaluations: &[F]) -> Self {
+        let half = evaluations.len() / 2;
+        let leaves =
+            cfg_into_iter!(0..half).map(|j| hasher.evaluate(&[evaluations[j], evaluations[j + half]])).collect();
+
+        let mut layers: Vec<Vec<F>> = vec![leaves];
+        while layers.last().map_or(false, |layer| layer.len() > 1) {
+            let layer = layers.last().unwrap();
+            let next = cfg_into_iter!(0..layer.len() / 2).map(|i| hasher.evaluate(&layer[2 * i..2 * i + 2])).collect();
+            layers.push(next);
+        }
+        Self { layers }
+    }
+
+    /// Returns the root of the tree.
+    pub(super) fn root(&self) -> F {
+        self.layers.last().unwrap()[0]
+    }
+
+    /// Returns the siblings on the path from the leaf at `index` to the root.
+    pub(super) fn prove(&self, mut index: usize) -> Vec<F> {
+        let mut path = Vec::with_capacity(self.layers.len() - 1);
+        for layer in &self.layers[..self.layers.len() - 1] {
+            path.push(layer[index ^ 1]);
+            index >>= 1;
+        }
+        path
+    }
+
+    /// Returns `true` if `path` proves that the leaf at `index` hashes `evaluations` under `root`.
+    pub(super) fn verify(hasher: &Poseidon<F, 2>, root: F, mut index: usize, evaluations: (F, F), path: &[F]) -> bool {
+        let mut node = hasher.evaluate(&[evaluations.0, evaluations.1]);
+        for sibling in path {
+            node = if index & 1 == 0 { hasher.evaluate(&[node, *sibling]) } else { hasher.evaluate(&[*sibling, node]) };
+            index >>= 1;
+        }
+        node == root
+    }
+}
@@ -0,0 +1,450 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Here we construct a transparent polynomial commitment scheme from the FRI low-degree test of
+//! Ben-Sasson, Bentov, Horesh, and Riabzev ([BBHR18](https://eccc.weizmann.ac.il/report/2017/134/)).
+//! A polynomial is committed to as the Merkle root of its evaluations over a coset of an FFT domain,
+//! and the evaluations `p_i(z) = v_i` of one or more polynomials are proven by running FRI on a random
+//! linear combination of the quotients `(p_i(X) - v_i) / (X - z)`.
+//! The scheme requires no trusted setup and relies only on a collision-resistant hash function, so it is
+//! plausibly post-quantum secure, at the cost of proofs of polylogarithmic size. The commitments are not hiding.
+//!
+//! Let `2^log_blowup` be the ratio of the size of the evaluation domain to the degree bound. The soundness
+//! error of the queries is *conjectured* to be about `2^{-num_queries * log_blowup}`, which assumes that FRI
+//! is sound up to the list-decoding capacity. The best proven bound, in the Johnson regime of Ben-Sasson,
+//! Carmon, Ishai, Kopparty, and Saraf ([BCIKS20](https://eprint.iacr.org/2020/654)), is only about
+//! `2^{-num_queries * log_blowup / 2}`, plus a term that grows with the domain size over the field size.
+//! Parameters that must be provably secure should be chosen with the proven bound.
+
+use crate::{
+    crypto_hash::Poseidon,
+    fft::{DensePolynomial, EvaluationDomain},
+    polycommit::PCError,
+    AlgebraicSponge,
+    PolynomialCommitment,
+};
+use snarkvm_fields::{batch_inversion, PrimeField};
+
+use anyhow::anyhow;
+use core::marker::PhantomData;
+use itertools::Itertools;
+use rand_core::RngCore;
+
+mod data_structures;
+pub use data_structures::*;
+
+mod merkle;
+use merkle::MerkleTree;
+
+/// Polynomial commitment based on the FRI low-degree test of [[BBHR18]][bbhr], where evaluation
+/// proofs follow the quotienting approach of [[VP19, “RedShift”]][redshift].
+/// The challenges of the protocol are drawn from the given sponge.
+///
+/// The scheme implements [`PolynomialCommitment`], like [`IPAPC`](crate::polycommit::ipa_pc::IPAPC),
+/// and supports openings of one or more polynomials at a single point. As the commitments are not hiding,
+/// the randomness is `()`, and asking for a hiding commitment is an error.
+///
+/// [bbhr]: https://eccc.weizmann.ac.il/report/2017/134/
+/// [redshift]: https://eprint.iacr.org/2019/1400
+#[derive(Clone, Debug)]
+pub struct FriPC<F: PrimeField, S: AlgebraicSponge<F, 2>> {
+    _field: PhantomData<(F, S)>,
+}
+
+impl<F: PrimeField, S: AlgebraicSponge<F, 2>> FriPC<F, S> {
+    /// Constructs the committer and verifier keys for polynomials of degree at most `max_degree`,
+    /// evaluated over a domain `2^log_blowup` times larger than the degree bound, and checked at `num_queries` points.
+    /// The parameters are public, so no trusted setup is required.
+    pub fn setup(
+        max_degree: usize,
+        log_blowup: usize,
+        num_queries: usize,
+    ) -> Result<(FriCommitterKey<F>, FriVerifierKey<F>), PCError> {
+        if max_degree == 0 {
+            return Err(PCError::DegreeIsZero);
+        }
+        if log_blowup == 0 || num_queries == 0 {
+            return Err(anyhow!("The log blowup factor and the number of queries must be greater than 0").into());
+        }
+        let degree_bound = (max_degree + 1).next_power_of_two();
+        let domain = degree_bound
+            .checked_shl(log_blowup as u32)
+            .and_then(EvaluationDomain::new)
+            .ok_or(PCError::UnsupportedDegreeBound(max_degree))?;
+        let num_rounds = degree_bound.trailing_zeros() as usize;
+
+        let ck = FriCommitterKey { hasher: Poseidon::setup(), domain, num_rounds, num_queries };
+        Ok((ck.clone(), ck))
+    }
+}
+
+impl<F: PrimeField, S: AlgebraicSponge<F, 2>> PolynomialCommitment for FriPC<F, S> {
+    type BaseField = F;
+    type Commitment = FriCommitment<F>;
+    type CommitterKey = FriCommitterKey<F>;
+    type Proof = FriProof<F>;
+    type Randomness = ();
+    type ScalarField = F;
+    type Sponge = S;
+    type VerifierKey = FriVerifierKey<F>;
+
+    fn commit(
+        ck: &FriCommitterKey<F>,
+        polynomial: &DensePolynomial<F>,
+        hiding: bool,
+        _rng: Option<&mut dyn RngCore>,
+    ) -> Result<(FriCommitment<F>, ()), PCError> {
+        if hiding {
+            return Err(anyhow!("FRI commitments are not hiding").into());
+        }
+        Self::check_num_coefficients(polynomial, ck)?;
+        let commit_time = start_timer!(|| format!("Committing to polynomial of degree {}", polynomial.degree()));
+        let evaluations = ck.domain.coset_fft(&polynomial.coeffs);
+        let commitment = FriCommitment(MerkleTree::new(&ck.hasher, &evaluations).root());
+        end_timer!(commit_time);
+        Ok((commitment, ()))
+    }
+
+    fn batch_open(
+        ck: &FriCommitterKey<F>,
+        polynomials: &[DensePolynomial<F>],
+        commitments: &[FriCommitment<F>],
+        point: F,
+        rands: &[()],
+        sponge: &mut S,
+        _rng: Option<&mut dyn RngCore>,
+    ) -> Result<FriProof<F>, PCError> {
+        if polynomials.len() != commitments.len() || polynomials.len() != rands.len() {
+            return Err(anyhow!(
+                "Mismatched number of polynomials ({}), commitments ({}), and randomness ({})",
+                polynomials.len(),
+                commitments.len(),
+                rands.len()
+            )
+            .into());
+        }
+        for polynomial in polynomials {
+            Self::check_num_coefficients(polynomial, ck)?;
+        }
+        Self::check_point(ck, point)?;
+        let open_time = start_timer!(|| format!("Opening {} polynomials", polynomials.len()));
+
+        let values = polynomials.iter().map(|polynomial| polynomial.evaluate(point)).collect_vec();
+        let evaluations = polynomials.iter().map(|polynomial| ck.domain.coset_fft(&polynomial.coeffs)).collect_vec();
+        let trees = evaluations.iter().map(|evaluations| MerkleTree::new(&ck.hasher, evaluations)).collect_vec();
+
+        // Compute the evaluations of the combined quotient `sum_i c^i (p_i(X) - v_i) / (X - z)` over the coset.
+        let quotient_time = start_timer!(|| "Computing the quotient polynomial");
+        let coefficients = Self::absorb_claims(sponge, commitments, point, &values);
+        let mut denominators = Self::coset_elements(ck).map(|x| x - point).collect_vec();
+        batch_inversion(&mut denominators);
+        let quotient = denominators
+            .iter()
+            .enumerate()
+            .map(|(j, d)| {
+                let numerator = coefficients.iter().zip(&evaluations).zip(&values).map(|((c, e), v)| *c * (e[j] - v));
+                numerator.sum::<F>() * d
+            })
+            .collect_vec();
+        end_timer!(quotient_time);
+
+        // Commit to each folding layer. The first layer is opened through the trees of the polynomials.
+        let fold_time = start_timer!(|| format!("Folding the quotient over {} rounds", ck.num_rounds));
+        let mut layers = Vec::with_capacity(ck.num_rounds - 1);
+        let mut layer_roots = Vec::with_capacity(ck.num_rounds - 1);
+        let (mut layer, mut offset, mut generator) = (quotient, F::multiplicative_generator(), ck.domain.group_gen);
+        for round in 0..ck.num_rounds {
+            let beta = sponge.squeeze_native_field_elements(1)[0];
+            layer = Self::fold(&layer, beta, offset, generator);
+            offset.square_in_place();
+            generator.square_in_place();
+            if round + 1 < ck.num_rounds {
+                let tree = MerkleTree::new(&ck.hasher, &layer);
+                sponge.absorb_native_field_elements(&[tree.root()]);
+                layer_roots.push(tree.root());
+                layers.push((tree, layer.clone()));
+            }
+        }
+        // For polynomials within the degree bound, the final layer is constant.
+        let final_value = layer[0];
+        end_timer!(fold_time);
+
+        // Open the polynomials and each folded layer at the queried points.
+        sponge.absorb_native_field_elements(&[final_value]);
+        let half = ck.domain.size() / 2;
+        let queries = Self::query_indices(ck, sponge)
+            .into_iter()
+            .map(|mut index| {
+                let mut query = FriQueryProof {
+                    polynomial_evaluations: evaluations.iter().map(|e| (e[index], e[index + half])).collect(),
+                    polynomial_paths: trees.iter().map(|tree| tree.prove(index)).collect(),
+                    ..Default::default()
+                };
+                for (tree, evaluations) in &layers {
+                    let half = evaluations.len() / 2;
+                    index %= half;
+                    query.evaluations.push((evaluations[index], evaluations[index + half]));
+                    query.paths.push(tree.prove(index));
+                }
+                query
+            })
+            .collect();
+
+        end_timer!(open_time);
+        Ok(FriProof { layer_roots, final_value, queries })
+    }
+
+    fn batch_check(
+        vk: &FriVerifierKey<F>,
+        commitments: &[FriCommitment<F>],
+        point: F,
+        values: &[F],
+        proof: &FriProof<F>,
+        sponge: &mut S,
+    ) -> Result<bool, PCError> {
+        if commitments.len() != values.len() {
+            return Err(anyhow!(
+                "Mismatched number of commitments ({}) and values ({})",
+                commitments.len(),
+                values.len()
+            )
+            .into());
+        }
+        Self::check_point(vk, point)?;
+        if proof.layer_roots.len() + 1 != vk.num_rounds || proof.queries.len() != vk.num_queries {
+            return Ok(false);
+        }
+        let check_time = start_timer!(|| format!("Checking {} evaluations", commitments.len()));
+
+        // Recompute the challenges of the prover.
+        let coefficients = Self::absorb_claims(sponge, commitments, point, values);
+        let mut betas = Vec::with_capacity(vk.num_rounds);
+        for round in 0..vk.num_rounds {
+            betas.push(sponge.squeeze_native_field_elements(1)[0]);
+            if let Some(root) = proof.layer_roots.get(round) {
+                sponge.absorb_native_field_elements(&[*root]);
+            }
+        }
+        sponge.absorb_native_field_elements(&[proof.final_value]);
+        let indices = Self::query_indices(vk, sponge);
+
+        for (mut index, query) in indices.into_iter().zip_eq(&proof.queries) {
+            if query.polynomial_evaluations.len() != commitments.len()
+                || query.polynomial_paths.len() != commitments.len()
+                || query.evaluations.len() != proof.layer_roots.len()
+                || query.paths.len() != proof.layer_roots.len()
+            {
+                return Ok(false);
+            }
+            let (mut size, mut offset, mut generator) =
+                (vk.domain.size(), F::multiplicative_generator(), vk.domain.group_gen);
+
+            // Ensure the evaluations are in the polynomials, and compute the quotient at `x` and `-x` from them.
+            let half = size / 2;
+            let x = offset * generator.pow([index as u64]);
+            let (mut a, mut b) = (F::zero(), F::zero());
+            for (((evaluations, path), commitment), (coefficient, value)) in query
+                .polynomial_evaluations
+                .iter()
+                .zip_eq(&query.polynomial_paths)
+                .zip_eq(commitments)
+                .zip_eq(coefficients.iter().zip_eq(values))
+            {
+                if path.len() != half.trailing_zeros() as usize
+                    || !MerkleTree::verify(&vk.hasher, commitment.0, index, *evaluations, path)
+                {
+                    return Ok(false);
+                }
+                a += *coefficient * (evaluations.0 - value);
+                b += *coefficient * (evaluations.1 - value);
+            }
+            a *= (x - point).inverse().unwrap();
+            b *= (-x - point).inverse().unwrap();
+            let mut expected = Self::fold_pair(a, b, betas[0], x.inverse().unwrap());
+            size = half;
+            offset.square_in_place();
+            generator.square_in_place();
+
+            for (((evaluations, path), root), beta) in
+                query.evaluations.iter().zip_eq(&query.paths).zip_eq(&proof.layer_roots).zip(&betas[1..])
+            {
+                let half = size / 2;
+                let position = index / half;
+                index %= half;
+
+                // Ensure the evaluations are in the layer, and are consistent with the previous fold.
+                if path.len() != half.trailing_zeros() as usize
+                    || !MerkleTree::verify(&vk.hasher, *root, index, *evaluations, path)
+                {
+                    return Ok(false);
+                }
+                let (a, b) = *evaluations;
+                if [a, b][position] != expected {
+                    return Ok(false);
+                }
+
+                let x = offset * generator.pow([index as u64]);
+                expected = Self::fold_pair(a, b, *beta, x.inverse().unwrap());
+
+                size = half;
+                offset.square_in_place();
+                generator.square_in_place();
+            }
+            if expected != proof.final_value {
+                return Ok(false);
+            }
+        }
+
+        end_timer!(check_time);
+        Ok(true)
+    }
+}
+
+impl<F: PrimeField, S: AlgebraicSponge<F, 2>> FriPC<F, S> {
+    /// Returns the elements `g ω^i` of the coset over which polynomials are evaluated.
+    fn coset_elements(ck: &FriCommitterKey<F>) -> impl Iterator<Item = F> {
+        let generator = ck.domain.group_gen;
+        core::iter::successors(Some(F::multiplicative_generator()), move |x| Some(*x * generator))
+            .take(ck.domain.size())
+    }
+
+    /// Folds the evaluations of `f` over the coset `offset <generator>` into the evaluations of
+    /// `f_even(Y) + beta f_odd(Y)` over the coset `offset^2 <generator^2>`, where `f(X) = f_even(X^2) + X f_odd(X^2)`.
+    fn fold(evaluations: &[F], beta: F, offset: F, generator: F) -> Vec<F> {
+        let half = evaluations.len() / 2;
+        let mut inverses = core::iter::successors(Some(offset), |x| Some(*x * generator)).take(half).collect_vec();
+        batch_inversion(&mut inverses);
+        (0..half).map(|j| Self::fold_pair(evaluations[j], evaluations[j + half], beta, inverses[j])).collect()
+    }
+
+    /// Returns `f_even(x^2) + beta f_odd(x^2)`, given `a = f(x)`, `b = f(-x)`, and `x_inv = 1 / x`.
+    fn fold_pair(a: F, b: F, beta: F, x_inv: F) -> F {
+        let two_inv = F::half();
+        (a + b) * two_inv + beta * (a - b) * two_inv * x_inv
+    }
+
+    /// Absorbs the commitments, point, and values into the sponge, and returns the
+    /// powers of a challenge that combine them, one for each commitment.
+    fn absorb_claims(sponge: &mut S, commitments: &[FriCommitment<F>], point: F, values: &[F]) -> Vec<F> {
+        sponge.absorb_native_field_elements(&commitments.iter().map(|c| c.0).collect_vec());
+        sponge.absorb_native_field_elements(&[point]);
+        sponge.absorb_native_field_elements(values);
+        let challenge = sponge.squeeze_native_field_elements(1)[0];
+        core::iter::successors(Some(F::one()), |c| Some(*c * challenge)).take(commitments.len()).collect()
+    }
+
+    /// Squeezes the indices of the queried leaves of the first layer.
+    fn query_indices(ck: &FriCommitterKey<F>, sponge: &mut S) -> Vec<usize> {
+        let num_leaves = ck.domain.size() / 2;
+        let elements = sponge.squeeze_native_field_elements(ck.num_queries);
+        elements.iter().map(|element| element.to_bigint().as_ref()[0] as usize % num_leaves).collect()
+    }
+
+    /// Ensures that `point` is outside of the coset, so that the quotient is well-defined.
+    fn check_point(ck: &FriCommitterKey<F>, point: F) -> Result<(), PCError> {
+        let size = [ck.domain.size() as u64];
+        if point.pow(size) == F::multiplicative_generator().pow(size) {
+            return Err(anyhow!("The evaluation point lies in the evaluation domain").into());
+        }
+        Ok(())
+    }
+
+    fn check_num_coefficients(polynomial: &DensePolynomial<F>, ck: &FriCommitterKey<F>) -> Result<(), PCError> {
+        if polynomial.coeffs.len() > ck.size() {
+            return Err(PCError::TooManyCoefficients {
+                num_coefficients: polynomial.coeffs.len(),
+                num_powers: ck.size(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{crypto_hash::PoseidonSponge, polycommit::test_templates::polynomial_commitment_test_template};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::One;
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    type Sponge = PoseidonSponge<Fr, 2, 1>;
+    type PC = FriPC<Fr, Sponge>;
+
+    #[test]
+    fn test_end_to_end() {
+        let rng = &mut TestRng::default();
+        for max_degree in [1, 7, 30, 64] {
+            let (ck, vk) = PC::setup(max_degree, 2, 16).unwrap();
+            let polynomial = DensePolynomial::rand(max_degree, rng);
+            let (commitment, rand) = PC::commit(&ck, &polynomial, false, None).unwrap();
+
+            let point = Fr::rand(rng);
+            let value = polynomial.evaluate(point);
+            let proof = PC::open(&ck, &polynomial, &commitment, point, &rand, &mut Sponge::new(), None).unwrap();
+            assert!(PC::check(&vk, &commitment, point, value, &proof, &mut Sponge::new()).unwrap());
+
+            // Ensure the proof does not verify for another value or point.
+            assert!(!PC::check(&vk, &commitment, point, value + Fr::one(), &proof, &mut Sponge::new()).unwrap());
+            assert!(!PC::check(&vk, &commitment, Fr::rand(rng), value, &proof, &mut Sponge::new()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_polynomial_commitment() {
+        let (ck, vk) = PC::setup(15, 2, 16).unwrap();
+        polynomial_commitment_test_template::<PC>(&ck, &vk, 15, false).unwrap();
+    }
+
+    #[test]
+    fn test_tampered_proof() {
+        let rng = &mut TestRng::default();
+        let (ck, vk) = PC::setup(15, 2, 8).unwrap();
+        let polynomials = (0..2).map(|_| DensePolynomial::rand(15, rng)).collect::<Vec<_>>();
+        let (commitments, rands): (Vec<_>, Vec<_>) =
+            polynomials.iter().map(|p| PC::commit(&ck, p, false, None).unwrap()).unzip();
+        let point = Fr::rand(rng);
+        let values = polynomials.iter().map(|p| p.evaluate(point)).collect::<Vec<_>>();
+        let proof = PC::batch_open(&ck, &polynomials, &commitments, point, &rands, &mut Sponge::new(), None).unwrap();
+        assert!(PC::batch_check(&vk, &commitments, point, &values, &proof, &mut Sponge::new()).unwrap());
+
+        let mut tampered = proof.clone();
+        tampered.final_value += Fr::one();
+        assert!(!PC::batch_check(&vk, &commitments, point, &values, &tampered, &mut Sponge::new()).unwrap());
+
+        let mut tampered = proof.clone();
+        tampered.queries[0].polynomial_evaluations[1].0 += Fr::one();
+        assert!(!PC::batch_check(&vk, &commitments, point, &values, &tampered, &mut Sponge::new()).unwrap());
+
+        let mut tampered = proof;
+        tampered.queries[0].evaluations[1].0 += Fr::one();
+        assert!(!PC::batch_check(&vk, &commitments, point, &values, &tampered, &mut Sponge::new()).unwrap());
+    }
+
+    #[test]
+    fn test_hiding_is_unsupported() {
+        let rng = &mut TestRng::default();
+        let (ck, _) = PC::setup(7, 2, 8).unwrap();
+        let polynomial = DensePolynomial::rand(7, rng);
+        assert!(PC::commit(&ck, &polynomial, true, Some(rng)).is_err());
+    }
+
+    #[test]
+    fn test_degree_is_too_large() {
+        let rng = &mut TestRng::default();
+        let (ck, _) = PC::setup(7, 2, 8).unwrap();
+        let polynomial = DensePolynomial::rand(8, rng);
+        assert!(PC::commit(&ck, &polynomial, false, None).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::Zero;
+use snarkvm_utilities::{
+    rand::Uniform,
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+};
+
+use rand_core::RngCore;
+
+/// `IPACommitterKey` is used to commit to, and create evaluation proofs for, a given polynomial.
+#[derive(Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IPACommitterKey<G: AffineCurve> {
+    /// The generators used to commit to the coefficients of a polynomial.
+    pub comm_key: Vec<G>,
+    /// The generator that binds the claimed evaluation to the inner-product argument.
+    pub h: G,
+    /// The generator used for making a commitment hiding.
+    pub s: G,
+}
+
+impl<G: AffineCurve> IPACommitterKey<G> {
+    /// The number of generators in `self.comm_key`, which is always a power of two.
+    pub fn size(&self) -> usize {
+        self.comm_key.len()
+    }
+
+    /// The maximum degree of a polynomial that can be committed to with `self`.
+    pub fn max_degree(&self) -> usize {
+        self.comm_key.len().saturating_sub(1)
+    }
+}
+
+/// `IPAVerifierKey` is used to check evaluation proofs for a given commitment.
+/// As the scheme is transparent, it consists of the same generators as the committer key.
+pub type IPAVerifierKey<G> = IPACommitterKey<G>;
+
+/// A commitment to a polynomial, as output by `IPAPC::commit`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IPACommitment<G: AffineCurve>(
+    /// The commitment is a group element.
+    pub G,
+);
+
+/// `IPARandomness` hides the polynomial inside a commitment. It is output by `IPAPC::commit`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IPARandomness<G: AffineCurve> {
+    /// The coefficient of the hiding generator in the commitment.
+    pub rand: G::ScalarField,
+}
+
+impl<G: AffineCurve> IPARandomness<G> {
+    /// Returns randomness for a non-hiding commitment.
+    pub fn empty() -> Self {
+        Self { rand: G::ScalarField::zero() }
+    }
+
+    /// Samples randomness for a hiding commitment.
+    pub fn rand<R: RngCore>(rng: &mut R) -> Self {
+        Self { rand: G::ScalarField::rand(rng) }
+    }
+
+    /// Does `self` provide any hiding properties to the corresponding commitment?
+    pub fn is_hiding(&self) -> bool {
+        !self.rand.is_zero()
+    }
+}
+
+/// `IPAProof` is an evaluation proof that is output by `IPAPC::open`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IPAProof<G: AffineCurve> {
+    /// The left cross terms of each round of the inner-product argument.
+    pub l_vec: Vec<G>,
+    /// The right cross terms of each round of the inner-product argument.
+    pub r_vec: Vec<G>,
+    /// The single coefficient that remains after the final round.
+    pub c: G::ScalarField,
+    /// A commitment to the random polynomial that masks the opened polynomial, if hiding.
+    pub hiding_comm: Option<G>,
+    /// The randomness of the masked commitment, if hiding.
+    pub rand: Option<G::ScalarField>,
+}
+
+impl<G: AffineCurve> IPAProof<G> {
+    /// Is this proof hiding?
+    pub fn is_hiding(&self) -> bool {
+        self.hiding_comm.is_some()
+    }
+}
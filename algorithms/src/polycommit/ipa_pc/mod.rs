@@ -0,0 +1,467 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Here we construct a transparent polynomial commitment scheme from the inner-product argument (IPA)
+//! of Bootle et al. ([BCCGP16](https://eprint.iacr.org/2016/263)) and Bünz et al.
+//! ([Bulletproofs](https://eprint.iacr.org/2017/1066)), in the form used for polynomial commitments by
+//! Bünz, Chiesa, Mishra, and Spooner ([BCMS20](https://eprint.iacr.org/2020/499)). Unlike [\[KZG10\]][kzg],
+//! the scheme requires no trusted setup and only a group in which the discrete logarithm is hard, at the
+//! cost of logarithmic-size proofs and linear-time verification.
+//!
+//! [kzg]: http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf
+
+use crate::{
+    crypto_hash::hash_to_curve,
+    fft::DensePolynomial,
+    msm::VariableBase,
+    polycommit::PCError,
+    AlgebraicSponge,
+    PolynomialCommitment,
+};
+use snarkvm_curves::traits::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, ToConstraintField, Zero};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, rand::Uniform};
+
+use core::marker::PhantomData;
+use itertools::Itertools;
+use rand_core::RngCore;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+mod data_structures;
+pub use data_structures::*;
+
+/// The domain separator for the generators that commit to the coefficients of a polynomial.
+const COMM_KEY_DOMAIN: &[u8] = b"AleoIPACommitmentKey";
+/// The domain separator for the generator that binds the evaluation to the inner-product argument.
+const H_DOMAIN: &[u8] = b"AleoIPAEvaluationGenerator";
+/// The domain separator for the generator used for making a commitment hiding.
+const S_DOMAIN: &[u8] = b"AleoIPAHidingGenerator";
+
+/// Polynomial commitment based on the inner-product argument of [[BCCGP16]][bccgp] and
+/// [[BBBPWM18, “Bulletproofs”]][bulletproofs], following the construction in [[BCMS20]][bcms].
+/// The (optional) hiding property of the opening follows the approach in the same work, by
+/// masking the opened polynomial with a random polynomial that vanishes at the query point.
+///
+/// The committer key and verifier key are obtained by trimming public parameters, and the challenges
+/// of the argument are drawn from the given sponge. Like [`SonicKZG10`](crate::polycommit::sonic_pc::SonicKZG10),
+/// the scheme implements [`PolynomialCommitment`], which supports openings of one or more polynomials
+/// at a single point, without degree bounds or linear combinations.
+///
+/// Note that Varuna still uses the richer interface of `SonicKZG10` directly (degree bounds, Lagrange bases,
+/// and linear combinations), so this scheme can replace it only in code that is generic over [`PolynomialCommitment`].
+///
+/// [bccgp]: https://eprint.iacr.org/2016/263
+/// [bulletproofs]: https://eprint.iacr.org/2017/1066
+/// [bcms]: https://eprint.iacr.org/2020/499
+#[derive(Clone, Debug)]
+pub struct IPAPC<G: AffineCurve, S: AlgebraicSponge<G::BaseField, 2>>
+where
+    G::BaseField: PrimeField,
+{
+    _curve: PhantomData<(G, S)>,
+}
+
+impl<G, S> IPAPC<G, S>
+where
+    G: AffineCurve + ToConstraintField<G::BaseField>,
+    G::BaseField: PrimeField,
+    S: AlgebraicSponge<G::BaseField, 2>,
+{
+    /// Constructs public parameters for polynomials of degree at most `max_degree`.
+    /// The generators are obtained by hashing to the curve, so no trusted setup is required.
+    pub fn setup(max_degree: usize) -> Result<IPACommitterKey<G>, PCError> {
+        if max_degree == 0 {
+            return Err(PCError::DegreeIsZero);
+        }
+        let setup_time = start_timer!(|| format!("IPA setup for max degree {max_degree}"));
+        let size = (max_degree + 1).next_power_of_two();
+        let comm_key =
+            cfg_into_iter!(0..size).map(|i| hash_to_curve(COMM_KEY_DOMAIN, &(i as u64).to_le_bytes())).collect();
+        let h = hash_to_curve(H_DOMAIN, &[]);
+        let s = hash_to_curve(S_DOMAIN, &[]);
+        end_timer!(setup_time);
+        Ok(IPACommitterKey { comm_key, h, s })
+    }
+
+    /// Specializes the public parameters for polynomials of degree at most `supported_degree`.
+    pub fn trim(
+        pp: &IPACommitterKey<G>,
+        supported_degree: usize,
+    ) -> Result<(IPACommitterKey<G>, IPAVerifierKey<G>), PCError> {
+        let size = (supported_degree + 1).next_power_of_two();
+        if size > pp.size() {
+            return Err(PCError::TrimmingDegreeTooLarge);
+        }
+        let ck = IPACommitterKey { comm_key: pp.comm_key[..size].to_vec(), h: pp.h, s: pp.s };
+        Ok((ck.clone(), ck))
+    }
+}
+
+impl<G, S> PolynomialCommitment for IPAPC<G, S>
+where
+    G: AffineCurve + ToConstraintField<G::BaseField>,
+    G::BaseField: PrimeField,
+    S: AlgebraicSponge<G::BaseField, 2>,
+{
+    type BaseField = G::BaseField;
+    type Commitment = IPACommitment<G>;
+    type CommitterKey = IPACommitterKey<G>;
+    type Proof = IPAProof<G>;
+    type Randomness = IPARandomness<G>;
+    type ScalarField = G::ScalarField;
+    type Sponge = S;
+    type VerifierKey = IPAVerifierKey<G>;
+
+    fn commit(
+        ck: &IPACommitterKey<G>,
+        polynomial: &DensePolynomial<G::ScalarField>,
+        hiding: bool,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(IPACommitment<G>, IPARandomness<G>), PCError> {
+        Self::check_num_coefficients(polynomial, ck)?;
+        let commit_time = start_timer!(|| format!("Committing to polynomial of degree {}", polynomial.degree()));
+
+        let randomness = if hiding {
+            let mut rng = rng.ok_or(PCError::MissingRng)?;
+            IPARandomness::rand(&mut rng)
+        } else {
+            IPARandomness::empty()
+        };
+        let commitment = Self::cm_commit(&ck.comm_key, &polynomial.coeffs, ck.s, randomness.rand);
+
+        end_timer!(commit_time);
+        Ok((IPACommitment(commitment.to_affine()), randomness))
+    }
+
+    fn batch_open(
+        ck: &IPACommitterKey<G>,
+        polynomials: &[DensePolynomial<G::ScalarField>],
+        commitments: &[IPACommitment<G>],
+        point: G::ScalarField,
+        rands: &[IPARandomness<G>],
+        sponge: &mut S,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<IPAProof<G>, PCError> {
+        if polynomials.len() != commitments.len() || polynomials.len() != rands.len() {
+            return Err(anyhow::anyhow!(
+                "Mismatched number of polynomials ({}), commitments ({}), and randomness ({})",
+                polynomials.len(),
+                commitments.len(),
+                rands.len()
+            )
+            .into());
+        }
+        for polynomial in polynomials {
+            Self::check_num_coefficients(polynomial, ck)?;
+        }
+        let open_time = start_timer!(|| format!("Opening {} polynomials", polynomials.len()));
+
+        // Combine the polynomials into a single polynomial, using powers of a challenge.
+        let values = polynomials.iter().map(|polynomial| polynomial.evaluate(point)).collect_vec();
+        let combiner = Self::absorb_claims(sponge, commitments, point, &values);
+
+        let mut polynomial = DensePolynomial::zero();
+        let mut rand = G::ScalarField::zero();
+        let mut coefficient = G::ScalarField::one();
+        for (p, r) in polynomials.iter().zip_eq(rands) {
+            polynomial += (coefficient, p);
+            rand += coefficient * r.rand;
+            coefficient *= combiner;
+        }
+
+        // If any of the commitments is hiding, mask the polynomial with a random polynomial that vanishes at `point`.
+        let mut hiding_comm = None;
+        if rands.iter().any(|r| r.is_hiding()) {
+            let mut rng = rng.ok_or(PCError::MissingRng)?;
+            let mut hiding_polynomial = DensePolynomial::rand(ck.max_degree(), &mut rng);
+            let evaluation = hiding_polynomial.evaluate(point);
+            hiding_polynomial.coeffs[0] -= evaluation;
+            let hiding_rand = G::ScalarField::rand(&mut rng);
+
+            let comm = Self::cm_commit(&ck.comm_key, &hiding_polynomial.coeffs, ck.s, hiding_rand).to_affine();
+            sponge.absorb_native_field_elements(&[comm]);
+            let alpha = sponge.squeeze_nonnative_field_elements::<G::ScalarField>(1)[0];
+
+            polynomial += (alpha, &hiding_polynomial);
+            rand += alpha * hiding_rand;
+            hiding_comm = Some(comm);
+        }
+
+        // Run the inner-product argument on the coefficients of the polynomial and the powers of `point`.
+        let size = ck.size();
+        let h_prime = ck.h * sponge.squeeze_nonnative_field_elements::<G::ScalarField>(1)[0];
+
+        let mut coeffs = polynomial.coeffs;
+        coeffs.resize(size, G::ScalarField::zero());
+        let mut powers = Self::powers_of(point, size);
+        let mut key = ck.comm_key.clone();
+
+        let mut l_vec = Vec::with_capacity(size.trailing_zeros() as usize);
+        let mut r_vec = Vec::with_capacity(size.trailing_zeros() as usize);
+        let mut n = size;
+        while n > 1 {
+            n /= 2;
+            let (coeffs_lo, coeffs_hi) = coeffs.split_at(n);
+            let (powers_lo, powers_hi) = powers.split_at(n);
+            let (key_lo, key_hi) = key.split_at(n);
+
+            let l = Self::cm_commit(key_lo, coeffs_hi, G::zero(), G::ScalarField::zero())
+                + h_prime * Self::inner_product(coeffs_hi, powers_lo);
+            let r = Self::cm_commit(key_hi, coeffs_lo, G::zero(), G::ScalarField::zero())
+                + h_prime * Self::inner_product(coeffs_lo, powers_hi);
+            let lr = G::Projective::batch_normalization_into_affine(vec![l, r]);
+            let (l, r) = (lr[0], lr[1]);
+
+            let (challenge, challenge_inv) = Self::round_challenge(sponge, l, r)?;
+            l_vec.push(l);
+            r_vec.push(r);
+
+            coeffs = cfg_iter!(coeffs_lo).zip(coeffs_hi).map(|(lo, hi)| *lo + challenge * hi).collect();
+            powers = cfg_iter!(powers_lo).zip(powers_hi).map(|(lo, hi)| *lo + challenge_inv * hi).collect();
+            let folded_key =
+                cfg_iter!(key_lo).zip(key_hi).map(|(lo, hi)| (*hi * challenge_inv).add_mixed(lo)).collect();
+            key = G::Projective::batch_normalization_into_affine(folded_key);
+        }
+
+        end_timer!(open_time);
+        Ok(IPAProof { l_vec, r_vec, c: coeffs[0], hiding_comm, rand: hiding_comm.map(|_| rand) })
+    }
+
+    fn batch_check(
+        vk: &IPAVerifierKey<G>,
+        commitments: &[IPACommitment<G>],
+        point: G::ScalarField,
+        values: &[G::ScalarField],
+        proof: &IPAProof<G>,
+        sponge: &mut S,
+    ) -> Result<bool, PCError> {
+        if commitments.len() != values.len() {
+            return Err(anyhow::anyhow!(
+                "Mismatched number of commitments ({}) and values ({})",
+                commitments.len(),
+                values.len()
+            )
+            .into());
+        }
+        let num_rounds = vk.size().trailing_zeros() as usize;
+        if proof.l_vec.len() != num_rounds || proof.r_vec.len() != num_rounds {
+            return Ok(false);
+        }
+        let check_time = start_timer!(|| format!("Checking {} evaluations", commitments.len()));
+
+        // Combine the commitments and values, using powers of a challenge.
+        let combiner = Self::absorb_claims(sponge, commitments, point, values);
+
+        let mut commitment = G::Projective::zero();
+        let mut value = G::ScalarField::zero();
+        let mut coefficient = G::ScalarField::one();
+        for (c, v) in commitments.iter().zip_eq(values) {
+            commitment += c.0 * coefficient;
+            value += coefficient * v;
+            coefficient *= combiner;
+        }
+
+        // If the proof is hiding, add the masking commitment and remove the randomness.
+        match (proof.hiding_comm, proof.rand) {
+            (Some(hiding_comm), Some(rand)) => {
+                sponge.absorb_native_field_elements(&[hiding_comm]);
+                let alpha = sponge.squeeze_nonnative_field_elements::<G::ScalarField>(1)[0];
+                commitment += hiding_comm * alpha;
+                commitment -= vk.s * rand;
+            }
+            (None, None) => (),
+            _ => return Ok(false),
+        }
+
+        let h_prime = vk.h * sponge.squeeze_nonnative_field_elements::<G::ScalarField>(1)[0];
+        commitment += h_prime * value;
+
+        // Fold the commitment with the cross terms of each round.
+        let mut challenge_invs = Vec::with_capacity(num_rounds);
+        for (l, r) in proof.l_vec.iter().zip_eq(&proof.r_vec) {
+            let (challenge, challenge_inv) = Self::round_challenge(sponge, *l, *r)?;
+            commitment += *l * challenge;
+            commitment += *r * challenge_inv;
+            challenge_invs.push(challenge_inv);
+        }
+
+        // Compute the folded generator and the folded powers of `point`.
+        // Round `i` folds the half of the vectors whose indices have bit `num_rounds - 1 - i` set.
+        let mut scalars = vec![G::ScalarField::one()];
+        let mut b = G::ScalarField::one();
+        let mut point_power = point;
+        for challenge_inv in challenge_invs.iter().rev() {
+            let upper = scalars.iter().map(|s| *s * challenge_inv).collect_vec();
+            scalars.extend(upper);
+            b *= G::ScalarField::one() + *challenge_inv * point_power;
+            point_power.square_in_place();
+        }
+        let scalars = cfg_iter!(scalars).map(|s| s.to_bigint()).collect::<Vec<_>>();
+        let final_key = VariableBase::msm(&vk.comm_key, &scalars);
+
+        let result = commitment == final_key * proof.c + h_prime * (proof.c * b);
+        end_timer!(check_time, || format!("Result: {result}"));
+        Ok(result)
+    }
+}
+
+impl<G, S> IPAPC<G, S>
+where
+    G: AffineCurve + ToConstraintField<G::BaseField>,
+    G::BaseField: PrimeField,
+    S: AlgebraicSponge<G::BaseField, 2>,
+{
+    /// Ensures the polynomial fits within the generators of the committer key.
+    fn check_num_coefficients(
+        polynomial: &DensePolynomial<G::ScalarField>,
+        ck: &IPACommitterKey<G>,
+    ) -> Result<(), PCError> {
+        if polynomial.coeffs.len() > ck.size() {
+            return Err(PCError::TooManyCoefficients {
+                num_coefficients: polynomial.coeffs.len(),
+                num_powers: ck.size(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the Pedersen vector commitment `<scalars, bases> + rand * hiding_generator`.
+    fn cm_commit(bases: &[G], scalars: &[G::ScalarField], hiding_generator: G, rand: G::ScalarField) -> G::Projective {
+        let scalars = cfg_iter!(scalars).map(|s| s.to_bigint()).collect::<Vec<_>>();
+        let mut commitment = VariableBase::msm(&bases[..scalars.len()], &scalars);
+        if !rand.is_zero() {
+            commitment += hiding_generator * rand;
+        }
+        commitment
+    }
+
+    /// Absorbs the commitments, point, and values into the sponge, and returns the challenge that combines them.
+    fn absorb_claims(
+        sponge: &mut S,
+        commitments: &[IPACommitment<G>],
+        point: G::ScalarField,
+        values: &[G::ScalarField],
+    ) -> G::ScalarField {
+        sponge.absorb_native_field_elements(&commitments.iter().map(|c| c.0).collect_vec());
+        sponge.absorb_nonnative_field_elements([point]);
+        sponge.absorb_nonnative_field_elements(values.iter().copied());
+        sponge.squeeze_nonnative_field_elements::<G::ScalarField>(1)[0]
+    }
+
+    /// Absorbs the cross terms of a round into the sponge, and returns the challenge of the round and its inverse.
+    fn round_challenge(sponge: &mut S, l: G, r: G) -> Result<(G::ScalarField, G::ScalarField), PCError> {
+        sponge.absorb_native_field_elements(&[l, r]);
+        let challenge = sponge.squeeze_nonnative_field_elements::<G::ScalarField>(1)[0];
+        let challenge_inv = challenge.inverse().ok_or_else(|| anyhow::anyhow!("The round challenge is zero"))?;
+        Ok((challenge, challenge_inv))
+    }
+
+    /// Returns the inner product of `a` and `b`.
+    fn inner_product(a: &[G::ScalarField], b: &[G::ScalarField]) -> G::ScalarField {
+        a.iter().zip_eq(b).map(|(a, b)| *a * b).sum()
+    }
+
+    /// Returns the first `size` powers of `point`, starting with `1`.
+    fn powers_of(point: G::ScalarField, size: usize) -> Vec<G::ScalarField> {
+        core::iter::successors(Some(G::ScalarField::one()), |power| Some(*power * point)).take(size).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto_hash::PoseidonSponge;
+    use snarkvm_curves::bls12_377::{Fq, Fr, G1Affine};
+    use snarkvm_utilities::rand::{SeededRng, Uniform};
+
+    type Sponge = PoseidonSponge<Fq, 2, 1>;
+    type PC = IPAPC<G1Affine, Sponge>;
+
+    fn check_single_polynomial(hiding: bool) {
+        let rng = &mut SeededRng::default();
+        let pp = PC::setup(31).unwrap();
+
+        for degree in [1, 7, 20, 31] {
+            let (ck, vk) = PC::trim(&pp, degree).unwrap();
+            let polynomial = DensePolynomial::rand(degree, rng);
+            let (commitment, rand) = PC::commit(&ck, &polynomial, hiding, Some(rng)).unwrap();
+            assert_eq!(hiding, rand.is_hiding());
+
+            let point = Fr::rand(rng);
+            let value = polynomial.evaluate(point);
+            let proof = PC::open(&ck, &polynomial, &commitment, point, &rand, &mut Sponge::new(), Some(rng)).unwrap();
+            assert_eq!(hiding, proof.is_hiding());
+            assert!(PC::check(&vk, &commitment, point, value, &proof, &mut Sponge::new()).unwrap());
+
+            // Ensure a wrong value is rejected.
+            assert!(!PC::check(&vk, &commitment, point, value + Fr::one(), &proof, &mut Sponge::new()).unwrap());
+            // Ensure a wrong point is rejected.
+            assert!(!PC::check(&vk, &commitment, Fr::rand(rng), value, &proof, &mut Sponge::new()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_single_polynomial() {
+        check_single_polynomial(false);
+    }
+
+    #[test]
+    fn test_single_polynomial_hiding() {
+        check_single_polynomial(true);
+    }
+
+    #[test]
+    fn test_batch_open_and_check() {
+        let rng = &mut SeededRng::default();
+        let degree = 15;
+        let pp = PC::setup(degree).unwrap();
+        let (ck, vk) = PC::trim(&pp, degree).unwrap();
+
+        let polynomials = (0..5).map(|i| DensePolynomial::rand(degree - i, rng)).collect::<Vec<_>>();
+        let (commitments, rands): (Vec<_>, Vec<_>) =
+            polynomials.iter().enumerate().map(|(i, p)| PC::commit(&ck, p, i % 2 == 0, Some(rng)).unwrap()).unzip();
+        let point = Fr::rand(rng);
+        let mut values = polynomials.iter().map(|p| p.evaluate(point)).collect::<Vec<_>>();
+
+        let proof =
+            PC::batch_open(&ck, &polynomials, &commitments, point, &rands, &mut Sponge::new(), Some(rng)).unwrap();
+        assert!(PC::batch_check(&vk, &commitments, point, &values, &proof, &mut Sponge::new()).unwrap());
+
+        // Ensure a wrong value is rejected.
+        values[3] += Fr::one();
+        assert!(!PC::batch_check(&vk, &commitments, point, &values, &proof, &mut Sponge::new()).unwrap());
+    }
+
+    #[test]
+    fn test_polynomial_commitment() {
+        let pp = PC::setup(15).unwrap();
+        let (ck, vk) = PC::trim(&pp, 15).unwrap();
+        for hiding in [false, true] {
+            crate::polycommit::test_templates::polynomial_commitment_test_template::<PC>(&ck, &vk, 15, hiding).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_degree_is_too_large() {
+        let rng = &mut SeededRng::default();
+        let pp = PC::setup(7).unwrap();
+        let (ck, _) = PC::trim(&pp, 7).unwrap();
+        assert!(PC::trim(&pp, 8).is_err());
+
+        let polynomial = DensePolynomial::rand(8, rng);
+        assert!(PC::commit(&ck, &polynomial, false, None).is_err());
+    }
+}
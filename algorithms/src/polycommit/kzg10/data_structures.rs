@@ -108,6 +108,21 @@ impl<E: PairingEngine> UniversalParams<E> {
             prepared_negative_powers_of_beta_h: self.powers.read().prepared_negative_powers_of_beta_h(),
         })
     }
+
+    /// Initializes the universal verifier from the hard-coded SRS, without loading the powers required by the prover.
+    pub fn load_universal_verifier() -> Result<UniversalVerifier<E>> {
+        let (g, gamma_g, beta_h, negative_powers_of_beta_h) = PowersOfG::<E>::load_verifier_powers()?;
+        let h = E::G2Affine::prime_subgroup_generator();
+        let prepared_h = h.prepare();
+        let prepared_beta_h = beta_h.prepare();
+        let prepared_negative_powers_of_beta_h =
+            Arc::new(negative_powers_of_beta_h.iter().map(|(d, affine)| (*d, affine.prepare())).collect());
+
+        Ok(UniversalVerifier {
+            vk: VerifierKey::<E> { g, gamma_g, h, beta_h, prepared_h, prepared_beta_h },
+            prepared_negative_powers_of_beta_h,
+        })
+    }
 }
 
 impl<E: PairingEngine> FromBytes for UniversalParams<E> {
@@ -39,6 +39,8 @@ use rayon::prelude::*;
 mod data_structures;
 pub use data_structures::*;
 
+mod standalone;
+
 use super::sonic_pc::LabeledPolynomialWithBasis;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -473,47 +475,10 @@ mod tests {
     #![allow(clippy::needless_borrow)]
     use super::*;
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
-    use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
-
-    use std::borrow::Cow;
+    use snarkvm_utilities::{rand::SeededRng, FromBytes, ToBytes};
 
     type KZG_Bls12_377 = KZG10<Bls12_377>;
 
-    impl<E: PairingEngine> KZG10<E> {
-        /// Specializes the public parameters for a given maximum degree `d` for polynomials
-        /// `d` should be less that `pp.max_degree()`.
-        pub(crate) fn trim(
-            pp: &UniversalParams<E>,
-            mut supported_degree: usize,
-            hiding_bound: Option<usize>,
-        ) -> (Powers<E>, VerifierKey<E>) {
-            if supported_degree == 1 {
-                supported_degree += 1;
-            }
-            let powers_of_beta_g = pp.powers_of_beta_g(0, supported_degree + 1).unwrap().to_vec();
-
-            let powers_of_beta_times_gamma_g = if let Some(hiding_bound) = hiding_bound {
-                (0..=(hiding_bound + 1)).map(|i| pp.powers_of_beta_times_gamma_g()[&i]).collect()
-            } else {
-                vec![]
-            };
-
-            let powers = Powers {
-                powers_of_beta_g: Cow::Owned(powers_of_beta_g),
-                powers_of_beta_times_gamma_g: Cow::Owned(powers_of_beta_times_gamma_g),
-            };
-            let vk = VerifierKey {
-                g: pp.power_of_beta_g(0).unwrap(),
-                gamma_g: pp.powers_of_beta_times_gamma_g()[&0],
-                h: pp.h,
-                beta_h: pp.beta_h(),
-                prepared_h: pp.prepared_h.clone(),
-                prepared_beta_h: pp.prepared_beta_h.clone(),
-            };
-            (powers, vk)
-        }
-    }
-
     #[test]
     fn test_kzg10_universal_params_serialization() {
         let degree = 4;
@@ -526,8 +491,16 @@ mod tests {
         assert_eq!(&pp_bytes, &pp_recovered_bytes);
     }
 
+    #[test]
+    fn test_kzg10_load_universal_verifier() {
+        let pp = UniversalParams::<Bls12_377>::load().unwrap();
+        let expected = pp.to_universal_verifier().unwrap();
+        let candidate = UniversalParams::<Bls12_377>::load_universal_verifier().unwrap();
+        assert_eq!(expected, candidate);
+    }
+
     fn end_to_end_test_template<E: PairingEngine>() -> Result<(), PCError> {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         for _ in 0..100 {
             let mut degree = 0;
             while degree <= 1 {
@@ -535,7 +508,7 @@ mod tests {
             }
             let pp = KZG10::<E>::load_srs(degree)?;
             let hiding_bound = Some(1);
-            let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound);
+            let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound)?;
             let p = DensePolynomial::rand(degree, rng);
             let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, Some(rng))?;
             let point = E::Fr::rand(rng);
@@ -553,12 +526,12 @@ mod tests {
     }
 
     fn linear_polynomial_test_template<E: PairingEngine>() -> Result<(), PCError> {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         for _ in 0..100 {
             let degree = 50;
             let pp = KZG10::<E>::load_srs(degree)?;
             let hiding_bound = Some(1);
-            let (ck, vk) = KZG10::trim(&pp, 2, hiding_bound);
+            let (ck, vk) = KZG10::trim(&pp, 2, hiding_bound)?;
             let p = DensePolynomial::rand(1, rng);
             let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, Some(rng))?;
             let point = E::Fr::rand(rng);
@@ -576,7 +549,7 @@ mod tests {
     }
 
     fn batch_check_test_template<E: PairingEngine>() -> Result<(), PCError> {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         for _ in 0..10 {
             let hiding_bound = Some(1);
             let mut degree = 0;
@@ -584,7 +557,7 @@ mod tests {
                 degree = usize::rand(rng) % 20;
             }
             let pp = KZG10::<E>::load_srs(degree)?;
-            let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound);
+            let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound)?;
 
             let mut comms = Vec::new();
             let mut values = Vec::new();
@@ -626,11 +599,11 @@ mod tests {
 
     #[test]
     fn test_degree_is_too_large() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
 
         let max_degree = 123;
         let pp = KZG_Bls12_377::load_srs(max_degree).unwrap();
-        let (powers, _) = KZG_Bls12_377::trim(&pp, max_degree, None);
+        let (powers, _) = KZG_Bls12_377::trim(&pp, max_degree, None).unwrap();
 
         let p = DensePolynomial::<Fr>::rand(max_degree + 1, rng);
         assert!(p.degree() > max_degree);
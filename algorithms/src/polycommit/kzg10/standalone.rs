@@ -0,0 +1,238 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A standalone interface to [`KZG10`], for applications (such as data-availability
+//! commitments) that need to commit to and open polynomials directly, without going
+//! through the labeled, degree-bounded machinery of [`SonicKZG10`](crate::polycommit::sonic_pc::SonicKZG10).
+//!
+//! Commitments produced here are non-hiding. Several polynomials may be opened at a
+//! common point with a single proof, by combining them with powers of a challenge
+//! that the caller derives (e.g. via Fiat-Shamir) after the commitments are fixed.
+
+use super::{KZGCommitment, KZGProof, KZGRandomness, Powers, UniversalParams, VerifierKey, KZG10};
+use crate::{fft::DensePolynomial, polycommit::PCError};
+use snarkvm_curves::traits::{PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{One, Zero};
+
+use core::ops::Mul;
+use std::borrow::Cow;
+
+impl<E: PairingEngine> KZG10<E> {
+    /// Specializes the public parameters for a given maximum degree `d` for polynomials
+    /// `d` should be less that `pp.max_degree()`.
+    /// Returns an error if the parameters do not support `supported_degree` or `hiding_bound`.
+    pub fn trim(
+        pp: &UniversalParams<E>,
+        mut supported_degree: usize,
+        hiding_bound: Option<usize>,
+    ) -> Result<(Powers<'static, E>, VerifierKey<E>), PCError> {
+        if supported_degree == 1 {
+            supported_degree += 1;
+        }
+        let powers_of_beta_g = pp.powers_of_beta_g(0, supported_degree + 1)?;
+
+        let powers_of_beta_times_gamma_g = if let Some(hiding_bound) = hiding_bound {
+            let powers = pp.powers_of_beta_times_gamma_g();
+            (0..=(hiding_bound + 1))
+                .map(|i| {
+                    powers.get(&i).copied().ok_or(PCError::HidingBoundToolarge {
+                        hiding_poly_degree: hiding_bound,
+                        num_powers: powers.len(),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            vec![]
+        };
+
+        let powers = Powers {
+            powers_of_beta_g: Cow::Owned(powers_of_beta_g),
+            powers_of_beta_times_gamma_g: Cow::Owned(powers_of_beta_times_gamma_g),
+        };
+        let vk = VerifierKey {
+            g: pp.power_of_beta_g(0)?,
+            gamma_g: pp
+                .powers_of_beta_times_gamma_g()
+                .get(&0)
+                .copied()
+                .ok_or_else(|| anyhow::anyhow!("The parameters do not contain the power of gamma_g"))?,
+            h: pp.h,
+            beta_h: pp.beta_h(),
+            prepared_h: pp.prepared_h.clone(),
+            prepared_beta_h: pp.prepared_beta_h.clone(),
+        };
+        Ok((powers, vk))
+    }
+
+    /// Returns the committer and verifier keys for non-hiding commitments
+    /// to polynomials of degree at most `max_degree`.
+    pub fn setup(max_degree: usize) -> Result<(Powers<'static, E>, VerifierKey<E>), PCError> {
+        if max_degree == 0 {
+            return Err(PCError::DegreeIsZero);
+        }
+        let pp = Self::load_srs(max_degree)?;
+        Self::trim(&pp, max_degree, None)
+    }
+
+    /// Outputs a non-hiding commitment to `polynomial`.
+    pub fn commit_polynomial(
+        powers: &Powers<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+    ) -> Result<KZGCommitment<E>, PCError> {
+        let (commitment, _) = Self::commit(powers, &polynomial.into(), None, None)?;
+        Ok(commitment)
+    }
+
+    /// Outputs the evaluation of `polynomial` at `point`, along with a proof of the evaluation.
+    pub fn open_at(
+        powers: &Powers<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        point: E::Fr,
+    ) -> Result<(E::Fr, KZGProof<E>), PCError> {
+        let value = polynomial.evaluate(point);
+        let proof = Self::open(powers, polynomial, point, &KZGRandomness::empty())?;
+        Ok((value, proof))
+    }
+
+    /// Outputs the evaluations of each of `polynomials` at `point`, along with a single proof
+    /// for all of them. The polynomials are combined using powers of `challenge`, which must be
+    /// chosen after the commitments to `polynomials` are fixed.
+    pub fn batch_open(
+        powers: &Powers<E>,
+        polynomials: &[DensePolynomial<E::Fr>],
+        point: E::Fr,
+        challenge: E::Fr,
+    ) -> Result<(Vec<E::Fr>, KZGProof<E>), PCError> {
+        let values = polynomials.iter().map(|polynomial| polynomial.evaluate(point)).collect();
+
+        let mut combined = DensePolynomial::zero();
+        let mut coefficient = E::Fr::one();
+        for polynomial in polynomials {
+            combined += (coefficient, polynomial);
+            coefficient *= challenge;
+        }
+        let proof = Self::open(powers, &combined, point, &KZGRandomness::empty())?;
+        Ok((values, proof))
+    }
+
+    /// Verifies that `value` is the evaluation at `point` of the polynomial
+    /// committed inside `commitment`.
+    pub fn verify(
+        vk: &VerifierKey<E>,
+        commitment: &KZGCommitment<E>,
+        point: E::Fr,
+        value: E::Fr,
+        proof: &KZGProof<E>,
+    ) -> Result<bool, PCError> {
+        Self::check(vk, commitment, point, value, proof)
+    }
+
+    /// Verifies a proof produced by [`KZG10::batch_open`], i.e. that each `value_i` in `values`
+    /// is the evaluation at `point` of the polynomial committed inside `commitment_i`.
+    pub fn batch_verify(
+        vk: &VerifierKey<E>,
+        commitments: &[KZGCommitment<E>],
+        point: E::Fr,
+        values: &[E::Fr],
+        proof: &KZGProof<E>,
+        challenge: E::Fr,
+    ) -> Result<bool, PCError> {
+        if commitments.len() != values.len() {
+            return Err(anyhow::anyhow!(
+                "Mismatched number of commitments ({}) and values ({})",
+                commitments.len(),
+                values.len()
+            )
+            .into());
+        }
+
+        let mut combined_commitment = E::G1Projective::zero();
+        let mut combined_value = E::Fr::zero();
+        let mut coefficient = E::Fr::one();
+        for (commitment, value) in commitments.iter().zip(values) {
+            combined_commitment += commitment.0.mul(coefficient);
+            combined_value += coefficient * value;
+            coefficient *= challenge;
+        }
+        let combined_commitment = KZGCommitment(combined_commitment.to_affine());
+        Self::check(vk, &combined_commitment, point, combined_value, proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    type PC = KZG10<Bls12_377>;
+
+    #[test]
+    fn test_open_and_verify() {
+        let rng = &mut TestRng::default();
+        let degree = 16;
+        let (powers, vk) = PC::setup(degree).unwrap();
+
+        for _ in 0..10 {
+            let polynomial = DensePolynomial::rand(degree, rng);
+            let commitment = PC::commit_polynomial(&powers, &polynomial).unwrap();
+            let point = Fr::rand(rng);
+            let (value, proof) = PC::open_at(&powers, &polynomial, point).unwrap();
+            assert!(PC::verify(&vk, &commitment, point, value, &proof).unwrap());
+            // Ensure a wrong value is rejected.
+            assert!(!PC::verify(&vk, &commitment, point, value + Fr::one(), &proof).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_batch_open_and_verify() {
+        let rng = &mut TestRng::default();
+        let degree = 16;
+        let (powers, vk) = PC::setup(degree).unwrap();
+
+        let polynomials = (0..5).map(|_| DensePolynomial::rand(degree, rng)).collect::<Vec<_>>();
+        let commitments = polynomials
+            .iter()
+            .map(|polynomial| PC::commit_polynomial(&powers, polynomial).unwrap())
+            .collect::<Vec<_>>();
+        let point = Fr::rand(rng);
+        let challenge = Fr::rand(rng);
+
+        let (mut values, proof) = PC::batch_open(&powers, &polynomials, point, challenge).unwrap();
+        assert!(PC::batch_verify(&vk, &commitments, point, &values, &proof, challenge).unwrap());
+        // Ensure a different challenge is rejected.
+        assert!(!PC::batch_verify(&vk, &commitments, point, &values, &proof, Fr::rand(rng)).unwrap());
+        // Ensure a wrong value is rejected.
+        values[2] += Fr::one();
+        assert!(!PC::batch_verify(&vk, &commitments, point, &values, &proof, challenge).unwrap());
+    }
+
+    #[test]
+    fn test_trim_hiding_bound_is_too_large() {
+        let pp = PC::load_srs(8).unwrap();
+        // The hiding bound is limited by the first gap in the powers of `beta * gamma_g`.
+        let powers = pp.powers_of_beta_times_gamma_g();
+        let num_powers = (0..).take_while(|i| powers.contains_key(i)).count();
+        assert!(PC::trim(&pp, 8, Some(num_powers - 2)).is_ok());
+        assert!(PC::trim(&pp, 8, Some(num_powers - 1)).is_err());
+    }
+
+    #[test]
+    fn test_degree_is_too_large() {
+        let rng = &mut TestRng::default();
+        let (powers, _) = PC::setup(8).unwrap();
+        let polynomial = DensePolynomial::rand(9, rng);
+        assert!(PC::commit_polynomial(&powers, &polynomial).is_err());
+    }
+}
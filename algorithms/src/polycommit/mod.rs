@@ -33,6 +33,25 @@ pub mod kzg10;
 /// [al]: https://eprint.iacr.org/2019/601
 pub mod sonic_pc;
 
+/// Transparent polynomial commitment scheme based on the inner-product argument of
+/// [[BCCGP16]][bccgp] and [[BBBPWM18, “Bulletproofs”]][bulletproofs], which requires no trusted setup.
+///
+/// [bccgp]: https://eprint.iacr.org/2016/263
+/// [bulletproofs]: https://eprint.iacr.org/2017/1066
+pub mod ipa_pc;
+
+/// Polynomial commitment scheme for multilinear polynomials based on the construction in [[PST13]][pst],
+/// which commits to a polynomial through its evaluations over the Boolean hypercube.
+///
+/// [pst]: https://eprint.iacr.org/2011/587
+pub mod multilinear_pc;
+
+/// Transparent polynomial commitment scheme based on the FRI low-degree test of [[BBHR18]][bbhr],
+/// which relies only on a collision-resistant hash function.
+///
+/// [bbhr]: https://eccc.weizmann.ac.il/report/2017/134/
+pub mod fri_pc;
+
 /// Errors pertaining to query sets.
 pub mod error;
 pub use error::*;
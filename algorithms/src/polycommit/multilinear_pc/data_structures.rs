@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+/// `MultilinearUniversalParams` are the universal parameters for the multilinear polynomial commitment.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultilinearUniversalParams<E: PairingEngine> {
+    /// The maximum number of variables of a committed polynomial.
+    pub num_vars: usize,
+    /// For each `i` in `0..=num_vars`, the group elements `g^{eq(b, (τ_{i+1}, ..., τ_n))}`
+    /// for each `b` in the Boolean hypercube of `num_vars - i` variables.
+    pub powers_of_g: Vec<Vec<E::G1Affine>>,
+    /// The generator of G1.
+    pub g: E::G1Affine,
+    /// The generator of G2.
+    pub h: E::G2Affine,
+    /// For each `i` in `0..num_vars`, `h^{τ_{i+1}}`.
+    pub h_mask: Vec<E::G2Affine>,
+}
+
+/// `MultilinearCommitterKey` is used to commit to, and create evaluation proofs for, a given polynomial.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultilinearCommitterKey<E: PairingEngine> {
+    /// The number of variables of a committed polynomial.
+    pub num_vars: usize,
+    /// For each `i` in `0..=num_vars`, the group elements committing to the equality polynomials
+    /// in the last `num_vars - i` variables.
+    pub powers_of_g: Vec<Vec<E::G1Affine>>,
+}
+
+/// `MultilinearVerifierKey` is used to check evaluation proofs for a given commitment.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultilinearVerifierKey<E: PairingEngine> {
+    /// The number of variables of a committed polynomial.
+    pub num_vars: usize,
+    /// The generator of G1.
+    pub g: E::G1Affine,
+    /// The generator of G2.
+    pub h: E::G2Affine,
+    /// For each `i` in `0..num_vars`, `h` raised to the trapdoor of the `i`-th variable.
+    pub h_mask: Vec<E::G2Affine>,
+}
+
+/// A commitment to a multilinear polynomial, as output by `MultilinearPC::commit`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultilinearCommitment<E: PairingEngine>(
+    /// The commitment is a group element.
+    pub E::G1Affine,
+);
+
+/// `MultilinearProof` is an evaluation proof that is output by `MultilinearPC::open`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultilinearProof<E: PairingEngine> {
+    /// For each variable, the commitment to the quotient of the polynomial by `X_i - z_i`.
+    pub proofs: Vec<E::G1Affine>,
+}
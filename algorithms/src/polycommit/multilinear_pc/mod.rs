@@ -0,0 +1,243 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Here we construct a polynomial commitment to multilinear polynomials, following the construction of
+//! Papamanthou, Shi, and Tamassia ([PST13](https://eprint.iacr.org/2011/587)). A polynomial in `n` variables
+//! is committed to through its evaluations over the Boolean hypercube `{0, 1}^n`, so that provers built on
+//! the sumcheck protocol can commit to their witnesses without interpolating them over an FFT domain.
+//! An evaluation proof consists of one group element per variable.
+
+use crate::{
+    fft::MultilinearPolynomial,
+    msm::{FixedBase, VariableBase},
+    polycommit::PCError,
+};
+use anyhow::anyhow;
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{One, PrimeField};
+use snarkvm_utilities::{cfg_iter, rand::Uniform};
+
+use core::{marker::PhantomData, ops::Mul};
+use itertools::Itertools;
+use rand_core::RngCore;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+mod data_structures;
+pub use data_structures::*;
+
+/// Polynomial commitment to multilinear polynomials based on [[PST13]][pst].
+/// The polynomial `f` is committed to as `g^{f(τ)}`, where `τ` is the trapdoor of the setup, and the
+/// evaluation proof at `z` consists of commitments to the quotients `q_i` such that
+/// `f(X) - f(z) = Σ_i (X_i - z_i) q_i(X_{i+1}, ..., X_n)`.
+///
+/// [pst]: https://eprint.iacr.org/2011/587
+#[derive(Clone, Debug)]
+pub struct MultilinearPC<E: PairingEngine> {
+    _engine: PhantomData<E>,
+}
+
+impl<E: PairingEngine> MultilinearPC<E> {
+    /// Constructs public parameters for polynomials in at most `num_vars` variables.
+    /// The trapdoor is sampled from `rng` and discarded, so the parameters are only as trustworthy as `rng`.
+    pub fn setup<R: RngCore>(num_vars: usize, rng: &mut R) -> Result<MultilinearUniversalParams<E>, PCError> {
+        if num_vars == 0 {
+            return Err(PCError::DegreeIsZero);
+        }
+        let setup_time = start_timer!(|| format!("Multilinear setup for {num_vars} variables"));
+
+        let g = E::G1Projective::rand(rng);
+        let h = E::G2Projective::rand(rng);
+        let tau = (0..num_vars).map(|_| E::Fr::rand(rng)).collect_vec();
+
+        // For each suffix of the trapdoor, compute the evaluations of the equality polynomial in those variables.
+        let eq_time = start_timer!(|| "Computing the equality polynomials of the trapdoor");
+        let eq_polynomials = (0..=num_vars).map(|i| MultilinearPolynomial::eq(&tau[i..])).collect_vec();
+        let scalars = eq_polynomials.iter().flat_map(|eq| eq.evaluations().iter().copied()).collect_vec();
+        end_timer!(eq_time);
+
+        let g_time = start_timer!(|| format!("Generating {} powers of G", scalars.len()));
+        let scalar_bits = E::Fr::size_in_bits();
+        let window_size = FixedBase::get_mul_window_size(scalars.len());
+        let g_table = FixedBase::get_window_table(scalar_bits, window_size, g);
+        let powers = FixedBase::msm(scalar_bits, window_size, &g_table, &scalars);
+        let mut powers = E::G1Projective::batch_normalization_into_affine(powers).into_iter();
+        let powers_of_g =
+            eq_polynomials.iter().map(|eq| powers.by_ref().take(eq.evaluations().len()).collect()).collect();
+        end_timer!(g_time);
+
+        let h_mask = tau.iter().map(|tau_i| h.mul(*tau_i)).collect();
+        let h_mask = E::G2Projective::batch_normalization_into_affine(h_mask);
+
+        end_timer!(setup_time);
+        Ok(MultilinearUniversalParams { num_vars, powers_of_g, g: g.to_affine(), h: h.to_affine(), h_mask })
+    }
+
+    /// Specializes the public parameters for polynomials in `supported_num_vars` variables.
+    pub fn trim(
+        pp: &MultilinearUniversalParams<E>,
+        supported_num_vars: usize,
+    ) -> Result<(MultilinearCommitterKey<E>, MultilinearVerifierKey<E>), PCError> {
+        if supported_num_vars == 0 {
+            return Err(PCError::DegreeIsZero);
+        }
+        if supported_num_vars > pp.num_vars {
+            return Err(PCError::TrimmingDegreeTooLarge);
+        }
+        // The polynomial is committed to over the last `supported_num_vars` variables of the trapdoor.
+        let skip = pp.num_vars - supported_num_vars;
+        let ck = MultilinearCommitterKey { num_vars: supported_num_vars, powers_of_g: pp.powers_of_g[skip..].to_vec() };
+        let vk = MultilinearVerifierKey {
+            num_vars: supported_num_vars,
+            g: pp.g,
+            h: pp.h,
+            h_mask: pp.h_mask[skip..].to_vec(),
+        };
+        Ok((ck, vk))
+    }
+
+    /// Outputs a commitment to `polynomial`.
+    pub fn commit(
+        ck: &MultilinearCommitterKey<E>,
+        polynomial: &MultilinearPolynomial<E::Fr>,
+    ) -> Result<MultilinearCommitment<E>, PCError> {
+        Self::check_num_vars(polynomial.num_vars(), ck.num_vars)?;
+        let commit_time = start_timer!(|| format!("Committing to polynomial in {} variables", ck.num_vars));
+        let commitment = VariableBase::msm(&ck.powers_of_g[0], &convert_to_bigints(polynomial.evaluations()));
+        end_timer!(commit_time);
+        Ok(MultilinearCommitment(commitment.to_affine()))
+    }
+
+    /// On input a polynomial `f` and a point `point`, outputs the evaluation of `f` at `point`,
+    /// along with a proof of the evaluation.
+    pub fn open(
+        ck: &MultilinearCommitterKey<E>,
+        polynomial: &MultilinearPolynomial<E::Fr>,
+        point: &[E::Fr],
+    ) -> Result<(E::Fr, MultilinearProof<E>), PCError> {
+        Self::check_num_vars(polynomial.num_vars(), ck.num_vars)?;
+        Self::check_num_vars(point.len(), ck.num_vars)?;
+        let open_time = start_timer!(|| format!("Opening polynomial in {} variables", ck.num_vars));
+
+        let mut proofs = Vec::with_capacity(ck.num_vars);
+        let mut remainder = polynomial.evaluations().to_vec();
+        for (i, z_i) in point.iter().enumerate() {
+            // As `f` is linear in `X_i`, we have `f = (X_i - z_i) (f|_{X_i = 1} - f|_{X_i = 0}) + f|_{X_i = z_i}`.
+            let (quotient, folded): (Vec<_>, Vec<_>) = remainder
+                .chunks(2)
+                .map(|pair| {
+                    let quotient = pair[1] - pair[0];
+                    (quotient, pair[0] + quotient * z_i)
+                })
+                .unzip();
+            proofs.push(VariableBase::msm(&ck.powers_of_g[i + 1], &convert_to_bigints(&quotient)));
+            remainder = folded;
+        }
+        let proofs = E::G1Projective::batch_normalization_into_affine(proofs);
+
+        end_timer!(open_time);
+        Ok((remainder[0], MultilinearProof { proofs }))
+    }
+
+    /// Verifies that `value` is the evaluation at `point` of the polynomial
+    /// committed inside `commitment`.
+    pub fn check(
+        vk: &MultilinearVerifierKey<E>,
+        commitment: &MultilinearCommitment<E>,
+        point: &[E::Fr],
+        value: E::Fr,
+        proof: &MultilinearProof<E>,
+    ) -> Result<bool, PCError> {
+        Self::check_num_vars(point.len(), vk.num_vars)?;
+        Self::check_num_vars(proof.proofs.len(), vk.num_vars)?;
+        let check_time = start_timer!(|| format!("Checking evaluation in {} variables", vk.num_vars));
+
+        // Check that `e(C - g^v, h) = Π_i e(π_i, h^{τ_i - z_i})`.
+        let g1_elements = core::iter::once(commitment.0.to_projective() - vk.g.mul(value))
+            .chain(proof.proofs.iter().map(|proof_i| -proof_i.to_projective()))
+            .collect();
+        let g1_elements = E::G1Projective::batch_normalization_into_affine(g1_elements);
+        let g2_elements = core::iter::once(vk.h.to_projective())
+            .chain(vk.h_mask.iter().zip_eq(point).map(|(h_mask_i, z_i)| h_mask_i.to_projective() - vk.h.mul(*z_i)))
+            .collect();
+        let g2_elements = E::G2Projective::batch_normalization_into_affine(g2_elements);
+
+        let g1_prepared = g1_elements.iter().map(|g1| g1.prepare()).collect_vec();
+        let g2_prepared = g2_elements.iter().map(|g2| g2.prepare()).collect_vec();
+        let result = E::product_of_pairings(g1_prepared.iter().zip_eq(&g2_prepared)).is_one();
+
+        end_timer!(check_time, || format!("Result: {result}"));
+        Ok(result)
+    }
+
+    /// Ensures that `num_vars` matches the number of variables supported by the key.
+    fn check_num_vars(num_vars: usize, supported_num_vars: usize) -> Result<(), PCError> {
+        if num_vars != supported_num_vars {
+            return Err(anyhow!("Expected {supported_num_vars} variables, found {num_vars}").into());
+        }
+        Ok(())
+    }
+}
+
+fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
+    let to_bigint_time = start_timer!(|| "Converting polynomial coeffs to bigints");
+    let coeffs = cfg_iter!(p).map(|s| s.to_bigint()).collect::<Vec<_>>();
+    end_timer!(to_bigint_time);
+    coeffs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_fields::One;
+    use snarkvm_utilities::rand::{SeededRng, Uniform};
+
+    type PC = MultilinearPC<Bls12_377>;
+
+    #[test]
+    fn test_end_to_end() {
+        let rng = &mut SeededRng::default();
+        let pp = PC::setup(6, rng).unwrap();
+        for num_vars in 1..=6 {
+            let (ck, vk) = PC::trim(&pp, num_vars).unwrap();
+            let polynomial = MultilinearPolynomial::rand(num_vars, rng).unwrap();
+            let commitment = PC::commit(&ck, &polynomial).unwrap();
+
+            let point = (0..num_vars).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let (value, proof) = PC::open(&ck, &polynomial, &point).unwrap();
+            assert_eq!(value, polynomial.evaluate(&point).unwrap());
+            assert!(PC::check(&vk, &commitment, &point, value, &proof).unwrap());
+
+            // Ensure the proof does not verify for another value or point.
+            assert!(!PC::check(&vk, &commitment, &point, value + Fr::one(), &proof).unwrap());
+            let other_point = (0..num_vars).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            assert!(!PC::check(&vk, &commitment, &other_point, value, &proof).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_mismatched_num_vars() {
+        let rng = &mut SeededRng::default();
+        let pp = PC::setup(4, rng).unwrap();
+        let (ck, _) = PC::trim(&pp, 4).unwrap();
+
+        let polynomial = MultilinearPolynomial::rand(3, rng).unwrap();
+        assert!(PC::commit(&ck, &polynomial).is_err());
+        let polynomial = MultilinearPolynomial::rand(4, rng).unwrap();
+        assert!(PC::open(&ck, &polynomial, &[Fr::rand(rng); 3]).is_err());
+        assert!(PC::trim(&pp, 5).is_err());
+    }
+}
@@ -13,10 +13,10 @@
 // limitations under the License.
 
 use super::{LabeledPolynomial, PolynomialInfo};
-use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
+use crate::{crypto_hash::sha256::Sha256Writer, fft::EvaluationDomain, polycommit::kzg10};
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
-use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
+use snarkvm_utilities::{bounded_capacity, error, read_vec_without_len_in_parallel, serialize::*, FromBytes, ToBytes};
 
 use hashbrown::HashMap;
 use std::{
@@ -65,45 +65,28 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
         // Deserialize `powers`.
         let powers_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut powers_of_beta_g = Vec::with_capacity(powers_len as usize);
-        for _ in 0..powers_len {
-            let power: E::G1Affine = FromBytes::read_le(&mut reader)?;
-            powers_of_beta_g.push(power);
-        }
+        let powers_of_beta_g: Vec<E::G1Affine> = read_vec_without_len_in_parallel(&mut reader, powers_len as usize)?;
 
         // Deserialize `lagrange_basis_at_beta`.
         let lagrange_bases_at_beta_len: u32 = FromBytes::read_le(&mut reader)?;
         let mut lagrange_bases_at_beta_g = BTreeMap::new();
         for _ in 0..lagrange_bases_at_beta_len {
             let size: u32 = FromBytes::read_le(&mut reader)?;
-            let mut basis = Vec::with_capacity(size as usize);
-            for _ in 0..size {
-                let power: E::G1Affine = FromBytes::read_le(&mut reader)?;
-                basis.push(power);
-            }
+            let basis: Vec<E::G1Affine> = read_vec_without_len_in_parallel(&mut reader, size as usize)?;
             lagrange_bases_at_beta_g.insert(size as usize, basis);
         }
 
         // Deserialize `powers_of_beta_times_gamma_g`.
         let powers_of_beta_times_gamma_g_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut powers_of_beta_times_gamma_g = Vec::with_capacity(powers_of_beta_times_gamma_g_len as usize);
-        for _ in 0..powers_of_beta_times_gamma_g_len {
-            let powers_of_g: E::G1Affine = FromBytes::read_le(&mut reader)?;
-            powers_of_beta_times_gamma_g.push(powers_of_g);
-        }
+        let powers_of_beta_times_gamma_g: Vec<E::G1Affine> =
+            read_vec_without_len_in_parallel(&mut reader, powers_of_beta_times_gamma_g_len as usize)?;
 
         // Deserialize `shifted_powers_of_beta_g`.
         let has_shifted_powers_of_beta_g: bool = FromBytes::read_le(&mut reader)?;
         let shifted_powers_of_beta_g = match has_shifted_powers_of_beta_g {
             true => {
                 let shifted_powers_len: u32 = FromBytes::read_le(&mut reader)?;
-                let mut shifted_powers_of_beta_g = Vec::with_capacity(shifted_powers_len as usize);
-                for _ in 0..shifted_powers_len {
-                    let shifted_power: E::G1Affine = FromBytes::read_le(&mut reader)?;
-                    shifted_powers_of_beta_g.push(shifted_power);
-                }
-
-                Some(shifted_powers_of_beta_g)
+                Some(read_vec_without_len_in_parallel::<E::G1Affine, _>(&mut reader, shifted_powers_len as usize)?)
             }
             false => None,
         };
@@ -118,7 +101,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
                     let key: u32 = FromBytes::read_le(&mut reader)?;
 
                     let value_len: u32 = FromBytes::read_le(&mut reader)?;
-                    let mut value = Vec::with_capacity(value_len as usize);
+                    let mut value = Vec::with_capacity(bounded_capacity::<E::G1Affine>(value_len as usize));
                     for _ in 0..value_len {
                         let val: E::G1Affine = FromBytes::read_le(&mut reader)?;
                         value.push(val);
@@ -137,7 +120,8 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
         let enforced_degree_bounds = match has_enforced_degree_bounds {
             true => {
                 let enforced_degree_bounds_len: u32 = FromBytes::read_le(&mut reader)?;
-                let mut enforced_degree_bounds = Vec::with_capacity(enforced_degree_bounds_len as usize);
+                let mut enforced_degree_bounds =
+                    Vec::with_capacity(bounded_capacity::<usize>(enforced_degree_bounds_len as usize));
                 for _ in 0..enforced_degree_bounds_len {
                     let enforced_degree_bound: u32 = FromBytes::read_le(&mut reader)?;
                     enforced_degree_bounds.push(enforced_degree_bound as usize);
@@ -148,26 +132,27 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
             false => None,
         };
 
-        // Construct the hash of the group elements.
-        let mut hash_input = powers_of_beta_g.to_bytes_le().map_err(|_| error("Could not serialize powers"))?;
+        // Construct the hash of the group elements, without buffering their bytes.
+        let mut hasher = Sha256Writer::default();
+        powers_of_beta_g.write_le(&mut hasher).map_err(|_| error("Could not serialize powers"))?;
         powers_of_beta_times_gamma_g
-            .write_le(&mut hash_input)
+            .write_le(&mut hasher)
             .map_err(|_| error("Could not serialize powers_of_beta_times_gamma_g"))?;
 
         if let Some(shifted_powers_of_beta_g) = &shifted_powers_of_beta_g {
             shifted_powers_of_beta_g
-                .write_le(&mut hash_input)
+                .write_le(&mut hasher)
                 .map_err(|_| error("Could not serialize shifted_powers_of_beta_g"))?;
         }
 
         if let Some(shifted_powers_of_beta_times_gamma_g) = &shifted_powers_of_beta_times_gamma_g {
             for value in shifted_powers_of_beta_times_gamma_g.values() {
-                value.write_le(&mut hash_input).map_err(|_| error("Could not serialize shifted_power_of_gamma_g"))?;
+                value.write_le(&mut hasher).map_err(|_| error("Could not serialize shifted_power_of_gamma_g"))?;
             }
         }
 
         // Deserialize `hash`.
-        let hash = sha256(&hash_input);
+        let hash = hasher.finalize();
         let expected_hash: [u8; 32] = FromBytes::read_le(&mut reader)?;
 
         // Enforce the group elements construct the expected hash.
@@ -240,26 +225,27 @@ impl<E: PairingEngine> ToBytes for CommitterKey<E> {
             }
         }
 
-        // Construct the hash of the group elements.
-        let mut hash_input = self.powers_of_beta_g.to_bytes_le().map_err(|_| error("Could not serialize powers"))?;
+        // Construct the hash of the group elements, without buffering their bytes.
+        let mut hasher = Sha256Writer::default();
+        self.powers_of_beta_g.write_le(&mut hasher).map_err(|_| error("Could not serialize powers"))?;
         self.powers_of_beta_times_gamma_g
-            .write_le(&mut hash_input)
+            .write_le(&mut hasher)
             .map_err(|_| error("Could not serialize powers_of_beta_times_gamma_g"))?;
 
         if let Some(shifted_powers_of_beta_g) = &self.shifted_powers_of_beta_g {
             shifted_powers_of_beta_g
-                .write_le(&mut hash_input)
+                .write_le(&mut hasher)
                 .map_err(|_| error("Could not serialize shifted_powers_of_beta_g"))?;
         }
 
         if let Some(shifted_powers_of_beta_times_gamma_g) = &self.shifted_powers_of_beta_times_gamma_g {
             for value in shifted_powers_of_beta_times_gamma_g.values() {
-                value.write_le(&mut hash_input).map_err(|_| error("Could not serialize shifted_power_of_gamma_g"))?;
+                value.write_le(&mut hasher).map_err(|_| error("Could not serialize shifted_power_of_gamma_g"))?;
             }
         }
 
         // Serialize `hash`
-        let hash = sha256(&hash_input);
+        let hash = hasher.finalize();
         hash.write_le(&mut writer)
     }
 }
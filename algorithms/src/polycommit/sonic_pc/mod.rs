@@ -18,6 +18,7 @@ use crate::{
     polycommit::{kzg10, optional_rng::OptionalRng, PCError},
     srs::{UniversalProver, UniversalVerifier},
     AlgebraicSponge,
+    PolynomialCommitment,
 };
 use hashbrown::HashMap;
 use itertools::Itertools;
@@ -543,6 +544,86 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     }
 }
 
+/// The single-point interface of the scheme, which ignores degree bounds and Lagrange bases.
+/// A hiding commitment requires a committer key trimmed with a hiding bound of at least 1.
+impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> PolynomialCommitment for SonicKZG10<E, S> {
+    type BaseField = E::Fq;
+    type Commitment = Commitment<E>;
+    type CommitterKey = CommitterKey<E>;
+    type Proof = kzg10::KZGProof<E>;
+    type Randomness = Randomness<E>;
+    type ScalarField = E::Fr;
+    type Sponge = S;
+    type VerifierKey = UniversalVerifier<E>;
+
+    fn commit(
+        ck: &CommitterKey<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        hiding: bool,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Commitment<E>, Randomness<E>), PCError> {
+        kzg10::KZG10::commit(&Self::powers(ck), &polynomial.into(), hiding.then_some(1), rng)
+    }
+
+    fn batch_open(
+        ck: &CommitterKey<E>,
+        polynomials: &[DensePolynomial<E::Fr>],
+        commitments: &[Commitment<E>],
+        point: E::Fr,
+        rands: &[Randomness<E>],
+        sponge: &mut S,
+        _rng: Option<&mut dyn RngCore>,
+    ) -> Result<kzg10::KZGProof<E>, PCError> {
+        if polynomials.len() != commitments.len() || polynomials.len() != rands.len() {
+            return Err(anyhow::anyhow!(
+                "Mismatched number of polynomials ({}), commitments ({}), and randomness ({})",
+                polynomials.len(),
+                commitments.len(),
+                rands.len()
+            )
+            .into());
+        }
+        let open_time = start_timer!(|| format!("Opening {} polynomials", polynomials.len()));
+
+        let values = polynomials.iter().map(|polynomial| polynomial.evaluate(point)).collect_vec();
+        let coefficients = Self::absorb_claims(sponge, commitments, point, &values);
+        let (polynomial, rand) = Self::combine_polynomials(
+            coefficients.into_iter().zip_eq(polynomials).zip_eq(rands).map(|((c, p), r)| (c, p, r)),
+        );
+        let proof = kzg10::KZG10::open(&Self::powers(ck), &polynomial, point, &rand);
+
+        end_timer!(open_time);
+        proof
+    }
+
+    fn batch_check(
+        vk: &UniversalVerifier<E>,
+        commitments: &[Commitment<E>],
+        point: E::Fr,
+        values: &[E::Fr],
+        proof: &kzg10::KZGProof<E>,
+        sponge: &mut S,
+    ) -> Result<bool, PCError> {
+        if commitments.len() != values.len() {
+            return Err(anyhow::anyhow!(
+                "Mismatched number of commitments ({}) and values ({})",
+                commitments.len(),
+                values.len()
+            )
+            .into());
+        }
+        let check_time = start_timer!(|| format!("Checking {} evaluations", commitments.len()));
+
+        let coefficients = Self::absorb_claims(sponge, commitments, point, values);
+        let value = coefficients.iter().zip_eq(values).map(|(c, v)| *c * v).sum();
+        let commitment = Self::combine_commitments(coefficients.into_iter().zip_eq(commitments));
+        let result = kzg10::KZG10::check(&vk.vk, &kzg10::KZGCommitment(commitment.to_affine()), point, value, proof);
+
+        end_timer!(check_time);
+        result
+    }
+}
+
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     fn combine_polynomials<'a, B: Borrow<DensePolynomial<E::Fr>>>(
         coeffs_polys_rands: impl IntoIterator<Item = (E::Fr, B, &'a Randomness<E>)>,
@@ -574,6 +655,24 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         let comms = E::G1Projective::batch_normalization_into_affine(commitments);
         comms.into_iter().map(|c| kzg10::KZGCommitment(c))
     }
+
+    /// Returns the powers of `ck` for committing to polynomials without a degree bound.
+    fn powers(ck: &CommitterKey<E>) -> kzg10::Powers<'_, E> {
+        kzg10::Powers {
+            powers_of_beta_g: ck.powers_of_beta_g.as_slice().into(),
+            powers_of_beta_times_gamma_g: ck.powers_of_beta_times_gamma_g.as_slice().into(),
+        }
+    }
+
+    /// Absorbs the commitments, point, and values into the sponge, and returns the
+    /// powers of a challenge that combine them, one for each commitment.
+    fn absorb_claims(sponge: &mut S, commitments: &[Commitment<E>], point: E::Fr, values: &[E::Fr]) -> Vec<E::Fr> {
+        sponge.absorb_native_field_elements(commitments);
+        sponge.absorb_nonnative_field_elements([point]);
+        sponge.absorb_nonnative_field_elements(values.iter().copied());
+        let challenge = sponge.squeeze_short_nonnative_field_element::<E::Fr>();
+        core::iter::successors(Some(E::Fr::one()), |c| Some(*c * challenge)).take(commitments.len()).collect()
+    }
 }
 
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
@@ -681,7 +780,7 @@ mod tests {
     use super::{CommitterKey, SonicKZG10};
     use crate::{crypto_hash::PoseidonSponge, polycommit::test_templates::*};
     use snarkvm_curves::bls12_377::{Bls12_377, Fq};
-    use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
+    use snarkvm_utilities::{rand::SeededRng, FromBytes, ToBytes};
 
     use rand::distributions::Distribution;
 
@@ -690,7 +789,7 @@ mod tests {
 
     #[test]
     fn test_committer_key_serialization() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         let max_degree = rand::distributions::Uniform::from(8..=64).sample(rng);
         let supported_degree = rand::distributions::Uniform::from(1..=max_degree).sample(rng);
 
@@ -707,6 +806,16 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[test]
+    fn test_polynomial_commitment() {
+        let max_degree = 31;
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let (ck, vk) = PC_Bls12_377::trim(&pp, max_degree, [], 1, None).unwrap();
+        for hiding in [false, true] {
+            polynomial_commitment_test_template::<PC_Bls12_377>(&ck, &vk, max_degree, hiding).unwrap();
+        }
+    }
+
     #[test]
     fn test_single_poly() {
         single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
@@ -31,10 +31,11 @@ use crate::{
     },
     srs::UniversalVerifier,
     AlgebraicSponge,
+    PolynomialCommitment,
 };
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, Zero};
-use snarkvm_utilities::rand::{TestRng, Uniform};
+use snarkvm_utilities::rand::{SeededRng, Uniform};
 
 use itertools::Itertools;
 use rand::{
@@ -66,7 +67,7 @@ pub struct TestComponents<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> {
 }
 
 pub fn bad_degree_bound_test<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>() -> Result<(), PCError> {
-    let rng = &mut TestRng::default();
+    let rng = &mut SeededRng::default();
     let max_degree = 100;
     let pp = SonicKZG10::<E, S>::load_srs(max_degree)?;
     let universal_prover = &pp.to_universal_prover().unwrap();
@@ -132,7 +133,7 @@ pub fn lagrange_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>()
     let max_num_queries = 2usize;
     let mut test_components = Vec::new();
 
-    let rng = &mut TestRng::default();
+    let rng = &mut SeededRng::default();
     let pp = SonicKZG10::<E, S>::load_srs(max_degree)?;
     let universal_prover = &pp.to_universal_prover().unwrap();
 
@@ -247,7 +248,7 @@ where
 
     let mut test_components = Vec::new();
 
-    let rng = &mut TestRng::default();
+    let rng = &mut SeededRng::default();
     let max_degree = max_degree.unwrap_or_else(|| distributions::Uniform::from(8..=64).sample(rng));
     let pp = SonicKZG10::<E, S>::load_srs(max_degree)?;
     let universal_prover = &pp.to_universal_prover().unwrap();
@@ -369,7 +370,7 @@ fn equation_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>(
 
     let mut test_components = Vec::new();
 
-    let rng = &mut TestRng::default();
+    let rng = &mut SeededRng::default();
     let max_degree = max_degree.unwrap_or_else(|| distributions::Uniform::from(8..=64).sample(rng));
     let pp = SonicKZG10::<E, S>::load_srs(max_degree)?;
     let universal_prover = &pp.to_universal_prover().unwrap();
@@ -707,3 +708,42 @@ where
     };
     equation_test_template::<E, S>(info)
 }
+
+/// Opens polynomials of degree at most `max_degree`, one at a time and as a batch, through the
+/// [`PolynomialCommitment`] interface, and checks that the proofs are accepted only for the right claims.
+pub fn polynomial_commitment_test_template<PC: PolynomialCommitment>(
+    ck: &PC::CommitterKey,
+    vk: &PC::VerifierKey,
+    max_degree: usize,
+    hiding: bool,
+) -> Result<(), PCError> {
+    let rng = &mut SeededRng::default();
+    let num_polynomials = 5;
+
+    let polynomials = (0..num_polynomials)
+        .map(|i| DensePolynomial::<PC::ScalarField>::rand(max_degree.saturating_sub(i), rng))
+        .collect_vec();
+    let (commitments, rands): (Vec<_>, Vec<_>) = polynomials
+        .iter()
+        .enumerate()
+        .map(|(i, polynomial)| PC::commit(ck, polynomial, hiding && i % 2 == 0, Some(rng)))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip();
+    let point = PC::ScalarField::rand(rng);
+    let mut values = polynomials.iter().map(|polynomial| polynomial.evaluate(point)).collect_vec();
+
+    for (((polynomial, commitment), rand), value) in polynomials.iter().zip(&commitments).zip(&rands).zip(&values) {
+        let proof = PC::open(ck, polynomial, commitment, point, rand, &mut PC::Sponge::new(), Some(rng))?;
+        assert!(PC::check(vk, commitment, point, *value, &proof, &mut PC::Sponge::new())?);
+        assert!(!PC::check(vk, commitment, point, *value + PC::ScalarField::one(), &proof, &mut PC::Sponge::new())?);
+    }
+
+    let proof = PC::batch_open(ck, &polynomials, &commitments, point, &rands, &mut PC::Sponge::new(), Some(rng))?;
+    assert!(PC::batch_check(vk, &commitments, point, &values, &proof, &mut PC::Sponge::new())?);
+    assert!(!PC::batch_check(vk, &commitments, PC::ScalarField::rand(rng), &values, &proof, &mut PC::Sponge::new())?);
+
+    values[num_polynomials - 1] += PC::ScalarField::one();
+    assert!(!PC::batch_check(vk, &commitments, point, &values, &proof, &mut PC::Sponge::new())?);
+    Ok(())
+}
@@ -0,0 +1,374 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Import of circuits that are compiled by circom, from their `.r1cs` constraint system and `.wtns` witness.
+//!
+//! The circuit must be compiled over the scalar field of the SNARK (e.g. with `circom --prime bls12377`).
+//! The imported `CircomCircuit` is a `ConstraintSynthesizer`, so it can be indexed and proven with
+//! Varuna and the universal SRS like any other circuit (see `CircomCircuit::setup_and_prove`).
+
+mod r1cs_file;
+pub use r1cs_file::*;
+
+mod witness_file;
+pub use witness_file::*;
+
+use crate::r1cs::{ConstraintSynthesizer, ConstraintSystem, LinearCombination, SynthesisError};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::biginteger::BigInteger;
+
+use anyhow::{bail, ensure, Result};
+
+/// A circuit that is compiled by circom, with an optional witness.
+#[derive(Clone, Debug)]
+pub struct CircomCircuit<F: PrimeField> {
+    /// The constraint system.
+    r1cs: R1csFile<F>,
+    /// The assignment of every wire, if the circuit is to be proven.
+    witness: Option<Vec<F>>,
+}
+
+impl<F: PrimeField> CircomCircuit<F> {
+    /// Returns a circuit without a witness, which may be used to index the circuit.
+    pub fn new(r1cs: R1csFile<F>) -> Self {
+        Self { r1cs, witness: None }
+    }
+
+    /// Returns a circuit with the given witness, which may be used to prove the circuit.
+    pub fn with_witness(r1cs: R1csFile<F>, witness: WitnessFile<F>) -> Result<Self> {
+        let witness = witness.into_values();
+        ensure!(
+            witness.len() == r1cs.num_wires(),
+            "The witness has {} values, but the circuit has {} wires",
+            witness.len(),
+            r1cs.num_wires()
+        );
+        ensure!(witness[0].is_one(), "The first value of the witness must be 1");
+        Ok(Self { r1cs, witness: Some(witness) })
+    }
+
+    /// Returns the constraint system.
+    pub const fn r1cs(&self) -> &R1csFile<F> {
+        &self.r1cs
+    }
+
+    /// Returns the public inputs of the witness, which are the public outputs followed by the public inputs
+    /// of the circom circuit, excluding the constant `1`.
+    pub fn public_inputs(&self) -> Result<Vec<F>> {
+        match &self.witness {
+            Some(witness) => Ok(witness[1..=self.r1cs.num_public()].to_vec()),
+            None => bail!("The circuit does not have a witness"),
+        }
+    }
+
+    /// Returns the value of the given wire, or an error if the circuit does not have a witness.
+    fn value(&self, wire: usize) -> Result<F, SynthesisError> {
+        self.witness.as_ref().map(|witness| witness[wire]).ok_or(SynthesisError::AssignmentMissing)
+    }
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for CircomCircuit<F> {
+    fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        // Allocate the wires, where wire 0 is the constant 1, followed by the public wires and the private wires.
+        let mut variables = Vec::with_capacity(self.r1cs.num_wires());
+        variables.push(CS::one());
+        for wire in 1..self.r1cs.num_wires() {
+            let variable = match wire <= self.r1cs.num_public() {
+                true => cs.alloc_input(|| format!("wire {wire}"), || self.value(wire))?,
+                false => cs.alloc(|| format!("wire {wire}"), || self.value(wire))?,
+            };
+            variables.push(variable);
+        }
+
+        // Enforce the constraints.
+        let to_lc = |terms: &[(u32, F)], mut lc: LinearCombination<F>| {
+            for (wire, coefficient) in terms {
+                lc += (*coefficient, variables[*wire as usize]);
+            }
+            lc
+        };
+        for (i, (a, b, c)) in self.r1cs.constraints().iter().enumerate() {
+            cs.enforce(|| format!("constraint {i}"), |lc| to_lc(a, lc), |lc| to_lc(b, lc), |lc| to_lc(c, lc));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "snark")]
+impl<F: PrimeField> CircomCircuit<F> {
+    /// Indexes the circuit with the given universal SRS, and proves it with its witness.
+    /// Returns the proving key (which contains the circuit index), the verifying key, and the proof.
+    #[allow(clippy::type_complexity)]
+    pub fn setup_and_prove<S: crate::SNARK<ScalarField = F>, R: rand::Rng + rand::CryptoRng>(
+        &self,
+        universal_srs: &S::UniversalSRS,
+        universal_prover: &S::UniversalProver,
+        fs_parameters: &S::FSParameters,
+        rng: &mut R,
+    ) -> Result<(S::ProvingKey, S::VerifyingKey, S::Proof)> {
+        ensure!(self.witness.is_some(), "The circuit does not have a witness");
+        let (proving_key, verifying_key) = S::circuit_setup(universal_srs, self)?;
+        let proof = S::prove(universal_prover, fs_parameters, &proving_key, self, rng)?;
+        Ok((proving_key, verifying_key, proof))
+    }
+}
+
+/// Returns the sections of a circom binary file with the given magic, as `(type, contents)` pairs.
+fn read_sections<'a>(bytes: &'a [u8], magic: &[u8; 4], version: u32) -> Result<Vec<(u32, &'a [u8])>> {
+    let mut reader = bytes;
+    ensure!(take(&mut reader, 4)? == magic, "Invalid magic, expected '{}'", String::from_utf8_lossy(magic));
+    let file_version = read_u32(&mut reader)?;
+    ensure!(file_version == version, "Unsupported file version {file_version}, expected {version}");
+    let num_sections = read_u32(&mut reader)?;
+
+    let mut sections = Vec::new();
+    for _ in 0..num_sections {
+        let section_type = read_u32(&mut reader)?;
+        let size = usize::try_from(read_u64(&mut reader)?)?;
+        sections.push((section_type, take(&mut reader, size)?));
+    }
+    ensure!(reader.is_empty(), "Found {} trailing bytes", reader.len());
+    Ok(sections)
+}
+
+/// Returns the contents of the section of the given type, which must appear exactly once.
+fn find_section<'a>(sections: &[(u32, &'a [u8])], section_type: u32) -> Result<&'a [u8]> {
+    let mut matches = sections.iter().filter(|(candidate, _)| *candidate == section_type);
+    match (matches.next(), matches.next()) {
+        (Some((_, contents)), None) => Ok(contents),
+        (None, _) => bail!("Missing section {section_type}"),
+        (Some(_), Some(_)) => bail!("Duplicate section {section_type}"),
+    }
+}
+
+/// Reads the field header, which is the size of a field element in bytes, and the prime,
+/// and checks that the prime is the modulus of `F`.
+fn read_field_header<F: PrimeField>(reader: &mut &[u8]) -> Result<()> {
+    let size = read_u32(reader)? as usize;
+    ensure!(size == F::BigInteger::NUM_LIMBS * 8, "Invalid field element size ({size} bytes)");
+    let prime = take(reader, size)?;
+    ensure!(to_limbs::<F>(prime) == F::modulus(), "The circuit is not defined over the expected field");
+    Ok(())
+}
+
+/// Reads a field element in little-endian order, and checks that it is reduced.
+fn read_field_element<F: PrimeField>(reader: &mut &[u8]) -> Result<F> {
+    let bytes = take(reader, F::BigInteger::NUM_LIMBS * 8)?;
+    match F::from_bigint(to_limbs::<F>(bytes)) {
+        Some(element) => Ok(element),
+        None => bail!("The field element is not reduced"),
+    }
+}
+
+/// Returns the big integer of the given little-endian bytes, which must be the size of the big integer.
+fn to_limbs<F: PrimeField>(bytes: &[u8]) -> F::BigInteger {
+    let mut bigint = F::BigInteger::default();
+    for (limb, chunk) in bigint.as_mut().iter_mut().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    bigint
+}
+
+/// Returns the next `n` bytes of the reader.
+fn take<'a>(reader: &mut &'a [u8], n: usize) -> Result<&'a [u8]> {
+    ensure!(reader.len() >= n, "Expected {n} bytes, found {}", reader.len());
+    let (bytes, rest) = reader.split_at(n);
+    *reader = rest;
+    Ok(bytes)
+}
+
+/// Reads a little-endian `u32`.
+fn read_u32(reader: &mut &[u8]) -> Result<u32> {
+    Ok(u32::from_le_bytes(take(reader, 4)?.try_into()?))
+}
+
+/// Reads a little-endian `u64`.
+fn read_u64(reader: &mut &[u8]) -> Result<u64> {
+    Ok(u64::from_le_bytes(take(reader, 8)?.try_into()?))
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use snarkvm_fields::PrimeField;
+    use snarkvm_utilities::ToBytes;
+
+    /// Returns a section with the given type and contents.
+    fn section(section_type: u32, contents: &[u8]) -> Vec<u8> {
+        [&section_type.to_le_bytes()[..], &(contents.len() as u64).to_le_bytes(), contents].concat()
+    }
+
+    /// Returns the field header of `F`.
+    fn field_header<F: PrimeField>() -> Vec<u8> {
+        let prime = F::modulus().to_bytes_le().unwrap();
+        [&(prime.len() as u32).to_le_bytes()[..], &prime].concat()
+    }
+
+    /// Returns the `.r1cs` file of a circuit with the public output `z` and the private inputs `x` and `y`,
+    /// which enforces `x * y = z` and `(x + y) * 1 = s`, where `s` is an internal wire.
+    pub(crate) fn sample_r1cs<F: PrimeField>() -> Vec<u8> {
+        // The wires are [1, z, x, y, s].
+        let mut header = field_header::<F>();
+        for value in [5u32, 1, 0, 2] {
+            header.extend_from_slice(&value.to_le_bytes());
+        }
+        header.extend_from_slice(&5u64.to_le_bytes());
+        header.extend_from_slice(&2u32.to_le_bytes());
+
+        let lc = |terms: &[(u32, u64)]| {
+            let mut bytes = (terms.len() as u32).to_le_bytes().to_vec();
+            for (wire, coefficient) in terms {
+                bytes.extend_from_slice(&wire.to_le_bytes());
+                bytes.extend_from_slice(&F::from(*coefficient).to_bigint().to_bytes_le().unwrap());
+            }
+            bytes
+        };
+        let constraints = [
+            [lc(&[(2, 1)]), lc(&[(3, 1)]), lc(&[(1, 1)])].concat(),
+            [lc(&[(2, 1), (3, 1)]), lc(&[(0, 1)]), lc(&[(4, 1)])].concat(),
+        ]
+        .concat();
+        let labels: Vec<u8> = (0..5u64).flat_map(|label| label.to_le_bytes()).collect();
+
+        let sections = [section(1, &header), section(2, &constraints), section(3, &labels)].concat();
+        [&b"r1cs"[..], &1u32.to_le_bytes(), &3u32.to_le_bytes(), &sections].concat()
+    }
+
+    /// Returns the `.wtns` file of the given wire values.
+    pub(crate) fn sample_witness<F: PrimeField>(values: &[u64]) -> Vec<u8> {
+        let mut header = field_header::<F>();
+        header.extend_from_slice(&(values.len() as u32).to_le_bytes());
+        let values: Vec<u8> =
+            values.iter().flat_map(|value| F::from(*value).to_bigint().to_bytes_le().unwrap()).collect();
+
+        let sections = [section(1, &header), section(2, &values)].concat();
+        [&b"wtns"[..], &2u32.to_le_bytes(), &2u32.to_le_bytes(), &sections].concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{test_helpers::*, *};
+    use crate::r1cs::TestConstraintSystem;
+    use snarkvm_curves::bls12_377::Fr;
+
+    #[test]
+    fn test_circom_circuit() {
+        let r1cs = R1csFile::<Fr>::from_bytes(&sample_r1cs::<Fr>()).unwrap();
+        assert_eq!(r1cs.num_wires(), 5);
+        assert_eq!(r1cs.num_public(), 1);
+        assert_eq!(r1cs.constraints().len(), 2);
+
+        // Check that a valid witness satisfies the constraints.
+        let witness = WitnessFile::from_bytes(&sample_witness::<Fr>(&[1, 12, 3, 4, 7])).unwrap();
+        let circuit = CircomCircuit::with_witness(r1cs.clone(), witness).unwrap();
+        assert_eq!(circuit.public_inputs().unwrap(), vec![Fr::from(12u64)]);
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        circuit.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+        assert_eq!(cs.num_constraints(), 2);
+
+        // Check that an invalid witness does not satisfy the constraints.
+        let witness = WitnessFile::from_bytes(&sample_witness::<Fr>(&[1, 13, 3, 4, 7])).unwrap();
+        let circuit = CircomCircuit::with_witness(r1cs.clone(), witness).unwrap();
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        circuit.generate_constraints(&mut cs).unwrap();
+        assert!(!cs.is_satisfied());
+
+        // Check that a witness of the wrong length is rejected.
+        let witness = WitnessFile::from_bytes(&sample_witness::<Fr>(&[1, 12, 3, 4])).unwrap();
+        assert!(CircomCircuit::with_witness(r1cs, witness).is_err());
+    }
+
+    #[test]
+    fn test_fixtures() {
+        // The fixtures are the files of `resources/multiplier.circom`, which are generated by `resources/generate.py`.
+        let r1cs = R1csFile::<Fr>::from_bytes(include_bytes!("resources/multiplier.r1cs")).unwrap();
+        assert_eq!(r1cs.num_wires(), 6);
+        assert_eq!(r1cs.num_public(), 2);
+        assert_eq!(r1cs.num_private_inputs(), 2);
+        assert_eq!(r1cs.constraints().len(), 2);
+
+        // Check that the witness satisfies the constraints.
+        let witness = WitnessFile::<Fr>::from_bytes(include_bytes!("resources/multiplier.wtns")).unwrap();
+        let circuit = CircomCircuit::with_witness(r1cs.clone(), witness.clone()).unwrap();
+        assert_eq!(circuit.public_inputs().unwrap(), vec![Fr::from(60u64), Fr::from(3u64)]);
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        circuit.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+
+        // Check that a tampered witness does not satisfy the constraints.
+        let mut values = witness.into_values();
+        values[1] = Fr::from(61u64);
+        let circuit = CircomCircuit { r1cs, witness: Some(values) };
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        circuit.generate_constraints(&mut cs).unwrap();
+        assert!(!cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_invalid_files() {
+        let bytes = sample_r1cs::<Fr>();
+        assert!(R1csFile::<Fr>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(R1csFile::<Fr>::from_bytes(&[&bytes[..], &[0]].concat()).is_err());
+        assert!(WitnessFile::<Fr>::from_bytes(&bytes).is_err());
+
+        // Check that a circuit over another field is rejected.
+        let bytes = sample_r1cs::<snarkvm_curves::bls12_377::Fq>();
+        assert!(R1csFile::<Fr>::from_bytes(&bytes).is_err());
+    }
+
+    #[cfg(feature = "snark")]
+    #[test]
+    fn test_setup_and_prove() {
+        use crate::{
+            crypto_hash::PoseidonSponge,
+            snark::varuna::{AHPForR1CS, VarunaHidingMode, VarunaSNARK},
+            traits::{AlgebraicSponge, SNARK},
+        };
+        use snarkvm_curves::bls12_377::{Bls12_377, Fq};
+        use snarkvm_utilities::TestRng;
+
+        type VarunaInst = VarunaSNARK<Bls12_377, PoseidonSponge<Fq, 2, 1>, VarunaHidingMode>;
+
+        let rng = &mut TestRng::default();
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = PoseidonSponge::<Fq, 2, 1>::sample_parameters();
+
+        let r1cs = R1csFile::<Fr>::from_bytes(&sample_r1cs::<Fr>()).unwrap();
+        let witness = WitnessFile::from_bytes(&sample_witness::<Fr>(&[1, 12, 3, 4, 7])).unwrap();
+        let circuit = CircomCircuit::with_witness(r1cs, witness).unwrap();
+        let (_, verifying_key, proof) =
+            circuit.setup_and_prove::<VarunaInst, _>(&universal_srs, universal_prover, &fs_parameters, rng).unwrap();
+
+        let inputs = circuit.public_inputs().unwrap();
+        assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &verifying_key, inputs, &proof).unwrap());
+        let inputs = [Fr::from(13u64)];
+        assert!(!VarunaInst::verify(universal_verifier, &fs_parameters, &verifying_key, inputs, &proof).unwrap());
+
+        // Check that the circom fixture is proven and verified end-to-end.
+        let r1cs = R1csFile::<Fr>::from_bytes(include_bytes!("resources/multiplier.r1cs")).unwrap();
+        let witness = WitnessFile::from_bytes(include_bytes!("resources/multiplier.wtns")).unwrap();
+        let circuit = CircomCircuit::with_witness(r1cs, witness).unwrap();
+        let (_, verifying_key, proof) =
+            circuit.setup_and_prove::<VarunaInst, _>(&universal_srs, universal_prover, &fs_parameters, rng).unwrap();
+
+        let inputs = circuit.public_inputs().unwrap();
+        assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &verifying_key, inputs, &proof).unwrap());
+        let inputs = [Fr::from(60u64), Fr::from(4u64)];
+        assert!(!VarunaInst::verify(universal_verifier, &fs_parameters, &verifying_key, inputs, &proof).unwrap());
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{find_section, read_field_element, read_field_header, read_sections, read_u32, read_u64};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::biginteger::BigInteger;
+
+use anyhow::{ensure, Result};
+
+/// The magic of a `.r1cs` file.
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+/// The version of a `.r1cs` file.
+const R1CS_VERSION: u32 = 1;
+/// The section type of the header.
+const HEADER_SECTION: u32 = 1;
+/// The section type of the constraints.
+const CONSTRAINTS_SECTION: u32 = 2;
+
+/// A linear combination, as pairs of a wire and its coefficient.
+pub type CircomLinearCombination<F> = Vec<(u32, F)>;
+/// A constraint, as linear combinations `(a, b, c)` such that `a * b = c`.
+pub type CircomConstraint<F> = (CircomLinearCombination<F>, CircomLinearCombination<F>, CircomLinearCombination<F>);
+
+/// A constraint system that is compiled by circom, in the `.r1cs` format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct R1csFile<F: PrimeField> {
+    /// The number of wires, including the constant `1`.
+    num_wires: usize,
+    /// The number of public outputs.
+    num_public_outputs: usize,
+    /// The number of public inputs.
+    num_public_inputs: usize,
+    /// The number of private inputs.
+    num_private_inputs: usize,
+    /// The constraints, as linear combinations `(a, b, c)` such that `a * b = c`.
+    constraints: Vec<CircomConstraint<F>>,
+}
+
+impl<F: PrimeField> R1csFile<F> {
+    /// Returns the constraint system from the bytes of a `.r1cs` file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let sections = read_sections(bytes, R1CS_MAGIC, R1CS_VERSION)?;
+
+        // Read the header.
+        let mut header = find_section(&sections, HEADER_SECTION)?;
+        read_field_header::<F>(&mut header)?;
+        let num_wires = read_u32(&mut header)? as usize;
+        let num_public_outputs = read_u32(&mut header)? as usize;
+        let num_public_inputs = read_u32(&mut header)? as usize;
+        let num_private_inputs = read_u32(&mut header)? as usize;
+        let _num_labels = read_u64(&mut header)?;
+        let num_constraints = read_u32(&mut header)? as usize;
+        ensure!(header.is_empty(), "Found {} trailing bytes in the header", header.len());
+        ensure!(
+            num_wires > num_public_outputs + num_public_inputs + num_private_inputs,
+            "The circuit has fewer wires than inputs"
+        );
+
+        // Read the constraints. Note: the number of constraints is not trusted for the allocation.
+        let mut reader = find_section(&sections, CONSTRAINTS_SECTION)?;
+        let mut constraints = Vec::new();
+        for _ in 0..num_constraints {
+            let a = read_linear_combination(&mut reader, num_wires)?;
+            let b = read_linear_combination(&mut reader, num_wires)?;
+            let c = read_linear_combination(&mut reader, num_wires)?;
+            constraints.push((a, b, c));
+        }
+        ensure!(reader.is_empty(), "Found {} trailing bytes in the constraints", reader.len());
+
+        Ok(Self { num_wires, num_public_outputs, num_public_inputs, num_private_inputs, constraints })
+    }
+
+    /// Returns the number of wires, including the constant `1`.
+    pub const fn num_wires(&self) -> usize {
+        self.num_wires
+    }
+
+    /// Returns the number of public wires, which are the public outputs and the public inputs.
+    pub const fn num_public(&self) -> usize {
+        self.num_public_outputs + self.num_public_inputs
+    }
+
+    /// Returns the number of private inputs.
+    pub const fn num_private_inputs(&self) -> usize {
+        self.num_private_inputs
+    }
+
+    /// Returns the constraints, as linear combinations `(a, b, c)` such that `a * b = c`.
+    pub fn constraints(&self) -> &[CircomConstraint<F>] {
+        &self.constraints
+    }
+}
+
+/// Reads a linear combination, and checks that its wires are in range.
+fn read_linear_combination<F: PrimeField>(reader: &mut &[u8], num_wires: usize) -> Result<CircomLinearCombination<F>> {
+    let num_terms = read_u32(reader)? as usize;
+    // Ensure the number of terms is consistent with the remaining bytes, before allocating them.
+    let term_size = 4 + F::BigInteger::NUM_LIMBS * 8;
+    ensure!(num_terms.saturating_mul(term_size) <= reader.len(), "Invalid number of terms ({num_terms})");
+
+    let mut terms = Vec::with_capacity(num_terms);
+    for _ in 0..num_terms {
+        let wire = read_u32(reader)?;
+        ensure!((wire as usize) < num_wires, "Invalid wire {wire}");
+        terms.push((wire, read_field_element(reader)?));
+    }
+    Ok(terms)
+}
@@ -0,0 +1,106 @@
+#!/usr/bin/env python3
+# Copyright (C) 2019-2023 Aleo Systems Inc.
+# This file is part of the snarkVM library.
+
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at:
+# http://www.apache.org/licenses/LICENSE-2.0
+
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+"""
+Writes `multiplier.r1cs` and `multiplier.wtns`, which are the files that circom emits for `multiplier.circom`
+with `circom multiplier.circom --r1cs --wasm --prime bls12377`, and the witness of `a = 3`, `b = 4`, `c = 5`.
+
+The files follow the circom binary formats (https://github.com/iden3/r1csfile/blob/master/doc/r1cs_bin_format.md
+and https://github.com/iden3/snarkjs/blob/master/src/wtns_utils.js). As circom, the constraints are written
+with negated coefficients, i.e. `(-a) * b = (-ab)`.
+"""
+
+import struct
+
+# The scalar field modulus of BLS12-377.
+R = 8444461749428370424248824938781546531375899335154063827935233455917409239041
+N8 = 32
+
+# The wires are [1, d, a, b, c, ab]: the constant, the public output, the public input,
+# the private inputs, and the internal signal.
+NUM_WIRES = 6
+NUM_PUBLIC_OUTPUTS = 1
+NUM_PUBLIC_INPUTS = 1
+NUM_PRIVATE_INPUTS = 2
+D, A, B, C, AB = 1, 2, 3, 4, 5
+
+# The constraints `a * b = ab` and `ab * c = d`.
+CONSTRAINTS = [
+    ([(A, R - 1)], [(B, 1)], [(AB, R - 1)]),
+    ([(AB, R - 1)], [(C, 1)], [(D, R - 1)]),
+]
+
+# The witness of `a = 3`, `b = 4`, `c = 5`.
+WITNESS = [1, 60, 3, 4, 5, 12]
+
+
+def u32(value):
+    return struct.pack("<I", value)
+
+
+def u64(value):
+    return struct.pack("<Q", value)
+
+
+def field(value):
+    assert 0 <= value < R
+    return value.to_bytes(N8, "little")
+
+
+def section(section_type, contents):
+    return u32(section_type) + u64(len(contents)) + contents
+
+
+def file(magic, version, sections):
+    return magic + u32(version) + u32(len(sections)) + b"".join(sections)
+
+
+def linear_combination(terms):
+    return u32(len(terms)) + b"".join(u32(wire) + field(coefficient) for wire, coefficient in terms)
+
+
+def check_witness():
+    for a, b, c in CONSTRAINTS:
+        evaluate = lambda terms: sum(WITNESS[wire] * coefficient for wire, coefficient in terms) % R
+        assert evaluate(a) * evaluate(b) % R == evaluate(c)
+
+
+def main():
+    check_witness()
+
+    header = (
+        u32(N8)
+        + R.to_bytes(N8, "little")
+        + u32(NUM_WIRES)
+        + u32(NUM_PUBLIC_OUTPUTS)
+        + u32(NUM_PUBLIC_INPUTS)
+        + u32(NUM_PRIVATE_INPUTS)
+        + u64(NUM_WIRES)
+        + u32(len(CONSTRAINTS))
+    )
+    constraints = b"".join(
+        linear_combination(a) + linear_combination(b) + linear_combination(c) for a, b, c in CONSTRAINTS
+    )
+    labels = b"".join(u64(label) for label in range(NUM_WIRES))
+    with open("multiplier.r1cs", "wb") as f:
+        f.write(file(b"r1cs", 1, [section(1, header), section(2, constraints), section(3, labels)]))
+
+    header = u32(N8) + R.to_bytes(N8, "little") + u32(len(WITNESS))
+    values = b"".join(field(value) for value in WITNESS)
+    with open("multiplier.wtns", "wb") as f:
+        f.write(file(b"wtns", 2, [section(1, header), section(2, values)]))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,15 @@
+pragma circom 2.0.0;
+
+// Computes `d = a * b * c`, where `a` is a public input.
+template Multiplier3() {
+    signal input a;
+    signal input b;
+    signal input c;
+    signal ab;
+    signal output d;
+
+    ab <== a * b;
+    d <== ab * c;
+}
+
+component main {public [a]} = Multiplier3();
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{find_section, read_field_element, read_field_header, read_sections, read_u32};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::biginteger::BigInteger;
+
+use anyhow::{ensure, Result};
+
+/// The magic of a `.wtns` file.
+const WITNESS_MAGIC: &[u8; 4] = b"wtns";
+/// The version of a `.wtns` file.
+const WITNESS_VERSION: u32 = 2;
+/// The section type of the header.
+const HEADER_SECTION: u32 = 1;
+/// The section type of the values.
+const VALUES_SECTION: u32 = 2;
+
+/// A witness that is computed by circom, in the `.wtns` format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WitnessFile<F: PrimeField> {
+    /// The value of every wire, starting with the constant `1`.
+    values: Vec<F>,
+}
+
+impl<F: PrimeField> WitnessFile<F> {
+    /// Returns the witness from the bytes of a `.wtns` file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let sections = read_sections(bytes, WITNESS_MAGIC, WITNESS_VERSION)?;
+
+        // Read the header.
+        let mut header = find_section(&sections, HEADER_SECTION)?;
+        read_field_header::<F>(&mut header)?;
+        let num_values = read_u32(&mut header)? as usize;
+        ensure!(header.is_empty(), "Found {} trailing bytes in the header", header.len());
+
+        // Read the values.
+        let mut reader = find_section(&sections, VALUES_SECTION)?;
+        ensure!(
+            Some(reader.len()) == num_values.checked_mul(F::BigInteger::NUM_LIMBS * 8),
+            "The witness section does not contain {num_values} values"
+        );
+        let values = (0..num_values).map(|_| read_field_element(&mut reader)).collect::<Result<_>>()?;
+        Ok(Self { values })
+    }
+
+    /// Returns the value of every wire, starting with the constant `1`.
+    pub fn values(&self) -> &[F] {
+        &self.values
+    }
+
+    /// Returns the value of every wire, starting with the constant `1`.
+    pub fn into_values(self) -> Vec<F> {
+        self.values
+    }
+}
@@ -15,6 +15,8 @@
 mod assignment;
 pub use assignment::*;
 
+pub mod circom;
+
 mod constraint_counter;
 pub use constraint_counter::*;
 
@@ -0,0 +1,422 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{Proof, VerifyingKey};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use snarkvm_curves::{
+    bls12_377::{Bls12_377G1Parameters, Bls12_377G2Parameters, Fq, Fq2},
+    templates::short_weierstrass_jacobian::Affine,
+    traits::AffineCurve,
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::biginteger::BigInteger384;
+
+// Note: The points are named by their concrete curve parameters, rather than through `Bls12_377`,
+// as otherwise the `Point` impls for G1 and G2 are rejected as overlapping.
+type G1Affine = Affine<Bls12_377G1Parameters>;
+type G2Affine = Affine<Bls12_377G2Parameters>;
+
+/// The size of a base field element, in bytes.
+const FQ_SIZE: usize = 48;
+/// The mask of the flags in the first byte of an encoded point.
+const FLAGS_MASK: u8 = 0b111 << 5;
+
+/// The byte layout of Groth16 proofs and verifying keys in another library.
+///
+/// In both layouts, coordinates are big-endian, the imaginary component of an `Fq2` element
+/// precedes the real component, and the flags of a point are in the three most significant bits
+/// of its first byte.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// The layout of gnark, in which the flags are a 3-bit tag (`0b000` uncompressed, `0b010` uncompressed
+    /// infinity, `0b100` compressed with the smallest y, `0b101` compressed with the largest y, and `0b110`
+    /// compressed infinity), and an `Fq2` element is compared on its real component first.
+    ///
+    /// Proofs are the compressed points `A || B || C`, and verifying keys are the compressed points
+    /// `alpha_g1 || beta_g1 || beta_g2 || gamma_g2 || delta_g1 || delta_g2`, followed by the big-endian `u32`
+    /// number of input bases and the compressed input bases. The commitment extension of gnark is not supported.
+    Gnark,
+    /// The layout of bellman (i.e. the zcash serialization), in which the flags are the bits
+    /// `compressed`, `infinity`, and `largest y`, and an `Fq2` element is compared on its imaginary component first.
+    ///
+    /// Proofs are the compressed points `A || B || C`, and verifying keys are the uncompressed points
+    /// `alpha_g1 || beta_g1 || beta_g2 || gamma_g2 || delta_g1 || delta_g2`, followed by the big-endian `u32`
+    /// number of input bases and the uncompressed input bases.
+    Bellman,
+}
+
+impl Layout {
+    /// Returns `true` if the points of verifying keys are compressed in this layout.
+    const fn compresses_verifying_keys(&self) -> bool {
+        match self {
+            Self::Gnark => true,
+            Self::Bellman => false,
+        }
+    }
+
+    /// Returns the flags of a point with the given properties.
+    const fn encode_flags(&self, is_compressed: bool, is_infinity: bool, is_largest: bool) -> u8 {
+        match self {
+            Self::Gnark => match (is_compressed, is_infinity, is_largest) {
+                (false, false, _) => 0b000 << 5,
+                (false, true, _) => 0b010 << 5,
+                (true, false, false) => 0b100 << 5,
+                (true, false, true) => 0b101 << 5,
+                (true, true, _) => 0b110 << 5,
+            },
+            Self::Bellman => {
+                let is_largest = is_compressed && !is_infinity && is_largest;
+                ((is_compressed as u8) << 7) | ((is_infinity as u8) << 6) | ((is_largest as u8) << 5)
+            }
+        }
+    }
+
+    /// Returns the properties `(is_compressed, is_infinity, is_largest)` of the given flags.
+    fn decode_flags(&self, flags: u8) -> Result<(bool, bool, bool)> {
+        match self {
+            Self::Gnark => match flags >> 5 {
+                0b000 => Ok((false, false, false)),
+                0b010 => Ok((false, true, false)),
+                0b100 => Ok((true, false, false)),
+                0b101 => Ok((true, false, true)),
+                0b110 => Ok((true, true, false)),
+                _ => bail!("Invalid gnark point flags ({flags:#010b})"),
+            },
+            Self::Bellman => {
+                let is_compressed = flags & 0x80 != 0;
+                let is_infinity = flags & 0x40 != 0;
+                let is_largest = flags & 0x20 != 0;
+                ensure!(!is_largest || (is_compressed && !is_infinity), "Invalid bellman point flags ({flags:#010b})");
+                Ok((is_compressed, is_infinity, is_largest))
+            }
+        }
+    }
+
+    /// Returns the proof in this layout.
+    pub fn write_proof(&self, proof: &Proof) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 * FQ_SIZE);
+        self.write_point(&proof.a, true, &mut bytes);
+        self.write_point(&proof.b, true, &mut bytes);
+        self.write_point(&proof.c, true, &mut bytes);
+        bytes
+    }
+
+    /// Returns the proof from its bytes in this layout.
+    pub fn read_proof(&self, bytes: &[u8]) -> Result<Proof> {
+        let mut reader = bytes;
+        let a = self.read_point(&mut reader, true)?;
+        let b = self.read_point(&mut reader, true)?;
+        let c = self.read_point(&mut reader, true)?;
+        let proof = Proof { a, b, c };
+        ensure!(reader.is_empty(), "Found {} trailing bytes after the proof", reader.len());
+        Ok(proof)
+    }
+
+    /// Returns the verifying key in this layout.
+    pub fn write_verifying_key(&self, verifying_key: &VerifyingKey) -> Result<Vec<u8>> {
+        let compressed = self.compresses_verifying_keys();
+        let mut bytes = Vec::new();
+        self.write_point(&verifying_key.alpha_g1, compressed, &mut bytes);
+        self.write_point(&verifying_key.beta_g1, compressed, &mut bytes);
+        self.write_point(&verifying_key.beta_g2, compressed, &mut bytes);
+        self.write_point(&verifying_key.gamma_g2, compressed, &mut bytes);
+        self.write_point(&verifying_key.delta_g1, compressed, &mut bytes);
+        self.write_point(&verifying_key.delta_g2, compressed, &mut bytes);
+        let num_bases = u32::try_from(verifying_key.gamma_abc_g1.len())?;
+        bytes.extend_from_slice(&num_bases.to_be_bytes());
+        for base in &verifying_key.gamma_abc_g1 {
+            self.write_point(base, compressed, &mut bytes);
+        }
+        Ok(bytes)
+    }
+
+    /// Returns the verifying key from its bytes in this layout.
+    pub fn read_verifying_key(&self, bytes: &[u8]) -> Result<VerifyingKey> {
+        let compressed = self.compresses_verifying_keys();
+        let mut reader = bytes;
+        let alpha_g1 = self.read_point(&mut reader, compressed)?;
+        let beta_g1 = self.read_point(&mut reader, compressed)?;
+        let beta_g2 = self.read_point(&mut reader, compressed)?;
+        let gamma_g2 = self.read_point(&mut reader, compressed)?;
+        let delta_g1 = self.read_point(&mut reader, compressed)?;
+        let delta_g2 = self.read_point(&mut reader, compressed)?;
+        let num_bases = u32::from_be_bytes(take::<4>(&mut reader)?) as usize;
+        // Ensure the number of bases is consistent with the remaining bytes, before allocating them.
+        let base_size = <G1Affine as Point>::encoded_size(compressed);
+        ensure!(num_bases.checked_mul(base_size) == Some(reader.len()), "Invalid number of input bases ({num_bases})");
+        let gamma_abc_g1 = (0..num_bases).map(|_| self.read_point(&mut reader, compressed)).collect::<Result<_>>()?;
+        Ok(VerifyingKey { alpha_g1, beta_g1, beta_g2, gamma_g2, delta_g1, delta_g2, gamma_abc_g1 })
+    }
+
+    /// Writes the given point in this layout.
+    fn write_point<G: Point>(&self, point: &G, compressed: bool, bytes: &mut Vec<u8>) {
+        let start = bytes.len();
+        let is_infinity = point.is_zero();
+        let (x, y) = match is_infinity {
+            true => (G::BaseField::zero(), G::BaseField::zero()),
+            false => (point.to_x_coordinate(), point.to_y_coordinate()),
+        };
+        G::write_coordinate(&x, bytes);
+        if !compressed {
+            G::write_coordinate(&y, bytes);
+        }
+        let is_largest = !is_infinity && G::is_largest(self, &y);
+        bytes[start] |= self.encode_flags(compressed, is_infinity, is_largest);
+    }
+
+    /// Reads a point in this layout, and checks that it is on the curve and in the prime-order subgroup.
+    fn read_point<G: Point>(&self, reader: &mut &[u8], compressed: bool) -> Result<G> {
+        let size = G::encoded_size(compressed);
+        ensure!(reader.len() >= size, "Expected {size} bytes for a point, found {}", reader.len());
+        let mut bytes = reader[..size].to_vec();
+        *reader = &reader[size..];
+
+        // Recover the flags.
+        let (is_compressed, is_infinity, is_largest) = self.decode_flags(bytes[0] & FLAGS_MASK)?;
+        match compressed {
+            true => ensure!(is_compressed, "Expected a compressed point"),
+            false => ensure!(!is_compressed, "Expected an uncompressed point"),
+        }
+        bytes[0] &= !FLAGS_MASK;
+        if is_infinity {
+            ensure!(bytes.iter().all(|byte| *byte == 0), "Invalid encoding of the point at infinity");
+            return Ok(G::zero());
+        }
+
+        // Recover the point.
+        let x = G::read_coordinate(&bytes[..G::COORDINATE_SIZE])?;
+        let point = match compressed {
+            true => {
+                let point = G::from_x_coordinate(x, false).ok_or_else(|| anyhow!("Invalid x-coordinate"))?;
+                match G::is_largest(self, &point.to_y_coordinate()) == is_largest {
+                    true => point,
+                    false => -point,
+                }
+            }
+            false => G::from_xy(x, G::read_coordinate(&bytes[G::COORDINATE_SIZE..])?),
+        };
+        ensure!(
+            point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve(),
+            "The point is not in the prime-order subgroup"
+        );
+        Ok(point)
+    }
+}
+
+/// A curve point that can be encoded in a `Layout`.
+trait Point: AffineCurve {
+    /// The size of a coordinate, in bytes.
+    const COORDINATE_SIZE: usize;
+
+    /// Writes the given coordinate in big-endian order.
+    fn write_coordinate(coordinate: &Self::BaseField, bytes: &mut Vec<u8>);
+
+    /// Reads a coordinate in big-endian order.
+    fn read_coordinate(bytes: &[u8]) -> Result<Self::BaseField>;
+
+    /// Returns `true` if the given y-coordinate is larger than its negation, as compared in the given layout.
+    fn is_largest(layout: &Layout, y: &Self::BaseField) -> bool;
+
+    /// Returns the point with the given coordinates, which is not checked to be on the curve.
+    fn from_xy(x: Self::BaseField, y: Self::BaseField) -> Self;
+
+    /// Returns the size of an encoded point, in bytes.
+    fn encoded_size(compressed: bool) -> usize {
+        match compressed {
+            true => Self::COORDINATE_SIZE,
+            false => 2 * Self::COORDINATE_SIZE,
+        }
+    }
+}
+
+impl Point for G1Affine {
+    const COORDINATE_SIZE: usize = FQ_SIZE;
+
+    fn write_coordinate(coordinate: &Fq, bytes: &mut Vec<u8>) {
+        write_fq(coordinate, bytes);
+    }
+
+    fn read_coordinate(bytes: &[u8]) -> Result<Fq> {
+        read_fq(bytes)
+    }
+
+    fn is_largest(_layout: &Layout, y: &Fq) -> bool {
+        *y > -*y
+    }
+
+    fn from_xy(x: Fq, y: Fq) -> Self {
+        Self::new(x, y, false)
+    }
+}
+
+impl Point for G2Affine {
+    const COORDINATE_SIZE: usize = 2 * FQ_SIZE;
+
+    fn write_coordinate(coordinate: &Fq2, bytes: &mut Vec<u8>) {
+        write_fq(&coordinate.c1, bytes);
+        write_fq(&coordinate.c0, bytes);
+    }
+
+    fn read_coordinate(bytes: &[u8]) -> Result<Fq2> {
+        Ok(Fq2::new(read_fq(&bytes[FQ_SIZE..])?, read_fq(&bytes[..FQ_SIZE])?))
+    }
+
+    fn is_largest(layout: &Layout, y: &Fq2) -> bool {
+        // Compare on the first nonzero component, in the order of the layout.
+        let (first, second) = match layout {
+            Layout::Gnark => (y.c0, y.c1),
+            Layout::Bellman => (y.c1, y.c0),
+        };
+        match first.is_zero() {
+            true => second > -second,
+            false => first > -first,
+        }
+    }
+
+    fn from_xy(x: Fq2, y: Fq2) -> Self {
+        Self::new(x, y, false)
+    }
+}
+
+/// Writes the given base field element in big-endian order.
+fn write_fq(element: &Fq, bytes: &mut Vec<u8>) {
+    for limb in element.to_bigint().0.iter().rev() {
+        bytes.extend_from_slice(&limb.to_be_bytes());
+    }
+}
+
+/// Reads a base field element in big-endian order, and checks that it is reduced.
+fn read_fq(bytes: &[u8]) -> Result<Fq> {
+    ensure!(bytes.len() == FQ_SIZE, "Expected {FQ_SIZE} bytes for a base field element");
+    let mut limbs = [0u64; 6];
+    for (limb, chunk) in limbs.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into()?);
+    }
+    Fq::from_bigint(BigInteger384::new(limbs)).ok_or_else(|| anyhow!("The base field element is not reduced"))
+}
+
+/// Returns the next `N` bytes of the reader.
+fn take<const N: usize>(reader: &mut &[u8]) -> Result<[u8; N]> {
+    ensure!(reader.len() >= N, "Expected {N} bytes, found {}", reader.len());
+    let (bytes, rest) = reader.split_at(N);
+    *reader = rest;
+    Ok(bytes.try_into()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snark::groth16::test_helpers::sample_proof;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::TestRng;
+
+    use std::str::FromStr;
+
+    const LAYOUTS: [Layout; 2] = [Layout::Gnark, Layout::Bellman];
+
+    /// Returns the public inputs of the fixtures in `resources`, which are generated by `resources/generate.py`.
+    fn fixture_inputs() -> Vec<Fr> {
+        include_str!("resources/public_inputs.txt").lines().map(|line| Fr::from_str(line).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_fixtures() {
+        let inputs = fixture_inputs();
+        let fixtures = [
+            (
+                Layout::Gnark,
+                &include_bytes!("resources/gnark_verifying_key.bin")[..],
+                &include_bytes!("resources/gnark_proof.bin")[..],
+            ),
+            (
+                Layout::Bellman,
+                &include_bytes!("resources/bellman_verifying_key.bin")[..],
+                &include_bytes!("resources/bellman_proof.bin")[..],
+            ),
+        ];
+
+        for (layout, verifying_key_bytes, proof_bytes) in fixtures {
+            // Check that the fixture verifies.
+            let verifying_key = layout.read_verifying_key(verifying_key_bytes).unwrap();
+            let proof = layout.read_proof(proof_bytes).unwrap();
+            assert!(verifying_key.verify(&inputs, &proof).unwrap());
+            assert!(!verifying_key.verify(&[inputs[1], inputs[0]], &proof).unwrap());
+
+            // Check that the fixture is re-encoded byte-for-byte in both layouts.
+            for (other, other_verifying_key_bytes, other_proof_bytes) in fixtures {
+                assert_eq!(other.write_verifying_key(&verifying_key).unwrap(), other_verifying_key_bytes);
+                assert_eq!(other.write_proof(&proof), other_proof_bytes);
+            }
+        }
+    }
+
+    #[test]
+    fn test_proof_roundtrip() {
+        let rng = &mut TestRng::default();
+        for layout in LAYOUTS {
+            for _ in 0..10 {
+                let (verifying_key, proof, inputs) = sample_proof(2, rng);
+                let bytes = layout.write_proof(&proof);
+                assert_eq!(bytes.len(), 4 * FQ_SIZE);
+                let candidate = layout.read_proof(&bytes).unwrap();
+                assert_eq!(proof, candidate);
+                assert!(verifying_key.verify(&inputs, &candidate).unwrap());
+
+                // Check that trailing bytes are rejected.
+                assert!(layout.read_proof(&[&bytes[..], &[0]].concat()).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_verifying_key_roundtrip() {
+        let rng = &mut TestRng::default();
+        for layout in LAYOUTS {
+            let (verifying_key, ..) = sample_proof(3, rng);
+            let bytes = layout.write_verifying_key(&verifying_key).unwrap();
+            assert_eq!(verifying_key, layout.read_verifying_key(&bytes).unwrap());
+
+            // Check that a truncated key is rejected.
+            assert!(layout.read_verifying_key(&bytes[..bytes.len() - 1]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_point_encoding() {
+        let generator = G1Affine::prime_subgroup_generator();
+        for layout in LAYOUTS {
+            // Check the flags of the point at infinity, and of a point and its negation.
+            let mut bytes = vec![];
+            layout.write_point(&G1Affine::zero(), true, &mut bytes);
+            layout.write_point(&generator, true, &mut bytes);
+            layout.write_point(&-generator, true, &mut bytes);
+            let flags: Vec<_> = bytes.chunks(FQ_SIZE).map(|chunk| chunk[0] & FLAGS_MASK).collect();
+            // Note: both layouts happen to tag the compressed point at infinity with `0b110`.
+            assert_eq!(flags[0], 0b110 << 5);
+            assert_ne!(flags[1], flags[2]);
+
+            let mut reader = &bytes[..];
+            assert!(layout.read_point::<G1Affine>(&mut reader, true).unwrap().is_zero());
+            assert_eq!(layout.read_point::<G1Affine>(&mut reader, true).unwrap(), generator);
+            assert_eq!(layout.read_point::<G1Affine>(&mut reader, true).unwrap(), -generator);
+
+            // Check that an uncompressed point is rejected where a compressed point is expected.
+            let mut bytes = vec![];
+            layout.write_point(&generator, false, &mut bytes);
+            assert!(layout.read_point::<G1Affine>(&mut &bytes[..], true).is_err());
+            assert_eq!(layout.read_point::<G1Affine>(&mut &bytes[..], false).unwrap(), generator);
+        }
+    }
+}
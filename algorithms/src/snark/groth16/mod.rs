@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Groth16 proofs and verifying keys over BLS12-377, for interoperability with other proving stacks.
+//!
+//! snarkVM does not produce Groth16 proofs. Instead, this module verifies the proofs that are
+//! produced by other libraries, and converts proofs and verifying keys between the byte layouts
+//! of those libraries (see [`Layout`]).
+
+mod encoding;
+pub use encoding::*;
+
+use anyhow::{ensure, Result};
+use snarkvm_curves::{
+    bls12_377::{Bls12_377, Fr, G1Affine, G2Affine},
+    traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve},
+};
+use snarkvm_fields::One;
+
+/// A Groth16 proof.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub a: G1Affine,
+    pub b: G2Affine,
+    pub c: G1Affine,
+}
+
+/// A Groth16 verifying key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey {
+    pub alpha_g1: G1Affine,
+    pub beta_g1: G1Affine,
+    pub beta_g2: G2Affine,
+    pub gamma_g2: G2Affine,
+    pub delta_g1: G1Affine,
+    pub delta_g2: G2Affine,
+    /// The bases of the public inputs, where the first base is for the constant `1`.
+    pub gamma_abc_g1: Vec<G1Affine>,
+}
+
+impl VerifyingKey {
+    /// Returns `true` if the proof is valid for the given public inputs,
+    /// which exclude the constant `1` that is implicitly the first input.
+    pub fn verify(&self, public_inputs: &[Fr], proof: &Proof) -> Result<bool> {
+        ensure!(
+            public_inputs.len() + 1 == self.gamma_abc_g1.len(),
+            "Expected {} public inputs, found {}",
+            self.gamma_abc_g1.len().saturating_sub(1),
+            public_inputs.len()
+        );
+        // Compute the linear combination of the public inputs.
+        let mut inputs = self.gamma_abc_g1[0].to_projective();
+        for (base, input) in self.gamma_abc_g1[1..].iter().zip(public_inputs) {
+            inputs += *base * *input;
+        }
+        let inputs = inputs.to_affine();
+
+        // Check that e(A, B) = e(alpha, beta) * e(inputs, gamma) * e(C, delta).
+        let g1 = [proof.a.prepare(), (-self.alpha_g1).prepare(), (-inputs).prepare(), (-proof.c).prepare()];
+        let g2 = [proof.b.prepare(), self.beta_g2.prepare(), self.gamma_g2.prepare(), self.delta_g2.prepare()];
+        Ok(Bls12_377::product_of_pairings(g1.iter().zip(g2.iter())).is_one())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use snarkvm_curves::bls12_377::G1Projective;
+    use snarkvm_fields::Field;
+    use snarkvm_utilities::Uniform;
+
+    use rand::Rng;
+
+    /// Returns a verifying key, a valid proof, and the public inputs, which are simulated with the trapdoor of the key.
+    pub(crate) fn sample_proof<R: Rng>(num_inputs: usize, rng: &mut R) -> (VerifyingKey, Proof, Vec<Fr>) {
+        let (g1, g2) = (G1Projective::prime_subgroup_generator(), G2Affine::prime_subgroup_generator().to_projective());
+        let (alpha, beta, gamma, delta) = (Fr::rand(rng), Fr::rand(rng), Fr::rand(rng), Fr::rand(rng));
+        let gamma_abc: Vec<Fr> = (0..=num_inputs).map(|_| Fr::rand(rng)).collect();
+        let public_inputs: Vec<Fr> = (0..num_inputs).map(|_| Fr::rand(rng)).collect();
+
+        let verifying_key = VerifyingKey {
+            alpha_g1: (g1 * alpha).to_affine(),
+            beta_g1: (g1 * beta).to_affine(),
+            beta_g2: (g2 * beta).to_affine(),
+            gamma_g2: (g2 * gamma).to_affine(),
+            delta_g1: (g1 * delta).to_affine(),
+            delta_g2: (g2 * delta).to_affine(),
+            gamma_abc_g1: gamma_abc.iter().map(|scalar| (g1 * *scalar).to_affine()).collect(),
+        };
+
+        // Select A and B, and solve a * b = alpha * beta + inputs * gamma + c * delta for C.
+        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+        let inputs = gamma_abc[0] + gamma_abc[1..].iter().zip(&public_inputs).map(|(x, y)| *x * *y).sum::<Fr>();
+        let c = (a * b - alpha * beta - inputs * gamma) * delta.inverse().unwrap();
+        let proof = Proof { a: (g1 * a).to_affine(), b: (g2 * b).to_affine(), c: (g1 * c).to_affine() };
+        (verifying_key, proof, public_inputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{test_helpers::sample_proof, *};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_verify() {
+        let rng = &mut TestRng::default();
+        let (verifying_key, proof, inputs) = sample_proof(3, rng);
+        assert!(verifying_key.verify(&inputs, &proof).unwrap());
+
+        // Check that an incorrect input, an incorrect proof, or a wrong number of inputs is rejected.
+        let mut wrong_inputs = inputs.clone();
+        wrong_inputs[1] = Fr::rand(rng);
+        assert!(!verifying_key.verify(&wrong_inputs, &proof).unwrap());
+        let wrong_proof = Proof { c: proof.a, ..proof };
+        assert!(!verifying_key.verify(&inputs, &wrong_proof).unwrap());
+        assert!(verifying_key.verify(&inputs[1..], &proof).is_err());
+    }
+}
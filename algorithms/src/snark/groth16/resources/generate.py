@@ -0,0 +1,191 @@
+#!/usr/bin/env python3
+# Copyright (C) 2019-2023 Aleo Systems Inc.
+# This file is part of the snarkVM library.
+
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at:
+# http://www.apache.org/licenses/LICENSE-2.0
+
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+"""Generates the Groth16 fixtures over BLS12-377 in the gnark and bellman byte layouts.
+
+The verifying key and proof are simulated with the trapdoor of the key, using an arithmetic
+implementation that is independent of snarkVM, so the fixtures check the encoding end-to-end.
+Usage: python3 generate.py (writes the fixtures next to this script).
+"""
+
+import os
+import random
+
+Q = 0x01AE3A4617C510EAC63B05C06CA1493B1A22D9F300F5138F1EF3622FBA094800170B5D44300000008508C00000000001
+R = 0x12AB655E9A2CA55660B44D1E5C37B00159AA76FED00000010A11800000000001
+NONRESIDUE = Q - 5
+
+G1 = (
+    89363714989903307245735717098563574705733591463163614225748337416674727625843187853442697973404985688481508350822,
+    3702177272937190650578065972808860481433820514072818216637796320125658674906330993856598323293086021583822603349,
+)
+G2 = (
+    (
+        170590608266080109581922461902299092015242589883741236963254737235977648828052995125541529645051927918098146183295,
+        83407003718128594709087171351153471074446327721872642659202721143408712182996929763094113874399921859453255070254,
+    ),
+    (
+        1843833842842620867708835993770650838640642469700861403869757682057607397502738488921663703124647238454792872005,
+        33145532013610981697337930729788870077912093258611421158732879580766461459275194744385880708057348608045241477209,
+    ),
+)
+
+
+class Fq:
+    def __init__(self, value):
+        self.value = value % Q
+
+    def __add__(self, other):
+        return Fq(self.value + other.value)
+
+    def __sub__(self, other):
+        return Fq(self.value - other.value)
+
+    def __mul__(self, other):
+        return Fq(self.value * other.value)
+
+    def __neg__(self):
+        return Fq(-self.value)
+
+    def __eq__(self, other):
+        return self.value == other.value
+
+    def inverse(self):
+        return Fq(pow(self.value, Q - 2, Q))
+
+    def is_zero(self):
+        return self.value == 0
+
+    def is_largest(self):
+        return self.value > (Q - 1) // 2
+
+
+class Fq2:
+    def __init__(self, c0, c1):
+        self.c0, self.c1 = c0 if isinstance(c0, Fq) else Fq(c0), c1 if isinstance(c1, Fq) else Fq(c1)
+
+    def __add__(self, other):
+        return Fq2(self.c0 + other.c0, self.c1 + other.c1)
+
+    def __sub__(self, other):
+        return Fq2(self.c0 - other.c0, self.c1 - other.c1)
+
+    def __mul__(self, other):
+        c0 = self.c0 * other.c0 + Fq(NONRESIDUE) * self.c1 * other.c1
+        c1 = self.c0 * other.c1 + self.c1 * other.c0
+        return Fq2(c0, c1)
+
+    def __neg__(self):
+        return Fq2(-self.c0, -self.c1)
+
+    def __eq__(self, other):
+        return self.c0 == other.c0 and self.c1 == other.c1
+
+    def inverse(self):
+        norm = (self.c0 * self.c0 - Fq(NONRESIDUE) * self.c1 * self.c1).inverse()
+        return Fq2(self.c0 * norm, -(self.c1 * norm))
+
+    def is_zero(self):
+        return self.c0.is_zero() and self.c1.is_zero()
+
+
+def add(p, q):
+    """Adds two affine points, where `None` is the point at infinity."""
+    if p is None:
+        return q
+    if q is None:
+        return p
+    (x1, y1), (x2, y2) = p, q
+    if x1 == x2:
+        if (y1 + y2).is_zero():
+            return None
+        slope = (x1 * x1 + x1 * x1 + x1 * x1) * (y1 + y1).inverse()
+    else:
+        slope = (y2 - y1) * (x2 - x1).inverse()
+    x3 = slope * slope - x1 - x2
+    return (x3, slope * (x1 - x3) - y1)
+
+
+def mul(p, scalar):
+    result = None
+    for bit in bin(scalar)[2:]:
+        result = add(result, result)
+        if bit == "1":
+            result = add(result, p)
+    return result
+
+
+def be(element):
+    return element.value.to_bytes(48, "big")
+
+
+def encode(point, layout, compressed):
+    """Encodes the given point in the given layout, as documented in `encoding.rs`."""
+    x, y = point
+    if isinstance(x, Fq2):
+        x_bytes, y_bytes = be(x.c1) + be(x.c0), be(y.c1) + be(y.c0)
+        first, second = (y.c0, y.c1) if layout == "gnark" else (y.c1, y.c0)
+        is_largest = second.is_largest() if first.is_zero() else first.is_largest()
+    else:
+        x_bytes, y_bytes = be(x), be(y)
+        is_largest = y.is_largest()
+    data = bytearray(x_bytes if compressed else x_bytes + y_bytes)
+    if layout == "gnark":
+        flags = (0b101 if is_largest else 0b100) if compressed else 0b000
+    else:
+        flags = (0b100 | (0b001 if is_largest else 0)) if compressed else 0b000
+    data[0] |= flags << 5
+    return bytes(data)
+
+
+def main():
+    rng = random.Random(1234)
+    g1 = (Fq(G1[0]), Fq(G1[1]))
+    g2 = (Fq2(*G2[0]), Fq2(*G2[1]))
+
+    # Check the generators are on their curves, and in the prime-order subgroup.
+    assert g1[1] * g1[1] == g1[0] * g1[0] * g1[0] + Fq(1)
+    b2 = g2[1] * g2[1] - g2[0] * g2[0] * g2[0]
+    assert b2.c0.is_zero()
+    assert mul(g1, R) is None and mul(g2, R) is None
+
+    alpha, beta, gamma, delta = (rng.randrange(1, R) for _ in range(4))
+    gamma_abc = [rng.randrange(1, R) for _ in range(3)]
+    inputs = [rng.randrange(R) for _ in range(2)]
+
+    # Select A and B, and solve a * b = alpha * beta + inputs * gamma + c * delta for C.
+    a, b = rng.randrange(1, R), rng.randrange(1, R)
+    combined = (gamma_abc[0] + sum(x * y for x, y in zip(gamma_abc[1:], inputs))) % R
+    c = (a * b - alpha * beta - combined * gamma) * pow(delta, R - 2, R) % R
+
+    vk_points = [mul(g1, alpha), mul(g1, beta), mul(g2, beta), mul(g2, gamma), mul(g1, delta), mul(g2, delta)]
+    bases = [mul(g1, scalar) for scalar in gamma_abc]
+    proof = [mul(g1, a), mul(g2, b), mul(g1, c)]
+
+    directory = os.path.dirname(os.path.abspath(__file__))
+    for layout, compress_vk in [("gnark", True), ("bellman", False)]:
+        verifying_key = b"".join(encode(point, layout, compress_vk) for point in vk_points)
+        verifying_key += len(bases).to_bytes(4, "big")
+        verifying_key += b"".join(encode(point, layout, compress_vk) for point in bases)
+        with open(os.path.join(directory, f"{layout}_verifying_key.bin"), "wb") as file:
+            file.write(verifying_key)
+        with open(os.path.join(directory, f"{layout}_proof.bin"), "wb") as file:
+            file.write(b"".join(encode(point, layout, True) for point in proof))
+    with open(os.path.join(directory, "public_inputs.txt"), "w") as file:
+        file.write("".join(f"{x}\n" for x in inputs))
+
+
+if __name__ == "__main__":
+    main()
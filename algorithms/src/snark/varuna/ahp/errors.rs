@@ -22,7 +22,7 @@ pub enum AHPError {
     BatchSizeIsZero,
 
     #[error("An error occurred during constraint generation.")]
-    ConstraintSystemError(crate::r1cs::errors::SynthesisError),
+    ConstraintSystemError(#[source] crate::r1cs::errors::SynthesisError),
 
     #[error("The instance generated during proving does not match that in the index.")]
     InstanceDoesNotMatchIndex,
@@ -18,10 +18,16 @@ use snarkvm_utilities::{
     error,
     io::{self, Read, Write},
     serialize::*,
+    string::String,
     FromBytes,
+    FromBytesDeserializer,
     ToBytes,
+    ToBytesSerializer,
 };
 
+use core::{fmt, str::FromStr};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
 /// A certificate for the verifying key.
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Certificate<E: PairingEngine> {
@@ -47,3 +53,43 @@ impl<E: PairingEngine> FromBytes for Certificate<E> {
         Self::deserialize_compressed(&mut r).map_err(|_| error("Failed to deserialize certificate"))
     }
 }
+
+impl<E: PairingEngine> FromStr for Certificate<E> {
+    type Err = anyhow::Error;
+
+    #[inline]
+    fn from_str(certificate_hex: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes_le(&hex::decode(certificate_hex)?)
+    }
+}
+
+impl<E: PairingEngine> fmt::Display for Certificate<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let certificate_hex = hex::encode(self.to_bytes_le().expect("Failed to convert certificate to bytes"));
+        write!(f, "{certificate_hex}")
+    }
+}
+
+impl<E: PairingEngine> Serialize for Certificate<E> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, E: PairingEngine> Deserialize<'de> for Certificate<E> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let s: String = Deserialize::deserialize(deserializer)?;
+                FromStr::from_str(&s).map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "certificate"),
+        }
+    }
+}
@@ -25,10 +25,15 @@ use snarkvm_utilities::{
     error,
     io::{self, Read, Write},
     serialize::*,
+    string::String,
     FromBytes,
+    FromBytesDeserializer,
     ToBytes,
+    ToBytesSerializer,
 };
 
+use core::{fmt, str::FromStr};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
@@ -88,7 +93,13 @@ impl<E: PairingEngine> Commitments<E> {
         compress: Compress,
         validate: Validate,
     ) -> Result<Self, snarkvm_utilities::SerializationError> {
-        let mut w = Vec::with_capacity(batch_sizes.iter().sum());
+        // Ensure the total number of instances is within the deserialization limits.
+        let num_instances = batch_sizes
+            .iter()
+            .try_fold(0usize, |total, batch_size| total.checked_add(*batch_size))
+            .ok_or(snarkvm_utilities::SerializationError::InvalidData)?;
+        let num_instances = DeserializationLimits::check_num_elements(num_instances as u64)?;
+        let mut w = Vec::with_capacity(bounded_capacity::<WitnessCommitments<E>>(num_instances));
         for batch_size in batch_sizes {
             w.extend(deserialize_vec_without_len(&mut reader, compress, validate, *batch_size)?);
         }
@@ -370,6 +381,46 @@ impl<E: PairingEngine> FromBytes for Proof<E> {
     }
 }
 
+impl<E: PairingEngine> FromStr for Proof<E> {
+    type Err = anyhow::Error;
+
+    #[inline]
+    fn from_str(proof_hex: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes_le(&hex::decode(proof_hex)?)
+    }
+}
+
+impl<E: PairingEngine> fmt::Display for Proof<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let proof_hex = hex::encode(self.to_bytes_le().expect("Failed to convert proof to bytes"));
+        write!(f, "{proof_hex}")
+    }
+}
+
+impl<E: PairingEngine> Serialize for Proof<E> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, E: PairingEngine> Deserialize<'de> for Proof<E> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let s: String = Deserialize::deserialize(deserializer)?;
+                FromStr::from_str(&s).map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proof"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![allow(non_camel_case_types)]
@@ -506,4 +557,55 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_deserializing_proof_with_limits() {
+        let rng = &mut TestRng::default();
+
+        let commitments = rand_commitments(2, 3, false);
+        let evaluations: Evaluations<Fr> = rand_evaluations(rng, 3);
+        let third_msg = ThirdMessage::<Fr> { sums: vec![vec![rand_sums(rng); 2]; 3] };
+        let fourth_msg = FourthMessage::<Fr> { sums: vec![rand_sums(rng); 3] };
+        let pc_proof = sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false); 2]) };
+        let expected = Proof { batch_sizes: vec![2; 3], commitments, evaluations, third_msg, fourth_msg, pc_proof };
+        let bytes = expected.to_bytes_le().unwrap();
+
+        // Check that the proof is deserialized within limits that fit it.
+        let limits = DeserializationLimits::new(6, bytes.len());
+        assert_eq!(expected, Proof::read_le_with_limits(&bytes[..], limits).unwrap());
+
+        // Check that the proof is rejected if it has too many instances, or too many bytes.
+        for limits in [DeserializationLimits::new(5, bytes.len()), DeserializationLimits::new(6, bytes.len() - 1)] {
+            assert!(Proof::<Bls12_377>::read_le_with_limits(&bytes[..], limits).is_err());
+        }
+
+        // Check that huge batch sizes are rejected, without allocating for them.
+        for batch_sizes in [vec![1u64 << 40], vec![u64::MAX, 1]] {
+            let mut bytes = vec![];
+            batch_sizes.serialize_compressed(&mut bytes).unwrap();
+            assert!(Proof::<Bls12_377>::read_le(&bytes[..]).is_err());
+            assert!(Proof::<Bls12_377>::read_le_with_limits(&bytes[..], DeserializationLimits::NETWORK).is_err());
+        }
+    }
+
+    #[test]
+    fn test_serde_proof() {
+        let rng = &mut snarkvm_utilities::rand::TestRng::default();
+
+        let commitments = rand_commitments(2, 3, false);
+        let evaluations: Evaluations<Fr> = rand_evaluations(rng, 3);
+        let third_msg = ThirdMessage::<Fr> { sums: vec![vec![rand_sums(rng); 2]; 3] };
+        let fourth_msg = FourthMessage::<Fr> { sums: vec![rand_sums(rng); 3] };
+        let pc_proof = sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false); 2]) };
+        let expected = Proof { batch_sizes: vec![2; 3], commitments, evaluations, third_msg, fourth_msg, pc_proof };
+
+        // Serialize (JSON).
+        let candidate = serde_json::to_string(&expected).unwrap();
+        assert_eq!(format!("\"{expected}\""), candidate);
+        assert_eq!(expected, serde_json::from_str(&candidate).unwrap());
+
+        // Serialize (bincode).
+        let candidate = bincode::serialize(&expected).unwrap();
+        assert_eq!(expected, bincode::deserialize(&candidate[..]).unwrap());
+    }
 }
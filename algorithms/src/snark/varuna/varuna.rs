@@ -74,12 +74,22 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
         circuits: &[&C],
     ) -> Result<Vec<(CircuitProvingKey<E, SM>, CircuitVerifyingKey<E>)>> {
         let index_time = start_timer!(|| "Varuna::CircuitSetup");
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("varuna::setup", num_circuits = circuits.len()).entered();
 
         let universal_prover = &universal_srs.to_universal_prover()?;
 
         let mut circuit_keys = Vec::with_capacity(circuits.len());
         for circuit in circuits {
             let mut indexed_circuit = AHPForR1CS::<_, SM>::index(*circuit)?;
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!(
+                "varuna::index",
+                circuit_id = %indexed_circuit.id,
+                num_constraints = indexed_circuit.index_info.num_constraints,
+                num_variables = indexed_circuit.index_info.num_variables,
+            )
+            .entered();
             // TODO: Add check that c is in the correct mode.
             // Ensure the universal SRS supports the circuit size.
             universal_srs.download_powers_for(0..indexed_circuit.max_degree()?).map_err(|e| {
@@ -349,12 +359,25 @@ where
         if keys_to_constraints.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "varuna::prove",
+            circuit_ids = %keys_to_constraints.keys().map(|pk| pk.circuit.id).join(","),
+            num_circuits = keys_to_constraints.len(),
+            num_instances = tracing::field::Empty,
+        )
+        .entered();
 
         let mut circuits_to_constraints = BTreeMap::new();
         for (pk, constraints) in keys_to_constraints {
             circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
         }
-        let prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, zk_rng)?;
+        let prover_state = {
+            #[cfg(feature = "tracing")]
+            let _span =
+                tracing::info_span!("varuna::synthesize", num_circuits = circuits_to_constraints.len()).entered();
+            AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, zk_rng)?
+        };
 
         // extract information from the prover key and state to consume in further calculations
         let mut batch_sizes = BTreeMap::new();
@@ -379,6 +402,8 @@ where
             circuit_ids.push(circuit_id);
         }
         ensure!(prover_state.total_instances == total_instances);
+        #[cfg(feature = "tracing")]
+        span.record("num_instances", total_instances);
 
         let committer_key = CommitterUnionKey::union(keys_to_constraints.keys().map(|pk| pk.committer_key.deref()));
 
@@ -640,6 +665,14 @@ where
 
         proof.check_batch_sizes()?;
         let batch_sizes_vec = proof.batch_sizes();
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "varuna::verify",
+            circuit_ids = %keys_to_inputs.keys().map(|vk| vk.id).join(","),
+            num_circuits = keys_to_inputs.len(),
+            num_instances = batch_sizes_vec.iter().sum::<usize>(),
+        )
+        .entered();
         let mut batch_sizes = BTreeMap::new();
         for (i, (vk, public_inputs_i)) in keys_to_inputs.iter().enumerate() {
             batch_sizes.insert(vk.id, batch_sizes_vec[i]);
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Write},
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    FromBytes,
+    ToBytes,
+};
+
+use std::io;
+
+/// `ThresholdPublicKey` is the public key of a committee, along with the verification key of each party.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ThresholdPublicKey<E: PairingEngine> {
+    /// The number of decryption shares required to decrypt a ciphertext.
+    pub threshold: u32,
+    /// The public key of the committee, `s * H` for the generator `H` of G2.
+    pub public_key: E::G2Affine,
+    /// The verification key of each party, `s_i * H`, ordered by the index of the party.
+    pub verification_keys: Vec<E::G2Affine>,
+}
+
+impl<E: PairingEngine> ThresholdPublicKey<E> {
+    /// Returns the number of parties in the committee.
+    pub fn num_parties(&self) -> usize {
+        self.verification_keys.len()
+    }
+
+    /// Returns the verification key of the party with the given index, if it exists.
+    pub fn verification_key(&self, index: u32) -> Option<&E::G2Affine> {
+        (index as usize).checked_sub(1).and_then(|i| self.verification_keys.get(i))
+    }
+}
+
+/// `SecretKeyShare` is the share of the committee secret key that is held by one party.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SecretKeyShare<E: PairingEngine> {
+    /// The index of the party, starting from 1.
+    pub index: u32,
+    /// The evaluation of the secret-sharing polynomial at `index`.
+    pub share: E::Fr,
+}
+
+/// `ThresholdCiphertext` is a message encrypted to the public key of a committee.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ThresholdCiphertext<E: PairingEngine> {
+    /// The encapsulation, `r * G` for the generator `G` of G1.
+    pub encapsulation: E::G1Affine,
+    /// The message, masked with the key stream.
+    pub masked_message: Vec<u8>,
+    /// The proof of well-formedness, `r * H(encapsulation, masked_message)` in G2.
+    pub proof: E::G2Affine,
+}
+
+/// `DecryptionShare` is the contribution of one party to the decryption of a ciphertext.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DecryptionShare<E: PairingEngine> {
+    /// The index of the party, starting from 1.
+    pub index: u32,
+    /// The encapsulation, multiplied by the secret key share of the party.
+    pub share: E::G1Affine,
+}
+
+macro_rules! impl_bytes {
+    ($name:ident) => {
+        impl<E: PairingEngine> FromBytes for $name<E> {
+            fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+                CanonicalDeserialize::deserialize_compressed(&mut reader)
+                    .map_err(|_| error(concat!("could not deserialize ", stringify!($name))))
+            }
+        }
+
+        impl<E: PairingEngine> ToBytes for $name<E> {
+            fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+                CanonicalSerialize::serialize_compressed(self, &mut writer)
+                    .map_err(|_| error(concat!("could not serialize ", stringify!($name))))
+            }
+        }
+    };
+}
+
+impl_bytes!(ThresholdPublicKey);
+impl_bytes!(SecretKeyShare);
+impl_bytes!(ThresholdCiphertext);
+impl_bytes!(DecryptionShare);
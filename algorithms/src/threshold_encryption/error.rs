@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The error type for `ThresholdEncryption`.
+#[derive(Debug, Error)]
+pub enum ThresholdEncryptionError {
+    #[error("The threshold ({threshold}) must be between 1 and the number of parties ({num_parties})")]
+    InvalidThreshold {
+        /// The requested threshold.
+        threshold: usize,
+        /// The requested number of parties.
+        num_parties: usize,
+    },
+
+    #[error("The ciphertext is malformed")]
+    InvalidCiphertext,
+
+    #[error("The decryption share of party {0} is invalid")]
+    InvalidDecryptionShare(u32),
+
+    #[error("Party {0} is not a member of the committee")]
+    UnknownParty(u32),
+
+    #[error("Party {0} provided more than one decryption share")]
+    DuplicateDecryptionShare(u32),
+
+    #[error("Expected at least {expected} valid decryption shares, found {found}")]
+    NotEnoughDecryptionShares {
+        /// The threshold of the committee.
+        expected: usize,
+        /// The number of valid decryption shares of distinct parties.
+        found: usize,
+    },
+}
@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Here we construct a threshold public-key encryption scheme over a pairing-friendly curve, which enables
+//! anyone to encrypt a message to the public key of a committee of `n` parties, such that any `t` of
+//! the parties can jointly decrypt it, while any `t - 1` of the parties learn nothing about it.
+//! Our construction follows the scheme of Baek and Zheng ([BZ03](https://eprint.iacr.org/2003/164)):
+//! each ciphertext carries a proof of its well-formedness that anyone can check with a pairing, and each
+//! decryption share can be checked against the verification key of the party that produced it, so a
+//! dishonest party cannot prevent decryption by publishing an invalid share.
+
+use crate::{crypto_hash::hash_to_curve, msm::VariableBase};
+use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, PrimeField, Zero};
+use snarkvm_utilities::{cfg_iter, rand::Uniform, serialize::CanonicalSerialize, ToBytes};
+
+use blake2::{Blake2s256, Digest};
+use core::marker::PhantomData;
+use rand_core::RngCore;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+mod data_structures;
+pub use data_structures::*;
+
+mod error;
+pub use error::*;
+
+#[cfg(test)]
+mod tests;
+
+/// The domain separator of the key stream.
+const KEY_STREAM_DOMAIN: &[u8] = b"AleoThresholdKeyStream0";
+/// The domain separator of the hash of a ciphertext to G2.
+const HASH_TO_G2_DOMAIN: &[u8] = b"AleoThresholdHashToG20";
+
+/// `ThresholdEncryption` is an implementation of the threshold public-key encryption scheme of Baek and Zheng.
+#[derive(Clone, Debug)]
+pub struct ThresholdEncryption<E: PairingEngine> {
+    _engine: PhantomData<E>,
+}
+
+impl<E: PairingEngine> ThresholdEncryption<E> {
+    /// Samples a committee of `num_parties` parties, of which any `threshold` can decrypt a ciphertext,
+    /// and returns the public key of the committee and the secret key share of each party.
+    ///
+    /// The shares are produced by a trusted dealer; a distributed key generation can be used instead,
+    /// as long as it outputs shares of a degree `threshold - 1` polynomial.
+    pub fn setup<R: RngCore>(
+        num_parties: usize,
+        threshold: usize,
+        rng: &mut R,
+    ) -> Result<(ThresholdPublicKey<E>, Vec<SecretKeyShare<E>>), ThresholdEncryptionError> {
+        if threshold == 0 || threshold > num_parties || u32::try_from(num_parties).is_err() {
+            return Err(ThresholdEncryptionError::InvalidThreshold { threshold, num_parties });
+        }
+
+        // Sample the secret-sharing polynomial, whose constant term is the secret key of the committee.
+        let coefficients = (0..threshold).map(|_| E::Fr::rand(rng)).collect::<Vec<_>>();
+        // Evaluate the polynomial at the index of each party.
+        let shares = (1..=num_parties as u32)
+            .map(|index| {
+                let x = E::Fr::from(index);
+                let share = coefficients.iter().rev().fold(E::Fr::zero(), |acc, coefficient| acc * x + coefficient);
+                SecretKeyShare { index, share }
+            })
+            .collect::<Vec<_>>();
+
+        let generator = E::G2Affine::prime_subgroup_generator();
+        let public_key = (generator * coefficients[0]).to_affine();
+        let verification_keys = E::G2Projective::batch_normalization_into_affine(
+            cfg_iter!(shares).map(|share| generator * share.share).collect(),
+        );
+
+        Ok((ThresholdPublicKey { threshold: threshold as u32, public_key, verification_keys }, shares))
+    }
+
+    /// Encrypts the given message to the public key of the committee.
+    pub fn encrypt<R: RngCore>(
+        public_key: &ThresholdPublicKey<E>,
+        message: &[u8],
+        rng: &mut R,
+    ) -> ThresholdCiphertext<E> {
+        let randomness = E::Fr::rand(rng);
+
+        // Compute the encapsulation, and the key `e(G, s * H)^r`, which the committee recovers as `e(s * (r * G), H)`.
+        let encapsulation = (E::G1Affine::prime_subgroup_generator() * randomness).to_affine();
+        let key = E::pairing(encapsulation, public_key.public_key);
+
+        // Mask the message, and prove that the ciphertext was produced by someone who knows `r`.
+        let masked_message = mask(KEY_STREAM_DOMAIN, &key, message);
+        let proof = (hash_to_g2::<E>(&encapsulation, &masked_message) * randomness).to_affine();
+
+        ThresholdCiphertext { encapsulation, masked_message, proof }
+    }
+
+    /// Returns `true` if the given ciphertext is well-formed.
+    ///
+    /// Parties must only produce decryption shares for well-formed ciphertexts,
+    /// as a decryption share of a malformed ciphertext may leak information about another ciphertext.
+    pub fn verify_ciphertext(ciphertext: &ThresholdCiphertext<E>) -> bool {
+        let ThresholdCiphertext { encapsulation, masked_message, proof } = ciphertext;
+        if encapsulation.is_zero()
+            || !encapsulation.is_in_correct_subgroup_assuming_on_curve()
+            || !proof.is_in_correct_subgroup_assuming_on_curve()
+        {
+            return false;
+        }
+        // Check that `e(G, r * H') == e(r * G, H')`.
+        let hash = hash_to_g2::<E>(encapsulation, masked_message);
+        E::pairing(E::G1Affine::prime_subgroup_generator(), *proof) == E::pairing(*encapsulation, hash)
+    }
+
+    /// Returns the decryption share of the given party for the given ciphertext.
+    pub fn decryption_share(
+        secret_key_share: &SecretKeyShare<E>,
+        ciphertext: &ThresholdCiphertext<E>,
+    ) -> Result<DecryptionShare<E>, ThresholdEncryptionError> {
+        if !Self::verify_ciphertext(ciphertext) {
+            return Err(ThresholdEncryptionError::InvalidCiphertext);
+        }
+        let share = (ciphertext.encapsulation * secret_key_share.share).to_affine();
+        Ok(DecryptionShare { index: secret_key_share.index, share })
+    }
+
+    /// Returns `true` if the given decryption share was produced by its party for the given ciphertext.
+    pub fn verify_decryption_share(
+        public_key: &ThresholdPublicKey<E>,
+        ciphertext: &ThresholdCiphertext<E>,
+        decryption_share: &DecryptionShare<E>,
+    ) -> bool {
+        let Some(verification_key) = public_key.verification_key(decryption_share.index) else {
+            return false;
+        };
+        if !decryption_share.share.is_in_correct_subgroup_assuming_on_curve() {
+            return false;
+        }
+        // Check that `e(s_i * (r * G), H) == e(r * G, s_i * H)`.
+        E::pairing(decryption_share.share, E::G2Affine::prime_subgroup_generator())
+            == E::pairing(ciphertext.encapsulation, *verification_key)
+    }
+
+    /// Decrypts the given ciphertext with any `threshold` valid decryption shares of distinct parties.
+    /// Every decryption share is verified against the public key of the committee, and invalid shares,
+    /// as well as the shares of unknown parties and repeated shares of a party, are skipped.
+    pub fn decrypt(
+        public_key: &ThresholdPublicKey<E>,
+        ciphertext: &ThresholdCiphertext<E>,
+        decryption_shares: &[DecryptionShare<E>],
+    ) -> Result<Vec<u8>, ThresholdEncryptionError> {
+        if !Self::verify_ciphertext(ciphertext) {
+            return Err(ThresholdEncryptionError::InvalidCiphertext);
+        }
+
+        // Select `threshold` valid decryption shares from distinct members of the committee.
+        let threshold = public_key.threshold as usize;
+        let decryption_shares = select_shares(
+            decryption_shares,
+            threshold,
+            |share| share.index,
+            |share| Self::verify_decryption_share(public_key, ciphertext, share),
+        )
+        .map_err(|found| ThresholdEncryptionError::NotEnoughDecryptionShares { expected: threshold, found })?;
+
+        // Interpolate `s * (r * G)` from the decryption shares, and recover the key `e(s * (r * G), H)`.
+        let indices = decryption_shares.iter().map(|share| share.index).collect::<Vec<_>>();
+        let bases = decryption_shares.iter().map(|share| share.share).collect::<Vec<_>>();
+        let scalars = lagrange_coefficients_at_zero::<E::Fr>(&indices)
+            .into_iter()
+            .map(|coefficient| coefficient.to_bigint())
+            .collect::<Vec<_>>();
+        let combined = VariableBase::msm(&bases, &scalars).to_affine();
+        let key = E::pairing(combined, E::G2Affine::prime_subgroup_generator());
+
+        Ok(mask(KEY_STREAM_DOMAIN, &key, &ciphertext.masked_message))
+    }
+}
+
+/// Returns the first `threshold` of the given shares that are valid and from distinct parties,
+/// or the number of such shares if there are fewer than `threshold`.
+pub(crate) fn select_shares<'a, S>(
+    shares: &'a [S],
+    threshold: usize,
+    index: impl Fn(&S) -> u32,
+    is_valid: impl Fn(&S) -> bool,
+) -> Result<Vec<&'a S>, usize> {
+    let mut selected: Vec<&S> = Vec::with_capacity(threshold);
+    for share in shares {
+        if selected.len() == threshold {
+            break;
+        }
+        // Skip a party that already has a valid share, before the (expensive) validity check.
+        if !selected.iter().any(|other| index(other) == index(share)) && is_valid(share) {
+            selected.push(share);
+        }
+    }
+    match selected.len() == threshold {
+        true => Ok(selected),
+        false => Err(selected.len()),
+    }
+}
+
+/// Returns the Lagrange coefficients for evaluating, at zero, the polynomial through the given distinct indices.
+pub(crate) fn lagrange_coefficients_at_zero<F: Field>(indices: &[u32]) -> Vec<F> {
+    let points = indices.iter().map(|index| F::from(*index)).collect::<Vec<_>>();
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, x_i)| {
+            let (numerator, denominator) = points
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold((F::one(), F::one()), |(num, den), (_, x_j)| (num * x_j, den * (*x_j - x_i)));
+            // The indices are distinct and nonzero, so the denominator is nonzero.
+            numerator * denominator.inverse().unwrap()
+        })
+        .collect()
+}
+
+/// Returns the given message, XORed with the key stream derived from the given domain separator and key.
+pub(crate) fn mask<K: ToBytes>(domain: &[u8], key: &K, message: &[u8]) -> Vec<u8> {
+    let key = key.to_bytes_le().expect("Failed to serialize the key");
+    message
+        .chunks(32)
+        .enumerate()
+        .flat_map(|(counter, chunk)| {
+            let block = Blake2s256::new()
+                .chain_update(domain)
+                .chain_update((counter as u64).to_le_bytes())
+                .chain_update(&key)
+                .finalize();
+            chunk.iter().zip(block).map(|(byte, mask)| byte ^ mask).collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Hashes the given encapsulation and masked message to G2.
+fn hash_to_g2<E: PairingEngine>(encapsulation: &E::G1Affine, masked_message: &[u8]) -> E::G2Affine {
+    let mut input = Vec::with_capacity(encapsulation.compressed_size() + masked_message.len());
+    encapsulation.serialize_compressed(&mut input).expect("Failed to serialize the encapsulation");
+    input.extend_from_slice(masked_message);
+    hash_to_curve(HASH_TO_G2_DOMAIN, &input)
+}
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_curves::bls12_377::{Bls12_377, Fr, G2Affine};
+use snarkvm_utilities::{rand::TestRng, FromBytes};
+
+use rand::seq::SliceRandom;
+
+type TE = ThresholdEncryption<Bls12_377>;
+
+const NUM_PARTIES: usize = 7;
+const THRESHOLD: usize = 4;
+
+#[test]
+fn test_encrypt_and_decrypt() {
+    let rng = &mut TestRng::default();
+
+    let (public_key, secret_key_shares) = TE::setup(NUM_PARTIES, THRESHOLD, rng).unwrap();
+    assert_eq!(public_key.num_parties(), NUM_PARTIES);
+
+    for length in [0, 1, 31, 32, 33, 200] {
+        let message = (0..length).map(|_| rng.next_u32() as u8).collect::<Vec<_>>();
+        let ciphertext = TE::encrypt(&public_key, &message, rng);
+        assert!(TE::verify_ciphertext(&ciphertext));
+
+        // Decrypt with a random subset of the parties.
+        let mut decryption_shares = secret_key_shares
+            .iter()
+            .map(|secret_key_share| TE::decryption_share(secret_key_share, &ciphertext).unwrap())
+            .collect::<Vec<_>>();
+        decryption_shares.shuffle(rng);
+        for decryption_share in &decryption_shares {
+            assert!(TE::verify_decryption_share(&public_key, &ciphertext, decryption_share));
+        }
+        assert_eq!(TE::decrypt(&public_key, &ciphertext, &decryption_shares[..THRESHOLD]).unwrap(), message);
+
+        // Ensure decryption fails with fewer than the threshold of parties.
+        assert!(matches!(
+            TE::decrypt(&public_key, &ciphertext, &decryption_shares[..THRESHOLD - 1]),
+            Err(ThresholdEncryptionError::NotEnoughDecryptionShares { expected: THRESHOLD, found })
+                if found == THRESHOLD - 1
+        ));
+    }
+}
+
+#[test]
+fn test_invalid_decryption_shares() {
+    let rng = &mut TestRng::default();
+
+    let (public_key, secret_key_shares) = TE::setup(NUM_PARTIES, THRESHOLD, rng).unwrap();
+    let ciphertext = TE::encrypt(&public_key, b"hello world", rng);
+    let mut decryption_shares = secret_key_shares
+        .iter()
+        .map(|secret_key_share| TE::decryption_share(secret_key_share, &ciphertext).unwrap())
+        .collect::<Vec<_>>();
+
+    // Checks that the shares decrypt the ciphertext, but that their first `THRESHOLD` do not.
+    let check = |decryption_shares: &[DecryptionShare<Bls12_377>]| {
+        assert_eq!(TE::decrypt(&public_key, &ciphertext, decryption_shares).unwrap(), b"hello world");
+        assert!(matches!(
+            TE::decrypt(&public_key, &ciphertext, &decryption_shares[..THRESHOLD]),
+            Err(ThresholdEncryptionError::NotEnoughDecryptionShares { expected: THRESHOLD, found })
+                if found == THRESHOLD - 1
+        ));
+    };
+
+    // Ensure a share for another ciphertext is skipped.
+    let other = TE::encrypt(&public_key, b"hello world", rng);
+    let invalid = TE::decryption_share(&secret_key_shares[0], &other).unwrap();
+    assert!(!TE::verify_decryption_share(&public_key, &ciphertext, &invalid));
+    decryption_shares[0] = invalid;
+    check(&decryption_shares);
+
+    // Ensure a repeated share of a party is only counted once.
+    decryption_shares[0] = decryption_shares[1].clone();
+    check(&decryption_shares);
+
+    // Ensure the share of an unknown party is skipped.
+    decryption_shares[0].index = NUM_PARTIES as u32 + 1;
+    assert!(!TE::verify_decryption_share(&public_key, &ciphertext, &decryption_shares[0]));
+    check(&decryption_shares);
+}
+
+#[test]
+fn test_malformed_ciphertext() {
+    let rng = &mut TestRng::default();
+
+    let (public_key, secret_key_shares) = TE::setup(NUM_PARTIES, THRESHOLD, rng).unwrap();
+    let mut ciphertext = TE::encrypt(&public_key, b"hello world", rng);
+
+    // Ensure a ciphertext whose masked message was modified is rejected.
+    ciphertext.masked_message[0] ^= 1;
+    assert!(!TE::verify_ciphertext(&ciphertext));
+    assert!(matches!(
+        TE::decryption_share(&secret_key_shares[0], &ciphertext),
+        Err(ThresholdEncryptionError::InvalidCiphertext)
+    ));
+}
+
+#[test]
+fn test_setup() {
+    let rng = &mut TestRng::default();
+
+    assert!(TE::setup(NUM_PARTIES, 0, rng).is_err());
+    assert!(TE::setup(NUM_PARTIES, NUM_PARTIES + 1, rng).is_err());
+
+    // Ensure the shares of any threshold of parties interpolate to the secret key of the committee.
+    let (public_key, secret_key_shares) = TE::setup(NUM_PARTIES, THRESHOLD, rng).unwrap();
+    let shares = &secret_key_shares[NUM_PARTIES - THRESHOLD..];
+    let indices = shares.iter().map(|share| share.index).collect::<Vec<_>>();
+    let secret_key = lagrange_coefficients_at_zero::<Fr>(&indices)
+        .iter()
+        .zip(shares)
+        .map(|(coefficient, share)| *coefficient * share.share)
+        .sum::<Fr>();
+    assert_eq!((G2Affine::prime_subgroup_generator() * secret_key).to_affine(), public_key.public_key);
+}
+
+#[test]
+fn test_bytes() {
+    let rng = &mut TestRng::default();
+
+    let (public_key, secret_key_shares) = TE::setup(NUM_PARTIES, THRESHOLD, rng).unwrap();
+    let ciphertext = TE::encrypt(&public_key, b"hello world", rng);
+    let decryption_share = TE::decryption_share(&secret_key_shares[0], &ciphertext).unwrap();
+
+    let bytes = public_key.to_bytes_le().unwrap();
+    assert_eq!(public_key, ThresholdPublicKey::read_le(&bytes[..]).unwrap());
+    let bytes = secret_key_shares[0].to_bytes_le().unwrap();
+    assert_eq!(secret_key_shares[0], SecretKeyShare::read_le(&bytes[..]).unwrap());
+    let bytes = ciphertext.to_bytes_le().unwrap();
+    assert_eq!(ciphertext, ThresholdCiphertext::read_le(&bytes[..]).unwrap());
+    let bytes = decryption_share.to_bytes_le().unwrap();
+    assert_eq!(decryption_share, DecryptionShare::read_le(&bytes[..]).unwrap());
+}
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Write},
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    FromBytes,
+    ToBytes,
+};
+
+use std::io;
+
+/// `RoundSignature` is the signature of the beacon committee on a round, which is published once the round is reached.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct RoundSignature<E: PairingEngine> {
+    /// The round.
+    pub round: u64,
+    /// The signature, `s * H(round)` in G1.
+    pub signature: E::G1Affine,
+}
+
+/// `RoundSignatureShare` is the contribution of one party to the signature of the beacon committee on a round.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct RoundSignatureShare<E: PairingEngine> {
+    /// The index of the party, starting from 1.
+    pub index: u32,
+    /// The round.
+    pub round: u64,
+    /// The signature share, `s_i * H(round)` in G1.
+    pub share: E::G1Affine,
+}
+
+/// `TimelockCiphertext` is a message that can only be decrypted with the signature of the beacon on its round.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct TimelockCiphertext<E: PairingEngine> {
+    /// The round after which the ciphertext can be decrypted.
+    pub round: u64,
+    /// The encapsulation, `r * G` for the generator `G` of G2.
+    pub encapsulation: E::G2Affine,
+    /// The random seed of the encryption, masked with the key derived from the round.
+    pub masked_seed: Vec<u8>,
+    /// The message, masked with the key stream derived from the seed.
+    pub masked_message: Vec<u8>,
+}
+
+macro_rules! impl_bytes {
+    ($name:ident) => {
+        impl<E: PairingEngine> FromBytes for $name<E> {
+            fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+                CanonicalDeserialize::deserialize_compressed(&mut reader)
+                    .map_err(|_| error(concat!("could not deserialize ", stringify!($name))))
+            }
+        }
+
+        impl<E: PairingEngine> ToBytes for $name<E> {
+            fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+                CanonicalSerialize::serialize_compressed(self, &mut writer)
+                    .map_err(|_| error(concat!("could not serialize ", stringify!($name))))
+            }
+        }
+    };
+}
+
+impl_bytes!(RoundSignature);
+impl_bytes!(RoundSignatureShare);
+impl_bytes!(TimelockCiphertext);
@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The error type for `TimelockEncryption`.
+#[derive(Debug, Error)]
+pub enum TimelockError {
+    #[error("The ciphertext is malformed, or was not encrypted to round {0}")]
+    InvalidCiphertext(u64),
+
+    #[error("The signature of the beacon for round {0} is invalid")]
+    InvalidRoundSignature(u64),
+
+    #[error("The signature share of party {0} is invalid")]
+    InvalidSignatureShare(u32),
+
+    #[error("Party {0} is not a member of the beacon committee")]
+    UnknownParty(u32),
+
+    #[error("Party {0} provided more than one signature share")]
+    DuplicateSignatureShare(u32),
+
+    #[error("Expected at least {expected} valid signature shares, found {found}")]
+    NotEnoughSignatureShares {
+        /// The threshold of the beacon committee.
+        expected: usize,
+        /// The number of valid signature shares of distinct parties.
+        found: usize,
+    },
+}
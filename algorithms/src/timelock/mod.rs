@@ -0,0 +1,205 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Here we construct a timelock encryption scheme, which enables anyone to encrypt a message to a future
+//! round of a threshold randomness beacon, such that the message can only be decrypted once the beacon
+//! publishes its signature on that round. Our construction follows the identity-based encryption scheme of
+//! Boneh and Franklin ([BF01](https://eprint.iacr.org/2001/090)), where the identity is the round and the
+//! private key of the identity is the BLS signature of the beacon on the round, as in
+//! [tlock](https://eprint.iacr.org/2023/189). The Fujisaki-Okamoto transform binds the message to the
+//! encapsulation, so a decryptor detects a ciphertext that was modified or encrypted to another round.
+//!
+//! The beacon committee reuses the keys of [`ThresholdEncryption`], so any `threshold` of its parties
+//! produce the signature of a round, and no coalition of fewer parties can decrypt a ciphertext early.
+
+use crate::{
+    crypto_hash::hash_to_curve,
+    msm::VariableBase,
+    threshold_encryption::{lagrange_coefficients_at_zero, mask, select_shares, SecretKeyShare, ThresholdPublicKey},
+};
+use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+
+use blake2::{Blake2s256, Digest};
+use core::marker::PhantomData;
+use rand_core::RngCore;
+
+mod data_structures;
+pub use data_structures::*;
+
+mod error;
+pub use error::*;
+
+#[cfg(test)]
+mod tests;
+
+/// The domain separator of the hash of a round to G1.
+const ROUND_DOMAIN: &[u8] = b"AleoTimelockRound0";
+/// The domain separator of the key stream of the seed.
+const SEED_DOMAIN: &[u8] = b"AleoTimelockSeed0";
+/// The domain separator of the key stream of the message.
+const MESSAGE_DOMAIN: &[u8] = b"AleoTimelockMessage0";
+/// The domain separator of the encryption randomness.
+const RANDOMNESS_DOMAIN: &[u8] = b"AleoTimelockRandomness0";
+/// The size of the random seed of an encryption, in bytes.
+const SEED_SIZE: usize = 32;
+
+/// `TimelockEncryption` is an implementation of timelock encryption to the rounds of a threshold BLS beacon.
+#[derive(Clone, Debug)]
+pub struct TimelockEncryption<E: PairingEngine> {
+    _engine: PhantomData<E>,
+}
+
+impl<E: PairingEngine> TimelockEncryption<E> {
+    /// Returns the identity of the given round, which is the message signed by the beacon.
+    pub fn round_identity(round: u64) -> E::G1Affine {
+        hash_to_curve(ROUND_DOMAIN, &round.to_le_bytes())
+    }
+
+    /// Returns the signature share of the given party on the given round.
+    ///
+    /// Parties must only sign a round once it is reached, as its signature decrypts every ciphertext to it.
+    pub fn sign_round(secret_key_share: &SecretKeyShare<E>, round: u64) -> RoundSignatureShare<E> {
+        let share = (Self::round_identity(round) * secret_key_share.share).to_affine();
+        RoundSignatureShare { index: secret_key_share.index, round, share }
+    }
+
+    /// Returns `true` if the given signature share was produced by its party.
+    pub fn verify_signature_share(public_key: &ThresholdPublicKey<E>, share: &RoundSignatureShare<E>) -> bool {
+        let Some(verification_key) = public_key.verification_key(share.index) else {
+            return false;
+        };
+        if !share.share.is_in_correct_subgroup_assuming_on_curve() {
+            return false;
+        }
+        // Check that `e(s_i * H(round), G) == e(H(round), s_i * G)`.
+        E::pairing(share.share, E::G2Affine::prime_subgroup_generator())
+            == E::pairing(Self::round_identity(share.round), *verification_key)
+    }
+
+    /// Returns the signature of the beacon on the given round, from any `threshold` valid signature shares
+    /// of distinct parties. Every signature share is verified, and invalid shares, shares on another round,
+    /// as well as the shares of unknown parties and repeated shares of a party, are skipped.
+    pub fn aggregate_signature(
+        public_key: &ThresholdPublicKey<E>,
+        round: u64,
+        shares: &[RoundSignatureShare<E>],
+    ) -> Result<RoundSignature<E>, TimelockError> {
+        // Select `threshold` valid signature shares on the given round, from distinct members of the committee.
+        let threshold = public_key.threshold as usize;
+        let shares = select_shares(
+            shares,
+            threshold,
+            |share| share.index,
+            |share| share.round == round && Self::verify_signature_share(public_key, share),
+        )
+        .map_err(|found| TimelockError::NotEnoughSignatureShares { expected: threshold, found })?;
+
+        // Interpolate `s * H(round)` from the signature shares.
+        let indices = shares.iter().map(|share| share.index).collect::<Vec<_>>();
+        let bases = shares.iter().map(|share| share.share).collect::<Vec<_>>();
+        let scalars = lagrange_coefficients_at_zero::<E::Fr>(&indices)
+            .into_iter()
+            .map(|coefficient| coefficient.to_bigint())
+            .collect::<Vec<_>>();
+        let signature = VariableBase::msm(&bases, &scalars).to_affine();
+
+        Ok(RoundSignature { round, signature })
+    }
+
+    /// Returns `true` if the given signature was produced by the beacon with the given public key.
+    pub fn verify_round_signature(public_key: &E::G2Affine, signature: &RoundSignature<E>) -> bool {
+        if signature.signature.is_zero() || !signature.signature.is_in_correct_subgroup_assuming_on_curve() {
+            return false;
+        }
+        // Check that `e(s * H(round), G) == e(H(round), s * G)`.
+        E::pairing(signature.signature, E::G2Affine::prime_subgroup_generator())
+            == E::pairing(Self::round_identity(signature.round), *public_key)
+    }
+
+    /// Encrypts the given message to the given round of the beacon with the given public key.
+    pub fn encrypt<R: RngCore>(
+        public_key: &E::G2Affine,
+        round: u64,
+        message: &[u8],
+        rng: &mut R,
+    ) -> TimelockCiphertext<E> {
+        let mut seed = [0u8; SEED_SIZE];
+        rng.fill_bytes(&mut seed);
+
+        // Derive the randomness from the seed and the message, so that decryption can check the encapsulation.
+        let randomness = derive_randomness::<E>(round, &seed, message);
+        let encapsulation = (E::G2Affine::prime_subgroup_generator() * randomness).to_affine();
+
+        // Compute the key `e(H(round), s * G)^r`, which the signature recovers as `e(s * H(round), r * G)`.
+        let key = E::pairing(Self::round_identity(round) * randomness, *public_key);
+
+        let masked_seed = mask(SEED_DOMAIN, &key, &seed);
+        let masked_message = mask(MESSAGE_DOMAIN, &seed, message);
+
+        TimelockCiphertext { round, encapsulation, masked_seed, masked_message }
+    }
+
+    /// Returns `true` if the given ciphertext is well-formed. This does not require the signature of its round,
+    /// and so cannot check that the ciphertext decrypts; `decrypt` checks the ciphertext in full.
+    pub fn verify_ciphertext(ciphertext: &TimelockCiphertext<E>) -> bool {
+        ciphertext.masked_seed.len() == SEED_SIZE
+            && !ciphertext.encapsulation.is_zero()
+            && ciphertext.encapsulation.is_in_correct_subgroup_assuming_on_curve()
+    }
+
+    /// Decrypts the given ciphertext with the signature of the beacon with the given public key on its round.
+    pub fn decrypt(
+        public_key: &E::G2Affine,
+        ciphertext: &TimelockCiphertext<E>,
+        signature: &RoundSignature<E>,
+    ) -> Result<Vec<u8>, TimelockError> {
+        if signature.round != ciphertext.round || !Self::verify_ciphertext(ciphertext) {
+            return Err(TimelockError::InvalidCiphertext(signature.round));
+        }
+        if !Self::verify_round_signature(public_key, signature) {
+            return Err(TimelockError::InvalidRoundSignature(signature.round));
+        }
+
+        // Recover the seed and the message.
+        let key = E::pairing(signature.signature, ciphertext.encapsulation);
+        let seed = mask(SEED_DOMAIN, &key, &ciphertext.masked_seed);
+        let message = mask(MESSAGE_DOMAIN, &seed, &ciphertext.masked_message);
+
+        // Ensure the encapsulation was derived from the seed and the message.
+        let randomness = derive_randomness::<E>(ciphertext.round, &seed, &message);
+        if E::G2Affine::prime_subgroup_generator() * randomness != ciphertext.encapsulation.to_projective() {
+            return Err(TimelockError::InvalidCiphertext(ciphertext.round));
+        }
+        Ok(message)
+    }
+}
+
+/// Returns the encryption randomness for the given round, seed, and message.
+fn derive_randomness<E: PairingEngine>(round: u64, seed: &[u8], message: &[u8]) -> E::Fr {
+    // Hash to twice the size of the scalar field, so that the reduction is statistically close to uniform.
+    let bytes = [0u8, 1u8]
+        .iter()
+        .flat_map(|block| {
+            Blake2s256::new()
+                .chain_update(RANDOMNESS_DOMAIN)
+                .chain_update([*block])
+                .chain_update(round.to_le_bytes())
+                .chain_update(seed)
+                .chain_update(message)
+                .finalize()
+        })
+        .collect::<Vec<_>>();
+    E::Fr::from_bytes_le_mod_order(&bytes)
+}
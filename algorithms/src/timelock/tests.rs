@@ -0,0 +1,168 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::threshold_encryption::ThresholdEncryption;
+use snarkvm_curves::bls12_377::Bls12_377;
+use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
+
+type TE = ThresholdEncryption<Bls12_377>;
+type TL = TimelockEncryption<Bls12_377>;
+
+const NUM_PARTIES: usize = 5;
+const THRESHOLD: usize = 3;
+
+/// Returns the signature of the beacon on the given round, signed by the last `THRESHOLD` parties.
+fn sign(
+    public_key: &ThresholdPublicKey<Bls12_377>,
+    secret_key_shares: &[SecretKeyShare<Bls12_377>],
+    round: u64,
+) -> RoundSignature<Bls12_377> {
+    let shares = secret_key_shares[NUM_PARTIES - THRESHOLD..]
+        .iter()
+        .map(|secret_key_share| TL::sign_round(secret_key_share, round))
+        .collect::<Vec<_>>();
+    TL::aggregate_signature(public_key, round, &shares).unwrap()
+}
+
+#[test]
+fn test_encrypt_and_decrypt() {
+    let rng = &mut TestRng::default();
+
+    let (public_key, secret_key_shares) = TE::setup(NUM_PARTIES, THRESHOLD, rng).unwrap();
+    let signature = sign(&public_key, &secret_key_shares, 42);
+    assert!(TL::verify_round_signature(&public_key.public_key, &signature));
+
+    for length in [0, 1, 32, 100] {
+        let message = (0..length).map(|_| rng.next_u32() as u8).collect::<Vec<_>>();
+        let ciphertext = TL::encrypt(&public_key.public_key, 42, &message, rng);
+        assert!(TL::verify_ciphertext(&ciphertext));
+        assert_eq!(TL::decrypt(&public_key.public_key, &ciphertext, &signature).unwrap(), message);
+    }
+
+    // Ensure the signature is the same for any threshold of parties.
+    let shares = secret_key_shares[..THRESHOLD].iter().map(|share| TL::sign_round(share, 42)).collect::<Vec<_>>();
+    assert_eq!(TL::aggregate_signature(&public_key, 42, &shares).unwrap(), signature);
+}
+
+#[test]
+fn test_decrypt_with_wrong_round() {
+    let rng = &mut TestRng::default();
+
+    let (public_key, secret_key_shares) = TE::setup(NUM_PARTIES, THRESHOLD, rng).unwrap();
+    let ciphertext = TL::encrypt(&public_key.public_key, 43, b"sealed bid", rng);
+
+    // Ensure the signature on an earlier round does not decrypt the ciphertext.
+    let signature = sign(&public_key, &secret_key_shares, 42);
+    assert!(matches!(
+        TL::decrypt(&public_key.public_key, &ciphertext, &signature),
+        Err(TimelockError::InvalidCiphertext(42))
+    ));
+
+    // Ensure a ciphertext that was relabelled to the earlier round does not decrypt.
+    let mut relabelled = ciphertext.clone();
+    relabelled.round = 42;
+    assert!(matches!(
+        TL::decrypt(&public_key.public_key, &relabelled, &signature),
+        Err(TimelockError::InvalidCiphertext(42))
+    ));
+
+    // Ensure a forged signature is rejected.
+    let forged = RoundSignature { round: 43, signature: signature.signature };
+    assert!(!TL::verify_round_signature(&public_key.public_key, &forged));
+    assert!(matches!(
+        TL::decrypt(&public_key.public_key, &ciphertext, &forged),
+        Err(TimelockError::InvalidRoundSignature(43))
+    ));
+
+    let signature = sign(&public_key, &secret_key_shares, 43);
+    assert_eq!(TL::decrypt(&public_key.public_key, &ciphertext, &signature).unwrap(), b"sealed bid");
+}
+
+#[test]
+fn test_modified_ciphertext() {
+    let rng = &mut TestRng::default();
+
+    let (public_key, secret_key_shares) = TE::setup(NUM_PARTIES, THRESHOLD, rng).unwrap();
+    let ciphertext = TL::encrypt(&public_key.public_key, 42, b"sealed bid", rng);
+    let signature = sign(&public_key, &secret_key_shares, 42);
+
+    let mut modified = ciphertext.clone();
+    modified.masked_message[0] ^= 1;
+    assert!(matches!(
+        TL::decrypt(&public_key.public_key, &modified, &signature),
+        Err(TimelockError::InvalidCiphertext(42))
+    ));
+
+    let mut modified = ciphertext;
+    modified.masked_seed.pop();
+    assert!(!TL::verify_ciphertext(&modified));
+}
+
+#[test]
+fn test_aggregate_signature() {
+    let rng = &mut TestRng::default();
+
+    let (public_key, secret_key_shares) = TE::setup(NUM_PARTIES, THRESHOLD, rng).unwrap();
+    let mut shares = secret_key_shares.iter().map(|share| TL::sign_round(share, 42)).collect::<Vec<_>>();
+    for share in &shares {
+        assert!(TL::verify_signature_share(&public_key, share));
+    }
+
+    assert!(matches!(
+        TL::aggregate_signature(&public_key, 42, &shares[..THRESHOLD - 1]),
+        Err(TimelockError::NotEnoughSignatureShares { expected: THRESHOLD, found }) if found == THRESHOLD - 1
+    ));
+
+    let signature = TL::aggregate_signature(&public_key, 42, &shares[..THRESHOLD]).unwrap();
+
+    // Ensure a share on another round is skipped.
+    shares[0] = TL::sign_round(&secret_key_shares[0], 43);
+    assert_eq!(TL::aggregate_signature(&public_key, 42, &shares).unwrap(), signature);
+    assert!(matches!(
+        TL::aggregate_signature(&public_key, 42, &shares[..THRESHOLD]),
+        Err(TimelockError::NotEnoughSignatureShares { expected: THRESHOLD, found }) if found == THRESHOLD - 1
+    ));
+
+    // Ensure a share with the index of another party is skipped.
+    shares[0] = shares[1];
+    shares[0].index = 1;
+    assert!(!TL::verify_signature_share(&public_key, &shares[0]));
+    assert_eq!(TL::aggregate_signature(&public_key, 42, &shares).unwrap(), signature);
+
+    // Ensure a repeated share of a party is only counted once.
+    shares[0].index = 2;
+    assert_eq!(TL::aggregate_signature(&public_key, 42, &shares).unwrap(), signature);
+    assert!(matches!(
+        TL::aggregate_signature(&public_key, 42, &shares[..THRESHOLD]),
+        Err(TimelockError::NotEnoughSignatureShares { expected: THRESHOLD, found }) if found == THRESHOLD - 1
+    ));
+}
+
+#[test]
+fn test_bytes() {
+    let rng = &mut TestRng::default();
+
+    let (public_key, secret_key_shares) = TE::setup(NUM_PARTIES, THRESHOLD, rng).unwrap();
+    let ciphertext = TL::encrypt(&public_key.public_key, 42, b"sealed bid", rng);
+    let share = TL::sign_round(&secret_key_shares[0], 42);
+    let signature = sign(&public_key, &secret_key_shares, 42);
+
+    let bytes = ciphertext.to_bytes_le().unwrap();
+    assert_eq!(ciphertext, TimelockCiphertext::read_le(&bytes[..]).unwrap());
+    let bytes = share.to_bytes_le().unwrap();
+    assert_eq!(share, RoundSignatureShare::read_le(&bytes[..]).unwrap());
+    let bytes = signature.to_bytes_le().unwrap();
+    assert_eq!(signature, RoundSignature::read_le(&bytes[..]).unwrap());
+}
@@ -15,5 +15,10 @@
 pub mod algebraic_sponge;
 pub use algebraic_sponge::*;
 
+#[cfg(feature = "polycommit")]
+pub mod polynomial_commitment;
+#[cfg(feature = "polycommit")]
+pub use polynomial_commitment::*;
+
 pub mod snark;
 pub use snark::*;
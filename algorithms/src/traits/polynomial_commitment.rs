@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{fft::DensePolynomial, polycommit::PCError, AlgebraicSponge};
+use snarkvm_fields::PrimeField;
+
+use rand_core::RngCore;
+use std::fmt::Debug;
+
+/// A polynomial commitment scheme for univariate polynomials, in which one or more committed
+/// polynomials are opened at a single point, and the challenges are drawn from a sponge.
+///
+/// The public parameters are left to each scheme, as they differ in shape:
+/// e.g. a structured reference string for [`SonicKZG10`](crate::polycommit::sonic_pc::SonicKZG10),
+/// or a blowup factor and a number of queries for [`FriPC`](crate::polycommit::fri_pc::FriPC).
+pub trait PolynomialCommitment {
+    /// The field of the committed polynomials.
+    type ScalarField: PrimeField;
+    /// The field over which the sponge operates.
+    type BaseField: PrimeField;
+
+    /// The key used to commit to polynomials, and to create evaluation proofs.
+    type CommitterKey: Clone + Debug;
+    /// The key used to check evaluation proofs.
+    type VerifierKey: Clone + Debug;
+    /// A commitment to a polynomial.
+    type Commitment: Clone + Debug + PartialEq + Eq;
+    /// The randomness used to make a commitment hiding.
+    type Randomness: Clone + Debug;
+    /// An evaluation proof for one or more polynomials at a single point.
+    type Proof: Clone + Debug + PartialEq + Eq;
+
+    /// The sponge from which the challenges are drawn.
+    type Sponge: AlgebraicSponge<Self::BaseField, 2>;
+
+    /// Outputs a commitment to `polynomial`, which is hiding for a single evaluation if `hiding` is set.
+    ///
+    /// `rng` should not be `None` if `hiding` is set.
+    fn commit(
+        ck: &Self::CommitterKey,
+        polynomial: &DensePolynomial<Self::ScalarField>,
+        hiding: bool,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Self::Commitment, Self::Randomness), PCError>;
+
+    /// On input a list of polynomials, their commitments and randomness, and a point `point`,
+    /// outputs a single proof for the evaluations of all of the polynomials at `point`.
+    ///
+    /// `rng` should not be `None` if any of the commitments is hiding.
+    fn batch_open(
+        ck: &Self::CommitterKey,
+        polynomials: &[DensePolynomial<Self::ScalarField>],
+        commitments: &[Self::Commitment],
+        point: Self::ScalarField,
+        rands: &[Self::Randomness],
+        sponge: &mut Self::Sponge,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<Self::Proof, PCError>;
+
+    /// Verifies a proof produced by [`PolynomialCommitment::batch_open`], i.e. that each `value_i`
+    /// in `values` is the evaluation at `point` of the polynomial committed inside `commitment_i`.
+    fn batch_check(
+        vk: &Self::VerifierKey,
+        commitments: &[Self::Commitment],
+        point: Self::ScalarField,
+        values: &[Self::ScalarField],
+        proof: &Self::Proof,
+        sponge: &mut Self::Sponge,
+    ) -> Result<bool, PCError>;
+
+    /// On input a polynomial `p` and a point `point`, outputs a proof for the evaluation of `p` at `point`.
+    fn open(
+        ck: &Self::CommitterKey,
+        polynomial: &DensePolynomial<Self::ScalarField>,
+        commitment: &Self::Commitment,
+        point: Self::ScalarField,
+        rand: &Self::Randomness,
+        sponge: &mut Self::Sponge,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<Self::Proof, PCError> {
+        Self::batch_open(
+            ck,
+            core::slice::from_ref(polynomial),
+            core::slice::from_ref(commitment),
+            point,
+            core::slice::from_ref(rand),
+            sponge,
+            rng,
+        )
+    }
+
+    /// Verifies that `value` is the evaluation at `point` of the polynomial committed inside `commitment`.
+    fn check(
+        vk: &Self::VerifierKey,
+        commitment: &Self::Commitment,
+        point: Self::ScalarField,
+        value: Self::ScalarField,
+        proof: &Self::Proof,
+        sponge: &mut Self::Sponge,
+    ) -> Result<bool, PCError> {
+        Self::batch_check(vk, core::slice::from_ref(commitment), point, &[value], proof, sponge)
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U8};
+
+use core::marker::PhantomData;
+
+/// The data encapsulation of the ECIES Poseidon construction used for record encryption.
+///
+/// This circuit matches `console::ECIESPoseidonEncryption`: a message is encoded with a `1` terminator byte,
+/// and encrypted by adding the Poseidon key stream of the symmetric key, under the encryption domain.
+pub struct ECIESPoseidonEncryption<A: Aleo>(PhantomData<A>);
+
+impl<A: Aleo> ECIESPoseidonEncryption<A> {
+    /// Returns the number of message bytes that are encoded in each field element.
+    pub fn bytes_per_field() -> usize {
+        A::BaseField::size_in_data_bits() / 8
+    }
+
+    /// Returns the encoding of the given message as field elements.
+    ///
+    /// The message is terminated with a `1` byte, and padded with zeros to fill the last field element.
+    pub fn encode_message(message: &[U8<A>]) -> Vec<Field<A>> {
+        let mut bits_le = message.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+        // Append the terminator byte.
+        bits_le.push(Boolean::constant(true));
+        bits_le.extend((1..8).map(|_| Boolean::constant(false)));
+        // Pack the bits into field elements, which pads the last field element with zeros.
+        bits_le.chunks(Self::bytes_per_field() * 8).map(Field::from_bits_le).collect()
+    }
+
+    /// Encrypts the given field elements under the given symmetric key.
+    pub fn encrypt(symmetric_key: &Field<A>, message: &[Field<A>]) -> Vec<Field<A>> {
+        let randomizers = Self::key_stream(symmetric_key, message.len());
+        message.iter().zip_eq(&randomizers).map(|(message, randomizer)| message + randomizer).collect()
+    }
+
+    /// Decrypts the given field elements under the given symmetric key.
+    pub fn decrypt(symmetric_key: &Field<A>, ciphertext: &[Field<A>]) -> Vec<Field<A>> {
+        let randomizers = Self::key_stream(symmetric_key, ciphertext.len());
+        ciphertext.iter().zip_eq(&randomizers).map(|(ciphertext, randomizer)| ciphertext - randomizer).collect()
+    }
+
+    /// Returns the first `num_randomizers` elements of the key stream of the given symmetric key.
+    fn key_stream(symmetric_key: &Field<A>, num_randomizers: usize) -> Vec<Field<A>> {
+        match u16::try_from(num_randomizers) {
+            Ok(num_randomizers) => A::hash_many_psd8(&[A::encryption_domain(), symmetric_key.clone()], num_randomizers),
+            Err(_) => A::halt(format!("Cannot encrypt more than {} field elements", u16::MAX)),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+    type NativeEncryption = console::ECIESPoseidonEncryption<CurrentNetwork>;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_encode_message(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for length in [0, 1, NativeEncryption::bytes_per_field() - 1, NativeEncryption::bytes_per_field(), 100] {
+            let message = (0..length).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+            let expected = NativeEncryption::encode_message(&message)?;
+
+            let candidate_message = message
+                .iter()
+                .map(|byte| U8::<Circuit>::new(mode, <U8<Circuit> as Inject>::Primitive::new(*byte)))
+                .collect::<Vec<_>>();
+            Circuit::scope(format!("{mode} {length}"), || {
+                let candidate = ECIESPoseidonEncryption::<Circuit>::encode_message(&candidate_message);
+                assert_eq!(expected, candidate.eject_value());
+                // Check that the encoding packs the bits of the message without constraints.
+                assert_eq!(0, Circuit::num_constraints_in_scope());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    fn check_encrypt_and_decrypt(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let symmetric_key = console::Field::<CurrentNetwork>::rand(rng);
+            let message = (0..i).map(|_| console::Field::rand(rng)).collect::<Vec<_>>();
+            let expected = NativeEncryption::encrypt(&symmetric_key, &message);
+
+            let candidate_key = Field::<Circuit>::new(mode, symmetric_key);
+            let candidate_message = message.iter().map(|field| Field::<Circuit>::new(mode, *field)).collect::<Vec<_>>();
+            Circuit::scope(format!("{mode} {i}"), || {
+                let ciphertext = ECIESPoseidonEncryption::<Circuit>::encrypt(&candidate_key, &candidate_message);
+                assert_eq!(expected, ciphertext.eject_value());
+
+                let decrypted = ECIESPoseidonEncryption::<Circuit>::decrypt(&candidate_key, &ciphertext);
+                assert_eq!(message, decrypted.eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_message_constant() -> Result<()> {
+        check_encode_message(Mode::Constant)
+    }
+
+    #[test]
+    fn test_encode_message_private() -> Result<()> {
+        check_encode_message(Mode::Private)
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt_constant() -> Result<()> {
+        check_encrypt_and_decrypt(Mode::Constant)
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt_private() -> Result<()> {
+        check_encrypt_and_decrypt(Mode::Private)
+    }
+}
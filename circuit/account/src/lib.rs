@@ -21,6 +21,9 @@ use snarkvm_circuit_network::AleoV0 as Circuit;
 pub mod compute_key;
 pub use compute_key::*;
 
+pub mod encryption;
+pub use encryption::*;
+
 pub mod graph_key;
 pub use graph_key::*;
 
@@ -81,7 +81,7 @@ mod tests {
             let (_private_key, _compute_key, view_key, _address) = generate_account()?;
 
             Circuit::scope(format!("New {mode}"), || {
-                let candidate = ViewKey::<Circuit>::new(mode, view_key);
+                let candidate = ViewKey::<Circuit>::new(mode, view_key.clone());
                 assert_eq!(mode, candidate.eject_mode());
                 assert_eq!(view_key, candidate.eject_value());
                 assert_scope!(num_constants, num_public, num_private, num_constraints);
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Hash for Anemoi<E> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+
+    /// Returns the cryptographic hash for a list of field elements as input.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Construct the preimage: [ DOMAIN || LENGTH(INPUT) || INPUT ].
+        let mut preimage = Vec::with_capacity(2 + input.len());
+        preimage.push(self.domain.clone());
+        preimage.push(Field::constant(console::Field::from_u128(input.len() as u128)));
+        preimage.extend_from_slice(input);
+
+        // Absorb each element into `x`, and permute the state after each element.
+        let (mut x, mut y) = (Field::zero(), Field::zero());
+        for element in preimage {
+            (x, y) = self.permute(&(x + element), &y);
+        }
+        x
+    }
+}
+
+impl<E: Environment> Anemoi<E> {
+    /// Returns the compression of two field elements, with the Jive mode of Anemoi:
+    /// `left + right + u + v`, where `(u, v)` is the permutation of `(left, right)`.
+    #[inline]
+    pub fn compress(&self, left: &Field<E>, right: &Field<E>) -> Field<E> {
+        let (u, v) = self.permute(left, right);
+        left + right + u + v
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("AnemoiCircuit", 0);
+    const ITERATIONS: usize = 10;
+
+    fn check_hash(mode: Mode, num_inputs: usize, rng: &mut TestRng) -> Result<()> {
+        use console::Hash as H;
+
+        let native = console::Anemoi::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let anemoi = Anemoi::<Circuit>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs)
+                .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(rng))
+                .collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash(&native_input).expect("Failed to hash native input");
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Anemoi {mode} {i}"), || {
+                let candidate = anemoi.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                // Ensure the hash of constants is computed without constraints.
+                if mode.is_constant() || num_inputs == 0 {
+                    assert_eq!(0, Circuit::num_private_in_scope());
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    fn check_compress(mode: Mode, rng: &mut TestRng) -> Result<()> {
+        let native = console::Anemoi::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let anemoi = Anemoi::<Circuit>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            let native_left = console::Field::<<Circuit as Environment>::Network>::rand(rng);
+            let native_right = console::Field::<<Circuit as Environment>::Network>::rand(rng);
+            let left = Field::<Circuit>::new(mode, native_left);
+            let right = Field::<Circuit>::new(mode, native_right);
+
+            // Compute the native compression.
+            let expected = native.compress(&native_left, &native_right);
+
+            // Compute the circuit compression.
+            Circuit::scope(format!("Anemoi compress {mode} {i}"), || {
+                let candidate = anemoi.compress(&left, &right);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=4 {
+            check_hash(Mode::Constant, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=4 {
+            check_hash(Mode::Public, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=4 {
+            check_hash(Mode::Private, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_compress(Mode::Constant, &mut rng)?;
+        check_compress(Mode::Public, &mut rng)?;
+        check_compress(Mode::Private, &mut rng)
+    }
+
+    #[test]
+    fn test_compress_constraints_against_poseidon() -> Result<()> {
+        use crate::Poseidon;
+
+        let mut rng = TestRng::default();
+
+        let native = console::Anemoi::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let rounds = native.parameters().rounds as u64;
+        let anemoi = Anemoi::<Circuit>::constant(native);
+        let poseidon = Poseidon::<Circuit, 2>::constant(console::Poseidon::setup(DOMAIN)?);
+
+        let left = Field::<Circuit>::new(Mode::Private, console::Field::rand(&mut rng));
+        let right = Field::<Circuit>::new(Mode::Private, console::Field::rand(&mut rng));
+
+        // Each round of Anemoi costs 8 constraints: 2 for the squares of the open Flystel,
+        // 5 for the S-box `x^11`, and 1 to check the witness of the inverse S-box against it.
+        let anemoi_constraints = Circuit::scope("Anemoi compress", || {
+            anemoi.compress(&left, &right);
+            assert_eq!(8 * rounds, Circuit::num_private_in_scope());
+            Circuit::num_constraints_in_scope()
+        });
+        assert_eq!(8 * rounds, anemoi_constraints);
+
+        // Ensure the compression costs fewer constraints than a Poseidon hash of the same two elements.
+        let poseidon_constraints = Circuit::scope("Poseidon hash", || {
+            poseidon.hash(&[left.clone(), right.clone()]);
+            Circuit::num_constraints_in_scope()
+        });
+        assert!(
+            anemoi_constraints < poseidon_constraints,
+            "Anemoi compression costs {anemoi_constraints} constraints, Poseidon costs {poseidon_constraints}"
+        );
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+#[cfg(test)]
+use snarkvm_utilities::{TestRng, Uniform};
+
+use crate::Hash;
+use snarkvm_circuit_types::{environment::prelude::*, Field};
+
+#[derive(Clone)]
+pub struct Anemoi<E: Environment> {
+    /// The domain separator for the Anemoi hash function.
+    domain: Field<E>,
+    /// The exponent of the S-box of the open Flystel.
+    alpha: Field<E>,
+    /// The exponent of the inverse S-box, as little-endian limbs.
+    alpha_inv: Vec<u64>,
+    /// The coefficient of the quadratic functions of the open Flystel.
+    beta: Field<E>,
+    /// The constant of the second quadratic function of the open Flystel.
+    delta: Field<E>,
+    /// The round constants that are added to `x`, indexed by round.
+    c: Vec<Field<E>>,
+    /// The round constants that are added to `y`, indexed by round.
+    d: Vec<Field<E>>,
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for Anemoi<E> {
+    type Primitive = console::Anemoi<E::Network>;
+
+    fn new(_mode: Mode, anemoi: Self::Primitive) -> Self {
+        // Initialize the domain separator.
+        let domain = Field::constant(anemoi.domain());
+
+        // Initialize the Anemoi parameters.
+        let parameters = anemoi.parameters();
+        let alpha = Field::constant(console::Field::from_u128(parameters.alpha as u128));
+        // Cache the bits for the field element.
+        alpha.to_bits_le();
+        let alpha_inv = parameters.alpha_inv.clone();
+        let beta = Field::constant(console::Field::new(parameters.beta));
+        let delta = Field::constant(console::Field::new(parameters.delta));
+        let c = parameters.c.iter().map(|field| Field::constant(console::Field::new(*field))).collect();
+        let d = parameters.d.iter().map(|field| Field::constant(console::Field::new(*field))).collect();
+
+        Self { domain, alpha, alpha_inv, beta, delta, c, d }
+    }
+}
+
+impl<E: Environment> Anemoi<E> {
+    /// Returns the Anemoi permutation of `(x, y)`.
+    fn permute(&self, x: &Field<E>, y: &Field<E>) -> (Field<E>, Field<E>) {
+        let (mut x, mut y) = (x.clone(), y.clone());
+        for (c, d) in self.c.iter().zip_eq(&self.d) {
+            // Add the round constants.
+            x += c;
+            y += d;
+            // Apply the linear layer.
+            y += &x;
+            x += &y;
+            // Apply the open Flystel.
+            x -= &self.beta * y.square();
+            y -= self.inverse_sbox(&x);
+            x += &self.beta * y.square() + &self.delta;
+        }
+        // Apply the final linear layer.
+        y += &x;
+        x += &y;
+        (x, y)
+    }
+
+    /// Returns `x^(1 / alpha)`, which is witnessed and checked with the S-box `x^alpha`.
+    fn inverse_sbox(&self, x: &Field<E>) -> Field<E> {
+        let alpha_inv = &self.alpha_inv;
+        let output: Field<E> = witness!(|x| console::Field::new((*x).pow(alpha_inv)));
+        // Ensure `output^alpha` == `x`.
+        E::assert_eq((&output).pow(&self.alpha), x);
+        output
+    }
+}
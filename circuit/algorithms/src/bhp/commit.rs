@@ -34,7 +34,7 @@ mod tests {
     use anyhow::Result;
 
     const ITERATIONS: u64 = 100;
-    const DOMAIN: &str = "BHPCircuit0";
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("BHPCircuit", 0);
 
     fn check_commit<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
         mode: Mode,
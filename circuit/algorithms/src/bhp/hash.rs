@@ -33,7 +33,7 @@ mod tests {
     use anyhow::Result;
 
     const ITERATIONS: u64 = 100;
-    const DOMAIN: &str = "BHPCircuit0";
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("BHPCircuit", 0);
 
     fn check_hash<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
         mode: Mode,
@@ -85,4 +85,34 @@ mod tests {
     fn test_hash_private() -> Result<()> {
         check_hash::<32, 48>(Mode::Private, 470, 0, 8774, 8776)
     }
+
+    #[test]
+    fn test_hash_variable_length() -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP256::<Circuit>::new(Mode::Constant, native.clone());
+
+        let mut rng = TestRng::default();
+
+        // Sample inputs that span up to several iterations of the hasher.
+        for num_bits in [1, 261, 262, 522, 523, 1000] {
+            // Sample a random input.
+            let input = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input);
+
+            Circuit::scope(format!("BHP {num_bits}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
 }
@@ -80,7 +80,7 @@ mod tests {
     use anyhow::Result;
 
     const ITERATIONS: u64 = 100;
-    const DOMAIN: &str = "BHPCircuit0";
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("BHPCircuit", 0);
 
     macro_rules! check_hash_uncompressed {
         ($bhp:ident, $mode:ident, $num_bits:expr, ($num_constants:expr, $num_public:expr, $num_private:expr, $num_constraints:expr), $rng:expr) => {{
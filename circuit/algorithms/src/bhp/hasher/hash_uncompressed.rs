@@ -187,7 +187,7 @@ mod tests {
     use anyhow::Result;
 
     const ITERATIONS: usize = 100;
-    const MESSAGE: &str = "BHPCircuit0";
+    const MESSAGE: console::DomainSeparator = console::DomainSeparator::new("BHPCircuit", 0);
 
     fn check_hash_uncompressed<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
         mode: Mode,
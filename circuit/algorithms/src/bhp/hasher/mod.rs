@@ -107,7 +107,7 @@ mod tests {
     use anyhow::Result;
 
     const ITERATIONS: usize = 10;
-    const MESSAGE: &str = "BHPCircuit0";
+    const MESSAGE: console::DomainSeparator = console::DomainSeparator::new("BHPCircuit", 0);
 
     #[test]
     fn test_setup_constant() -> Result<()> {
@@ -19,6 +19,7 @@ mod commit;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
+mod rerandomize;
 
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::assert_scope;
@@ -92,7 +93,7 @@ mod tests {
     use anyhow::Result;
 
     const ITERATIONS: usize = 10;
-    const MESSAGE: &str = "BHPCircuit0";
+    const MESSAGE: console::DomainSeparator = console::DomainSeparator::new("BHPCircuit", 0);
 
     #[test]
     fn test_setup_constant() -> Result<()> {
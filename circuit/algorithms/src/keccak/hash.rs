@@ -286,6 +286,7 @@ mod tests {
     use super::*;
     use console::Rng;
     use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{bits_from_bytes_le, bytes_from_bits_le};
 
     const ITERATIONS: usize = 3;
 
@@ -416,6 +417,20 @@ mod tests {
         check_hash(Mode::Private, 1024, 0, 0, 152448, 152448, &mut rng);
     }
 
+    #[test]
+    fn test_keccak_256_ethereum_vector() {
+        // The Ethereum hash of "abc", which uses the original Keccak padding.
+        let expected = "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45";
+
+        let expected = (0..expected.len()).step_by(2).map(|i| u8::from_str_radix(&expected[i..i + 2], 16).unwrap());
+
+        let input = bits_from_bytes_le(b"abc").map(|bit| Boolean::<Circuit>::new(Mode::Private, bit));
+        let candidate = Keccak256::<Circuit>::new().hash(&input.collect::<Vec<_>>());
+        assert_eq!(bytes_from_bits_le(&candidate.eject_value()), expected.collect::<Vec<_>>());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
     #[test]
     fn test_keccak_224_equivalence() {
         check_equivalence!(console::Keccak224::default(), Keccak224::<Circuit>::new());
@@ -15,6 +15,9 @@
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 
+pub mod anemoi;
+pub use anemoi::*;
+
 pub mod bhp;
 pub use bhp::*;
 
@@ -30,5 +33,17 @@ pub use pedersen::*;
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod secp256k1;
+pub use secp256k1::*;
+
+pub mod sha256;
+pub use sha256::*;
+
+pub mod sinsemilla;
+pub use sinsemilla::*;
+
+pub mod sphincs_plus;
+pub use sphincs_plus::*;
+
 pub mod traits;
 pub use traits::*;
@@ -54,7 +54,7 @@ mod tests {
     use snarkvm_utilities::{TestRng, Uniform};
 
     const ITERATIONS: u64 = 10;
-    const MESSAGE: &str = "PedersenCircuit0";
+    const MESSAGE: console::DomainSeparator = console::DomainSeparator::new("PedersenCircuit", 0);
     const NUM_BITS_MULTIPLIER: u8 = 8;
 
     fn check_hash<const NUM_BITS: u8>(mode: Mode, rng: &mut TestRng) {
@@ -108,7 +108,7 @@ mod tests {
     use snarkvm_utilities::{TestRng, Uniform};
 
     const ITERATIONS: u64 = 10;
-    const MESSAGE: &str = "PedersenCircuit0";
+    const MESSAGE: console::DomainSeparator = console::DomainSeparator::new("PedersenCircuit", 0);
     const NUM_BITS_MULTIPLIER: u8 = 8;
 
     fn check_hash_uncompressed<const NUM_BITS: u8>(mode: Mode, rng: &mut TestRng) {
@@ -202,7 +202,8 @@ mod tests {
     #[test]
     fn test_pedersen64_homomorphism_private() {
         // Initialize Pedersen64.
-        let pedersen = Pedersen64::constant(console::Pedersen64::setup("Pedersen64HomomorphismTest"));
+        let domain = console::DomainSeparator::new("Pedersen64HomomorphismTest", 0);
+        let pedersen = Pedersen64::constant(console::Pedersen64::setup(domain));
 
         let mut rng = TestRng::default();
 
@@ -265,7 +266,8 @@ mod tests {
         }
 
         // Check Pedersen128.
-        let pedersen128 = Pedersen128::constant(console::Pedersen128::setup("Pedersen128HomomorphismTest"));
+        let domain = console::DomainSeparator::new("Pedersen128HomomorphismTest", 0);
+        let pedersen128 = Pedersen128::constant(console::Pedersen128::setup(domain));
         check_pedersen_homomorphism(&pedersen128);
     }
 }
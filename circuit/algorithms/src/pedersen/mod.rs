@@ -16,6 +16,7 @@ mod commit;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
+mod rerandomize;
 
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::{assert_count, assert_output_mode, assert_scope};
@@ -61,7 +62,7 @@ mod tests {
     use snarkvm_circuit_types::environment::Circuit;
 
     const ITERATIONS: u64 = 10;
-    const MESSAGE: &str = "PedersenCircuit0";
+    const MESSAGE: console::DomainSeparator = console::DomainSeparator::new("PedersenCircuit", 0);
     const NUM_BITS_MULTIPLIER: u8 = 8;
 
     fn check_setup<const NUM_BITS: u8>(num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the given commitment, rerandomized by `delta_randomness`.
+    /// The result is the commitment to the same input with randomizer `randomizer + delta_randomness`,
+    /// so the commitment is refreshed without revealing or changing the committed input.
+    pub fn rerandomize(&self, commitment: &Group<E>, delta_randomness: &Scalar<E>) -> Group<E> {
+        // Compute h^delta.
+        delta_randomness
+            .to_bits_le()
+            .iter()
+            .zip_eq(&self.random_base)
+            .map(|(bit, power)| Group::ternary(bit, power, &Group::zero()))
+            .fold(commitment.clone(), |acc, x| acc + x)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: console::DomainSeparator = console::DomainSeparator::new("PedersenCircuit", 0);
+
+    fn check_rerandomize(mode: Mode) {
+        use console::CommitUncompressed as C;
+
+        // Initialize the Pedersen hash.
+        let native = console::Pedersen64::<<Circuit as Environment>::Network>::setup(MESSAGE);
+        let circuit = Pedersen64::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input, randomizer, and delta randomness.
+            let input = (0..64).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let randomizer = Uniform::rand(&mut rng);
+            let delta_randomness = Uniform::rand(&mut rng);
+            // Compute the expected commitment.
+            let commitment = native.commit_uncompressed(&input, &randomizer).expect("Failed to commit native input");
+            let expected = native.rerandomize(&commitment, &delta_randomness);
+            // Prepare the circuit commitment and delta randomness.
+            let circuit_commitment = Group::<Circuit>::new(mode, commitment);
+            let circuit_delta_randomness = Scalar::<Circuit>::new(mode, delta_randomness);
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                let candidate = circuit.rerandomize(&circuit_commitment, &circuit_delta_randomness);
+                assert_eq!(expected, candidate.eject_value());
+                // Ensure the rerandomized commitment opens to the same input with the combined randomizer.
+                let combined = native.commit_uncompressed(&input, &(randomizer + delta_randomness));
+                assert_eq!(expected, combined.expect("Failed to commit native input"));
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_rerandomize_constant() {
+        check_rerandomize(Mode::Constant)
+    }
+
+    #[test]
+    fn test_rerandomize_public() {
+        check_rerandomize(Mode::Public)
+    }
+
+    #[test]
+    fn test_rerandomize_private() {
+        check_rerandomize(Mode::Private)
+    }
+}
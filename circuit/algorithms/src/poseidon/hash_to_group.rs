@@ -44,7 +44,7 @@ mod tests {
     use anyhow::Result;
 
     const ITERATIONS: u64 = 100;
-    const DOMAIN: &str = "PoseidonCircuit0";
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("PoseidonCircuit", 0);
 
     macro_rules! check_hash_to_group {
         ($poseidon:ident, $mode:ident, $num_fields:expr, ($num_constants:expr, $num_public:expr, $num_private:expr, $num_constraints:expr)) => {{
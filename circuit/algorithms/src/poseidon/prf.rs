@@ -39,7 +39,7 @@ mod tests {
 
     use anyhow::Result;
 
-    const DOMAIN: &str = "PoseidonCircuit0";
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("PoseidonCircuit", 0);
     const ITERATIONS: usize = 10;
     const RATE: usize = 4;
 
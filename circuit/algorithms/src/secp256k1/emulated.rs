@@ -0,0 +1,414 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of bits in a range-checked carry, which is offset by `2^(CARRY_SIZE - 1)` to be non-negative.
+const CARRY_SIZE: usize = 80;
+/// The number of bits in a range-checked quotient, which is offset by `2^(QUOTIENT_SIZE - 1)` to be non-negative.
+/// This bounds the relations to sums of a few products of elements.
+const QUOTIENT_SIZE: usize = 264;
+
+/// The modulus of an emulated field.
+pub(super) trait Modulus {
+    /// The little-endian limbs of the modulus.
+    const LIMBS: [u64; NUM_LIMBS];
+
+    /// Returns the modulus as an integer.
+    fn modulus() -> BigUint {
+        Self::LIMBS.iter().rev().fold(BigUint::default(), |acc, limb| (acc << LIMB_SIZE) + *limb)
+    }
+}
+
+/// The modulus `p` of the base field of secp256k1.
+pub(super) struct BaseModulus;
+
+impl Modulus for BaseModulus {
+    const LIMBS: [u64; NUM_LIMBS] = [0xfffffffefffffc2f, 0xffffffffffffffff, 0xffffffffffffffff, 0xffffffffffffffff];
+}
+
+/// The order `n` of the prime-order group of secp256k1.
+pub(super) struct ScalarModulus;
+
+impl Modulus for ScalarModulus {
+    const LIMBS: [u64; NUM_LIMBS] = [0xbfd25e8cd0364141, 0xbaaedce6af48a03b, 0xfffffffffffffffe, 0xffffffffffffffff];
+}
+
+/// An element of the field of integers modulo `M`, as `NUM_LIMBS` little-endian limbs of `LIMB_SIZE` bits.
+///
+/// Each limb is less than `2^LIMB_SIZE`, however the element is not necessarily reduced modulo `M`.
+pub(super) struct Emulated<E: Environment, M: Modulus> {
+    limbs: Vec<Field<E>>,
+    _modulus: PhantomData<M>,
+}
+
+impl<E: Environment, M: Modulus> Emulated<E, M> {
+    /// Initializes a new element with the given mode and value, which is less than `2^ELEMENT_SIZE`.
+    /// The limbs of a non-constant element are range-checked.
+    pub(super) fn new(mode: Mode, value: &BigUint) -> Self {
+        let limbs = to_limbs(value, NUM_LIMBS)
+            .into_iter()
+            .map(|limb| {
+                let limb = Field::new(mode, console::Field::from_u64(limb));
+                if !mode.is_constant() {
+                    limb.to_lower_bits_le(LIMB_SIZE);
+                }
+                limb
+            })
+            .collect();
+        Self { limbs, _modulus: PhantomData }
+    }
+
+    /// Initializes a constant element from the given value, which is reduced modulo `M`.
+    pub(super) fn constant(value: &BigUint) -> Self {
+        Self::new(Mode::Constant, &(value % M::modulus()))
+    }
+
+    /// Initializes an element from `ELEMENT_SIZE` bits, as big-endian bytes in little-endian bit order.
+    pub(super) fn from_bits(bits: &[Boolean<E>]) -> Self {
+        if bits.len() != ELEMENT_SIZE {
+            E::halt(format!("Expected {ELEMENT_SIZE} bits for an emulated element, found {} bits", bits.len()))
+        }
+        // Reverse the order of the bytes, so that the bits are in little-endian order.
+        let bits_le = bits.chunks(8).rev().flatten().cloned().collect::<Vec<_>>();
+        Self { limbs: bits_le.chunks(LIMB_SIZE).map(Field::from_bits_le).collect(), _modulus: PhantomData }
+    }
+
+    /// Returns the limbs of the element.
+    pub(super) fn limbs(&self) -> &[Field<E>] {
+        &self.limbs
+    }
+
+    /// Returns the (unreduced) value of the element.
+    pub(super) fn value(&self) -> BigUint {
+        evaluate(&self.limbs).magnitude().clone()
+    }
+
+    /// Returns the mode of an element witnessed from the given elements.
+    pub(super) fn witness_mode(elements: &[&Self]) -> Mode {
+        match elements.iter().all(|element| element.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+
+    /// Returns `true` if all limbs of the element are constant.
+    pub(super) fn is_constant(&self) -> bool {
+        self.limbs.iter().all(|limb| limb.is_constant())
+    }
+
+    /// Returns the element as an element of another emulated field, without reducing it.
+    pub(super) fn cast<N: Modulus>(&self) -> Emulated<E, N> {
+        Emulated { limbs: self.limbs.clone(), _modulus: PhantomData }
+    }
+
+    /// Returns `self * other mod M`.
+    pub(super) fn mul(&self, other: &Self) -> Self {
+        let value = (self.value() * other.value()) % M::modulus();
+        let output = Self::new(Self::witness_mode(&[self, other]), &value);
+
+        // Ensure `self * other - output = 0 mod M`.
+        Self::enforce_zero_mod(&sub(&mul(&self.limbs, &other.limbs), &output.limbs));
+        output
+    }
+
+    /// Returns `self^(-1) mod M`, and enforces that `self` is invertible.
+    pub(super) fn inverse(&self) -> Self {
+        let output = Self::new(Self::witness_mode(&[self]), &inverse_mod(&self.value(), &M::modulus()));
+
+        // Ensure `self * output - 1 = 0 mod M`.
+        Self::enforce_zero_mod(&sub(&mul(&self.limbs, &output.limbs), &[Field::one()]));
+        output
+    }
+
+    /// Returns `self mod M`, as an element that is less than `M`.
+    pub(super) fn reduce(&self) -> Self {
+        let output = Self::new(Self::witness_mode(&[self]), &(self.value() % M::modulus()));
+
+        // Ensure `self - output = 0 mod M`.
+        Self::enforce_zero_mod(&sub(&self.limbs, &output.limbs));
+        output.enforce_canonical();
+        output
+    }
+
+    /// Enforces that the element is less than `M`.
+    pub(super) fn enforce_canonical(&self) {
+        let (value, modulus_minus_one) = (self.value(), M::modulus() - 1u32);
+
+        // Witness `M - 1 - self`, whose limbs are range-checked to be non-negative.
+        let difference = match value <= modulus_minus_one {
+            true => &modulus_minus_one - value,
+            false => BigUint::default(),
+        };
+        let difference = Self::new(Self::witness_mode(&[self]), &difference);
+
+        // Ensure `self + difference - (M - 1) = 0`.
+        enforce_zero(&sub(&add(&self.limbs, &difference.limbs), &constant(&modulus_minus_one)));
+    }
+
+    /// Returns `true` if the element is zero, for an element that is less than `M`.
+    pub(super) fn is_zero(&self) -> Boolean<E> {
+        // As the limbs are non-negative and small, their sum is zero if and only if all limbs are zero.
+        self.limbs.iter().fold(Field::zero(), |sum, limb| sum + limb).is_equal(&Field::zero())
+    }
+
+    /// Returns `true` if the elements are equal, for elements that are less than `M`.
+    pub(super) fn is_equal(&self, other: &Self) -> Boolean<E> {
+        self.limbs.iter().zip_eq(&other.limbs).fold(Boolean::constant(true), |acc, (a, b)| acc & a.is_equal(b))
+    }
+
+    /// Returns the (unreduced) value of the element, as `ELEMENT_SIZE` little-endian bits.
+    pub(super) fn to_bits_le(&self) -> Vec<Boolean<E>> {
+        self.limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_SIZE)).collect()
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub(super) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        let limbs = first.limbs.iter().zip_eq(&second.limbs).map(|(a, b)| Field::ternary(condition, a, b)).collect();
+        Self { limbs, _modulus: PhantomData }
+    }
+
+    /// Enforces that the given polynomial in the limbs evaluates to a multiple of `M` at `2^LIMB_SIZE`.
+    pub(super) fn enforce_zero_mod(polynomial: &[Field<E>]) {
+        let modulus = M::modulus();
+        let value = evaluate(polynomial);
+
+        // If the polynomial is constant, check it natively.
+        if polynomial.iter().all(|coefficient| coefficient.is_constant()) {
+            if (value % BigInt::from(modulus)).magnitude().bits() != 0 {
+                E::halt("The constant relation of emulated elements is not satisfied")
+            }
+            return;
+        }
+
+        // Witness the quotient, which may be negative, with an offset of `2^(QUOTIENT_SIZE - 1)`.
+        let offset = BigUint::from(1u32) << (QUOTIENT_SIZE - 1);
+        let quotient = value / BigInt::from(modulus.clone()) + BigInt::from(offset.clone());
+        let quotient =
+            to_limbs(&quotient.to_biguint().unwrap_or_default(), (QUOTIENT_SIZE + LIMB_SIZE - 1) / LIMB_SIZE)
+                .into_iter()
+                .enumerate()
+                .map(|(i, limb)| {
+                    let limb = Field::new(Mode::Private, console::Field::from_u64(limb));
+                    limb.to_lower_bits_le(LIMB_SIZE.min(QUOTIENT_SIZE - i * LIMB_SIZE));
+                    limb
+                })
+                .collect::<Vec<_>>();
+
+        // Ensure `polynomial - (quotient - offset) * M = 0`.
+        let product = mul(&quotient, &constant(&modulus));
+        enforce_zero(&add(&sub(polynomial, &product), &constant(&(offset * modulus))));
+    }
+}
+
+impl<E: Environment, M: Modulus> Clone for Emulated<E, M> {
+    fn clone(&self) -> Self {
+        Self { limbs: self.limbs.clone(), _modulus: PhantomData }
+    }
+}
+
+/// Enforces that the given polynomial evaluates to zero at `2^LIMB_SIZE`.
+///
+/// The coefficients are combined in pairs, and a carry of at most `2^(CARRY_SIZE - 1)` in absolute value
+/// is witnessed between each pair. This requires the coefficients to be less than `2^(CARRY_SIZE + LIMB_SIZE - 2)`
+/// in absolute value, which holds for sums of a few products of limbs.
+fn enforce_zero<E: Environment>(polynomial: &[Field<E>]) {
+    // If the polynomial is constant, check it natively.
+    if polynomial.iter().all(|coefficient| coefficient.is_constant()) {
+        if evaluate(polynomial).magnitude().bits() != 0 {
+            E::halt("The constant relation of emulated elements is not satisfied")
+        }
+        return;
+    }
+
+    let shift = Field::constant(console::Field::from_u128(1u128 << LIMB_SIZE));
+    let pair_shift = &shift * &shift;
+    let carry_offset = Field::constant(console::Field::from_u128(1u128 << (CARRY_SIZE - 1)));
+
+    let mut carry = Field::zero();
+    let mut pairs = polynomial.chunks(2).peekable();
+    while let Some(pair) = pairs.next() {
+        let sum = match pair {
+            [low, high] => low + high * &shift,
+            _ => pair[0].clone(),
+        } + &carry;
+
+        match pairs.peek() {
+            // Witness the carry of the pair, and ensure `sum = carry * 2^(2 * LIMB_SIZE)`.
+            Some(_) => {
+                carry = Field::new(Mode::Private, from_integer::<E>(&(evaluate(&[sum.clone()]) >> (2 * LIMB_SIZE))));
+                (&carry + &carry_offset).to_lower_bits_le(CARRY_SIZE);
+                E::assert_eq(sum, &carry * &pair_shift);
+            }
+            // Ensure the sum of the last pair is zero.
+            None => E::assert_eq(sum, Field::<E>::zero()),
+        }
+    }
+}
+
+/// Returns the value of the given polynomial at `2^LIMB_SIZE`, where each coefficient is a signed integer.
+fn evaluate<E: Environment>(polynomial: &[Field<E>]) -> BigInt {
+    polynomial.iter().rev().fold(BigInt::default(), |acc, coefficient| (acc << LIMB_SIZE) + to_integer(coefficient))
+}
+
+/// Returns the given field element as a signed integer of the smallest absolute value.
+fn to_integer<E: Environment>(field: &Field<E>) -> BigInt {
+    let to_biguint = |value: console::Field<E::Network>| {
+        value.to_bits_le().iter().rev().fold(BigUint::default(), |acc, bit| (acc << 1) + u32::from(*bit))
+    };
+    let value = field.eject_value();
+    let (positive, negative) = (to_biguint(value), to_biguint(-value));
+    match positive <= negative {
+        true => BigInt::from(positive),
+        false => -BigInt::from(negative),
+    }
+}
+
+/// Returns the given signed integer, which is less than `2^128` in absolute value, as a field element.
+fn from_integer<E: Environment>(value: &BigInt) -> console::Field<E::Network> {
+    let magnitude = console::Field::from_u128(u128::try_from(value.magnitude()).unwrap_or_default());
+    match value.sign() {
+        Sign::Minus => -magnitude,
+        _ => magnitude,
+    }
+}
+
+/// Returns the lowest `num_limbs` little-endian limbs of the given value.
+fn to_limbs(value: &BigUint, num_limbs: usize) -> Vec<u64> {
+    let digits = value.to_u64_digits();
+    (0..num_limbs).map(|i| digits.get(i).copied().unwrap_or_default()).collect()
+}
+
+/// Returns `a^(-1) mod m` for a prime modulus `m`, or zero if `a` is zero modulo `m`.
+pub(super) fn inverse_mod(a: &BigUint, modulus: &BigUint) -> BigUint {
+    a.modpow(&(modulus - 2u32), modulus)
+}
+
+/// Returns the given integer as a constant polynomial in the limbs.
+pub(super) fn constant<E: Environment>(value: &BigUint) -> Vec<Field<E>> {
+    value.to_u64_digits().into_iter().map(|digit| Field::constant(console::Field::from_u64(digit))).collect()
+}
+
+/// Returns the sum of the given polynomials.
+pub(super) fn add<E: Environment>(a: &[Field<E>], b: &[Field<E>]) -> Vec<Field<E>> {
+    (0..a.len().max(b.len()))
+        .map(|i| match (a.get(i), b.get(i)) {
+            (Some(a), Some(b)) => a + b,
+            (Some(c), None) | (None, Some(c)) => c.clone(),
+            (None, None) => unreachable!("The index is within the length of a polynomial"),
+        })
+        .collect()
+}
+
+/// Returns the difference of the given polynomials.
+pub(super) fn sub<E: Environment>(a: &[Field<E>], b: &[Field<E>]) -> Vec<Field<E>> {
+    (0..a.len().max(b.len()))
+        .map(|i| match (a.get(i), b.get(i)) {
+            (Some(a), Some(b)) => a - b,
+            (Some(a), None) => a.clone(),
+            (None, Some(b)) => -b,
+            (None, None) => unreachable!("The index is within the length of a polynomial"),
+        })
+        .collect()
+}
+
+/// Returns the product of the given polynomials.
+pub(super) fn mul<E: Environment>(a: &[Field<E>], b: &[Field<E>]) -> Vec<Field<E>> {
+    let mut output = vec![Field::zero(); a.len() + b.len() - 1];
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            output[i + j] += a * b;
+        }
+    }
+    output
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    const ITERATIONS: usize = 10;
+
+    type Scalar = Emulated<Circuit, ScalarModulus>;
+
+    /// Samples a random value, which is not necessarily reduced.
+    fn sample(rng: &mut TestRng) -> BigUint {
+        BigUint::from_bytes_le(&rng.gen::<[u8; 32]>())
+    }
+
+    fn check_operations(mode: Mode, rng: &mut TestRng) {
+        let n = ScalarModulus::modulus();
+
+        for i in 0..ITERATIONS {
+            let (a, b) = (sample(rng), sample(rng));
+
+            Circuit::scope(format!("Emulated {mode} {i}"), || {
+                let (first, second) = (Scalar::new(mode, &a), Scalar::new(mode, &b));
+                assert_eq!(a, first.value());
+
+                assert_eq!((&a * &b) % &n, first.mul(&second).value());
+                assert_eq!(&a % &n, first.reduce().value());
+                assert_eq!(BigUint::from(1u32), (&a * first.inverse().value()) % &n);
+                // Ensure the operations on constants are computed without constraints.
+                if mode.is_constant() {
+                    assert_eq!(0, Circuit::num_private_in_scope());
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_operations_constant() {
+        check_operations(Mode::Constant, &mut TestRng::default());
+    }
+
+    #[test]
+    fn test_operations_public() {
+        check_operations(Mode::Public, &mut TestRng::default());
+    }
+
+    #[test]
+    fn test_operations_private() {
+        check_operations(Mode::Private, &mut TestRng::default());
+    }
+
+    #[test]
+    fn test_incorrect_product_is_unsatisfied() {
+        let rng = &mut TestRng::default();
+        let n = ScalarModulus::modulus();
+
+        let (a, b) = (sample(rng), sample(rng));
+        let (first, second) = (Scalar::new(Mode::Private, &a), Scalar::new(Mode::Private, &b));
+        let product = Scalar::new(Mode::Private, &((&a * &b + 1u32) % &n));
+        Scalar::enforce_zero_mod(&sub(&mul(first.limbs(), second.limbs()), product.limbs()));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_enforce_canonical() {
+        let n = ScalarModulus::modulus();
+
+        for (value, expected) in [(&n - 1u32, true), (n.clone(), false), (&n + 1u32, false)] {
+            Scalar::new(Mode::Private, &value).enforce_canonical();
+            assert_eq!(expected, Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod emulated;
+use emulated::*;
+
+mod point;
+use point::Point;
+
+mod verify;
+
+#[cfg(test)]
+use snarkvm_utilities::TestRng;
+
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+
+use core::marker::PhantomData;
+use num_bigint::{BigInt, BigUint, Sign};
+
+/// The number of bits in a limb of an emulated field element.
+const LIMB_SIZE: usize = 64;
+/// The number of limbs in an emulated field element.
+const NUM_LIMBS: usize = 4;
+/// The number of bits in an emulated field element.
+const ELEMENT_SIZE: usize = LIMB_SIZE * NUM_LIMBS;
+
+/// ECDSA signature verification on secp256k1, as used by Bitcoin and Ethereum.
+///
+/// The base field and the scalar field of secp256k1 are emulated in the base field of the circuit:
+///   - An element is represented by four 64-bit limbs, which are range-checked when they are witnessed,
+///     and is not necessarily reduced, unless it is compared for equality.
+///   - A relation such as `a * b = c mod p` is enforced by witnessing the quotient `q` of `a * b - c` by `p`,
+///     and checking `a * b - c - q * p = 0` as an identity of polynomials in the limbs, evaluated at `2^64`.
+///     The evaluation is enforced two limbs at a time, with a range-checked carry between each pair of limbs.
+///   - Points are in affine coordinates, so that each addition and doubling only witnesses the slope and the result.
+///     The additions are incomplete, and the circuit is unsatisfiable if an exceptional case is reached.
+///   - The double scalar multiplication `u_1 G + u_2 Q` uses Shamir's trick, starting from an offset point
+///     so that the accumulator does not reach the point at infinity for honest inputs.
+///
+/// This gadget is not exposed as an instruction yet, so it is only available to circuits that are
+/// constructed in Rust, and not to Aleo programs. Signatures must be produced outside of snarkVM,
+/// as the native implementation in the console only verifies signatures.
+#[derive(Clone, Debug, Default)]
+pub struct Secp256k1<E: Environment> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment> Secp256k1<E> {
+    /// Initializes a new instance of secp256k1 signature verification.
+    pub fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An element of the base field of secp256k1.
+type Base<E> = Emulated<E, BaseModulus>;
+
+/// An affine point on secp256k1, whose coordinates are emulated.
+pub(super) struct Point<E: Environment> {
+    x: Base<E>,
+    y: Base<E>,
+}
+
+impl<E: Environment> Point<E> {
+    /// Initializes a constant point from the given point, which is not the point at infinity.
+    pub(super) fn constant(point: &console::Secp256k1Point) -> Self {
+        match point.to_coordinates() {
+            Some((x, y)) => {
+                Self { x: Base::constant(&BigUint::from_bytes_be(&x)), y: Base::constant(&BigUint::from_bytes_be(&y)) }
+            }
+            None => E::halt("The point at infinity cannot be represented in affine coordinates"),
+        }
+    }
+
+    /// Initializes a point from the bits of its coordinates `x || y`, and enforces that it is on the curve.
+    pub(super) fn from_bits(bits: &[Boolean<E>]) -> Self {
+        if bits.len() != 2 * ELEMENT_SIZE {
+            E::halt(format!("Expected {} bits for a secp256k1 point, found {} bits", 2 * ELEMENT_SIZE, bits.len()))
+        }
+        let (x, y) = (Base::from_bits(&bits[..ELEMENT_SIZE]), Base::from_bits(&bits[ELEMENT_SIZE..]));
+
+        // Ensure `y^2 - x^3 - 7 = 0 mod p`.
+        let x_squared = x.mul(&x);
+        let seven = Field::constant(console::Field::from_u8(7));
+        Base::enforce_zero_mod(&sub(&sub(&mul(y.limbs(), y.limbs()), &mul(x_squared.limbs(), x.limbs())), &[seven]));
+
+        Self { x, y }
+    }
+
+    /// Returns the x-coordinate of the point.
+    pub(super) fn x(&self) -> &Base<E> {
+        &self.x
+    }
+
+    /// Returns the sum of the two points, and enforces that their x-coordinates are distinct.
+    pub(super) fn add(&self, other: &Self) -> Self {
+        let p = BaseModulus::modulus();
+        let (x_1, y_1, x_2, y_2) = (self.x.value(), self.y.value(), other.x.value(), other.y.value());
+        let mode = Base::witness_mode(&[&self.x, &self.y, &other.x, &other.y]);
+
+        // Ensure `x_2 - x_1` is invertible, as otherwise the slope below is unconstrained.
+        let difference = sub(other.x.limbs(), self.x.limbs());
+        let difference_inverse = Base::new(mode, &inverse_mod(&((&x_2 + &p - &x_1 % &p) % &p), &p));
+        Base::enforce_zero_mod(&sub(&mul(&difference, difference_inverse.limbs()), &[Field::one()]));
+
+        // Witness `λ = (y_2 - y_1) / (x_2 - x_1)`, and ensure `λ (x_2 - x_1) - (y_2 - y_1) = 0 mod p`.
+        let lambda = Base::new(mode, &(((&y_2 + &p - &y_1 % &p) * difference_inverse.value()) % &p));
+        Base::enforce_zero_mod(&sub(&mul(lambda.limbs(), &difference), &sub(other.y.limbs(), self.y.limbs())));
+
+        self.from_lambda(&lambda, &other.x)
+    }
+
+    /// Returns the sum of the point with itself.
+    pub(super) fn double(&self) -> Self {
+        let p = BaseModulus::modulus();
+        let (x, y) = (self.x.value(), self.y.value());
+        let mode = Base::witness_mode(&[&self.x, &self.y]);
+
+        // Witness `λ = 3x^2 / 2y`, and ensure `2y λ - 3x^2 = 0 mod p`.
+        // As the group has odd order, `y` is non-zero for any point on the curve.
+        let lambda = Base::new(mode, &((3u32 * &x * &x * inverse_mod(&(2u32 * &y), &p)) % &p));
+        let two_y = add(self.y.limbs(), self.y.limbs());
+        let three_x = add(&add(self.x.limbs(), self.x.limbs()), self.x.limbs());
+        Base::enforce_zero_mod(&sub(&mul(lambda.limbs(), &two_y), &mul(&three_x, self.x.limbs())));
+
+        self.from_lambda(&lambda, &self.x)
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub(super) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self { x: Base::ternary(condition, &first.x, &second.x), y: Base::ternary(condition, &first.y, &second.y) }
+    }
+
+    /// Returns the negation of the third point on the line of slope `λ` through the point and a point
+    /// with x-coordinate `x_2`.
+    fn from_lambda(&self, lambda: &Base<E>, x_2: &Base<E>) -> Self {
+        let p = BaseModulus::modulus();
+        let (x_1, y_1, lambda_value) = (self.x.value() % &p, self.y.value() % &p, lambda.value());
+        let mode = Base::witness_mode(&[&self.x, &self.y, lambda, x_2]);
+
+        // Witness `x_3 = λ^2 - x_1 - x_2`, and ensure `λ^2 - x_1 - x_2 - x_3 = 0 mod p`.
+        let x_3 = (&lambda_value * &lambda_value + 2u32 * &p - &x_1 - x_2.value() % &p) % &p;
+        let x_3 = Base::new(mode, &x_3);
+        let lambda_squared = mul(lambda.limbs(), lambda.limbs());
+        Base::enforce_zero_mod(&sub(&sub(&sub(&lambda_squared, self.x.limbs()), x_2.limbs()), x_3.limbs()));
+
+        // Witness `y_3 = λ (x_1 - x_3) - y_1`, and ensure `λ (x_1 - x_3) - y_1 - y_3 = 0 mod p`.
+        let y_3 = (&lambda_value * ((&x_1 + &p - x_3.value()) % &p) + &p - &y_1) % &p;
+        let y_3 = Base::new(mode, &y_3);
+        let product = mul(lambda.limbs(), &sub(self.x.limbs(), x_3.limbs()));
+        Base::enforce_zero_mod(&sub(&sub(&product, self.y.limbs()), y_3.limbs()));
+
+        Self { x: x_3, y: y_3 }
+    }
+}
+
+impl<E: Environment> Clone for Point<E> {
+    fn clone(&self) -> Self {
+        Self { x: self.x.clone(), y: self.y.clone() }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::bits_from_bytes_le;
+
+    const ITERATIONS: usize = 5;
+
+    /// Samples a random point.
+    fn sample_point(rng: &mut TestRng) -> console::Secp256k1Point {
+        console::Secp256k1Point::generator().mul(&rng.gen())
+    }
+
+    /// Injects the given point with the given mode.
+    fn inject(mode: Mode, point: &console::Secp256k1Point) -> Point<Circuit> {
+        let (x, y) = point.to_coordinates().unwrap();
+        Point { x: Base::new(mode, &BigUint::from_bytes_be(&x)), y: Base::new(mode, &BigUint::from_bytes_be(&y)) }
+    }
+
+    /// Ejects the given point.
+    fn eject(point: &Point<Circuit>) -> console::Secp256k1Point {
+        let p = BaseModulus::modulus();
+        let to_bytes_be = |value: BigUint| {
+            let bytes = (value % &p).to_bytes_be();
+            let mut output = [0u8; 32];
+            output[32 - bytes.len()..].copy_from_slice(&bytes);
+            output
+        };
+        console::Secp256k1Point::from_coordinates(&to_bytes_be(point.x.value()), &to_bytes_be(point.y.value())).unwrap()
+    }
+
+    fn check_add_and_double(mode: Mode, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            let (a, b) = (sample_point(rng), sample_point(rng));
+
+            Circuit::scope(format!("Point {mode} {i}"), || {
+                let (first, second) = (inject(mode, &a), inject(mode, &b));
+                assert_eq!(&a + &b, eject(&first.add(&second)));
+                assert_eq!(a.double(), eject(&first.double()));
+                // Ensure the operations on constants are computed without constraints.
+                if mode.is_constant() {
+                    assert_eq!(0, Circuit::num_private_in_scope());
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_add_and_double_constant() {
+        check_add_and_double(Mode::Constant, &mut TestRng::default());
+    }
+
+    #[test]
+    fn test_add_and_double_private() {
+        check_add_and_double(Mode::Private, &mut TestRng::default());
+    }
+
+    #[test]
+    fn test_add_same_point_is_unsatisfied() {
+        let point = inject(Mode::Private, &sample_point(&mut TestRng::default()));
+        point.add(&point);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_bits() {
+        let (x, mut y) = sample_point(&mut TestRng::default()).to_coordinates().unwrap();
+        let inject_bits = |bytes: &[u8]| {
+            bits_from_bytes_le(bytes).map(|bit| Boolean::<Circuit>::new(Mode::Private, bit)).collect::<Vec<_>>()
+        };
+
+        Point::from_bits(&inject_bits(&[x, y].concat()));
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure a point that is not on the curve is rejected.
+        y[31] ^= 1;
+        Point::from_bits(&inject_bits(&[x, y].concat()));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
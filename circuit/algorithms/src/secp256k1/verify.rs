@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Secp256k1<E> {
+    /// Returns `true` if the ECDSA signature is valid for the given message hash and public key.
+    ///
+    /// The public key is given as the bits of its coordinates `x || y`, and the signature as the bits of `r || s`.
+    /// Each integer is given as 32 big-endian bytes in little-endian bit order, matching the output of the
+    /// SHA-256 and Keccak hash functions, so that the message hash can be computed in the circuit.
+    ///
+    /// Rather than returning `false`, the circuit is unsatisfiable if the public key is not on the curve,
+    /// if `r` or `s` is not in `[1, n)`, or if `u_1 G + u_2 Q` is the point at infinity. It is also unsatisfiable
+    /// if the double scalar multiplication reaches an exceptional case, which happens with negligible probability.
+    pub fn verify(
+        &self,
+        public_key: &[Boolean<E>],
+        message_hash: &[Boolean<E>],
+        signature: &[Boolean<E>],
+    ) -> Boolean<E> {
+        if signature.len() != 2 * ELEMENT_SIZE {
+            E::halt(format!(
+                "Expected {} bits for a secp256k1 signature, found {} bits",
+                2 * ELEMENT_SIZE,
+                signature.len()
+            ))
+        }
+
+        let public_key = Point::from_bits(public_key);
+        let message_hash = Emulated::<E, ScalarModulus>::from_bits(message_hash);
+        let r = Emulated::<E, ScalarModulus>::from_bits(&signature[..ELEMENT_SIZE]);
+        let s = Emulated::<E, ScalarModulus>::from_bits(&signature[ELEMENT_SIZE..]);
+
+        // Ensure `r` and `s` are in [1, n). The inverse of `s` below ensures `s` is non-zero.
+        r.enforce_canonical();
+        s.enforce_canonical();
+        E::assert(!r.is_zero());
+
+        // Compute `u_1 := z s^(-1) mod n` and `u_2 := r s^(-1) mod n`.
+        let s_inverse = s.inverse();
+        let u_1 = message_hash.mul(&s_inverse);
+        let u_2 = r.mul(&s_inverse);
+
+        // Compute `R := u_1 G + u_2 Q`, and check `x(R) mod n == r`.
+        let candidate = Self::double_scalar_multiply(&u_1.to_bits_le(), &u_2.to_bits_le(), &public_key);
+        candidate.x().enforce_canonical();
+        candidate.x().cast::<ScalarModulus>().reduce().is_equal(&r)
+    }
+
+    /// Returns `u_1 G + u_2 Q`, given the little-endian bits of `u_1` and `u_2`.
+    ///
+    /// At each step, the accumulator is doubled and `D + b_1 G + b_2 Q` is added, for the offset point `D`
+    /// and the bits `b_1` and `b_2`. The result is offset by `(2^256 - 1) D`, which is subtracted at the end.
+    fn double_scalar_multiply(u_1: &[Boolean<E>], u_2: &[Boolean<E>], public_key: &Point<E>) -> Point<E> {
+        let offset = Self::offset_point();
+
+        // Construct the table of `D`, `D + G`, `D + Q`, and `D + G + Q`.
+        let d = Point::constant(&offset);
+        let d_g = Point::constant(&(offset.clone() + console::Secp256k1Point::generator()));
+        let d_q = d.add(public_key);
+        let d_g_q = d_g.add(public_key);
+        let select = |b_1: &Boolean<E>, b_2: &Boolean<E>| {
+            let without_q = Point::ternary(b_1, &d_g, &d);
+            let with_q = Point::ternary(b_1, &d_g_q, &d_q);
+            Point::ternary(b_2, &with_q, &without_q)
+        };
+
+        // Process the bits from the most significant bit.
+        let mut bits = u_1.iter().zip(u_2).rev();
+        let mut accumulator = match bits.next() {
+            Some((b_1, b_2)) => select(b_1, b_2),
+            None => E::halt("The scalars of a secp256k1 multiplication cannot be empty"),
+        };
+        for (b_1, b_2) in bits {
+            accumulator = accumulator.double().add(&select(b_1, b_2));
+        }
+
+        // Subtract the offset `(2^256 - 1) D`.
+        accumulator.add(&Point::constant(&-offset.mul(&[0xff; 32])))
+    }
+
+    /// Returns the offset point `D`, which is the point with the smallest positive x-coordinate and an even y-coordinate.
+    /// Its discrete logarithm is unknown, so an honest accumulator does not reach an exceptional case.
+    fn offset_point() -> console::Secp256k1Point {
+        (1u8..=u8::MAX)
+            .find_map(|x| {
+                let mut bytes = [0u8; 33];
+                bytes[0] = 0x02;
+                bytes[32] = x;
+                console::Secp256k1Point::from_sec1_bytes(&bytes).ok()
+            })
+            .unwrap_or_else(|| E::halt("Failed to find the offset point of secp256k1"))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::bits_from_bytes_le;
+
+    /// Returns the bytes of the given hexadecimal string.
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        hex::decode(hex).unwrap().try_into().unwrap()
+    }
+
+    fn check_verify(mode: Mode, rng: &mut TestRng) {
+        // A signature of SHA-256("Aleo") produced by OpenSSL.
+        let public_key = console::Secp256k1Point::from_sec1_bytes(&from_hex::<33>(
+            "02200f3cbe7944b530375e920b7a097edae3c559ed218f8a6ac712ad981508e9b2",
+        ))
+        .unwrap();
+        let (x, y) = public_key.to_coordinates().unwrap();
+        let message_hash = from_hex("b7285d846386ed1a913328b9f6d695dca6a3962dd09e9fa99688762eeb6570e0");
+        let signature = console::Secp256k1Signature::from_bytes_be(&from_hex(
+            "0885a006eb986b04995f8411e8f7004ef51ff9322932f7ec98bcc2f9956a173c\
+             e96160f3ba8e2145cc8080051b05e0300368aef1037c245813ce14caa9c3214d",
+        ))
+        .unwrap();
+
+        // Injects the given bytes as bits.
+        let inject =
+            |bytes: &[u8]| bits_from_bytes_le(bytes).map(|bit| Boolean::<Circuit>::new(mode, bit)).collect::<Vec<_>>();
+
+        // Check the signature for the signed message hash, and for a different message hash.
+        for (message_hash, expected) in [(message_hash, true), (rng.gen(), false)] {
+            assert_eq!(expected, signature.verify(&public_key, &message_hash));
+
+            Circuit::scope(format!("Secp256k1 {mode} {expected}"), || {
+                let candidate = Secp256k1::<Circuit>::new().verify(
+                    &inject(&[x, y].concat()),
+                    &inject(&message_hash),
+                    &inject(&signature.to_bytes_be()),
+                );
+                assert_eq!(expected, candidate.eject_value());
+                // Ensure the verification of constants is computed without constraints.
+                if mode.is_constant() {
+                    assert_eq!(0, Circuit::num_private_in_scope());
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        check_verify(Mode::Constant, &mut TestRng::default());
+    }
+
+    #[test]
+    fn test_verify_private() {
+        check_verify(Mode::Private, &mut TestRng::default());
+    }
+}
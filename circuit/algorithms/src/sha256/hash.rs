@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Hash for Sha256<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the SHA-256 hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // The hash value `H` is initialized to `H^(0)`.
+        let mut state = Self::INITIAL_HASH.iter().map(|word| Self::constant_word(*word)).collect::<Vec<_>>();
+
+        // Process each block of the padded message.
+        for block in Self::pad(input).chunks(BLOCK_SIZE) {
+            state = Self::compress(&state, block);
+        }
+
+        // Output the hash value as big-endian words.
+        state.iter().flat_map(|word| Self::word_to_bytes(word)).collect()
+    }
+}
+
+impl<E: Environment> Sha256<E> {
+    /// In SHA-256, `pad` is defined as `pad(M) = M || 0x80 || 0x00…0x00 || len(M)`, where `M` is the input data,
+    /// and `len(M)` is the length of `M` in bits, as a 64-bit big-endian integer.
+    /// The padding extends the input data to a multiple of the block size.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Resize the input to a multiple of 8.
+        let mut padded_input = input.to_vec();
+        padded_input.resize((input.len() + 7) / 8 * 8, Boolean::constant(false));
+        let length_in_bits = padded_input.len() as u64;
+
+        // Step 1: Append the "0x80" byte to the message.
+        padded_input.extend((0..8).map(|i| Boolean::constant(i == 7)));
+
+        // Step 2: Append "0" bits until the length of the message is congruent to 448 mod 512.
+        while padded_input.len() % BLOCK_SIZE != BLOCK_SIZE - 64 {
+            padded_input.push(Boolean::constant(false));
+        }
+
+        // Step 3: Append the length of the message, as a 64-bit big-endian integer.
+        for byte in length_in_bits.to_be_bytes() {
+            padded_input.extend((0..8).map(|i| Boolean::constant((byte >> i) & 1 == 1)));
+        }
+        padded_input
+    }
+
+    /// The compression function, which updates the hash value with the given message block.
+    fn compress(state: &[Word<E>], block: &[Boolean<E>]) -> Vec<Word<E>> {
+        debug_assert_eq!(state.len(), 8, "The hash value must have 8 words");
+        debug_assert_eq!(block.len(), BLOCK_SIZE, "The message block must have {BLOCK_SIZE} bits");
+
+        /* The message schedule:
+         *
+         * W_t = M_t                                                   for 0 <= t <= 15
+         * W_t = σ1(W_{t-2}) + W_{t-7} + σ0(W_{t-15}) + W_{t-16}       for 16 <= t <= 63
+         */
+        let mut w = block.chunks(WORD_SIZE).map(Self::word_to_bytes).collect::<Vec<_>>();
+        for t in 16..NUM_ROUNDS {
+            let s0 = Self::xor3(&Self::rotr(&w[t - 15], 7), &Self::rotr(&w[t - 15], 18), &Self::shr(&w[t - 15], 3));
+            let s1 = Self::xor3(&Self::rotr(&w[t - 2], 17), &Self::rotr(&w[t - 2], 19), &Self::shr(&w[t - 2], 10));
+            let sum =
+                Self::to_field(&s1) + Self::to_field(&w[t - 7]) + Self::to_field(&s0) + Self::to_field(&w[t - 16]);
+            w.push(Self::from_field(&sum, 4));
+        }
+
+        /* The rounds:
+         *
+         * T1 = h + Σ1(e) + Ch(e, f, g) + K_t + W_t
+         * T2 = Σ0(a) + Maj(a, b, c)
+         * (a, b, c, d, e, f, g, h) = (T1 + T2, a, b, c, d + T1, e, f, g)
+         */
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] =
+            [0, 1, 2, 3, 4, 5, 6, 7].map(|i| state[i].clone());
+        for (t, round_constant) in Self::ROUND_CONSTANTS.iter().enumerate() {
+            let sigma_1 = Self::xor3(&Self::rotr(&e, 6), &Self::rotr(&e, 11), &Self::rotr(&e, 25));
+            let choice =
+                e.iter().zip_eq(&f).zip_eq(&g).map(|((e, f), g)| Boolean::ternary(e, f, g)).collect::<Vec<_>>();
+            // The sum `T1` is not reduced, as it is only used in the sums below.
+            let t1 = Self::to_field(&h)
+                + Self::to_field(&sigma_1)
+                + Self::to_field(&choice)
+                + Field::constant(console::Field::from_u32(*round_constant))
+                + Self::to_field(&w[t]);
+
+            let sigma_0 = Self::xor3(&Self::rotr(&a, 2), &Self::rotr(&a, 13), &Self::rotr(&a, 22));
+            let majority =
+                a.iter().zip_eq(&b).zip_eq(&c).map(|((a, b), c)| Boolean::ternary(&(a ^ b), c, a)).collect::<Vec<_>>();
+
+            h = g;
+            g = f;
+            f = e;
+            e = Self::from_field(&(Self::to_field(&d) + &t1), 6);
+            d = c;
+            c = b;
+            b = a;
+            a = Self::from_field(&(t1 + Self::to_field(&sigma_0) + Self::to_field(&majority)), 7);
+        }
+
+        // H^(i) = H^(i-1) + (a, b, c, d, e, f, g, h)
+        [a, b, c, d, e, f, g, h]
+            .iter()
+            .zip_eq(state)
+            .map(|(word, previous)| Self::from_field(&(Self::to_field(word) + Self::to_field(previous)), 2))
+            .collect()
+    }
+
+    /// Returns the given word as a constant.
+    fn constant_word(word: u32) -> Word<E> {
+        (0..WORD_SIZE).map(|i| Boolean::constant((word >> i) & 1 == 1)).collect()
+    }
+
+    /// Converts between a big-endian word of bytes in little-endian bit order, and a word in little-endian bits.
+    /// The conversion is its own inverse.
+    fn word_to_bytes(bits: &[Boolean<E>]) -> Word<E> {
+        bits.chunks(8).rev().flatten().cloned().collect()
+    }
+
+    /// Returns the rotation of the given word to the right by `n` bits.
+    fn rotr(word: &[Boolean<E>], n: usize) -> Word<E> {
+        let mut bits = word.to_vec();
+        bits.rotate_left(n);
+        bits
+    }
+
+    /// Returns the shift of the given word to the right by `n` bits.
+    fn shr(word: &[Boolean<E>], n: usize) -> Word<E> {
+        word[n..].iter().cloned().chain((0..n).map(|_| Boolean::constant(false))).collect()
+    }
+
+    /// Returns the bitwise XOR of the given words.
+    fn xor3(first: &[Boolean<E>], second: &[Boolean<E>], third: &[Boolean<E>]) -> Word<E> {
+        first.iter().zip_eq(second).zip_eq(third).map(|((first, second), third)| first ^ second ^ third).collect()
+    }
+
+    /// Returns the given word as a field element, which is a linear combination of its bits.
+    fn to_field(word: &[Boolean<E>]) -> Field<E> {
+        Field::from_bits_le(word)
+    }
+
+    /// Returns the given sum of `num_terms` words modulo `2^32`, as a word.
+    fn from_field(sum: &Field<E>, num_terms: usize) -> Word<E> {
+        // The sum is less than `num_terms * 2^32`, so it is decomposed into its lower `32 + ceil(log2(num_terms))` bits.
+        let num_carry_bits = num_terms.next_power_of_two().trailing_zeros() as usize;
+        let mut bits = sum.to_lower_bits_le(WORD_SIZE + num_carry_bits);
+        bits.truncate(WORD_SIZE);
+        bits
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    const ITERATIONS: usize = 3;
+
+    fn check_hash(mode: Mode, num_inputs: usize, rng: &mut TestRng) {
+        use console::Hash as H;
+
+        let native = console::Sha256;
+        let sha256 = Sha256::<Circuit>::new();
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+            let input = native_input.iter().map(|v| Boolean::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash(&native_input).expect("Failed to hash native input");
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Sha256 {mode} {i}"), || {
+                let candidate = sha256.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                // Ensure the hash of constants is computed without constraints.
+                if mode.is_constant() {
+                    assert_eq!(0, Circuit::num_private_in_scope());
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_sha256_hash_constant() {
+        let mut rng = TestRng::default();
+
+        for num_inputs in [0, 1, 8, 447, 448, 512, 1024] {
+            check_hash(Mode::Constant, num_inputs, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_sha256_hash_public() {
+        let mut rng = TestRng::default();
+
+        for num_inputs in [1, 8, 447, 448, 512, 1024] {
+            check_hash(Mode::Public, num_inputs, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_sha256_hash_private() {
+        let mut rng = TestRng::default();
+
+        let mut input_sizes = vec![1, 7, 8, 9, 255, 256, 440, 447, 448, 449, 511, 512, 513, 1024];
+        input_sizes.extend((0..3).map(|_| rng.gen_range(1..1024)));
+        for num_inputs in input_sizes {
+            check_hash(Mode::Private, num_inputs, &mut rng);
+        }
+    }
+}
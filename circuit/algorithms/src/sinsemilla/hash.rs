@@ -0,0 +1,26 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const WINDOW_SIZE: u8, const NUM_WINDOWS: u16> Hash for Sinsemilla<E, WINDOW_SIZE, NUM_WINDOWS> {
+    type Input = Boolean<E>;
+    type Output = Field<E>;
+
+    /// Returns the Sinsemilla hash of the given input as a field element.
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Compute the Sinsemilla hash as an affine group element, and return the x-coordinate.
+        self.hash_uncompressed(input).to_x_coordinate()
+    }
+}
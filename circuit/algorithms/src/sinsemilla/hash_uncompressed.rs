@@ -0,0 +1,274 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const WINDOW_SIZE: u8, const NUM_WINDOWS: u16> HashUncompressed
+    for Sinsemilla<E, WINDOW_SIZE, NUM_WINDOWS>
+{
+    type Input = Boolean<E>;
+    type Output = Group<E>;
+
+    /// Returns the Sinsemilla hash of the given input as an affine group element.
+    ///
+    /// The input size must be a multiple of `WINDOW_SIZE`, as the input is not padded.
+    fn hash_uncompressed(&self, input: &[Self::Input]) -> Self::Output {
+        // Ensure the input size is within the parameter size.
+        if input.len() > Self::MAX_BITS {
+            E::halt(format!("Inputs to this Sinsemilla cannot exceed {} bits", Self::MAX_BITS))
+        }
+        // Ensure the input size is a multiple of the window size, as a partial window
+        // would otherwise hash to the same output as its zero-extension.
+        if input.len() % WINDOW_SIZE as usize != 0 {
+            E::halt(format!("Inputs to this Sinsemilla must be a multiple of {WINDOW_SIZE} bits"))
+        }
+
+        // Declare the constant coefficients A and B for the Montgomery curve.
+        let coeff_a = Field::constant(console::Group::<E::Network>::MONTGOMERY_A);
+        let coeff_b = Field::constant(console::Group::<E::Network>::MONTGOMERY_B);
+
+        // Implements `(this + that) + this` on the Montgomery curve, using the incomplete addition formulae.
+        // The y-coordinate of the intermediate sum is never witnessed, and one iteration costs 5 constraints.
+        let double_and_add = |(this_x, this_y): (&Field<E>, &Field<E>), (that_x, that_y): (&Field<E>, &Field<E>)| {
+            // Construct `lambda_1` as a witness defined as:
+            // `lambda_1 := (that_y - this_y) / (that_x - this_x)`
+            let lambda_1: Field<E> = witness!(|this_x, this_y, that_x, that_y| (that_y - this_y) / (that_x - this_x));
+
+            // Ensure `lambda_1` is correct by enforcing:
+            // `(that_x - this_x) * lambda_1 == (that_y - this_y)`
+            E::enforce(|| (that_x - this_x, &lambda_1, that_y - this_y));
+
+            // Construct `sum_x` as a witness defined as:
+            // `sum_x := (B * lambda_1^2) - A - this_x - that_x`
+            let sum_x: Field<E> = witness!(|lambda_1, that_x, this_x, coeff_a, coeff_b| {
+                coeff_b * lambda_1.square() - coeff_a - this_x - that_x
+            });
+
+            // Ensure `sum_x` is correct by enforcing:
+            // `(B * lambda_1) * lambda_1 == (A + this_x + that_x + sum_x)`
+            E::enforce(|| (&coeff_b * &lambda_1, &lambda_1, &coeff_a + this_x + that_x + &sum_x));
+
+            // Construct `lambda_2` as a witness, which is the slope between `(sum_x, sum_y)` and `(this_x, this_y)`.
+            // As `sum_y := -(this_y + lambda_1 * (sum_x - this_x))`, this is defined as:
+            // `lambda_2 := (2 * this_y) / (this_x - sum_x) - lambda_1`
+            let lambda_2: Field<E> =
+                witness!(|lambda_1, sum_x, this_x, this_y| this_y.double() / (this_x - sum_x) - lambda_1);
+
+            // Ensure `lambda_2` is correct by enforcing:
+            // `(lambda_1 + lambda_2) * (this_x - sum_x) == 2 * this_y`
+            E::enforce(|| (&lambda_1 + &lambda_2, this_x - &sum_x, this_y.double()));
+
+            // Construct `result_x` as a witness defined as:
+            // `result_x := (B * lambda_2^2) - A - sum_x - this_x`
+            let result_x: Field<E> = witness!(|lambda_2, sum_x, this_x, coeff_a, coeff_b| {
+                coeff_b * lambda_2.square() - coeff_a - sum_x - this_x
+            });
+
+            // Ensure `result_x` is correct by enforcing:
+            // `(B * lambda_2) * lambda_2 == (A + sum_x + this_x + result_x)`
+            E::enforce(|| (&coeff_b * &lambda_2, &lambda_2, &coeff_a + &sum_x + this_x + &result_x));
+
+            // Construct `result_y` as a witness defined as:
+            // `result_y := -(this_y + (lambda_2 * (result_x - this_x)))`
+            let result_y: Field<E> =
+                witness!(|lambda_2, result_x, this_x, this_y| -(this_y + (lambda_2 * (result_x - this_x))));
+
+            // Ensure `result_y` is correct by enforcing:
+            // `(this_x - result_x) * lambda_2 == (this_y + result_y)`
+            E::enforce(|| (this_x - &result_x, &lambda_2, this_y + &result_y));
+
+            (result_x, result_y)
+        };
+
+        // Compute `acc := (acc + S[m_i]) + acc` for each window `m_i`, starting from `Q`.
+        let (acc_x, acc_y) = input.chunks(WINDOW_SIZE as usize).fold(self.q.clone(), |(acc_x, acc_y), window| {
+            let (base_x, base_y) = self.lookup(window);
+            double_and_add((&acc_x, &acc_y), (&base_x, &base_y))
+        });
+
+        // Convert the accumulator into a point on the twisted Edwards curve.
+        let edwards_x = acc_x.div_unchecked(&acc_y); // 1 constraint (`acc_y` is never 0)
+        let edwards_y = (&acc_x - &Field::one()).div_unchecked(&(&acc_x + &Field::one())); // 1 constraint (numerator & denominator are never both 0)
+        Group::from_xy_coordinates_unchecked(edwards_x, edwards_y) // 0 constraints (this is safe)
+    }
+}
+
+impl<E: Environment, const WINDOW_SIZE: u8, const NUM_WINDOWS: u16> Sinsemilla<E, WINDOW_SIZE, NUM_WINDOWS> {
+    /// Returns the Montgomery point in the lookup table at the (little-endian) index given by the window.
+    ///
+    /// This evaluates the multilinear extension of the lookup table at the window bits,
+    /// which costs `2^WINDOW_SIZE - WINDOW_SIZE - 1` constraints.
+    fn lookup(&self, window: &[Boolean<E>]) -> (Field<E>, Field<E>) {
+        // Compute the product of the bits set in `mask`, for each `mask` of the window.
+        let mut monomials = Vec::with_capacity(1 << WINDOW_SIZE);
+        monomials.push(Field::one());
+        for (i, bit) in window.iter().enumerate() {
+            let bit = Field::from_boolean(bit);
+            for mask in 0..(1 << i) {
+                let monomial = &monomials[mask] * &bit;
+                monomials.push(monomial);
+            }
+        }
+
+        // Evaluate the multilinear extensions of the x- and y-coordinates.
+        let evaluate = |coefficients: &[Field<E>]| {
+            monomials
+                .iter()
+                .zip_eq(coefficients)
+                .map(|(monomial, coefficient)| monomial * coefficient)
+                .fold(Field::zero(), |sum, term| sum + term)
+        };
+        (evaluate(&self.x_coefficients), evaluate(&self.y_coefficients))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{Pedersen128, BHP256};
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: usize = 10;
+    const DOMAIN: &str = "SinsemillaCircuit0";
+
+    fn check_hash_uncompressed(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::HashUncompressed as H;
+
+        // Initialize the Sinsemilla hash.
+        let native = console::Sinsemilla256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = Sinsemilla256::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..256).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash_uncompressed(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Sinsemilla {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_uncompressed(&circuit_input);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_uncompressed_constant() -> Result<()> {
+        check_hash_uncompressed(Mode::Constant, 324, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_uncompressed_public() -> Result<()> {
+        check_hash_uncompressed(Mode::Public, 2, 0, 1026, 1026)
+    }
+
+    #[test]
+    fn test_hash_uncompressed_private() -> Result<()> {
+        check_hash_uncompressed(Mode::Private, 2, 0, 1026, 1026)
+    }
+
+    #[test]
+    fn test_hash_uncompressed_full_windows() -> Result<()> {
+        use console::HashUncompressed as H;
+
+        // Initialize the Sinsemilla hash.
+        let native = console::Sinsemilla256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = Sinsemilla256::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for num_bits in [0, 4, 8, 20, 252] {
+            // Sample a random input, which is a multiple of the window size.
+            let input = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let expected = native.hash_uncompressed(&input)?;
+
+            // Ensure the circuit hash matches the native hash.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input);
+            let candidate = circuit.hash_uncompressed(&circuit_input);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hash_uncompressed_partial_window_halts() {
+        let native = console::Sinsemilla256::<<Circuit as Environment>::Network>::setup(DOMAIN).unwrap();
+        let circuit = Sinsemilla256::<Circuit>::constant(native);
+
+        // Ensure an input with a partial window is rejected, instead of colliding with its zero-extension.
+        let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![false; 253]);
+        let _ = circuit.hash_uncompressed(&circuit_input);
+    }
+
+    #[test]
+    fn test_hash_uncompressed_compared_to_bhp_and_pedersen() -> Result<()> {
+        const NUM_BITS: usize = 128;
+        const NUM_WINDOWS: u64 = NUM_BITS as u64 / 4;
+
+        // Initialize Sinsemilla, BHP, and Pedersen for the same input size.
+        let sinsemilla = Sinsemilla256::<Circuit>::constant(console::Sinsemilla256::setup(DOMAIN)?);
+        let bhp_domain = console::DomainSeparator::new("BHPCircuit", 0);
+        let bhp = BHP256::<Circuit>::constant(console::BHP256::setup(bhp_domain)?);
+        let pedersen_domain = console::DomainSeparator::new("PedersenCircuit", 0);
+        let pedersen = Pedersen128::<Circuit>::constant(console::Pedersen128::setup(pedersen_domain));
+
+        let mut rng = TestRng::default();
+        let input = (0..NUM_BITS).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+
+        // Returns the number of constraints to hash the input as a private witness.
+        let count = |hash: &dyn Fn(&[Boolean<Circuit>]) -> Group<Circuit>| {
+            let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+            let _ = hash(&circuit_input);
+            assert!(Circuit::is_satisfied());
+            let num_constraints = Circuit::num_constraints();
+            Circuit::reset();
+            num_constraints
+        };
+        let num_sinsemilla = count(&|input| sinsemilla.hash_uncompressed(input));
+        let num_bhp = count(&|input| bhp.hash_uncompressed(input));
+        let num_pedersen = count(&|input| pedersen.hash_uncompressed(input));
+
+        // Each window costs 11 constraints to evaluate the lookup table, and 5 constraints to double-and-add.
+        assert_eq!(num_sinsemilla, NUM_WINDOWS * (11 + 5) + 2);
+        // Sinsemilla is cheaper than Pedersen, which costs one group addition per bit.
+        assert!(num_sinsemilla < num_pedersen, "Sinsemilla ({num_sinsemilla}) vs. Pedersen ({num_pedersen})");
+        // Without a lookup argument, Sinsemilla is more expensive than BHP, as the table is evaluated
+        // as a multilinear polynomial. Once a lookup argument replaces this evaluation, it is cheaper.
+        let num_sinsemilla_with_lookups = NUM_WINDOWS * 5 + 2;
+        assert!(
+            num_sinsemilla_with_lookups < num_bhp,
+            "Sinsemilla ({num_sinsemilla_with_lookups}) vs. BHP ({num_bhp})"
+        );
+        Ok(())
+    }
+}
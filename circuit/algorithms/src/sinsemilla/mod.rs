@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+mod hash_uncompressed;
+
+#[cfg(all(test, console))]
+use snarkvm_circuit_types::environment::assert_scope;
+
+use crate::{Hash, HashUncompressed};
+use snarkvm_circuit_types::prelude::*;
+
+/// Sinsemilla256 is a collision-resistant hash function that takes up to a 256-bit input.
+pub type Sinsemilla256<E> = Sinsemilla<E, 4, 64>;
+/// Sinsemilla512 is a collision-resistant hash function that takes up to a 512-bit input.
+pub type Sinsemilla512<E> = Sinsemilla<E, 4, 128>;
+
+/// Sinsemilla is a collision-resistant hash function that takes a variable-length input.
+///
+/// The lookup table is stored as the coefficients of its multilinear extension over the window bits,
+/// so that each lookup costs `2^WINDOW_SIZE - WINDOW_SIZE - 1` constraints. This is the only part of
+/// the gadget that depends on the window size, and is where a lookup argument is to be used once available.
+pub struct Sinsemilla<E: Environment, const WINDOW_SIZE: u8, const NUM_WINDOWS: u16> {
+    /// The initial base `Q` of the accumulator, as a point on the Montgomery curve.
+    q: (Field<E>, Field<E>),
+    /// The multilinear coefficients of the x-coordinates of the lookup table, on the Montgomery curve.
+    x_coefficients: Vec<Field<E>>,
+    /// The multilinear coefficients of the y-coordinates of the lookup table, on the Montgomery curve.
+    y_coefficients: Vec<Field<E>>,
+}
+
+impl<E: Environment, const WINDOW_SIZE: u8, const NUM_WINDOWS: u16> Sinsemilla<E, WINDOW_SIZE, NUM_WINDOWS> {
+    /// The maximum number of input bits.
+    const MAX_BITS: usize = WINDOW_SIZE as usize * NUM_WINDOWS as usize;
+}
+
+#[cfg(console)]
+impl<E: Environment, const WINDOW_SIZE: u8, const NUM_WINDOWS: u16> Inject for Sinsemilla<E, WINDOW_SIZE, NUM_WINDOWS> {
+    type Primitive = console::Sinsemilla<E::Network, WINDOW_SIZE, NUM_WINDOWS>;
+
+    /// Initializes a new instance of a Sinsemilla circuit with the given Sinsemilla variant.
+    fn new(_mode: Mode, sinsemilla: Self::Primitive) -> Self {
+        // Converts a twisted Edwards point into a Montgomery point.
+        let to_montgomery = |point: &console::Group<E::Network>| {
+            let one = console::Field::<E::Network>::one();
+            let x = (one + point.to_y_coordinate()) / (one - point.to_y_coordinate());
+            let y = x / point.to_x_coordinate();
+            (x, y)
+        };
+
+        // Initialize the initial base.
+        let (q_x, q_y) = to_montgomery(&sinsemilla.q());
+        let q = (Field::constant(q_x), Field::constant(q_y));
+
+        // Convert the lookup table into Montgomery points.
+        let (mut x_coefficients, mut y_coefficients): (Vec<_>, Vec<_>) =
+            sinsemilla.table().iter().map(to_montgomery).unzip();
+        assert_eq!(x_coefficients.len(), 1 << WINDOW_SIZE);
+
+        // Compute the coefficients of the multilinear extension of the lookup table,
+        // where the coefficient at `mask` belongs to the product of the bits set in `mask`.
+        for bit in 0..WINDOW_SIZE as usize {
+            for mask in 0..x_coefficients.len() {
+                if mask & (1 << bit) != 0 {
+                    let (x, y) = (x_coefficients[mask ^ (1 << bit)], y_coefficients[mask ^ (1 << bit)]);
+                    x_coefficients[mask] -= x;
+                    y_coefficients[mask] -= y;
+                }
+            }
+        }
+
+        Self {
+            q,
+            x_coefficients: x_coefficients.into_iter().map(Field::constant).collect(),
+            y_coefficients: y_coefficients.into_iter().map(Field::constant).collect(),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "SinsemillaCircuit0";
+
+    #[test]
+    fn test_setup_constant() -> Result<()> {
+        let native = console::Sinsemilla256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+
+        Circuit::scope("Sinsemilla::setup", || {
+            let circuit = Sinsemilla256::<Circuit>::constant(native.clone());
+            assert_scope!(34, 0, 0, 0);
+
+            // Ensure the initial base converts back to the native base.
+            let (q_x, q_y) = &circuit.q;
+            assert_eq!(native.q().to_x_coordinate(), (q_x / q_y).eject_value());
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+use crate::{Hash, HashMany, Poseidon2};
+use snarkvm_circuit_types::{Boolean, Field, environment::prelude::*};
+
+/// The base-2 logarithm of the Winternitz parameter.
+const WOTS_LOG_W: usize = 2;
+/// The Winternitz parameter, which is the number of values of a digit, and the length of each hash chain.
+const WOTS_W: usize = 1 << WOTS_LOG_W;
+/// The number of bits of the checksum of a WOTS+ message.
+const WOTS_CHECKSUM_BITS: usize = 10;
+/// The number of bits taken from each output of the message digest.
+const DIGEST_BITS_PER_OUTPUT: usize = 128;
+
+/// The address type of a hash in a WOTS+ chain.
+const ADDRESS_WOTS_HASH: u8 = 0;
+/// The address type of the compression of a WOTS+ public key.
+const ADDRESS_WOTS_PK: u8 = 1;
+/// The address type of a node of a hypertree layer.
+const ADDRESS_TREE: u8 = 2;
+/// The address type of a node of a FORS tree.
+const ADDRESS_FORS_TREE: u8 = 3;
+/// The address type of the compression of the FORS roots.
+const ADDRESS_FORS_ROOTS: u8 = 4;
+
+/// SPHINCS+ with the hypertree and FORS trees of SPHINCS+-128f, namely 22 layers of height 3, and 33 FORS trees
+/// of height 6. Note that this is not the SPHINCS+-128f parameter set, as WOTS+ uses a Winternitz parameter of 4.
+pub type SphincsPlus128W4<E> = SphincsPlus<E, 22, 3, 33, 6>;
+
+/// The verification of SPHINCS+ signatures.
+///
+/// Verification is expensive but functional, as it evaluates every hash that a native verifier evaluates:
+///   - The indices selected by the message digest are bits of the digest, so each address is a linear
+///     combination of bits and constants, and each Merkle path step is a pair of ternaries.
+///   - Each WOTS+ chain is evaluated for all `W - 1` steps from its signature value, and the end of the chain
+///     is selected by the digit. The steps beyond the end of the chain are evaluated but unused.
+#[derive(Clone)]
+pub struct SphincsPlus<
+    E: Environment,
+    const NUM_LAYERS: usize,
+    const TREE_HEIGHT: usize,
+    const NUM_FORS_TREES: usize,
+    const FORS_HEIGHT: usize,
+> {
+    /// The tweakable hash function.
+    poseidon: Poseidon2<E>,
+    /// The domain separator of the message digest.
+    digest_domain: Field<E>,
+}
+
+#[cfg(console)]
+impl<
+    E: Environment,
+    const NUM_LAYERS: usize,
+    const TREE_HEIGHT: usize,
+    const NUM_FORS_TREES: usize,
+    const FORS_HEIGHT: usize,
+> Inject for SphincsPlus<E, NUM_LAYERS, TREE_HEIGHT, NUM_FORS_TREES, FORS_HEIGHT>
+{
+    type Primitive = console::SphincsPlus<E::Network, NUM_LAYERS, TREE_HEIGHT, NUM_FORS_TREES, FORS_HEIGHT>;
+
+    /// Initializes SPHINCS+ verification from the given native instance.
+    fn new(_mode: Mode, sphincs_plus: Self::Primitive) -> Self {
+        Self {
+            poseidon: Poseidon2::constant(sphincs_plus.poseidon().clone()),
+            digest_domain: Field::constant(sphincs_plus.digest_domain()),
+        }
+    }
+}
+
+/// The public key of SPHINCS+.
+#[derive(Clone)]
+pub struct SphincsPlusPublicKey<E: Environment> {
+    /// The public seed of the tweakable hashes.
+    seed: Field<E>,
+    /// The root of the top layer of the hypertree.
+    root: Field<E>,
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for SphincsPlusPublicKey<E> {
+    type Primitive = console::SphincsPlusPublicKey<E::Network>;
+
+    /// Initializes a public key from the given mode and native public key.
+    fn new(mode: Mode, public_key: Self::Primitive) -> Self {
+        Self { seed: Field::new(mode, public_key.seed()), root: Field::new(mode, public_key.root()) }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Eject for SphincsPlusPublicKey<E> {
+    type Primitive = console::SphincsPlusPublicKey<E::Network>;
+
+    /// Ejects the mode of the public key.
+    fn eject_mode(&self) -> Mode {
+        (&self.seed, &self.root).eject_mode()
+    }
+
+    /// Ejects the public key.
+    fn eject_value(&self) -> Self::Primitive {
+        Self::Primitive::new(self.seed.eject_value(), self.root.eject_value())
+    }
+}
+
+/// A signature of SPHINCS+.
+#[derive(Clone)]
+pub struct SphincsPlusSignature<E: Environment> {
+    /// The randomizer of the message digest.
+    randomizer: Field<E>,
+    /// The secret leaf of each FORS tree.
+    fors_secrets: Vec<Field<E>>,
+    /// The authentication path of the secret leaf of each FORS tree.
+    fors_paths: Vec<Vec<Field<E>>>,
+    /// The WOTS+ signature of each layer of the hypertree.
+    wots_signatures: Vec<Vec<Field<E>>>,
+    /// The authentication path of the WOTS+ key pair of each layer of the hypertree.
+    tree_paths: Vec<Vec<Field<E>>>,
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for SphincsPlusSignature<E> {
+    type Primitive = console::SphincsPlusSignature<E::Network>;
+
+    /// Initializes a signature from the given mode and native signature.
+    fn new(mode: Mode, signature: Self::Primitive) -> Self {
+        Self {
+            randomizer: Field::new(mode, signature.randomizer()),
+            fors_secrets: Inject::new(mode, signature.fors_secrets().to_vec()),
+            fors_paths: Inject::new(mode, signature.fors_paths().to_vec()),
+            wots_signatures: Inject::new(mode, signature.wots_signatures().to_vec()),
+            tree_paths: Inject::new(mode, signature.tree_paths().to_vec()),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Eject for SphincsPlusSignature<E> {
+    type Primitive = console::SphincsPlusSignature<E::Network>;
+
+    /// Ejects the mode of the signature.
+    fn eject_mode(&self) -> Mode {
+        (&self.randomizer, &self.fors_secrets, &self.fors_paths, &self.wots_signatures, &self.tree_paths).eject_mode()
+    }
+
+    /// Ejects the signature.
+    fn eject_value(&self) -> Self::Primitive {
+        Self::Primitive::new(
+            self.randomizer.eject_value(),
+            self.fors_secrets.eject_value(),
+            self.fors_paths.eject_value(),
+            self.wots_signatures.eject_value(),
+            self.tree_paths.eject_value(),
+        )
+    }
+}
+
+impl<
+    E: Environment,
+    const NUM_LAYERS: usize,
+    const TREE_HEIGHT: usize,
+    const NUM_FORS_TREES: usize,
+    const FORS_HEIGHT: usize,
+> SphincsPlus<E, NUM_LAYERS, TREE_HEIGHT, NUM_FORS_TREES, FORS_HEIGHT>
+{
+    /// The total height of the hypertree.
+    const HEIGHT: usize = NUM_LAYERS * TREE_HEIGHT;
+
+    /// Returns the tweakable hash `H(public_seed, address, input)`.
+    fn tweakable_hash(&self, public_seed: &Field<E>, address: Field<E>, input: &[Field<E>]) -> Field<E> {
+        let mut preimage = Vec::with_capacity(2 + input.len());
+        preimage.push(public_seed.clone());
+        preimage.push(address);
+        preimage.extend_from_slice(input);
+        self.poseidon.hash(&preimage)
+    }
+}
+
+/// Returns the address of a hash as a field element, where:
+///     address := tree + 2^64 * layer + 2^72 * kind + 2^80 * keypair + 2^112 * word_1 + 2^144 * word_2
+fn address<E: Environment>(
+    layer: u8,
+    tree: &[Boolean<E>],
+    kind: u8,
+    keypair: &[Boolean<E>],
+    word_1: u32,
+    word_2: &Field<E>,
+) -> Field<E> {
+    let constant = |value: u128| Field::constant(console::Field::from_u128(value));
+    let words = constant(layer as u128 | (kind as u128) << 8 | (word_1 as u128) << 48) * constant(1 << 64);
+    Field::from_bits_le(tree)
+        + words
+        + Field::from_bits_le(keypair) * constant(1 << 80)
+        + word_2 * constant(1 << 72) * constant(1 << 72)
+}
+
+/// Returns the value in the given list at the given index, as little-endian bits.
+fn select<E: Environment>(values: Vec<Field<E>>, index: &[Boolean<E>]) -> Field<E> {
+    index
+        .iter()
+        .fold(values, |values, bit| values.chunks(2).map(|pair| Field::ternary(bit, &pair[1], &pair[0])).collect())
+        .swap_remove(0)
+}
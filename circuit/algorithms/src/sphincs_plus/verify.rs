@@ -0,0 +1,228 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<
+    E: Environment,
+    const NUM_LAYERS: usize,
+    const TREE_HEIGHT: usize,
+    const NUM_FORS_TREES: usize,
+    const FORS_HEIGHT: usize,
+> SphincsPlus<E, NUM_LAYERS, TREE_HEIGHT, NUM_FORS_TREES, FORS_HEIGHT>
+{
+    /// Returns `true` if the signature is valid for the given public key and message.
+    pub fn verify(
+        &self,
+        public_key: &SphincsPlusPublicKey<E>,
+        message: &[Field<E>],
+        signature: &SphincsPlusSignature<E>,
+    ) -> Boolean<E> {
+        // Ensure the signature has the shape of the parameters.
+        let wots_length = Self::wots_message_length() + WOTS_CHECKSUM_BITS / WOTS_LOG_W;
+        if signature.fors_secrets.len() != NUM_FORS_TREES
+            || signature.fors_paths.len() != NUM_FORS_TREES
+            || signature.fors_paths.iter().any(|path| path.len() != FORS_HEIGHT)
+            || signature.wots_signatures.len() != NUM_LAYERS
+            || signature.wots_signatures.iter().any(|wots_signature| wots_signature.len() != wots_length)
+            || signature.tree_paths.len() != NUM_LAYERS
+            || signature.tree_paths.iter().any(|path| path.len() != TREE_HEIGHT)
+        {
+            E::halt("The SPHINCS+ signature does not match the parameters")
+        }
+        let public_seed = &public_key.seed;
+
+        // Compute the message digest, which selects the FORS indices and the key pair at the bottom of the hypertree.
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([
+            self.digest_domain.clone(),
+            signature.randomizer.clone(),
+            public_seed.clone(),
+            public_key.root.clone(),
+        ]);
+        preimage.extend_from_slice(message);
+        let num_outputs =
+            (NUM_FORS_TREES * FORS_HEIGHT + Self::HEIGHT + DIGEST_BITS_PER_OUTPUT - 1) / DIGEST_BITS_PER_OUTPUT;
+        let bits = self
+            .poseidon
+            .hash_many(&preimage, num_outputs as u16)
+            .iter()
+            .flat_map(|output| output.to_bits_le().into_iter().take(DIGEST_BITS_PER_OUTPUT))
+            .collect::<Vec<_>>();
+        let (fors_bits, index_bits) = bits.split_at(NUM_FORS_TREES * FORS_HEIGHT);
+
+        // Compute the FORS public key.
+        let mut root = self.fors_public_key_from_signature(
+            public_seed,
+            &index_bits[TREE_HEIGHT..Self::HEIGHT],
+            &index_bits[..TREE_HEIGHT],
+            fors_bits,
+            signature,
+        );
+
+        // Compute the root of each layer from the root of the layer below, where the key pair of each layer
+        // is selected by the next bits of the digest, and the tree by the remaining bits.
+        for (layer, (wots_signature, tree_path)) in
+            signature.wots_signatures.iter().zip_eq(&signature.tree_paths).enumerate()
+        {
+            let leaf = &index_bits[layer * TREE_HEIGHT..(layer + 1) * TREE_HEIGHT];
+            let tree = &index_bits[(layer + 1) * TREE_HEIGHT..Self::HEIGHT];
+            let node = self.wots_public_key_from_signature(public_seed, layer as u8, tree, leaf, &root, wots_signature);
+
+            // Compute the root of the tree from the WOTS+ public key.
+            root = (1..).zip(leaf.iter().zip_eq(tree_path)).fold(node, |node, (height, (bit, sibling))| {
+                let left = Field::ternary(bit, sibling, &node);
+                let right = Field::ternary(bit, &node, sibling);
+                let index = Field::from_bits_le(&leaf[height as usize..]);
+                let address = address(layer as u8, tree, ADDRESS_TREE, &[], height, &index);
+                self.tweakable_hash(public_seed, address, &[left, right])
+            });
+        }
+        root.is_equal(&public_key.root)
+    }
+
+    /// Returns the number of digits of a WOTS+ message, without the checksum.
+    fn wots_message_length() -> usize {
+        (E::BaseField::size_in_bits() + WOTS_LOG_W - 1) / WOTS_LOG_W
+    }
+
+    /// Returns the FORS public key of the selected key pair, from the signature of the given indices.
+    fn fors_public_key_from_signature(
+        &self,
+        public_seed: &Field<E>,
+        tree: &[Boolean<E>],
+        keypair: &[Boolean<E>],
+        fors_bits: &[Boolean<E>],
+        signature: &SphincsPlusSignature<E>,
+    ) -> Field<E> {
+        let roots = fors_bits
+            .chunks(FORS_HEIGHT)
+            .zip_eq(signature.fors_secrets.iter().zip_eq(&signature.fors_paths))
+            .enumerate()
+            .map(|(i, (index, (secret, path)))| {
+                // Returns the address of the node at the given height, whose index is `(i << (a - height)) + (index >> height)`.
+                let node_address = |height: usize| {
+                    let offset = Field::constant(console::Field::from_u64((i as u64) << (FORS_HEIGHT - height)));
+                    let index = Field::from_bits_le(&index[height..]) + offset;
+                    address(0, tree, ADDRESS_FORS_TREE, keypair, height as u32, &index)
+                };
+
+                let leaf = self.tweakable_hash(public_seed, node_address(0), &[secret.clone()]);
+                (1..).zip(index.iter().zip_eq(path)).fold(leaf, |node, (height, (bit, sibling))| {
+                    let left = Field::ternary(bit, sibling, &node);
+                    let right = Field::ternary(bit, &node, sibling);
+                    self.tweakable_hash(public_seed, node_address(height), &[left, right])
+                })
+            })
+            .collect::<Vec<_>>();
+        self.tweakable_hash(public_seed, address(0, tree, ADDRESS_FORS_ROOTS, keypair, 0, &Field::zero()), &roots)
+    }
+
+    /// Returns the WOTS+ public key of the given key pair, from a signature of the given message.
+    fn wots_public_key_from_signature(
+        &self,
+        public_seed: &Field<E>,
+        layer: u8,
+        tree: &[Boolean<E>],
+        keypair: &[Boolean<E>],
+        message: &Field<E>,
+        signature: &[Field<E>],
+    ) -> Field<E> {
+        // Compute the digits of the message.
+        let mut bits = message.to_bits_le();
+        bits.resize(Self::wots_message_length() * WOTS_LOG_W, Boolean::constant(false));
+        // Compute the checksum of the digits, as `sum(W - 1 - digit_i)`, and append its digits.
+        let maximum = Field::constant(console::Field::from_u64(WOTS_W as u64 - 1));
+        let checksum = bits
+            .chunks(WOTS_LOG_W)
+            .fold(Field::zero(), |checksum, digit| checksum + &maximum - Field::from_bits_le(digit));
+        bits.extend(checksum.to_lower_bits_le(WOTS_CHECKSUM_BITS));
+
+        // Compute the end of each chain, from the position of the digit.
+        let ends = bits
+            .chunks(WOTS_LOG_W)
+            .zip_eq(signature)
+            .enumerate()
+            .map(|(chain, (digit, value))| {
+                let start = Field::from_bits_le(digit);
+                let mut values = Vec::with_capacity(WOTS_W);
+                values.push(value.clone());
+                for step in 0..WOTS_W as u64 - 1 {
+                    let position = &start + Field::constant(console::Field::from_u64(step));
+                    let address = address(layer, tree, ADDRESS_WOTS_HASH, keypair, chain as u32, &position);
+                    let next = self.tweakable_hash(public_seed, address, &values[values.len() - 1..]);
+                    values.push(next);
+                }
+                // Select the value after `W - 1 - digit` steps.
+                values.reverse();
+                select(values, digit)
+            })
+            .collect::<Vec<_>>();
+        self.tweakable_hash(public_seed, address(layer, tree, ADDRESS_WOTS_PK, keypair, 0, &Field::zero()), &ends)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    /// A small instantiation of SPHINCS+, so that the tests are fast.
+    type CurrentSphincsPlus<E> = SphincsPlus<E, 2, 2, 4, 3>;
+    type NativeSphincsPlus = console::SphincsPlus<<Circuit as Environment>::Network, 2, 2, 4, 3>;
+
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("SphincsPlusCircuit", 0);
+
+    fn check_verify(mode: Mode, rng: &mut TestRng) -> Result<()> {
+        let native = NativeSphincsPlus::setup(DOMAIN)?;
+        let private_key = native.generate_private_key(rng)?;
+        let public_key = private_key.public_key();
+        let message: Vec<_> = (0..4).map(|_| Uniform::rand(rng)).collect();
+        let signature = native.sign(&private_key, &message, rng)?;
+        assert!(native.verify(&public_key, &message, &signature));
+
+        let sphincs_plus = CurrentSphincsPlus::<Circuit>::constant(native.clone());
+        let failure_message: Vec<_> = (0..4).map(|_| Uniform::rand(rng)).collect();
+        for (message, expected) in [(&message, true), (&failure_message, false)] {
+            Circuit::scope(format!("SPHINCS+ {mode} {expected}"), || {
+                let public_key = SphincsPlusPublicKey::new(mode, public_key);
+                let message: Vec<Field<Circuit>> = Inject::new(mode, message.clone());
+                let signature = SphincsPlusSignature::new(mode, signature.clone());
+
+                let candidate = sphincs_plus.verify(&public_key, &message, &signature);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied());
+                if mode.is_constant() {
+                    assert_eq!(0, Circuit::num_private_in_scope());
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_constant() -> Result<()> {
+        check_verify(Mode::Constant, &mut TestRng::default())
+    }
+
+    #[test]
+    fn test_verify_private() -> Result<()> {
+        check_verify(Mode::Private, &mut TestRng::default())
+    }
+}
@@ -83,7 +83,7 @@ mod tests {
     use anyhow::Result;
 
     const ITERATIONS: u64 = 10;
-    const DOMAIN: &str = "MerkleTreeCircuit0";
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("MerkleTreeCircuit", 0);
 
     macro_rules! check_hash_leaf {
         ($native:ident, $circuit:ident, $mode:ident, $num_inputs:expr, ($num_constants:expr, $num_public:expr, $num_private:expr, $num_constraints:expr)) => {{
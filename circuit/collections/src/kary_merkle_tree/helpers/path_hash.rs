@@ -100,7 +100,7 @@ mod tests {
     use anyhow::Result;
 
     const ITERATIONS: u64 = 5;
-    const DOMAIN: &str = "MerkleTreeCircuit0";
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("MerkleTreeCircuit", 0);
 
     macro_rules! check_hash_children {
         ($native:ident, $circuit:ident, $mode:ident, $arity:expr, ($num_constants:expr, $num_public:expr, $num_private:expr, $num_constraints:expr)) => {{
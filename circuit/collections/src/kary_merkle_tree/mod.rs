@@ -106,8 +106,8 @@ mod tests {
         type NativeLH = NativeBHP1024<<Circuit as Environment>::Network>;
         type NativePH = NativeBHP512<<Circuit as Environment>::Network>;
 
-        let leaf_hasher = NativeLH::setup("AleoMerklePathTest0")?;
-        let path_hasher = NativePH::setup("AleoMerklePathTest1")?;
+        let leaf_hasher = NativeLH::setup(console::DomainSeparator::new("MerklePathTest", 0))?;
+        let path_hasher = NativePH::setup(console::DomainSeparator::new("MerklePathTest", 1))?;
 
         let mut create_leaves = |num_leaves| {
             (0..num_leaves)
@@ -112,7 +112,7 @@ mod tests {
     use anyhow::Result;
 
     const ITERATIONS: u128 = 10;
-    const DOMAIN: &str = "MerkleTreeCircuit0";
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("MerkleTreeCircuit", 0);
 
     macro_rules! check_verify {
         ($lh:ident, $ph:ident, $mode:ident, $depth:expr, $arity:expr, $num_inputs:expr, ($num_constants:expr, $num_public:expr, $num_private:expr, $num_constraints:expr)) => {{
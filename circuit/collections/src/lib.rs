@@ -17,3 +17,4 @@
 
 pub mod kary_merkle_tree;
 pub mod merkle_tree;
+pub mod sparse_merkle_tree;
@@ -65,7 +65,7 @@ mod tests {
     use anyhow::Result;
 
     const ITERATIONS: u64 = 10;
-    const DOMAIN: &str = "MerkleTreeCircuit0";
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("MerkleTreeCircuit", 0);
 
     macro_rules! check_hash_children {
         ($hash:ident, $mode:ident, ($num_constants:expr, $num_public:expr, $num_private:expr, $num_constraints:expr)) => {{
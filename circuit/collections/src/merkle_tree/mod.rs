@@ -13,7 +13,10 @@
 // limitations under the License.
 
 mod helpers;
-use helpers::{LeafHash, PathHash};
+pub use helpers::{LeafHash, PathHash};
+
+mod multi_path;
+pub use multi_path::MerkleMultiPath;
 
 mod verify;
 
@@ -0,0 +1,482 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Merkle multi-path, which authenticates a set of leaves under one root.
+///
+/// If the leaf indices are constants, the multi-path shares the nodes that are common to its leaves, so
+/// each shared node is hashed once. Otherwise, the shape of the circuit must not depend on the leaf indices,
+/// so each leaf is authenticated along its own path, with the siblings that are shared between leaves
+/// selected from the other paths in the circuit.
+pub struct MerkleMultiPath<E: Environment, const DEPTH: u8> {
+    /// The leaf indices for the multi-path, in strictly increasing order.
+    leaf_indices: Vec<U64<E>>,
+    /// The siblings for the multi-path.
+    siblings: MultiPathSiblings<E>,
+}
+
+/// The siblings of a Merkle multi-path.
+enum MultiPathSiblings<E: Environment> {
+    /// The sibling hashes, ordered by level from the leaves to the root, and by position from left to right
+    /// within each level, for constant leaf indices.
+    Shared(Vec<Field<E>>),
+    /// The sibling hashes of each leaf, from the leaf to the root, for non-constant leaf indices.
+    /// The siblings that are computed from the other leaves are zero, and are not used.
+    PerLeaf(Vec<Vec<Field<E>>>),
+}
+
+impl<E: Environment, const DEPTH: u8> MerkleMultiPath<E, DEPTH> {
+    /// Initializes a Merkle multi-path with constant leaf indices, and siblings in the given mode.
+    ///
+    /// As the leaf indices are constants, the nodes that are shared between leaves are hashed once.
+    #[cfg(console)]
+    pub fn new_with_constant_leaf_indices(
+        mode: Mode,
+        merkle_path: console::merkle_tree::MerkleMultiPath<E::Network, DEPTH>,
+    ) -> Self {
+        // Initialize the leaf indices.
+        let leaf_indices = merkle_path.leaf_indices().iter().map(|index| U64::constant(*index)).collect();
+        // Initialize the Merkle multi-path siblings.
+        let siblings = merkle_path.siblings().iter().map(|node| Field::new(mode, *node)).collect();
+        // Return the Merkle multi-path.
+        Self { leaf_indices, siblings: MultiPathSiblings::Shared(siblings) }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const DEPTH: u8> Inject for MerkleMultiPath<E, DEPTH> {
+    type Primitive = console::merkle_tree::MerkleMultiPath<E::Network, DEPTH>;
+
+    /// Initializes a Merkle multi-path from the given mode and native Merkle multi-path.
+    ///
+    /// If the mode is not constant, the number of siblings only depends on the number of leaves and `DEPTH`.
+    fn new(mode: Mode, merkle_path: Self::Primitive) -> Self {
+        if mode.is_constant() {
+            return Self::new_with_constant_leaf_indices(mode, merkle_path);
+        }
+        // Initialize the leaf indices.
+        let leaf_indices = merkle_path.leaf_indices().iter().map(|index| U64::new(mode, *index)).collect();
+        // Initialize the siblings of each leaf.
+        let siblings = merkle_path
+            .siblings_by_leaf()
+            .into_iter()
+            .map(|siblings| {
+                siblings.into_iter().map(|node| Field::new(mode, node.unwrap_or_else(console::Field::zero))).collect()
+            })
+            .collect();
+        // Return the Merkle multi-path.
+        Self { leaf_indices, siblings: MultiPathSiblings::PerLeaf(siblings) }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const DEPTH: u8> Eject for MerkleMultiPath<E, DEPTH> {
+    type Primitive = console::merkle_tree::MerkleMultiPath<E::Network, DEPTH>;
+
+    /// Ejects the mode of the Merkle multi-path.
+    fn eject_mode(&self) -> Mode {
+        match &self.siblings {
+            MultiPathSiblings::Shared(siblings) => (&self.leaf_indices, siblings).eject_mode(),
+            MultiPathSiblings::PerLeaf(siblings) => (&self.leaf_indices, siblings).eject_mode(),
+        }
+    }
+
+    /// Ejects the Merkle multi-path.
+    fn eject_value(&self) -> Self::Primitive {
+        let leaf_indices = self.leaf_indices.eject_value();
+        let merkle_path = match &self.siblings {
+            MultiPathSiblings::Shared(siblings) => Self::Primitive::try_from((leaf_indices, siblings.eject_value())),
+            MultiPathSiblings::PerLeaf(siblings) => {
+                Self::Primitive::from_siblings_by_leaf(leaf_indices, &siblings.eject_value())
+            }
+        };
+        match merkle_path {
+            Ok(merkle_path) => merkle_path,
+            Err(error) => E::halt(format!("Failed to eject the Merkle multi-path: {error}")),
+        }
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> MerkleMultiPath<E, DEPTH> {
+    /// Returns `true` if the Merkle multi-path is valid for the given root and leaves.
+    /// The leaves must be given in the same order as the leaf indices.
+    pub fn verify<LH: LeafHash<E, Hash = PH::Hash>, PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaves: &[LH::Leaf],
+    ) -> Boolean<E> {
+        // Ensure the number of leaves matches the number of leaf indices.
+        if leaves.len() != self.leaf_indices.len() {
+            E::halt("Found an incorrect number of Merkle leaves")
+        }
+        // Ensure there is at least one leaf index.
+        else if self.leaf_indices.is_empty() {
+            E::halt("Merkle multi-path must contain at least one leaf index")
+        }
+
+        // Compute the leaf hashes.
+        let leaf_hashes = leaves.iter().map(|leaf| leaf_hasher.hash_leaf(leaf)).collect();
+
+        match &self.siblings {
+            MultiPathSiblings::Shared(siblings) => self.verify_shared(path_hasher, root, leaf_hashes, siblings),
+            MultiPathSiblings::PerLeaf(siblings) => self.verify_per_leaf(path_hasher, root, leaf_hashes, siblings),
+        }
+    }
+
+    /// Returns `true` if the Merkle multi-path with constant leaf indices is valid for the given root and leaf hashes.
+    ///
+    /// As the leaf indices are constants, the nodes that are shared between leaves are determined
+    /// when the circuit is synthesized, and each shared node is hashed once.
+    fn verify_shared<PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf_hashes: Vec<Field<E>>,
+        siblings: &[Field<E>],
+    ) -> Boolean<E> {
+        // Ensure the leaf indices are constants, as they determine the shape of the circuit.
+        if !self.leaf_indices.iter().all(|index| index.is_constant()) {
+            E::halt("Merkle multi-path with shared siblings must have constant leaf indices")
+        }
+
+        // Retrieve the positions of the leaves.
+        let mut positions = self.leaf_indices.iter().map(|index| *index.eject_value()).collect::<Vec<_>>();
+        // Ensure the leaf indices are in strictly increasing order.
+        if positions.windows(2).any(|pair| pair[0] >= pair[1]) {
+            E::halt("Merkle multi-path leaf indices must be in strictly increasing order")
+        }
+        // Ensure the leaf indices are within the tree depth.
+        else if positions.iter().any(|position| (*position as u128) >= (1u128 << DEPTH)) {
+            E::halt("Found an out of bounds Merkle leaf index")
+        }
+
+        // Initialize a tracker for the current hashes on the current level.
+        let mut current_hashes = leaf_hashes;
+        // Initialize an iterator over the sibling hashes.
+        let mut siblings = siblings.iter();
+
+        // Check levels between leaf level and root.
+        for _ in 0..DEPTH {
+            let mut parent_hashes = Vec::with_capacity(current_hashes.len());
+            let mut parent_positions = Vec::with_capacity(positions.len());
+            let mut current = 0;
+            while current < current_hashes.len() {
+                let position = positions[current];
+                let current_hash = &current_hashes[current];
+                // Get the sibling hash, which is either the next node on this level, or the next sibling in the path.
+                let sibling_hash = match positions.get(current + 1) == Some(&(position ^ 1)) {
+                    true => {
+                        current += 2;
+                        &current_hashes[current - 1]
+                    }
+                    false => {
+                        current += 1;
+                        match siblings.next() {
+                            Some(sibling_hash) => sibling_hash,
+                            None => E::halt("Found an insufficient number of Merkle multi-path siblings"),
+                        }
+                    }
+                };
+                // Construct the ordering of the left & right child hash for this level.
+                let (left, right) = match position & 1 == 0 {
+                    true => (current_hash, sibling_hash),
+                    false => (sibling_hash, current_hash),
+                };
+                // Compute the parent hash for the next level.
+                parent_hashes.push(path_hasher.hash_children(left, right));
+                parent_positions.push(position >> 1);
+            }
+            current_hashes = parent_hashes;
+            positions = parent_positions;
+        }
+
+        // Ensure every sibling hash was used.
+        if siblings.next().is_some() {
+            E::halt("Found an excess number of Merkle multi-path siblings")
+        }
+
+        // Ensure the final hash matches the given root.
+        match current_hashes.as_slice() {
+            [current_hash] => root.is_equal(current_hash),
+            _ => E::halt("Found an invalid Merkle multi-path"),
+        }
+    }
+
+    /// Returns `true` if the Merkle multi-path is valid for the given root and leaf hashes.
+    ///
+    /// The shape of the circuit only depends on the number of leaves and `DEPTH`. Each leaf is hashed along its
+    /// own path to the root. On each level, the sibling of a node is taken from the nearest path with a different
+    /// node, if that node is the sibling, and otherwise from the siblings of the leaf.
+    fn verify_per_leaf<PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf_hashes: Vec<Field<E>>,
+        siblings: &[Vec<Field<E>>],
+    ) -> Boolean<E> {
+        let num_leaves = self.leaf_indices.len();
+        // Ensure there are siblings for each leaf, on each level.
+        if siblings.len() != num_leaves || siblings.iter().any(|siblings| siblings.len() != DEPTH as usize) {
+            E::halt("Found an incorrect Merkle multi-path length")
+        }
+
+        // Initialize a tracker for the validity of the leaf indices.
+        let mut is_valid = Boolean::constant(true);
+        // Ensure the leaf indices are in strictly increasing order.
+        for pair in self.leaf_indices.windows(2) {
+            is_valid &= pair[0].is_less_than(&pair[1]);
+        }
+        // Retrieve the bits of each leaf index, ensuring the leaf indices are within the tree depth.
+        let bits = self
+            .leaf_indices
+            .iter()
+            .map(|leaf_index| {
+                let mut bits = leaf_index.to_bits_le();
+                bits.split_off(DEPTH as usize).iter().for_each(|bit| is_valid &= !bit);
+                bits
+            })
+            .collect::<Vec<_>>();
+
+        // Compute, for each pair of adjacent leaves, whether the leaves share the node on each level.
+        // A pair shares the node on a level if their leaf indices match on that level and all levels above.
+        let mut same_node = vec![vec![Boolean::constant(true); DEPTH as usize + 1]; num_leaves.saturating_sub(1)];
+        for (i, pair) in same_node.iter_mut().enumerate() {
+            for level in (0..DEPTH as usize).rev() {
+                pair[level] = &pair[level + 1] & bits[i][level].is_equal(&bits[i + 1][level]);
+            }
+        }
+
+        // Initialize a tracker for the current hash of each leaf.
+        let mut current_hashes = leaf_hashes;
+
+        // Check levels between leaf level and root.
+        for level in 0..DEPTH as usize {
+            // Compute the hash of the nearest node on the left of each leaf, and whether it is the sibling.
+            let mut left_nodes = Vec::with_capacity(num_leaves);
+            left_nodes.push((Field::zero(), Boolean::constant(false)));
+            for i in 1..num_leaves {
+                let (left_hash, left_is_sibling) = &left_nodes[i - 1];
+                let is_same = &same_node[i - 1][level];
+                let left_node = (
+                    Field::ternary(is_same, left_hash, &current_hashes[i - 1]),
+                    Boolean::ternary(is_same, left_is_sibling, &same_node[i - 1][level + 1]),
+                );
+                left_nodes.push(left_node);
+            }
+            // Compute the hash of the nearest node on the right of each leaf, and whether it is the sibling.
+            let mut right_nodes = vec![(Field::zero(), Boolean::constant(false)); num_leaves];
+            for i in (0..num_leaves.saturating_sub(1)).rev() {
+                let is_same = &same_node[i][level];
+                right_nodes[i] = (
+                    Field::ternary(is_same, &right_nodes[i + 1].0, &current_hashes[i + 1]),
+                    Boolean::ternary(is_same, &right_nodes[i + 1].1, &same_node[i][level + 1]),
+                );
+            }
+
+            current_hashes = (0..num_leaves)
+                .map(|i| {
+                    // If the bit is `true`, the current node is the right child, and its sibling is on the left.
+                    let bit = &bits[i][level];
+                    let ((left_hash, left_is_sibling), (right_hash, right_is_sibling)) =
+                        (&left_nodes[i], &right_nodes[i]);
+                    let neighbor_hash = Field::ternary(bit, left_hash, right_hash);
+                    let neighbor_is_sibling = Boolean::ternary(bit, left_is_sibling, right_is_sibling);
+                    // Select the sibling hash from the nearest node, or from the siblings of the leaf.
+                    let sibling_hash = Field::ternary(&neighbor_is_sibling, &neighbor_hash, &siblings[i][level]);
+
+                    // Construct the ordering of the left & right child hash for this level.
+                    let left = Field::ternary(bit, &sibling_hash, &current_hashes[i]);
+                    let right = Field::ternary(bit, &current_hashes[i], &sibling_hash);
+                    // Compute the parent hash for the next level.
+                    path_hasher.hash_children(&left, &right)
+                })
+                .collect();
+        }
+
+        // Ensure the final hash of each leaf matches the given root.
+        current_hashes.iter().fold(is_valid, |is_valid, current_hash| is_valid & root.is_equal(current_hash))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_algorithms::{Poseidon2, Poseidon4};
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: usize = 10;
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("MerkleTreeCircuit", 0);
+    const DEPTH: u8 = 16;
+
+    type NativeLeafHasher = snarkvm_console_algorithms::Poseidon4<<Circuit as Environment>::Network>;
+    type NativePathHasher = snarkvm_console_algorithms::Poseidon2<<Circuit as Environment>::Network>;
+    type NativeTree =
+        console::merkle_tree::MerkleTree<<Circuit as Environment>::Network, NativeLeafHasher, NativePathHasher, DEPTH>;
+    type NativeLeaf = Vec<console::Field<<Circuit as Environment>::Network>>;
+
+    /// Returns the native and circuit hashers.
+    fn sample_hashers() -> Result<(NativeLeafHasher, NativePathHasher, Poseidon4<Circuit>, Poseidon2<Circuit>)> {
+        let native_leaf_hasher = NativeLeafHasher::setup(DOMAIN)?;
+        let native_path_hasher = NativePathHasher::setup(DOMAIN)?;
+        let circuit_leaf_hasher = Poseidon4::<Circuit>::constant(native_leaf_hasher.clone());
+        let circuit_path_hasher = Poseidon2::<Circuit>::constant(native_path_hasher.clone());
+        Ok((native_leaf_hasher, native_path_hasher, circuit_leaf_hasher, circuit_path_hasher))
+    }
+
+    /// Returns the given number of random leaves.
+    fn sample_leaves(num_leaves: usize, rng: &mut TestRng) -> Vec<NativeLeaf> {
+        (0..num_leaves).map(|_| (0..4).map(|_| Uniform::rand(rng)).collect()).collect()
+    }
+
+    fn check_verify(mode: Mode, constant_leaf_indices: bool) -> Result<()> {
+        let (native_leaf_hasher, native_path_hasher, circuit_leaf_hasher, circuit_path_hasher) = sample_hashers()?;
+
+        let mut rng = TestRng::default();
+
+        for i in 1..=ITERATIONS {
+            // Compute the leaves.
+            let leaves = sample_leaves(i * 3, &mut rng);
+            // Compute the Merkle tree.
+            let merkle_tree = NativeTree::new(&native_leaf_hasher, &native_path_hasher, &leaves)?;
+
+            // Select a random subset of the leaves, ensuring at least one leaf is selected.
+            let selected = leaves
+                .iter()
+                .cloned()
+                .enumerate()
+                .filter(|(index, _)| *index == 0 || bool::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let selected_leaves = selected.iter().map(|(_, leaf)| leaf.clone()).collect::<Vec<_>>();
+
+            // Compute the Merkle multi-path.
+            let native_path = merkle_tree.prove_many(&selected)?;
+
+            // Initialize the Merkle multi-path.
+            let path = match constant_leaf_indices {
+                true => MerkleMultiPath::<Circuit, DEPTH>::new_with_constant_leaf_indices(mode, native_path.clone()),
+                false => MerkleMultiPath::<Circuit, DEPTH>::new(mode, native_path.clone()),
+            };
+            assert_eq!(native_path, path.eject_value());
+            // Initialize the Merkle root.
+            let root = Field::new(mode, *merkle_tree.root());
+            // Initialize the Merkle leaves.
+            let circuit_leaves: Vec<Vec<_>> = Inject::new(mode, selected_leaves.clone());
+
+            Circuit::scope(format!("Verify {mode}"), || {
+                let candidate = path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &root, &circuit_leaves);
+                assert!(candidate.eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            let num_constraints = Circuit::num_constraints();
+            Circuit::reset();
+
+            // Ensure the Merkle multi-path with constant leaf indices uses no more constraints than the Merkle paths.
+            if constant_leaf_indices {
+                for (index, leaf) in &selected {
+                    let path = MerklePath::<Circuit, DEPTH>::new(mode, merkle_tree.prove(*index, leaf)?);
+                    let leaf: Vec<_> = Inject::new(mode, leaf.clone());
+                    assert!(path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &root, &leaf).eject_value());
+                }
+                assert!(num_constraints <= Circuit::num_constraints());
+                Circuit::reset();
+            }
+
+            // Ensure the Merkle multi-path fails on an incorrect root.
+            let incorrect_root = root.clone() + Field::one();
+            let candidate = path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &incorrect_root, &circuit_leaves);
+            assert!(!candidate.eject_value());
+            Circuit::reset();
+
+            // Ensure the Merkle multi-path fails on an incorrect leaf.
+            let mut incorrect_leaves = selected_leaves.clone();
+            incorrect_leaves[0][0] = Uniform::rand(&mut rng);
+            let incorrect_leaves: Vec<Vec<_>> = Inject::new(mode, incorrect_leaves);
+            let candidate = path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &root, &incorrect_leaves);
+            assert!(!candidate.eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_constant() -> Result<()> {
+        check_verify(Mode::Constant, true)
+    }
+
+    #[test]
+    fn test_verify_public() -> Result<()> {
+        check_verify(Mode::Public, false)
+    }
+
+    #[test]
+    fn test_verify_private() -> Result<()> {
+        check_verify(Mode::Private, false)
+    }
+
+    #[test]
+    fn test_verify_public_with_constant_leaf_indices() -> Result<()> {
+        check_verify(Mode::Public, true)
+    }
+
+    #[test]
+    fn test_verify_private_with_constant_leaf_indices() -> Result<()> {
+        check_verify(Mode::Private, true)
+    }
+
+    #[test]
+    fn test_verify_private_shape_is_independent_of_leaf_indices() -> Result<()> {
+        let (native_leaf_hasher, native_path_hasher, circuit_leaf_hasher, circuit_path_hasher) = sample_hashers()?;
+
+        let mut rng = TestRng::default();
+
+        // Compute the Merkle tree.
+        let leaves = sample_leaves(64, &mut rng);
+        let merkle_tree = NativeTree::new(&native_leaf_hasher, &native_path_hasher, &leaves)?;
+
+        // Returns the number of constants, public variables, private variables, and constraints to verify
+        // the Merkle multi-path of the given leaf indices, with private leaf indices.
+        let synthesize = |leaf_indices: &[usize]| -> Result<(u64, u64, u64, u64)> {
+            Circuit::reset();
+            let selected = leaf_indices.iter().map(|index| (*index, leaves[*index].clone())).collect::<Vec<_>>();
+            let path = MerkleMultiPath::<Circuit, DEPTH>::new(Mode::Private, merkle_tree.prove_many(&selected)?);
+            let root = Field::new(Mode::Private, *merkle_tree.root());
+            let circuit_leaves: Vec<Vec<_>> =
+                Inject::new(Mode::Private, selected.into_iter().map(|(_, leaf)| leaf).collect::<Vec<_>>());
+            assert!(path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &root, &circuit_leaves).eject_value());
+            assert!(Circuit::is_satisfied());
+            let counts =
+                (Circuit::num_constants(), Circuit::num_public(), Circuit::num_private(), Circuit::num_constraints());
+            Circuit::reset();
+            Ok(counts)
+        };
+
+        // Check that disjoint, adjacent, and overlapping leaf indices synthesize the same circuit.
+        let expected = synthesize(&[0, 21, 42, 63])?;
+        assert_eq!(expected, synthesize(&[0, 1, 2, 3])?);
+        assert_eq!(expected, synthesize(&[30, 31, 32, 33])?);
+        for _ in 0..ITERATIONS {
+            let leaf_indices = (0..64).filter(|_| bool::rand(&mut rng)).take(4).collect::<Vec<_>>();
+            if leaf_indices.len() == 4 {
+                assert_eq!(expected, synthesize(&leaf_indices)?);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const DEPTH: u8> SparseMerklePath<E, DEPTH> {
+    /// Returns `true` if the sparse Merkle path is valid for the given root, key, and value.
+    pub fn verify_membership<LH: LeafHash<E, Hash = PH::Hash>, PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &Field<E>,
+        key: &Field<E>,
+        value: &LH::Leaf,
+    ) -> Boolean<E> {
+        // Compute the hash of the leaf node, which binds the key to the value.
+        let leaf_hash = path_hasher.hash_children(key, &leaf_hasher.hash_leaf(value));
+        // Ensure the final hash matches the given root.
+        root.is_equal(&self.compute_root(path_hasher, key, leaf_hash))
+    }
+
+    /// Returns the root of the sparse Merkle tree, given the leaf hash at the position of the given key.
+    fn compute_root<PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        key: &Field<E>,
+        leaf_hash: Field<E>,
+    ) -> Field<E> {
+        // Ensure the path length matches the expected depth.
+        if self.siblings.len() != DEPTH as usize {
+            E::halt("Found an incorrect sparse Merkle path length")
+        }
+
+        // Compute the ordering of the current hash and sibling hash on each level.
+        // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
+        // If the indicator bit is `false`, then the ordering is (sibling_hash, current_hash).
+        let indicators = key.to_bits_le().into_iter().take(DEPTH as usize).map(|b| !b);
+
+        // Check levels between leaf level and root.
+        let mut current_hash = leaf_hash;
+        for (indicator, sibling_hash) in indicators.zip_eq(&self.siblings) {
+            // Construct the ordering of the left & right child hash for this level.
+            let left = Field::ternary(&indicator, &current_hash, sibling_hash);
+            let right = Field::ternary(&indicator, sibling_hash, &current_hash);
+
+            // Update the current hash for the next level.
+            current_hash = path_hasher.hash_children(&left, &right);
+        }
+        current_hash
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> NonMembershipProof<E, DEPTH> {
+    /// Returns `true` if the proof shows the given key is not in the sparse Merkle tree with the given root.
+    pub fn verify<PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &Field<E>,
+        key: &Field<E>,
+    ) -> Boolean<E> {
+        // As every key has its own leaf, the key is absent if and only if its leaf is empty.
+        root.is_equal(&self.path.compute_root(path_hasher, key, Field::zero()))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_algorithms::{Poseidon2, Poseidon4};
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: usize = 10;
+    const DOMAIN: console::DomainSeparator = console::DomainSeparator::new("SparseMerkleTreeCircuit", 0);
+    const DEPTH: u8 = 253;
+
+    type NativeTree = console::sparse_merkle_tree::SparseMerkleTree<
+        <Circuit as Environment>::Network,
+        snarkvm_console_algorithms::Poseidon4<<Circuit as Environment>::Network>,
+        snarkvm_console_algorithms::Poseidon2<<Circuit as Environment>::Network>,
+        DEPTH,
+    >;
+
+    fn check_verify(mode: Mode) -> Result<()> {
+        // Initialize the leaf hasher.
+        let native_leaf_hasher =
+            snarkvm_console_algorithms::Poseidon4::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit_leaf_hasher = Poseidon4::<Circuit>::constant(native_leaf_hasher.clone());
+        // Initialize the path hasher.
+        let native_path_hasher =
+            snarkvm_console_algorithms::Poseidon2::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit_path_hasher = Poseidon2::<Circuit>::constant(native_path_hasher.clone());
+
+        let mut rng = TestRng::default();
+
+        // Construct the sparse Merkle tree.
+        let mut tree = NativeTree::new(&native_leaf_hasher, &native_path_hasher)?;
+        let entries = (0..ITERATIONS)
+            .map(|_| (Uniform::rand(&mut rng), (0..4).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        for (key, value) in &entries {
+            tree.insert(key, value)?;
+        }
+        let root = Field::new(mode, *tree.root());
+
+        for (key, value) in &entries {
+            // Initialize the sparse Merkle path.
+            let native_path = tree.prove_membership(key, value)?;
+            let path = SparseMerklePath::<Circuit, DEPTH>::new(mode, native_path.clone());
+            assert_eq!(native_path, path.eject_value());
+            let circuit_key = Field::new(mode, *key);
+            let circuit_value: Vec<_> = Inject::new(mode, value.clone());
+
+            Circuit::scope(format!("Verify membership {mode}"), || {
+                let candidate = path.verify_membership(
+                    &circuit_leaf_hasher,
+                    &circuit_path_hasher,
+                    &root,
+                    &circuit_key,
+                    &circuit_value,
+                );
+                assert!(candidate.eject_value());
+            });
+            Circuit::reset();
+
+            // Ensure the membership of another key fails.
+            let other_key = Field::new(mode, Uniform::rand(&mut rng));
+            let candidate =
+                path.verify_membership(&circuit_leaf_hasher, &circuit_path_hasher, &root, &other_key, &circuit_value);
+            assert!(!candidate.eject_value());
+            Circuit::reset();
+
+            // Ensure the non-membership of a key in the tree fails, using a proof for a key that is not in the tree.
+            let absent_key = Uniform::rand(&mut rng);
+            let proof = NonMembershipProof::<Circuit, DEPTH>::new(mode, tree.prove_non_membership(&absent_key)?);
+            let candidate = proof.verify(&circuit_path_hasher, &root, &circuit_key);
+            assert!(!candidate.eject_value());
+            Circuit::reset();
+        }
+
+        for _ in 0..ITERATIONS {
+            // Initialize the non-membership proof.
+            let key = Uniform::rand(&mut rng);
+            let native_proof = tree.prove_non_membership(&key)?;
+            let proof = NonMembershipProof::<Circuit, DEPTH>::new(mode, native_proof.clone());
+            assert_eq!(native_proof, proof.eject_value());
+            let circuit_key = Field::new(mode, key);
+
+            Circuit::scope(format!("Verify non-membership {mode}"), || {
+                let candidate = proof.verify(&circuit_path_hasher, &root, &circuit_key);
+                assert!(candidate.eject_value());
+            });
+            Circuit::reset();
+
+            // Ensure the proof fails on an incorrect root.
+            let incorrect_root = root.clone() + Field::one();
+            let candidate = proof.verify(&circuit_path_hasher, &incorrect_root, &circuit_key);
+            assert!(!candidate.eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_constant() -> Result<()> {
+        check_verify(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_public() -> Result<()> {
+        check_verify(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_private() -> Result<()> {
+        check_verify(Mode::Private)
+    }
+}
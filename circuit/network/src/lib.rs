@@ -73,6 +73,24 @@ pub trait Aleo: Environment {
     /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
     fn commit_to_group_ped128(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self>;
 
+    /// Returns the given BHP commitment with an input hasher of 256-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp256(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns the given BHP commitment with an input hasher of 512-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp512(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns the given BHP commitment with an input hasher of 768-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp768(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns the given BHP commitment with an input hasher of 1024-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp1024(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns the given Pedersen commitment for an (up to) 64-bit input, rerandomized by the given randomizer.
+    fn rerandomize_ped64(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns the given Pedersen commitment for an (up to) 128-bit input, rerandomized by the given randomizer.
+    fn rerandomize_ped128(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self>;
+
     /// Returns the BHP hash with an input hasher of 256-bits.
     fn hash_bhp256(input: &[Boolean<Self>]) -> Field<Self>;
 
@@ -163,6 +181,15 @@ pub trait Aleo: Environment {
     /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
     fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Scalar<Self>;
 
+    /// Returns the Poseidon PRF with an input rate of 2.
+    fn prf_psd2(seed: &Field<Self>, input: &[Field<Self>]) -> Field<Self>;
+
+    /// Returns the Poseidon PRF with an input rate of 4.
+    fn prf_psd4(seed: &Field<Self>, input: &[Field<Self>]) -> Field<Self>;
+
+    /// Returns the Poseidon PRF with an input rate of 8.
+    fn prf_psd8(seed: &Field<Self>, input: &[Field<Self>]) -> Field<Self>;
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
     #[allow(clippy::ptr_arg)]
     fn verify_merkle_path_bhp<const DEPTH: u8>(
@@ -36,6 +36,7 @@ use snarkvm_circuit_algorithms::{
     BHP256,
     BHP512,
     BHP768,
+    PRF,
 };
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_types::{
@@ -187,6 +188,36 @@ impl Aleo for AleoV0 {
         PEDERSEN_128.with(|pedersen| pedersen.commit_uncompressed(input, randomizer))
     }
 
+    /// Returns the given BHP commitment with an input hasher of 256-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp256(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self> {
+        BHP_256.with(|bhp| bhp.rerandomize(commitment, delta_randomness))
+    }
+
+    /// Returns the given BHP commitment with an input hasher of 512-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp512(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self> {
+        BHP_512.with(|bhp| bhp.rerandomize(commitment, delta_randomness))
+    }
+
+    /// Returns the given BHP commitment with an input hasher of 768-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp768(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self> {
+        BHP_768.with(|bhp| bhp.rerandomize(commitment, delta_randomness))
+    }
+
+    /// Returns the given BHP commitment with an input hasher of 1024-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp1024(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self> {
+        BHP_1024.with(|bhp| bhp.rerandomize(commitment, delta_randomness))
+    }
+
+    /// Returns the given Pedersen commitment for an (up to) 64-bit input, rerandomized by the given randomizer.
+    fn rerandomize_ped64(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self> {
+        PEDERSEN_64.with(|pedersen| pedersen.rerandomize(commitment, delta_randomness))
+    }
+
+    /// Returns the given Pedersen commitment for an (up to) 128-bit input, rerandomized by the given randomizer.
+    fn rerandomize_ped128(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self> {
+        PEDERSEN_128.with(|pedersen| pedersen.rerandomize(commitment, delta_randomness))
+    }
+
     /// Returns the BHP hash with an input hasher of 256-bits.
     fn hash_bhp256(input: &[Boolean<Self>]) -> Field<Self> {
         BHP_256.with(|bhp| bhp.hash(input))
@@ -337,6 +368,21 @@ impl Aleo for AleoV0 {
         POSEIDON_8.with(|poseidon| poseidon.hash_to_scalar(input))
     }
 
+    /// Returns the Poseidon PRF with an input rate of 2.
+    fn prf_psd2(seed: &Field<Self>, input: &[Field<Self>]) -> Field<Self> {
+        POSEIDON_2.with(|poseidon| poseidon.prf(seed, input))
+    }
+
+    /// Returns the Poseidon PRF with an input rate of 4.
+    fn prf_psd4(seed: &Field<Self>, input: &[Field<Self>]) -> Field<Self> {
+        POSEIDON_4.with(|poseidon| poseidon.prf(seed, input))
+    }
+
+    /// Returns the Poseidon PRF with an input rate of 8.
+    fn prf_psd8(seed: &Field<Self>, input: &[Field<Self>]) -> Field<Self> {
+        POSEIDON_8.with(|poseidon| poseidon.prf(seed, input))
+    }
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
     fn verify_merkle_path_bhp<const DEPTH: u8>(
         path: &MerklePath<Self, DEPTH>,
@@ -497,9 +543,14 @@ impl Display for AleoV0 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::{
+        prelude::{TestRng, Uniform},
+        Network,
+    };
     use snarkvm_circuit_types::Field;
 
     type CurrentAleo = AleoV0;
+    type CurrentNetwork = <CurrentAleo as Environment>::Network;
 
     /// Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
     fn create_example_circuit<E: Environment>() -> Field<E> {
@@ -533,6 +584,62 @@ mod tests {
         println!("{output}");
     }
 
+    #[test]
+    fn test_prf_psd() {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=10 {
+            // Sample the seed and input shared by the native and circuit PRFs.
+            let seed = Uniform::rand(&mut rng);
+            let input = (0..num_inputs).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Compute the native PRFs.
+            let expected_psd2 = CurrentNetwork::prf_psd2(&seed, &input).unwrap();
+            let expected_psd4 = CurrentNetwork::prf_psd4(&seed, &input).unwrap();
+            let expected_psd8 = CurrentNetwork::prf_psd8(&seed, &input).unwrap();
+
+            // Compute the circuit PRFs.
+            let seed = Field::<CurrentAleo>::new(Mode::Private, seed);
+            let input = input.iter().map(|value| Field::new(Mode::Private, *value)).collect::<Vec<_>>();
+            assert_eq!(expected_psd2, CurrentAleo::prf_psd2(&seed, &input).eject_value());
+            assert_eq!(expected_psd4, CurrentAleo::prf_psd4(&seed, &input).eject_value());
+            assert_eq!(expected_psd8, CurrentAleo::prf_psd8(&seed, &input).eject_value());
+            assert!(CurrentAleo::is_satisfied());
+            CurrentAleo::reset();
+        }
+    }
+
+    #[test]
+    fn test_prf_psd_vectors() {
+        // The expected outputs are those of the console PRFs, for the seed `1`.
+        const VECTORS: [(usize, u64, &str); 9] = [
+            (2, 0, "107338370619223092068132723896052301422983572279086036748479670139944641824field"),
+            (2, 2, "7200107266741300576287128422632566638252611441475937708605571633688427921904field"),
+            (2, 9, "6097704810018335053522063435537532026721173864405929333283623445754251926173field"),
+            (4, 0, "4118718987748259783013585284985897557152498697752820235066334744459981242055field"),
+            (4, 2, "8393672207741630055028942157821890981007845026669435324694477579426687904205field"),
+            (4, 9, "2106999547717860622662407507889883997770862845626559669869564741371816422769field"),
+            (8, 0, "5979392658650639904568495682277601692484903308279058440836580197467543126506field"),
+            (8, 2, "6997114219207164604936152705742629637102890770395303842448243806833877237446field"),
+            (8, 9, "5130062477044463882375323808941579230368813101119888044858859831705367012888field"),
+        ];
+
+        for (rate, num_inputs, expected) in VECTORS {
+            let seed = Field::<CurrentAleo>::new(Mode::Private, console::Field::one());
+            let input = (2..2 + num_inputs)
+                .map(|value| Field::new(Mode::Private, console::Field::from_u64(value)))
+                .collect::<Vec<_>>();
+            let candidate = match rate {
+                2 => CurrentAleo::prf_psd2(&seed, &input),
+                4 => CurrentAleo::prf_psd4(&seed, &input),
+                _ => CurrentAleo::prf_psd8(&seed, &input),
+            };
+            assert_eq!(candidate.eject_value(), console::Field::from_str(expected).unwrap());
+            assert!(CurrentAleo::is_satisfied());
+            CurrentAleo::reset();
+        }
+    }
+
     #[test]
     fn test_circuit_scope() {
         CurrentAleo::scope("test_circuit_scope", || {
@@ -24,6 +24,24 @@ impl<A: Aleo> Record<A, Plaintext<A>> {
         // Compute the BHP hash of the program record.
         A::hash_bhp1024(&input)
     }
+
+    /// Returns a hiding record commitment with the given randomizer.
+    ///
+    /// Unlike the record commitment, it can be rerandomized with `A::rerandomize_bhp1024`,
+    /// without revealing or changing the record.
+    pub fn to_hiding_commitment(
+        &self,
+        program_id: &ProgramID<A>,
+        record_name: &Identifier<A>,
+        randomizer: &Scalar<A>,
+    ) -> Group<A> {
+        // Construct the input as `(program_id || record_name || record)`.
+        let mut input = program_id.to_bits_le();
+        record_name.write_bits_le(&mut input);
+        self.write_bits_le(&mut input);
+        // Compute the BHP commitment of the program record.
+        A::commit_to_group_bhp1024(&input, randomizer)
+    }
 }
 
 impl<A: Aleo> Record<A, Ciphertext<A>> {
@@ -31,4 +49,14 @@ impl<A: Aleo> Record<A, Ciphertext<A>> {
     pub fn to_commitment(&self, _program_id: &ProgramID<A>, _record_name: &Identifier<A>) -> Field<A> {
         A::halt("Illegal operation: Record::to_commitment() cannot be invoked on the `Ciphertext` variant.")
     }
+
+    /// Returns a hiding record commitment with the given randomizer.
+    pub fn to_hiding_commitment(
+        &self,
+        _program_id: &ProgramID<A>,
+        _record_name: &Identifier<A>,
+        _randomizer: &Scalar<A>,
+    ) -> Group<A> {
+        A::halt("Illegal operation: Record::to_hiding_commitment() cannot be invoked on the `Ciphertext` variant.")
+    }
 }
@@ -56,10 +56,20 @@ fn account_address(c: &mut Criterion) {
     });
 }
 
+fn account_batch(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    c.bench_function("account_batch_1000", move |b| {
+        b.iter(|| {
+            let _accounts = PrivateKey::<CurrentNetwork>::new_batch(1000, rng).unwrap();
+        })
+    });
+}
+
 criterion_group! {
     name = account;
     config = Criterion::default().sample_size(20);
-    targets = account_private_key, account_view_key, account_address
+    targets = account_private_key, account_view_key, account_address, account_batch
 }
 
 criterion_main!(account);
@@ -90,15 +90,15 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new address.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-            let expected = Address::try_from(private_key)?;
+            let expected = Address::try_from(&private_key)?;
 
             // Check the address derived from the compute key.
-            let compute_key = ComputeKey::<CurrentNetwork>::try_from(private_key)?;
+            let compute_key = ComputeKey::<CurrentNetwork>::try_from(&private_key)?;
             assert_eq!(expected, Address::try_from(compute_key)?);
 
             // Check the address derived from the view key.
-            let view_key = ViewKey::<CurrentNetwork>::try_from(private_key)?;
-            assert_eq!(expected, Address::try_from(view_key)?);
+            let view_key = ViewKey::<CurrentNetwork>::try_from(&private_key)?;
+            assert_eq!(expected, Address::try_from(&view_key)?);
         }
         Ok(())
     }
@@ -34,6 +34,10 @@ impl<N: Network> ToBytes for ComputeKey<N> {
     }
 }
 
+impl<N: Network> ConstSerializedSize for ComputeKey<N> {
+    const SIZE: usize = 2 * Field::<N>::SIZE;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,7 +54,7 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new compute key.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-            let expected = ComputeKey::try_from(private_key)?;
+            let expected = ComputeKey::try_from(&private_key)?;
 
             // Check the byte representation.
             let expected_bytes = expected.to_bytes_le()?;
@@ -48,7 +48,7 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new compute key.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-            let expected = ComputeKey::try_from(private_key)?;
+            let expected = ComputeKey::try_from(&private_key)?;
 
             // Serialize
             let expected_bytes = expected.to_bytes_le()?;
@@ -40,8 +40,8 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new compute key and address.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-            let compute_key = ComputeKey::try_from(private_key)?;
-            let address = Address::try_from(private_key)?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
 
             assert_eq!(address, compute_key.to_address());
         }
@@ -76,7 +76,7 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new compute key.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-            let candidate = ComputeKey::try_from(private_key)?;
+            let candidate = ComputeKey::try_from(&private_key)?;
 
             // Check that sk_prf matches.
             // Compute sk_prf := HashToScalar(pk_sig || pr_sig).
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Secret;
+
+const ASSOCIATED_DATA_DOMAIN: DomainSeparator = DomainSeparator::new("EncryptionAssociatedData", 0);
+
+impl<N: Network> ECIESPoseidonEncryption<N> {
+    /// Encrypts the given field elements under the given symmetric key, bound to the given associated data.
+    ///
+    /// The associated data is absorbed with the symmetric key before the key stream is squeezed, and the
+    /// ciphertext is authenticated, so `decrypt_with_aad` rejects it under any other associated data.
+    /// The associated data (e.g. a program ID or recipient address) is not encrypted.
+    pub fn encrypt_with_aad(
+        symmetric_key: &Field<N>,
+        associated_data: &[Field<N>],
+        message: &[Field<N>],
+    ) -> Result<Vec<Field<N>>> {
+        let bound_key = Self::bind_associated_data(symmetric_key, associated_data)?;
+        Self::encrypt_authenticated(&bound_key, message)
+    }
+
+    /// Checks that the given ciphertext is bound to the given associated data, and decrypts it.
+    pub fn decrypt_with_aad(
+        symmetric_key: &Field<N>,
+        associated_data: &[Field<N>],
+        ciphertext: &[Field<N>],
+    ) -> Result<Vec<Field<N>>> {
+        let bound_key = Self::bind_associated_data(symmetric_key, associated_data)?;
+        Self::decrypt_checked(&bound_key, ciphertext)
+    }
+
+    /// Returns the symmetric key bound to the given associated data.
+    ///
+    /// The length of the associated data is absorbed first, so that no associated data is a prefix of another.
+    fn bind_associated_data(symmetric_key: &Field<N>, associated_data: &[Field<N>]) -> Result<Secret<Field<N>>> {
+        let mut preimage = Vec::with_capacity(3 + associated_data.len());
+        preimage.push(Field::from_domain_separator(ASSOCIATED_DATA_DOMAIN));
+        preimage.push(*symmetric_key);
+        preimage.push(Field::from_u64(associated_data.len() as u64));
+        preimage.extend_from_slice(associated_data);
+        Ok(Secret::new(N::hash_psd8(&preimage)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Encryption = ECIESPoseidonEncryption<CurrentNetwork>;
+
+    const ITERATIONS: u64 = 25;
+
+    #[test]
+    fn test_encrypt_and_decrypt_with_aad() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let symmetric_key = Field::<CurrentNetwork>::rand(rng);
+            let associated_data = (0..rng.gen_range(0..5)).map(|_| Field::rand(rng)).collect_vec();
+            let message = Encryption::encode_message(&(0..rng.gen_range(0..100)).map(|_| rng.gen()).collect_vec())?;
+
+            let ciphertext = Encryption::encrypt_with_aad(&symmetric_key, &associated_data, &message)?;
+            assert_eq!(ciphertext.len(), message.len() + 1);
+            assert_eq!(message, Encryption::decrypt_with_aad(&symmetric_key, &associated_data, &ciphertext)?);
+
+            // Check that the ciphertext is not decrypted under other associated data.
+            let mut extended = associated_data.clone();
+            extended.push(Field::zero());
+            assert!(Encryption::decrypt_with_aad(&symmetric_key, &extended, &ciphertext).is_err());
+            for i in 0..associated_data.len() {
+                let mut modified = associated_data.clone();
+                modified[i] += Field::one();
+                assert!(Encryption::decrypt_with_aad(&symmetric_key, &modified, &ciphertext).is_err());
+            }
+            // Check that the ciphertext is not decrypted without the associated data.
+            assert!(Encryption::decrypt_checked(&symmetric_key, &ciphertext).is_err());
+            // Check that a different key is detected.
+            assert!(Encryption::decrypt_with_aad(&Field::rand(rng), &associated_data, &ciphertext).is_err());
+        }
+        Ok(())
+    }
+}
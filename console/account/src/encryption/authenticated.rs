@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+const TAG_DOMAIN: DomainSeparator = DomainSeparator::new("EncryptionTag", 0);
+
+impl<N: Network> ECIESPoseidonEncryption<N> {
+    /// Encrypts the given field elements under the given symmetric key, and appends a tag
+    /// that authenticates the ciphertext, so that any modification is detected by `decrypt_checked`.
+    pub fn encrypt_authenticated(symmetric_key: &Field<N>, message: &[Field<N>]) -> Result<Vec<Field<N>>> {
+        let mut ciphertext = Self::encrypt(symmetric_key, message);
+        let tag = Self::tag(symmetric_key, &ciphertext)?;
+        ciphertext.push(tag);
+        Ok(ciphertext)
+    }
+
+    /// Checks the tag of the given ciphertext under the given symmetric key, and decrypts it.
+    pub fn decrypt_checked(symmetric_key: &Field<N>, ciphertext: &[Field<N>]) -> Result<Vec<Field<N>>> {
+        let Some((tag, ciphertext)) = ciphertext.split_last() else {
+            bail!("The ciphertext is missing its tag");
+        };
+        ensure!(*tag == Self::tag(symmetric_key, ciphertext)?, "Failed to authenticate the ciphertext");
+        Ok(Self::decrypt(symmetric_key, ciphertext))
+    }
+
+    /// Returns the tag of the given ciphertext under the given symmetric key.
+    fn tag(symmetric_key: &Field<N>, ciphertext: &[Field<N>]) -> Result<Field<N>> {
+        let mut preimage = Vec::with_capacity(2 + ciphertext.len());
+        preimage.push(Field::from_domain_separator(TAG_DOMAIN));
+        preimage.push(*symmetric_key);
+        preimage.extend_from_slice(ciphertext);
+        N::hash_psd8(&preimage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Encryption = ECIESPoseidonEncryption<CurrentNetwork>;
+
+    const ITERATIONS: u64 = 25;
+
+    #[test]
+    fn test_encrypt_and_decrypt_checked() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let symmetric_key = Field::<CurrentNetwork>::rand(rng);
+            let message = Encryption::encode_message(&(0..rng.gen_range(0..100)).map(|_| rng.gen()).collect_vec())?;
+
+            let ciphertext = Encryption::encrypt_authenticated(&symmetric_key, &message)?;
+            assert_eq!(ciphertext.len(), message.len() + 1);
+            assert_eq!(message, Encryption::decrypt_checked(&symmetric_key, &ciphertext)?);
+
+            // Check that modifying any field element of the ciphertext is detected.
+            for i in 0..ciphertext.len() {
+                let mut modified = ciphertext.clone();
+                modified[i] += Field::one();
+                assert!(Encryption::decrypt_checked(&symmetric_key, &modified).is_err());
+            }
+            // Check that truncating the ciphertext is detected.
+            assert!(Encryption::decrypt_checked(&symmetric_key, &ciphertext[1..]).is_err());
+            // Check that a different key is detected.
+            assert!(Encryption::decrypt_checked(&Field::rand(rng), &ciphertext).is_err());
+        }
+        assert!(Encryption::decrypt_checked(&Field::rand(rng), &[]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Secret, ViewKey};
+use snarkvm_console_types::{Address, Group, Scalar};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+impl<N: Network> ECIESPoseidonEncryption<N> {
+    /// Samples the given number of key pairs, and returns the `(view key, address)` of each.
+    ///
+    /// The addresses are computed in parallel, with the fixed-base generator powers.
+    pub fn batch_generate_asymmetric_keys<R: Rng + CryptoRng>(
+        num_keys: usize,
+        rng: &mut R,
+    ) -> Vec<(ViewKey<N>, Address<N>)> {
+        // Sample the view keys sequentially, so that the keys are deterministic for a given RNG.
+        let scalars = (0..num_keys).map(|_| Scalar::rand(rng)).collect::<Vec<Scalar<N>>>();
+        cfg_into_iter!(scalars)
+            .map(|scalar| {
+                let view_key = ViewKey::from_scalar(scalar);
+                let address = view_key.to_address();
+                (view_key, address)
+            })
+            .collect()
+    }
+
+    /// Encrypts each of the given messages to its address, and returns the `(nonce, ciphertext)` of each.
+    ///
+    /// This is faster than encrypting each message separately, as the scalar multiplications run in parallel,
+    /// and the shared points are normalized with a single inversion.
+    pub fn batch_encrypt<R: Rng + CryptoRng>(
+        recipients: &[(Address<N>, Vec<Field<N>>)],
+        rng: &mut R,
+    ) -> Vec<(Group<N>, Vec<Field<N>>)> {
+        // Sample the randomizers sequentially, so that the ciphertexts are deterministic for a given RNG.
+        let randomizers = Secret::new((0..recipients.len()).map(|_| Scalar::rand(rng)).collect::<Vec<Scalar<N>>>());
+
+        // Compute the nonce and the shared point of each recipient.
+        let (nonces, shared_points): (Vec<_>, Vec<_>) = cfg_iter!(recipients)
+            .zip_eq(cfg_iter!(randomizers))
+            .map(|((address, _), randomizer)| (N::g_scalar_multiply(randomizer), **address * randomizer))
+            .unzip();
+        let symmetric_keys = Secret::new(Group::batch_to_x_coordinates(&shared_points));
+
+        // Encrypt the messages.
+        cfg_iter!(recipients)
+            .zip_eq(cfg_into_iter!(nonces))
+            .zip_eq(cfg_iter!(symmetric_keys))
+            .map(|(((_, message), nonce), symmetric_key)| (nonce, Self::encrypt(symmetric_key, message)))
+            .collect()
+    }
+
+    /// Decrypts each of the given `(nonce, ciphertext)` pairs with the given view key.
+    ///
+    /// This is faster than decrypting each ciphertext separately, as the scalar multiplications run in parallel,
+    /// and the shared points are normalized with a single inversion.
+    pub fn batch_decrypt(
+        view_key: &ViewKey<N>,
+        ciphertexts: &[(Group<N>, Vec<Field<N>>)],
+    ) -> Result<Vec<Vec<Field<N>>>> {
+        // Ensure no nonce is the identity, which would fix the shared secret.
+        ensure!(ciphertexts.iter().all(|(nonce, _)| !nonce.is_zero()), "The nonce must not be the identity");
+
+        // Compute the shared point of each ciphertext.
+        let shared_points = cfg_iter!(ciphertexts).map(|(nonce, _)| *nonce * **view_key).collect::<Vec<_>>();
+        let symmetric_keys = Secret::new(Group::batch_to_x_coordinates(&shared_points));
+
+        // Decrypt the ciphertexts.
+        Ok(cfg_iter!(ciphertexts)
+            .zip_eq(cfg_iter!(symmetric_keys))
+            .map(|((_, ciphertext), symmetric_key)| Self::decrypt(symmetric_key, ciphertext))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ECIESPoseidonKem, KemScheme};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Encryption = ECIESPoseidonEncryption<CurrentNetwork>;
+
+    #[test]
+    fn test_batch_encrypt_and_decrypt() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for num_recipients in [0, 1, 10] {
+            let keys = Encryption::batch_generate_asymmetric_keys(num_recipients, rng);
+            for (view_key, address) in &keys {
+                assert_eq!(*address, view_key.to_address());
+            }
+
+            let recipients = keys
+                .iter()
+                .map(|(_, address)| (*address, (0..rng.gen_range(1..10)).map(|_| Field::rand(rng)).collect_vec()))
+                .collect_vec();
+            let ciphertexts = Encryption::batch_encrypt(&recipients, rng);
+            assert_eq!(ciphertexts.len(), num_recipients);
+
+            for (((view_key, _), (_, message)), (nonce, ciphertext)) in keys.iter().zip(&recipients).zip(&ciphertexts) {
+                // Check that each ciphertext decrypts on its own.
+                let symmetric_key = ECIESPoseidonKem::decapsulate(view_key, nonce)?;
+                assert_eq!(*message, Encryption::decrypt(&symmetric_key, ciphertext));
+                // Check that each ciphertext decrypts in a batch.
+                let candidate = Encryption::batch_decrypt(view_key, &[(*nonce, ciphertext.clone())])?;
+                assert_eq!(vec![message.clone()], candidate);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_decrypt() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Encrypt several messages to one address, as when scanning for records.
+        let (view_key, address) = Encryption::batch_generate_asymmetric_keys(1, rng).remove(0);
+        let recipients = (0..10).map(|_| (address, vec![Field::rand(rng)])).collect_vec();
+        let ciphertexts = Encryption::batch_encrypt(&recipients, rng);
+
+        let messages = recipients.into_iter().map(|(_, message)| message).collect_vec();
+        assert_eq!(messages, Encryption::batch_decrypt(&view_key, &ciphertexts)?);
+
+        assert!(Encryption::batch_decrypt(&view_key, &[(Group::zero(), vec![])]).is_err());
+        Ok(())
+    }
+}
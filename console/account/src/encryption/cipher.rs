@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Secret;
+
+/// The Poseidon stream cipher of `ECIESPoseidonEncryption`, for use with a symmetric key on its own,
+/// such as a key derived from a password to encrypt local wallet state.
+///
+/// The ciphertext is malleable; use `ECIESPoseidonEncryption::encrypt_authenticated` when the ciphertext
+/// is not otherwise authenticated.
+pub struct PoseidonCipher<N: Network> {
+    /// The symmetric key.
+    key: Secret<Field<N>>,
+}
+
+impl<N: Network> PoseidonCipher<N> {
+    /// Initializes the cipher with the given symmetric key.
+    pub const fn new(key: Field<N>) -> Self {
+        Self { key: Secret::new(key) }
+    }
+
+    /// Encrypts the given field elements.
+    pub fn encrypt(&self, message: &[Field<N>]) -> Vec<Field<N>> {
+        let mut key_stream = ECIESPoseidonEncryption::key_stream(&self.key);
+        ECIESPoseidonEncryption::apply_key_stream(&mut key_stream, message, |message, randomizer| *message + randomizer)
+    }
+
+    /// Decrypts the given field elements.
+    pub fn decrypt(&self, ciphertext: &[Field<N>]) -> Vec<Field<N>> {
+        let mut key_stream = ECIESPoseidonEncryption::key_stream(&self.key);
+        ECIESPoseidonEncryption::apply_key_stream(&mut key_stream, ciphertext, |ciphertext, randomizer| {
+            *ciphertext - randomizer
+        })
+    }
+
+    /// Encodes the given bytes with `ECIESPoseidonEncryption::encode_message`, and encrypts them.
+    pub fn encrypt_bytes(&self, message: &[u8]) -> Result<Vec<Field<N>>> {
+        Ok(self.encrypt(&ECIESPoseidonEncryption::encode_message(message)?))
+    }
+
+    /// Decrypts the given field elements, and decodes them into bytes.
+    pub fn decrypt_bytes(&self, ciphertext: &[Field<N>]) -> Result<Vec<u8>> {
+        ECIESPoseidonEncryption::decode_message(&self.decrypt(ciphertext))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 25;
+
+    #[test]
+    fn test_encrypt_and_decrypt_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let key = Field::<CurrentNetwork>::rand(rng);
+            let cipher = PoseidonCipher::new(key);
+
+            let message: Vec<u8> = (0..rng.gen_range(0..200)).map(|_| rng.gen()).collect_vec();
+            let ciphertext = cipher.encrypt_bytes(&message)?;
+            assert_eq!(message, cipher.decrypt_bytes(&ciphertext)?);
+
+            // Check that the cipher matches the data encapsulation of ECIES Poseidon.
+            let encoded = ECIESPoseidonEncryption::encode_message(&message)?;
+            assert_eq!(ciphertext, ECIESPoseidonEncryption::encrypt(&key, &encoded));
+            assert_eq!(encoded, cipher.decrypt(&ciphertext));
+        }
+        Ok(())
+    }
+}
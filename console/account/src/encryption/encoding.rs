@@ -0,0 +1,273 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of bytes of the length prefix of `MessageEncoding::LengthPrefixed`.
+const LENGTH_PREFIX_SIZE: usize = 4;
+
+/// The strategy that encodes a byte message as field elements, so that the encoding of external systems can be matched.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MessageEncoding {
+    /// The message is terminated with a `1` byte, and padded with zeros to fill the last field element.
+    #[default]
+    Terminator,
+    /// The message is prefixed with its length as a little-endian `u32`, and padded with zeros to fill
+    /// the last field element.
+    LengthPrefixed,
+    /// The message is padded with `n` bytes of value `n` to fill the last field element, as in PKCS#7.
+    /// A message that fills its last field element is followed by a full field element of padding.
+    Pkcs7,
+}
+
+impl MessageEncoding {
+    /// Returns the minimum number of bytes that the encoding adds to a message.
+    const fn min_padding_size(&self) -> usize {
+        match self {
+            Self::Terminator | Self::Pkcs7 => 1,
+            Self::LengthPrefixed => LENGTH_PREFIX_SIZE,
+        }
+    }
+}
+
+impl<N: Network> ECIESPoseidonEncryption<N> {
+    /// Returns the encoding of the given message as field elements, with the given encoding.
+    pub fn encode_message_with(encoding: MessageEncoding, message: &[u8]) -> Result<Vec<Field<N>>> {
+        let bytes_per_field = Self::bytes_per_field();
+
+        let mut padded =
+            Vec::with_capacity(message.len().saturating_add(LENGTH_PREFIX_SIZE).saturating_add(bytes_per_field));
+        match encoding {
+            MessageEncoding::Terminator => {
+                padded.extend_from_slice(message);
+                padded.push(1u8);
+            }
+            MessageEncoding::LengthPrefixed => {
+                padded.extend_from_slice(&u32::try_from(message.len())?.to_le_bytes());
+                padded.extend_from_slice(message);
+            }
+            MessageEncoding::Pkcs7 => {
+                let num_padding_bytes = bytes_per_field - message.len() % bytes_per_field;
+                padded.extend_from_slice(message);
+                padded.resize(padded.len() + num_padding_bytes, u8::try_from(num_padding_bytes)?);
+            }
+        }
+        // Pad the message with zeros to fill the last field element.
+        let num_fields = (padded.len() + bytes_per_field - 1) / bytes_per_field;
+        padded.resize(num_fields * bytes_per_field, 0u8);
+        padded.chunks(bytes_per_field).map(Self::encode_chunk).collect()
+    }
+
+    /// Returns the message of the given encoding, with the given encoding.
+    pub fn decode_message_with(encoding: MessageEncoding, encoded: &[Field<N>]) -> Result<Vec<u8>> {
+        let bytes_per_field = Self::bytes_per_field();
+
+        let mut message = Vec::with_capacity(encoded.len() * bytes_per_field);
+        for field in encoded {
+            message.extend(Self::decode_chunk(field)?);
+        }
+        Self::remove_padding(encoding, message)
+    }
+
+    /// Returns the message of the given encoding, with the given encoding, for an encoding from an untrusted source.
+    ///
+    /// The number of field elements is checked against the maximum length of the message before any element is
+    /// decoded, and the error reports the index of the field element that is invalid.
+    pub fn decode_message_checked(
+        encoding: MessageEncoding,
+        encoded: &[Field<N>],
+        max_length: usize,
+    ) -> Result<Vec<u8>> {
+        let bytes_per_field = Self::bytes_per_field();
+
+        // Ensure the number of field elements does not exceed the encoding of a message of the maximum length.
+        let max_num_fields = max_length.saturating_add(encoding.min_padding_size()).saturating_add(bytes_per_field - 1)
+            / bytes_per_field;
+        ensure!(!encoded.is_empty(), "The encoded message is empty");
+        ensure!(
+            encoded.len() <= max_num_fields,
+            "The encoded message has {} field elements, which exceeds the maximum of {max_num_fields}",
+            encoded.len()
+        );
+
+        let mut message = Vec::with_capacity(encoded.len() * bytes_per_field);
+        for (index, field) in encoded.iter().enumerate() {
+            let chunk = Self::decode_chunk(field).map_err(|_| {
+                anyhow!("The field element at index {index} exceeds the capacity of {bytes_per_field} bytes")
+            })?;
+            message.extend(chunk);
+        }
+        let message = Self::remove_padding(encoding, message)?;
+
+        // Ensure the message does not exceed the maximum length.
+        ensure!(
+            message.len() <= max_length,
+            "The message has {} bytes, which exceeds the maximum of {max_length} bytes",
+            message.len()
+        );
+        Ok(message)
+    }
+
+    /// Removes the padding of the given encoding from the given decoded message.
+    fn remove_padding(encoding: MessageEncoding, mut message: Vec<u8>) -> Result<Vec<u8>> {
+        let bytes_per_field = Self::bytes_per_field();
+
+        match encoding {
+            MessageEncoding::Terminator => Self::unpad(message),
+            MessageEncoding::LengthPrefixed => {
+                ensure!(message.len() >= LENGTH_PREFIX_SIZE, "Invalid message length prefix");
+                let mut length_bytes = [0u8; LENGTH_PREFIX_SIZE];
+                length_bytes.copy_from_slice(&message[..LENGTH_PREFIX_SIZE]);
+                // Note: The sum is checked, as it may overflow on 32-bit targets.
+                let end = usize::try_from(u32::from_le_bytes(length_bytes))
+                    .ok()
+                    .and_then(|length| LENGTH_PREFIX_SIZE.checked_add(length))
+                    .filter(|end| *end <= message.len());
+                let Some(end) = end else { bail!("Invalid message length prefix") };
+                // Ensure the zero padding is shorter than a field element.
+                let padding = &message[end..];
+                ensure!(
+                    padding.len() < bytes_per_field && padding.iter().all(|byte| *byte == 0),
+                    "Invalid message padding"
+                );
+                message.truncate(end);
+                message.drain(..LENGTH_PREFIX_SIZE);
+                Ok(message)
+            }
+            MessageEncoding::Pkcs7 => {
+                let num_padding_bytes = message.last().map_or(0, |byte| *byte as usize);
+                ensure!(
+                    (1..=bytes_per_field).contains(&num_padding_bytes) && num_padding_bytes <= message.len(),
+                    "Invalid message padding"
+                );
+                let start = message.len() - num_padding_bytes;
+                ensure!(
+                    message[start..].iter().all(|byte| *byte as usize == num_padding_bytes),
+                    "Invalid message padding"
+                );
+                message.truncate(start);
+                Ok(message)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Encryption = ECIESPoseidonEncryption<CurrentNetwork>;
+
+    const ENCODINGS: [MessageEncoding; 3] =
+        [MessageEncoding::Terminator, MessageEncoding::LengthPrefixed, MessageEncoding::Pkcs7];
+
+    #[test]
+    fn test_encode_and_decode_message_with() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for encoding in ENCODINGS {
+            for length in 0..(3 * Encryption::bytes_per_field()) {
+                let message = (0..length).map(|_| rng.gen()).collect::<Vec<u8>>();
+                let encoded = Encryption::encode_message_with(encoding, &message)?;
+                assert_eq!(message, Encryption::decode_message_with(encoding, &encoded)?);
+
+                // Check that a trailing field element of zeros is rejected.
+                let mut extended = encoded.clone();
+                extended.push(Field::zero());
+                assert!(Encryption::decode_message_with(encoding, &extended).is_err());
+            }
+            assert!(Encryption::decode_message_with(encoding, &[]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_encoding_sizes() -> Result<()> {
+        let bytes_per_field = Encryption::bytes_per_field();
+        let full = vec![7u8; bytes_per_field];
+
+        // Check the number of field elements of a message that fills a field element.
+        assert_eq!(Encryption::encode_message_with(MessageEncoding::Terminator, &full)?.len(), 2);
+        assert_eq!(Encryption::encode_message_with(MessageEncoding::LengthPrefixed, &full)?.len(), 2);
+        assert_eq!(Encryption::encode_message_with(MessageEncoding::Pkcs7, &full)?.len(), 2);
+        // Check the number of field elements of a message that leaves room for the padding.
+        let short = &full[..bytes_per_field - LENGTH_PREFIX_SIZE];
+        assert_eq!(Encryption::encode_message_with(MessageEncoding::Terminator, short)?.len(), 1);
+        assert_eq!(Encryption::encode_message_with(MessageEncoding::LengthPrefixed, short)?.len(), 1);
+        assert_eq!(Encryption::encode_message_with(MessageEncoding::Pkcs7, short)?.len(), 1);
+
+        // Check that the default encoding is the one of `encode_message`.
+        assert_eq!(
+            Encryption::encode_message(&full)?,
+            Encryption::encode_message_with(MessageEncoding::default(), &full)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_message_checked() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let bytes_per_field = Encryption::bytes_per_field();
+
+        for encoding in ENCODINGS {
+            for length in 0..(3 * bytes_per_field) {
+                let message = (0..length).map(|_| rng.gen()).collect::<Vec<u8>>();
+                let encoded = Encryption::encode_message_with(encoding, &message)?;
+                assert_eq!(message, Encryption::decode_message_checked(encoding, &encoded, length)?);
+                // Check that a message that exceeds the maximum length is rejected.
+                if length > 0 {
+                    assert!(Encryption::decode_message_checked(encoding, &encoded, length - 1).is_err());
+                }
+            }
+            assert!(Encryption::decode_message_checked(encoding, &[], usize::MAX).is_err());
+        }
+
+        // Check that too many field elements are rejected before they are decoded.
+        let encoded = vec![-Field::<CurrentNetwork>::one(); 3];
+        let error = Encryption::decode_message_checked(MessageEncoding::Terminator, &encoded, 0).unwrap_err();
+        assert!(error.to_string().contains("3 field elements"));
+
+        // Check that the index of a field element that exceeds the capacity is reported.
+        let mut encoded = Encryption::encode_message(&vec![7u8; 2 * bytes_per_field])?;
+        encoded[1] = -Field::one();
+        let error = Encryption::decode_message_checked(MessageEncoding::Terminator, &encoded, usize::MAX).unwrap_err();
+        assert!(error.to_string().contains("index 1"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_padding() -> Result<()> {
+        let bytes_per_field = Encryption::bytes_per_field();
+
+        // Check that a length prefix beyond the message is rejected.
+        let mut bytes = 100u32.to_le_bytes().to_vec();
+        bytes.resize(bytes_per_field, 0u8);
+        let encoded = [Encryption::encode_chunk(&bytes)?];
+        assert!(Encryption::decode_message_with(MessageEncoding::LengthPrefixed, &encoded).is_err());
+        // Check that the maximum length prefix is rejected.
+        let mut bytes = u32::MAX.to_le_bytes().to_vec();
+        bytes.resize(bytes_per_field, 0u8);
+        let encoded = [Encryption::encode_chunk(&bytes)?];
+        assert!(Encryption::decode_message_with(MessageEncoding::LengthPrefixed, &encoded).is_err());
+
+        // Check that inconsistent PKCS#7 padding is rejected.
+        let mut bytes = vec![3u8; bytes_per_field];
+        bytes[bytes_per_field - 2] = 2;
+        let encoded = [Encryption::encode_chunk(&bytes)?];
+        assert!(Encryption::decode_message_with(MessageEncoding::Pkcs7, &encoded).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod associated_data;
+mod authenticated;
+mod batch;
+
+mod cipher;
+pub use cipher::*;
+
+mod encoding;
+pub use encoding::*;
+
+#[cfg(feature = "multi_recipient")]
+mod multi_recipient;
+#[cfg(feature = "multi_recipient")]
+pub use multi_recipient::*;
+
+mod re_encryption;
+pub use re_encryption::*;
+
+mod session;
+pub use session::*;
+
+mod stealth;
+pub use stealth::*;
+
+mod stream;
+pub use stream::*;
+
+mod threshold;
+pub use threshold::*;
+
+use snarkvm_console_network::{prelude::*, PoseidonStream};
+use snarkvm_console_types::Field;
+
+use core::marker::PhantomData;
+
+/// The data encapsulation of the ECIES Poseidon construction used for record encryption.
+///
+/// A message is encrypted by adding a Poseidon key stream, derived from the symmetric key, to each of its
+/// field elements. The symmetric key is the shared secret of `ECIESPoseidonKem`, so encrypting the fields
+/// of a plaintext under a plaintext view key produces the fields of its record ciphertext.
+/// Byte messages are first encoded as field elements with `encode_message`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ECIESPoseidonEncryption<N: Network>(PhantomData<N>);
+
+impl<N: Network> ECIESPoseidonEncryption<N> {
+    /// Returns the number of message bytes that are encoded in each field element.
+    pub fn bytes_per_field() -> usize {
+        Field::<N>::size_in_data_bits() / 8
+    }
+
+    /// Returns the encoding of the given message as field elements.
+    ///
+    /// The message is terminated with a `1` byte, and padded with zeros to fill the last field element.
+    /// Use `encode_message_with` to select another `MessageEncoding`.
+    pub fn encode_message(message: &[u8]) -> Result<Vec<Field<N>>> {
+        Self::encode_message_with(MessageEncoding::Terminator, message)
+    }
+
+    /// Returns the message of the given encoding.
+    ///
+    /// Use `decode_message_checked` to bound the length of a message from an untrusted source.
+    pub fn decode_message(encoded: &[Field<N>]) -> Result<Vec<u8>> {
+        Self::decode_message_with(MessageEncoding::Terminator, encoded)
+    }
+
+    /// Encrypts the given field elements under the given symmetric key.
+    ///
+    /// The ciphertext is malleable; use `encrypt_authenticated` when the ciphertext is not otherwise authenticated.
+    pub fn encrypt(symmetric_key: &Field<N>, message: &[Field<N>]) -> Vec<Field<N>> {
+        PoseidonCipher::new(*symmetric_key).encrypt(message)
+    }
+
+    /// Decrypts the given field elements under the given symmetric key.
+    pub fn decrypt(symmetric_key: &Field<N>, ciphertext: &[Field<N>]) -> Vec<Field<N>> {
+        PoseidonCipher::new(*symmetric_key).decrypt(ciphertext)
+    }
+}
+
+impl<N: Network> ECIESPoseidonEncryption<N> {
+    /// Returns the key stream of the given symmetric key.
+    fn key_stream(symmetric_key: &Field<N>) -> PoseidonStream<N, 8> {
+        N::hash_many_psd8_stream(&[N::encryption_domain(), *symmetric_key])
+    }
+
+    /// Combines each of the given field elements with the next randomizer of the key stream.
+    #[allow(clippy::cast_possible_truncation)]
+    fn apply_key_stream(
+        key_stream: &mut PoseidonStream<N, 8>,
+        input: &[Field<N>],
+        combine: impl Fn(&Field<N>, &Field<N>) -> Field<N>,
+    ) -> Vec<Field<N>> {
+        input
+            .chunks(u16::MAX as usize)
+            .flat_map(|chunk| {
+                // Note: This cast is safe, as the chunk has at most `u16::MAX` elements.
+                let randomizers = key_stream.squeeze(chunk.len() as u16);
+                chunk.iter().zip_eq(&randomizers).map(|(input, randomizer)| combine(input, randomizer)).collect_vec()
+            })
+            .collect()
+    }
+
+    /// Returns the field element that encodes the given chunk of at most `bytes_per_field` bytes.
+    fn encode_chunk(chunk: &[u8]) -> Result<Field<N>> {
+        ensure!(chunk.len() <= Self::bytes_per_field(), "The chunk exceeds the capacity of a field element");
+        let mut bytes = chunk.to_vec();
+        bytes.resize((Field::<N>::size_in_bits() + 7) / 8, 0u8);
+        Ok(Field::read_le(&bytes[..])?)
+    }
+
+    /// Returns the `bytes_per_field` bytes that are encoded in the given field element.
+    fn decode_chunk(field: &Field<N>) -> Result<Vec<u8>> {
+        let mut bytes = field.to_bytes_le()?;
+        ensure!(bytes[Self::bytes_per_field()..].iter().all(|byte| *byte == 0), "Invalid message encoding");
+        bytes.truncate(Self::bytes_per_field());
+        Ok(bytes)
+    }
+
+    /// Removes the padding from the given decoded message.
+    fn unpad(mut message: Vec<u8>) -> Result<Vec<u8>> {
+        // Remove the zero padding, which is shorter than a field element.
+        let num_zeros = message.iter().rev().take_while(|byte| **byte == 0).count();
+        ensure!(num_zeros < Self::bytes_per_field(), "Invalid message padding");
+        message.truncate(message.len() - num_zeros);
+        // Remove the terminator.
+        ensure!(message.pop() == Some(1u8), "Invalid message padding");
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, ECIESPoseidonKem, KemScheme, PrivateKey, ViewKey};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Encryption = ECIESPoseidonEncryption<CurrentNetwork>;
+
+    const ITERATIONS: u64 = 25;
+
+    #[test]
+    fn test_encode_and_decode_message() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for length in 0..(3 * Encryption::bytes_per_field()) {
+            let message = (0..length).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let encoded = Encryption::encode_message(&message)?;
+            assert_eq!(encoded.len(), length / Encryption::bytes_per_field() + 1);
+            assert_eq!(message, Encryption::decode_message(&encoded)?);
+        }
+
+        // Check that a missing terminator is rejected.
+        assert!(Encryption::decode_message(&[]).is_err());
+        assert!(Encryption::decode_message(&[Field::zero()]).is_err());
+        // Check that a field element that exceeds the capacity of a chunk is rejected.
+        assert!(Encryption::decode_message(&[-Field::<CurrentNetwork>::one()]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            let (nonce, symmetric_key) = ECIESPoseidonKem::encapsulate(&address, rng)?;
+            let message = (0..rng.gen_range(0..200)).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let ciphertext = Encryption::encrypt(&symmetric_key, &Encryption::encode_message(&message)?);
+
+            let symmetric_key = ECIESPoseidonKem::decapsulate(&view_key, &nonce)?;
+            assert_eq!(message, Encryption::decode_message(&Encryption::decrypt(&symmetric_key, &ciphertext))?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_record_encryption() {
+        let rng = &mut TestRng::default();
+
+        // Check that the key stream is the one used to encrypt records.
+        let symmetric_key = Field::<CurrentNetwork>::rand(rng);
+        let message = (0..10).map(|_| Field::rand(rng)).collect::<Vec<_>>();
+        let randomizers = CurrentNetwork::hash_many_psd8(&[CurrentNetwork::encryption_domain(), symmetric_key], 10);
+        let expected = message.iter().zip_eq(&randomizers).map(|(m, r)| *m + r).collect::<Vec<_>>();
+        assert_eq!(expected, Encryption::encrypt(&symmetric_key, &message));
+    }
+}
@@ -0,0 +1,382 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{ECIESPoseidonKem, KemScheme, Secret, ViewKey};
+use snarkvm_console_types::{Address, Group};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+const KEY_WRAP_DOMAIN: DomainSeparator = DomainSeparator::new("EncryptionKeyWrap", 0);
+
+/// The content key of a multi-recipient ciphertext, wrapped to one recipient.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WrappedKey<N: Network> {
+    /// The nonce of the recipient's key encapsulation.
+    nonce: Group<N>,
+    /// The content key, masked with the recipient's shared secret.
+    masked_key: Field<N>,
+}
+
+impl<N: Network> WrappedKey<N> {
+    /// Returns the nonce of the recipient's key encapsulation.
+    pub const fn nonce(&self) -> &Group<N> {
+        &self.nonce
+    }
+
+    /// Returns the masked content key.
+    pub const fn masked_key(&self) -> &Field<N> {
+        &self.masked_key
+    }
+}
+
+/// A message encrypted once under a content key, along with the content key wrapped to each recipient.
+///
+/// The ciphertext is authenticated under the content key, so each recipient detects a wrapped key
+/// that does not unwrap to the content key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiRecipientCiphertext<N: Network> {
+    /// The content key, wrapped to each recipient.
+    wrapped_keys: Vec<WrappedKey<N>>,
+    /// The authenticated ciphertext of the message.
+    ciphertext: Vec<Field<N>>,
+}
+
+impl<N: Network> MultiRecipientCiphertext<N> {
+    /// Returns the content key, wrapped to each recipient.
+    pub fn wrapped_keys(&self) -> &[WrappedKey<N>] {
+        &self.wrapped_keys
+    }
+
+    /// Returns the authenticated ciphertext of the message.
+    pub fn ciphertext(&self) -> &[Field<N>] {
+        &self.ciphertext
+    }
+}
+
+impl<N: Network> ECIESPoseidonEncryption<N> {
+    /// Encrypts the given field elements once, so that each of the given recipients can decrypt them.
+    ///
+    /// The wrapped keys are in the order of the recipients, but do not reveal the recipients.
+    pub fn encrypt_multi<R: Rng + CryptoRng>(
+        recipients: &[Address<N>],
+        message: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<MultiRecipientCiphertext<N>> {
+        ensure!(!recipients.is_empty(), "Expected at least one recipient");
+        ensure!(recipients.len() <= u16::MAX as usize, "Expected at most {} recipients", u16::MAX);
+
+        // Sample the content key, and encrypt the message under it.
+        let content_key = Secret::new(Field::rand(rng));
+        let ciphertext = Self::encrypt_authenticated(&content_key, message)?;
+
+        // Wrap the content key to each recipient.
+        let wrapped_keys = recipients
+            .iter()
+            .map(|address| {
+                let (nonce, shared_secret) = ECIESPoseidonKem::encapsulate(address, rng)?;
+                let shared_secret = Secret::new(shared_secret);
+                Ok(WrappedKey { nonce, masked_key: *content_key + Self::key_wrap_mask(&shared_secret)? })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(MultiRecipientCiphertext { wrapped_keys, ciphertext })
+    }
+
+    /// Decrypts the given multi-recipient ciphertext with the given view key.
+    ///
+    /// The wrapped keys are tried in order, until one unwraps to the key that authenticates the ciphertext.
+    /// A wrapped key that fails to unwrap (e.g. one with an invalid nonce) is skipped, rather than aborting.
+    pub fn decrypt_multi(view_key: &ViewKey<N>, ciphertext: &MultiRecipientCiphertext<N>) -> Result<Vec<Field<N>>> {
+        ciphertext
+            .wrapped_keys
+            .iter()
+            .find_map(|wrapped_key| Self::unwrap_and_decrypt(view_key, wrapped_key, &ciphertext.ciphertext).ok())
+            .ok_or_else(|| anyhow!("The ciphertext is not encrypted to the given view key"))
+    }
+
+    /// Decrypts each of the given multi-recipient ciphertexts with the given view key.
+    ///
+    /// Returns the result of each ciphertext, so that a ciphertext that is malformed, or not encrypted
+    /// to the given view key, does not prevent the others from being decrypted.
+    pub fn decrypt_multi_batch(
+        view_key: &ViewKey<N>,
+        ciphertexts: &[MultiRecipientCiphertext<N>],
+    ) -> Vec<Result<Vec<Field<N>>>> {
+        cfg_iter!(ciphertexts).map(|ciphertext| Self::decrypt_multi(view_key, ciphertext)).collect()
+    }
+
+    /// Unwraps the content key from the given wrapped key, and decrypts the given ciphertext with it.
+    fn unwrap_and_decrypt(
+        view_key: &ViewKey<N>,
+        wrapped_key: &WrappedKey<N>,
+        ciphertext: &[Field<N>],
+    ) -> Result<Vec<Field<N>>> {
+        let shared_secret = Secret::new(ECIESPoseidonKem::decapsulate(view_key, &wrapped_key.nonce)?);
+        let content_key = Secret::new(wrapped_key.masked_key - Self::key_wrap_mask(&shared_secret)?);
+        Self::decrypt_checked(&content_key, ciphertext)
+    }
+
+    /// Returns the mask of the content key for the given shared secret.
+    fn key_wrap_mask(shared_secret: &Field<N>) -> Result<Field<N>> {
+        N::hash_psd2(&[Field::from_domain_separator(KEY_WRAP_DOMAIN), *shared_secret])
+    }
+}
+
+impl<N: Network> FromBytes for WrappedKey<N> {
+    /// Reads a wrapped key from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let nonce = Group::read_le(&mut reader)?;
+        let masked_key = Field::read_le(&mut reader)?;
+        Ok(Self { nonce, masked_key })
+    }
+}
+
+impl<N: Network> ToBytes for WrappedKey<N> {
+    /// Writes a wrapped key to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.nonce.write_le(&mut writer)?;
+        self.masked_key.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for MultiRecipientCiphertext<N> {
+    /// Reads a multi-recipient ciphertext from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        if version != 1 {
+            return Err(error("Invalid multi-recipient ciphertext version"));
+        }
+        // Read the wrapped keys.
+        let num_wrapped_keys = u16::read_le(&mut reader)?;
+        if num_wrapped_keys == 0 {
+            return Err(error("A multi-recipient ciphertext must have at least one recipient"));
+        }
+        let wrapped_keys =
+            (0..num_wrapped_keys).map(|_| WrappedKey::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the ciphertext.
+        let num_fields = u32::read_le(&mut reader)?;
+        let ciphertext = (0..num_fields).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        Ok(Self { wrapped_keys, ciphertext })
+    }
+}
+
+impl<N: Network> ToBytes for MultiRecipientCiphertext<N> {
+    /// Writes a multi-recipient ciphertext to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the wrapped keys.
+        u16::try_from(self.wrapped_keys.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        self.wrapped_keys.write_le(&mut writer)?;
+        // Write the ciphertext.
+        u32::try_from(self.ciphertext.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        self.ciphertext.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> Serialize for WrappedKey<N> {
+    /// Serializes the wrapped key into a struct or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut wrapped_key = serializer.serialize_struct("WrappedKey", 2)?;
+                wrapped_key.serialize_field("nonce", &self.nonce)?;
+                wrapped_key.serialize_field("masked_key", &self.masked_key)?;
+                wrapped_key.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for WrappedKey<N> {
+    /// Deserializes the wrapped key from a struct or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut wrapped_key = serde_json::Value::deserialize(deserializer)?;
+                Ok(Self {
+                    nonce: DeserializeExt::take_from_value::<D>(&mut wrapped_key, "nonce")?,
+                    masked_key: DeserializeExt::take_from_value::<D>(&mut wrapped_key, "masked_key")?,
+                })
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "wrapped key"),
+        }
+    }
+}
+
+impl<N: Network> Serialize for MultiRecipientCiphertext<N> {
+    /// Serializes the multi-recipient ciphertext into a struct or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut ciphertext = serializer.serialize_struct("MultiRecipientCiphertext", 2)?;
+                ciphertext.serialize_field("wrapped_keys", &self.wrapped_keys)?;
+                ciphertext.serialize_field("ciphertext", &self.ciphertext)?;
+                ciphertext.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for MultiRecipientCiphertext<N> {
+    /// Deserializes the multi-recipient ciphertext from a struct or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut ciphertext = serde_json::Value::deserialize(deserializer)?;
+                let wrapped_keys: Vec<WrappedKey<N>> =
+                    DeserializeExt::take_from_value::<D>(&mut ciphertext, "wrapped_keys")?;
+                // Ensure the recipients are within the bounds of the byte encoding.
+                if wrapped_keys.is_empty() || wrapped_keys.len() > u16::MAX as usize {
+                    return Err(de::Error::custom("Invalid number of recipients in a multi-recipient ciphertext"));
+                }
+                Ok(Self {
+                    wrapped_keys,
+                    ciphertext: DeserializeExt::take_from_value::<D>(&mut ciphertext, "ciphertext")?,
+                })
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(
+                deserializer,
+                "multi-recipient ciphertext",
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Encryption = ECIESPoseidonEncryption<CurrentNetwork>;
+
+    #[test]
+    fn test_encrypt_and_decrypt_multi() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let keys = Encryption::batch_generate_asymmetric_keys(5, rng);
+        let recipients = keys[..4].iter().map(|(_, address)| *address).collect_vec();
+        let message = Encryption::encode_message(b"to everyone on the list")?;
+
+        let ciphertext = Encryption::encrypt_multi(&recipients, &message, rng)?;
+        assert_eq!(ciphertext.wrapped_keys().len(), recipients.len());
+        assert_eq!(ciphertext.ciphertext().len(), message.len() + 1);
+
+        // Check that each recipient decrypts the message, and that others do not.
+        for (view_key, _) in &keys[..4] {
+            assert_eq!(message, Encryption::decrypt_multi(view_key, &ciphertext)?);
+        }
+        assert!(Encryption::decrypt_multi(&keys[4].0, &ciphertext).is_err());
+
+        // Check that a modified wrapped key is detected by its recipient.
+        let mut modified = ciphertext.clone();
+        modified.wrapped_keys[0].masked_key += Field::one();
+        assert!(Encryption::decrypt_multi(&keys[0].0, &modified).is_err());
+        assert_eq!(message, Encryption::decrypt_multi(&keys[1].0, &modified)?);
+
+        // Check that a wrapped key with an invalid nonce is skipped.
+        let mut modified = ciphertext.clone();
+        modified.wrapped_keys[0].nonce = Group::zero();
+        assert_eq!(message, Encryption::decrypt_multi(&keys[1].0, &modified)?);
+
+        assert!(Encryption::encrypt_multi(&[], &message, rng).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_multi_batch() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let keys = Encryption::batch_generate_asymmetric_keys(3, rng);
+        let (view_key, address) = (&keys[0].0, keys[0].1);
+        let messages = (0..4).map(|_| (0..5).map(|_| Field::rand(rng)).collect_vec()).collect_vec();
+
+        // Encrypt the first and last messages to the view key, and the others only to other recipients.
+        let mut ciphertexts = vec![
+            Encryption::encrypt_multi(&[keys[1].1, address], &messages[0], rng)?,
+            Encryption::encrypt_multi(&[keys[1].1, keys[2].1], &messages[1], rng)?,
+            Encryption::encrypt_multi(&[address], &messages[2], rng)?,
+            Encryption::encrypt_multi(&[address, keys[2].1], &messages[3], rng)?,
+        ];
+        // Tamper with the ciphertext of the third message.
+        ciphertexts[2].ciphertext[0] += Field::one();
+
+        // Check that each ciphertext is decrypted independently of the others.
+        let results = Encryption::decrypt_multi_batch(view_key, &ciphertexts);
+        assert_eq!(results.len(), ciphertexts.len());
+        assert_eq!(&messages[0], results[0].as_ref().unwrap());
+        assert!(results[1].is_err());
+        assert!(results[2].is_err());
+        assert_eq!(&messages[3], results[3].as_ref().unwrap());
+
+        assert!(Encryption::decrypt_multi_batch(view_key, &[]).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let keys = Encryption::batch_generate_asymmetric_keys(3, rng);
+        let recipients = keys.iter().map(|(_, address)| *address).collect_vec();
+        let message = (0..5).map(|_| Field::rand(rng)).collect_vec();
+        let expected = Encryption::encrypt_multi(&recipients, &message, rng)?;
+
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, MultiRecipientCiphertext::read_le(&expected_bytes[..])?);
+        assert!(MultiRecipientCiphertext::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let keys = Encryption::batch_generate_asymmetric_keys(3, rng);
+        let recipients = keys.iter().map(|(_, address)| *address).collect_vec();
+        let message = (0..5).map(|_| Field::rand(rng)).collect_vec();
+        let expected = Encryption::encrypt_multi(&recipients, &message, rng)?;
+
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Check that a ciphertext without recipients is rejected.
+        let mut value = serde_json::to_value(&expected)?;
+        value["wrapped_keys"] = serde_json::Value::Array(vec![]);
+        assert!(serde_json::from_value::<MultiRecipientCiphertext<CurrentNetwork>>(value).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let keys = Encryption::batch_generate_asymmetric_keys(3, rng);
+        let recipients = keys.iter().map(|(_, address)| *address).collect_vec();
+        let message = (0..5).map(|_| Field::rand(rng)).collect_vec();
+        let expected = Encryption::encrypt_multi(&recipients, &message, rng)?;
+
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        Ok(())
+    }
+}
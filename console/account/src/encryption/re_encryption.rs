@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{ECIESPoseidonKem, KemScheme, Secret, ViewKey};
+use snarkvm_console_types::{Address, Group, Scalar};
+
+use zeroize::Zeroize;
+
+const RE_ENCRYPTION_DOMAIN: DomainSeparator = DomainSeparator::new("ProxyReEncryption", 0);
+
+/// A key that lets a proxy transform ciphertexts for the delegator into ciphertexts for the delegatee.
+///
+/// The key is `(a / t, t * G)`, where `a` is the view key of the delegator, and `t` is derived from a key
+/// encapsulation to the delegatee, so only the delegatee recovers `t`. On its own, the proxy learns neither
+/// the view key of the delegator nor the plaintext of the ciphertexts it transforms.
+///
+/// This scheme is not collusion-resistant: the delegatee recovers `t`, so a delegatee that colludes with
+/// the proxy (or obtains the re-encryption key) recovers the view key `a = (a / t) * t` of the delegator.
+/// A delegator must therefore only delegate to a delegatee that they would trust with their view key,
+/// and the re-encryption key must be kept as confidential as the view key itself, so it is zeroized on drop.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ReEncryptionKey<N: Network> {
+    /// The view key of the delegator, divided by the delegation secret.
+    scalar: Scalar<N>,
+    /// The nonce of the key encapsulation to the delegatee.
+    delegation_nonce: Group<N>,
+}
+
+impl<N: Network> Debug for ReEncryptionKey<N> {
+    /// Omits the scalar, which is secret.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ReEncryptionKey").field("delegation_nonce", &self.delegation_nonce).finish_non_exhaustive()
+    }
+}
+
+impl<N: Network> Drop for ReEncryptionKey<N> {
+    /// Zeroizes the scalar.
+    fn drop(&mut self) {
+        self.scalar.zeroize();
+    }
+}
+
+/// The nonce of a ciphertext that was re-encrypted for a delegatee.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ReEncryptedNonce<N: Network> {
+    /// The nonce of the ciphertext, multiplied by the re-encryption key.
+    nonce: Group<N>,
+    /// The nonce of the key encapsulation to the delegatee.
+    delegation_nonce: Group<N>,
+}
+
+impl<N: Network> ReEncryptedNonce<N> {
+    /// Returns the re-encrypted nonce of the ciphertext.
+    pub const fn nonce(&self) -> &Group<N> {
+        &self.nonce
+    }
+
+    /// Returns the nonce of the key encapsulation to the delegatee.
+    pub const fn delegation_nonce(&self) -> &Group<N> {
+        &self.delegation_nonce
+    }
+}
+
+impl<N: Network> ECIESPoseidonEncryption<N> {
+    /// Returns a re-encryption key from the given delegator to the given delegatee.
+    ///
+    /// Note that the delegatee and the holder of the re-encryption key can jointly recover the view key
+    /// of the delegator; see [`ReEncryptionKey`] for the trust assumption.
+    pub fn generate_re_encryption_key<R: Rng + CryptoRng>(
+        delegator: &ViewKey<N>,
+        delegatee: &Address<N>,
+        rng: &mut R,
+    ) -> Result<ReEncryptionKey<N>> {
+        let (delegation_nonce, shared_secret) = ECIESPoseidonKem::encapsulate(delegatee, rng)?;
+        let delegation_secret = Self::delegation_secret(&Secret::new(shared_secret))?;
+        Ok(ReEncryptionKey { scalar: **delegator * delegation_secret.inverse()?, delegation_nonce })
+    }
+
+    /// Re-encrypts the ciphertext with the given nonce for the delegatee of the given re-encryption key.
+    ///
+    /// Only the nonce is transformed, so the ciphertext itself is unchanged.
+    pub fn re_encrypt(re_encryption_key: &ReEncryptionKey<N>, nonce: &Group<N>) -> Result<ReEncryptedNonce<N>> {
+        // Ensure the nonce is not the identity, which would fix the shared secret.
+        ensure!(!nonce.is_zero(), "The nonce must not be the identity");
+        Ok(ReEncryptedNonce {
+            nonce: *nonce * re_encryption_key.scalar,
+            delegation_nonce: re_encryption_key.delegation_nonce,
+        })
+    }
+
+    /// Returns the symmetric key of a re-encrypted ciphertext for the delegatee with the given view key.
+    ///
+    /// This is the symmetric key that the delegator derives from the original nonce.
+    pub fn decapsulate_re_encrypted(view_key: &ViewKey<N>, nonce: &ReEncryptedNonce<N>) -> Result<Field<N>> {
+        ensure!(!nonce.nonce.is_zero(), "The nonce must not be the identity");
+        let shared_secret = Secret::new(ECIESPoseidonKem::decapsulate(view_key, &nonce.delegation_nonce)?);
+        let delegation_secret = Self::delegation_secret(&shared_secret)?;
+        Ok((nonce.nonce * *delegation_secret).to_x_coordinate())
+    }
+
+    /// Returns the delegation secret for the given shared secret of the delegatee.
+    fn delegation_secret(shared_secret: &Field<N>) -> Result<Secret<Scalar<N>>> {
+        let secret = N::hash_to_scalar_psd2(&[Field::from_domain_separator(RE_ENCRYPTION_DOMAIN), *shared_secret])?;
+        ensure!(!secret.is_zero(), "The delegation secret must not be zero");
+        Ok(Secret::new(secret))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Encryption = ECIESPoseidonEncryption<CurrentNetwork>;
+
+    const ITERATIONS: u64 = 25;
+
+    #[test]
+    fn test_re_encrypt() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let keys = Encryption::batch_generate_asymmetric_keys(3, rng);
+            let [(alice, alice_address), (bob, bob_address), (eve, _)] = <[_; 3]>::try_from(keys).unwrap();
+
+            // Encrypt a message to Alice.
+            let message = Encryption::encode_message(b"delegated record")?;
+            let (nonce, symmetric_key) = ECIESPoseidonKem::encapsulate(&alice_address, rng)?;
+            let ciphertext = Encryption::encrypt(&symmetric_key, &message);
+
+            // Re-encrypt the ciphertext for Bob, and check that Bob decrypts it.
+            let re_encryption_key = Encryption::generate_re_encryption_key(&alice, &bob_address, rng)?;
+            let re_encrypted = Encryption::re_encrypt(&re_encryption_key, &nonce)?;
+            let candidate = Encryption::decapsulate_re_encrypted(&bob, &re_encrypted)?;
+            assert_eq!(candidate, symmetric_key);
+            assert_eq!(message, Encryption::decrypt(&candidate, &ciphertext));
+
+            // Check that the re-encrypted nonce does not decrypt under any other view key.
+            assert_ne!(symmetric_key, Encryption::decapsulate_re_encrypted(&eve, &re_encrypted)?);
+            assert_ne!(symmetric_key, Encryption::decapsulate_re_encrypted(&alice, &re_encrypted)?);
+            assert_ne!(symmetric_key, ECIESPoseidonKem::decapsulate(&bob, re_encrypted.nonce())?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_re_encrypt_collusion() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Check the documented trust assumption, that the delegatee and the proxy jointly recover the view key.
+        let keys = Encryption::batch_generate_asymmetric_keys(2, rng);
+        let [(alice, _), (bob, bob_address)] = <[_; 2]>::try_from(keys).unwrap();
+        let re_encryption_key = Encryption::generate_re_encryption_key(&alice, &bob_address, rng)?;
+        let shared_secret = ECIESPoseidonKem::decapsulate(&bob, &re_encryption_key.delegation_nonce)?;
+        let delegation_secret = Encryption::delegation_secret(&shared_secret)?;
+        assert_eq!(re_encryption_key.scalar * *delegation_secret, *alice);
+        Ok(())
+    }
+
+    #[test]
+    fn test_re_encrypt_identity() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let keys = Encryption::batch_generate_asymmetric_keys(2, rng);
+        let re_encryption_key = Encryption::generate_re_encryption_key(&keys[0].0, &keys[1].1, rng)?;
+        assert!(Encryption::re_encrypt(&re_encryption_key, &Group::zero()).is_err());
+        Ok(())
+    }
+}
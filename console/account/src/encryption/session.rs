@@ -0,0 +1,272 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Address, Secret, ViewKey};
+use snarkvm_console_types::{Group, Scalar};
+
+const RATCHET_DOMAIN: DomainSeparator = DomainSeparator::new("EncryptionRatchet", 0);
+const SESSION_DOMAIN: DomainSeparator = DomainSeparator::new("EncryptionSession", 0);
+
+impl<N: Network> ECIESPoseidonEncryption<N> {
+    /// Returns the symmetric key that follows the given symmetric key.
+    ///
+    /// The ratchet is one-way, so a compromised key does not reveal the keys that preceded it.
+    pub fn ratchet(symmetric_key: &Field<N>) -> Result<Field<N>> {
+        N::hash_psd2(&[Field::from_domain_separator(RATCHET_DOMAIN), *symmetric_key])
+    }
+}
+
+/// The ephemeral key of one side of a session, whose public key is sent to the peer to start the session.
+///
+/// A new ephemeral key must be sampled for every session. It is consumed when the session starts, so the
+/// session keys cannot be recomputed from the long-term view keys once the session ends.
+pub struct SessionEphemeralKey<N: Network> {
+    /// The ephemeral secret key.
+    secret_key: Secret<Scalar<N>>,
+    /// The ephemeral public key, which is sent to the peer.
+    public_key: Group<N>,
+}
+
+impl<N: Network> SessionEphemeralKey<N> {
+    /// Samples a new ephemeral key.
+    pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        let secret_key = Secret::new(Scalar::rand(rng));
+        let public_key = N::g_scalar_multiply(&secret_key);
+        Self { secret_key, public_key }
+    }
+
+    /// Returns the ephemeral public key, which is sent to the peer.
+    pub const fn public_key(&self) -> &Group<N> {
+        &self.public_key
+    }
+}
+
+/// An encrypted channel between two Aleo addresses, which ratchets its keys after every message.
+///
+/// Each direction of the channel has its own chain of keys, derived from both the Diffie-Hellman secret of the
+/// two accounts and the Diffie-Hellman secret of the two ephemeral keys of the session. As the ephemeral keys
+/// are fresh for every session, two sessions between the same accounts never share a key stream.
+/// Messages are encrypted with `encrypt_authenticated`, and must be decrypted in the order in which they were
+/// encrypted. A key is ratcheted as soon as it is used, so a compromised session does not reveal the messages
+/// that were exchanged before it.
+pub struct SessionCipher<N: Network> {
+    /// The key of the next message to the peer.
+    sending_key: Secret<Field<N>>,
+    /// The key of the next message from the peer.
+    receiving_key: Secret<Field<N>>,
+}
+
+impl<N: Network> SessionCipher<N> {
+    /// Initializes the session between the account of the given view key and the given peer,
+    /// from the ephemeral key of this account and the ephemeral public key of the peer.
+    pub fn new(
+        view_key: &ViewKey<N>,
+        peer: &Address<N>,
+        ephemeral_key: SessionEphemeralKey<N>,
+        peer_ephemeral_public_key: &Group<N>,
+    ) -> Result<Self> {
+        let address = view_key.to_address();
+        // Ensure the peer is another account, as both directions of the channel would otherwise share their keys.
+        ensure!(address != *peer, "The peer of a session must not be the account itself");
+        // Ensure the peer is not the identity, which would fix the shared secret.
+        ensure!(!peer.is_zero(), "The peer of a session must not be the identity");
+        // Ensure the ephemeral public key of the peer is not the identity, which would fix the ephemeral secret.
+        ensure!(!peer_ephemeral_public_key.is_zero(), "The ephemeral public key of the peer must not be the identity");
+        // Ensure the ephemeral keys are distinct, as an echoed ephemeral public key indicates a reflection.
+        ensure!(
+            ephemeral_key.public_key != *peer_ephemeral_public_key,
+            "The ephemeral public key of the peer must not be the ephemeral public key of the account"
+        );
+
+        // Compute the Diffie-Hellman secret of the two accounts.
+        let static_secret = Secret::new((**peer * **view_key).to_x_coordinate());
+        // Compute the Diffie-Hellman secret of the two ephemeral keys.
+        let ephemeral_secret = Secret::new((*peer_ephemeral_public_key * *ephemeral_key.secret_key).to_x_coordinate());
+
+        // Bind each direction of the channel to its sender and receiver.
+        let local = (address.to_x_coordinate(), ephemeral_key.public_key.to_x_coordinate());
+        let remote = (peer.to_x_coordinate(), peer_ephemeral_public_key.to_x_coordinate());
+        Ok(Self {
+            sending_key: Self::chain_key(&static_secret, &ephemeral_secret, local, remote)?,
+            receiving_key: Self::chain_key(&static_secret, &ephemeral_secret, remote, local)?,
+        })
+    }
+
+    /// Encrypts the given message to the peer, and ratchets the sending key.
+    pub fn encrypt(&mut self, message: &[u8]) -> Result<Vec<Field<N>>> {
+        let encoded = ECIESPoseidonEncryption::encode_message(message)?;
+        let ciphertext = ECIESPoseidonEncryption::encrypt_authenticated(&self.sending_key, &encoded)?;
+        self.sending_key = Secret::new(ECIESPoseidonEncryption::ratchet(&self.sending_key)?);
+        Ok(ciphertext)
+    }
+
+    /// Decrypts the given message from the peer, and ratchets the receiving key.
+    ///
+    /// The receiving key is only ratcheted if the ciphertext is valid, so a rejected ciphertext does not
+    /// desynchronize the session.
+    pub fn decrypt(&mut self, ciphertext: &[Field<N>]) -> Result<Vec<u8>> {
+        let encoded = ECIESPoseidonEncryption::decrypt_checked(&self.receiving_key, ciphertext)?;
+        let message = ECIESPoseidonEncryption::decode_message(&encoded)?;
+        self.receiving_key = Secret::new(ECIESPoseidonEncryption::ratchet(&self.receiving_key)?);
+        Ok(message)
+    }
+
+    /// Returns the first key of the chain from the given sender to the given receiver,
+    /// where each party is given as the pair of its address and ephemeral public key coordinates.
+    fn chain_key(
+        static_secret: &Field<N>,
+        ephemeral_secret: &Field<N>,
+        (sender, sender_ephemeral): (Field<N>, Field<N>),
+        (receiver, receiver_ephemeral): (Field<N>, Field<N>),
+    ) -> Result<Secret<Field<N>>> {
+        Ok(Secret::new(N::hash_psd8(&[
+            Field::from_domain_separator(SESSION_DOMAIN),
+            *static_secret,
+            *ephemeral_secret,
+            sender,
+            sender_ephemeral,
+            receiver,
+            receiver_ephemeral,
+        ])?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    /// Returns the view key and address of a new account.
+    fn sample_account(rng: &mut TestRng) -> Result<(ViewKey<CurrentNetwork>, Address<CurrentNetwork>)> {
+        let private_key = PrivateKey::new(rng)?;
+        Ok((ViewKey::try_from(&private_key)?, Address::try_from(&private_key)?))
+    }
+
+    /// Returns a new session for each of the two given accounts, with fresh ephemeral keys.
+    fn sample_sessions(
+        (alice_view_key, alice): &(ViewKey<CurrentNetwork>, Address<CurrentNetwork>),
+        (bob_view_key, bob): &(ViewKey<CurrentNetwork>, Address<CurrentNetwork>),
+        rng: &mut TestRng,
+    ) -> Result<(SessionCipher<CurrentNetwork>, SessionCipher<CurrentNetwork>)> {
+        let alice_ephemeral_key = SessionEphemeralKey::new(rng);
+        let bob_ephemeral_key = SessionEphemeralKey::new(rng);
+        let alice_ephemeral_public_key = *alice_ephemeral_key.public_key();
+        let bob_ephemeral_public_key = *bob_ephemeral_key.public_key();
+        Ok((
+            SessionCipher::new(alice_view_key, bob, alice_ephemeral_key, &bob_ephemeral_public_key)?,
+            SessionCipher::new(bob_view_key, alice, bob_ephemeral_key, &alice_ephemeral_public_key)?,
+        ))
+    }
+
+    #[test]
+    fn test_ratchet() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let key = Field::<CurrentNetwork>::rand(rng);
+        let next = ECIESPoseidonEncryption::ratchet(&key)?;
+        assert_ne!(key, next);
+        assert_eq!(next, ECIESPoseidonEncryption::ratchet(&key)?);
+        assert_ne!(next, ECIESPoseidonEncryption::ratchet(&next)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_session() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let alice = sample_account(rng)?;
+        let bob = sample_account(rng)?;
+        let (mut alice_session, mut bob_session) = sample_sessions(&alice, &bob, rng)?;
+
+        for _ in 0..ITERATIONS {
+            let message: Vec<u8> = (0..rng.gen_range(0..100)).map(|_| rng.gen()).collect_vec();
+            let ciphertext = alice_session.encrypt(&message)?;
+            assert_eq!(message, bob_session.decrypt(&ciphertext)?);
+
+            let message: Vec<u8> = (0..rng.gen_range(0..100)).map(|_| rng.gen()).collect_vec();
+            let ciphertext = bob_session.encrypt(&message)?;
+            assert_eq!(message, alice_session.decrypt(&ciphertext)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_session_ratchets() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let alice = sample_account(rng)?;
+        let bob = sample_account(rng)?;
+        let (mut alice_session, mut bob_session) = sample_sessions(&alice, &bob, rng)?;
+
+        // Check that the same message is encrypted under a new key every time.
+        let first = alice_session.encrypt(b"message")?;
+        let second = alice_session.encrypt(b"message")?;
+        assert_ne!(first, second);
+
+        // Check that a message sent by Alice is not decrypted as a message to Alice.
+        assert!(alice_session.decrypt(&first).is_err());
+        // Check that a rejected ciphertext does not desynchronize the session.
+        assert!(bob_session.decrypt(&second).is_err());
+        assert_eq!(b"message".to_vec(), bob_session.decrypt(&first)?);
+        assert_eq!(b"message".to_vec(), bob_session.decrypt(&second)?);
+        // Check that a replayed ciphertext is rejected.
+        assert!(bob_session.decrypt(&first).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sessions_are_independent() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let alice = sample_account(rng)?;
+        let bob = sample_account(rng)?;
+        let (mut first_alice_session, _) = sample_sessions(&alice, &bob, rng)?;
+        let (mut second_alice_session, mut second_bob_session) = sample_sessions(&alice, &bob, rng)?;
+
+        // Check that two sessions between the same accounts encrypt the same message differently.
+        let first = first_alice_session.encrypt(b"message")?;
+        let second = second_alice_session.encrypt(b"message")?;
+        assert_ne!(first, second);
+
+        // Check that a message from one session is not decrypted in another session.
+        assert!(second_bob_session.decrypt(&first).is_err());
+        assert_eq!(b"message".to_vec(), second_bob_session.decrypt(&second)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_session_with_self() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (view_key, address) = sample_account(rng)?;
+        let (_, peer) = sample_account(rng)?;
+        let peer_ephemeral_key = SessionEphemeralKey::<CurrentNetwork>::new(rng);
+        let candidate =
+            SessionCipher::new(&view_key, &address, SessionEphemeralKey::new(rng), peer_ephemeral_key.public_key());
+        assert!(candidate.is_err());
+
+        // Check that an identity or reflected ephemeral public key is rejected.
+        assert!(SessionCipher::new(&view_key, &peer, SessionEphemeralKey::new(rng), &Group::zero()).is_err());
+        let ephemeral_key = SessionEphemeralKey::new(rng);
+        let ephemeral_public_key = *ephemeral_key.public_key();
+        assert!(SessionCipher::new(&view_key, &peer, ephemeral_key, &ephemeral_public_key).is_err());
+        Ok(())
+    }
+}
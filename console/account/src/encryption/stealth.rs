@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{ECIESPoseidonKem, KemScheme, Secret, ViewKey};
+use snarkvm_console_types::{Address, Group, Scalar};
+
+const STEALTH_DOMAIN: DomainSeparator = DomainSeparator::new("StealthPublicKey", 0);
+
+/// A one-time public key of a recipient, which the sender derives from the address of the recipient.
+///
+/// The key is `(r * G, A + d * G)`, where `A` is the address of the recipient, and the diversifier `d` is
+/// derived from `r * A`. Only the recipient recovers `d`, by scanning with its view key, so the one-time keys
+/// of an address are unlinkable to the address and to each other. Messages are encrypted to the address of
+/// the one-time key, and decrypted with the one-time view key `a + d`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct StealthPublicKey<N: Network> {
+    /// The ephemeral key `r * G`, from which the recipient derives the diversifier.
+    ephemeral_key: Group<N>,
+    /// The one-time key `A + d * G`.
+    one_time_key: Group<N>,
+}
+
+impl<N: Network> StealthPublicKey<N> {
+    /// Returns the ephemeral key, from which the recipient derives the diversifier.
+    pub const fn ephemeral_key(&self) -> &Group<N> {
+        &self.ephemeral_key
+    }
+
+    /// Returns the address of the one-time key, to which messages are encrypted.
+    pub const fn to_address(&self) -> Address<N> {
+        Address::new(self.one_time_key)
+    }
+}
+
+impl<N: Network> ECIESPoseidonEncryption<N> {
+    /// Returns a new one-time public key of the given address.
+    pub fn generate_stealth_public_key<R: Rng + CryptoRng>(
+        address: &Address<N>,
+        rng: &mut R,
+    ) -> Result<StealthPublicKey<N>> {
+        let (ephemeral_key, shared_secret) = ECIESPoseidonKem::encapsulate(address, rng)?;
+        let diversifier = Self::stealth_diversifier(&Secret::new(shared_secret))?;
+        Ok(StealthPublicKey { ephemeral_key, one_time_key: **address + N::g_scalar_multiply(&diversifier) })
+    }
+
+    /// Returns the one-time view key of the given one-time public key, or `None` if the one-time public key
+    /// does not belong to the given view key.
+    pub fn scan_stealth_public_key(
+        view_key: &ViewKey<N>,
+        stealth_public_key: &StealthPublicKey<N>,
+    ) -> Result<Option<ViewKey<N>>> {
+        let shared_secret = Secret::new(ECIESPoseidonKem::decapsulate(view_key, &stealth_public_key.ephemeral_key)?);
+        let diversifier = Self::stealth_diversifier(&shared_secret)?;
+        let one_time_view_key = ViewKey::from_scalar(**view_key + *diversifier);
+        match *one_time_view_key.to_address() == stealth_public_key.one_time_key {
+            true => Ok(Some(one_time_view_key)),
+            false => Ok(None),
+        }
+    }
+
+    /// Returns the diversifier for the given shared secret.
+    fn stealth_diversifier(shared_secret: &Field<N>) -> Result<Secret<Scalar<N>>> {
+        Ok(Secret::new(N::hash_to_scalar_psd2(&[Field::from_domain_separator(STEALTH_DOMAIN), *shared_secret])?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Encryption = ECIESPoseidonEncryption<CurrentNetwork>;
+
+    const ITERATIONS: u64 = 25;
+
+    #[test]
+    fn test_stealth_encrypt_and_decrypt() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            // The sender encrypts the message to a one-time public key of the recipient.
+            let stealth_public_key = Encryption::generate_stealth_public_key(&address, rng)?;
+            let message = (0..rng.gen_range(0..10)).map(|_| Field::rand(rng)).collect_vec();
+            let (nonce, symmetric_key) = ECIESPoseidonKem::encapsulate(&stealth_public_key.to_address(), rng)?;
+            let ciphertext = Encryption::encrypt(&symmetric_key, &message);
+
+            // The recipient recognizes the one-time public key, and decrypts the message.
+            let one_time_view_key = Encryption::scan_stealth_public_key(&view_key, &stealth_public_key)?.unwrap();
+            let symmetric_key = ECIESPoseidonKem::decapsulate(&one_time_view_key, &nonce)?;
+            assert_eq!(message, Encryption::decrypt(&symmetric_key, &ciphertext));
+
+            // Check that another account does not recognize the one-time public key.
+            let other = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(Encryption::scan_stealth_public_key(&other, &stealth_public_key)?.is_none());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_stealth_public_keys_are_unlinkable() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let first = Encryption::generate_stealth_public_key(&address, rng)?;
+        let second = Encryption::generate_stealth_public_key(&address, rng)?;
+        assert_ne!(first.to_address(), address);
+        assert_ne!(first.to_address(), second.to_address());
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An incremental encryption of a byte message, for messages that are too large to encode at once.
+///
+/// The ciphertext is the output of `ECIESPoseidonEncryption::encrypt` on the encoding of the message,
+/// so it may be decrypted at once, or incrementally with a `DecryptionStream`.
+#[derive(Clone, Debug)]
+pub struct EncryptionStream<N: Network> {
+    /// The key stream, which is squeezed as the message is written.
+    key_stream: PoseidonStream<N, 8>,
+    /// The bytes of the message that do not yet fill a field element.
+    buffer: Vec<u8>,
+}
+
+impl<N: Network> EncryptionStream<N> {
+    /// Initializes a new encryption stream under the given symmetric key.
+    pub fn new(symmetric_key: &Field<N>) -> Self {
+        let key_stream = ECIESPoseidonEncryption::key_stream(symmetric_key);
+        Self { key_stream, buffer: Vec::with_capacity(ECIESPoseidonEncryption::<N>::bytes_per_field()) }
+    }
+
+    /// Writes the given bytes of the message, and returns the ciphertext of every completed field element.
+    pub fn write(&mut self, bytes: &[u8]) -> Result<Vec<Field<N>>> {
+        let bytes_per_field = ECIESPoseidonEncryption::<N>::bytes_per_field();
+        self.buffer.extend_from_slice(bytes);
+
+        // Encode the completed field elements, and keep the remaining bytes.
+        let num_bytes = self.buffer.len() - self.buffer.len() % bytes_per_field;
+        let encoded = self.buffer[..num_bytes]
+            .chunks(bytes_per_field)
+            .map(ECIESPoseidonEncryption::encode_chunk)
+            .collect::<Result<Vec<_>>>()?;
+        self.buffer.drain(..num_bytes);
+
+        Ok(self.encrypt(&encoded))
+    }
+
+    /// Pads the message, and returns the ciphertext of its last field element.
+    pub fn finalize(mut self) -> Result<Vec<Field<N>>> {
+        // The buffer holds less than a field element, so the terminator completes the last field element.
+        self.buffer.push(1u8);
+        let encoded = ECIESPoseidonEncryption::encode_chunk(&self.buffer)?;
+        Ok(self.encrypt(&[encoded]))
+    }
+
+    /// Encrypts the given field elements with the next randomizers of the key stream.
+    fn encrypt(&mut self, encoded: &[Field<N>]) -> Vec<Field<N>> {
+        ECIESPoseidonEncryption::apply_key_stream(&mut self.key_stream, encoded, |message, randomizer| {
+            *message + randomizer
+        })
+    }
+}
+
+/// An incremental decryption of a byte message, for ciphertexts that are too large to decrypt at once.
+#[derive(Clone, Debug)]
+pub struct DecryptionStream<N: Network> {
+    /// The key stream, which is squeezed as the ciphertext is written.
+    key_stream: PoseidonStream<N, 8>,
+    /// The decoded bytes of the last field element, which hold the padding if the ciphertext is complete.
+    last: Option<Vec<u8>>,
+}
+
+impl<N: Network> DecryptionStream<N> {
+    /// Initializes a new decryption stream under the given symmetric key.
+    pub fn new(symmetric_key: &Field<N>) -> Self {
+        Self { key_stream: ECIESPoseidonEncryption::key_stream(symmetric_key), last: None }
+    }
+
+    /// Writes the given field elements of the ciphertext, and returns the bytes of the message that are
+    /// known not to be padding.
+    pub fn write(&mut self, ciphertext: &[Field<N>]) -> Result<Vec<u8>> {
+        let decrypted =
+            ECIESPoseidonEncryption::apply_key_stream(&mut self.key_stream, ciphertext, |ciphertext, randomizer| {
+                *ciphertext - randomizer
+            });
+
+        let mut message = Vec::with_capacity(decrypted.len() * ECIESPoseidonEncryption::<N>::bytes_per_field());
+        for field in &decrypted {
+            // Release the previous field element, as it is not the last.
+            if let Some(previous) = self.last.replace(ECIESPoseidonEncryption::decode_chunk(field)?) {
+                message.extend(previous);
+            }
+        }
+        Ok(message)
+    }
+
+    /// Removes the padding from the last field element, and returns the remaining bytes of the message.
+    pub fn finalize(mut self) -> Result<Vec<u8>> {
+        match self.last.take() {
+            Some(last) => ECIESPoseidonEncryption::<N>::unpad(last),
+            None => bail!("The ciphertext is empty"),
+        }
+    }
+}
+
+impl<N: Network> Drop for EncryptionStream<N> {
+    /// Zeroizes the buffered bytes of the message.
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.buffer);
+    }
+}
+
+impl<N: Network> Drop for DecryptionStream<N> {
+    /// Zeroizes the decoded bytes of the last field element.
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.last);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Encryption = ECIESPoseidonEncryption<CurrentNetwork>;
+
+    #[test]
+    fn test_stream_matches_encrypt() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for length in [0, 1, 30, 31, 32, 100, 1000] {
+            let symmetric_key = Field::<CurrentNetwork>::rand(rng);
+            let message = (0..length).map(|_| rng.gen()).collect::<Vec<u8>>();
+
+            // Encrypt the message in chunks of random sizes.
+            let mut stream = EncryptionStream::new(&symmetric_key);
+            let mut ciphertext = Vec::new();
+            let mut remaining = &message[..];
+            while !remaining.is_empty() {
+                let (chunk, rest) = remaining.split_at(rng.gen_range(1..=remaining.len()));
+                ciphertext.extend(stream.write(chunk)?);
+                remaining = rest;
+            }
+            ciphertext.extend(stream.finalize()?);
+            assert_eq!(ciphertext, Encryption::encrypt(&symmetric_key, &Encryption::encode_message(&message)?));
+
+            // Decrypt the ciphertext in chunks of random sizes.
+            let mut stream = DecryptionStream::new(&symmetric_key);
+            let mut candidate = Vec::new();
+            let mut remaining = &ciphertext[..];
+            while !remaining.is_empty() {
+                let (chunk, rest) = remaining.split_at(rng.gen_range(1..=remaining.len()));
+                candidate.extend(stream.write(chunk)?);
+                remaining = rest;
+            }
+            candidate.extend(stream.finalize()?);
+            assert_eq!(message, candidate);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decryption_stream_with_wrong_key() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let symmetric_key = Field::<CurrentNetwork>::rand(rng);
+        let ciphertext = Encryption::encrypt(&symmetric_key, &Encryption::encode_message(b"hello world")?);
+
+        // A wrong key decrypts to field elements that are not valid encodings, with overwhelming probability.
+        let mut stream = DecryptionStream::new(&Field::rand(rng));
+        assert!(stream.write(&ciphertext).is_err());
+        assert!(DecryptionStream::<CurrentNetwork>::new(&symmetric_key).finalize().is_err());
+        Ok(())
+    }
+}
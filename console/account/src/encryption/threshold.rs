@@ -0,0 +1,361 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Secret, ViewKey};
+use snarkvm_console_types::{Address, Group, Scalar};
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+const THRESHOLD_DECRYPTION_DOMAIN: DomainSeparator = DomainSeparator::new("ThresholdDecryption", 0);
+
+/// The share of a view key that is held by one party of a threshold committee, which is zeroized on drop.
+#[derive(Clone, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct ViewKeyShare<N: Network> {
+    /// The index of the party, starting from 1.
+    index: u32,
+    /// The evaluation of the secret-sharing polynomial at `index`.
+    share: Scalar<N>,
+}
+
+impl<N: Network> ViewKeyShare<N> {
+    /// Returns the index of the party.
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+impl<N: Network> Debug for ViewKeyShare<N> {
+    /// Omits the share, which is secret.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "ViewKeyShare({})", self.index)
+    }
+}
+
+/// The public parameters of a view key that is shared among a threshold committee.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdViewKey<N: Network> {
+    /// The number of partial decryptions required to decrypt a ciphertext.
+    threshold: u32,
+    /// The address of the shared view key, to which ciphertexts are encrypted.
+    address: Address<N>,
+    /// The verification key of each party, `s_i * G`, ordered by the index of the party.
+    verification_keys: Vec<Group<N>>,
+}
+
+impl<N: Network> ThresholdViewKey<N> {
+    /// Returns the number of partial decryptions required to decrypt a ciphertext.
+    pub const fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Returns the address of the shared view key.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+
+    /// Returns the number of parties in the committee.
+    pub fn num_parties(&self) -> usize {
+        self.verification_keys.len()
+    }
+
+    /// Returns the verification key of the party with the given index, if it exists.
+    pub fn verification_key(&self, index: u32) -> Option<&Group<N>> {
+        (index as usize).checked_sub(1).and_then(|i| self.verification_keys.get(i))
+    }
+}
+
+/// The contribution of one party to the decryption of a ciphertext, which is the nonce multiplied
+/// by the view key share of the party, along with a proof that it was computed with that share.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PartialDecryption<N: Network> {
+    /// The index of the party.
+    index: u32,
+    /// The nonce, multiplied by the view key share of the party.
+    share: Group<N>,
+    /// The challenge of the proof of equality of discrete logarithms.
+    challenge: Scalar<N>,
+    /// The response of the proof of equality of discrete logarithms.
+    response: Scalar<N>,
+}
+
+impl<N: Network> PartialDecryption<N> {
+    /// Returns the index of the party.
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+impl<N: Network> ECIESPoseidonEncryption<N> {
+    /// Shares the given view key among `num_parties` parties, of which any `threshold` can decrypt a ciphertext
+    /// to its address, and returns the public parameters of the shared view key and the share of each party.
+    ///
+    /// The shares are produced by a trusted dealer, who must erase the view key afterwards.
+    pub fn share_view_key<R: Rng + CryptoRng>(
+        view_key: &ViewKey<N>,
+        num_parties: u32,
+        threshold: u32,
+        rng: &mut R,
+    ) -> Result<(ThresholdViewKey<N>, Vec<ViewKeyShare<N>>)> {
+        ensure!(
+            threshold > 0 && threshold <= num_parties,
+            "The threshold ({threshold}) must be between 1 and the number of parties ({num_parties})"
+        );
+
+        // Sample the secret-sharing polynomial, whose constant term is the view key.
+        let mut coefficients = Vec::with_capacity(threshold as usize);
+        coefficients.push(**view_key);
+        coefficients.extend((1..threshold).map(|_| Scalar::<N>::rand(rng)));
+        // Evaluate the polynomial at the index of each party.
+        let shares = (1..=num_parties)
+            .map(|index| {
+                let x = Self::scalar_from_index(index);
+                let share = coefficients.iter().rev().fold(Scalar::zero(), |acc, coefficient| acc * x + coefficient);
+                ViewKeyShare { index, share }
+            })
+            .collect::<Vec<_>>();
+        coefficients.zeroize();
+
+        let verification_keys = shares.iter().map(|share| N::g_scalar_multiply(&share.share)).collect();
+        Ok((ThresholdViewKey { threshold, address: view_key.to_address(), verification_keys }, shares))
+    }
+
+    /// Returns the partial decryption of the given view key share for the ciphertext with the given nonce.
+    pub fn partial_decrypt<R: Rng + CryptoRng>(
+        view_key_share: &ViewKeyShare<N>,
+        nonce: &Group<N>,
+        rng: &mut R,
+    ) -> Result<PartialDecryption<N>> {
+        // Ensure the nonce is not the identity, which would fix the shared secret.
+        ensure!(!nonce.is_zero(), "The nonce must not be the identity");
+
+        let share = *nonce * view_key_share.share;
+        let verification_key = N::g_scalar_multiply(&view_key_share.share);
+
+        // Prove that `share` and `verification_key` have the same discrete logarithm, to the bases `nonce` and `G`.
+        let randomizer = Secret::new(Scalar::rand(rng));
+        let g_randomizer = N::g_scalar_multiply(&randomizer);
+        let nonce_randomizer = *nonce * *randomizer;
+        let challenge = Self::partial_decryption_challenge(
+            view_key_share.index,
+            nonce,
+            &verification_key,
+            &share,
+            &g_randomizer,
+            &nonce_randomizer,
+        )?;
+        let response = *randomizer - challenge * view_key_share.share;
+
+        Ok(PartialDecryption { index: view_key_share.index, share, challenge, response })
+    }
+
+    /// Returns `true` if the given partial decryption was produced by its party for the ciphertext with the given nonce.
+    pub fn verify_partial_decryption(
+        threshold_view_key: &ThresholdViewKey<N>,
+        nonce: &Group<N>,
+        partial_decryption: &PartialDecryption<N>,
+    ) -> bool {
+        let Some(verification_key) = threshold_view_key.verification_key(partial_decryption.index) else {
+            return false;
+        };
+        let PartialDecryption { index, share, challenge, response } = *partial_decryption;
+
+        // Recompute the commitments of the proof, and check the challenge.
+        let g_randomizer = N::g_scalar_multiply(&response) + *verification_key * challenge;
+        let nonce_randomizer = *nonce * response + share * challenge;
+        match Self::partial_decryption_challenge(
+            index,
+            nonce,
+            verification_key,
+            &share,
+            &g_randomizer,
+            &nonce_randomizer,
+        ) {
+            Ok(candidate) => candidate == challenge,
+            Err(_) => false,
+        }
+    }
+
+    /// Combines any `threshold` valid partial decryptions of distinct parties for the ciphertext with the given
+    /// nonce, and returns the symmetric key of the ciphertext. Every partial decryption is verified against
+    /// the threshold view key, and invalid ones, as well as repeated ones of a party, are skipped.
+    pub fn combine_partial_decryptions(
+        threshold_view_key: &ThresholdViewKey<N>,
+        nonce: &Group<N>,
+        partial_decryptions: &[PartialDecryption<N>],
+    ) -> Result<Field<N>> {
+        // Select `threshold` valid partial decryptions from distinct members of the committee.
+        let threshold = threshold_view_key.threshold as usize;
+        let mut selected: Vec<&PartialDecryption<N>> = Vec::with_capacity(threshold);
+        for partial_decryption in partial_decryptions {
+            if selected.len() == threshold {
+                break;
+            }
+            // Skip a party that already has a valid partial decryption, before verifying the proof.
+            if !selected.iter().any(|other| other.index == partial_decryption.index)
+                && Self::verify_partial_decryption(threshold_view_key, nonce, partial_decryption)
+            {
+                selected.push(partial_decryption);
+            }
+        }
+        ensure!(
+            selected.len() == threshold,
+            "Expected at least {threshold} valid partial decryptions, found {}",
+            selected.len()
+        );
+        let partial_decryptions = selected;
+
+        // Interpolate `view_key * nonce` from the partial decryptions, and recover the symmetric key.
+        let mut shared_point = Group::zero();
+        for (i, partial_decryption) in partial_decryptions.iter().enumerate() {
+            let x_i = Self::scalar_from_index(partial_decryption.index);
+            // Compute the Lagrange coefficient at zero, `prod_{j != i} x_j / (x_j - x_i)`.
+            let mut numerator = Scalar::<N>::one();
+            let mut denominator = Scalar::<N>::one();
+            for (j, other) in partial_decryptions.iter().enumerate() {
+                if i != j {
+                    let x_j = Self::scalar_from_index(other.index);
+                    numerator *= x_j;
+                    denominator *= x_j - x_i;
+                }
+            }
+            shared_point += partial_decryption.share * (numerator * denominator.inverse()?);
+        }
+        Ok(shared_point.to_x_coordinate())
+    }
+
+    /// Returns the challenge of the proof of a partial decryption.
+    fn partial_decryption_challenge(
+        index: u32,
+        nonce: &Group<N>,
+        verification_key: &Group<N>,
+        share: &Group<N>,
+        g_randomizer: &Group<N>,
+        nonce_randomizer: &Group<N>,
+    ) -> Result<Scalar<N>> {
+        let mut preimage = vec![Field::from_domain_separator(THRESHOLD_DECRYPTION_DOMAIN), Field::from_u32(index)];
+        preimage.extend(
+            [nonce, verification_key, share, g_randomizer, nonce_randomizer].map(|point| point.to_x_coordinate()),
+        );
+        N::hash_to_scalar_psd8(&preimage)
+    }
+
+    /// Returns the evaluation point of the party with the given index.
+    fn scalar_from_index(index: u32) -> Scalar<N> {
+        Scalar::new(N::Scalar::from(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ECIESPoseidonKem, KemScheme, PrivateKey};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Encryption = ECIESPoseidonEncryption<CurrentNetwork>;
+
+    const NUM_PARTIES: u32 = 5;
+    const THRESHOLD: u32 = 3;
+
+    fn sample_committee(
+        rng: &mut TestRng,
+    ) -> Result<(ViewKey<CurrentNetwork>, ThresholdViewKey<CurrentNetwork>, Vec<ViewKeyShare<CurrentNetwork>>)> {
+        let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let (threshold_view_key, shares) = Encryption::share_view_key(&view_key, NUM_PARTIES, THRESHOLD, rng)?;
+        Ok((view_key, threshold_view_key, shares))
+    }
+
+    #[test]
+    fn test_threshold_decrypt() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (view_key, threshold_view_key, shares) = sample_committee(rng)?;
+        assert_eq!(threshold_view_key.num_parties(), NUM_PARTIES as usize);
+        assert_eq!(*threshold_view_key.address(), view_key.to_address());
+
+        let message = Encryption::encode_message(b"for the committee")?;
+        let (nonce, symmetric_key) = ECIESPoseidonKem::encapsulate(threshold_view_key.address(), rng)?;
+        let ciphertext = Encryption::encrypt(&symmetric_key, &message);
+
+        let partial_decryptions =
+            shares.iter().map(|share| Encryption::partial_decrypt(share, &nonce, rng)).collect::<Result<Vec<_>>>()?;
+        for partial_decryption in &partial_decryptions {
+            assert!(Encryption::verify_partial_decryption(&threshold_view_key, &nonce, partial_decryption));
+        }
+
+        // Check that any `threshold` of the parties recover the symmetric key.
+        for start in 0..=(NUM_PARTIES - THRESHOLD) as usize {
+            let candidate = Encryption::combine_partial_decryptions(
+                &threshold_view_key,
+                &nonce,
+                &partial_decryptions[start..start + THRESHOLD as usize],
+            )?;
+            assert_eq!(candidate, symmetric_key);
+            assert_eq!(message, Encryption::decrypt(&candidate, &ciphertext));
+        }
+        let reversed = partial_decryptions.iter().rev().copied().collect_vec();
+        assert_eq!(symmetric_key, Encryption::combine_partial_decryptions(&threshold_view_key, &nonce, &reversed)?);
+
+        // Check that fewer than `threshold` parties are rejected.
+        let too_few = &partial_decryptions[..THRESHOLD as usize - 1];
+        assert!(Encryption::combine_partial_decryptions(&threshold_view_key, &nonce, too_few).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_partial_decryptions() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (_, threshold_view_key, shares) = sample_committee(rng)?;
+        let (nonce, symmetric_key) = ECIESPoseidonKem::encapsulate(threshold_view_key.address(), rng)?;
+        let mut partial_decryptions =
+            shares.iter().map(|share| Encryption::partial_decrypt(share, &nonce, rng)).collect::<Result<Vec<_>>>()?;
+
+        // Check that a partial decryption for another nonce is rejected.
+        let (other_nonce, _) = ECIESPoseidonKem::encapsulate(threshold_view_key.address(), rng)?;
+        assert!(!Encryption::verify_partial_decryption(&threshold_view_key, &other_nonce, &partial_decryptions[0]));
+
+        // Checks that the partial decryptions recover the symmetric key, but that their first `threshold` do not.
+        let check = |partial_decryptions: &[PartialDecryption<CurrentNetwork>]| -> Result<()> {
+            let candidate = Encryption::combine_partial_decryptions(&threshold_view_key, &nonce, partial_decryptions)?;
+            assert_eq!(candidate, symmetric_key);
+            let first = &partial_decryptions[..THRESHOLD as usize];
+            assert!(Encryption::combine_partial_decryptions(&threshold_view_key, &nonce, first).is_err());
+            Ok(())
+        };
+
+        // Check that a repeated partial decryption of a party is only counted once.
+        let duplicates = [partial_decryptions[0], partial_decryptions[0], partial_decryptions[1]];
+        assert!(Encryption::combine_partial_decryptions(&threshold_view_key, &nonce, &duplicates).is_err());
+        check(&[&duplicates[..], &partial_decryptions[2..]].concat())?;
+
+        // Check that a modified share is skipped.
+        partial_decryptions[0].share += Group::generator();
+        assert!(!Encryption::verify_partial_decryption(&threshold_view_key, &nonce, &partial_decryptions[0]));
+        check(&partial_decryptions)?;
+
+        // Check that an unknown party is skipped.
+        partial_decryptions[0] = partial_decryptions[1];
+        partial_decryptions[0].index = NUM_PARTIES + 1;
+        assert!(!Encryption::verify_partial_decryption(&threshold_view_key, &nonce, &partial_decryptions[0]));
+        check(&partial_decryptions)?;
+
+        // Check that an invalid threshold is rejected.
+        let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        assert!(Encryption::share_view_key(&view_key, 3, 0, rng).is_err());
+        assert!(Encryption::share_view_key(&view_key, 3, 4, rng).is_err());
+        Ok(())
+    }
+}
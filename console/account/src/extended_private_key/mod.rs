@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{PrivateKey, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+const ACCOUNT_MASTER_KEY_DOMAIN: DomainSeparator = DomainSeparator::new("AccountMasterKey", 0);
+const ACCOUNT_CHILD_KEY_DOMAIN: DomainSeparator = DomainSeparator::new("AccountChildKey", 0);
+
+/// An extended private key, which derives a tree of account private keys from a master seed.
+///
+/// Every derivation is hardened: a child is derived with a Poseidon hash of the account seed and the
+/// chain code of its parent, so a child key reveals neither its parent nor its siblings.
+/// The key is zeroized on drop.
+#[derive(Clone, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct ExtendedPrivateKey<N: Network> {
+    /// The account seed of the key.
+    seed: Field<N>,
+    /// The chain code, which is mixed into the derivation of each child.
+    chain_code: Field<N>,
+}
+
+impl<N: Network> ExtendedPrivateKey<N> {
+    /// Returns the master key for the given master seed.
+    pub fn from_seed(master_seed: &Field<N>) -> Result<Self> {
+        let domain = Field::from_domain_separator(ACCOUNT_MASTER_KEY_DOMAIN);
+        Self::from_hash(&[domain, *master_seed])
+    }
+
+    /// Returns the child key at the given index.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        let domain = Field::from_domain_separator(ACCOUNT_CHILD_KEY_DOMAIN);
+        Self::from_hash(&[domain, self.seed, self.chain_code, Field::from_u32(index)])
+    }
+
+    /// Returns the descendant key along the given path of child indices.
+    pub fn derive_path(&self, path: &[u32]) -> Result<Self> {
+        path.iter().try_fold(self.clone(), |key, index| key.derive_child(*index))
+    }
+
+    /// Returns the account private key.
+    pub fn to_private_key(&self) -> Result<PrivateKey<N>> {
+        PrivateKey::try_from(self.seed)
+    }
+
+    /// Returns the account view key, which is the private key for encryption to the account.
+    pub fn to_view_key(&self) -> Result<ViewKey<N>> {
+        ViewKey::try_from(self.to_private_key()?)
+    }
+
+    /// Returns the key whose seed and chain code are the hash of the given input.
+    fn from_hash(input: &[Field<N>]) -> Result<Self> {
+        match N::hash_many_psd4(input, 2).as_slice() {
+            [seed, chain_code] => Ok(Self { seed: *seed, chain_code: *chain_code }),
+            _ => bail!("Failed to derive the extended private key"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ExtendedPrivateKey<N> {
+    /// Omits the seed and chain code, which are secret.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "ExtendedPrivateKey")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 25;
+
+    #[test]
+    fn test_derive_child() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let master = ExtendedPrivateKey::<CurrentNetwork>::from_seed(&Field::rand(rng))?;
+
+            // Check that the derivation is deterministic.
+            let index = rng.gen();
+            assert_eq!(master.derive_child(index)?, master.derive_child(index)?);
+            assert_eq!(master.derive_child(index)?.to_private_key()?, master.derive_child(index)?.to_private_key()?);
+
+            // Check that distinct indices, and distinct parents, derive distinct keys.
+            assert_ne!(master.derive_child(0)?, master.derive_child(1)?);
+            assert_ne!(master.derive_child(0)?.derive_child(0)?, master.derive_child(1)?.derive_child(0)?);
+            assert_ne!(master.to_private_key()?, master.derive_child(0)?.to_private_key()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_path() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let master = ExtendedPrivateKey::<CurrentNetwork>::from_seed(&Field::rand(rng))?;
+        assert_eq!(master, master.derive_path(&[])?);
+        assert_eq!(master.derive_child(44)?.derive_child(0)?.derive_child(7)?, master.derive_path(&[44, 0, 7])?);
+        assert_ne!(master.derive_path(&[44, 0, 7])?, master.derive_path(&[44, 7, 0])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_encryption_keys() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let child = ExtendedPrivateKey::<CurrentNetwork>::from_seed(&Field::rand(rng))?.derive_path(&[1, 2])?;
+        let private_key = child.to_private_key()?;
+        let view_key = child.to_view_key()?;
+        assert_eq!(view_key, ViewKey::try_from(&private_key)?);
+        assert_eq!(view_key.to_address(), Address::try_from(&private_key)?);
+        Ok(())
+    }
+}
@@ -62,8 +62,8 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new graph key.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-            let view_key = ViewKey::try_from(private_key)?;
-            let expected = GraphKey::try_from(view_key)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let expected = GraphKey::try_from(&view_key)?;
 
             // Check the string representation.
             let candidate = format!("{expected}");
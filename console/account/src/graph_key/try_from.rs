@@ -73,8 +73,8 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new graph key.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-            let view_key = ViewKey::try_from(private_key)?;
-            let candidate = GraphKey::try_from(view_key)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let candidate = GraphKey::try_from(&view_key)?;
 
             // Check that graph key is derived correctly from `sk_tag`.
             assert_eq!(candidate, GraphKey::try_from(candidate.sk_tag())?);
@@ -0,0 +1,433 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod session;
+mod symmetric;
+
+pub use session::Session;
+
+use symmetric::{CipherState, SymmetricState, HASH_LENGTH, TAG_LENGTH};
+
+use crate::{Secret, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Group, Scalar};
+
+use zeroize::Zeroize;
+
+/// The length of an encoded public key, in bytes.
+const PUBLIC_KEY_LENGTH: usize = 32;
+
+/// The Noise handshake patterns supported for authenticating a session.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HandshakePattern {
+    /// Both parties transmit their static keys during the handshake, in three messages.
+    XX,
+    /// The initiator already knows the responder's static key, and the handshake completes in two messages.
+    IK,
+}
+
+/// A single step of a handshake message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Token {
+    /// The sender's ephemeral public key.
+    E,
+    /// The sender's (encrypted) static public key.
+    S,
+    /// A Diffie-Hellman between the two ephemeral keys.
+    EE,
+    /// A Diffie-Hellman between the initiator's ephemeral key and the responder's static key.
+    ES,
+    /// A Diffie-Hellman between the initiator's static key and the responder's ephemeral key.
+    SE,
+    /// A Diffie-Hellman between the two static keys.
+    SS,
+}
+
+impl HandshakePattern {
+    /// Returns the protocol name, which is bound into the handshake hash.
+    const fn protocol_name(&self) -> &'static [u8] {
+        match self {
+            Self::XX => b"Noise_XX_EdwardsBLS12_ChaChaPoly_BLAKE2s",
+            Self::IK => b"Noise_IK_EdwardsBLS12_ChaChaPoly_BLAKE2s",
+        }
+    }
+
+    /// Returns the tokens of each message, alternating between the initiator and the responder.
+    const fn messages(&self) -> &'static [&'static [Token]] {
+        match self {
+            Self::XX => &[&[Token::E], &[Token::E, Token::EE, Token::S, Token::ES], &[Token::S, Token::SE]],
+            Self::IK => &[&[Token::E, Token::ES, Token::S, Token::SS], &[Token::E, Token::EE, Token::SE]],
+        }
+    }
+}
+
+/// A Noise handshake between two accounts, using the account view key as the static private key
+/// and the account address as the static public key.
+///
+/// The parties exchange the messages returned by `write_message` and passed to `read_message` in turn,
+/// starting with the initiator. Once `is_finished` returns `true`, `into_session` returns the session
+/// used to encrypt the subsequent traffic.
+///
+/// The cipher and hash are ChaCha20-Poly1305 and BLAKE2s, as in the standard Noise cipher suites.
+/// These reuse `chacha20poly1305`, which also encrypts the keystore, and `blake2s_simd`,
+/// which is the BLAKE2s implementation of `snarkvm-console-algorithms`.
+pub struct Handshake<N: Network> {
+    /// The handshake pattern.
+    pattern: HandshakePattern,
+    /// The flag indicating whether this party sends the first message.
+    is_initiator: bool,
+    /// The chaining key, handshake hash, and handshake cipher.
+    symmetric: SymmetricState,
+    /// The static private key.
+    static_key: ViewKey<N>,
+    /// The ephemeral private key, once sampled.
+    ephemeral_key: Option<Secret<Scalar<N>>>,
+    /// The remote static public key, once known.
+    remote_static: Option<Address<N>>,
+    /// The remote ephemeral public key, once received.
+    remote_ephemeral: Option<Group<N>>,
+    /// The index of the next message in the pattern.
+    message_index: usize,
+}
+
+impl<N: Network> Handshake<N> {
+    /// Initializes the handshake for the party sending the first message.
+    /// The remote static key is required for `IK`, and must be omitted for `XX`.
+    pub fn initiator(
+        pattern: HandshakePattern,
+        static_key: ViewKey<N>,
+        remote_static: Option<Address<N>>,
+        prologue: &[u8],
+    ) -> Result<Self> {
+        match (pattern, remote_static) {
+            (HandshakePattern::IK, None) => bail!("The IK handshake requires the responder's address"),
+            (HandshakePattern::XX, Some(_)) => bail!("The XX handshake receives the responder's address"),
+            _ => Self::new(pattern, true, static_key, remote_static, prologue),
+        }
+    }
+
+    /// Initializes the handshake for the party receiving the first message.
+    pub fn responder(pattern: HandshakePattern, static_key: ViewKey<N>, prologue: &[u8]) -> Result<Self> {
+        Self::new(pattern, false, static_key, None, prologue)
+    }
+
+    /// Initializes the handshake, mixing the prologue and any pre-message keys into the handshake hash.
+    fn new(
+        pattern: HandshakePattern,
+        is_initiator: bool,
+        static_key: ViewKey<N>,
+        remote_static: Option<Address<N>>,
+        prologue: &[u8],
+    ) -> Result<Self> {
+        let mut symmetric = SymmetricState::new(pattern.protocol_name());
+        symmetric.mix_hash(prologue);
+        // In the IK pattern, the responder's static key is known to both parties before the first message.
+        if pattern == HandshakePattern::IK {
+            let responder = match is_initiator {
+                true => remote_static.ok_or_else(|| anyhow!("Missing the responder's address"))?,
+                false => static_key.to_address(),
+            };
+            symmetric.mix_hash(&responder.to_bytes_le()?);
+        }
+        Ok(Self {
+            pattern,
+            is_initiator,
+            symmetric,
+            static_key,
+            ephemeral_key: None,
+            remote_static,
+            remote_ephemeral: None,
+            message_index: 0,
+        })
+    }
+
+    /// Returns `true` if every message of the handshake has been written or read.
+    pub fn is_finished(&self) -> bool {
+        self.message_index == self.pattern.messages().len()
+    }
+
+    /// Returns `true` if the next message is written by this party.
+    pub fn is_my_turn(&self) -> bool {
+        !self.is_finished() && (self.message_index % 2 == 0) == self.is_initiator
+    }
+
+    /// Returns the remote address, once it is known.
+    pub const fn remote_static(&self) -> Option<&Address<N>> {
+        self.remote_static.as_ref()
+    }
+
+    /// Returns the hash of the handshake transcript so far.
+    pub const fn handshake_hash(&self) -> &[u8; HASH_LENGTH] {
+        self.symmetric.handshake_hash()
+    }
+
+    /// Returns the next handshake message, carrying the given payload.
+    /// The payload is encrypted once a shared key has been established, and is sent in the clear otherwise.
+    pub fn write_message<R: Rng + CryptoRng>(&mut self, payload: &[u8], rng: &mut R) -> Result<Vec<u8>> {
+        ensure!(self.is_my_turn(), "Attempted to write a handshake message out of turn");
+
+        let mut message = Vec::new();
+        for token in self.pattern.messages()[self.message_index] {
+            match token {
+                Token::E => {
+                    let ephemeral_key = Secret::new(Scalar::rand(rng));
+                    let ephemeral_public = N::g_scalar_multiply(&ephemeral_key).to_bytes_le()?;
+                    self.symmetric.mix_hash(&ephemeral_public);
+                    message.extend_from_slice(&ephemeral_public);
+                    self.ephemeral_key = Some(ephemeral_key);
+                }
+                Token::S => {
+                    let static_public = self.static_key.to_address().to_bytes_le()?;
+                    message.extend(self.symmetric.encrypt_and_hash(&static_public)?);
+                }
+                _ => {
+                    let mut shared_secret = self.diffie_hellman(*token)?;
+                    self.symmetric.mix_key(&shared_secret);
+                    shared_secret.zeroize();
+                }
+            }
+        }
+        message.extend(self.symmetric.encrypt_and_hash(payload)?);
+
+        self.message_index += 1;
+        Ok(message)
+    }
+
+    /// Processes the next handshake message from the remote party, and returns its payload.
+    pub fn read_message(&mut self, message: &[u8]) -> Result<Vec<u8>> {
+        ensure!(!self.is_finished(), "The handshake is already finished");
+        ensure!(!self.is_my_turn(), "Attempted to read a handshake message out of turn");
+
+        let mut remaining = message;
+        for token in self.pattern.messages()[self.message_index] {
+            match token {
+                Token::E => {
+                    let (ephemeral_public, rest) = split_message(remaining, PUBLIC_KEY_LENGTH)?;
+                    let remote_ephemeral = Group::<N>::from_bytes_le(ephemeral_public)?;
+                    self.symmetric.mix_hash(ephemeral_public);
+                    self.remote_ephemeral = Some(remote_ephemeral);
+                    remaining = rest;
+                }
+                Token::S => {
+                    let length = match self.symmetric.has_key() {
+                        true => PUBLIC_KEY_LENGTH + TAG_LENGTH,
+                        false => PUBLIC_KEY_LENGTH,
+                    };
+                    let (static_public, rest) = split_message(remaining, length)?;
+                    let static_public = self.symmetric.decrypt_and_hash(static_public)?;
+                    self.remote_static = Some(Address::from_bytes_le(&static_public)?);
+                    remaining = rest;
+                }
+                _ => {
+                    let mut shared_secret = self.diffie_hellman(*token)?;
+                    self.symmetric.mix_key(&shared_secret);
+                    shared_secret.zeroize();
+                }
+            }
+        }
+        let payload = self.symmetric.decrypt_and_hash(remaining)?;
+
+        self.message_index += 1;
+        Ok(payload)
+    }
+
+    /// Returns the session for the completed handshake.
+    pub fn into_session(mut self) -> Result<Session<N>> {
+        ensure!(self.is_finished(), "The handshake is not finished");
+        let remote_static = self.remote_static.ok_or_else(|| anyhow!("Missing the remote address"))?;
+
+        let (initiator_to_responder, responder_to_initiator) = self.symmetric.split();
+        // Zeroize the ephemeral key, as it is not needed once the transport keys are derived.
+        if let Some(ephemeral_key) = &mut self.ephemeral_key {
+            ephemeral_key.zeroize();
+        }
+        self.ephemeral_key = None;
+        let (sender, receiver) = match self.is_initiator {
+            true => (initiator_to_responder, responder_to_initiator),
+            false => (responder_to_initiator, initiator_to_responder),
+        };
+        Ok(Session::new(sender, receiver, remote_static, *self.symmetric.handshake_hash()))
+    }
+
+    /// Returns the x-coordinate of the Diffie-Hellman shared secret for the given token.
+    fn diffie_hellman(&self, token: Token) -> Result<[u8; PUBLIC_KEY_LENGTH]> {
+        let ephemeral_key =
+            || self.ephemeral_key.as_deref().copied().ok_or_else(|| anyhow!("Missing the ephemeral key"));
+        let remote_ephemeral = || self.remote_ephemeral.ok_or_else(|| anyhow!("Missing the remote ephemeral key"));
+        let remote_static = || {
+            self.remote_static.map(|address| *address.to_group()).ok_or_else(|| anyhow!("Missing the remote address"))
+        };
+
+        let (private_key, public_key) = match (token, self.is_initiator) {
+            (Token::EE, _) => (ephemeral_key()?, remote_ephemeral()?),
+            (Token::ES, true) | (Token::SE, false) => (ephemeral_key()?, remote_static()?),
+            (Token::ES, false) | (Token::SE, true) => (*self.static_key, remote_ephemeral()?),
+            (Token::SS, _) => (*self.static_key, remote_static()?),
+            (Token::E, _) | (Token::S, _) => bail!("'{token:?}' is not a Diffie-Hellman token"),
+        };
+
+        let shared_point = public_key * private_key;
+        ensure!(!shared_point.is_zero(), "The Diffie-Hellman shared secret is the identity");
+
+        let mut shared_secret = [0u8; PUBLIC_KEY_LENGTH];
+        shared_secret.copy_from_slice(&shared_point.to_x_coordinate().to_bytes_le()?);
+        Ok(shared_secret)
+    }
+}
+
+/// Splits the first `length` bytes from the message.
+fn split_message(message: &[u8], length: usize) -> Result<(&[u8], &[u8])> {
+    ensure!(message.len() >= length, "The handshake message is truncated");
+    Ok(message.split_at(length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    fn sample_view_key(rng: &mut TestRng) -> Result<ViewKey<CurrentNetwork>> {
+        ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)
+    }
+
+    /// Runs the handshake to completion, checking that every payload is received.
+    fn run_handshake(
+        initiator: &mut Handshake<CurrentNetwork>,
+        responder: &mut Handshake<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        let mut index = 0u8;
+        while !initiator.is_finished() {
+            let (sender, receiver) = match initiator.is_my_turn() {
+                true => (&mut *initiator, &mut *responder),
+                false => (&mut *responder, &mut *initiator),
+            };
+            let payload = vec![index; index as usize];
+            let message = sender.write_message(&payload, rng)?;
+            assert_eq!(receiver.read_message(&message)?, payload);
+            index += 1;
+        }
+        assert!(responder.is_finished());
+        Ok(())
+    }
+
+    /// Checks that the sessions exchange messages in both directions.
+    fn check_session(mut initiator: Session<CurrentNetwork>, mut responder: Session<CurrentNetwork>) -> Result<()> {
+        assert_eq!(initiator.handshake_hash(), responder.handshake_hash());
+
+        let ciphertext = initiator.encrypt(b"ping")?;
+        assert_eq!(responder.decrypt(&ciphertext)?, b"ping");
+        let ciphertext = responder.encrypt(b"pong")?;
+        assert_eq!(initiator.decrypt(&ciphertext)?, b"pong");
+        // Check that a replayed message is rejected.
+        assert!(initiator.decrypt(&ciphertext).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_xx() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (alice, bob) = (sample_view_key(rng)?, sample_view_key(rng)?);
+
+            let mut initiator = Handshake::initiator(HandshakePattern::XX, alice.clone(), None, b"prologue")?;
+            let mut responder = Handshake::responder(HandshakePattern::XX, bob.clone(), b"prologue")?;
+            run_handshake(&mut initiator, &mut responder, rng)?;
+
+            assert_eq!(initiator.remote_static(), Some(&bob.to_address()));
+            assert_eq!(responder.remote_static(), Some(&alice.to_address()));
+            check_session(initiator.into_session()?, responder.into_session()?)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ik() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (alice, bob) = (sample_view_key(rng)?, sample_view_key(rng)?);
+
+            let mut initiator = Handshake::initiator(HandshakePattern::IK, alice.clone(), Some(bob.to_address()), b"")?;
+            let mut responder = Handshake::responder(HandshakePattern::IK, bob, b"")?;
+            run_handshake(&mut initiator, &mut responder, rng)?;
+
+            assert_eq!(responder.remote_static(), Some(&alice.to_address()));
+            check_session(initiator.into_session()?, responder.into_session()?)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ik_wrong_responder() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (alice, bob, eve) = (sample_view_key(rng)?, sample_view_key(rng)?, sample_view_key(rng)?);
+        let mut initiator = Handshake::initiator(HandshakePattern::IK, alice, Some(bob.to_address()), b"")?;
+        let mut responder = Handshake::responder(HandshakePattern::IK, eve, b"")?;
+
+        let message = initiator.write_message(b"", rng)?;
+        assert!(responder.read_message(&message).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mismatched_prologue() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (alice, bob) = (sample_view_key(rng)?, sample_view_key(rng)?);
+        let mut initiator = Handshake::initiator(HandshakePattern::XX, alice, None, b"testnet3")?;
+        let mut responder = Handshake::responder(HandshakePattern::XX, bob, b"mainnet")?;
+        assert!(run_handshake(&mut initiator, &mut responder, rng).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tampered_message() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (alice, bob) = (sample_view_key(rng)?, sample_view_key(rng)?);
+        let mut initiator = Handshake::initiator(HandshakePattern::XX, alice, None, b"")?;
+        let mut responder = Handshake::responder(HandshakePattern::XX, bob, b"")?;
+
+        let message = initiator.write_message(b"", rng)?;
+        responder.read_message(&message)?;
+        let mut message = responder.write_message(b"", rng)?;
+        let last = message.len() - 1;
+        message[last] ^= 1;
+        assert!(initiator.read_message(&message).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_out_of_turn() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (alice, bob) = (sample_view_key(rng)?, sample_view_key(rng)?);
+        let mut initiator = Handshake::initiator(HandshakePattern::XX, alice, None, b"")?;
+        let mut responder = Handshake::responder(HandshakePattern::XX, bob, b"")?;
+
+        assert!(responder.write_message(b"", rng).is_err());
+        assert!(initiator.read_message(&[0u8; PUBLIC_KEY_LENGTH]).is_err());
+        assert!(Handshake::initiator(HandshakePattern::IK, alice, None, b"").is_err());
+        assert!(initiator.into_session().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An established session, which encrypts and authenticates the traffic after a completed handshake.
+pub struct Session<N: Network> {
+    /// The cipher for outgoing messages.
+    sender: CipherState,
+    /// The cipher for incoming messages.
+    receiver: CipherState,
+    /// The address of the remote party.
+    remote_static: Address<N>,
+    /// The hash of the handshake transcript, which uniquely identifies the session.
+    handshake_hash: [u8; HASH_LENGTH],
+}
+
+impl<N: Network> Session<N> {
+    /// Initializes a new session.
+    pub(super) const fn new(
+        sender: CipherState,
+        receiver: CipherState,
+        remote_static: Address<N>,
+        handshake_hash: [u8; HASH_LENGTH],
+    ) -> Self {
+        Self { sender, receiver, remote_static, handshake_hash }
+    }
+
+    /// Returns the authenticated address of the remote party.
+    pub const fn remote_static(&self) -> &Address<N> {
+        &self.remote_static
+    }
+
+    /// Returns the hash of the handshake transcript, which may be used for channel binding.
+    pub const fn handshake_hash(&self) -> &[u8; HASH_LENGTH] {
+        &self.handshake_hash
+    }
+
+    /// Encrypts the next outgoing message.
+    pub fn encrypt(&mut self, plaintext: &[u8]) -> Result<Vec<u8>> {
+        self.sender.encrypt(&[], plaintext)
+    }
+
+    /// Decrypts the next incoming message. Messages must be decrypted in the order they were encrypted.
+    pub fn decrypt(&mut self, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        self.receiver.decrypt(&[], ciphertext)
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305,
+    Key,
+    Nonce,
+};
+
+/// The output length of the handshake hash, in bytes.
+pub(super) const HASH_LENGTH: usize = 32;
+/// The block length of the handshake hash, in bytes.
+const BLOCK_LENGTH: usize = 64;
+/// The length of the authentication tag appended to every ciphertext, in bytes.
+pub(super) const TAG_LENGTH: usize = 16;
+
+/// Returns the BLAKE2s digest of the given inputs.
+fn hash(inputs: &[&[u8]]) -> [u8; HASH_LENGTH] {
+    let mut state = blake2s_simd::Params::new().hash_length(HASH_LENGTH).to_state();
+    inputs.iter().for_each(|input| {
+        state.update(input);
+    });
+    let mut output = [0u8; HASH_LENGTH];
+    output.copy_from_slice(state.finalize().as_bytes());
+    output
+}
+
+/// Returns HMAC-BLAKE2s of the given inputs under the given key.
+fn hmac(key: &[u8; HASH_LENGTH], inputs: &[&[u8]]) -> [u8; HASH_LENGTH] {
+    let mut ipad = [0x36u8; BLOCK_LENGTH];
+    let mut opad = [0x5cu8; BLOCK_LENGTH];
+    for (i, byte) in key.iter().enumerate() {
+        ipad[i] ^= byte;
+        opad[i] ^= byte;
+    }
+    let mut inner_inputs: Vec<&[u8]> = vec![&ipad[..]];
+    inner_inputs.extend_from_slice(inputs);
+    let inner = hash(&inner_inputs);
+    let output = hash(&[&opad[..], &inner[..]]);
+    ipad.zeroize();
+    opad.zeroize();
+    output
+}
+
+/// Returns the two output keys of the Noise HKDF over the given chaining key and input key material.
+pub(super) fn hkdf(chaining_key: &[u8; HASH_LENGTH], input: &[u8]) -> ([u8; HASH_LENGTH], [u8; HASH_LENGTH]) {
+    let mut temp_key = hmac(chaining_key, &[input]);
+    let output1 = hmac(&temp_key, &[&[0x01u8][..]]);
+    let output2 = hmac(&temp_key, &[&output1[..], &[0x02u8][..]]);
+    temp_key.zeroize();
+    (output1, output2)
+}
+
+/// A ChaCha20-Poly1305 key with its message counter.
+#[derive(Clone)]
+pub(super) struct CipherState {
+    /// The cipher key, if one has been established.
+    key: Option<[u8; HASH_LENGTH]>,
+    /// The counter used as the nonce for the next message.
+    nonce: u64,
+}
+
+impl CipherState {
+    /// Initializes a cipher state without a key.
+    pub(super) const fn empty() -> Self {
+        Self { key: None, nonce: 0 }
+    }
+
+    /// Initializes a cipher state with the given key.
+    pub(super) const fn new(key: [u8; HASH_LENGTH]) -> Self {
+        Self { key: Some(key), nonce: 0 }
+    }
+
+    /// Returns `true` if a key has been established.
+    pub(super) const fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    /// Encrypts the plaintext with the associated data, passing it through unchanged if no key is set.
+    pub(super) fn encrypt(&mut self, associated_data: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+        let Some(key) = &self.key else { return Ok(plaintext.to_vec()) };
+        let nonce = Self::encode_nonce(self.nonce)?;
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(key))
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg: plaintext, aad: associated_data })
+            .map_err(|_| anyhow!("Failed to encrypt the handshake message"))?;
+        self.nonce += 1;
+        Ok(ciphertext)
+    }
+
+    /// Decrypts the ciphertext with the associated data, passing it through unchanged if no key is set.
+    ///
+    /// The nonce only advances once the ciphertext is authenticated, so a forged message
+    /// does not desynchronize the cipher from the sender.
+    pub(super) fn decrypt(&mut self, associated_data: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let Some(key) = &self.key else { return Ok(ciphertext.to_vec()) };
+        let nonce = Self::encode_nonce(self.nonce)?;
+        let plaintext = ChaCha20Poly1305::new(Key::from_slice(key))
+            .decrypt(Nonce::from_slice(&nonce), Payload { msg: ciphertext, aad: associated_data })
+            .map_err(|_| anyhow!("Failed to authenticate the handshake message"))?;
+        self.nonce += 1;
+        Ok(plaintext)
+    }
+
+    /// Returns the encoded nonce for the given counter.
+    fn encode_nonce(counter: u64) -> Result<[u8; 12]> {
+        // The maximum counter value is reserved, as in the Noise specification.
+        ensure!(counter < u64::MAX, "The cipher nonce has been exhausted");
+        let mut nonce = [0u8; 12];
+        nonce[4..].copy_from_slice(&counter.to_le_bytes());
+        Ok(nonce)
+    }
+}
+
+impl Drop for CipherState {
+    fn drop(&mut self) {
+        if let Some(key) = &mut self.key {
+            key.zeroize();
+        }
+    }
+}
+
+/// The chaining key, handshake hash, and cipher state of a handshake in progress.
+pub(super) struct SymmetricState {
+    /// The chaining key, from which all cipher keys are derived.
+    chaining_key: [u8; HASH_LENGTH],
+    /// The hash of every value sent or received so far.
+    handshake_hash: [u8; HASH_LENGTH],
+    /// The cipher used to encrypt static keys and payloads during the handshake.
+    cipher: CipherState,
+}
+
+impl SymmetricState {
+    /// Initializes the symmetric state from the protocol name.
+    pub(super) fn new(protocol_name: &[u8]) -> Self {
+        let handshake_hash = match protocol_name.len() <= HASH_LENGTH {
+            true => {
+                let mut padded = [0u8; HASH_LENGTH];
+                padded[..protocol_name.len()].copy_from_slice(protocol_name);
+                padded
+            }
+            false => hash(&[protocol_name]),
+        };
+        Self { chaining_key: handshake_hash, handshake_hash, cipher: CipherState::empty() }
+    }
+
+    /// Returns the current handshake hash.
+    pub(super) const fn handshake_hash(&self) -> &[u8; HASH_LENGTH] {
+        &self.handshake_hash
+    }
+
+    /// Returns `true` if a cipher key has been established.
+    pub(super) const fn has_key(&self) -> bool {
+        self.cipher.has_key()
+    }
+
+    /// Mixes the given input key material into the chaining key, and rekeys the cipher.
+    pub(super) fn mix_key(&mut self, input: &[u8]) {
+        let (chaining_key, key) = hkdf(&self.chaining_key, input);
+        self.chaining_key = chaining_key;
+        self.cipher = CipherState::new(key);
+    }
+
+    /// Mixes the given data into the handshake hash.
+    pub(super) fn mix_hash(&mut self, data: &[u8]) {
+        self.handshake_hash = hash(&[&self.handshake_hash[..], data]);
+    }
+
+    /// Encrypts the plaintext bound to the handshake hash, and mixes the ciphertext into the hash.
+    pub(super) fn encrypt_and_hash(&mut self, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let ciphertext = self.cipher.encrypt(&self.handshake_hash, plaintext)?;
+        self.mix_hash(&ciphertext);
+        Ok(ciphertext)
+    }
+
+    /// Decrypts the ciphertext bound to the handshake hash, and mixes the ciphertext into the hash.
+    pub(super) fn decrypt_and_hash(&mut self, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let plaintext = self.cipher.decrypt(&self.handshake_hash, ciphertext)?;
+        self.mix_hash(ciphertext);
+        Ok(plaintext)
+    }
+
+    /// Returns the pair of transport ciphers, for the initiator-to-responder and responder-to-initiator directions.
+    pub(super) fn split(&self) -> (CipherState, CipherState) {
+        let (key1, key2) = hkdf(&self.chaining_key, &[]);
+        (CipherState::new(key1), CipherState::new(key2))
+    }
+}
+
+impl Drop for SymmetricState {
+    fn drop(&mut self) {
+        self.chaining_key.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cipher_state_roundtrip() -> Result<()> {
+        let mut sender = CipherState::new([7u8; HASH_LENGTH]);
+        let mut receiver = CipherState::new([7u8; HASH_LENGTH]);
+
+        for message in [&b""[..], &b"hello"[..], &[0u8; 1000][..]] {
+            let ciphertext = sender.encrypt(b"ad", message)?;
+            assert_eq!(ciphertext.len(), message.len() + TAG_LENGTH);
+            assert_eq!(receiver.decrypt(b"ad", &ciphertext)?, message);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_cipher_state_rejects_tampering() -> Result<()> {
+        let mut sender = CipherState::new([7u8; HASH_LENGTH]);
+        let ciphertext = sender.encrypt(b"ad", b"hello")?;
+
+        // Check that a modified ciphertext is rejected.
+        let mut tampered = ciphertext.clone();
+        tampered[0] ^= 1;
+        assert!(CipherState::new([7u8; HASH_LENGTH]).decrypt(b"ad", &tampered).is_err());
+        // Check that different associated data is rejected.
+        assert!(CipherState::new([7u8; HASH_LENGTH]).decrypt(b"da", &ciphertext).is_err());
+        // Check that an out-of-order nonce is rejected.
+        let mut receiver = CipherState::new([7u8; HASH_LENGTH]);
+        receiver.nonce = 1;
+        assert!(receiver.decrypt(b"ad", &ciphertext).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cipher_state_forgery_keeps_nonce() -> Result<()> {
+        let mut sender = CipherState::new([7u8; HASH_LENGTH]);
+        let mut receiver = CipherState::new([7u8; HASH_LENGTH]);
+        let ciphertext = sender.encrypt(b"ad", b"hello")?;
+
+        // Check that a forged message does not advance the nonce, so the genuine message is still accepted.
+        let mut forged = ciphertext.clone();
+        forged[0] ^= 1;
+        assert!(receiver.decrypt(b"ad", &forged).is_err());
+        assert_eq!(receiver.nonce, 0);
+        assert_eq!(receiver.decrypt(b"ad", &ciphertext)?, b"hello");
+        assert_eq!(receiver.nonce, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hkdf_is_deterministic() {
+        let (a1, a2) = hkdf(&[1u8; HASH_LENGTH], b"input");
+        let (b1, b2) = hkdf(&[1u8; HASH_LENGTH], b"input");
+        assert_eq!((a1, a2), (b1, b2));
+        assert_ne!(a1, a2);
+        assert_ne!(hkdf(&[1u8; HASH_LENGTH], b"other").0, a1);
+    }
+}
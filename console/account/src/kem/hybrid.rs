@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The domain separator of the hybrid shared secret.
+const HYBRID_KEM_DOMAIN: DomainSeparator = DomainSeparator::new("HybridKem", 0);
+
+/// A hybrid key encapsulation mechanism, which combines `ECIESPoseidonKem` with a post-quantum KEM.
+///
+/// The shared secret is the Poseidon hash of both shared secrets and of the ECIES nonce, so it remains secret
+/// as long as either KEM is secure. This protects record payloads that are harvested now from being decrypted
+/// once a quantum computer breaks the discrete logarithm, at the cost of the larger post-quantum encapsulation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct HybridKem<N: Network, P: KemScheme>(PhantomData<(N, P)>);
+
+impl<N: Network, P: KemScheme> KemScheme for HybridKem<N, P>
+where
+    P::SharedSecret: ToBits,
+{
+    type Encapsulation = (Group<N>, P::Encapsulation);
+    type PublicKey = (Address<N>, P::PublicKey);
+    type SecretKey = (ViewKey<N>, P::SecretKey);
+    type SharedSecret = Field<N>;
+
+    /// Returns a fresh encapsulation to both public keys, along with the hybrid shared secret.
+    fn encapsulate<R: Rng + CryptoRng>(
+        (address, public_key): &Self::PublicKey,
+        rng: &mut R,
+    ) -> Result<(Self::Encapsulation, Self::SharedSecret)> {
+        let (nonce, classical_secret) = ECIESPoseidonKem::encapsulate(address, rng)?;
+        let (encapsulation, post_quantum_secret) = P::encapsulate(public_key, rng)?;
+        let shared_secret = Self::combine(&nonce, &Secret::new(classical_secret), &post_quantum_secret)?;
+        Ok(((nonce, encapsulation), shared_secret))
+    }
+
+    /// Returns the hybrid shared secret for the given encapsulation.
+    fn decapsulate(
+        (view_key, secret_key): &Self::SecretKey,
+        (nonce, encapsulation): &Self::Encapsulation,
+    ) -> Result<Self::SharedSecret> {
+        let classical_secret = Secret::new(ECIESPoseidonKem::decapsulate(view_key, nonce)?);
+        let post_quantum_secret = P::decapsulate(secret_key, encapsulation)?;
+        Self::combine(nonce, &classical_secret, &post_quantum_secret)
+    }
+}
+
+impl<N: Network, P: KemScheme> HybridKem<N, P>
+where
+    P::SharedSecret: ToBits,
+{
+    /// Returns the hybrid shared secret of the given shared secrets.
+    fn combine(
+        nonce: &Group<N>,
+        classical_secret: &Field<N>,
+        post_quantum_secret: &P::SharedSecret,
+    ) -> Result<Field<N>> {
+        let mut preimage = Secret::new(vec![
+            Field::from_domain_separator(HYBRID_KEM_DOMAIN),
+            *classical_secret,
+            nonce.to_x_coordinate(),
+        ]);
+        // Pack the post-quantum shared secret into field elements.
+        let bits = Secret::new(post_quantum_secret.to_bits_le());
+        for chunk in bits.chunks(Field::<N>::size_in_data_bits()) {
+            preimage.push(Field::from_bits_le(chunk)?);
+        }
+        N::hash_psd8(&preimage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    // The tests combine two instances of the classical KEM, as the post-quantum KEMs are optional.
+    type Kem = HybridKem<CurrentNetwork, ECIESPoseidonKem<CurrentNetwork>>;
+
+    const ITERATIONS: u64 = 25;
+
+    /// Returns the view key and address of a new account.
+    fn sample_account(rng: &mut TestRng) -> Result<(ViewKey<CurrentNetwork>, Address<CurrentNetwork>)> {
+        let private_key = PrivateKey::new(rng)?;
+        Ok((ViewKey::try_from(&private_key)?, Address::try_from(&private_key)?))
+    }
+
+    #[test]
+    fn test_encapsulate_and_decapsulate() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (view_key, address) = sample_account(rng)?;
+            let (other_view_key, other_address) = sample_account(rng)?;
+
+            let (encapsulation, expected) = Kem::encapsulate(&(address, other_address), rng)?;
+            assert_eq!(expected, Kem::decapsulate(&(view_key.clone(), other_view_key.clone()), &encapsulation)?);
+
+            // Check that the shared secret depends on both KEMs.
+            let (view_key_b, _) = sample_account(rng)?;
+            assert_ne!(expected, Kem::decapsulate(&(view_key_b.clone(), other_view_key), &encapsulation)?);
+            assert_ne!(expected, Kem::decapsulate(&(view_key.clone(), view_key_b), &encapsulation)?);
+            // Check that the hybrid shared secret differs from the classical shared secret.
+            assert_ne!(expected, ECIESPoseidonKem::decapsulate(&view_key, &encapsulation.0)?);
+        }
+        Ok(())
+    }
+}
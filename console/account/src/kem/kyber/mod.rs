@@ -0,0 +1,290 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod pke;
+mod poly;
+
+use super::*;
+
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// The rank of the module, which is the number of polynomials of a vector.
+const K: usize = 3;
+/// The parameter of the centered binomial distribution of the secret and the error of the key pair.
+const ETA1: usize = 2;
+/// The parameter of the centered binomial distribution of the error of the ciphertext.
+const ETA2: usize = 2;
+/// The number of bits of a compressed coefficient of the vector `u` of the ciphertext.
+const DU: u32 = 10;
+/// The number of bits of a compressed coefficient of the polynomial `v` of the ciphertext.
+const DV: u32 = 4;
+
+/// The number of bytes of a Kyber public key.
+pub const KYBER_PUBLIC_KEY_SIZE: usize = pke::PKE_PUBLIC_KEY_SIZE;
+/// The number of bytes of a Kyber secret key.
+pub const KYBER_SECRET_KEY_SIZE: usize = pke::VECTOR_BYTES + KYBER_PUBLIC_KEY_SIZE + 64;
+/// The number of bytes of a Kyber ciphertext.
+pub const KYBER_CIPHERTEXT_SIZE: usize = K * 32 * DU as usize + 32 * DV as usize;
+
+/// A Kyber public key, which is an ML-KEM-768 encapsulation key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KyberPublicKey(Vec<u8>);
+
+/// A Kyber secret key, which is an ML-KEM-768 decapsulation key. It is wiped from memory when dropped.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct KyberSecretKey(Vec<u8>);
+
+/// A Kyber ciphertext, which is an ML-KEM-768 ciphertext.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KyberCiphertext(Vec<u8>);
+
+impl KyberPublicKey {
+    /// Returns the public key of the given bytes, after checking that its coefficients are reduced.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        ensure!(bytes.len() == KYBER_PUBLIC_KEY_SIZE, "Invalid Kyber public key size ({} bytes)", bytes.len());
+        ensure!(pke::is_public_key_reduced(bytes), "Invalid Kyber public key");
+        Ok(Self(bytes.to_vec()))
+    }
+
+    /// Returns the bytes of the public key.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl KyberSecretKey {
+    /// Returns the secret key of the given bytes, after checking the hash of its public key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        ensure!(bytes.len() == KYBER_SECRET_KEY_SIZE, "Invalid Kyber secret key size ({} bytes)", bytes.len());
+        let secret_key = Self(bytes.to_vec());
+        ensure!(pke::hash_h(secret_key.public_key()) == secret_key.public_key_hash(), "Invalid Kyber secret key");
+        Ok(secret_key)
+    }
+
+    /// Returns the bytes of the secret key.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the K-PKE decryption key.
+    fn pke_secret_key(&self) -> &[u8] {
+        &self.0[..pke::VECTOR_BYTES]
+    }
+
+    /// Returns the encoded public key.
+    fn public_key(&self) -> &[u8] {
+        &self.0[pke::VECTOR_BYTES..pke::VECTOR_BYTES + KYBER_PUBLIC_KEY_SIZE]
+    }
+
+    /// Returns the hash of the encoded public key.
+    fn public_key_hash(&self) -> &[u8] {
+        &self.0[KYBER_SECRET_KEY_SIZE - 64..KYBER_SECRET_KEY_SIZE - 32]
+    }
+
+    /// Returns the implicit rejection value.
+    fn rejection_value(&self) -> &[u8] {
+        &self.0[KYBER_SECRET_KEY_SIZE - 32..]
+    }
+}
+
+impl KyberCiphertext {
+    /// Returns the ciphertext of the given bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        ensure!(bytes.len() == KYBER_CIPHERTEXT_SIZE, "Invalid Kyber ciphertext size ({} bytes)", bytes.len());
+        Ok(Self(bytes.to_vec()))
+    }
+
+    /// Returns the bytes of the ciphertext.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// The Kyber key encapsulation mechanism, a post-quantum KEM for use in a `HybridKem`.
+///
+/// This is ML-KEM-768, the standardized variant of Kyber in FIPS 203. The polynomial arithmetic on secret
+/// values has no secret-dependent branches or divisions (see KyberSlash), and decapsulation selects the
+/// implicit rejection value in constant time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KyberKem;
+
+impl KyberKem {
+    /// Samples a new key pair, and returns the `(secret key, public key)`.
+    pub fn generate_key_pair<R: Rng + CryptoRng>(rng: &mut R) -> Result<(KyberSecretKey, KyberPublicKey)> {
+        let mut seed = Secret::new([0u8; 64]);
+        rng.fill_bytes(&mut *seed);
+        let (d, z) = seed.split_at(32);
+        Ok(Self::generate_key_pair_from_seed(d.try_into()?, z.try_into()?))
+    }
+
+    /// Returns the `(secret key, public key)` derived from the seed `(d, z)`, as in `ML-KEM.KeyGen_internal`.
+    fn generate_key_pair_from_seed(d: &[u8; 32], z: &[u8; 32]) -> (KyberSecretKey, KyberPublicKey) {
+        let (public_key, mut pke_secret_key) = pke::generate(d);
+
+        let mut secret_key = Vec::with_capacity(KYBER_SECRET_KEY_SIZE);
+        secret_key.extend_from_slice(&pke_secret_key);
+        secret_key.extend_from_slice(&public_key);
+        secret_key.extend_from_slice(&pke::hash_h(&public_key));
+        secret_key.extend_from_slice(z);
+        pke_secret_key.zeroize();
+
+        (KyberSecretKey(secret_key), KyberPublicKey(public_key))
+    }
+
+    /// Returns the ciphertext and the shared secret for the given message, as in `ML-KEM.Encaps_internal`.
+    fn encapsulate_with(public_key: &KyberPublicKey, message: &[u8; 32]) -> (KyberCiphertext, [u8; 32]) {
+        let (shared_secret, randomness) = pke::hash_g(&[&message[..], &pke::hash_h(&public_key.0)[..]]);
+        let ciphertext = pke::encrypt(&public_key.0, message, &randomness);
+        (KyberCiphertext(ciphertext), shared_secret)
+    }
+}
+
+impl KemScheme for KyberKem {
+    type Encapsulation = KyberCiphertext;
+    type PublicKey = KyberPublicKey;
+    type SecretKey = KyberSecretKey;
+    type SharedSecret = [u8; 32];
+
+    /// Returns a fresh Kyber ciphertext to the given public key, along with the shared secret.
+    fn encapsulate<R: Rng + CryptoRng>(
+        public_key: &KyberPublicKey,
+        rng: &mut R,
+    ) -> Result<(KyberCiphertext, Self::SharedSecret)> {
+        let mut message = Secret::new([0u8; 32]);
+        rng.fill_bytes(&mut *message);
+        Ok(Self::encapsulate_with(public_key, &message))
+    }
+
+    /// Returns the shared secret for the given Kyber ciphertext.
+    ///
+    /// A ciphertext that is not the encryption of its decrypted message derives a pseudorandom shared secret
+    /// from the implicit rejection value, instead of an error, so a failure is indistinguishable to the sender.
+    fn decapsulate(secret_key: &KyberSecretKey, ciphertext: &KyberCiphertext) -> Result<Self::SharedSecret> {
+        let message = Secret::new(pke::decrypt(secret_key.pke_secret_key(), &ciphertext.0));
+        let (shared_secret, randomness) = pke::hash_g(&[&message[..], secret_key.public_key_hash()]);
+        let rejected_secret = pke::hash_j(&[secret_key.rejection_value(), &ciphertext.0]);
+
+        // Select the shared secret if the ciphertext is re-encrypted identically, in constant time.
+        let expected = pke::encrypt(secret_key.public_key(), &message, &randomness);
+        let is_valid = expected.ct_eq(&ciphertext.0);
+        Ok(core::array::from_fn(|i| u8::conditional_select(&rejected_secret[i], &shared_secret[i], is_valid)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Kem = HybridKem<CurrentNetwork, KyberKem>;
+
+    const ITERATIONS: u64 = 10;
+
+    /// The number of bytes of a vector in `resources/ml_kem_768.bin`.
+    const VECTOR_SIZE: usize = 64 + KYBER_PUBLIC_KEY_SIZE + 2 * (KYBER_CIPHERTEXT_SIZE + 32);
+
+    #[test]
+    fn test_ml_kem_768_vectors() -> Result<()> {
+        // The vectors are generated by `resources/generate.py`, with the ML-KEM-768 implementation of OpenSSL.
+        let vectors = include_bytes!("resources/ml_kem_768.bin");
+        assert_eq!(vectors.len() % VECTOR_SIZE, 0);
+
+        for vector in vectors.chunks_exact(VECTOR_SIZE) {
+            let (seed, vector) = vector.split_at(64);
+            let (public_key, vector) = vector.split_at(KYBER_PUBLIC_KEY_SIZE);
+            let (ciphertext, vector) = vector.split_at(KYBER_CIPHERTEXT_SIZE);
+            let (shared_secret, vector) = vector.split_at(32);
+            let (invalid_ciphertext, rejected_secret) = vector.split_at(KYBER_CIPHERTEXT_SIZE);
+
+            // Check the key pair derived from the seed.
+            let (secret_key, expected_public_key) =
+                KyberKem::generate_key_pair_from_seed(seed[..32].try_into()?, seed[32..].try_into()?);
+            assert_eq!(expected_public_key.as_bytes(), public_key);
+            let secret_key = KyberSecretKey::from_bytes(secret_key.as_bytes())?;
+
+            // Check the shared secret of a valid ciphertext.
+            let ciphertext = KyberCiphertext::from_bytes(ciphertext)?;
+            assert_eq!(&KyberKem::decapsulate(&secret_key, &ciphertext)?[..], shared_secret);
+
+            // Check the implicitly rejected shared secret of an invalid ciphertext.
+            let invalid_ciphertext = KyberCiphertext::from_bytes(invalid_ciphertext)?;
+            assert_eq!(&KyberKem::decapsulate(&secret_key, &invalid_ciphertext)?[..], rejected_secret);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_kyber_kem() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (secret_key, public_key) = KyberKem::generate_key_pair(rng)?;
+            let public_key = KyberPublicKey::from_bytes(public_key.as_bytes())?;
+
+            let (ciphertext, expected) = KyberKem::encapsulate(&public_key, rng)?;
+            assert_eq!(expected, KyberKem::decapsulate(&secret_key, &ciphertext)?);
+
+            // Check that a modified ciphertext derives a different shared secret.
+            let mut bytes = ciphertext.as_bytes().to_vec();
+            bytes[0] ^= 1;
+            assert_ne!(expected, KyberKem::decapsulate(&secret_key, &KyberCiphertext::from_bytes(&bytes)?)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_kyber_from_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let (secret_key, public_key) = KyberKem::generate_key_pair(rng)?;
+
+        // Check that a public key with an unreduced coefficient is rejected.
+        let mut bytes = public_key.as_bytes().to_vec();
+        bytes[0] = 0xFF;
+        bytes[1] |= 0x0F;
+        assert!(KyberPublicKey::from_bytes(&bytes).is_err());
+        assert!(KyberPublicKey::from_bytes(&public_key.as_bytes()[1..]).is_err());
+
+        // Check that a secret key with a mismatched public key hash is rejected.
+        let mut bytes = secret_key.as_bytes().to_vec();
+        bytes[pke::VECTOR_BYTES] ^= 1;
+        assert!(KyberSecretKey::from_bytes(&bytes).is_err());
+        assert!(KyberSecretKey::from_bytes(&secret_key.as_bytes()[1..]).is_err());
+
+        assert!(KyberCiphertext::from_bytes(&[0u8; KYBER_CIPHERTEXT_SIZE - 1]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hybrid_kyber() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+            let (secret_key, public_key) = KyberKem::generate_key_pair(rng)?;
+
+            let (encapsulation, expected) = Kem::encapsulate(&(address, public_key), rng)?;
+            assert_eq!(expected, Kem::decapsulate(&(view_key.clone(), secret_key), &encapsulation)?);
+
+            // Check that a different Kyber secret key derives a different shared secret.
+            let (other_secret_key, _) = KyberKem::generate_key_pair(rng)?;
+            assert_ne!(expected, Kem::decapsulate(&(view_key, other_secret_key), &encapsulation)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The K-PKE public-key encryption scheme of ML-KEM-768, and its hash functions (FIPS 203, Sections 4 and 5).
+
+use super::{
+    poly::{Poly, POLY_BYTES},
+    DU,
+    DV,
+    ETA1,
+    ETA2,
+    K,
+    KYBER_CIPHERTEXT_SIZE,
+};
+
+use tiny_keccak::{Hasher, Sha3, Shake, Xof};
+
+/// The number of bytes of an encoded vector of polynomials.
+pub(super) const VECTOR_BYTES: usize = K * POLY_BYTES;
+/// The number of bytes of the K-PKE encryption key.
+pub(super) const PKE_PUBLIC_KEY_SIZE: usize = VECTOR_BYTES + 32;
+
+/// A vector of `K` polynomials.
+type Vector = [Poly; K];
+
+/// Returns `SHA3-256(input)`.
+pub(super) fn hash_h(input: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    let mut hasher = Sha3::v256();
+    hasher.update(input);
+    hasher.finalize(&mut output);
+    output
+}
+
+/// Returns `SHA3-512(inputs)`, split into two halves.
+pub(super) fn hash_g(inputs: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    let mut output = [0u8; 64];
+    let mut hasher = Sha3::v512();
+    inputs.iter().for_each(|input| hasher.update(input));
+    hasher.finalize(&mut output);
+    let (mut first, mut second) = ([0u8; 32], [0u8; 32]);
+    first.copy_from_slice(&output[..32]);
+    second.copy_from_slice(&output[32..]);
+    (first, second)
+}
+
+/// Returns `SHAKE256(inputs)`, with 32 bytes of output.
+pub(super) fn hash_j(inputs: &[&[u8]]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    let mut hasher = Shake::v256();
+    inputs.iter().for_each(|input| hasher.update(input));
+    hasher.finalize(&mut output);
+    output
+}
+
+/// Returns the polynomial sampled from `CBD_eta(PRF_eta(seed, nonce))`, where `PRF_eta` is SHAKE256.
+fn sample_noise(seed: &[u8; 32], nonce: u8, eta: usize) -> Poly {
+    let mut bytes = vec![0u8; 64 * eta];
+    let mut hasher = Shake::v256();
+    hasher.update(seed);
+    hasher.update(&[nonce]);
+    hasher.finalize(&mut bytes);
+    Poly::sample_cbd(&bytes, eta)
+}
+
+/// Returns the vector of `K` polynomials sampled from `CBD_eta`, with the nonces starting at `nonce`.
+fn sample_noise_vector(seed: &[u8; 32], nonce: &mut u8, eta: usize) -> Vector {
+    core::array::from_fn(|_| {
+        let poly = sample_noise(seed, *nonce, eta);
+        *nonce += 1;
+        poly
+    })
+}
+
+/// Returns the matrix `A` in the NTT domain, expanded from the given seed with SHAKE128,
+/// where `A[i][j]` is sampled from the XOF of `rho || j || i`.
+#[allow(clippy::cast_possible_truncation)]
+fn expand_matrix(rho: &[u8; 32]) -> [Vector; K] {
+    core::array::from_fn(|i| {
+        core::array::from_fn(|j| {
+            let mut xof = Shake::v128();
+            xof.update(rho);
+            xof.update(&[j as u8, i as u8]);
+            Poly::sample_ntt(|| {
+                let mut bytes = [0u8; 3];
+                xof.squeeze(&mut bytes);
+                bytes
+            })
+        })
+    })
+}
+
+/// Returns the encoding of the vector, with 12 bits per coefficient.
+fn encode_vector(vector: &Vector) -> Vec<u8> {
+    vector.iter().flat_map(|poly| poly.encode(12)).collect()
+}
+
+/// Returns the vector of the given encoding, with 12 bits per coefficient.
+fn decode_vector(bytes: &[u8]) -> Vector {
+    core::array::from_fn(|i| Poly::decode(&bytes[i * POLY_BYTES..(i + 1) * POLY_BYTES], 12))
+}
+
+/// Returns the inner product of the two vectors, which are both in the NTT domain.
+fn inner_product(a: &Vector, b: &Vector) -> Poly {
+    a.iter().zip(b).fold(Poly::default(), |sum, (a, b)| sum.add(&a.multiply_ntt(b)))
+}
+
+/// Returns the K-PKE `(encryption key, decryption key)` derived from the given seed.
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn generate(d: &[u8; 32]) -> (Vec<u8>, Vec<u8>) {
+    let (rho, sigma) = hash_g(&[&d[..], &[K as u8]]);
+    let matrix = expand_matrix(&rho);
+
+    let mut nonce = 0;
+    let s = sample_noise_vector(&sigma, &mut nonce, ETA1).map(|poly| poly.ntt());
+    let e = sample_noise_vector(&sigma, &mut nonce, ETA1).map(|poly| poly.ntt());
+
+    // Compute `t = A * s + e`.
+    let t: Vector = core::array::from_fn(|i| inner_product(&matrix[i], &s).add(&e[i]));
+
+    let mut public_key = encode_vector(&t);
+    public_key.extend_from_slice(&rho);
+    (public_key, encode_vector(&s))
+}
+
+/// Returns `true` if the coefficients of the encoded vector of the encryption key are reduced modulo `q`.
+pub(super) fn is_public_key_reduced(public_key: &[u8]) -> bool {
+    let vector = &public_key[..VECTOR_BYTES];
+    encode_vector(&decode_vector(vector)) == vector
+}
+
+/// Returns the K-PKE encryption of the 32-byte message, with the given randomness.
+pub(super) fn encrypt(public_key: &[u8], message: &[u8; 32], randomness: &[u8; 32]) -> Vec<u8> {
+    let t = decode_vector(&public_key[..VECTOR_BYTES]);
+    let mut rho = [0u8; 32];
+    rho.copy_from_slice(&public_key[VECTOR_BYTES..PKE_PUBLIC_KEY_SIZE]);
+    let matrix = expand_matrix(&rho);
+
+    let mut nonce = 0;
+    let y = sample_noise_vector(randomness, &mut nonce, ETA1).map(|poly| poly.ntt());
+    let e1 = sample_noise_vector(randomness, &mut nonce, ETA2);
+    let e2 = sample_noise(randomness, nonce, ETA2);
+
+    // Compute `u = A^T * y + e1`, and `v = t^T * y + e2 + mu`.
+    let u: Vector = core::array::from_fn(|i| {
+        let column: Vector = core::array::from_fn(|j| matrix[j][i]);
+        inner_product(&column, &y).inverse_ntt().add(&e1[i])
+    });
+    let mu = Poly::decode(message, 1).decompress(1);
+    let v = inner_product(&t, &y).inverse_ntt().add(&e2).add(&mu);
+
+    let mut ciphertext = Vec::with_capacity(KYBER_CIPHERTEXT_SIZE);
+    u.iter().for_each(|poly| ciphertext.extend(poly.compress(DU).encode(DU)));
+    ciphertext.extend(v.compress(DV).encode(DV));
+    ciphertext
+}
+
+/// Returns the 32-byte message of the K-PKE ciphertext.
+pub(super) fn decrypt(secret_key: &[u8], ciphertext: &[u8]) -> [u8; 32] {
+    let poly_size = 32 * DU as usize;
+    let u: Vector = core::array::from_fn(|i| {
+        Poly::decode(&ciphertext[i * poly_size..(i + 1) * poly_size], DU).decompress(DU).ntt()
+    });
+    let v = Poly::decode(&ciphertext[K * poly_size..], DV).decompress(DV);
+    let s = decode_vector(secret_key);
+
+    // Compute `w = v - s^T * u`.
+    let w = v.sub(&inner_product(&s, &u).inverse_ntt());
+
+    let mut message = [0u8; 32];
+    message.copy_from_slice(&w.compress(1).encode(1));
+    message
+}
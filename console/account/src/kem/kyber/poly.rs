@@ -0,0 +1,244 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The polynomial arithmetic of ML-KEM, over `Z_q[X] / (X^256 + 1)` with `q = 3329`.
+//!
+//! Every operation on secret coefficients is free of secret-dependent branches and divisions,
+//! as a division by `q` may compile to a variable-time instruction (see KyberSlash).
+
+use subtle::{Choice, ConditionallySelectable};
+
+/// The number of coefficients of a polynomial.
+pub(super) const N: usize = 256;
+/// The modulus of the coefficients.
+pub(super) const Q: u16 = 3329;
+/// The number of bytes of a polynomial, encoded with 12 bits per coefficient.
+pub(super) const POLY_BYTES: usize = 384;
+
+/// The Barrett constant `floor(2^32 / q)`, for the reduction of a 32-bit integer.
+const BARRETT_MULTIPLIER: u64 = (1 << 32) / Q as u64;
+/// The constant `ceil(2^33 / q)`, for which `(n * DIVISION_MULTIPLIER) >> 33 = floor(n / q)` for any `n < 2^23`.
+const DIVISION_MULTIPLIER: u64 = 2580335;
+/// The inverse of `128` modulo `q`, which scales the output of the inverse NTT.
+const NTT_SCALE: u16 = 3303;
+
+/// The powers `17^BitRev7(i) mod q` of the primitive 256-th root of unity `17`, for `i` in `0..128`.
+const ZETAS: [u16; 128] = [
+    1, 1729, 2580, 3289, 2642, 630, 1897, 848, 1062, 1919, 193, 797, 2786, 3260, 569, 1746, 296, 2447, 1339, 1476,
+    3046, 56, 2240, 1333, 1426, 2094, 535, 2882, 2393, 2879, 1974, 821, 289, 331, 3253, 1756, 1197, 2304, 2277, 2055,
+    650, 1977, 2513, 632, 2865, 33, 1320, 1915, 2319, 1435, 807, 452, 1438, 2868, 1534, 2402, 2647, 2617, 1481, 648,
+    2474, 3110, 1227, 910, 17, 2761, 583, 2649, 1637, 723, 2288, 1100, 1409, 2662, 3281, 233, 756, 2156, 3015, 3050,
+    1703, 1651, 2789, 1789, 1847, 952, 1461, 2687, 939, 2308, 2437, 2388, 733, 2337, 268, 641, 1584, 2298, 2037, 3220,
+    375, 2549, 2090, 1645, 1063, 319, 2773, 757, 2099, 561, 2466, 2594, 2804, 1092, 403, 1026, 1143, 2150, 2775, 886,
+    1722, 1212, 1874, 1029, 2110, 2935, 885, 2154,
+];
+
+/// The powers `17^(2 * BitRev7(i) + 1) mod q`, which are the roots of the quadratic factors of `X^256 + 1`.
+const GAMMAS: [u16; 128] = [
+    17, 3312, 2761, 568, 583, 2746, 2649, 680, 1637, 1692, 723, 2606, 2288, 1041, 1100, 2229, 1409, 1920, 2662, 667,
+    3281, 48, 233, 3096, 756, 2573, 2156, 1173, 3015, 314, 3050, 279, 1703, 1626, 1651, 1678, 2789, 540, 1789, 1540,
+    1847, 1482, 952, 2377, 1461, 1868, 2687, 642, 939, 2390, 2308, 1021, 2437, 892, 2388, 941, 733, 2596, 2337, 992,
+    268, 3061, 641, 2688, 1584, 1745, 2298, 1031, 2037, 1292, 3220, 109, 375, 2954, 2549, 780, 2090, 1239, 1645, 1684,
+    1063, 2266, 319, 3010, 2773, 556, 757, 2572, 2099, 1230, 561, 2768, 2466, 863, 2594, 735, 2804, 525, 1092, 2237,
+    403, 2926, 1026, 2303, 1143, 2186, 2150, 1179, 2775, 554, 886, 2443, 1722, 1607, 1212, 2117, 1874, 1455, 1029,
+    2300, 2110, 1219, 2935, 394, 885, 2444, 2154, 1175,
+];
+
+/// Returns `a - q` if `a >= q`, and `a` otherwise, for any `a < 2q`.
+fn reduce_once(a: u16) -> u16 {
+    let (difference, borrow) = a.overflowing_sub(Q);
+    u16::conditional_select(&difference, &a, Choice::from(borrow as u8))
+}
+
+/// Returns `a mod q`, for any 32-bit `a`.
+#[allow(clippy::cast_possible_truncation)]
+fn barrett_reduce(a: u32) -> u16 {
+    let quotient = ((a as u64 * BARRETT_MULTIPLIER) >> 32) as u32;
+    // The remainder is less than `2q`, as the quotient is at most one less than `floor(a / q)`.
+    reduce_once((a - quotient * Q as u32) as u16)
+}
+
+/// Returns `a * b mod q`.
+fn multiply(a: u16, b: u16) -> u16 {
+    barrett_reduce(a as u32 * b as u32)
+}
+
+/// Returns `round(2^d / q * x) mod 2^d`, for `x < q` and `d <= 11`.
+#[allow(clippy::cast_possible_truncation)]
+fn compress(x: u16, d: u32) -> u16 {
+    // Note: `floor(n / q)` is computed with a multiplication, as `n` is less than `2^23`.
+    let numerator = ((x as u64) << d) + (Q as u64 / 2);
+    (((numerator * DIVISION_MULTIPLIER) >> 33) as u16) & ((1 << d) - 1)
+}
+
+/// Returns `round(q / 2^d * y)`, for `y < 2^d`.
+#[allow(clippy::cast_possible_truncation)]
+fn decompress(y: u16, d: u32) -> u16 {
+    ((y as u32 * Q as u32 + (1 << (d - 1))) >> d) as u16
+}
+
+/// A polynomial, whose coefficients are reduced modulo `q`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) struct Poly(pub(super) [u16; N]);
+
+impl Default for Poly {
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+impl Poly {
+    /// Returns the sum of the two polynomials.
+    pub(super) fn add(&self, other: &Self) -> Self {
+        Self(core::array::from_fn(|i| reduce_once(self.0[i] + other.0[i])))
+    }
+
+    /// Returns the difference of the two polynomials.
+    pub(super) fn sub(&self, other: &Self) -> Self {
+        Self(core::array::from_fn(|i| reduce_once(self.0[i] + Q - other.0[i])))
+    }
+
+    /// Returns the number-theoretic transform of the polynomial.
+    pub(super) fn ntt(&self) -> Self {
+        let mut f = self.0;
+        let mut k = 1;
+        let mut len = 128;
+        while len >= 2 {
+            for start in (0..N).step_by(2 * len) {
+                let zeta = ZETAS[k];
+                k += 1;
+                for j in start..start + len {
+                    let t = multiply(zeta, f[j + len]);
+                    f[j + len] = reduce_once(f[j] + Q - t);
+                    f[j] = reduce_once(f[j] + t);
+                }
+            }
+            len /= 2;
+        }
+        Self(f)
+    }
+
+    /// Returns the polynomial of the given number-theoretic transform.
+    pub(super) fn inverse_ntt(&self) -> Self {
+        let mut f = self.0;
+        let mut k = 127;
+        let mut len = 2;
+        while len <= 128 {
+            for start in (0..N).step_by(2 * len) {
+                let zeta = ZETAS[k];
+                k -= 1;
+                for j in start..start + len {
+                    let t = f[j];
+                    f[j] = reduce_once(t + f[j + len]);
+                    f[j + len] = multiply(zeta, f[j + len] + Q - t);
+                }
+            }
+            len *= 2;
+        }
+        Self(f.map(|coefficient| multiply(coefficient, NTT_SCALE)))
+    }
+
+    /// Returns the product of the two polynomials, which are both in the NTT domain.
+    pub(super) fn multiply_ntt(&self, other: &Self) -> Self {
+        let mut h = [0u16; N];
+        for (i, gamma) in GAMMAS.iter().enumerate() {
+            let (a0, a1) = (self.0[2 * i] as u32, self.0[2 * i + 1] as u32);
+            let (b0, b1) = (other.0[2 * i] as u32, other.0[2 * i + 1] as u32);
+            let a1_b1 = barrett_reduce(a1 * b1) as u32;
+            h[2 * i] = barrett_reduce(a0 * b0 + a1_b1 * *gamma as u32);
+            h[2 * i + 1] = barrett_reduce(a0 * b1 + a1 * b0);
+        }
+        Self(h)
+    }
+
+    /// Returns the polynomial whose coefficients follow the centered binomial distribution `CBD_eta`,
+    /// from the given `64 * eta` bytes.
+    pub(super) fn sample_cbd(bytes: &[u8], eta: usize) -> Self {
+        debug_assert_eq!(bytes.len(), 64 * eta);
+        let bit = |index: usize| ((bytes[index / 8] >> (index % 8)) & 1) as u16;
+        Self(core::array::from_fn(|i| {
+            let x: u16 = (0..eta).map(|j| bit(2 * i * eta + j)).sum();
+            let y: u16 = (0..eta).map(|j| bit(2 * i * eta + eta + j)).sum();
+            reduce_once(x + Q - y)
+        }))
+    }
+
+    /// Returns the polynomial in the NTT domain, whose coefficients are sampled uniformly by rejection
+    /// from the given stream of public bytes.
+    pub(super) fn sample_ntt(mut next_bytes: impl FnMut() -> [u8; 3]) -> Self {
+        let mut f = [0u16; N];
+        let mut j = 0;
+        while j < N {
+            let [c0, c1, c2] = next_bytes().map(u16::from);
+            let d1 = c0 | ((c1 & 0x0f) << 8);
+            let d2 = (c1 >> 4) | (c2 << 4);
+            if d1 < Q {
+                f[j] = d1;
+                j += 1;
+            }
+            if d2 < Q && j < N {
+                f[j] = d2;
+                j += 1;
+            }
+        }
+        Self(f)
+    }
+
+    /// Returns the polynomial with the compressed coefficients, which are `d` bits each.
+    pub(super) fn compress(&self, d: u32) -> Self {
+        Self(self.0.map(|coefficient| compress(coefficient, d)))
+    }
+
+    /// Returns the polynomial with the decompressed coefficients, which are `d` bits each.
+    pub(super) fn decompress(&self, d: u32) -> Self {
+        Self(self.0.map(|coefficient| decompress(coefficient, d)))
+    }
+
+    /// Returns the encoding of the polynomial, with `d` bits per coefficient.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(super) fn encode(&self, d: u32) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 * d as usize);
+        let (mut buffer, mut num_bits) = (0u32, 0);
+        for coefficient in self.0 {
+            buffer |= (coefficient as u32) << num_bits;
+            num_bits += d;
+            while num_bits >= 8 {
+                bytes.push(buffer as u8);
+                buffer >>= 8;
+                num_bits -= 8;
+            }
+        }
+        bytes
+    }
+
+    /// Returns the polynomial of the given encoding, with `d` bits per coefficient.
+    /// The coefficients are reduced modulo `q` if `d = 12`, and are less than `2^d` otherwise.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(super) fn decode(bytes: &[u8], d: u32) -> Self {
+        debug_assert_eq!(bytes.len(), 32 * d as usize);
+        let mut f = [0u16; N];
+        let (mut buffer, mut num_bits, mut bytes) = (0u32, 0, bytes.iter());
+        for coefficient in f.iter_mut() {
+            while num_bits < d {
+                buffer |= (*bytes.next().unwrap_or(&0) as u32) << num_bits;
+                num_bits += 8;
+            }
+            let value = (buffer & ((1 << d) - 1)) as u16;
+            *coefficient = if d == 12 { barrett_reduce(value as u32) } else { value };
+            buffer >>= d;
+            num_bits -= d;
+        }
+        Self(f)
+    }
+}
@@ -0,0 +1,56 @@
+#!/usr/bin/env python3
+# Copyright (C) 2019-2023 Aleo Systems Inc.
+# This file is part of the snarkVM library.
+
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at:
+# http://www.apache.org/licenses/LICENSE-2.0
+
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+"""
+Writes `ml_kem_768.bin`, the ML-KEM-768 test vectors of `KyberKem`, using the implementation of OpenSSL
+(through `cryptography`, version 46 or later).
+
+Each vector is the concatenation of:
+  - the 64-byte seed `d || z` of the key pair,
+  - the 1184-byte public key derived from the seed,
+  - a 1088-byte ciphertext to the public key, and its 32-byte shared secret,
+  - a 1088-byte invalid ciphertext, and its 32-byte implicitly rejected shared secret.
+
+The seeds are fixed, but the ciphertexts are sampled by OpenSSL, so the file differs on every run.
+"""
+
+import random
+
+from cryptography.hazmat.primitives.asymmetric.mlkem import MLKEM768PrivateKey
+
+NUM_VECTORS = 4
+
+
+def main():
+    rng = random.Random(1234)
+    vectors = b""
+    for _ in range(NUM_VECTORS):
+        seed = rng.randbytes(64)
+        secret_key = MLKEM768PrivateKey.from_seed_bytes(seed)
+        public_key = secret_key.public_key()
+        shared_secret, ciphertext = public_key.encapsulate()
+        assert secret_key.decapsulate(ciphertext) == shared_secret
+
+        invalid_ciphertext = rng.randbytes(1088)
+        rejected_secret = secret_key.decapsulate(invalid_ciphertext)
+
+        vectors += seed + public_key.public_bytes_raw() + ciphertext + shared_secret
+        vectors += invalid_ciphertext + rejected_secret
+
+    with open("ml_kem_768.bin", "wb") as f:
+        f.write(vectors)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hybrid;
+pub use hybrid::*;
+
+#[cfg(feature = "kyber")]
+mod kyber;
+#[cfg(feature = "kyber")]
+pub use kyber::*;
+
+use crate::{Secret, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Group, Scalar};
+
+use core::marker::PhantomData;
+
+/// A key encapsulation mechanism, which establishes a shared secret with the holder of a public key.
+///
+/// A KEM is independent of the cipher that consumes the shared secret, so higher-level protocols
+/// may compose it with any data encapsulation mechanism, or combine several KEMs.
+pub trait KemScheme {
+    /// The public key of the recipient.
+    type PublicKey;
+    /// The secret key of the recipient.
+    type SecretKey;
+    /// The encapsulation sent to the recipient.
+    type Encapsulation;
+    /// The shared secret established by the encapsulation.
+    type SharedSecret;
+
+    /// Returns a fresh encapsulation to the given public key, along with the shared secret.
+    fn encapsulate<R: Rng + CryptoRng>(
+        public_key: &Self::PublicKey,
+        rng: &mut R,
+    ) -> Result<(Self::Encapsulation, Self::SharedSecret)>;
+
+    /// Returns the shared secret for the given encapsulation.
+    fn decapsulate(secret_key: &Self::SecretKey, encapsulation: &Self::Encapsulation) -> Result<Self::SharedSecret>;
+}
+
+/// The key encapsulation of the ECIES Poseidon construction used for record encryption.
+///
+/// The encapsulation is the nonce `r * G`, and the shared secret is the x-coordinate of `r * address`,
+/// which is the plaintext view key consumed by `Plaintext::encrypt_symmetric`. Protocols that derive
+/// more than one key from the shared secret should hash it with a domain separator first.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ECIESPoseidonKem<N: Network>(PhantomData<N>);
+
+impl<N: Network> KemScheme for ECIESPoseidonKem<N> {
+    type Encapsulation = Group<N>;
+    type PublicKey = Address<N>;
+    type SecretKey = ViewKey<N>;
+    type SharedSecret = Field<N>;
+
+    /// Returns a fresh nonce to the given address, along with the plaintext view key.
+    fn encapsulate<R: Rng + CryptoRng>(address: &Address<N>, rng: &mut R) -> Result<(Group<N>, Field<N>)> {
+        // Sample the randomizer, and compute the nonce.
+        let randomizer = Secret::new(Scalar::rand(rng));
+        let nonce = N::g_scalar_multiply(&randomizer);
+        // Compute the plaintext view key.
+        let plaintext_view_key = (**address * *randomizer).to_x_coordinate();
+        Ok((nonce, plaintext_view_key))
+    }
+
+    /// Returns the plaintext view key for the given nonce.
+    fn decapsulate(view_key: &ViewKey<N>, nonce: &Group<N>) -> Result<Field<N>> {
+        // Ensure the nonce is not the identity, which would fix the shared secret.
+        ensure!(!nonce.is_zero(), "The nonce must not be the identity");
+        Ok((*nonce * **view_key).to_x_coordinate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Kem = ECIESPoseidonKem<CurrentNetwork>;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_encapsulate_and_decapsulate() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            let (nonce, expected) = Kem::encapsulate(&address, rng)?;
+            assert_eq!(expected, Kem::decapsulate(&view_key, &nonce)?);
+
+            // Check that a different view key derives a different shared secret.
+            let other = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert_ne!(expected, Kem::decapsulate(&other, &nonce)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decapsulate_identity() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        assert!(Kem::decapsulate(&view_key, &Group::zero()).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The magic bytes at the start of every keystore.
+const MAGIC: &[u8; 8] = b"ALEOKEYS";
+/// The length of the checksum at the end of every keystore, in bytes.
+const CHECKSUM_LENGTH: usize = 32;
+/// The maximum length of the encrypted secret, in bytes.
+const MAX_CIPHERTEXT_LENGTH: u32 = 1024;
+
+impl Keystore {
+    /// Returns the keystore from its bytes, verifying its checksum and header.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, KeystoreError> {
+        let corrupted = |message: &str| KeystoreError::Corrupted(message.to_string());
+
+        // Verify the checksum, so that corruption is distinguished from a wrong passphrase.
+        if bytes.len() < MAGIC.len() + 1 + CHECKSUM_LENGTH {
+            return Err(corrupted("the keystore is truncated"));
+        }
+        let (contents, checksum) = bytes.split_at(bytes.len() - CHECKSUM_LENGTH);
+        if blake2s_simd::blake2s(contents).as_bytes() != checksum {
+            return Err(corrupted("the checksum does not match"));
+        }
+
+        let mut reader = contents;
+        // Read the magic bytes and version.
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic).map_err(|_| corrupted("the keystore is truncated"))?;
+        if &magic != MAGIC {
+            return Err(corrupted("the keystore does not start with the magic bytes"));
+        }
+        let version = u8::read_le(&mut reader).map_err(|_| corrupted("missing the version"))?;
+        if version != KEYSTORE_VERSION {
+            return Err(KeystoreError::UnsupportedVersion(version));
+        }
+
+        // Read the header and ciphertext.
+        let keystore = Self::read_body(&mut reader).map_err(|e| KeystoreError::Corrupted(e.to_string()))?;
+        if !reader.is_empty() {
+            return Err(corrupted("the keystore contains trailing bytes"));
+        }
+        Ok(keystore)
+    }
+
+    /// Returns the bytes of the keystore, ending with its checksum.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, KeystoreError> {
+        let mut bytes = self.header_bytes()?;
+        let write = |bytes: &mut Vec<u8>| -> IoResult<()> {
+            u32::try_from(self.ciphertext.len()).map_err(|e| error(e.to_string()))?.write_le(&mut *bytes)?;
+            bytes.write_all(&self.ciphertext)
+        };
+        write(&mut bytes).map_err(|e| KeystoreError::Message(e.to_string()))?;
+        let checksum = blake2s_simd::blake2s(&bytes);
+        bytes.extend_from_slice(checksum.as_bytes());
+        Ok(bytes)
+    }
+
+    /// Returns the header bytes, which are authenticated as the associated data of the ciphertext.
+    pub(super) fn header_bytes(&self) -> Result<Vec<u8>, KeystoreError> {
+        let mut bytes = Vec::new();
+        self.write_header(&mut bytes).map_err(|e| KeystoreError::Message(e.to_string()))?;
+        Ok(bytes)
+    }
+
+    /// Writes the magic bytes, version, kind, parameters, salt, nonce, and extension fields.
+    fn write_header<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        writer.write_all(MAGIC)?;
+        KEYSTORE_VERSION.write_le(&mut writer)?;
+        (self.kind as u8).write_le(&mut writer)?;
+        self.params.memory_cost.write_le(&mut writer)?;
+        self.params.time_cost.write_le(&mut writer)?;
+        self.params.parallelism.write_le(&mut writer)?;
+        writer.write_all(&self.salt)?;
+        writer.write_all(&self.nonce)?;
+        u16::try_from(self.extensions.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for (tag, value) in &self.extensions {
+            tag.write_le(&mut writer)?;
+            u16::try_from(value.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+            writer.write_all(value)?;
+        }
+        Ok(())
+    }
+
+    /// Reads the fields following the version.
+    fn read_body<R: Read>(mut reader: R) -> IoResult<Self> {
+        let kind = match u8::read_le(&mut reader)? {
+            0 => KeystoreKind::PrivateKey,
+            1 => KeystoreKind::Seed,
+            kind => return Err(error(format!("unknown secret kind '{kind}'"))),
+        };
+        let memory_cost = u32::read_le(&mut reader)?;
+        let time_cost = u32::read_le(&mut reader)?;
+        let parallelism = u32::read_le(&mut reader)?;
+        let params = KeystoreParams { memory_cost, time_cost, parallelism };
+
+        let mut salt = [0u8; SALT_LENGTH];
+        reader.read_exact(&mut salt)?;
+        let mut nonce = [0u8; NONCE_LENGTH];
+        reader.read_exact(&mut nonce)?;
+
+        let num_extensions = u16::read_le(&mut reader)?;
+        let mut extensions = Vec::with_capacity(num_extensions as usize);
+        for _ in 0..num_extensions {
+            let tag = u16::read_le(&mut reader)?;
+            let mut value = vec![0u8; u16::read_le(&mut reader)? as usize];
+            reader.read_exact(&mut value)?;
+            extensions.push((tag, value));
+        }
+
+        let ciphertext_length = u32::read_le(&mut reader)?;
+        if ciphertext_length > MAX_CIPHERTEXT_LENGTH {
+            return Err(error("the ciphertext is too large"));
+        }
+        let mut ciphertext = vec![0u8; ciphertext_length as usize];
+        reader.read_exact(&mut ciphertext)?;
+
+        Ok(Self { kind, params, salt, nonce, extensions, ciphertext })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The minimal parameters, for fast tests.
+    const TEST_PARAMS: KeystoreParams = KeystoreParams { memory_cost: 8, time_cost: 1, parallelism: 1 };
+
+    #[test]
+    fn test_bytes() -> Result<(), KeystoreError> {
+        let rng = &mut TestRng::default();
+
+        let expected = Keystore::encrypt(KeystoreKind::Seed, &[7u8; 64], "passphrase", TEST_PARAMS, rng)?;
+        let bytes = expected.to_bytes()?;
+        let candidate = Keystore::from_bytes(&bytes)?;
+        assert!(expected == candidate);
+        assert_eq!(candidate.decrypt("passphrase")?.as_slice(), &[7u8; 64]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extensions_are_preserved() -> Result<(), KeystoreError> {
+        let rng = &mut TestRng::default();
+
+        // Simulate a keystore written by a later version, with an extension field this version does not know.
+        let mut keystore = Keystore::encrypt(KeystoreKind::Seed, &[7u8; 32], "passphrase", TEST_PARAMS, rng)?;
+        keystore.extensions.push((42, b"label".to_vec()));
+        let key = TEST_PARAMS.derive_key("passphrase", &keystore.salt)?;
+        keystore.ciphertext = XChaCha20Poly1305::new(Key::from_slice(&key[..]))
+            .encrypt(XNonce::from_slice(&keystore.nonce), Payload { msg: &[7u8; 32], aad: &keystore.header_bytes()? })
+            .unwrap();
+
+        let candidate = Keystore::from_bytes(&keystore.to_bytes()?)?;
+        assert_eq!(candidate.extensions(), &[(42, b"label".to_vec())]);
+        assert_eq!(candidate.decrypt("passphrase")?.as_slice(), &[7u8; 32]);
+
+        // Check that a stripped extension field fails to authenticate.
+        let mut stripped = candidate;
+        stripped.extensions.clear();
+        assert!(stripped.decrypt("passphrase").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_corrupted() -> Result<(), KeystoreError> {
+        let rng = &mut TestRng::default();
+
+        let keystore = Keystore::encrypt(KeystoreKind::Seed, &[7u8; 32], "passphrase", TEST_PARAMS, rng)?;
+        let bytes = keystore.to_bytes()?;
+
+        // Check that every flipped bit is reported as corruption, not as a wrong passphrase.
+        for index in 0..bytes.len() {
+            let mut corrupted = bytes.clone();
+            corrupted[index] ^= 1;
+            assert!(matches!(Keystore::from_bytes(&corrupted), Err(KeystoreError::Corrupted(_))));
+        }
+        // Check that truncation is reported as corruption.
+        assert!(matches!(Keystore::from_bytes(&bytes[..bytes.len() - 1]), Err(KeystoreError::Corrupted(_))));
+        assert!(matches!(Keystore::from_bytes(&[]), Err(KeystoreError::Corrupted(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_version() -> Result<(), KeystoreError> {
+        let rng = &mut TestRng::default();
+
+        let keystore = Keystore::encrypt(KeystoreKind::Seed, &[7u8; 32], "passphrase", TEST_PARAMS, rng)?;
+        let mut bytes = keystore.to_bytes()?;
+        // Rewrite the version, and recompute the checksum.
+        bytes[MAGIC.len()] = KEYSTORE_VERSION + 1;
+        bytes.truncate(bytes.len() - CHECKSUM_LENGTH);
+        let checksum = blake2s_simd::blake2s(&bytes);
+        bytes.extend_from_slice(checksum.as_bytes());
+        assert!(matches!(Keystore::from_bytes(&bytes), Err(KeystoreError::UnsupportedVersion(2))));
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use thiserror::Error;
+
+/// An error from reading or decrypting a keystore.
+#[derive(Debug, Error)]
+pub enum KeystoreError {
+    #[error("The keystore is corrupted: {}", _0)]
+    Corrupted(String),
+
+    #[error("Invalid keystore parameters: {}", _0)]
+    InvalidParameters(String),
+
+    #[error("{}", _0)]
+    Message(String),
+
+    #[error("Unsupported keystore version {}", _0)]
+    UnsupportedVersion(u8),
+
+    #[error("The passphrase is incorrect")]
+    WrongPassphrase,
+}
@@ -0,0 +1,245 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod error;
+
+pub use error::KeystoreError;
+
+use crate::PrivateKey;
+use snarkvm_console_network::prelude::*;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{
+    aead::{Aead, Payload},
+    Key,
+    KeyInit,
+    XChaCha20Poly1305,
+    XNonce,
+};
+use zeroize::Zeroizing;
+
+/// The current version of the keystore format.
+const KEYSTORE_VERSION: u8 = 1;
+/// The length of the Argon2id salt, in bytes.
+const SALT_LENGTH: usize = 16;
+/// The length of the XChaCha20-Poly1305 nonce, in bytes.
+const NONCE_LENGTH: usize = 24;
+/// The length of the derived encryption key, in bytes.
+const KEY_LENGTH: usize = 32;
+
+/// The kind of secret held in a keystore.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum KeystoreKind {
+    /// An account private key, stored as its account seed.
+    PrivateKey = 0,
+    /// An arbitrary seed, such as a BIP39 seed.
+    Seed = 1,
+}
+
+/// The Argon2id parameters used to derive the encryption key from the passphrase.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeystoreParams {
+    /// The memory cost, in KiB.
+    pub memory_cost: u32,
+    /// The number of iterations.
+    pub time_cost: u32,
+    /// The degree of parallelism.
+    pub parallelism: u32,
+}
+
+impl Default for KeystoreParams {
+    /// Returns the recommended parameters of 64 MiB, 3 iterations, and 1 lane.
+    fn default() -> Self {
+        Self { memory_cost: 64 * 1024, time_cost: 3, parallelism: 1 }
+    }
+}
+
+impl KeystoreParams {
+    /// The maximum memory cost accepted when reading a keystore, in KiB (256 MiB, four times the default).
+    const MAX_MEMORY_COST: u32 = 256 * 1024;
+    /// The maximum degree of parallelism accepted when reading a keystore.
+    const MAX_PARALLELISM: u32 = 4;
+    /// The maximum number of iterations accepted when reading a keystore.
+    const MAX_TIME_COST: u32 = 10;
+
+    /// Returns the encryption key derived from the passphrase and salt.
+    fn derive_key(&self, passphrase: &str, salt: &[u8]) -> Result<Zeroizing<[u8; KEY_LENGTH]>, KeystoreError> {
+        // Ensure the parameters are within the accepted bounds, so a keystore cannot exhaust resources.
+        if self.memory_cost > Self::MAX_MEMORY_COST
+            || self.time_cost > Self::MAX_TIME_COST
+            || self.parallelism > Self::MAX_PARALLELISM
+        {
+            return Err(KeystoreError::InvalidParameters(format!("{self:?} exceeds the accepted bounds")));
+        }
+        let params = Params::new(self.memory_cost, self.time_cost, self.parallelism, Some(KEY_LENGTH))
+            .map_err(|e| KeystoreError::InvalidParameters(e.to_string()))?;
+
+        let mut key = Zeroizing::new([0u8; KEY_LENGTH]);
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(passphrase.as_bytes(), salt, &mut key[..])
+            .map_err(|e| KeystoreError::InvalidParameters(e.to_string()))?;
+        Ok(key)
+    }
+}
+
+/// A secret encrypted under a passphrase, in a portable format for moving keys between wallets.
+///
+/// The secret is encrypted with XChaCha20-Poly1305, under a key derived from the passphrase with Argon2id.
+/// The header, including any extension fields, is authenticated as associated data. Readers preserve
+/// extension fields they do not recognize, so later versions may add fields without breaking older readers.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Keystore {
+    /// The kind of secret.
+    kind: KeystoreKind,
+    /// The Argon2id parameters.
+    params: KeystoreParams,
+    /// The Argon2id salt.
+    salt: [u8; SALT_LENGTH],
+    /// The XChaCha20-Poly1305 nonce.
+    nonce: [u8; NONCE_LENGTH],
+    /// The extension fields, as `(tag, value)` pairs.
+    extensions: Vec<(u16, Vec<u8>)>,
+    /// The encrypted secret, with its authentication tag.
+    ciphertext: Vec<u8>,
+}
+
+impl Keystore {
+    /// Encrypts the secret under the passphrase.
+    pub fn encrypt<R: Rng + CryptoRng>(
+        kind: KeystoreKind,
+        secret: &[u8],
+        passphrase: &str,
+        params: KeystoreParams,
+        rng: &mut R,
+    ) -> Result<Self, KeystoreError> {
+        let mut keystore =
+            Self { kind, params, salt: rng.gen(), nonce: rng.gen(), extensions: Vec::new(), ciphertext: Vec::new() };
+
+        let key = params.derive_key(passphrase, &keystore.salt)?;
+        let header = keystore.header_bytes()?;
+        keystore.ciphertext = XChaCha20Poly1305::new(Key::from_slice(&key[..]))
+            .encrypt(XNonce::from_slice(&keystore.nonce), Payload { msg: secret, aad: &header })
+            .map_err(|_| KeystoreError::Message("Failed to encrypt the keystore".to_string()))?;
+        Ok(keystore)
+    }
+
+    /// Decrypts the secret with the passphrase.
+    pub fn decrypt(&self, passphrase: &str) -> Result<Zeroizing<Vec<u8>>, KeystoreError> {
+        let key = self.params.derive_key(passphrase, &self.salt)?;
+        let header = self.header_bytes()?;
+        // The file checksum has already been verified, so an authentication failure means the passphrase is wrong.
+        XChaCha20Poly1305::new(Key::from_slice(&key[..]))
+            .decrypt(XNonce::from_slice(&self.nonce), Payload { msg: &self.ciphertext, aad: &header })
+            .map(Zeroizing::new)
+            .map_err(|_| KeystoreError::WrongPassphrase)
+    }
+
+    /// Returns the kind of secret.
+    pub const fn kind(&self) -> KeystoreKind {
+        self.kind
+    }
+
+    /// Returns the Argon2id parameters.
+    pub const fn params(&self) -> KeystoreParams {
+        self.params
+    }
+
+    /// Returns the extension fields, as `(tag, value)` pairs.
+    pub fn extensions(&self) -> &[(u16, Vec<u8>)] {
+        &self.extensions
+    }
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the private key encrypted under the passphrase, with the default parameters.
+    pub fn to_keystore<R: Rng + CryptoRng>(&self, passphrase: &str, rng: &mut R) -> Result<Keystore, KeystoreError> {
+        let secret = Zeroizing::new(self.to_bytes_le().map_err(|e| KeystoreError::Message(e.to_string()))?);
+        Keystore::encrypt(KeystoreKind::PrivateKey, &secret, passphrase, KeystoreParams::default(), rng)
+    }
+
+    /// Returns the private key decrypted from the keystore with the passphrase.
+    pub fn from_keystore(keystore: &Keystore, passphrase: &str) -> Result<Self, KeystoreError> {
+        if keystore.kind() != KeystoreKind::PrivateKey {
+            return Err(KeystoreError::Message(format!("Expected a private key, found {:?}", keystore.kind())));
+        }
+        let secret = keystore.decrypt(passphrase)?;
+        Self::from_bytes_le(&secret).map_err(|e| KeystoreError::Message(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// The minimal parameters, for fast tests.
+    const TEST_PARAMS: KeystoreParams = KeystoreParams { memory_cost: 8, time_cost: 1, parallelism: 1 };
+
+    #[test]
+    fn test_encrypt_and_decrypt() -> Result<(), KeystoreError> {
+        let rng = &mut TestRng::default();
+
+        let keystore = Keystore::encrypt(KeystoreKind::Seed, &[7u8; 64], "passphrase", TEST_PARAMS, rng)?;
+        assert_eq!(keystore.kind(), KeystoreKind::Seed);
+        assert_eq!(keystore.decrypt("passphrase")?.as_slice(), &[7u8; 64]);
+        assert!(matches!(keystore.decrypt("wrong passphrase"), Err(KeystoreError::WrongPassphrase)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_private_key() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let keystore = private_key.to_keystore("passphrase", rng)?;
+        assert_eq!(keystore.params(), KeystoreParams::default());
+        assert_eq!(private_key, PrivateKey::from_keystore(&keystore, "passphrase")?);
+        assert!(matches!(
+            PrivateKey::<CurrentNetwork>::from_keystore(&keystore, "wrong passphrase"),
+            Err(KeystoreError::WrongPassphrase)
+        ));
+
+        // Check that a seed keystore is not read as a private key.
+        let keystore = Keystore::encrypt(KeystoreKind::Seed, &[0u8; 32], "passphrase", TEST_PARAMS, rng)?;
+        assert!(PrivateKey::<CurrentNetwork>::from_keystore(&keystore, "passphrase").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let rng = &mut TestRng::default();
+
+        // Check that parameters beyond each bound are rejected before any memory is allocated.
+        for params in [
+            KeystoreParams { memory_cost: u32::MAX, ..TEST_PARAMS },
+            KeystoreParams { memory_cost: KeystoreParams::MAX_MEMORY_COST + 1, ..TEST_PARAMS },
+            KeystoreParams { time_cost: KeystoreParams::MAX_TIME_COST + 1, ..TEST_PARAMS },
+            KeystoreParams { parallelism: KeystoreParams::MAX_PARALLELISM + 1, ..TEST_PARAMS },
+        ] {
+            assert!(matches!(
+                Keystore::encrypt(KeystoreKind::Seed, &[0u8; 32], "", params, rng),
+                Err(KeystoreError::InvalidParameters(_))
+            ));
+        }
+        // Check that the default parameters are within the bounds.
+        let defaults = KeystoreParams::default();
+        assert!(defaults.memory_cost <= KeystoreParams::MAX_MEMORY_COST);
+        assert!(defaults.time_cost <= KeystoreParams::MAX_TIME_COST);
+        assert!(defaults.parallelism <= KeystoreParams::MAX_PARALLELISM);
+    }
+}
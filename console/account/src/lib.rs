@@ -26,21 +26,64 @@ pub mod compute_key;
 #[cfg(feature = "compute_key")]
 pub use compute_key::*;
 
+#[cfg(feature = "view_key")]
+pub mod encryption;
+#[cfg(feature = "view_key")]
+pub use encryption::*;
+
+#[cfg(all(feature = "private_key", feature = "view_key"))]
+pub mod extended_private_key;
+#[cfg(all(feature = "private_key", feature = "view_key"))]
+pub use extended_private_key::*;
+
 #[cfg(feature = "graph_key")]
 pub mod graph_key;
 #[cfg(feature = "graph_key")]
 pub use graph_key::*;
 
+#[cfg(feature = "handshake")]
+pub mod handshake;
+#[cfg(feature = "handshake")]
+pub use handshake::{Handshake, HandshakePattern, Session};
+
+#[cfg(feature = "view_key")]
+pub mod kem;
+#[cfg(feature = "view_key")]
+pub use kem::*;
+
+#[cfg(feature = "keystore")]
+pub mod keystore;
+#[cfg(feature = "keystore")]
+pub use keystore::{Keystore, KeystoreError, KeystoreKind, KeystoreParams};
+
+#[cfg(feature = "mnemonic")]
+pub mod mnemonic;
+#[cfg(feature = "mnemonic")]
+pub use mnemonic::Mnemonic;
+
 #[cfg(feature = "private_key")]
 pub mod private_key;
 #[cfg(feature = "private_key")]
 pub use private_key::*;
 
+#[cfg(feature = "signature")]
+pub mod ring_signature;
+#[cfg(feature = "signature")]
+pub use ring_signature::RingSignature;
+
+mod secret;
+pub use secret::Secret;
+
 #[cfg(feature = "signature")]
 pub mod signature;
 #[cfg(feature = "signature")]
 pub use signature::*;
 
+#[cfg(feature = "signature")]
+pub mod signer;
+#[cfg(feature = "signature")]
+pub use signer::*;
+
 #[cfg(feature = "view_key")]
 pub mod view_key;
 #[cfg(feature = "view_key")]
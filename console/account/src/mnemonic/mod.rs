@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{ExtendedPrivateKey, PrivateKey, Secret};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+use bip39::Language;
+
+const ACCOUNT_MNEMONIC_SEED_DOMAIN: DomainSeparator = DomainSeparator::new("AccountMnemonicSeed", 0);
+
+/// The number of bytes of entropy in a newly-sampled mnemonic, which encodes as 24 words.
+const ENTROPY_SIZE_IN_BYTES: usize = 32;
+
+/// A BIP39 mnemonic phrase in the English wordlist, which backs up an account.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mnemonic(bip39::Mnemonic);
+
+impl Mnemonic {
+    /// Samples a new 24-word mnemonic.
+    pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Result<Self> {
+        Self::from_entropy(&rng.gen::<[u8; ENTROPY_SIZE_IN_BYTES]>())
+    }
+
+    /// Returns the mnemonic for the given entropy, which must be 16, 20, 24, 28, or 32 bytes.
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self> {
+        Ok(Self(bip39::Mnemonic::from_entropy_in(Language::English, entropy).map_err(|e| anyhow!("{e}"))?))
+    }
+
+    /// Returns the mnemonic for the given phrase, validating its words and checksum.
+    pub fn from_phrase(phrase: &str) -> Result<Self> {
+        Ok(Self(bip39::Mnemonic::parse_in(Language::English, phrase).map_err(|e| anyhow!("Invalid mnemonic: {e}"))?))
+    }
+
+    /// Returns the number of words in the mnemonic.
+    pub fn word_count(&self) -> usize {
+        self.0.word_count()
+    }
+
+    /// Returns the 64-byte BIP39 seed for the given passphrase, which may be empty.
+    ///
+    /// The seed derives every account of the mnemonic, so callers should wrap it in a `Secret`.
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        self.0.to_seed(passphrase)
+    }
+
+    /// Returns the master extended private key for the given passphrase, which derives the account hierarchy.
+    pub fn to_extended_private_key<N: Network>(&self, passphrase: &str) -> Result<ExtendedPrivateKey<N>> {
+        let seed = Secret::new(self.to_seed(passphrase));
+        ExtendedPrivateKey::from_seed(&Secret::new(Self::to_account_seed::<N>(&seed)?))
+    }
+
+    /// Returns the private key of the master account for the given passphrase.
+    pub fn to_private_key<N: Network>(&self, passphrase: &str) -> Result<PrivateKey<N>> {
+        self.to_extended_private_key::<N>(passphrase)?.to_private_key()
+    }
+
+    /// Returns the master seed of the extended private key for the given BIP39 seed.
+    pub fn to_account_seed<N: Network>(seed: &[u8; 64]) -> Result<Field<N>> {
+        // Pack the BIP39 seed into field elements, which are zeroized with its bits on drop.
+        let mut preimage = Secret::new(vec![Field::<N>::from_domain_separator(ACCOUNT_MNEMONIC_SEED_DOMAIN)]);
+        for chunk in Secret::new(seed.to_bits_le()).chunks(Field::<N>::size_in_data_bits()) {
+            preimage.push(Field::from_bits_le(chunk)?);
+        }
+        // Compute the account seed.
+        N::hash_psd4(&preimage)
+    }
+}
+
+impl Display for Mnemonic {
+    /// Writes the mnemonic phrase, with the words separated by spaces.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Debug for Mnemonic {
+    /// Omits the mnemonic phrase, which is secret.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Mnemonic({} words)", self.word_count())
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = Error;
+
+    /// Returns the mnemonic for the given phrase.
+    fn from_str(phrase: &str) -> Result<Self> {
+        Self::from_phrase(phrase)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, ViewKey};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    /// The BIP39 test vector for 16 zero bytes of entropy, with the passphrase `TREZOR`.
+    const PHRASE: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    const SEED: &str = "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04";
+
+    #[test]
+    fn test_bip39_vector() -> Result<()> {
+        let mnemonic = Mnemonic::from_entropy(&[0u8; 16])?;
+        assert_eq!(mnemonic.to_string(), PHRASE);
+        assert_eq!(mnemonic, Mnemonic::from_str(PHRASE)?);
+        assert_eq!(to_canonical_hex(&mnemonic.to_seed("TREZOR")), SEED);
+        Ok(())
+    }
+
+    #[test]
+    fn test_new() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let mnemonic = Mnemonic::new(rng)?;
+            assert_eq!(mnemonic.word_count(), 24);
+
+            // Check that the phrase recovers the same account.
+            let candidate = Mnemonic::from_phrase(&mnemonic.to_string())?;
+            let private_key = mnemonic.to_private_key::<CurrentNetwork>("")?;
+            assert_eq!(private_key, candidate.to_private_key("")?);
+            assert_eq!(Address::try_from(&private_key)?, Address::try_from(candidate.to_private_key("")?)?);
+            assert_eq!(ViewKey::try_from(&private_key)?, ViewKey::try_from(candidate.to_private_key("")?)?);
+
+            // Check that the passphrase changes the account.
+            assert_ne!(private_key, mnemonic.to_private_key("passphrase")?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_extended_private_key() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let mnemonic = Mnemonic::new(rng)?;
+            let master = mnemonic.to_extended_private_key::<CurrentNetwork>("")?;
+            assert_eq!(master.to_private_key()?, mnemonic.to_private_key("")?);
+
+            // Check that the phrase recovers the same account hierarchy.
+            let candidate = Mnemonic::from_phrase(&mnemonic.to_string())?.to_extended_private_key("")?;
+            assert_eq!(master, candidate);
+            assert_eq!(
+                master.derive_path(&[44, 0, 7])?.to_private_key()?,
+                candidate.derive_path(&[44, 0, 7])?.to_private_key()?
+            );
+
+            // Check that the passphrase changes the account hierarchy.
+            assert_ne!(master, mnemonic.to_extended_private_key("passphrase")?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_phrase() {
+        // Check that an invalid checksum is rejected.
+        assert!(Mnemonic::from_phrase(&PHRASE.replace("about", "abandon")).is_err());
+        // Check that an unknown word is rejected.
+        assert!(Mnemonic::from_phrase(&PHRASE.replace("about", "aleo")).is_err());
+        // Check that an invalid word count is rejected.
+        assert!(Mnemonic::from_phrase("abandon abandon about").is_err());
+        // Check that invalid entropy is rejected.
+        assert!(Mnemonic::from_entropy(&[0u8; 15]).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::ViewKey;
+use snarkvm_console_types::{Address, Group};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+impl<N: Network> PrivateKey<N> {
+    /// Samples the given number of new accounts, and returns the `(private key, view key, address)` of each.
+    ///
+    /// Rather than deriving each account separately, the signature keys of every account are normalized
+    /// together with a single field inversion, and the scalar multiplications run in parallel.
+    pub fn new_batch<R: Rng + CryptoRng>(
+        num_accounts: usize,
+        rng: &mut R,
+    ) -> Result<Vec<(Self, ViewKey<N>, Address<N>)>> {
+        // Sample the account seeds sequentially, so that the accounts are deterministic for a given RNG.
+        let seeds = (0..num_accounts).map(|_| Uniform::rand(rng)).collect::<Vec<Field<N>>>();
+        // Derive the private keys.
+        let private_keys = cfg_into_iter!(seeds).map(Self::try_from).collect::<Result<Vec<_>>>()?;
+
+        // Compute pk_sig := G^sk_sig and pr_sig := G^r_sig for each account.
+        let (pk_sigs, pr_sigs): (Vec<Group<N>>, Vec<Group<N>>) = cfg_iter!(private_keys)
+            .map(|private_key| (N::g_scalar_multiply(&private_key.sk_sig), N::g_scalar_multiply(&private_key.r_sig)))
+            .unzip();
+        // Normalize every pk_sig and pr_sig with a single inversion.
+        let x_coordinates = Group::batch_to_x_coordinates(&[pk_sigs.as_slice(), pr_sigs.as_slice()].concat());
+        let (pk_sig_x_coordinates, pr_sig_x_coordinates) = x_coordinates.split_at(num_accounts);
+
+        // Derive the accounts.
+        cfg_into_iter!(private_keys)
+            .zip_eq(cfg_iter!(pk_sigs))
+            .zip_eq(cfg_iter!(pr_sigs))
+            .zip_eq(cfg_iter!(pk_sig_x_coordinates))
+            .zip_eq(cfg_iter!(pr_sig_x_coordinates))
+            .map(|((((private_key, pk_sig), pr_sig), pk_sig_x_coordinate), pr_sig_x_coordinate)| {
+                // Compute sk_prf := HashToScalar(pk_sig || pr_sig).
+                let sk_prf = N::hash_to_scalar_psd4(&[*pk_sig_x_coordinate, *pr_sig_x_coordinate])?;
+                // Compute view_key := sk_sig + r_sig + sk_prf.
+                let view_key = ViewKey::from_scalar(private_key.sk_sig + private_key.r_sig + sk_prf);
+                // Compute address := pk_sig + pr_sig + G^sk_prf.
+                let address = Address::new(*pk_sig + *pr_sig + N::g_scalar_multiply(&sk_prf));
+                Ok((private_key, view_key, address))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_new_batch() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for num_accounts in [0, 1, 10, 100] {
+            let accounts = PrivateKey::<CurrentNetwork>::new_batch(num_accounts, rng)?;
+            assert_eq!(accounts.len(), num_accounts);
+
+            // Check that each account matches the single-account derivation.
+            for (private_key, view_key, address) in &accounts {
+                assert_eq!(*view_key, ViewKey::try_from(private_key)?);
+                assert_eq!(*address, Address::try_from(private_key)?);
+            }
+            // Check that the accounts are distinct.
+            let addresses = accounts.iter().map(|(_, _, address)| *address).collect::<std::collections::HashSet<_>>();
+            assert_eq!(addresses.len(), num_accounts);
+        }
+        Ok(())
+    }
+}
@@ -28,6 +28,10 @@ impl<N: Network> ToBytes for PrivateKey<N> {
     }
 }
 
+impl<N: Network> ConstSerializedSize for PrivateKey<N> {
+    const SIZE: usize = Field::<N>::SIZE;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "view_key")]
+mod batch;
 mod bytes;
 mod serialize;
 mod string;
@@ -23,9 +25,10 @@ mod sign;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Field, Scalar};
 
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Zeroize)]
+/// The account private key, which is zeroized on drop.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Zeroize, ZeroizeOnDrop)]
 pub struct PrivateKey<N: Network> {
     /// The account seed that derives the full private key.
     seed: Field<N>,
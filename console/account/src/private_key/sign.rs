@@ -21,6 +21,12 @@ impl<N: Network> PrivateKey<N> {
         Signature::sign(self, message, rng)
     }
 
+    /// Returns a signature for the given message (as field elements) using the private key,
+    /// with a nonce derived deterministically from the private key and the message.
+    pub fn sign_deterministic(&self, message: &[Field<N>]) -> Result<Signature<N>> {
+        Signature::sign_deterministic(self, message)
+    }
+
     /// Returns a signature for the given message (as bytes) using the private key.
     pub fn sign_bytes<R: Rng + CryptoRng>(&self, message: &[u8], rng: &mut R) -> Result<Signature<N>> {
         Signature::sign_bytes(self, message, rng)
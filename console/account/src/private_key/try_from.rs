@@ -15,19 +15,18 @@
 use super::*;
 use snarkvm_console_types::Field;
 
-static ACCOUNT_SK_SIG_DOMAIN: &str = "AleoAccountSignatureSecretKey0";
-static ACCOUNT_R_SIG_DOMAIN: &str = "AleoAccountSignatureRandomizer0";
+const ACCOUNT_SK_SIG_DOMAIN: DomainSeparator = DomainSeparator::new("AccountSignatureSecretKey", 0);
+const ACCOUNT_R_SIG_DOMAIN: DomainSeparator = DomainSeparator::new("AccountSignatureRandomizer", 0);
 
 impl<N: Network> PrivateKey<N> {
     /// Returns the account private key from an account seed.
     #[inline]
     pub fn try_from(seed: Field<N>) -> Result<Self> {
         // Construct the sk_sig domain separator.
-        let sk_sig_domain = Field::<N>::new_domain_separator(ACCOUNT_SK_SIG_DOMAIN);
+        let sk_sig_domain = Field::<N>::from_domain_separator(ACCOUNT_SK_SIG_DOMAIN);
 
         // Construct the r_sig domain separator.
-        let r_sig_input = format!("{}.{}", ACCOUNT_R_SIG_DOMAIN, 0);
-        let r_sig_domain = Field::new_domain_separator(&r_sig_input);
+        let r_sig_domain = Field::from_domain_separator(ACCOUNT_R_SIG_DOMAIN.with_index(0));
 
         Ok(Self {
             seed,
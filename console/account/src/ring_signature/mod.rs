@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(feature = "private_key")]
+mod sign;
+mod verify;
+
+#[cfg(feature = "private_key")]
+use crate::PrivateKey;
+
+use crate::ComputeKey;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+const RING_SIGNATURE_DOMAIN: DomainSeparator = DomainSeparator::new("RingSignature", 0);
+const RING_KEY_IMAGE_DOMAIN: DomainSeparator = DomainSeparator::new("RingKeyImage", 0);
+
+/// A ring signature, which proves that the signer holds the private key of one of the compute keys
+/// in a ring, without revealing which one.
+///
+/// A linkable ring signature also contains the key image `sk_sig * HashToGroup(pk_sig)` of the signer,
+/// which is the same for all signatures by the same private key, irrespective of the ring and the message.
+/// This allows a verifier to detect when a member of a ring signs twice.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RingSignature<N: Network> {
+    /// The verifier challenge of the first member of the ring.
+    challenge: Scalar<N>,
+    /// The prover response of each member of the ring.
+    responses: Vec<Scalar<N>>,
+    /// The key image of the signer, if the signature is linkable.
+    key_image: Option<Group<N>>,
+}
+
+impl<N: Network> RingSignature<N> {
+    /// Returns the verifier challenge of the first member of the ring.
+    pub const fn challenge(&self) -> Scalar<N> {
+        self.challenge
+    }
+
+    /// Returns the prover response of each member of the ring.
+    pub fn responses(&self) -> &[Scalar<N>] {
+        &self.responses
+    }
+
+    /// Returns the key image of the signer, if the signature is linkable.
+    pub const fn key_image(&self) -> Option<Group<N>> {
+        self.key_image
+    }
+
+    /// Returns `true` if both signatures are linkable and were produced by the same private key.
+    pub fn is_linked(&self, other: &Self) -> bool {
+        matches!((self.key_image, other.key_image), (Some(first), Some(second)) if first == second)
+    }
+}
+
+impl<N: Network> RingSignature<N> {
+    /// Returns the base of the key image of the given member, as `HashToGroup(pk_sig)`.
+    fn key_image_base(pk_sig: &Group<N>) -> Result<Group<N>> {
+        N::hash_to_group_psd2(&[Field::from_domain_separator(RING_KEY_IMAGE_DOMAIN), pk_sig.to_x_coordinate()])
+    }
+
+    /// Returns the digest of the ring, the key image, and the message, to which every challenge is bound.
+    fn digest(pk_sigs: &[Group<N>], key_image: Option<&Group<N>>, message: &[Field<N>]) -> Result<Field<N>> {
+        let mut preimage = Vec::with_capacity(3 + pk_sigs.len() + message.len());
+        preimage.push(Field::from_domain_separator(RING_SIGNATURE_DOMAIN));
+        preimage.push(Field::from_u64(pk_sigs.len() as u64));
+        preimage.extend(pk_sigs.iter().map(|pk_sig| pk_sig.to_x_coordinate()));
+        preimage.extend(key_image.map(|key_image| key_image.to_x_coordinate()));
+        preimage.extend(message);
+        N::hash_psd8(&preimage)
+    }
+
+    /// Returns the challenge of the next member of the ring, given the nonce commitments of the current member:
+    ///     challenge_{i+1} := HashToScalar(digest, g_r_i, h_r_i)
+    fn challenge_from_commitments(digest: Field<N>, g_r: Group<N>, h_r: Option<Group<N>>) -> Result<Scalar<N>> {
+        match h_r {
+            Some(h_r) => N::hash_to_scalar_psd4(&[digest, g_r.to_x_coordinate(), h_r.to_x_coordinate()]),
+            None => N::hash_to_scalar_psd2(&[digest, g_r.to_x_coordinate()]),
+        }
+    }
+
+    /// Returns the challenge of the next member of the ring, given the challenge and response of the current member:
+    ///     g_r_i := response_i * G + challenge_i * pk_sig_i
+    ///     h_r_i := response_i * HashToGroup(pk_sig_i) + challenge_i * key_image
+    fn next_challenge(
+        digest: Field<N>,
+        pk_sig: &Group<N>,
+        key_image: Option<&Group<N>>,
+        challenge: Scalar<N>,
+        response: Scalar<N>,
+    ) -> Result<Scalar<N>> {
+        let g_r = N::g_scalar_multiply(&response) + (*pk_sig * challenge);
+        let h_r = match key_image {
+            Some(key_image) => Some((Self::key_image_base(pk_sig)? * response) + (*key_image * challenge)),
+            None => None,
+        };
+        Self::challenge_from_commitments(digest, g_r, h_r)
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Secret;
+
+impl<N: Network> RingSignature<N> {
+    /// Returns a ring signature for the given message, by the given private key on behalf of the given ring.
+    ///
+    /// Starting from a nonce commitment at the position of the signer, the challenge of each following member is
+    /// derived from a random response, until the ring is closed with the response of the signer:
+    ///     response_signer := nonce - challenge_signer * private_key.sk_sig()
+    ///
+    /// If `linkable` is `true`, the signature contains the key image of the signer.
+    pub fn sign<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        ring: &[ComputeKey<N>],
+        message: &[Field<N>],
+        linkable: bool,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        ensure!(!ring.is_empty(), "Cannot sign on behalf of an empty ring");
+        let pk_sigs = ring.iter().map(|compute_key| compute_key.pk_sig()).collect::<Vec<_>>();
+        ensure!(!has_duplicates(&pk_sigs), "Cannot sign on behalf of a ring with duplicate members");
+
+        // Retrieve the position of the signer in the ring.
+        let pk_sig = N::g_scalar_multiply(&private_key.sk_sig());
+        let Some(index) = pk_sigs.iter().position(|candidate| *candidate == pk_sig) else {
+            bail!("The private key is not a member of the ring")
+        };
+
+        // Compute the key image, if the signature is linkable.
+        let key_image_base = Self::key_image_base(&pk_sig)?;
+        let key_image = linkable.then(|| key_image_base * private_key.sk_sig());
+        let digest = Self::digest(&pk_sigs, key_image.as_ref(), message)?;
+
+        // Commit to a random nonce at the position of the signer.
+        let nonce = Secret::new(Scalar::rand(rng));
+        let h_r = key_image.map(|_| key_image_base * *nonce);
+        let mut challenge = Self::challenge_from_commitments(digest, N::g_scalar_multiply(&nonce), h_r)?;
+
+        // Sample the responses of the other members, in order around the ring.
+        let mut challenges = vec![Scalar::zero(); ring.len()];
+        let mut responses = vec![Scalar::zero(); ring.len()];
+        for i in (1..ring.len()).map(|offset| (index + offset) % ring.len()) {
+            challenges[i] = challenge;
+            responses[i] = Scalar::rand(rng);
+            challenge = Self::next_challenge(digest, &pk_sigs[i], key_image.as_ref(), challenge, responses[i])?;
+        }
+
+        // Close the ring with the response of the signer.
+        challenges[index] = challenge;
+        responses[index] = *nonce - (challenge * private_key.sk_sig());
+
+        Ok(Self { challenge: challenges[0], responses, key_image })
+    }
+}
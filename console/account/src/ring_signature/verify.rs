@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RingSignature<N> {
+    /// Verifies that the challenges close the ring, where the challenge of each member is derived from
+    /// the challenge and response of the previous member, and the challenge of the first member is given.
+    pub fn verify(&self, ring: &[ComputeKey<N>], message: &[Field<N>]) -> bool {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            eprintln!("Cannot verify the ring signature: the signed message exceeds maximum allowed size");
+            return false;
+        }
+        // Ensure the ring is non-empty, and there is one response for each member.
+        if ring.is_empty() || ring.len() != self.responses.len() {
+            return false;
+        }
+        let pk_sigs = ring.iter().map(|compute_key| compute_key.pk_sig()).collect::<Vec<_>>();
+        if has_duplicates(&pk_sigs) {
+            return false;
+        }
+        // Ensure the key image is not the identity, as otherwise it does not link the signer.
+        if self.key_image.is_some_and(|key_image| key_image.is_zero()) {
+            return false;
+        }
+
+        // Compute the digest of the ring, and return `false` if this operation fails.
+        let digest = match Self::digest(&pk_sigs, self.key_image.as_ref(), message) {
+            Ok(digest) => digest,
+            Err(_) => return false,
+        };
+
+        // Compute the challenges around the ring, and return `false` if this operation fails.
+        let mut challenge = self.challenge;
+        for (pk_sig, response) in pk_sigs.iter().zip_eq(&self.responses) {
+            challenge = match Self::next_challenge(digest, pk_sig, self.key_image.as_ref(), challenge, *response) {
+                Ok(challenge) => challenge,
+                Err(_) => return false,
+            };
+        }
+
+        // Return `true` if the challenges close the ring.
+        challenge == self.challenge
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 5;
+
+    /// Samples a ring of the given size, and returns it with the private keys of its members.
+    fn sample_ring(
+        size: usize,
+        rng: &mut TestRng,
+    ) -> Result<(Vec<PrivateKey<CurrentNetwork>>, Vec<ComputeKey<CurrentNetwork>>)> {
+        let private_keys = (0..size).map(|_| PrivateKey::new(rng)).collect::<Result<Vec<_>>>()?;
+        let ring = private_keys.iter().map(ComputeKey::try_from).collect::<Result<Vec<_>>>()?;
+        Ok((private_keys, ring))
+    }
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for size in 1..=ITERATIONS {
+            let (private_keys, ring) = sample_ring(size, rng)?;
+            let message = (0..size).map(|_| Uniform::rand(rng)).collect::<Vec<Field<CurrentNetwork>>>();
+
+            for (private_key, linkable) in private_keys.iter().zip_eq((0..size).map(|i| i % 2 == 0)) {
+                let signature = RingSignature::sign(private_key, &ring, &message, linkable, rng)?;
+                assert!(signature.verify(&ring, &message));
+                assert_eq!(linkable, signature.key_image().is_some());
+
+                // Ensure the signature is invalid for a different message.
+                let other_message = (0..size).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+                assert!(!signature.verify(&ring, &other_message));
+                // Ensure the signature is invalid for a different ring.
+                let (_, other_ring) = sample_ring(size, rng)?;
+                assert!(!signature.verify(&other_ring, &message));
+                assert!(!signature.verify(&ring[..size - 1], &message));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_linkability() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (private_keys, ring) = sample_ring(ITERATIONS, rng)?;
+        let (_, other_ring) = sample_ring(ITERATIONS, rng)?;
+        let other_ring = [&other_ring[1..], &ring[..1]].concat();
+        let message = [Uniform::rand(rng)];
+
+        // Ensure the signatures of the same signer are linked, even for a different ring and message.
+        let first = RingSignature::sign(&private_keys[0], &ring, &message, true, rng)?;
+        let other_message = [Uniform::rand(rng)];
+        let second = RingSignature::sign(&private_keys[0], &other_ring, &other_message, true, rng)?;
+        assert!(second.verify(&other_ring, &other_message));
+        assert!(first.is_linked(&second));
+
+        // Ensure the signatures of different signers, or unlinkable signatures, are not linked.
+        let third = RingSignature::sign(&private_keys[1], &ring, &message, true, rng)?;
+        assert!(!first.is_linked(&third));
+        let fourth = RingSignature::sign(&private_keys[0], &ring, &message, false, rng)?;
+        assert!(!first.is_linked(&fourth));
+        assert!(!fourth.is_linked(&fourth));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_fails() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (private_keys, ring) = sample_ring(ITERATIONS, rng)?;
+        let message = [Uniform::rand(rng)];
+
+        // Ensure a non-member cannot sign on behalf of the ring.
+        let (outsiders, _) = sample_ring(1, rng)?;
+        assert!(RingSignature::sign(&outsiders[0], &ring, &message, true, rng).is_err());
+        // Ensure the ring must be non-empty, and must not have duplicate members.
+        assert!(RingSignature::sign(&private_keys[0], &[], &message, true, rng).is_err());
+        let duplicate_ring = [&ring[..], &ring[..1]].concat();
+        assert!(RingSignature::sign(&private_keys[0], &duplicate_ring, &message, true, rng).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+#[cfg(feature = "private_key")]
+use crate::Secret;
+use snarkvm_console_types::Group;
+
+/// A pre-signature bound to an adaptor point `T := t * G`, which completes into a signature
+/// given the adaptor secret `t`, and reveals `t` once the completed signature is published.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AdaptorPreSignature<N: Network> {
+    /// The verifier challenge to check against.
+    challenge: Scalar<N>,
+    /// The prover response to the challenge, which is missing the adaptor secret.
+    response: Scalar<N>,
+    /// The compute key of the prover.
+    compute_key: ComputeKey<N>,
+}
+
+impl<N: Network> AdaptorPreSignature<N> {
+    /// Returns the verifier challenge.
+    pub const fn challenge(&self) -> Scalar<N> {
+        self.challenge
+    }
+
+    /// Returns the prover response.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+
+    /// Returns the compute key of the prover.
+    pub const fn compute_key(&self) -> ComputeKey<N> {
+        self.compute_key
+    }
+}
+
+impl<N: Network> Signature<N> {
+    /// Returns a pre-signature `(challenge, response, compute_key)` bound to the given adaptor point, where:
+    ///     challenge := HashToScalar(nonce * G + T, pk_sig, pr_sig, address, message)
+    ///     response := nonce - challenge * private_key.sk_sig()
+    #[cfg(feature = "private_key")]
+    pub fn adaptor_sign<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        adaptor_point: &Group<N>,
+        message: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<AdaptorPreSignature<N>> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        ensure!(!adaptor_point.is_zero(), "The adaptor point must not be the identity");
+
+        // Sample a random nonce from the scalar field.
+        let nonce = Secret::new(Scalar::rand(rng));
+        // Compute `g_r` as `nonce * G + T`.
+        let g_r = N::g_scalar_multiply(&nonce) + adaptor_point;
+        ensure!(!g_r.is_zero(), "The adapted nonce must not be the identity");
+
+        // Derive the compute key from the private key.
+        let compute_key = ComputeKey::try_from(private_key)?;
+        // Retrieve pk_sig.
+        let pk_sig = compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = compute_key.pr_sig();
+
+        // Derive the address from the compute key.
+        let address = Address::try_from(compute_key)?;
+
+        // Construct the hash input as (r * G + T, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([g_r, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Compute the verifier challenge.
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+        // Compute the prover response.
+        let response = *nonce - (challenge * private_key.sk_sig());
+
+        // Output the pre-signature.
+        Ok(AdaptorPreSignature { challenge, response, compute_key })
+    }
+
+    /// Verifies (challenge == challenge') && (address == address') where:
+    ///     challenge' := HashToScalar(G^response pk_sig^challenge T, pk_sig, pr_sig, address, message)
+    ///
+    /// A valid pre-signature ensures that the adaptor secret completes it into a valid signature.
+    pub fn adaptor_verify(
+        pre_signature: &AdaptorPreSignature<N>,
+        address: &Address<N>,
+        adaptor_point: &Group<N>,
+        message: &[Field<N>],
+    ) -> bool {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            eprintln!("Cannot verify the pre-signature: the signed message exceeds maximum allowed size");
+            return false;
+        }
+        if adaptor_point.is_zero() {
+            return false;
+        }
+
+        // Retrieve pk_sig.
+        let pk_sig = pre_signature.compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = pre_signature.compute_key.pr_sig();
+
+        // Compute `g_r` := (response * G) + (challenge * pk_sig) + T.
+        let g_r = N::g_scalar_multiply(&pre_signature.response) + (pk_sig * pre_signature.challenge) + adaptor_point;
+
+        // Construct the hash input as (r * G + T, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([g_r, pk_sig, pr_sig, **address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Hash to derive the verifier challenge, and return `false` if this operation fails.
+        let candidate_challenge = match N::hash_to_scalar_psd8(&preimage) {
+            Ok(candidate_challenge) => candidate_challenge,
+            Err(_) => return false,
+        };
+
+        // Derive the address from the compute key, and return `false` if this operation fails.
+        let candidate_address = match Address::try_from(pre_signature.compute_key) {
+            Ok(candidate_address) => candidate_address,
+            Err(_) => return false,
+        };
+
+        // Return `true` if the candidate challenge and address are correct.
+        pre_signature.challenge == candidate_challenge && *address == candidate_address
+    }
+
+    /// Completes the pre-signature into a signature with the adaptor secret `t`, where:
+    ///     response := response + t
+    pub fn adaptor_complete(pre_signature: &AdaptorPreSignature<N>, adaptor_secret: &Scalar<N>) -> Self {
+        Self {
+            challenge: pre_signature.challenge,
+            response: pre_signature.response + adaptor_secret,
+            compute_key: pre_signature.compute_key,
+        }
+    }
+
+    /// Returns the adaptor secret `t := response - response'` from this signature, which completes
+    /// the given pre-signature, and ensures that `t * G` is the given adaptor point.
+    pub fn adaptor_extract(
+        &self,
+        pre_signature: &AdaptorPreSignature<N>,
+        adaptor_point: &Group<N>,
+    ) -> Result<Scalar<N>> {
+        ensure!(
+            self.challenge == pre_signature.challenge && self.compute_key == pre_signature.compute_key,
+            "The signature does not complete the pre-signature"
+        );
+
+        // Extract the adaptor secret.
+        let adaptor_secret = self.response - pre_signature.response;
+        ensure!(
+            N::g_scalar_multiply(&adaptor_secret) == *adaptor_point,
+            "The extracted adaptor secret does not match the adaptor point"
+        );
+        Ok(adaptor_secret)
+    }
+}
+
+#[cfg(all(test, feature = "private_key"))]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_adaptor_signature() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample the signer and the adaptor secret.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+            let adaptor_secret = Scalar::rand(rng);
+            let adaptor_point = CurrentNetwork::g_scalar_multiply(&adaptor_secret);
+
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+
+            // Check that the pre-signature is valid, but is not a valid signature.
+            let pre_signature = Signature::adaptor_sign(&private_key, &adaptor_point, &message, rng)?;
+            assert!(Signature::adaptor_verify(&pre_signature, &address, &adaptor_point, &message));
+            let signature =
+                Signature::from((pre_signature.challenge(), pre_signature.response(), pre_signature.compute_key()));
+            assert!(!signature.verify(&address, &message));
+
+            // Check that the completed signature verifies normally.
+            let signature = Signature::adaptor_complete(&pre_signature, &adaptor_secret);
+            assert!(signature.verify(&address, &message));
+
+            // Check that the adaptor secret is extracted from the completed signature.
+            assert_eq!(signature.adaptor_extract(&pre_signature, &adaptor_point)?, adaptor_secret);
+
+            // Check that the pre-signature is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..i + 1).map(|_| Uniform::rand(rng)).collect();
+            assert!(!Signature::adaptor_verify(&pre_signature, &address, &adaptor_point, &failure_message));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_adaptor_signature() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let adaptor_secret = Scalar::rand(rng);
+        let adaptor_point = CurrentNetwork::g_scalar_multiply(&adaptor_secret);
+        let message: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
+
+        let pre_signature = Signature::adaptor_sign(&private_key, &adaptor_point, &message, rng)?;
+
+        // Check that the pre-signature is invalid for another adaptor point.
+        let other_point = CurrentNetwork::g_scalar_multiply(&Scalar::rand(rng));
+        assert!(!Signature::adaptor_verify(&pre_signature, &address, &other_point, &message));
+        assert!(!Signature::adaptor_verify(&pre_signature, &address, &Group::zero(), &message));
+
+        // Check that an incorrect adaptor secret yields an invalid signature.
+        let signature = Signature::adaptor_complete(&pre_signature, &Scalar::rand(rng));
+        assert!(!signature.verify(&address, &message));
+        assert!(signature.adaptor_extract(&pre_signature, &adaptor_point).is_err());
+
+        // Check that a signature for another pre-signature is rejected.
+        let other_pre_signature = Signature::adaptor_sign(&private_key, &adaptor_point, &message, rng)?;
+        let signature = Signature::adaptor_complete(&other_pre_signature, &adaptor_secret);
+        assert!(signature.adaptor_extract(&pre_signature, &adaptor_point).is_err());
+        Ok(())
+    }
+}
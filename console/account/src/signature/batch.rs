@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_types::Group;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+impl<N: Network> Signature<N> {
+    /// Returns `true` if each signature is valid for its address and message.
+    ///
+    /// As a signature contains the challenge (and not `g_r`), each `g_r` must be recomputed to derive
+    /// its challenge, so the signatures cannot be folded into a single multi-scalar multiplication.
+    /// Instead, this is faster than verifying each signature separately, as the scalar multiplications
+    /// run in parallel, and each `g_r` is normalized with a single inversion.
+    pub fn batch_verify(messages: &[Vec<Field<N>>], addresses: &[Address<N>], signatures: &[Self]) -> bool {
+        // Ensure there is one message and one address for each signature.
+        if messages.len() != signatures.len() || addresses.len() != signatures.len() {
+            eprintln!(
+                "Cannot verify the signatures: found {} messages and {} addresses for {} signatures",
+                messages.len(),
+                addresses.len(),
+                signatures.len()
+            );
+            return false;
+        }
+
+        // Ensure the number of field elements in each message does not exceed the maximum allowed size.
+        if messages.iter().any(|message| message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize) {
+            eprintln!("Cannot verify the signatures: a signed message exceeds maximum allowed size");
+            return false;
+        }
+
+        // Compute `g_r` := (response * G) + (challenge * pk_sig) for each signature.
+        let g_r = cfg_iter!(signatures)
+            .map(|signature| {
+                N::g_scalar_multiply(&signature.response) + (signature.compute_key.pk_sig() * signature.challenge)
+            })
+            .collect::<Vec<_>>();
+        let g_r_x_coordinates = Group::batch_to_x_coordinates(&g_r);
+
+        cfg_iter!(signatures)
+            .zip_eq(cfg_iter!(addresses))
+            .zip_eq(cfg_iter!(messages))
+            .zip_eq(cfg_iter!(g_r_x_coordinates))
+            .all(|(((signature, address), message), g_r_x_coordinate)| {
+                // Retrieve pk_sig.
+                let pk_sig = signature.compute_key.pk_sig();
+                // Retrieve pr_sig.
+                let pr_sig = signature.compute_key.pr_sig();
+
+                // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+                let mut preimage = Vec::with_capacity(4 + message.len());
+                preimage.push(*g_r_x_coordinate);
+                preimage.extend([pk_sig, pr_sig, **address].map(|point| point.to_x_coordinate()));
+                preimage.extend(message);
+
+                // Hash to derive the verifier challenge, and return `false` if this operation fails.
+                let candidate_challenge = match N::hash_to_scalar_psd8(&preimage) {
+                    Ok(candidate_challenge) => candidate_challenge,
+                    Err(_) => return false,
+                };
+
+                // Derive the address from the compute key, and return `false` if this operation fails.
+                let candidate_address = match Address::try_from(signature.compute_key) {
+                    Ok(candidate_address) => candidate_address,
+                    Err(_) => return false,
+                };
+
+                // Return `true` if the candidate challenge and address are correct.
+                signature.challenge == candidate_challenge && *address == candidate_address
+            })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_batch_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for num_signatures in [0, 1, 10] {
+            let mut messages = Vec::with_capacity(num_signatures);
+            let mut addresses = Vec::with_capacity(num_signatures);
+            let mut signatures = Vec::with_capacity(num_signatures);
+            for i in 0..num_signatures {
+                // Sample an address and a private key.
+                let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+                let address = Address::try_from(&private_key)?;
+
+                // Sign a message.
+                let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+                signatures.push(Signature::sign(&private_key, &message, rng)?);
+                messages.push(message);
+                addresses.push(address);
+            }
+
+            // Check that the batch matches the individual verification.
+            for ((signature, address), message) in signatures.iter().zip(&addresses).zip(&messages) {
+                assert!(signature.verify(address, message));
+            }
+            assert!(Signature::batch_verify(&messages, &addresses, &signatures));
+
+            if num_signatures > 0 {
+                // Check that the batch is invalid if any message is incorrect.
+                let mut failure_messages = messages.clone();
+                failure_messages[num_signatures - 1].push(Uniform::rand(rng));
+                assert!(!Signature::batch_verify(&failure_messages, &addresses, &signatures));
+
+                // Check that the batch is invalid if any address is incorrect.
+                let mut failure_addresses = addresses.clone();
+                failure_addresses[0] = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+                assert!(!Signature::batch_verify(&messages, &failure_addresses, &signatures));
+
+                // Check that the batch is invalid if the lengths do not match.
+                assert!(!Signature::batch_verify(&messages[1..], &addresses, &signatures));
+                assert!(!Signature::batch_verify(&messages, &addresses, &signatures[1..]));
+            }
+        }
+        Ok(())
+    }
+}
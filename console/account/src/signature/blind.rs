@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Secret;
+use snarkvm_console_types::Group;
+
+/// The secret nonce of the signer for one blind signing session, which is consumed by the blind response.
+pub struct BlindSecretNonce<N: Network> {
+    /// The nonce.
+    nonce: Secret<Scalar<N>>,
+}
+
+/// The nonce commitment `nonce * G` that the signer sends to the requester in the first round of a blind signing session.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BlindNonceCommitment<N: Network> {
+    /// The commitment to the nonce.
+    g_r: Group<N>,
+}
+
+/// The blinded challenge that the requester sends to the signer in the second round of a blind signing session.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BlindChallenge<N: Network> {
+    /// The blinded challenge.
+    challenge: Scalar<N>,
+}
+
+/// The blind response that the signer sends to the requester in the third round of a blind signing session.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BlindResponse<N: Network> {
+    /// The blinded response.
+    response: Scalar<N>,
+}
+
+/// The secret state of the requester for one blind signing session, which is consumed to unblind the response.
+pub struct BlindingFactors<N: Network> {
+    /// The blinding factor of the nonce.
+    alpha: Secret<Scalar<N>>,
+    /// The unblinded challenge.
+    challenge: Scalar<N>,
+    /// The blinded challenge sent to the signer.
+    blinded_challenge: Scalar<N>,
+    /// The nonce commitment of the signer.
+    g_r: Group<N>,
+    /// The compute key of the signer.
+    compute_key: ComputeKey<N>,
+}
+
+impl<N: Network> Signature<N> {
+    /// Returns the secret nonce and the nonce commitment of the signer for a new blind signing session.
+    ///
+    /// The signer must complete each session before starting the next one, as answering many
+    /// sessions concurrently allows the requester to forge an additional signature (the ROS attack).
+    pub fn blind_nonce<R: Rng + CryptoRng>(rng: &mut R) -> (BlindSecretNonce<N>, BlindNonceCommitment<N>) {
+        let nonce = Secret::new(Scalar::rand(rng));
+        let g_r = N::g_scalar_multiply(&nonce);
+        (BlindSecretNonce { nonce }, BlindNonceCommitment { g_r })
+    }
+
+    /// Returns the blinding factors of the requester and the blinded challenge for the given message, where:
+    ///     g_r' := g_r + alpha * G + beta * pk_sig
+    ///     challenge' := HashToScalar(g_r', pk_sig, pr_sig, address, message)
+    ///     challenge := challenge' - beta
+    ///
+    /// The blinded challenge reveals nothing to the signer about the message or the unblinded signature.
+    pub fn blind_challenge<R: Rng + CryptoRng>(
+        compute_key: &ComputeKey<N>,
+        nonce_commitment: &BlindNonceCommitment<N>,
+        message: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<(BlindingFactors<N>, BlindChallenge<N>)> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        ensure!(!nonce_commitment.g_r.is_zero(), "The nonce commitment must not be the identity");
+
+        // Sample the blinding factors.
+        let alpha = Secret::new(Scalar::rand(rng));
+        let beta = Secret::new(Scalar::rand(rng));
+
+        // Retrieve pk_sig.
+        let pk_sig = compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = compute_key.pr_sig();
+        // Derive the address from the compute key.
+        let address = Address::try_from(compute_key)?;
+
+        // Blind the nonce commitment.
+        let g_r = nonce_commitment.g_r + N::g_scalar_multiply(&alpha) + (pk_sig * *beta);
+        ensure!(!g_r.is_zero(), "The blinded nonce commitment must not be the identity");
+
+        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([g_r, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Compute the verifier challenge, and blind it.
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+        let blinded_challenge = challenge - *beta;
+
+        let blinding_factors = BlindingFactors {
+            alpha,
+            challenge,
+            blinded_challenge,
+            g_r: nonce_commitment.g_r,
+            compute_key: *compute_key,
+        };
+        Ok((blinding_factors, BlindChallenge { challenge: blinded_challenge }))
+    }
+
+    /// Returns the blind response of the signer to the given blinded challenge, where:
+    ///     response := nonce - challenge * private_key.sk_sig()
+    #[cfg(feature = "private_key")]
+    pub fn blind_sign(
+        private_key: &PrivateKey<N>,
+        secret_nonce: BlindSecretNonce<N>,
+        blinded_challenge: &BlindChallenge<N>,
+    ) -> BlindResponse<N> {
+        BlindResponse { response: *secret_nonce.nonce - (blinded_challenge.challenge * private_key.sk_sig()) }
+    }
+
+    /// Unblinds the blind response of the signer into a signature, which verifies under the address
+    /// of the signer, where:
+    ///     response' := response + alpha
+    pub fn blind_unblind(blinding_factors: BlindingFactors<N>, blind_response: &BlindResponse<N>) -> Result<Self> {
+        // Ensure the blind response is valid, where:
+        //     response * G + challenge * pk_sig == g_r
+        let pk_sig = blinding_factors.compute_key.pk_sig();
+        ensure!(
+            N::g_scalar_multiply(&blind_response.response) + (pk_sig * blinding_factors.blinded_challenge)
+                == blinding_factors.g_r,
+            "The blind response is invalid"
+        );
+
+        // Unblind the response.
+        let response = blind_response.response + *blinding_factors.alpha;
+        Ok(Self { challenge: blinding_factors.challenge, response, compute_key: blinding_factors.compute_key })
+    }
+}
+
+#[cfg(all(test, feature = "private_key"))]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_blind_signature() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample the signer.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+
+            // Run the blind signing session.
+            let (secret_nonce, nonce_commitment) = Signature::blind_nonce(rng);
+            let (blinding_factors, blinded_challenge) =
+                Signature::blind_challenge(&compute_key, &nonce_commitment, &message, rng)?;
+            let blind_response = Signature::blind_sign(&private_key, secret_nonce, &blinded_challenge);
+            let signature = Signature::blind_unblind(blinding_factors, &blind_response)?;
+
+            // Check that the unblinded signature verifies normally.
+            assert!(signature.verify(&address, &message));
+            assert_eq!(signature.compute_key(), compute_key);
+
+            // Check that the signature is unlinkable to the values seen by the signer.
+            assert_ne!(signature.challenge(), blinded_challenge.challenge);
+            assert_ne!(signature.response(), blind_response.response);
+
+            // Check that the signature is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..i + 1).map(|_| Uniform::rand(rng)).collect();
+            assert!(!signature.verify(&address, &failure_message));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_blind_response() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let compute_key = ComputeKey::try_from(&private_key)?;
+        let message: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
+
+        // Check that a modified blind response is rejected.
+        let (secret_nonce, nonce_commitment) = Signature::blind_nonce(rng);
+        let (blinding_factors, blinded_challenge) =
+            Signature::blind_challenge(&compute_key, &nonce_commitment, &message, rng)?;
+        let mut blind_response = Signature::blind_sign(&private_key, secret_nonce, &blinded_challenge);
+        blind_response.response += Scalar::one();
+        assert!(Signature::blind_unblind(blinding_factors, &blind_response).is_err());
+
+        // Check that a blind response from another signer is rejected.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let (secret_nonce, nonce_commitment) = Signature::blind_nonce(rng);
+        let (blinding_factors, blinded_challenge) =
+            Signature::blind_challenge(&compute_key, &nonce_commitment, &message, rng)?;
+        let blind_response = Signature::blind_sign(&other_private_key, secret_nonce, &blinded_challenge);
+        assert!(Signature::blind_unblind(blinding_factors, &blind_response).is_err());
+        Ok(())
+    }
+}
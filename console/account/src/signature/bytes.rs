@@ -35,6 +35,10 @@ impl<N: Network> ToBytes for Signature<N> {
     }
 }
 
+impl<N: Network> ConstSerializedSize for Signature<N> {
+    const SIZE: usize = 2 * Scalar::<N>::SIZE + ComputeKey::<N>::SIZE;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,6 +60,13 @@ mod tests {
             let signature_bytes = signature.to_bytes_le()?;
             assert_eq!(signature, Signature::read_le(&signature_bytes[..])?);
             assert!(Signature::<CurrentNetwork>::read_le(&signature_bytes[1..]).is_err());
+
+            // Check the fixed-size byte representation.
+            assert_eq!(signature_bytes.len(), Signature::<CurrentNetwork>::SIZE);
+            let signature_array = signature.to_bytes_le_array::<{ Signature::<CurrentNetwork>::SIZE }>()?;
+            assert_eq!(signature_bytes, signature_array);
+            assert_eq!(signature, Signature::from_bytes_le_array(&signature_array)?);
+            assert!(signature.to_bytes_le_array::<64>().is_err());
         }
         Ok(())
     }
@@ -0,0 +1,645 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Secret;
+use snarkvm_console_types::Group;
+
+use zeroize::Zeroize;
+
+const FROST_KEY_GENERATION_DOMAIN: DomainSeparator = DomainSeparator::new("FrostKeyGeneration", 0);
+const FROST_BINDING_DOMAIN: DomainSeparator = DomainSeparator::new("FrostBinding", 0);
+
+/// The secret state of a party between the two rounds of the distributed key generation.
+pub struct FrostKeyGenerationSecret<N: Network> {
+    /// The index of the party, starting from 1.
+    index: u32,
+    /// The number of parties in the committee.
+    num_parties: u32,
+    /// The coefficients of the secret-sharing polynomial of the party.
+    coefficients: Secret<Vec<Scalar<N>>>,
+}
+
+/// The commitment that a party broadcasts in the first round of the distributed key generation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrostKeyGenerationCommitment<N: Network> {
+    /// The index of the party.
+    index: u32,
+    /// The commitment `a_k * G` to each coefficient of the secret-sharing polynomial of the party.
+    commitments: Vec<Group<N>>,
+    /// The contribution of the party to the `pr_sig` of the committee.
+    pr_sig: Group<N>,
+    /// The challenge of the proof of knowledge of the constant term.
+    challenge: Scalar<N>,
+    /// The response of the proof of knowledge of the constant term.
+    response: Scalar<N>,
+}
+
+impl<N: Network> FrostKeyGenerationCommitment<N> {
+    /// Returns the index of the party.
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+/// The share that a party sends privately to another party in the second round of the distributed key generation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Zeroize)]
+pub struct FrostKeyGenerationShare<N: Network> {
+    /// The index of the sending party.
+    sender: u32,
+    /// The index of the receiving party.
+    receiver: u32,
+    /// The evaluation of the secret-sharing polynomial of the sender at the index of the receiver.
+    share: Scalar<N>,
+}
+
+impl<N: Network> FrostKeyGenerationShare<N> {
+    /// Returns the index of the receiving party.
+    pub const fn receiver(&self) -> u32 {
+        self.receiver
+    }
+}
+
+/// The signing share of a party, which is the evaluation of the shared `sk_sig` at the index of the party.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Zeroize)]
+pub struct FrostSigningShare<N: Network> {
+    /// The index of the party.
+    index: u32,
+    /// The share of `sk_sig`.
+    share: Scalar<N>,
+}
+
+impl<N: Network> FrostSigningShare<N> {
+    /// Returns the index of the party.
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+/// The public parameters of an account whose `sk_sig` is shared among a threshold committee.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrostGroupKey<N: Network> {
+    /// The number of signing shares required to sign a message.
+    threshold: u32,
+    /// The compute key of the account.
+    compute_key: ComputeKey<N>,
+    /// The address of the account, under which the signatures verify.
+    address: Address<N>,
+    /// The verification key of each party, `s_i * G`, ordered by the index of the party.
+    verification_keys: Vec<Group<N>>,
+}
+
+impl<N: Network> FrostGroupKey<N> {
+    /// Returns the number of signing shares required to sign a message.
+    pub const fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Returns the compute key of the account.
+    pub const fn compute_key(&self) -> &ComputeKey<N> {
+        &self.compute_key
+    }
+
+    /// Returns the address of the account.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+
+    /// Returns the number of parties in the committee.
+    pub fn num_parties(&self) -> usize {
+        self.verification_keys.len()
+    }
+
+    /// Returns the verification key of the party with the given index, if it exists.
+    pub fn verification_key(&self, index: u32) -> Option<&Group<N>> {
+        (index as usize).checked_sub(1).and_then(|i| self.verification_keys.get(i))
+    }
+}
+
+/// The secret nonces of a signer for one signing session, which are consumed by the signature share.
+pub struct FrostSecretNonce<N: Network> {
+    /// The index of the signer.
+    index: u32,
+    /// The hiding nonce.
+    d: Secret<Scalar<N>>,
+    /// The binding nonce.
+    e: Secret<Scalar<N>>,
+}
+
+/// The nonce commitment that a signer sends to the other signers in the first round of a signing session.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FrostNonceCommitment<N: Network> {
+    /// The index of the signer.
+    index: u32,
+    /// The commitment `d * G` to the hiding nonce.
+    g_d: Group<N>,
+    /// The commitment `e * G` to the binding nonce.
+    g_e: Group<N>,
+}
+
+impl<N: Network> FrostNonceCommitment<N> {
+    /// Returns the index of the signer.
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+/// The signature share that a signer sends to the aggregator in the second round of a signing session.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FrostSignatureShare<N: Network> {
+    /// The index of the signer.
+    index: u32,
+    /// The partial response of the signer.
+    response: Scalar<N>,
+}
+
+impl<N: Network> FrostSignatureShare<N> {
+    /// Returns the index of the signer.
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+impl<N: Network> Signature<N> {
+    /// Returns the secret state and the broadcast commitment of the party with the given index,
+    /// in the first round of a distributed key generation among `num_parties` parties,
+    /// of which any `threshold` can sign a message.
+    pub fn frost_key_generation_commit<R: Rng + CryptoRng>(
+        index: u32,
+        num_parties: u32,
+        threshold: u32,
+        rng: &mut R,
+    ) -> Result<(FrostKeyGenerationSecret<N>, FrostKeyGenerationCommitment<N>)> {
+        ensure!(
+            threshold > 0 && threshold <= num_parties,
+            "The threshold ({threshold}) must be between 1 and the number of parties ({num_parties})"
+        );
+        ensure!(index > 0 && index <= num_parties, "The index ({index}) must be between 1 and {num_parties}");
+
+        // Sample the secret-sharing polynomial, and commit to its coefficients.
+        let coefficients = Secret::new((0..threshold).map(|_| Scalar::<N>::rand(rng)).collect::<Vec<_>>());
+        let commitments = coefficients.iter().map(N::g_scalar_multiply).collect::<Vec<_>>();
+        // Sample the contribution to `pr_sig`, whose discrete logarithm is not needed to sign.
+        let pr_sig = N::g_scalar_multiply(&Scalar::rand(rng));
+
+        // Prove knowledge of the constant term, which prevents rogue-key attacks.
+        let randomizer = Secret::new(Scalar::rand(rng));
+        let challenge =
+            Self::frost_key_generation_challenge(index, &commitments, &pr_sig, &N::g_scalar_multiply(&randomizer))?;
+        let response = *randomizer - challenge * coefficients[0];
+
+        let commitment = FrostKeyGenerationCommitment { index, commitments, pr_sig, challenge, response };
+        Ok((FrostKeyGenerationSecret { index, num_parties, coefficients }, commitment))
+    }
+
+    /// Returns the share of the given party for every party in the committee (including itself),
+    /// in the second round of the distributed key generation. Each share must be sent privately to its receiver.
+    pub fn frost_key_generation_shares(secret: &FrostKeyGenerationSecret<N>) -> Vec<FrostKeyGenerationShare<N>> {
+        (1..=secret.num_parties)
+            .map(|receiver| {
+                let x = Self::frost_scalar_from_index(receiver);
+                let share =
+                    secret.coefficients.iter().rev().fold(Scalar::zero(), |acc, coefficient| acc * x + coefficient);
+                FrostKeyGenerationShare { sender: secret.index, receiver, share }
+            })
+            .collect()
+    }
+
+    /// Returns the signing share of the given party and the group key of the committee, from the commitments
+    /// of all parties (ordered by index) and the shares that the party received from all parties (ordered by sender).
+    pub fn frost_key_generation_finalize(
+        secret: &FrostKeyGenerationSecret<N>,
+        commitments: &[FrostKeyGenerationCommitment<N>],
+        shares: &[FrostKeyGenerationShare<N>],
+    ) -> Result<(FrostSigningShare<N>, FrostGroupKey<N>)> {
+        let num_parties = secret.num_parties as usize;
+        let threshold = secret.coefficients.len();
+        ensure!(commitments.len() == num_parties, "Expected {num_parties} commitments, found {}", commitments.len());
+        ensure!(shares.len() == num_parties, "Expected {num_parties} shares, found {}", shares.len());
+
+        // Ensure the commitments are well-formed, and the proof of knowledge of each party is valid.
+        for (i, commitment) in commitments.iter().enumerate() {
+            let index = commitment.index;
+            ensure!(index as usize == i + 1, "Expected the commitment of party {}, found party {index}", i + 1);
+            ensure!(commitment.commitments.len() == threshold, "The commitment of party {index} has the wrong degree");
+            let g_randomizer =
+                N::g_scalar_multiply(&commitment.response) + commitment.commitments[0] * commitment.challenge;
+            let candidate = Self::frost_key_generation_challenge(
+                index,
+                &commitment.commitments,
+                &commitment.pr_sig,
+                &g_randomizer,
+            )?;
+            ensure!(candidate == commitment.challenge, "The proof of knowledge of party {index} is invalid");
+        }
+
+        // Ensure each share is consistent with the commitment of its sender.
+        for (commitment, share) in commitments.iter().zip_eq(shares) {
+            let index = commitment.index;
+            ensure!(share.sender == index, "Expected the share of party {index}, found party {}", share.sender);
+            ensure!(share.receiver == secret.index, "The share of party {index} is for another party");
+            ensure!(
+                N::g_scalar_multiply(&share.share)
+                    == Self::frost_evaluate_commitments(&commitment.commitments, secret.index),
+                "The share of party {index} is inconsistent with its commitment"
+            );
+        }
+
+        // Compute the signing share of the party.
+        let signing_share =
+            FrostSigningShare { index: secret.index, share: shares.iter().map(|share| share.share).sum() };
+
+        // Compute the verification key of each party, and the compute key of the committee.
+        let verification_keys = (1..=secret.num_parties)
+            .map(|index| {
+                commitments
+                    .iter()
+                    .map(|commitment| Self::frost_evaluate_commitments(&commitment.commitments, index))
+                    .sum()
+            })
+            .collect::<Vec<Group<N>>>();
+        let pk_sig = commitments.iter().map(|commitment| commitment.commitments[0]).sum::<Group<N>>();
+        let pr_sig = commitments.iter().map(|commitment| commitment.pr_sig).sum::<Group<N>>();
+        let compute_key = ComputeKey::try_from((pk_sig, pr_sig))?;
+        let address = Address::try_from(compute_key)?;
+
+        let group_key = FrostGroupKey { threshold: u32::try_from(threshold)?, compute_key, address, verification_keys };
+        Ok((signing_share, group_key))
+    }
+
+    /// Returns the secret nonces and the nonce commitment of a signer for a new signing session.
+    ///
+    /// The secret nonces must only be used for one signature share, and must not be reused.
+    pub fn frost_nonces<R: Rng + CryptoRng>(
+        signing_share: &FrostSigningShare<N>,
+        rng: &mut R,
+    ) -> (FrostSecretNonce<N>, FrostNonceCommitment<N>) {
+        let d = Secret::new(Scalar::rand(rng));
+        let e = Secret::new(Scalar::rand(rng));
+        let index = signing_share.index;
+        let commitment = FrostNonceCommitment { index, g_d: N::g_scalar_multiply(&d), g_e: N::g_scalar_multiply(&e) };
+        (FrostSecretNonce { index, d, e }, commitment)
+    }
+
+    /// Returns the signature share of the given signer for the given message, where:
+    ///     response_i := d_i + rho_i * e_i - challenge * lambda_i * s_i
+    ///
+    /// The nonce commitments of the signers of the session must be given in increasing order of index.
+    pub fn frost_sign(
+        signing_share: &FrostSigningShare<N>,
+        group_key: &FrostGroupKey<N>,
+        secret_nonce: FrostSecretNonce<N>,
+        nonce_commitments: &[FrostNonceCommitment<N>],
+        message: &[Field<N>],
+    ) -> Result<FrostSignatureShare<N>> {
+        let index = signing_share.index;
+        ensure!(secret_nonce.index == index, "The secret nonce belongs to another signer");
+
+        // Ensure the nonce commitment of the signer matches its secret nonces.
+        let position = match nonce_commitments.iter().position(|commitment| commitment.index == index) {
+            Some(position) => position,
+            None => bail!("Signer {index} is not a signer of the session"),
+        };
+        let nonce_commitment = FrostNonceCommitment {
+            index,
+            g_d: N::g_scalar_multiply(&secret_nonce.d),
+            g_e: N::g_scalar_multiply(&secret_nonce.e),
+        };
+        ensure!(
+            nonce_commitments[position] == nonce_commitment,
+            "The nonce commitment of signer {index} does not match"
+        );
+
+        // Compute the binding factors, the Lagrange coefficients, and the challenge of the session.
+        let (binding_factors, lagrange_coefficients, challenge) =
+            Self::frost_session(group_key, nonce_commitments, message)?;
+
+        let response = *secret_nonce.d + binding_factors[position] * *secret_nonce.e
+            - challenge * lagrange_coefficients[position] * signing_share.share;
+        Ok(FrostSignatureShare { index, response })
+    }
+
+    /// Returns `true` if the given signature share was produced by its signer for the given message, where:
+    ///     response_i * G + (challenge * lambda_i) * Y_i == g_d_i + rho_i * g_e_i
+    pub fn frost_verify_share(
+        group_key: &FrostGroupKey<N>,
+        signature_share: &FrostSignatureShare<N>,
+        nonce_commitments: &[FrostNonceCommitment<N>],
+        message: &[Field<N>],
+    ) -> bool {
+        let index = signature_share.index;
+        let (Some(verification_key), Some(position)) = (
+            group_key.verification_key(index),
+            nonce_commitments.iter().position(|commitment| commitment.index == index),
+        ) else {
+            return false;
+        };
+        let Ok((binding_factors, lagrange_coefficients, challenge)) =
+            Self::frost_session(group_key, nonce_commitments, message)
+        else {
+            return false;
+        };
+
+        let nonce_commitment = &nonce_commitments[position];
+        N::g_scalar_multiply(&signature_share.response)
+            + *verification_key * (challenge * lagrange_coefficients[position])
+            == nonce_commitment.g_d + nonce_commitment.g_e * binding_factors[position]
+    }
+
+    /// Combines the signature shares of the signers of a session into a signature, which verifies under
+    /// the address of the group key. Every signature share is verified against the group key.
+    ///
+    /// The nonce commitments and signature shares must be given in increasing order of index.
+    pub fn frost_aggregate(
+        group_key: &FrostGroupKey<N>,
+        nonce_commitments: &[FrostNonceCommitment<N>],
+        signature_shares: &[FrostSignatureShare<N>],
+        message: &[Field<N>],
+    ) -> Result<Self> {
+        ensure!(
+            signature_shares.len() == nonce_commitments.len(),
+            "Expected {} signature shares, found {}",
+            nonce_commitments.len(),
+            signature_shares.len()
+        );
+
+        // Ensure the signature shares are valid, and from the signers of the session.
+        for (commitment, signature_share) in nonce_commitments.iter().zip_eq(signature_shares) {
+            let index = signature_share.index;
+            ensure!(commitment.index == index, "Expected the signature share of signer {}", commitment.index);
+            ensure!(
+                Self::frost_verify_share(group_key, signature_share, nonce_commitments, message),
+                "The signature share of signer {index} is invalid"
+            );
+        }
+
+        // Compute the challenge of the session, and the response as the sum of the partial responses.
+        let (_, _, challenge) = Self::frost_session(group_key, nonce_commitments, message)?;
+        let response = signature_shares.iter().map(|signature_share| signature_share.response).sum();
+        Ok(Self { challenge, response, compute_key: group_key.compute_key })
+    }
+
+    /// Returns the binding factor and Lagrange coefficient of each signer, and the challenge of a session, where:
+    ///     rho_i := HashToScalar(HashPSD8(commitments, address, message), i)
+    ///     g_r := sum(g_d_i + rho_i * g_e_i)
+    ///     challenge := HashToScalar(g_r, pk_sig, pr_sig, address, message)
+    fn frost_session(
+        group_key: &FrostGroupKey<N>,
+        nonce_commitments: &[FrostNonceCommitment<N>],
+        message: &[Field<N>],
+    ) -> Result<(Vec<Scalar<N>>, Vec<Scalar<N>>, Scalar<N>)> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        ensure!(
+            nonce_commitments.len() >= group_key.threshold as usize,
+            "Expected at least {} signers, found {}",
+            group_key.threshold,
+            nonce_commitments.len()
+        );
+        // Ensure the signers are members of the committee, in increasing order of index.
+        ensure!(
+            nonce_commitments.windows(2).all(|pair| pair[0].index < pair[1].index),
+            "The signers must be distinct, and in increasing order of index"
+        );
+        ensure!(
+            nonce_commitments.iter().all(|commitment| group_key.verification_key(commitment.index).is_some()),
+            "The signers must be members of the committee"
+        );
+
+        // Retrieve pk_sig.
+        let pk_sig = group_key.compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = group_key.compute_key.pr_sig();
+        // Retrieve the address.
+        let address = *group_key.address;
+
+        // Hash the nonce commitments, the address, and the message.
+        let mut preimage = Vec::with_capacity(2 + 3 * nonce_commitments.len() + message.len());
+        preimage.push(Field::from_domain_separator(FROST_BINDING_DOMAIN));
+        for commitment in nonce_commitments {
+            preimage.push(Field::from_u32(commitment.index));
+            preimage.extend([commitment.g_d, commitment.g_e].map(|point| point.to_x_coordinate()));
+        }
+        preimage.push(address.to_x_coordinate());
+        preimage.extend(message);
+        let session = N::hash_psd8(&preimage)?;
+
+        // Compute the binding factor of each signer, and the aggregate nonce.
+        let binding_factors = nonce_commitments
+            .iter()
+            .map(|commitment| N::hash_to_scalar_psd2(&[session, Field::from_u32(commitment.index)]))
+            .collect::<Result<Vec<_>>>()?;
+        let g_r = nonce_commitments
+            .iter()
+            .zip_eq(&binding_factors)
+            .map(|(commitment, binding_factor)| commitment.g_d + commitment.g_e * binding_factor)
+            .sum::<Group<N>>();
+        ensure!(!g_r.is_zero(), "The aggregate nonce must not be the identity");
+
+        // Compute the Lagrange coefficient at zero of each signer, `prod_{j != i} x_j / (x_j - x_i)`.
+        let lagrange_coefficients = nonce_commitments
+            .iter()
+            .map(|commitment| {
+                let x_i = Self::frost_scalar_from_index(commitment.index);
+                let mut numerator = Scalar::<N>::one();
+                let mut denominator = Scalar::<N>::one();
+                for other in nonce_commitments.iter().filter(|other| other.index != commitment.index) {
+                    let x_j = Self::frost_scalar_from_index(other.index);
+                    numerator *= x_j;
+                    denominator *= x_j - x_i;
+                }
+                Ok(numerator * denominator.inverse()?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([g_r, pk_sig, pr_sig, address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Compute the verifier challenge.
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+        Ok((binding_factors, lagrange_coefficients, challenge))
+    }
+
+    /// Returns the challenge of the proof of knowledge of a party in the distributed key generation.
+    fn frost_key_generation_challenge(
+        index: u32,
+        commitments: &[Group<N>],
+        pr_sig: &Group<N>,
+        g_randomizer: &Group<N>,
+    ) -> Result<Scalar<N>> {
+        let mut preimage = vec![Field::from_domain_separator(FROST_KEY_GENERATION_DOMAIN), Field::from_u32(index)];
+        preimage.extend(commitments.iter().chain([pr_sig, g_randomizer]).map(|point| point.to_x_coordinate()));
+        N::hash_to_scalar_psd8(&preimage)
+    }
+
+    /// Returns `sum(C_k * x^k)` for the given polynomial commitments at the index of the given party.
+    fn frost_evaluate_commitments(commitments: &[Group<N>], index: u32) -> Group<N> {
+        let x = Self::frost_scalar_from_index(index);
+        commitments.iter().rev().fold(Group::zero(), |acc, commitment| acc * x + commitment)
+    }
+
+    /// Returns the evaluation point of the party with the given index.
+    fn frost_scalar_from_index(index: u32) -> Scalar<N> {
+        Scalar::new(N::Scalar::from(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const NUM_PARTIES: u32 = 5;
+    const THRESHOLD: u32 = 3;
+
+    /// Runs the distributed key generation, and returns the signing share of each party and the group key.
+    fn sample_committee(
+        rng: &mut TestRng,
+    ) -> Result<(Vec<FrostSigningShare<CurrentNetwork>>, FrostGroupKey<CurrentNetwork>)> {
+        // In the first round, each party broadcasts its commitment.
+        let (secrets, commitments): (Vec<_>, Vec<_>) = (1..=NUM_PARTIES)
+            .map(|index| Signature::frost_key_generation_commit(index, NUM_PARTIES, THRESHOLD, rng))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        // In the second round, each party sends a share to each party.
+        let shares = secrets.iter().map(Signature::frost_key_generation_shares).collect_vec();
+
+        // Each party derives its signing share and the group key.
+        let mut signing_shares = Vec::with_capacity(NUM_PARTIES as usize);
+        let mut group_keys = Vec::with_capacity(NUM_PARTIES as usize);
+        for (i, secret) in secrets.iter().enumerate() {
+            let received = shares.iter().map(|shares| shares[i]).collect_vec();
+            let (signing_share, group_key) = Signature::frost_key_generation_finalize(secret, &commitments, &received)?;
+            signing_shares.push(signing_share);
+            group_keys.push(group_key);
+        }
+        // Ensure every party derives the same group key.
+        assert!(group_keys.iter().all(|group_key| *group_key == group_keys[0]));
+        Ok((signing_shares, group_keys.swap_remove(0)))
+    }
+
+    /// Signs the given message with the given signers, and returns the nonce commitments and signature shares.
+    fn sign(
+        signers: &[FrostSigningShare<CurrentNetwork>],
+        group_key: &FrostGroupKey<CurrentNetwork>,
+        message: &[Field<CurrentNetwork>],
+        rng: &mut TestRng,
+    ) -> Result<(Vec<FrostNonceCommitment<CurrentNetwork>>, Vec<FrostSignatureShare<CurrentNetwork>>)> {
+        // In the first round, each signer sends its nonce commitment.
+        let (secret_nonces, nonce_commitments): (Vec<_>, Vec<_>) =
+            signers.iter().map(|signing_share| Signature::frost_nonces(signing_share, rng)).unzip();
+
+        // In the second round, each signer sends its signature share.
+        let signature_shares = signers
+            .iter()
+            .zip_eq(secret_nonces)
+            .map(|(signing_share, secret_nonce)| {
+                Signature::frost_sign(signing_share, group_key, secret_nonce, &nonce_commitments, message)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok((nonce_commitments, signature_shares))
+    }
+
+    #[test]
+    fn test_frost() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (signing_shares, group_key) = sample_committee(rng)?;
+        assert_eq!(group_key.num_parties(), NUM_PARTIES as usize);
+        for signing_share in &signing_shares {
+            let verification_key = group_key.verification_key(signing_share.index()).unwrap();
+            assert_eq!(*verification_key, CurrentNetwork::g_scalar_multiply(&signing_share.share));
+        }
+
+        // Check that any `threshold` or more of the parties produce a valid signature.
+        let message: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
+        for signers in [&signing_shares[..3], &signing_shares[2..], &signing_shares[..]] {
+            let (nonce_commitments, signature_shares) = sign(signers, &group_key, &message, rng)?;
+            for signature_share in &signature_shares {
+                assert!(Signature::frost_verify_share(&group_key, signature_share, &nonce_commitments, &message));
+            }
+            let signature = Signature::frost_aggregate(&group_key, &nonce_commitments, &signature_shares, &message)?;
+            assert!(signature.verify(group_key.address(), &message));
+            assert_eq!(signature.compute_key(), *group_key.compute_key());
+
+            // Check that the signature is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
+            assert!(!signature.verify(group_key.address(), &failure_message));
+        }
+
+        // Check that fewer than `threshold` signers are rejected.
+        assert!(sign(&signing_shares[..2], &group_key, &message, rng).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_signature_shares() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (signing_shares, group_key) = sample_committee(rng)?;
+        let message: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
+        let (nonce_commitments, mut signature_shares) = sign(&signing_shares[..3], &group_key, &message, rng)?;
+
+        // Check that a signature share for another message is rejected.
+        let other_message: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
+        assert!(!Signature::frost_verify_share(&group_key, &signature_shares[0], &nonce_commitments, &other_message));
+
+        // Check that signers out of order are rejected.
+        let reversed = nonce_commitments.iter().rev().copied().collect_vec();
+        assert!(!Signature::frost_verify_share(&group_key, &signature_shares[0], &reversed, &message));
+
+        // Check that a modified signature share is rejected.
+        signature_shares[0].response += Scalar::one();
+        assert!(!Signature::frost_verify_share(&group_key, &signature_shares[0], &nonce_commitments, &message));
+        assert!(Signature::frost_aggregate(&group_key, &nonce_commitments, &signature_shares, &message).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_key_generation() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Check that invalid parameters are rejected.
+        assert!(Signature::<CurrentNetwork>::frost_key_generation_commit(1, 3, 0, rng).is_err());
+        assert!(Signature::<CurrentNetwork>::frost_key_generation_commit(1, 3, 4, rng).is_err());
+        assert!(Signature::<CurrentNetwork>::frost_key_generation_commit(0, 3, 2, rng).is_err());
+        assert!(Signature::<CurrentNetwork>::frost_key_generation_commit(4, 3, 2, rng).is_err());
+
+        let (secrets, mut commitments): (Vec<_>, Vec<_>) = (1..=3)
+            .map(|index| Signature::<CurrentNetwork>::frost_key_generation_commit(index, 3, 2, rng))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        let mut shares = secrets.iter().map(|secret| Signature::frost_key_generation_shares(secret)[0]).collect_vec();
+        assert!(Signature::frost_key_generation_finalize(&secrets[0], &commitments, &shares).is_ok());
+
+        // Check that a share that is inconsistent with its commitment is rejected.
+        shares[1].share += Scalar::one();
+        assert!(Signature::frost_key_generation_finalize(&secrets[0], &commitments, &shares).is_err());
+        shares[1].share -= Scalar::one();
+
+        // Check that a commitment with an invalid proof of knowledge is rejected.
+        commitments[2].commitments[0] += Group::generator();
+        assert!(Signature::frost_key_generation_finalize(&secrets[0], &commitments, &shares).is_err());
+        Ok(())
+    }
+}
@@ -12,16 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod adaptor;
+pub use adaptor::AdaptorPreSignature;
+mod batch;
 mod bitwise;
+mod blind;
+pub use blind::{BlindChallenge, BlindNonceCommitment, BlindResponse, BlindSecretNonce, BlindingFactors};
 mod bytes;
 mod from_bits;
+mod frost;
+pub use frost::{
+    FrostGroupKey,
+    FrostKeyGenerationCommitment,
+    FrostKeyGenerationSecret,
+    FrostKeyGenerationShare,
+    FrostNonceCommitment,
+    FrostSecretNonce,
+    FrostSignatureShare,
+    FrostSigningShare,
+};
 mod parse;
+mod prepared;
+pub use prepared::PreparedPublicKey;
 mod serialize;
 mod size_in_bits;
 mod to_bits;
 mod to_fields;
 mod verify;
 
+#[cfg(feature = "private_key")]
+mod musig;
+#[cfg(feature = "private_key")]
+pub use musig::{AggregateKey, PartialSignature, PublicNonce, SecretNonce};
 #[cfg(feature = "private_key")]
 mod sign;
 
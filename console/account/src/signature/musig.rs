@@ -0,0 +1,356 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Secret;
+use snarkvm_console_types::Group;
+
+const MUSIG_KEY_AGGREGATION_DOMAIN: DomainSeparator = DomainSeparator::new("MuSigKeyAggregation", 0);
+const MUSIG_NONCE_DOMAIN: DomainSeparator = DomainSeparator::new("MuSigNonce", 0);
+
+/// The aggregate key of the signers of a multi-signature, which signs as a single account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregateKey<N: Network> {
+    /// The `pk_sig` of each signer, in the order of the signers.
+    pk_sigs: Vec<Group<N>>,
+    /// The key aggregation coefficient of each signer, in the order of the signers.
+    coefficients: Vec<Scalar<N>>,
+    /// The compute key of the aggregate account.
+    compute_key: ComputeKey<N>,
+    /// The address of the aggregate account.
+    address: Address<N>,
+}
+
+impl<N: Network> AggregateKey<N> {
+    /// Aggregates the compute keys of the given signers, where:
+    ///     a_i := HashToScalar(HashPSD8(pk_sig_1, ..., pk_sig_n), pk_sig_i)
+    ///     pk_sig := sum(a_i * pk_sig_i), and pr_sig := sum(a_i * pr_sig_i)
+    ///
+    /// The coefficients bind each key to the set of signers, which prevents rogue-key attacks.
+    pub fn new(compute_keys: &[ComputeKey<N>]) -> Result<Self> {
+        ensure!(!compute_keys.is_empty(), "Cannot aggregate an empty set of compute keys");
+        let pk_sigs = compute_keys.iter().map(|compute_key| compute_key.pk_sig()).collect::<Vec<_>>();
+        ensure!(!has_duplicates(&pk_sigs), "Cannot aggregate a set of compute keys with duplicate signers");
+
+        // Hash the set of signers.
+        let mut preimage = Vec::with_capacity(1 + pk_sigs.len());
+        preimage.push(Field::from_domain_separator(MUSIG_KEY_AGGREGATION_DOMAIN));
+        preimage.extend(pk_sigs.iter().map(|pk_sig| pk_sig.to_x_coordinate()));
+        let signers = N::hash_psd8(&preimage)?;
+
+        // Compute the key aggregation coefficient of each signer.
+        let coefficients = pk_sigs
+            .iter()
+            .map(|pk_sig| N::hash_to_scalar_psd2(&[signers, pk_sig.to_x_coordinate()]))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the aggregate compute key.
+        let (pk_sig, pr_sig) = compute_keys.iter().zip_eq(&coefficients).fold(
+            (Group::zero(), Group::zero()),
+            |(pk_sig, pr_sig), (compute_key, coefficient)| {
+                (pk_sig + compute_key.pk_sig() * *coefficient, pr_sig + compute_key.pr_sig() * *coefficient)
+            },
+        );
+        ensure!(!pk_sig.is_zero(), "The aggregate signature public key must not be the identity");
+        let compute_key = ComputeKey::try_from((pk_sig, pr_sig))?;
+        let address = Address::try_from(compute_key)?;
+
+        Ok(Self { pk_sigs, coefficients, compute_key, address })
+    }
+
+    /// Returns the number of signers.
+    pub fn num_signers(&self) -> usize {
+        self.pk_sigs.len()
+    }
+
+    /// Returns the compute key of the aggregate account.
+    pub const fn compute_key(&self) -> &ComputeKey<N> {
+        &self.compute_key
+    }
+
+    /// Returns the address of the aggregate account, under which the multi-signature verifies.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+}
+
+/// The secret nonces of a signer for one signing session, which are consumed by the partial signature.
+pub struct SecretNonce<N: Network> {
+    /// The first secret nonce.
+    r_1: Secret<Scalar<N>>,
+    /// The second secret nonce.
+    r_2: Secret<Scalar<N>>,
+}
+
+/// The public nonces of a signer for one signing session, which are sent to the other signers in the first round.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PublicNonce<N: Network> {
+    /// The first public nonce `r_1 * G`.
+    g_r_1: Group<N>,
+    /// The second public nonce `r_2 * G`.
+    g_r_2: Group<N>,
+}
+
+/// The partial signature of a signer, which is sent to the aggregator in the second round.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PartialSignature<N: Network> {
+    /// The partial response of the signer.
+    response: Scalar<N>,
+}
+
+impl<N: Network> Signature<N> {
+    /// Returns the secret and public nonces of a signer for a new multi-signature session.
+    ///
+    /// The secret nonces must only be used for one partial signature, and must not be reused.
+    pub fn musig_nonces<R: Rng + CryptoRng>(rng: &mut R) -> (SecretNonce<N>, PublicNonce<N>) {
+        let r_1 = Secret::new(Scalar::rand(rng));
+        let r_2 = Secret::new(Scalar::rand(rng));
+        let public_nonce = PublicNonce { g_r_1: N::g_scalar_multiply(&r_1), g_r_2: N::g_scalar_multiply(&r_2) };
+        (SecretNonce { r_1, r_2 }, public_nonce)
+    }
+
+    /// Returns the partial signature of the given private key for the given message, where:
+    ///     response_i := r_1 + b * r_2 - challenge * a_i * private_key.sk_sig()
+    ///
+    /// The public nonces must be given in the order of the signers of the aggregate key.
+    pub fn musig_partial_sign(
+        private_key: &PrivateKey<N>,
+        aggregate_key: &AggregateKey<N>,
+        secret_nonce: SecretNonce<N>,
+        public_nonces: &[PublicNonce<N>],
+        message: &[Field<N>],
+    ) -> Result<PartialSignature<N>> {
+        // Retrieve the key aggregation coefficient of the signer.
+        let pk_sig = N::g_scalar_multiply(&private_key.sk_sig());
+        let index = match aggregate_key.pk_sigs.iter().position(|candidate| *candidate == pk_sig) {
+            Some(index) => index,
+            None => bail!("The private key is not a signer of the aggregate key"),
+        };
+        let coefficient = aggregate_key.coefficients[index];
+
+        // Ensure the public nonce of the signer matches its secret nonce.
+        let g_r_1 = N::g_scalar_multiply(&secret_nonce.r_1);
+        let g_r_2 = N::g_scalar_multiply(&secret_nonce.r_2);
+        ensure!(
+            public_nonces.get(index) == Some(&PublicNonce { g_r_1, g_r_2 }),
+            "The public nonce of the signer does not match its secret nonce"
+        );
+
+        // Compute the nonce coefficient and the challenge of the session.
+        let (b, _, challenge) = Self::musig_session(aggregate_key, public_nonces, message)?;
+
+        // Compute the partial response.
+        let response = *secret_nonce.r_1 + (b * *secret_nonce.r_2) - (challenge * coefficient * private_key.sk_sig());
+        Ok(PartialSignature { response })
+    }
+
+    /// Returns `true` if the partial signature of the signer at the given index is valid, where:
+    ///     response_i * G + (challenge * a_i) * pk_sig_i == g_r_1_i + b * g_r_2_i
+    pub fn musig_verify_partial(
+        aggregate_key: &AggregateKey<N>,
+        index: usize,
+        partial_signature: &PartialSignature<N>,
+        public_nonces: &[PublicNonce<N>],
+        message: &[Field<N>],
+    ) -> bool {
+        // Retrieve the signer and its public nonce.
+        let (pk_sig, coefficient, public_nonce) =
+            match (aggregate_key.pk_sigs.get(index), aggregate_key.coefficients.get(index), public_nonces.get(index)) {
+                (Some(pk_sig), Some(coefficient), Some(public_nonce)) => (pk_sig, coefficient, public_nonce),
+                _ => return false,
+            };
+
+        // Compute the nonce coefficient and the challenge of the session.
+        let (b, _, challenge) = match Self::musig_session(aggregate_key, public_nonces, message) {
+            Ok(session) => session,
+            Err(_) => return false,
+        };
+
+        N::g_scalar_multiply(&partial_signature.response) + (*pk_sig * (challenge * *coefficient))
+            == public_nonce.g_r_1 + (public_nonce.g_r_2 * b)
+    }
+
+    /// Combines the partial signatures of all signers into a signature, which verifies under the
+    /// address of the aggregate key.
+    ///
+    /// The public nonces and partial signatures must be given in the order of the signers of the aggregate key.
+    pub fn musig_aggregate(
+        aggregate_key: &AggregateKey<N>,
+        public_nonces: &[PublicNonce<N>],
+        partial_signatures: &[PartialSignature<N>],
+        message: &[Field<N>],
+    ) -> Result<Self> {
+        ensure!(
+            partial_signatures.len() == aggregate_key.num_signers(),
+            "Expected {} partial signatures, found {}",
+            aggregate_key.num_signers(),
+            partial_signatures.len()
+        );
+
+        // Compute the challenge of the session.
+        let (_, _, challenge) = Self::musig_session(aggregate_key, public_nonces, message)?;
+        // Compute the response as the sum of the partial responses.
+        let response = partial_signatures.iter().map(|partial_signature| partial_signature.response).sum();
+
+        // Ensure the signature is valid, as any invalid partial signature would invalidate it.
+        let signature = Self { challenge, response, compute_key: aggregate_key.compute_key };
+        ensure!(signature.verify(&aggregate_key.address, message), "The aggregate signature is invalid");
+        Ok(signature)
+    }
+
+    /// Returns the nonce coefficient `b`, the aggregate nonce `g_r`, and the challenge of a session, where:
+    ///     b := HashToScalar(sum(g_r_1_i), sum(g_r_2_i), pk_sig, address, message)
+    ///     g_r := sum(g_r_1_i) + b * sum(g_r_2_i)
+    ///     challenge := HashToScalar(g_r, pk_sig, pr_sig, address, message)
+    fn musig_session(
+        aggregate_key: &AggregateKey<N>,
+        public_nonces: &[PublicNonce<N>],
+        message: &[Field<N>],
+    ) -> Result<(Scalar<N>, Group<N>, Scalar<N>)> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        ensure!(
+            public_nonces.len() == aggregate_key.num_signers(),
+            "Expected {} public nonces, found {}",
+            aggregate_key.num_signers(),
+            public_nonces.len()
+        );
+
+        // Aggregate the public nonces.
+        let g_r_1 = public_nonces.iter().map(|public_nonce| public_nonce.g_r_1).sum::<Group<N>>();
+        let g_r_2 = public_nonces.iter().map(|public_nonce| public_nonce.g_r_2).sum::<Group<N>>();
+
+        // Retrieve pk_sig.
+        let pk_sig = aggregate_key.compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = aggregate_key.compute_key.pr_sig();
+        // Retrieve the address.
+        let address = *aggregate_key.address;
+
+        // Compute the nonce coefficient, which binds the aggregate nonce to the session.
+        let mut preimage = Vec::with_capacity(5 + message.len());
+        preimage.push(Field::from_domain_separator(MUSIG_NONCE_DOMAIN));
+        preimage.extend([g_r_1, g_r_2, pk_sig, address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+        let b = N::hash_to_scalar_psd8(&preimage)?;
+
+        // Compute the aggregate nonce.
+        let g_r = g_r_1 + (g_r_2 * b);
+        ensure!(!g_r.is_zero(), "The aggregate nonce must not be the identity");
+
+        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([g_r, pk_sig, pr_sig, address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Compute the verifier challenge.
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+        Ok((b, g_r, challenge))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_musig() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for num_signers in [1, 2, 5] {
+            // Sample the signers, and aggregate their compute keys.
+            let private_keys =
+                (0..num_signers).map(|_| PrivateKey::<CurrentNetwork>::new(rng)).collect::<Result<Vec<_>>>()?;
+            let compute_keys = private_keys.iter().map(ComputeKey::try_from).collect::<Result<Vec<_>>>()?;
+            let aggregate_key = AggregateKey::new(&compute_keys)?;
+            let message: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
+
+            // In the first round, each signer samples its nonces.
+            let (secret_nonces, public_nonces): (Vec<_>, Vec<_>) =
+                (0..num_signers).map(|_| Signature::musig_nonces(rng)).unzip();
+
+            // In the second round, each signer produces its partial signature.
+            let partial_signatures = private_keys
+                .iter()
+                .zip_eq(secret_nonces)
+                .map(|(private_key, secret_nonce)| {
+                    Signature::musig_partial_sign(private_key, &aggregate_key, secret_nonce, &public_nonces, &message)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            for (index, partial_signature) in partial_signatures.iter().enumerate() {
+                assert!(Signature::musig_verify_partial(
+                    &aggregate_key,
+                    index,
+                    partial_signature,
+                    &public_nonces,
+                    &message
+                ));
+            }
+
+            // Check that the aggregate signature verifies under the aggregate address.
+            let signature = Signature::musig_aggregate(&aggregate_key, &public_nonces, &partial_signatures, &message)?;
+            assert!(signature.verify(aggregate_key.address(), &message));
+            assert_eq!(signature.compute_key(), *aggregate_key.compute_key());
+
+            // Check that the aggregate signature is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
+            assert!(!signature.verify(aggregate_key.address(), &failure_message));
+
+            // Check that an incorrect partial signature is detected.
+            let mut failure_partial_signatures = partial_signatures.clone();
+            failure_partial_signatures[0].response += Scalar::one();
+            assert!(!Signature::musig_verify_partial(
+                &aggregate_key,
+                0,
+                &failure_partial_signatures[0],
+                &public_nonces,
+                &message
+            ));
+            assert!(
+                Signature::musig_aggregate(&aggregate_key, &public_nonces, &failure_partial_signatures, &message)
+                    .is_err()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_aggregate_key() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_keys = (0..3).map(|_| PrivateKey::<CurrentNetwork>::new(rng)).collect::<Result<Vec<_>>>()?;
+        let compute_keys = private_keys.iter().map(ComputeKey::try_from).collect::<Result<Vec<_>>>()?;
+
+        // Ensure the aggregate key depends on the order of the signers.
+        let aggregate_key = AggregateKey::new(&compute_keys)?;
+        let reversed = compute_keys.iter().rev().copied().collect::<Vec<_>>();
+        assert_ne!(aggregate_key.address(), AggregateKey::new(&reversed)?.address());
+
+        // Ensure an empty set and duplicate signers are rejected.
+        assert!(AggregateKey::<CurrentNetwork>::new(&[]).is_err());
+        assert!(AggregateKey::new(&[compute_keys[0], compute_keys[0]]).is_err());
+
+        // Ensure a non-signer cannot produce a partial signature.
+        let (secret_nonce, public_nonce) = Signature::musig_nonces(rng);
+        let other = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let public_nonces = [public_nonce; 3];
+        assert!(Signature::musig_partial_sign(&other, &aggregate_key, secret_nonce, &public_nonces, &[]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_types::Group;
+
+/// The number of bits in each window of a fixed-base table, which matches the table of `Network::g_windows`.
+const WINDOW_SIZE: usize = 4;
+/// The number of multiples of the base in each window of a fixed-base table.
+const WINDOW_LENGTH: usize = 1 << WINDOW_SIZE;
+
+/// A public key with a fixed-base precomputation table for `pk_sig`, which, with the table of the generator
+/// that is shared by the network, makes repeated verifications of signatures under the same key several times faster.
+///
+/// Preparing a key costs about as much as a few verifications, so it pays off for keys that sign often.
+#[derive(Clone)]
+pub struct PreparedPublicKey<N: Network> {
+    /// The compute key of the signer.
+    compute_key: ComputeKey<N>,
+    /// The address of the signer.
+    address: Address<N>,
+    /// The fixed-base table of `pk_sig`.
+    pk_sig_table: Vec<[Group<N>; WINDOW_LENGTH]>,
+}
+
+impl<N: Network> PreparedPublicKey<N> {
+    /// Prepares the given compute key for repeated verifications.
+    pub fn new(compute_key: &ComputeKey<N>) -> Result<Self> {
+        Ok(Self {
+            compute_key: *compute_key,
+            address: Address::try_from(compute_key)?,
+            pk_sig_table: Self::fixed_base_table(compute_key.pk_sig()),
+        })
+    }
+
+    /// Returns the compute key of the signer.
+    pub const fn compute_key(&self) -> &ComputeKey<N> {
+        &self.compute_key
+    }
+
+    /// Returns the address of the signer.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+
+    /// Verifies (challenge == challenge') && (compute_key == compute_key') where:
+    ///     challenge' := HashToScalar(G^response pk_sig^challenge, pk_sig, pr_sig, address, message)
+    ///
+    /// This is equivalent to `signature.verify(self.address(), message)`.
+    pub fn verify_prepared(&self, signature: &Signature<N>, message: &[Field<N>]) -> bool {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            eprintln!("Cannot verify the signature: the signed message exceeds maximum allowed size");
+            return false;
+        }
+
+        // Ensure the signature was produced under the prepared compute key, which determines the address.
+        if signature.compute_key != self.compute_key {
+            return false;
+        }
+
+        // Compute `g_r` := (response * G) + (challenge * pk_sig).
+        let g_r = Self::fixed_base_multiply(N::g_windows(), &signature.response)
+            + Self::fixed_base_multiply(&self.pk_sig_table, &signature.challenge);
+
+        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend(
+            [g_r, self.compute_key.pk_sig(), self.compute_key.pr_sig(), *self.address]
+                .map(|point| point.to_x_coordinate()),
+        );
+        preimage.extend(message);
+
+        // Hash to derive the verifier challenge, and return `false` if this operation fails.
+        match N::hash_to_scalar_psd8(&preimage) {
+            Ok(candidate_challenge) => signature.challenge == candidate_challenge,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the fixed-base table of the given base, where:
+    ///     table[i][j] := j * 2^(WINDOW_SIZE * i) * base
+    fn fixed_base_table(base: Group<N>) -> Vec<[Group<N>; WINDOW_LENGTH]> {
+        let num_windows = (Scalar::<N>::size_in_bits() + WINDOW_SIZE - 1) / WINDOW_SIZE;
+        let mut table = Vec::with_capacity(num_windows);
+        let mut window_base = base;
+        for _ in 0..num_windows {
+            let mut window = [Group::zero(); WINDOW_LENGTH];
+            for j in 1..WINDOW_LENGTH {
+                window[j] = window[j - 1] + window_base;
+            }
+            // Advance the base to the next window.
+            window_base = window[WINDOW_LENGTH - 1] + window_base;
+            table.push(window);
+        }
+        table
+    }
+
+    /// Returns the product of the given scalar and the base of the given fixed-base table.
+    fn fixed_base_multiply(table: &[[Group<N>; WINDOW_LENGTH]], scalar: &Scalar<N>) -> Group<N> {
+        scalar
+            .to_bits_le()
+            .chunks(WINDOW_SIZE)
+            .zip_eq(table)
+            .map(|(bits, window)| {
+                let index = bits.iter().rev().fold(0, |index, bit| (index << 1) | *bit as usize);
+                window[index]
+            })
+            .sum()
+    }
+}
+
+#[cfg(all(test, feature = "private_key"))]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_fixed_base_multiply() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let base: Group<CurrentNetwork> = Uniform::rand(rng);
+        let table = PreparedPublicKey::fixed_base_table(base);
+        for _ in 0..ITERATIONS {
+            let scalar = Uniform::rand(rng);
+            assert_eq!(PreparedPublicKey::fixed_base_multiply(&table, &scalar), base * scalar);
+        }
+
+        // Ensure the shared table of the generator matches the fixed-base table of the generator.
+        let g_table = PreparedPublicKey::<CurrentNetwork>::fixed_base_table(CurrentNetwork::g_powers()[0]);
+        assert_eq!(*CurrentNetwork::g_windows(), g_table);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_prepared() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let compute_key = ComputeKey::try_from(&private_key)?;
+        let prepared_key = PreparedPublicKey::new(&compute_key)?;
+        assert_eq!(*prepared_key.address(), Address::try_from(&private_key)?);
+
+        for i in 0..ITERATIONS {
+            // Check that the signature is valid for the message.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = Signature::sign(&private_key, &message, rng)?;
+            assert!(prepared_key.verify_prepared(&signature, &message));
+
+            // Check that the signature is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..i + 1).map(|_| Uniform::rand(rng)).collect();
+            assert!(!prepared_key.verify_prepared(&signature, &failure_message));
+
+            // Check that a signature under another key is invalid.
+            let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let other_signature = Signature::sign(&other_private_key, &message, rng)?;
+            assert!(other_signature.verify(&Address::try_from(&other_private_key)?, &message));
+            assert!(!prepared_key.verify_prepared(&other_signature, &message));
+        }
+        Ok(())
+    }
+}
@@ -13,19 +13,51 @@
 // limitations under the License.
 
 use super::*;
+use crate::Secret;
+
+const SIGNATURE_NONCE_DOMAIN: DomainSeparator = DomainSeparator::new("AleoSignatureNonce", 0);
 
 impl<N: Network> Signature<N> {
     /// Returns a signature `(challenge, response, compute_key)` for a given message and RNG, where:
     ///     challenge := HashToScalar(nonce * G, pk_sig, pr_sig, address, message)
     ///     response := nonce - challenge * private_key.sk_sig()
     pub fn sign<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, message: &[Field<N>], rng: &mut R) -> Result<Self> {
+        // Sample a random nonce from the scalar field.
+        let nonce = Secret::new(Scalar::rand(rng));
+        // Sign the message.
+        Self::sign_with_nonce(private_key, message, nonce)
+    }
+
+    /// Returns a signature `(challenge, response, compute_key)` for a given message, without an RNG, where:
+    ///     nonce := HashToScalar(private_key.seed(), message)
+    ///
+    /// The nonce is unique to each message, so signing never depends on the quality of the available entropy.
+    pub fn sign_deterministic(private_key: &PrivateKey<N>, message: &[Field<N>]) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+
+        // Construct the hash input as (seed, message length, message).
+        let mut preimage = Vec::with_capacity(3 + message.len());
+        preimage.push(Field::from_domain_separator(SIGNATURE_NONCE_DOMAIN));
+        preimage.push(private_key.seed());
+        preimage.push(Field::from_u64(message.len() as u64));
+        preimage.extend(message);
+
+        // Derive the nonce from the private key and the message.
+        let nonce = Secret::new(N::hash_to_scalar_psd8(&preimage)?);
+        // Sign the message.
+        Self::sign_with_nonce(private_key, message, nonce)
+    }
+
+    /// Returns a signature `(challenge, response, compute_key)` for a given message and nonce.
+    fn sign_with_nonce(private_key: &PrivateKey<N>, message: &[Field<N>], nonce: Secret<Scalar<N>>) -> Result<Self> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
             bail!("Cannot sign the message: the message exceeds maximum allowed size")
         }
 
-        // Sample a random nonce from the scalar field.
-        let nonce = Scalar::rand(rng);
         // Compute `g_r` as `nonce * G`.
         let g_r = N::g_scalar_multiply(&nonce);
 
@@ -47,7 +79,7 @@ impl<N: Network> Signature<N> {
         // Compute the verifier challenge.
         let challenge = N::hash_to_scalar_psd8(&preimage)?;
         // Compute the prover response.
-        let response = nonce - (challenge * private_key.sk_sig());
+        let response = *nonce - (challenge * private_key.sk_sig());
 
         // Output the signature.
         Ok(Self { challenge, response, compute_key })
@@ -109,6 +109,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sign_deterministic_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the signature is valid for the message, and is the same for every call.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = Signature::sign_deterministic(&private_key, &message)?;
+            assert!(signature.verify(&address, &message));
+            assert_eq!(signature, Signature::sign_deterministic(&private_key, &message)?);
+
+            // Check that the signature differs for another message, or another private key.
+            let other_message: Vec<_> = (0..i + 1).map(|_| Uniform::rand(rng)).collect();
+            let other_signature = Signature::sign_deterministic(&private_key, &other_message)?;
+            assert!(other_signature.verify(&address, &other_message));
+            assert_ne!(signature.challenge(), other_signature.challenge());
+            let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            assert_ne!(signature, Signature::sign_deterministic(&other_private_key, &message)?);
+
+            // Check that the signature is invalid for an incorrect message.
+            assert!(!signature.verify(&address, &other_message));
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_sign_and_verify_bytes() -> Result<()> {
         let rng = &mut TestRng::default();
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{PrivateKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field};
+
+/// An entity that can produce account signatures, such as an in-process private key,
+/// a hardware security module, or a remote signing service.
+///
+/// Implementations outside of this crate may assemble the signature from its
+/// `(challenge, response, compute_key)` components, and may ignore the given RNG
+/// if they sample the nonce themselves.
+pub trait Signer<N: Network> {
+    /// Returns the address of the signer.
+    fn address(&self) -> Result<Address<N>>;
+
+    /// Returns a signature for the given message (as field elements).
+    fn sign<R: Rng + CryptoRng>(&self, message: &[Field<N>], rng: &mut R) -> Result<Signature<N>>;
+
+    /// Returns a signature for the given message (as bytes).
+    fn sign_bytes<R: Rng + CryptoRng>(&self, message: &[u8], rng: &mut R) -> Result<Signature<N>> {
+        // Convert the message into bits, and sign the message.
+        self.sign_bits(&message.to_bits_le(), rng)
+    }
+
+    /// Returns a signature for the given message (as bits).
+    fn sign_bits<R: Rng + CryptoRng>(&self, message: &[bool], rng: &mut R) -> Result<Signature<N>> {
+        // Pack the bits into field elements.
+        let fields =
+            message.chunks(Field::<N>::size_in_data_bits()).map(Field::from_bits_le).collect::<Result<Vec<_>>>()?;
+        // Sign the message.
+        self.sign(&fields, rng)
+    }
+}
+
+impl<N: Network> Signer<N> for PrivateKey<N> {
+    /// Returns the address of the private key.
+    fn address(&self) -> Result<Address<N>> {
+        Address::try_from(self)
+    }
+
+    /// Returns a signature for the given message (as field elements) using the private key.
+    fn sign<R: Rng + CryptoRng>(&self, message: &[Field<N>], rng: &mut R) -> Result<Signature<N>> {
+        Signature::sign(self, message, rng)
+    }
+}
+
+impl<N: Network, S: Signer<N>> Signer<N> for &S {
+    /// Returns the address of the signer.
+    fn address(&self) -> Result<Address<N>> {
+        (**self).address()
+    }
+
+    /// Returns a signature for the given message (as field elements).
+    fn sign<R: Rng + CryptoRng>(&self, message: &[Field<N>], rng: &mut R) -> Result<Signature<N>> {
+        (**self).sign(message, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ComputeKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    /// A signer that holds the key elsewhere, and returns the signature in its components.
+    struct RemoteSigner(PrivateKey<CurrentNetwork>);
+
+    impl Signer<CurrentNetwork> for RemoteSigner {
+        fn address(&self) -> Result<Address<CurrentNetwork>> {
+            Address::try_from(&self.0)
+        }
+
+        fn sign<R: Rng + CryptoRng>(
+            &self,
+            message: &[Field<CurrentNetwork>],
+            rng: &mut R,
+        ) -> Result<Signature<CurrentNetwork>> {
+            let signature = Signature::sign(&self.0, message, rng)?;
+            Ok(Signature::from((signature.challenge(), signature.response(), ComputeKey::try_from(&self.0)?)))
+        }
+    }
+
+    fn check_signer<S: Signer<CurrentNetwork>>(signer: &S, rng: &mut TestRng) -> Result<()> {
+        let address = signer.address()?;
+
+        let message: Vec<Field<CurrentNetwork>> = (0..10).map(|_| Uniform::rand(rng)).collect();
+        assert!(signer.sign(&message, rng)?.verify(&address, &message));
+        assert!(signer.sign_bytes(b"message", rng)?.verify_bytes(&address, b"message"));
+        assert!(signer.sign_bits(&[true, false, true], rng)?.verify_bits(&address, &[true, false, true]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_signer() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            check_signer(&private_key, rng)?;
+            check_signer(&&private_key, rng)?;
+            check_signer(&RemoteSigner(private_key), rng)?;
+        }
+        Ok(())
+    }
+}
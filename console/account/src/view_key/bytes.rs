@@ -28,6 +28,10 @@ impl<N: Network> ToBytes for ViewKey<N> {
     }
 }
 
+impl<N: Network> ConstSerializedSize for ViewKey<N> {
+    const SIZE: usize = Scalar::<N>::SIZE;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,7 +48,7 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new view key.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-            let expected = ViewKey::try_from(private_key)?;
+            let expected = ViewKey::try_from(&private_key)?;
 
             // Check the byte representation.
             let expected_bytes = expected.to_bytes_le()?;
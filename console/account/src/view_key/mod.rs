@@ -26,10 +26,10 @@ use crate::PrivateKey;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Address, Scalar};
 
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
-/// The account view key used to decrypt records and ciphertext.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Zeroize)]
+/// The account view key used to decrypt records and ciphertext, which is zeroized on drop.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Zeroize, ZeroizeOnDrop)]
 pub struct ViewKey<N: Network>(Scalar<N>);
 
 impl<N: Network> ViewKey<N> {
@@ -64,7 +64,7 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new address.
             let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
-            let expected = ViewKey::try_from(private_key)?;
+            let expected = ViewKey::try_from(&private_key)?;
 
             // Check the scalar representation.
             let candidate = *expected;
@@ -54,7 +54,7 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new view key.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-            let expected = ViewKey::try_from(private_key)?;
+            let expected = ViewKey::try_from(&private_key)?;
 
             // Serialize
             let expected_string = &expected.to_string();
@@ -75,7 +75,7 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new view key.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-            let expected = ViewKey::try_from(private_key)?;
+            let expected = ViewKey::try_from(&private_key)?;
 
             // Serialize
             let expected_bytes = expected.to_bytes_le()?;
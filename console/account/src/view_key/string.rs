@@ -61,7 +61,7 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new view key.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-            let expected = ViewKey::try_from(private_key)?;
+            let expected = ViewKey::try_from(&private_key)?;
 
             // Check the string representation.
             let candidate = format!("{expected}");
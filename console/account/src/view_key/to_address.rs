@@ -37,8 +37,8 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new view key and address.
             let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
-            let view_key = ViewKey::try_from(private_key)?;
-            let address = Address::try_from(private_key)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
 
             assert_eq!(address, view_key.to_address());
         }
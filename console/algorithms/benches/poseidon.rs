@@ -24,7 +24,7 @@ type F = Field<Console>;
 
 fn poseidon2(c: &mut Criterion) {
     let rng = &mut TestRng::default();
-    let hash = Poseidon2::<Console>::setup("Poseidon2").unwrap();
+    let hash = Poseidon2::<Console>::setup(DomainSeparator::primitive("Poseidon", 2)).unwrap();
 
     let input = [F::rand(rng), F::rand(rng), F::rand(rng), F::rand(rng)];
     c.bench_function("Poseidon2 Hash 4 -> 1", |b| b.iter(|| hash.hash(&input)));
@@ -38,7 +38,7 @@ fn poseidon2(c: &mut Criterion) {
 
 fn poseidon4(c: &mut Criterion) {
     let rng = &mut TestRng::default();
-    let hash = Poseidon4::<Console>::setup("Poseidon4").unwrap();
+    let hash = Poseidon4::<Console>::setup(DomainSeparator::primitive("Poseidon", 4)).unwrap();
 
     let input = [F::rand(rng), F::rand(rng), F::rand(rng), F::rand(rng)];
     c.bench_function("Poseidon4 Hash 4 -> 1", |b| b.iter(|| hash.hash(&input)));
@@ -52,7 +52,7 @@ fn poseidon4(c: &mut Criterion) {
 
 fn poseidon8(c: &mut Criterion) {
     let rng = &mut TestRng::default();
-    let hash = Poseidon8::<Console>::setup("Poseidon8").unwrap();
+    let hash = Poseidon8::<Console>::setup(DomainSeparator::primitive("Poseidon", 8)).unwrap();
 
     let input = [F::rand(rng), F::rand(rng), F::rand(rng), F::rand(rng)];
     c.bench_function("Poseidon8 Hash 4 -> 1", |b| b.iter(|| hash.hash(&input)));
@@ -64,10 +64,26 @@ fn poseidon8(c: &mut Criterion) {
     c.bench_function("Poseidon8 Hash 10 -> 8", |b| b.iter(|| hash.hash_many(&input, 8)));
 }
 
+fn poseidon2_batch(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+    let hash = Poseidon2::<Console>::setup(DomainSeparator::primitive("Poseidon", 2)).unwrap();
+
+    // Hash pairs of field elements, as when building the layers of a Merkle tree.
+    for num_inputs in [64, 1024] {
+        let inputs: Vec<_> = (0..num_inputs).map(|_| [F::rand(rng), F::rand(rng)]).collect();
+        c.bench_function(&format!("Poseidon2 Hash {num_inputs} x (2 -> 1) Sequential"), |b| {
+            b.iter(|| inputs.iter().map(|input| hash.hash(input)).collect::<Result<Vec<_>>>())
+        });
+        c.bench_function(&format!("Poseidon2 Hash {num_inputs} x (2 -> 1) Batch"), |b| {
+            b.iter(|| hash.hash_batch(&inputs))
+        });
+    }
+}
+
 criterion_group! {
     name = sponge;
     config = Criterion::default().sample_size(50);
-    targets = poseidon2, poseidon4, poseidon8,
+    targets = poseidon2, poseidon4, poseidon8, poseidon2_batch,
 }
 
 criterion_main!(sponge);
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Hash for Anemoi<E> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+
+    /// Returns the cryptographic hash for a list of field elements as input.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Construct the preimage: [ DOMAIN || LENGTH(INPUT) || INPUT ].
+        let mut preimage = Vec::with_capacity(2 + input.len());
+        preimage.push(self.domain);
+        preimage.push(Field::<E>::from_u128(input.len() as u128));
+        preimage.extend_from_slice(input);
+
+        // Absorb each element into `x`, and permute the state after each element.
+        let (mut x, mut y) = (Field::<E>::zero(), Field::<E>::zero());
+        for element in preimage {
+            (x, y) = self.permute(x + element, y);
+        }
+        Ok(x)
+    }
+}
+
+impl<E: Environment> Anemoi<E> {
+    /// Returns the compression of two field elements, with the Jive mode of Anemoi:
+    /// `left + right + u + v`, where `(u, v)` is the permutation of `(left, right)`.
+    #[inline]
+    pub fn compress(&self, left: &Field<E>, right: &Field<E>) -> Field<E> {
+        let (u, v) = self.permute(*left, *right);
+        *left + right + u + v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_hash() -> Result<()> {
+        let mut rng = TestRng::default();
+        let anemoi = Anemoi::<CurrentEnvironment>::setup(DomainSeparator::new("AnemoiTest", 0))?;
+        let other = Anemoi::<CurrentEnvironment>::setup(DomainSeparator::new("AnemoiOther", 0))?;
+
+        for num_inputs in 0..ITERATIONS as usize / 10 {
+            let input = (0..num_inputs).map(|_| Uniform::rand(&mut rng)).collect::<Vec<Field<_>>>();
+            let expected = anemoi.hash(&input)?;
+            assert_eq!(expected, anemoi.hash(&input)?);
+
+            // Ensure the hash depends on the domain, and on the length of the input.
+            assert_ne!(expected, other.hash(&input)?);
+            assert_ne!(expected, anemoi.hash(&[input.clone(), vec![Field::zero()]].concat())?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress() -> Result<()> {
+        let mut rng = TestRng::default();
+        let anemoi = Anemoi::<CurrentEnvironment>::setup(DomainSeparator::new("AnemoiTest", 0))?;
+
+        for _ in 0..ITERATIONS {
+            let left = Field::<CurrentEnvironment>::rand(&mut rng);
+            let right = Field::<CurrentEnvironment>::rand(&mut rng);
+            assert_eq!(anemoi.compress(&left, &right), anemoi.compress(&left, &right));
+            assert_ne!(anemoi.compress(&left, &right), anemoi.compress(&right, &left));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress_vectors() -> Result<()> {
+        let anemoi = Anemoi::<CurrentEnvironment>::setup(DomainSeparator::new("AnemoiTest", 0))?;
+
+        // The expected values were computed with a transcription of the reference implementation of Anemoi
+        // (https://eprint.iacr.org/2022/840), for a single column and a security level of 128 bits.
+        for (left, right, expected) in [
+            (0, 0, "676553123262956770831412211816454043839638653372636331333841739259626467495field"),
+            (1, 2, "5898515182380263597150544989296574863935343499999913470931421317949689202199field"),
+        ] {
+            let (left, right) = (Field::<CurrentEnvironment>::from_u64(left), Field::from_u64(right));
+            assert_eq!(anemoi.compress(&left, &right), Field::from_str(expected)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+use snarkvm_console_types::prelude::*;
+use snarkvm_fields::AnemoiParameters;
+
+use std::sync::Arc;
+
+/// Anemoi is an arithmetization-friendly hash function over a single column `(x, y)`,
+/// which hashes with a sponge of rate 1 and compresses two field elements with the Jive mode.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Anemoi<E: Environment> {
+    /// The domain separator for the Anemoi hash function.
+    domain: Field<E>,
+    /// The Anemoi parameters for hashing.
+    parameters: Arc<AnemoiParameters<E::Field>>,
+}
+
+impl<E: Environment> Anemoi<E> {
+    /// Initializes a new instance of Anemoi.
+    pub fn setup(domain: DomainSeparator) -> Result<Self> {
+        // Ensure the given domain is within the allowed size in bits.
+        let num_bits = domain.to_string().len().saturating_mul(8);
+        let max_bits = Field::<E>::size_in_data_bits();
+        ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
+
+        Ok(Self {
+            domain: Field::<E>::from_domain_separator(domain),
+            parameters: Arc::new(AnemoiParameters::default_parameters()?),
+        })
+    }
+
+    /// Returns the domain separator for the hash function.
+    pub fn domain(&self) -> Field<E> {
+        self.domain
+    }
+
+    /// Returns the Anemoi parameters for hashing.
+    pub fn parameters(&self) -> &Arc<AnemoiParameters<E::Field>> {
+        &self.parameters
+    }
+
+    /// Returns the Anemoi permutation of `(x, y)`.
+    fn permute(&self, x: Field<E>, y: Field<E>) -> (Field<E>, Field<E>) {
+        let parameters = &self.parameters;
+        let (mut x, mut y) = (*x, *y);
+        for (c, d) in parameters.c.iter().zip(&parameters.d) {
+            // Add the round constants.
+            x += c;
+            y += d;
+            // Apply the linear layer.
+            y += x;
+            x += y;
+            // Apply the open Flystel.
+            x -= parameters.beta * y.square();
+            y -= x.pow(&parameters.alpha_inv);
+            x += parameters.beta * y.square() + parameters.delta;
+        }
+        // Apply the final linear layer.
+        y += x;
+        x += y;
+        (Field::new(x), Field::new(y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_permute_is_invertible() -> Result<()> {
+        let mut rng = TestRng::default();
+        let anemoi = Anemoi::<CurrentEnvironment>::setup(DomainSeparator::new("AnemoiTest", 0))?;
+        let parameters = anemoi.parameters();
+
+        for _ in 0..ITERATIONS {
+            let (x, y) = (Field::<CurrentEnvironment>::rand(&mut rng), Field::rand(&mut rng));
+            let (u, v) = anemoi.permute(x, y);
+
+            // Invert the final linear layer.
+            let (mut u, mut v) = (*u, *v);
+            u -= v;
+            v -= u;
+            // Invert the rounds, in reverse order.
+            for (c, d) in parameters.c.iter().zip(&parameters.d).rev() {
+                // Invert the open Flystel.
+                u -= parameters.beta * v.square() + parameters.delta;
+                v += u.pow(&parameters.alpha_inv);
+                u += parameters.beta * v.square();
+                // Invert the linear layer.
+                u -= v;
+                v -= u;
+                // Subtract the round constants.
+                u -= c;
+                v -= d;
+            }
+            assert_eq!((*x, *y), (u, v));
+        }
+        Ok(())
+    }
+}
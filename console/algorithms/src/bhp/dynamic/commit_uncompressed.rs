@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> CommitUncompressed for BHPDynamic<E> {
+    type Input = bool;
+    type Output = Group<E>;
+    type Randomizer = Scalar<E>;
+
+    /// Returns the BHP commitment of the given input and randomizer as an affine group element.
+    fn commit_uncompressed(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output> {
+        let mut output = self.hash_uncompressed(input)?;
+
+        // Compute h^r.
+        randomizer.to_bits_le().iter().zip_eq(&*self.random_base).filter(|(bit, _)| **bit).for_each(|(_, base)| {
+            output += base;
+        });
+
+        Ok(output)
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> HashUncompressed for BHPDynamic<E> {
+    type Input = bool;
+    type Output = Group<E>;
+
+    /// Returns the BHP hash of the given input as an affine group element.
+    ///
+    /// This uncompressed variant of the BHP hash function is provided to support
+    /// the BHP commitment scheme, as it is typically not used by applications.
+    fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        let num_hasher_bits = self.num_windows as usize * self.window_size as usize * BHP_CHUNK_SIZE;
+        hash_iterations(&self.domain, num_hasher_bits, input, |preimage| {
+            hash_bases_lookup(&self.bases_lookup, self.window_size, preimage)
+        })
+    }
+}
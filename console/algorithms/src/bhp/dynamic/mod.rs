@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod commit;
+mod commit_uncompressed;
+mod hash;
+mod hash_uncompressed;
+
+use super::{
+    hash_uncompressed::hash_iterations,
+    hasher::{hash_bases_lookup, setup_bases, BHP_LOOKUP_SIZE},
+    BHP_CHUNK_SIZE,
+};
+use snarkvm_console_types::prelude::*;
+
+use std::sync::Arc;
+
+/// BHP is a collision-resistant hash function that takes a variable-length input.
+/// Unlike `BHP`, the number of windows and the window size of `BHPDynamic` are chosen at setup,
+/// so that a single type supports any parameter size.
+///
+/// For the same parameters, `BHPDynamic` computes the same hashes and commitments as `BHP`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BHPDynamic<E: Environment> {
+    /// The domain separator for the BHP hash function.
+    domain: Vec<bool>,
+    /// The number of windows.
+    num_windows: u8,
+    /// The window size.
+    window_size: u8,
+    /// The bases for the BHP hash.
+    bases: Arc<Vec<Vec<Group<E>>>>,
+    /// The bases lookup table for the BHP hash.
+    bases_lookup: Arc<Vec<Vec<[Group<E>; BHP_LOOKUP_SIZE]>>>,
+    /// The random base for the BHP commitment.
+    random_base: Arc<Vec<Group<E>>>,
+}
+
+impl<E: Environment> BHPDynamic<E> {
+    /// Initializes a new instance of BHP with the given domain, number of windows, and window size.
+    pub fn setup(domain: DomainSeparator, num_windows: u8, window_size: u8) -> Result<Self> {
+        // Ensure the number of windows and the window size are nonzero.
+        ensure!(num_windows > 0, "The number of BHP windows must be greater than zero");
+        ensure!(window_size > 0, "The BHP window size must be greater than zero");
+
+        // Ensure each iteration fits the previous digest and at least one input bit.
+        let num_hasher_bits = num_windows as usize * window_size as usize * BHP_CHUNK_SIZE;
+        let num_data_bits = Field::<E>::size_in_data_bits();
+        ensure!(
+            num_hasher_bits > num_data_bits,
+            "The BHP parameters must fit more than {num_data_bits} bits, found {num_hasher_bits} bits"
+        );
+
+        // Encode the domain separator.
+        let encoded_domain = domain.to_string();
+
+        // Ensure the given domain is within the allowed size in bits.
+        let num_bits = encoded_domain.len().saturating_mul(8);
+        let max_bits = num_data_bits - 64; // 64 bits encode the length.
+        ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
+
+        // Compute the bases.
+        let (bases, bases_lookup, random_base) = setup_bases::<E>(domain, num_windows, window_size)?;
+
+        // Convert the domain into a boolean vector.
+        let mut domain = encoded_domain.as_bytes().to_bits_le();
+        // Pad the domain with zeros up to the maximum size in bits.
+        domain.resize(max_bits, false);
+        // Reverse the domain so that it is: [ 0...0 || DOMAIN ].
+        domain.reverse();
+
+        Ok(Self {
+            domain,
+            num_windows,
+            window_size,
+            bases: Arc::new(bases),
+            bases_lookup: Arc::new(bases_lookup),
+            random_base: Arc::new(random_base),
+        })
+    }
+
+    /// Returns the domain separator for the BHP hash function.
+    pub fn domain(&self) -> &[bool] {
+        &self.domain
+    }
+
+    /// Returns the bases.
+    pub fn bases(&self) -> &Arc<Vec<Vec<Group<E>>>> {
+        &self.bases
+    }
+
+    /// Returns the random base window.
+    pub fn random_base(&self) -> &Arc<Vec<Group<E>>> {
+        &self.random_base
+    }
+
+    /// Returns the number of windows.
+    pub fn num_windows(&self) -> u8 {
+        self.num_windows
+    }
+
+    /// Returns the window size.
+    pub fn window_size(&self) -> u8 {
+        self.window_size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BHP, BHP1024, BHP256, BHP512, BHP768};
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: DomainSeparator = DomainSeparator::new("BHPTest", 0);
+
+    fn check_equivalence<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(rng: &mut TestRng) -> Result<()> {
+        let expected = BHP::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::setup(DOMAIN)?;
+        let candidate = BHPDynamic::<CurrentEnvironment>::setup(DOMAIN, NUM_WINDOWS, WINDOW_SIZE)?;
+        assert_eq!(expected.domain(), candidate.domain());
+        assert_eq!(expected.bases(), candidate.bases());
+        assert_eq!(expected.random_base(), candidate.random_base());
+
+        for i in 0..ITERATIONS {
+            // Sample inputs that span one or more iterations of the hasher.
+            let num_bits = rng.gen_range(0..1024) + i as usize * 64;
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+            let randomizer = Uniform::rand(rng);
+
+            assert_eq!(expected.hash(&input)?, candidate.hash(&input)?);
+            assert_eq!(expected.commit(&input, &randomizer)?, candidate.commit(&input, &randomizer)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bhp_dynamic_equivalence() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_equivalence::<3, 57>(&mut rng)?; // BHP256
+        check_equivalence::<6, 43>(&mut rng)?; // BHP512
+        check_equivalence::<15, 23>(&mut rng)?; // BHP768
+        check_equivalence::<8, 54>(&mut rng)?; // BHP1024
+        check_equivalence::<4, 32>(&mut rng)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_bhp_dynamic_num_windows_and_window_size() -> Result<()> {
+        let bhp = BHPDynamic::<CurrentEnvironment>::setup(DOMAIN, 15, 23)?;
+        assert_eq!(bhp.num_windows(), BHP768::<CurrentEnvironment>::setup(DOMAIN)?.num_windows());
+        assert_eq!(bhp.window_size(), BHP768::<CurrentEnvironment>::setup(DOMAIN)?.window_size());
+        assert_eq!(bhp.bases().len(), 15);
+        assert!(bhp.bases().iter().all(|window| window.len() == 23));
+
+        // Ensure distinct parameters sample distinct bases.
+        let bhp256 = BHPDynamic::<CurrentEnvironment>::setup(DOMAIN, 3, 57)?;
+        assert_eq!(bhp256.bases()[0], BHP256::<CurrentEnvironment>::setup(DOMAIN)?.bases()[0]);
+        assert_ne!(bhp256.bases()[0], BHP512::<CurrentEnvironment>::setup(DOMAIN)?.bases()[0]);
+        assert_ne!(bhp256.bases()[0], BHP1024::<CurrentEnvironment>::setup(DOMAIN)?.bases()[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bhp_dynamic_setup_fails() {
+        // Ensure the number of windows and the window size must be nonzero.
+        assert!(BHPDynamic::<CurrentEnvironment>::setup(DOMAIN, 0, 57).is_err());
+        assert!(BHPDynamic::<CurrentEnvironment>::setup(DOMAIN, 3, 0).is_err());
+        // Ensure the parameters must fit more than the digest.
+        assert!(BHPDynamic::<CurrentEnvironment>::setup(DOMAIN, 1, 57).is_err());
+        assert!(BHPDynamic::<CurrentEnvironment>::setup(DOMAIN, 2, 42).is_err());
+        // Ensure the window size cannot exceed the maximum window size.
+        assert!(BHPDynamic::<CurrentEnvironment>::setup(DOMAIN, 3, u8::MAX).is_err());
+        // Ensure the domain cannot exceed the maximum size.
+        let domain = DomainSeparator::new("BHPDomainThatExceedsTheMaximumSize", 0);
+        assert!(BHPDynamic::<CurrentEnvironment>::setup(domain, 3, 57).is_err());
+    }
+}
@@ -23,3 +23,34 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Hash for BHP<
         Ok(self.hash_uncompressed(input)?.to_x_coordinate())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_hash_variable_length() -> Result<()> {
+        let bhp = BHP256::<CurrentEnvironment>::setup(DomainSeparator::new("BHPTest", 0))?;
+
+        let mut rng = TestRng::default();
+
+        // Sample inputs that span up to many iterations of the hasher.
+        for num_bits in [1, 261, 262, 522, 523, 1000, 10_000] {
+            let input = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let digest = bhp.hash(&input)?;
+
+            // Ensure the length of the input is bound to the digest.
+            let mut padded_input = input.clone();
+            padded_input.push(false);
+            assert_ne!(digest, bhp.hash(&padded_input)?);
+
+            // Ensure the domain is bound to the digest.
+            let other = BHP256::<CurrentEnvironment>::setup(DomainSeparator::new("BHPOther", 0))?;
+            assert_ne!(digest, other.hash(&input)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The length field of the first iteration of an incremental hash, which no input of `BHP::hash` can have.
+const INCREMENTAL_LENGTH_MARKER: u64 = u64::MAX;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashIncremental
+    for BHP<E, NUM_WINDOWS, WINDOW_SIZE>
+{
+    type Hasher = BHPIncremental<E, NUM_WINDOWS, WINDOW_SIZE>;
+    type Output = Field<E>;
+
+    /// Returns a hasher for a stream of bytes, which are hashed as bits in little-endian bit order.
+    ///
+    /// As the length of the stream is not known in advance, the length field of the first iteration holds
+    /// a marker, and the number of input bits is appended to the input, as in Merkle-Damgard strengthening:
+    /// [ 0...0 || DOMAIN || MARKER || INPUT || LENGTH(INPUT) ]. So the digest differs from that of `BHP::hash`.
+    fn hasher(&self) -> Self::Hasher {
+        // The number of hasher bits to fit.
+        let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        // The maximum number of input bits per iteration.
+        let max_input_bits_per_iteration = num_hasher_bits - Field::<E>::size_in_data_bits();
+
+        BHPIncremental {
+            bhp: self.clone(),
+            num_absorbed: 0,
+            max_input_bits_per_iteration,
+            block: Vec::with_capacity(max_input_bits_per_iteration),
+            digest: None,
+        }
+    }
+}
+
+/// The state of the BHP hash of a stream of bytes, which holds at most one block of the input.
+#[derive(Clone, Debug)]
+pub struct BHPIncremental<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
+    /// The BHP hash function.
+    bhp: BHP<E, NUM_WINDOWS, WINDOW_SIZE>,
+    /// The number of bytes absorbed so far.
+    num_absorbed: u64,
+    /// The maximum number of input bits per iteration.
+    max_input_bits_per_iteration: usize,
+    /// The input bits of the current iteration.
+    block: Vec<bool>,
+    /// The digest of the previous iteration, if any.
+    digest: Option<Group<E>>,
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Hasher
+    for BHPIncremental<E, NUM_WINDOWS, WINDOW_SIZE>
+{
+    type Output = Field<E>;
+
+    /// Absorbs the given bytes into the hasher.
+    fn update(&mut self, data: &[u8]) -> Result<()> {
+        // Ensure the number of input bits fits in the length field.
+        let num_absorbed = self.num_absorbed.saturating_add(data.len() as u64);
+        ensure!(num_absorbed.checked_mul(8).is_some(), "The input exceeds the maximum length of the hasher");
+        self.num_absorbed = num_absorbed;
+
+        for byte in data {
+            self.absorb_bits(&byte.to_bits_le())?;
+        }
+        Ok(())
+    }
+
+    /// Returns the hash of the absorbed bytes.
+    fn finalize(mut self) -> Result<Self::Output> {
+        // Absorb the number of input bits, which `update` ensures does not overflow.
+        let num_bits = self.num_absorbed * 8;
+        self.absorb_bits(&num_bits.to_bits_le())?;
+        // Hash the last block, which holds at least the length.
+        self.hash_block()?;
+        Ok(self.digest.unwrap_or_else(Group::zero).to_x_coordinate())
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPIncremental<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Absorbs the given bits, hashing the current block once it is full and there are more bits.
+    fn absorb_bits(&mut self, bits: &[bool]) -> Result<()> {
+        for bit in bits {
+            if self.block.len() == self.max_input_bits_per_iteration {
+                self.hash_block()?;
+            }
+            self.block.push(*bit);
+        }
+        Ok(())
+    }
+
+    /// Hashes the current block, and clears it.
+    fn hash_block(&mut self) -> Result<()> {
+        let mut preimage = Vec::with_capacity(self.max_input_bits_per_iteration + Field::<E>::size_in_data_bits());
+        match self.digest {
+            // Construct the first iteration as: [ 0...0 || DOMAIN || MARKER || INPUT[0..BLOCK_SIZE] ].
+            None => {
+                preimage.extend(&self.bhp.domain);
+                INCREMENTAL_LENGTH_MARKER.write_bits_le(&mut preimage);
+            }
+            // Construct the subsequent iterations as: [ PREVIOUS_HASH[0..DATA_BITS] || INPUT[I * BLOCK_SIZE..(I + 1) * BLOCK_SIZE] ].
+            Some(digest) => {
+                digest.to_x_coordinate().write_bits_le(&mut preimage);
+                preimage.truncate(Field::<E>::size_in_data_bits());
+            }
+        }
+        preimage.append(&mut self.block);
+        self.digest = Some(self.bhp.hasher.hash_uncompressed(&preimage)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+    use snarkvm_utilities::bits_from_bytes_le;
+
+    type CurrentEnvironment = Console;
+
+    /// Returns the incremental hash of the given input, absorbed in chunks of the given size.
+    fn hash_in_chunks(
+        bhp: &BHP256<CurrentEnvironment>,
+        input: &[u8],
+        chunk_size: usize,
+    ) -> Result<Field<CurrentEnvironment>> {
+        let mut hasher = bhp.hasher();
+        for chunk in input.chunks(chunk_size) {
+            hasher.update(chunk)?;
+        }
+        hasher.finalize()
+    }
+
+    #[test]
+    fn test_hasher_matches_iterations() -> Result<()> {
+        let bhp = BHP256::<CurrentEnvironment>::setup(DomainSeparator::new("BHPTest", 0))?;
+        let max_input_bits_per_iteration = bhp.hasher().max_input_bits_per_iteration;
+
+        let mut rng = TestRng::default();
+
+        for num_bytes in [0, 1, 2, 5, 6, 7, 31, 32, 33, 100, 257] {
+            let input = (0..num_bytes).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Compute the expected hash of [ 0...0 || DOMAIN || MARKER || INPUT || LENGTH(INPUT) ].
+            let mut bits = bits_from_bytes_le(&input).collect::<Vec<_>>();
+            (num_bytes as u64 * 8).write_bits_le(&mut bits);
+            let mut digest = Group::zero();
+            for (i, block) in bits.chunks(max_input_bits_per_iteration).enumerate() {
+                let mut preimage = Vec::new();
+                match i == 0 {
+                    true => {
+                        preimage.extend(bhp.domain());
+                        INCREMENTAL_LENGTH_MARKER.write_bits_le(&mut preimage);
+                    }
+                    false => {
+                        digest.to_x_coordinate().write_bits_le(&mut preimage);
+                        preimage.truncate(Field::<CurrentEnvironment>::size_in_data_bits());
+                    }
+                }
+                preimage.extend(block);
+                digest = bhp.hasher.hash_uncompressed(&preimage)?;
+            }
+            let expected = digest.to_x_coordinate();
+
+            // Ensure the hash is the same for any split of the input.
+            for chunk_size in [1, 3, 32] {
+                assert_eq!(expected, hash_in_chunks(&bhp, &input, chunk_size)?);
+            }
+            // Ensure the digest is not that of the one-shot hash.
+            assert_ne!(expected, bhp.hash(&bits_from_bytes_le(&input).collect::<Vec<_>>())?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hasher_trailing_zeros() -> Result<()> {
+        let bhp = BHP256::<CurrentEnvironment>::setup(DomainSeparator::new("BHPTest", 0))?;
+
+        // Ensure inputs that differ only in trailing zero bytes do not collide.
+        let input = [1u8, 2, 3];
+        assert_ne!(hash_in_chunks(&bhp, &input, 1)?, hash_in_chunks(&bhp, &[input.as_slice(), &[0]].concat(), 1)?);
+        assert_ne!(hash_in_chunks(&bhp, &[], 1)?, hash_in_chunks(&bhp, &[0], 1)?);
+        Ok(())
+    }
+}
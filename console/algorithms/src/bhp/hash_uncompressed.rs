@@ -25,48 +25,58 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
     /// This uncompressed variant of the BHP hash function is provided to support
     /// the BHP commitment scheme, as it is typically not used by applications.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
-        // The number of hasher bits to fit.
         let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
-        // The number of data bits in the output.
-        let num_data_bits = Field::<E>::size_in_data_bits();
-        // The maximum number of input bits per iteration.
-        let max_input_bits_per_iteration = num_hasher_bits - num_data_bits;
-
-        debug_assert!(num_data_bits < num_hasher_bits);
-        debug_assert_eq!(num_data_bits - 64, self.domain.len());
-
-        // Initialize a variable to store the hash from the current iteration.
-        let mut digest = Group::<E>::zero();
-
-        // Prepare a reusable vector for the preimage.
-        let mut preimage = Vec::with_capacity(num_hasher_bits);
-
-        // Compute the hash of the input.
-        for (i, input_bits) in input.chunks(max_input_bits_per_iteration).enumerate() {
-            // Determine if this is the first iteration.
-            match i == 0 {
-                // Construct the first iteration as: [ 0...0 || DOMAIN || LENGTH(INPUT) || INPUT[0..BLOCK_SIZE] ].
-                true => {
-                    // Initialize a vector for the hash preimage.
-                    preimage.extend(&self.domain);
-                    (input.len() as u64).write_bits_le(&mut preimage);
-                    preimage.extend(input_bits);
-                }
-                // Construct the subsequent iterations as: [ PREVIOUS_HASH[0..DATA_BITS] || INPUT[I * BLOCK_SIZE..(I + 1) * BLOCK_SIZE] ].
-                false => {
-                    // Initialize a vector for the hash preimage.
-                    digest.to_x_coordinate().write_bits_le(&mut preimage);
-                    preimage.truncate(num_data_bits);
-                    preimage.extend(input_bits);
-                }
+        hash_iterations(&self.domain, num_hasher_bits, input, |preimage| self.hasher.hash_uncompressed(preimage))
+    }
+}
+
+/// Returns the BHP hash of the given input as an affine group element,
+/// using the given hasher to process each iteration of `num_hasher_bits` bits.
+pub(super) fn hash_iterations<E: Environment>(
+    domain: &[bool],
+    num_hasher_bits: usize,
+    input: &[bool],
+    hasher: impl Fn(&[bool]) -> Result<Group<E>>,
+) -> Result<Group<E>> {
+    // The number of data bits in the output.
+    let num_data_bits = Field::<E>::size_in_data_bits();
+    // The maximum number of input bits per iteration.
+    let max_input_bits_per_iteration = num_hasher_bits - num_data_bits;
+
+    debug_assert!(num_data_bits < num_hasher_bits);
+    debug_assert_eq!(num_data_bits - 64, domain.len());
+
+    // Initialize a variable to store the hash from the current iteration.
+    let mut digest = Group::<E>::zero();
+
+    // Prepare a reusable vector for the preimage.
+    let mut preimage = Vec::with_capacity(num_hasher_bits);
+
+    // Compute the hash of the input.
+    for (i, input_bits) in input.chunks(max_input_bits_per_iteration).enumerate() {
+        // Determine if this is the first iteration.
+        match i == 0 {
+            // Construct the first iteration as: [ 0...0 || DOMAIN || LENGTH(INPUT) || INPUT[0..BLOCK_SIZE] ].
+            true => {
+                // Initialize a vector for the hash preimage.
+                preimage.extend(domain);
+                (input.len() as u64).write_bits_le(&mut preimage);
+                preimage.extend(input_bits);
+            }
+            // Construct the subsequent iterations as: [ PREVIOUS_HASH[0..DATA_BITS] || INPUT[I * BLOCK_SIZE..(I + 1) * BLOCK_SIZE] ].
+            false => {
+                // Initialize a vector for the hash preimage.
+                digest.to_x_coordinate().write_bits_le(&mut preimage);
+                preimage.truncate(num_data_bits);
+                preimage.extend(input_bits);
             }
-            // Hash the preimage for this iteration.
-            digest = self.hasher.hash_uncompressed(&preimage)?;
-            preimage.clear();
         }
-
-        Ok(digest)
+        // Hash the preimage for this iteration.
+        digest = hasher(&preimage)?;
+        preimage.clear();
     }
+
+    Ok(digest)
 }
 
 #[cfg(test)]
@@ -80,7 +90,7 @@ mod tests {
 
     #[test]
     fn test_bhp256_input_size() -> Result<()> {
-        let bhp = BHP256::<CurrentEnvironment>::setup("BHPTest")?;
+        let bhp = BHP256::<CurrentEnvironment>::setup(DomainSeparator::new("BHPTest", 0))?;
 
         let mut rng = TestRng::default();
 
@@ -93,7 +103,7 @@ mod tests {
 
     #[test]
     fn test_bhp512_input_size() -> Result<()> {
-        let bhp = BHP512::<CurrentEnvironment>::setup("BHPTest")?;
+        let bhp = BHP512::<CurrentEnvironment>::setup(DomainSeparator::new("BHPTest", 0))?;
 
         let mut rng = TestRng::default();
 
@@ -106,7 +116,7 @@ mod tests {
 
     #[test]
     fn test_bhp768_input_size() -> Result<()> {
-        let bhp = BHP768::<CurrentEnvironment>::setup("BHPTest")?;
+        let bhp = BHP768::<CurrentEnvironment>::setup(DomainSeparator::new("BHPTest", 0))?;
 
         let mut rng = TestRng::default();
 
@@ -119,7 +129,7 @@ mod tests {
 
     #[test]
     fn test_bhp1024_input_size() -> Result<()> {
-        let bhp = BHP1024::<CurrentEnvironment>::setup("BHPTest")?;
+        let bhp = BHP1024::<CurrentEnvironment>::setup(DomainSeparator::new("BHPTest", 0))?;
 
         let mut rng = TestRng::default();
 
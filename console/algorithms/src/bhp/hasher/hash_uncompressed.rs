@@ -27,40 +27,49 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
     /// This uncompressed variant of the BHP hash function is provided to support
     /// the BHP commitment scheme, as it is typically not used by applications.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
-        // Ensure the input size is at least the window size.
-        ensure!(input.len() > Self::MIN_BITS, "Inputs to this BHP must be greater than {} bits", Self::MIN_BITS);
-        // Ensure the input size is within the parameter size,
-        ensure!(
-            input.len() <= Self::MAX_BITS,
-            "Inputs to this BHP cannot exceed {} bits, found {}",
-            Self::MAX_BITS,
-            input.len()
-        );
+        hash_bases_lookup(&self.bases_lookup, WINDOW_SIZE, input)
+    }
+}
+
+/// Returns the BHP hash of the given input as an affine group element, using the given bases lookup table.
+pub(in crate::bhp) fn hash_bases_lookup<E: Environment>(
+    bases_lookup: &[Vec<[Group<E>; BHP_LOOKUP_SIZE]>],
+    window_size: u8,
+    input: &[bool],
+) -> Result<Group<E>> {
+    // The maximum number of input bits.
+    let max_bits = bases_lookup.len() * window_size as usize * BHP_CHUNK_SIZE;
+    // The minimum number of input bits (at least one window).
+    let min_bits = window_size as usize * BHP_CHUNK_SIZE;
 
-        // Pad the input to a multiple of `BHP_CHUNK_SIZE` for hashing.
-        let input = if input.len() % BHP_CHUNK_SIZE != 0 {
-            let padding = BHP_CHUNK_SIZE - (input.len() % BHP_CHUNK_SIZE);
-            let mut padded_input = vec![false; input.len() + padding];
-            padded_input[..input.len()].copy_from_slice(input);
-            ensure!((padded_input.len() % BHP_CHUNK_SIZE) == 0, "Input must be a multiple of {BHP_CHUNK_SIZE}");
-            Cow::Owned(padded_input)
-        } else {
-            Cow::Borrowed(input)
-        };
+    // Ensure the input size is at least the window size.
+    ensure!(input.len() > min_bits, "Inputs to this BHP must be greater than {} bits", min_bits);
+    // Ensure the input size is within the parameter size,
+    ensure!(input.len() <= max_bits, "Inputs to this BHP cannot exceed {} bits, found {}", max_bits, input.len());
 
-        // Compute sum of h_i^{sum of (1-2*c_{i,j,2})*(1+c_{i,j,0}+2*c_{i,j,1})*2^{4*(j-1)} for all j in segment}
-        // for all i. Described in section 5.4.1.7 in the Zcash protocol specification.
-        //
-        // Note: `.zip()` is used here (as opposed to `.zip_eq()`) as the input can be less than
-        // `NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE` in length, which is the parameter size here.
-        Ok(input
-            .chunks(WINDOW_SIZE as usize * BHP_CHUNK_SIZE)
-            .zip(&*self.bases_lookup)
-            .flat_map(|(bits, bases)| {
-                bits.chunks(BHP_CHUNK_SIZE).zip(bases).map(|(chunk_bits, base)| {
-                    base[(chunk_bits[0] as usize) | (chunk_bits[1] as usize) << 1 | (chunk_bits[2] as usize) << 2]
-                })
+    // Pad the input to a multiple of `BHP_CHUNK_SIZE` for hashing.
+    let input = if input.len() % BHP_CHUNK_SIZE != 0 {
+        let padding = BHP_CHUNK_SIZE - (input.len() % BHP_CHUNK_SIZE);
+        let mut padded_input = vec![false; input.len() + padding];
+        padded_input[..input.len()].copy_from_slice(input);
+        ensure!((padded_input.len() % BHP_CHUNK_SIZE) == 0, "Input must be a multiple of {BHP_CHUNK_SIZE}");
+        Cow::Owned(padded_input)
+    } else {
+        Cow::Borrowed(input)
+    };
+
+    // Compute sum of h_i^{sum of (1-2*c_{i,j,2})*(1+c_{i,j,0}+2*c_{i,j,1})*2^{4*(j-1)} for all j in segment}
+    // for all i. Described in section 5.4.1.7 in the Zcash protocol specification.
+    //
+    // Note: `.zip()` is used here (as opposed to `.zip_eq()`) as the input can be less than
+    // `num_windows * window_size * BHP_CHUNK_SIZE` in length, which is the parameter size here.
+    Ok(input
+        .chunks(window_size as usize * BHP_CHUNK_SIZE)
+        .zip(bases_lookup)
+        .flat_map(|(bits, bases)| {
+            bits.chunks(BHP_CHUNK_SIZE).zip(bases).map(|(chunk_bits, base)| {
+                base[(chunk_bits[0] as usize) | (chunk_bits[1] as usize) << 1 | (chunk_bits[2] as usize) << 2]
             })
-            .sum())
-    }
+        })
+        .sum())
 }
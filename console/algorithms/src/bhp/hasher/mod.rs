@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod hash_uncompressed;
+pub(super) use hash_uncompressed::hash_bases_lookup;
 
 use crate::Blake2Xs;
 use snarkvm_console_types::prelude::*;
@@ -37,91 +38,9 @@ pub struct BHPHasher<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u
 }
 
 impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE> {
-    /// The maximum number of input bits.
-    const MAX_BITS: usize = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
-    /// The minimum number of input bits (at least one window).
-    const MIN_BITS: usize = WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
-
     /// Initializes a new instance of BHP with the given domain.
-    pub fn setup(domain: &str) -> Result<Self> {
-        // Calculate the maximum window size.
-        let mut maximum_window_size = 0;
-        let mut range = E::BigInteger::from(2_u64);
-        while range < E::Scalar::modulus_minus_one_div_two() {
-            // range < (p-1)/2
-            range.muln(4); // range * 2^4
-            maximum_window_size += 1;
-        }
-        ensure!(WINDOW_SIZE <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
-
-        // Compute the bases.
-        let bases = (0..NUM_WINDOWS)
-            .map(|index| {
-                // Construct an indexed message to attempt to sample a base.
-                let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!(
-                    "Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.{index}"
-                ));
-                let mut base = Group::<E>::new(generator);
-                // Compute the generators for the sampled base.
-                let mut powers = Vec::with_capacity(WINDOW_SIZE as usize);
-                for _ in 0..WINDOW_SIZE {
-                    powers.push(base);
-                    for _ in 0..4 {
-                        base = base.double();
-                    }
-                }
-                powers
-            })
-            .collect::<Vec<Vec<Group<E>>>>();
-        ensure!(bases.len() == NUM_WINDOWS as usize, "Incorrect number of BHP windows ({})", bases.len());
-        for window in &bases {
-            ensure!(window.len() == WINDOW_SIZE as usize, "Incorrect BHP window size ({})", window.len());
-        }
-
-        // Compute the bases lookup.
-        let bases_lookup = bases
-            .iter()
-            .map(|x| {
-                x.iter()
-                    .map(|g| {
-                        let mut lookup = [Group::<E>::zero(); BHP_LOOKUP_SIZE];
-                        for (i, element) in lookup.iter_mut().enumerate().take(BHP_LOOKUP_SIZE) {
-                            *element = *g;
-                            if (i & 0x01) != 0 {
-                                *element += g;
-                            }
-                            if (i & 0x02) != 0 {
-                                *element += g.double();
-                            }
-                            if (i & 0x04) != 0 {
-                                *element = element.neg();
-                            }
-                        }
-                        lookup
-                    })
-                    .collect()
-            })
-            .collect::<Vec<Vec<[Group<E>; BHP_LOOKUP_SIZE]>>>();
-        ensure!(bases_lookup.len() == NUM_WINDOWS as usize, "Incorrect number of BHP lookups ({})", bases_lookup.len());
-        for window in &bases_lookup {
-            ensure!(window.len() == WINDOW_SIZE as usize, "Incorrect BHP lookup window size ({})", window.len());
-        }
-
-        // Next, compute the random base.
-        let (generator, _, _) =
-            Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.Randomizer"));
-        let mut base_power = Group::<E>::new(generator);
-        let mut random_base = Vec::with_capacity(Scalar::<E>::size_in_bits());
-        for _ in 0..Scalar::<E>::size_in_bits() {
-            random_base.push(base_power);
-            base_power = base_power.double();
-        }
-        ensure!(
-            random_base.len() == Scalar::<E>::size_in_bits(),
-            "Incorrect number of BHP random base powers ({})",
-            random_base.len()
-        );
-
+    pub fn setup(domain: DomainSeparator) -> Result<Self> {
+        let (bases, bases_lookup, random_base) = setup_bases::<E>(domain, NUM_WINDOWS, WINDOW_SIZE)?;
         Ok(Self { bases: Arc::new(bases), bases_lookup: Arc::new(bases_lookup), random_base: Arc::new(random_base) })
     }
 
@@ -135,3 +54,90 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
         &self.random_base
     }
 }
+
+/// Returns the bases, the bases lookup table, and the random base for the given domain, number of windows, and window size.
+#[allow(clippy::type_complexity)]
+pub(super) fn setup_bases<E: Environment>(
+    domain: DomainSeparator,
+    num_windows: u8,
+    window_size: u8,
+) -> Result<(Vec<Vec<Group<E>>>, Vec<Vec<[Group<E>; BHP_LOOKUP_SIZE]>>, Vec<Group<E>>)> {
+    // Calculate the maximum window size.
+    let mut maximum_window_size = 0;
+    let mut range = E::BigInteger::from(2_u64);
+    while range < E::Scalar::modulus_minus_one_div_two() {
+        // range < (p-1)/2
+        range.muln(4); // range * 2^4
+        maximum_window_size += 1;
+    }
+    ensure!(window_size <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
+
+    // Compute the bases.
+    let bases = (0..num_windows)
+        .map(|index| {
+            // Construct an indexed message to attempt to sample a base.
+            let (generator, _, _) =
+                Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.BHP.{num_windows}.{window_size}.{domain}.{index}"));
+            let mut base = Group::<E>::new(generator);
+            // Compute the generators for the sampled base.
+            let mut powers = Vec::with_capacity(window_size as usize);
+            for _ in 0..window_size {
+                powers.push(base);
+                for _ in 0..4 {
+                    base = base.double();
+                }
+            }
+            powers
+        })
+        .collect::<Vec<Vec<Group<E>>>>();
+    ensure!(bases.len() == num_windows as usize, "Incorrect number of BHP windows ({})", bases.len());
+    for window in &bases {
+        ensure!(window.len() == window_size as usize, "Incorrect BHP window size ({})", window.len());
+    }
+
+    // Compute the bases lookup.
+    let bases_lookup = bases
+        .iter()
+        .map(|x| {
+            x.iter()
+                .map(|g| {
+                    let mut lookup = [Group::<E>::zero(); BHP_LOOKUP_SIZE];
+                    for (i, element) in lookup.iter_mut().enumerate().take(BHP_LOOKUP_SIZE) {
+                        *element = *g;
+                        if (i & 0x01) != 0 {
+                            *element += g;
+                        }
+                        if (i & 0x02) != 0 {
+                            *element += g.double();
+                        }
+                        if (i & 0x04) != 0 {
+                            *element = element.neg();
+                        }
+                    }
+                    lookup
+                })
+                .collect()
+        })
+        .collect::<Vec<Vec<[Group<E>; BHP_LOOKUP_SIZE]>>>();
+    ensure!(bases_lookup.len() == num_windows as usize, "Incorrect number of BHP lookups ({})", bases_lookup.len());
+    for window in &bases_lookup {
+        ensure!(window.len() == window_size as usize, "Incorrect BHP lookup window size ({})", window.len());
+    }
+
+    // Next, compute the random base.
+    let (generator, _, _) =
+        Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.BHP.{num_windows}.{window_size}.{domain}.Randomizer"));
+    let mut base_power = Group::<E>::new(generator);
+    let mut random_base = Vec::with_capacity(Scalar::<E>::size_in_bits());
+    for _ in 0..Scalar::<E>::size_in_bits() {
+        random_base.push(base_power);
+        base_power = base_power.double();
+    }
+    ensure!(
+        random_base.len() == Scalar::<E>::size_in_bits(),
+        "Incorrect number of BHP random base powers ({})",
+        random_base.len()
+    );
+
+    Ok((bases, bases_lookup, random_base))
+}
@@ -17,8 +17,13 @@ use hasher::BHPHasher;
 
 mod commit;
 mod commit_uncompressed;
+mod dynamic;
+pub use dynamic::BHPDynamic;
 mod hash;
+mod hash_incremental;
+pub use hash_incremental::BHPIncremental;
 mod hash_uncompressed;
+mod rerandomize;
 
 use snarkvm_console_types::prelude::*;
 
@@ -59,9 +64,12 @@ pub struct BHP<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
 
 impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
     /// Initializes a new instance of BHP with the given domain.
-    pub fn setup(domain: &str) -> Result<Self> {
+    pub fn setup(domain: DomainSeparator) -> Result<Self> {
+        // Encode the domain separator.
+        let encoded_domain = domain.to_string();
+
         // Ensure the given domain is within the allowed size in bits.
-        let num_bits = domain.len().saturating_mul(8);
+        let num_bits = encoded_domain.len().saturating_mul(8);
         let max_bits = Field::<E>::size_in_data_bits() - 64; // 64 bits encode the length.
         ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
 
@@ -69,7 +77,7 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WI
         let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::setup(domain)?;
 
         // Convert the domain into a boolean vector.
-        let mut domain = domain.as_bytes().to_bits_le();
+        let mut domain = encoded_domain.as_bytes().to_bits_le();
         // Pad the domain with zeros up to the maximum size in bits.
         domain.resize(max_bits, false);
         // Reverse the domain so that it is: [ 0...0 || DOMAIN ].
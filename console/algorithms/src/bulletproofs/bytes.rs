@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> FromBytes for RangeProof<E> {
+    /// Reads the range proof from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let a = Group::read_le(&mut reader)?;
+        let s = Group::read_le(&mut reader)?;
+        let t_1 = Group::read_le(&mut reader)?;
+        let t_2 = Group::read_le(&mut reader)?;
+        let tau_x = Scalar::read_le(&mut reader)?;
+        let mu = Scalar::read_le(&mut reader)?;
+        let t_hat = Scalar::read_le(&mut reader)?;
+        let num_rounds = u8::read_le(&mut reader)?;
+        let l_vec = (0..num_rounds).map(|_| Group::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        let r_vec = (0..num_rounds).map(|_| Group::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        let final_a = Scalar::read_le(&mut reader)?;
+        let final_b = Scalar::read_le(&mut reader)?;
+        Ok(Self::new(a, s, t_1, t_2, tau_x, mu, t_hat, l_vec, r_vec, final_a, final_b))
+    }
+}
+
+impl<E: Environment> ToBytes for RangeProof<E> {
+    /// Writes the range proof to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.a().write_le(&mut writer)?;
+        self.s().write_le(&mut writer)?;
+        self.t_1().write_le(&mut writer)?;
+        self.t_2().write_le(&mut writer)?;
+        self.tau_x().write_le(&mut writer)?;
+        self.mu().write_le(&mut writer)?;
+        self.t_hat().write_le(&mut writer)?;
+        // Ensure there is one right cross term for each left cross term.
+        if self.l_vec().len() != self.r_vec().len() {
+            return Err(error("Mismatched number of cross terms in the range proof"));
+        }
+        let num_rounds = u8::try_from(self.l_vec().len()).map_err(|_| error("Too many rounds in the range proof"))?;
+        num_rounds.write_le(&mut writer)?;
+        self.l_vec().iter().try_for_each(|l| l.write_le(&mut writer))?;
+        self.r_vec().iter().try_for_each(|r| r.write_le(&mut writer))?;
+        self.final_a().write_le(&mut writer)?;
+        self.final_b().write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pedersen64;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let pedersen = Pedersen64::<CurrentEnvironment>::setup(DomainSeparator::new("BulletproofsTest", 0));
+        let bulletproofs = Bulletproofs64::setup(&pedersen, DomainSeparator::new("BulletproofsTest", 0), 2)?;
+        let values = [rng.gen::<u64>() as u128, rng.gen::<u64>() as u128];
+        let randomizers = [Scalar::rand(rng), Scalar::rand(rng)];
+        let proof = bulletproofs.prove(&values, &randomizers, rng)?;
+
+        // Check the byte representation.
+        let proof_bytes = proof.to_bytes_le()?;
+        assert_eq!(proof, RangeProof::read_le(&proof_bytes[..])?);
+        assert!(RangeProof::<CurrentEnvironment>::read_le(&proof_bytes[1..]).is_err());
+        Ok(())
+    }
+}
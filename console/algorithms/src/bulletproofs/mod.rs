@@ -0,0 +1,185 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod proof;
+mod prove;
+mod verify;
+
+pub use proof::RangeProof;
+
+use crate::{Blake2Xs, Pedersen, Poseidon4, PoseidonDuplex};
+use snarkvm_console_types::prelude::*;
+
+use std::sync::Arc;
+
+/// Bulletproofs64 proves that values committed with `Pedersen64` lie in `[0, 2^64)`.
+pub type Bulletproofs64<E> = Bulletproofs<E, 64>;
+/// Bulletproofs128 proves that values committed with `Pedersen128` lie in `[0, 2^128)`.
+pub type Bulletproofs128<E> = Bulletproofs<E, 128>;
+
+/// Bulletproofs is a range proof system for Pedersen commitments, which proves that each committed
+/// value lies in `[0, 2^NUM_BITS)`, without revealing the value and without a trusted setup.
+///
+/// The commitments are those of [`Pedersen`], i.e. `V = v * G + r * H`, where `G` and `H` are the first
+/// bases of its base window and random base window. The range proofs of several commitments are aggregated
+/// into a single proof, whose size is logarithmic in the total number of bits, following the construction
+/// of Bünz et al. ([BBBPWM18](https://eprint.iacr.org/2017/1066)). The protocol is made non-interactive
+/// with the Fiat-Shamir transform, over a running Poseidon sponge in duplex mode.
+#[derive(Clone)]
+pub struct Bulletproofs<E: Environment, const NUM_BITS: u8> {
+    /// The base of the committed value, i.e. `G`.
+    value_base: Group<E>,
+    /// The base of the randomizer, i.e. `H`.
+    randomizer_base: Group<E>,
+    /// The bases for the bits of the values.
+    g_bases: Arc<Vec<Group<E>>>,
+    /// The bases for the bits of the values, minus one.
+    h_bases: Arc<Vec<Group<E>>>,
+    /// The base for the inner product of the inner-product argument.
+    u_base: Group<E>,
+    /// The Poseidon hash function for the transcript, which is separated by the domain.
+    poseidon: Poseidon4<E>,
+}
+
+impl<E: Environment, const NUM_BITS: u8> Bulletproofs<E, NUM_BITS> {
+    /// Initializes a new instance of Bulletproofs for the given Pedersen commitment scheme and domain,
+    /// which aggregates the range proofs of up to `max_values` commitments.
+    pub fn setup(pedersen: &Pedersen<E, NUM_BITS>, domain: DomainSeparator, max_values: usize) -> Result<Self> {
+        // Ensure the number of bits is a power of two that fits in a `u128`.
+        ensure!(NUM_BITS.is_power_of_two() && NUM_BITS <= 128, "The number of bits must be a power of two, up to 128");
+        // Ensure the maximum number of values is a nonzero power of two.
+        ensure!(max_values.is_power_of_two(), "The maximum number of values must be a nonzero power of two");
+
+        // Retrieve the bases of the Pedersen commitment.
+        let value_base = pedersen.base_window()[0];
+        let randomizer_base = pedersen.random_base_window()[0];
+
+        // Compute the bases for the bits of the values.
+        let num_bases = NUM_BITS as usize * max_values;
+        let hash_to_group = |name: &str, index: usize| {
+            let (generator, _, _) =
+                Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.Bulletproofs.{name}.{domain}.{index}"));
+            Group::<E>::new(generator)
+        };
+        let g_bases = (0..num_bases).map(|index| hash_to_group("G", index)).collect::<Vec<_>>();
+        let h_bases = (0..num_bases).map(|index| hash_to_group("H", index)).collect::<Vec<_>>();
+        let u_base = hash_to_group("U", 0);
+
+        Ok(Self {
+            value_base,
+            randomizer_base,
+            g_bases: Arc::new(g_bases),
+            h_bases: Arc::new(h_bases),
+            u_base,
+            poseidon: Poseidon4::setup(domain)?,
+        })
+    }
+
+    /// Returns the base of the committed value.
+    pub const fn value_base(&self) -> Group<E> {
+        self.value_base
+    }
+
+    /// Returns the base of the randomizer.
+    pub const fn randomizer_base(&self) -> Group<E> {
+        self.randomizer_base
+    }
+
+    /// Returns the maximum number of commitments in an aggregated range proof.
+    pub fn max_values(&self) -> usize {
+        self.g_bases.len() / NUM_BITS as usize
+    }
+}
+
+impl<E: Environment, const NUM_BITS: u8> Bulletproofs<E, NUM_BITS> {
+    /// Returns the transcript for the given commitments.
+    fn initialize_transcript(&self, commitments: &[Group<E>]) -> PoseidonDuplex<E, 4> {
+        let mut transcript = self.poseidon.duplex();
+        transcript.absorb(&[Field::from_u8(NUM_BITS), Field::from_u64(commitments.len() as u64)]);
+        Self::absorb(&mut transcript, commitments);
+        transcript
+    }
+
+    /// Absorbs the coordinates of the given group elements into the transcript.
+    fn absorb(transcript: &mut PoseidonDuplex<E, 4>, elements: &[Group<E>]) {
+        for element in elements {
+            transcript.absorb(&[element.to_x_coordinate(), element.to_y_coordinate()]);
+        }
+    }
+
+    /// Returns the next challenge from the transcript.
+    fn challenge(transcript: &mut PoseidonDuplex<E, 4>) -> Scalar<E> {
+        // Truncate the output to the size in data bits of the scalar field.
+        Scalar::from_field_lossy(&transcript.squeeze(1)[0])
+    }
+
+    /// Returns the bits of the given values, as scalars.
+    fn bits_of(values: &[u128]) -> Vec<Scalar<E>> {
+        values
+            .iter()
+            .flat_map(|value| {
+                (0..NUM_BITS).map(move |i| if (value >> i) & 1 == 1 { Scalar::one() } else { Scalar::zero() })
+            })
+            .collect()
+    }
+}
+
+/// Returns the first `num_powers` powers of `base`, starting with `1`.
+fn powers<E: Environment>(base: Scalar<E>, num_powers: usize) -> Vec<Scalar<E>> {
+    std::iter::successors(Some(Scalar::one()), |power| Some(*power * base)).take(num_powers).collect()
+}
+
+/// Returns the inner product of `a` and `b`.
+fn inner_product<E: Environment>(a: &[Scalar<E>], b: &[Scalar<E>]) -> Scalar<E> {
+    a.iter().zip_eq(b).map(|(a, b)| *a * b).sum()
+}
+
+/// Returns the multi-scalar multiplication of `bases` and `scalars`.
+fn multi_scalar_mul<E: Environment>(bases: &[Group<E>], scalars: &[Scalar<E>]) -> Group<E> {
+    bases.iter().zip_eq(scalars).map(|(base, scalar)| *base * scalar).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pedersen64;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const DOMAIN: DomainSeparator = DomainSeparator::new("BulletproofsTest", 0);
+
+    #[test]
+    fn test_setup() -> Result<()> {
+        let pedersen = Pedersen64::<CurrentEnvironment>::setup(DomainSeparator::new("BulletproofsTest", 0));
+        let bulletproofs = Bulletproofs64::setup(&pedersen, DOMAIN, 4)?;
+        assert_eq!(bulletproofs.max_values(), 4);
+        assert_eq!(bulletproofs.value_base(), pedersen.base_window()[0]);
+        assert_eq!(bulletproofs.randomizer_base(), pedersen.random_base_window()[0]);
+
+        // Ensure the bases are distinct.
+        let mut bases = bulletproofs.g_bases.to_vec();
+        bases.extend(bulletproofs.h_bases.iter());
+        bases.extend([bulletproofs.u_base, bulletproofs.value_base, bulletproofs.randomizer_base]);
+        assert!(!has_duplicates(bases));
+
+        // Ensure invalid parameters are rejected.
+        assert!(Bulletproofs64::setup(&pedersen, DOMAIN, 0).is_err());
+        assert!(Bulletproofs64::setup(&pedersen, DOMAIN, 3).is_err());
+        let pedersen = Pedersen::<CurrentEnvironment, 48>::setup(DomainSeparator::new("BulletproofsTest", 0));
+        assert!(Bulletproofs::setup(&pedersen, DOMAIN, 1).is_err());
+        Ok(())
+    }
+}
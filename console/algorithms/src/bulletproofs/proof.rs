@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An aggregated range proof of Bulletproofs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeProof<E: Environment> {
+    /// The commitment to the bits of the values, i.e. `A`.
+    a: Group<E>,
+    /// The commitment to the blinding vectors of the bits, i.e. `S`.
+    s: Group<E>,
+    /// The commitment to the linear coefficient of `t(X)`, i.e. `T_1`.
+    t_1: Group<E>,
+    /// The commitment to the quadratic coefficient of `t(X)`, i.e. `T_2`.
+    t_2: Group<E>,
+    /// The randomizer of the evaluation of `t(X)`, i.e. `tau_x`.
+    tau_x: Scalar<E>,
+    /// The randomizer of `A` and `S`, i.e. `mu`.
+    mu: Scalar<E>,
+    /// The evaluation of `t(X)` at the challenge, i.e. `t_hat`.
+    t_hat: Scalar<E>,
+    /// The left cross terms of each round of the inner-product argument.
+    l_vec: Vec<Group<E>>,
+    /// The right cross terms of each round of the inner-product argument.
+    r_vec: Vec<Group<E>>,
+    /// The remaining element of the left vector of the inner-product argument.
+    final_a: Scalar<E>,
+    /// The remaining element of the right vector of the inner-product argument.
+    final_b: Scalar<E>,
+}
+
+impl<E: Environment> RangeProof<E> {
+    /// Returns the range proof with the given components.
+    pub const fn new(
+        a: Group<E>,
+        s: Group<E>,
+        t_1: Group<E>,
+        t_2: Group<E>,
+        tau_x: Scalar<E>,
+        mu: Scalar<E>,
+        t_hat: Scalar<E>,
+        l_vec: Vec<Group<E>>,
+        r_vec: Vec<Group<E>>,
+        final_a: Scalar<E>,
+        final_b: Scalar<E>,
+    ) -> Self {
+        Self { a, s, t_1, t_2, tau_x, mu, t_hat, l_vec, r_vec, final_a, final_b }
+    }
+
+    /// Returns the commitment to the bits of the values.
+    pub const fn a(&self) -> Group<E> {
+        self.a
+    }
+
+    /// Returns the commitment to the blinding vectors of the bits.
+    pub const fn s(&self) -> Group<E> {
+        self.s
+    }
+
+    /// Returns the commitment to the linear coefficient of `t(X)`.
+    pub const fn t_1(&self) -> Group<E> {
+        self.t_1
+    }
+
+    /// Returns the commitment to the quadratic coefficient of `t(X)`.
+    pub const fn t_2(&self) -> Group<E> {
+        self.t_2
+    }
+
+    /// Returns the randomizer of the evaluation of `t(X)`.
+    pub const fn tau_x(&self) -> Scalar<E> {
+        self.tau_x
+    }
+
+    /// Returns the randomizer of `A` and `S`.
+    pub const fn mu(&self) -> Scalar<E> {
+        self.mu
+    }
+
+    /// Returns the evaluation of `t(X)` at the challenge.
+    pub const fn t_hat(&self) -> Scalar<E> {
+        self.t_hat
+    }
+
+    /// Returns the left cross terms of each round of the inner-product argument.
+    pub fn l_vec(&self) -> &[Group<E>] {
+        &self.l_vec
+    }
+
+    /// Returns the right cross terms of each round of the inner-product argument.
+    pub fn r_vec(&self) -> &[Group<E>] {
+        &self.r_vec
+    }
+
+    /// Returns the remaining element of the left vector of the inner-product argument.
+    pub const fn final_a(&self) -> Scalar<E> {
+        self.final_a
+    }
+
+    /// Returns the remaining element of the right vector of the inner-product argument.
+    pub const fn final_b(&self) -> Scalar<E> {
+        self.final_b
+    }
+}
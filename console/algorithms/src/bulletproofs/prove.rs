@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Bulletproofs<E, NUM_BITS> {
+    /// Returns the commitment to the given value with the given randomizer, i.e. `v * G + r * H`.
+    /// For a value less than `2^NUM_BITS`, this is the `Pedersen` commitment of the little-endian bits of the value.
+    pub fn commit(&self, value: u128, randomizer: &Scalar<E>) -> Group<E> {
+        self.value_base * Scalar::new(E::Scalar::from(value)) + self.randomizer_base * randomizer
+    }
+
+    /// Returns an aggregated proof that each of the given values lies in `[0, 2^NUM_BITS)`,
+    /// for the commitments of the values with the given randomizers.
+    pub fn prove<R: Rng + CryptoRng>(
+        &self,
+        values: &[u128],
+        randomizers: &[Scalar<E>],
+        rng: &mut R,
+    ) -> Result<RangeProof<E>> {
+        ensure!(!values.is_empty(), "Expected at least one value");
+        ensure!(values.len() == randomizers.len(), "Expected one randomizer per value");
+        ensure!(values.len() <= self.max_values(), "Expected at most {} values", self.max_values());
+        // Ensure each value lies in the range.
+        if NUM_BITS < 128 {
+            ensure!(values.iter().all(|value| value >> NUM_BITS == 0), "Each value must be less than 2^{NUM_BITS}");
+        }
+
+        // Pad the values to a power of two, with zero values and randomizers, whose commitments are zero.
+        let num_values = values.len().next_power_of_two();
+        let mut values = values.to_vec();
+        values.resize(num_values, 0);
+        let mut randomizers = randomizers.to_vec();
+        randomizers.resize(num_values, Scalar::zero());
+
+        let commitments =
+            values.iter().zip_eq(&randomizers).map(|(value, randomizer)| self.commit(*value, randomizer)).collect_vec();
+        let mut transcript = self.initialize_transcript(&commitments);
+
+        let num_bits = NUM_BITS as usize * num_values;
+        let g_bases = &self.g_bases[..num_bits];
+        let h_bases = &self.h_bases[..num_bits];
+
+        // Commit to the bits of the values `a_L`, and to `a_R = a_L - 1`.
+        let a_l = Self::bits_of(&values);
+        let a_r = a_l.iter().map(|bit| *bit - Scalar::one()).collect_vec();
+        let alpha = Scalar::rand(rng);
+        let a = self.randomizer_base * alpha + multi_scalar_mul(g_bases, &a_l) + multi_scalar_mul(h_bases, &a_r);
+
+        // Commit to the blinding vectors `s_L` and `s_R`.
+        let s_l = (0..num_bits).map(|_| Scalar::rand(rng)).collect_vec();
+        let s_r = (0..num_bits).map(|_| Scalar::rand(rng)).collect_vec();
+        let rho = Scalar::rand(rng);
+        let s = self.randomizer_base * rho + multi_scalar_mul(g_bases, &s_l) + multi_scalar_mul(h_bases, &s_r);
+
+        Self::absorb(&mut transcript, &[a, s]);
+        let y = Self::challenge(&mut transcript);
+        let z = Self::challenge(&mut transcript);
+
+        // Compute the coefficients of `l(X) = l_0 + l_1 X` and `r(X) = r_0 + r_1 X`, where
+        // `l_0 = a_L - z`, `l_1 = s_L`, `r_0 = y^i (a_R + z) + z^(2+j) 2^k`, and `r_1 = y^i s_R`,
+        // for the `k`-th bit of the `j`-th value at index `i = j * NUM_BITS + k`.
+        let powers_of_y = powers(y, num_bits);
+        let powers_of_two = powers(Scalar::<E>::one().double(), NUM_BITS as usize);
+        let powers_of_z = powers(z, num_values + 2);
+        let l_0 = a_l.iter().map(|bit| *bit - z).collect_vec();
+        let r_0 = (0..num_bits)
+            .map(|i| {
+                powers_of_y[i] * (a_r[i] + z)
+                    + powers_of_z[2 + i / NUM_BITS as usize] * powers_of_two[i % NUM_BITS as usize]
+            })
+            .collect_vec();
+        let r_1 = powers_of_y.iter().zip_eq(&s_r).map(|(y_i, s_r_i)| *y_i * s_r_i).collect_vec();
+
+        // Commit to the coefficients of `t(X) = <l(X), r(X)> = t_0 + t_1 X + t_2 X^2`.
+        let t_1 = inner_product(&l_0, &r_1) + inner_product(&s_l, &r_0);
+        let t_2 = inner_product(&s_l, &r_1);
+        let tau_1 = Scalar::rand(rng);
+        let tau_2 = Scalar::rand(rng);
+        let commitment_t_1 = self.value_base * t_1 + self.randomizer_base * tau_1;
+        let commitment_t_2 = self.value_base * t_2 + self.randomizer_base * tau_2;
+
+        Self::absorb(&mut transcript, &[commitment_t_1, commitment_t_2]);
+        let x = Self::challenge(&mut transcript);
+
+        // Evaluate `l(X)` and `r(X)` at the challenge.
+        let l = l_0.iter().zip_eq(&s_l).map(|(l_0, l_1)| *l_0 + *l_1 * x).collect_vec();
+        let r = r_0.iter().zip_eq(&r_1).map(|(r_0, r_1)| *r_0 + *r_1 * x).collect_vec();
+        let t_hat = inner_product(&l, &r);
+        let tau_x = tau_2 * x.square()
+            + tau_1 * x
+            + randomizers.iter().enumerate().map(|(j, randomizer)| powers_of_z[2 + j] * randomizer).sum::<Scalar<E>>();
+        let mu = alpha + rho * x;
+
+        transcript.absorb(&[tau_x.to_field()?, mu.to_field()?, t_hat.to_field()?]);
+        let w = Self::challenge(&mut transcript);
+
+        // Prove that `t_hat = <l, r>`, with respect to the bases `G` and `H' = y^(-i) H`.
+        let powers_of_y_inverse = powers(y.inverse()?, num_bits);
+        let h_prime_bases = h_bases.iter().zip_eq(&powers_of_y_inverse).map(|(h, y_i)| *h * y_i).collect_vec();
+        let (l_vec, r_vec, final_a, final_b) =
+            Self::prove_inner_product(&mut transcript, self.u_base * w, g_bases.to_vec(), h_prime_bases, l, r)?;
+
+        Ok(RangeProof::new(a, s, commitment_t_1, commitment_t_2, tau_x, mu, t_hat, l_vec, r_vec, final_a, final_b))
+    }
+
+    /// Returns the cross terms of each round of the inner-product argument for `<a, b>`,
+    /// along with the remaining elements of `a` and `b`.
+    #[allow(clippy::type_complexity)]
+    fn prove_inner_product(
+        transcript: &mut PoseidonDuplex<E, 4>,
+        u: Group<E>,
+        mut g: Vec<Group<E>>,
+        mut h: Vec<Group<E>>,
+        mut a: Vec<Scalar<E>>,
+        mut b: Vec<Scalar<E>>,
+    ) -> Result<(Vec<Group<E>>, Vec<Group<E>>, Scalar<E>, Scalar<E>)> {
+        let mut l_vec = Vec::with_capacity(a.len().trailing_zeros() as usize);
+        let mut r_vec = Vec::with_capacity(a.len().trailing_zeros() as usize);
+
+        while a.len() > 1 {
+            let half = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(half);
+            let (b_lo, b_hi) = b.split_at(half);
+            let (g_lo, g_hi) = g.split_at(half);
+            let (h_lo, h_hi) = h.split_at(half);
+
+            let l = multi_scalar_mul(g_hi, a_lo) + multi_scalar_mul(h_lo, b_hi) + u * inner_product(a_lo, b_hi);
+            let r = multi_scalar_mul(g_lo, a_hi) + multi_scalar_mul(h_hi, b_lo) + u * inner_product(a_hi, b_lo);
+
+            Self::absorb(transcript, &[l, r]);
+            let x = Self::challenge(transcript);
+            let x_inverse = x.inverse()?;
+            l_vec.push(l);
+            r_vec.push(r);
+
+            // Fold the vectors, such that `<a, G> + <b, H> + <a, b> U` becomes `P + x^2 L + x^(-2) R`.
+            let next_a = a_lo.iter().zip_eq(a_hi).map(|(lo, hi)| *lo * x + *hi * x_inverse).collect();
+            let next_b = b_lo.iter().zip_eq(b_hi).map(|(lo, hi)| *lo * x_inverse + *hi * x).collect();
+            let next_g = g_lo.iter().zip_eq(g_hi).map(|(lo, hi)| *lo * x_inverse + *hi * x).collect();
+            let next_h = h_lo.iter().zip_eq(h_hi).map(|(lo, hi)| *lo * x + *hi * x_inverse).collect();
+            (a, b, g, h) = (next_a, next_b, next_g, next_h);
+        }
+
+        Ok((l_vec, r_vec, a[0], b[0]))
+    }
+}
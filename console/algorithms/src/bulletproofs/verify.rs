@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Bulletproofs<E, NUM_BITS> {
+    /// Returns `true` if the proof shows that each of the given commitments is to a value in `[0, 2^NUM_BITS)`.
+    pub fn verify(&self, commitments: &[Group<E>], proof: &RangeProof<E>) -> bool {
+        self.check_proof(commitments, proof).unwrap_or(false)
+    }
+
+    /// Returns `true` if the proof is valid for the given commitments.
+    fn check_proof(&self, commitments: &[Group<E>], proof: &RangeProof<E>) -> Result<bool> {
+        ensure!(!commitments.is_empty(), "Expected at least one commitment");
+        ensure!(commitments.len() <= self.max_values(), "Expected at most {} commitments", self.max_values());
+
+        // Pad the commitments to a power of two, with zero commitments.
+        let num_values = commitments.len().next_power_of_two();
+        let mut commitments = commitments.to_vec();
+        commitments.resize(num_values, Group::zero());
+
+        let num_bits = NUM_BITS as usize * num_values;
+        let num_rounds = num_bits.trailing_zeros() as usize;
+        ensure!(proof.l_vec().len() == num_rounds, "Expected {num_rounds} left cross terms");
+        ensure!(proof.r_vec().len() == num_rounds, "Expected {num_rounds} right cross terms");
+
+        // Recompute the challenges from the transcript.
+        let mut transcript = self.initialize_transcript(&commitments);
+        Self::absorb(&mut transcript, &[proof.a(), proof.s()]);
+        let y = Self::challenge(&mut transcript);
+        let z = Self::challenge(&mut transcript);
+        Self::absorb(&mut transcript, &[proof.t_1(), proof.t_2()]);
+        let x = Self::challenge(&mut transcript);
+        transcript.absorb(&[proof.tau_x().to_field()?, proof.mu().to_field()?, proof.t_hat().to_field()?]);
+        let w = Self::challenge(&mut transcript);
+
+        let mut round_challenges = Vec::with_capacity(num_rounds);
+        for (l, r) in proof.l_vec().iter().zip_eq(proof.r_vec()) {
+            Self::absorb(&mut transcript, &[*l, *r]);
+            let challenge = Self::challenge(&mut transcript);
+            round_challenges.push((challenge, challenge.inverse()?));
+        }
+
+        let powers_of_y = powers(y, num_bits);
+        let powers_of_two = powers(Scalar::<E>::one().double(), NUM_BITS as usize);
+        let powers_of_z = powers(z, num_values + 3);
+
+        // Check that `t_hat G + tau_x H = z^2 z^j V_j + delta(y, z) G + x T_1 + x^2 T_2`, where
+        // `delta(y, z) = (z - z^2) <1, y^i> - z^3 z^j <1, 2^k>`.
+        let sum_of_powers_of_y = powers_of_y.iter().sum::<Scalar<E>>();
+        let sum_of_powers_of_two = powers_of_two.iter().sum::<Scalar<E>>();
+        let sum_of_powers_of_z = powers_of_z[3..].iter().sum::<Scalar<E>>();
+        let delta = (z - powers_of_z[2]) * sum_of_powers_of_y - sum_of_powers_of_z * sum_of_powers_of_two;
+        let expected = multi_scalar_mul(&commitments, &powers_of_z[2..num_values + 2])
+            + self.value_base * delta
+            + proof.t_1() * x
+            + proof.t_2() * x.square();
+        if self.value_base * proof.t_hat() + self.randomizer_base * proof.tau_x() != expected {
+            return Ok(false);
+        }
+
+        // Compute the scalars `s_i`, such that the folded bases of the inner-product argument
+        // are `<s, G>` and `<s^(-1), H'>`. Round `j` folds the indices with bit `num_rounds - 1 - j` set.
+        let mut s = vec![Scalar::<E>::one()];
+        let mut s_inverse = vec![Scalar::<E>::one()];
+        for (challenge, challenge_inverse) in round_challenges.iter().rev() {
+            s = s.iter().map(|s_i| *s_i * challenge_inverse).chain(s.iter().map(|s_i| *s_i * challenge)).collect();
+            s_inverse = s_inverse
+                .iter()
+                .map(|s_i| *s_i * challenge)
+                .chain(s_inverse.iter().map(|s_i| *s_i * challenge_inverse))
+                .collect();
+        }
+
+        // Check that `P = A + x S - z <1, G> + <z y^i + z^(2+j) 2^k, H'> - mu H + t_hat U'` opens to
+        // `final_a <s, G> + final_b <s^(-1), H'> + final_a final_b U'`, after folding in the cross terms,
+        // where `H' = y^(-i) H` and `U' = w U`.
+        let powers_of_y_inverse = powers(y.inverse()?, num_bits);
+        let g_scalars = s.iter().map(|s_i| -z - proof.final_a() * s_i).collect_vec();
+        let h_scalars = (0..num_bits)
+            .map(|i| {
+                let two_term = powers_of_z[2 + i / NUM_BITS as usize] * powers_of_two[i % NUM_BITS as usize];
+                z + (two_term - proof.final_b() * s_inverse[i]) * powers_of_y_inverse[i]
+            })
+            .collect_vec();
+        let cross_terms = proof
+            .l_vec()
+            .iter()
+            .zip_eq(proof.r_vec())
+            .zip_eq(&round_challenges)
+            .map(|((l, r), (challenge, challenge_inverse))| *l * challenge.square() + *r * challenge_inverse.square())
+            .sum::<Group<E>>();
+        let check = proof.a()
+            + proof.s() * x
+            + multi_scalar_mul(&self.g_bases[..num_bits], &g_scalars)
+            + multi_scalar_mul(&self.h_bases[..num_bits], &h_scalars)
+            - self.randomizer_base * proof.mu()
+            + self.u_base * ((proof.t_hat() - proof.final_a() * proof.final_b()) * w)
+            + cross_terms;
+        Ok(check.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pedersen64;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 5;
+
+    fn check_prove_and_verify(num_values: usize) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let pedersen = Pedersen64::<CurrentEnvironment>::setup(DomainSeparator::new("BulletproofsTest", 0));
+        let bulletproofs = Bulletproofs64::setup(&pedersen, DomainSeparator::new("BulletproofsTest", 0), 4)?;
+
+        for _ in 0..ITERATIONS {
+            let values = (0..num_values).map(|_| rng.gen::<u64>() as u128).collect::<Vec<_>>();
+            let randomizers = (0..num_values).map(|_| Scalar::rand(rng)).collect::<Vec<_>>();
+            let commitments = values
+                .iter()
+                .zip_eq(&randomizers)
+                .map(|(value, randomizer)| bulletproofs.commit(*value, randomizer))
+                .collect::<Vec<_>>();
+
+            // Ensure the commitments match the Pedersen commitments.
+            for ((value, randomizer), commitment) in values.iter().zip_eq(&randomizers).zip_eq(&commitments) {
+                let bits = (0..64).map(|i| (value >> i) & 1 == 1).collect::<Vec<_>>();
+                assert_eq!(*commitment, pedersen.commit_uncompressed(&bits, randomizer)?);
+            }
+
+            let proof = bulletproofs.prove(&values, &randomizers, rng)?;
+            assert!(bulletproofs.verify(&commitments, &proof));
+
+            // Ensure the proof does not verify for other commitments.
+            let mut other_commitments = commitments.clone();
+            other_commitments[0] = bulletproofs.commit(values[0] + 1, &randomizers[0]);
+            assert!(!bulletproofs.verify(&other_commitments, &proof));
+            // Ensure the proof does not verify for a subset of the commitments.
+            if num_values > 1 {
+                assert!(!bulletproofs.verify(&commitments[1..], &proof));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_prove_and_verify() -> Result<()> {
+        check_prove_and_verify(1)
+    }
+
+    #[test]
+    fn test_prove_and_verify_aggregated() -> Result<()> {
+        check_prove_and_verify(3)?;
+        check_prove_and_verify(4)
+    }
+
+    #[test]
+    fn test_prove_out_of_range() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let pedersen = Pedersen64::<CurrentEnvironment>::setup(DomainSeparator::new("BulletproofsTest", 0));
+        let bulletproofs = Bulletproofs64::setup(&pedersen, DomainSeparator::new("BulletproofsTest", 0), 2)?;
+        let randomizer = Scalar::rand(rng);
+
+        // Ensure the prover rejects a value that is out of range.
+        assert!(bulletproofs.prove(&[1 << 64], &[randomizer], rng).is_err());
+        // Ensure the prover rejects too many values.
+        assert!(bulletproofs.prove(&[1, 2, 3], &[randomizer; 3], rng).is_err());
+
+        // Ensure a proof for an in-range value does not verify for an out-of-range commitment.
+        let proof = bulletproofs.prove(&[u64::MAX as u128], &[randomizer], rng)?;
+        assert!(bulletproofs.verify(&[bulletproofs.commit(u64::MAX as u128, &randomizer)], &proof));
+        assert!(!bulletproofs.verify(&[bulletproofs.commit(1 << 64, &randomizer)], &proof));
+        Ok(())
+    }
+}
@@ -0,0 +1,340 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_curves::bls12_377::hash_to_curve::expand_message_xmd;
+use snarkvm_fields::{FftParameters, PrimeField};
+use snarkvm_utilities::BigInteger;
+
+use core::any::{Any, TypeId};
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, RwLock},
+};
+
+impl<E: Environment> Elligator2<E> {
+    /// The suite ID of `hash_to_group`, as in section 8.10 of RFC 9380.
+    pub const SUITE_ID: &'static str = "AleoGroup_XMD:SHA-256_ELL2_RO_";
+
+    /// Returns the hash of the given message to a group element, with the given domain separation tag.
+    ///
+    /// This hash follows the construction of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380):
+    /// the message is expanded with `expand_message_xmd` using SHA-256 into two field elements,
+    /// each element is mapped to the curve with Elligator2, and the sum of the points is cofactor cleared.
+    /// Unlike `Blake2Xs::hash_to_curve`, the number of operations does not depend on the message,
+    /// as there is no try-and-increment loop.
+    ///
+    /// The domain separation tag should be of the form `<APPLICATION>-V<XX>-CS<YY>-with-<SUITE_ID>`.
+    pub fn hash_to_group(dst: &[u8], message: &[u8]) -> Result<Group<E>> {
+        ensure!(!dst.is_empty(), "The domain separation tag must be nonempty");
+
+        // Hash the message to two field elements.
+        let num_bytes = (E::Field::size_in_bits() + 128 + 7) / 8;
+        let bytes = expand_message_xmd(dst, message, 2 * num_bytes);
+        let (first, second) = bytes.split_at(num_bytes);
+        let u0 = Field::<E>::new(E::Field::from_bytes_be_mod_order(first));
+        let u1 = Field::<E>::new(E::Field::from_bytes_be_mod_order(second));
+
+        // Map the field elements to the curve, and cofactor clear their sum.
+        let group = (Self::map_to_curve(&u0)? + Self::map_to_curve(&u1)?).mul_by_cofactor();
+        let affine = group.to_affine();
+        ensure!(affine.is_on_curve(), "Elligator2 failed: element is not on curve");
+        ensure!(affine.is_in_correct_subgroup_assuming_on_curve(), "Elligator2 failed: element in incorrect subgroup");
+
+        Ok(group)
+    }
+
+    /// Returns the twisted Edwards element that the given field element maps to, as in section 6.7.1 of RFC 9380.
+    ///
+    /// This follows the straight-line method of appendix F.3 of RFC 9380, so that the sequence of operations
+    /// does not depend on the input: selections use `cmov`, and inverses and square roots are computed with
+    /// `inv0` and `sqrt_ratio`, which only depend on the field.
+    ///
+    /// The output is on the curve, but not necessarily in the prime-order subgroup.
+    fn map_to_curve(u: &Field<E>) -> Result<Group<E>> {
+        let constants = MapConstants::<E>::load()?;
+        let MapConstants { z, c1, c2, .. } = *constants;
+        let (zero, one) = (Field::<E>::zero(), Field::<E>::one());
+
+        // Let tv1 = Z * u^2, or zero in the exceptional case of Z * u^2 == -1.
+        let tv1 = z * u.square();
+        let e1 = tv1 == -one;
+        let tv1 = cmov(&tv1, &zero, e1);
+
+        // Compute the candidates x1 = -(A / B) / (1 + Z * u^2) and x2 = -x1 - (A / B).
+        let x1 = -c1 * constants.inv0(&(tv1 + one));
+        let gx1 = ((x1 + c1) * x1 + c2) * x1;
+        let x2 = -x1 - c1;
+
+        // Compute y1 = sqrt(g(x1)) if g(x1) is a square, and y1 = sqrt(Z * g(x1)) otherwise.
+        let (e2, y1) = constants.sqrt_ratio(&gx1, &one);
+        let e2 = e2 | gx1.is_zero();
+        // As g(x2) = Z * u^2 * g(x1), let y2 = u * y1, or zero in the exceptional case, where g(x2) == 0.
+        let y2 = cmov(&(*u * y1), &zero, e1);
+
+        // Select the candidate x such that g(x) is a square, where exactly one of g(x1) and g(x2) is a square.
+        let x = cmov(&x2, &x1, e2);
+        let y = cmov(&y2, &y1, e2);
+        // Ensure the sign of y is odd if and only if g(x1) is a square.
+        let e3 = y.to_bigint().is_odd();
+        let y = cmov(&y, &-y, e2 ^ e3);
+
+        // Convert the element (x, y) into the Montgomery element (s, t).
+        let b = Group::<E>::MONTGOMERY_B;
+        let (s, t) = (x * b, y * b);
+
+        // Convert the Montgomery element (s, t) into the twisted Edwards element (v, w),
+        // where the exceptional cases of t == 0 or s == -1 map to the identity.
+        let tv1 = s + one;
+        let tv2 = constants.inv0(&(tv1 * t));
+        let v = tv2 * tv1 * s;
+        let w = cmov(&(tv2 * t * (s - one)), &one, tv2.is_zero());
+
+        // Recover the point from the twisted Edwards element (v, w).
+        let point = Group::from_xy_coordinates_unchecked(v, w);
+        // Ensure the recovered point is on the curve.
+        ensure!(point.to_affine().is_on_curve(), "Elligator2 failed: point is not on the curve");
+        Ok(point)
+    }
+}
+
+/// The constants of `map_to_curve`, which only depend on the environment.
+struct MapConstants<E: Environment> {
+    /// The nonsquare `Z`, selected as in appendix H.3 of RFC 9380.
+    z: Field<E>,
+    /// The coefficient `A / B` of the curve equation.
+    c1: Field<E>,
+    /// The coefficient `1 / B^2` of the curve equation.
+    c2: Field<E>,
+    /// The exponent `p - 2`, for `inv0`.
+    p_minus_two: E::BigInteger,
+    /// The two-adicity `s`, where `p - 1 = 2^s * t` for an odd `t`, for `sqrt_ratio`.
+    two_adicity: u32,
+    /// The exponent `(t - 1) / 2`, for `sqrt_ratio`.
+    t_minus_one_div_two: E::BigInteger,
+    /// The element `Z^t`, for `sqrt_ratio`.
+    z_pow_t: Field<E>,
+    /// The element `Z^((t + 1) / 2)`, for `sqrt_ratio`.
+    z_pow_t_plus_one_div_two: Field<E>,
+}
+
+impl<E: Environment> MapConstants<E> {
+    /// Returns the constants for the environment, which are computed on the first call and cached thereafter.
+    fn load() -> Result<&'static Self> {
+        type Cache = RwLock<HashMap<TypeId, &'static (dyn Any + Send + Sync)>>;
+        static CACHE: OnceLock<Cache> = OnceLock::new();
+
+        let cache = CACHE.get_or_init(Default::default);
+        let key = TypeId::of::<E>();
+
+        // Retrieve the cached constants, or compute and cache them for the lifetime of the program.
+        let cached = cache.read().map_err(|_| anyhow!("The Elligator2 constants are poisoned"))?.get(&key).copied();
+        let constants = match cached {
+            Some(constants) => constants,
+            None => {
+                let constants: &'static Self = Box::leak(Box::new(Self::new()?));
+                let mut cache = cache.write().map_err(|_| anyhow!("The Elligator2 constants are poisoned"))?;
+                *cache.entry(key).or_insert(constants)
+            }
+        };
+        constants.downcast_ref::<Self>().ok_or_else(|| anyhow!("The Elligator2 constants have an unexpected type"))
+    }
+
+    /// Computes the constants for the environment.
+    fn new() -> Result<Self> {
+        // Compute the coefficients for the curve: t^2 == s^3 + (A / B) * s^2 + s / B^2.
+        let (a, b) = (Group::<E>::MONTGOMERY_A, Group::<E>::MONTGOMERY_B);
+        let b_inverse =
+            b.inverse().map_err(|_| anyhow!("Montgomery B must be invertible in order to use Elligator2"))?;
+        let (c1, c2) = (a * b_inverse, b_inverse.square());
+
+        // Select the first nonsquare in the order 1, -1, 2, -2, and so on, as in appendix H.3 of RFC 9380.
+        let z = (1u64..)
+            .flat_map(|counter| [Field::<E>::from_u64(counter), -Field::<E>::from_u64(counter)])
+            .find(|z| z.legendre().is_qnr())
+            .ok_or_else(|| anyhow!("Elligator2 failed: no nonsquare was found"))?;
+
+        // Compute the exponent `p - 2`.
+        let mut p_minus_two = E::Field::modulus();
+        p_minus_two.sub_noborrow(&2u64.into());
+
+        // Compute the constants of `sqrt_ratio`, as in appendix F.2.1.1 of RFC 9380.
+        let two_adicity = <<E::Field as PrimeField>::Parameters as FftParameters>::TWO_ADICITY;
+        ensure!(two_adicity < 64, "Elligator2 requires a two-adicity below 64, found {two_adicity}");
+        let t_minus_one_div_two = E::Field::trace_minus_one_div_two();
+        let z_pow_t = pow(&z, E::Field::trace().as_ref());
+        let z_pow_t_plus_one_div_two = pow(&z, t_minus_one_div_two.as_ref()) * z;
+
+        Ok(Self { z, c1, c2, p_minus_two, two_adicity, t_minus_one_div_two, z_pow_t, z_pow_t_plus_one_div_two })
+    }
+
+    /// Returns the inverse of the given element, or zero if the element is zero, as the `inv0` of RFC 9380.
+    ///
+    /// This is computed as `x^(p - 2)`, as the inverse of the field is computed in variable time.
+    fn inv0(&self, x: &Field<E>) -> Field<E> {
+        pow(x, self.p_minus_two.as_ref())
+    }
+
+    /// Returns `(true, sqrt(u / v))` if `u / v` is a square, and `(false, sqrt(Z * u / v))` otherwise,
+    /// as in appendix F.2.1.1 of RFC 9380.
+    ///
+    /// Unlike the square root of the field, the sequence of operations only depends on the field.
+    fn sqrt_ratio(&self, u: &Field<E>, v: &Field<E>) -> (bool, Field<E>) {
+        let one = Field::<E>::one();
+
+        let mut tv1 = self.z_pow_t;
+        let tv2 = pow(v, &[(1u64 << self.two_adicity) - 1]);
+        let tv3 = tv2.square() * v;
+        let tv5 = pow(&(*u * tv3), self.t_minus_one_div_two.as_ref()) * tv2;
+        let tv2 = tv5 * v;
+        let mut tv3 = tv5 * u;
+        let mut tv4 = tv3 * tv2;
+        let is_qr = pow(&tv4, &[1u64 << (self.two_adicity - 1)]) == one;
+        tv3 = cmov(&(tv3 * self.z_pow_t_plus_one_div_two), &tv3, is_qr);
+        tv4 = cmov(&(tv4 * tv1), &tv4, is_qr);
+
+        for i in (2..=self.two_adicity).rev() {
+            let e1 = (2..i).fold(tv4, |tv5, _| tv5.square()) == one;
+            let tv2 = tv3 * tv1;
+            tv1 = tv1.square();
+            let tv5 = tv4 * tv1;
+            tv3 = cmov(&tv2, &tv3, e1);
+            tv4 = cmov(&tv5, &tv4, e1);
+        }
+        (is_qr, tv3)
+    }
+}
+
+/// Returns `b` if the condition is `true`, and `a` otherwise, as the `CMOV` of RFC 9380.
+///
+/// This is computed as `a + condition * (b - a)`, so that it does not branch on the condition.
+fn cmov<E: Environment>(a: &Field<E>, b: &Field<E>, condition: bool) -> Field<E> {
+    *a + Field::from_u64(condition as u64) * (*b - *a)
+}
+
+/// Returns `base^exponent`, where the sequence of operations only depends on the exponent.
+fn pow<E: Environment>(base: &Field<E>, exponent: &[u64]) -> Field<E> {
+    Field::new(E::Field::pow(base, exponent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    /// Returns the twisted Edwards element that the given field element maps to, as in section 6.7.1 of RFC 9380,
+    /// by branching on the input.
+    fn map_to_curve_reference(u: &Field<CurrentEnvironment>) -> Result<Group<CurrentEnvironment>> {
+        let MapConstants { z, c1, c2, .. } = *MapConstants::<CurrentEnvironment>::load()?;
+        let one = Field::one();
+
+        let tv1 = z * u.square();
+        let tv1 = if tv1 == -one { Field::zero() } else { tv1 };
+        let x1 = -c1 * (tv1 + one).inverse()?;
+        let gx1 = ((x1 + c1) * x1 + c2) * x1;
+        let is_square = !gx1.legendre().is_qnr();
+        let (x, gx) = if is_square { (x1, gx1) } else { (-x1 - c1, tv1 * gx1) };
+        let y = gx.square_root()?;
+        let y = if y.to_bigint().is_odd() != is_square { -y } else { y };
+
+        let b = Group::<CurrentEnvironment>::MONTGOMERY_B;
+        let (s, t) = (x * b, y * b);
+        match (s + one) * t == Field::zero() {
+            true => Ok(Group::zero()),
+            false => Ok(Group::from_xy_coordinates_unchecked(s / t, (s - one) / (s + one))),
+        }
+    }
+
+    #[test]
+    fn test_map_constants() -> Result<()> {
+        let constants = MapConstants::<CurrentEnvironment>::load()?;
+
+        // Ensure Z is the first nonsquare in the order 1, -1, 2, -2, and so on.
+        assert!(constants.z.legendre().is_qnr());
+        let index = (1u64..)
+            .position(|counter| Field::from_u64(counter) == constants.z || -Field::from_u64(counter) == constants.z);
+        for counter in 1..=index.unwrap() as u64 {
+            assert!(!Field::<CurrentEnvironment>::from_u64(counter).legendre().is_qnr());
+            assert!(!(-Field::<CurrentEnvironment>::from_u64(counter)).legendre().is_qnr());
+        }
+
+        // Ensure the constants are computed once.
+        assert!(core::ptr::eq(constants, MapConstants::<CurrentEnvironment>::load()?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sqrt_ratio() -> Result<()> {
+        let constants = MapConstants::<CurrentEnvironment>::load()?;
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (u, v): (Field<CurrentEnvironment>, Field<CurrentEnvironment>) =
+                (Uniform::rand(rng), Uniform::rand(rng));
+            let (is_qr, y) = constants.sqrt_ratio(&u, &v);
+
+            // Ensure the output is the square root of u / v if it is a square, and of Z * u / v otherwise.
+            assert_eq!(is_qr, !(u / v).legendre().is_qnr());
+            match is_qr {
+                true => assert_eq!(y.square() * v, u),
+                false => assert_eq!(y.square() * v, constants.z * u),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_to_curve() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let u = Uniform::rand(rng);
+            let point = Elligator2::<CurrentEnvironment>::map_to_curve(&u)?;
+            assert!(point.to_affine().is_on_curve());
+
+            // Ensure the straight-line method matches the method which branches on the input.
+            assert_eq!(point, map_to_curve_reference(&u)?);
+        }
+        // Ensure the exceptional case maps to the curve.
+        let point = Elligator2::<CurrentEnvironment>::map_to_curve(&Field::zero())?;
+        assert!(point.to_affine().is_on_curve());
+        assert_eq!(point, map_to_curve_reference(&Field::zero())?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_group() -> Result<()> {
+        let dst = format!("Aleo-V01-CS01-with-{}", Elligator2::<CurrentEnvironment>::SUITE_ID);
+
+        for i in 0..ITERATIONS {
+            let message = format!("message {i}");
+            let group = Elligator2::<CurrentEnvironment>::hash_to_group(dst.as_bytes(), message.as_bytes())?;
+            assert!(!group.is_zero());
+
+            // Ensure the hash is deterministic.
+            assert_eq!(group, Elligator2::<CurrentEnvironment>::hash_to_group(dst.as_bytes(), message.as_bytes())?);
+            // Ensure the hash depends on the domain separation tag.
+            assert_ne!(group, Elligator2::<CurrentEnvironment>::hash_to_group(b"Aleo-V01-CS02", message.as_bytes())?);
+        }
+
+        // Ensure the domain separation tag is nonempty.
+        assert!(Elligator2::<CurrentEnvironment>::hash_to_group(b"", b"message").is_err());
+        Ok(())
+    }
+}
@@ -14,6 +14,7 @@
 
 mod decode;
 mod encode;
+mod hash_to_group;
 
 use snarkvm_console_types::prelude::*;
 use snarkvm_fields::LegendreSymbol;
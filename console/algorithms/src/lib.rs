@@ -19,12 +19,18 @@
 
 pub use snarkvm_console_types::prelude::*;
 
+mod anemoi;
+pub use anemoi::Anemoi;
+
 pub mod bhp;
-pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
+pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768, BHPDynamic, BHPIncremental};
 
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
+mod bulletproofs;
+pub use bulletproofs::{Bulletproofs, Bulletproofs128, Bulletproofs64, RangeProof};
+
 mod elligator2;
 pub use elligator2::Elligator2;
 
@@ -32,7 +38,25 @@ mod keccak;
 pub use keccak::*;
 
 mod pedersen;
-pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
+pub use pedersen::{Pedersen, Pedersen128, Pedersen64, PedersenIncremental};
 
 mod poseidon;
-pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8, PoseidonDuplex, PoseidonIncremental, PoseidonStream};
+
+mod secp256k1;
+pub use secp256k1::{Secp256k1Point, Secp256k1Signature};
+
+mod sha256;
+pub use sha256::Sha256;
+
+mod sinsemilla;
+pub use sinsemilla::{Sinsemilla, Sinsemilla256, Sinsemilla512};
+
+mod sphincs_plus;
+pub use sphincs_plus::{
+    SphincsPlus,
+    SphincsPlus128W4,
+    SphincsPlusPrivateKey,
+    SphincsPlusPublicKey,
+    SphincsPlusSignature,
+};
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> HashIncremental for Pedersen<E, NUM_BITS> {
+    type Hasher = PedersenIncremental<E, NUM_BITS>;
+    type Output = Field<E>;
+
+    /// Returns a hasher for a stream of at most `NUM_BITS / 8` bytes.
+    /// The hash of the bytes is the Pedersen hash of their bits, in little-endian bit order.
+    ///
+    /// Note: As with `Pedersen::hash`, the length is not part of the preimage, and a zero bit adds nothing
+    /// to the digest, so inputs that differ only in trailing zero bytes (e.g. `[1]` and `[1, 0]`) collide.
+    /// Callers that hash variable-length inputs must encode the length in the input itself.
+    fn hasher(&self) -> Self::Hasher {
+        PedersenIncremental { base_window: self.base_window.clone(), num_absorbed: 0, digest: Group::zero() }
+    }
+}
+
+/// The state of the Pedersen hash of a stream of bytes, which is the sum of the bases of the absorbed bits.
+#[derive(Clone, Debug)]
+pub struct PedersenIncremental<E: Environment, const NUM_BITS: u8> {
+    /// The base window for the Pedersen hash.
+    base_window: Arc<Vec<Group<E>>>,
+    /// The number of bytes absorbed so far.
+    num_absorbed: u64,
+    /// The sum of the bases of the absorbed bits.
+    digest: Group<E>,
+}
+
+impl<E: Environment, const NUM_BITS: u8> Hasher for PedersenIncremental<E, NUM_BITS> {
+    type Output = Field<E>;
+
+    /// Absorbs the given bytes into the hasher.
+    fn update(&mut self, data: &[u8]) -> Result<()> {
+        // Ensure the input size is within the parameter size.
+        let num_absorbed = self.num_absorbed.saturating_add(data.len() as u64);
+        let num_bits = num_absorbed.saturating_mul(8);
+        ensure!(
+            num_bits <= NUM_BITS as u64,
+            "Invalid input size for Pedersen: expected <= {NUM_BITS}, found {num_bits}"
+        );
+
+        // Compute sum of h_i^{m_i} for the absorbed bits.
+        let bases = self.base_window.iter().skip(usize::try_from(self.num_absorbed)? * 8);
+        for (i, base) in bases.take(data.len() * 8).enumerate() {
+            if (data[i / 8] >> (i % 8)) & 1 == 1 {
+                self.digest += base;
+            }
+        }
+        self.num_absorbed = num_absorbed;
+        Ok(())
+    }
+
+    /// Returns the hash of the absorbed bytes.
+    fn finalize(self) -> Result<Self::Output> {
+        Ok(self.digest.to_x_coordinate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+    use snarkvm_utilities::bits_from_bytes_le;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_hasher_matches_hash() -> Result<()> {
+        let pedersen = Pedersen128::<CurrentEnvironment>::setup(DomainSeparator::new("PedersenTest", 0));
+
+        let mut rng = TestRng::default();
+
+        for num_bytes in 0..=16 {
+            let input = (0..num_bytes).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>();
+            let expected = pedersen.hash(&bits_from_bytes_le(&input).collect::<Vec<_>>())?;
+
+            // Ensure the hash is the same for any split of the input.
+            for chunk_size in [1, 3, 16] {
+                let mut hasher = pedersen.hasher();
+                for chunk in input.chunks(chunk_size) {
+                    hasher.update(chunk)?;
+                }
+                assert_eq!(expected, hasher.finalize()?);
+            }
+        }
+
+        // Ensure the hasher rejects an input that exceeds the parameter size.
+        let mut hasher = pedersen.hasher();
+        hasher.update(&[0u8; 16])?;
+        assert!(hasher.update(&[0u8]).is_err());
+        Ok(())
+    }
+}
@@ -15,7 +15,10 @@
 mod commit;
 mod commit_uncompressed;
 mod hash;
+mod hash_incremental;
+pub use hash_incremental::PedersenIncremental;
 mod hash_uncompressed;
+mod rerandomize;
 
 use crate::Blake2Xs;
 use snarkvm_console_types::prelude::*;
@@ -38,10 +41,10 @@ pub struct Pedersen<E: Environment, const NUM_BITS: u8> {
 }
 
 impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
-    /// Initializes a new instance of Pedersen with the given setup message.
-    pub fn setup(message: &str) -> Self {
+    /// Initializes a new instance of Pedersen with the given domain.
+    pub fn setup(domain: DomainSeparator) -> Self {
         // Construct an indexed message to attempt to sample a base.
-        let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.Pedersen.Base.{message}"));
+        let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.Pedersen.Base.{domain}"));
         // Construct the window with the base.
         let mut base_window = vec![Group::<E>::zero(); NUM_BITS as usize];
         {
@@ -54,7 +57,7 @@ impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
         }
 
         // Compute the random base.
-        let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.Pedersen.RandomBase.{message}"));
+        let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.Pedersen.RandomBase.{domain}"));
         // Construct the window with the random base.
         let mut random_base = Vec::with_capacity(Scalar::<E>::size_in_bits());
         {
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the given commitment, rerandomized by `delta_randomness`.
+    /// The result is the commitment to the same input with randomizer `randomizer + delta_randomness`,
+    /// so the commitment is refreshed without revealing or changing the committed input.
+    pub fn rerandomize(&self, commitment: &Group<E>, delta_randomness: &Scalar<E>) -> Group<E> {
+        let mut output = *commitment;
+
+        // Compute h^delta.
+        delta_randomness.to_bits_le().iter().zip_eq(&*self.random_base_window).filter(|(bit, _)| **bit).for_each(
+            |(_, base)| {
+                output += base;
+            },
+        );
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_rerandomize() -> Result<()> {
+        let mut rng = TestRng::default();
+        let pedersen = Pedersen64::<CurrentEnvironment>::setup(DomainSeparator::new("PedersenTest", 0));
+
+        for _ in 0..ITERATIONS {
+            let input = (0..64).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let randomizer = Scalar::rand(&mut rng);
+            let delta_randomness = Scalar::rand(&mut rng);
+
+            let commitment = pedersen.commit_uncompressed(&input, &randomizer)?;
+            let rerandomized = pedersen.rerandomize(&commitment, &delta_randomness);
+            assert_ne!(commitment, rerandomized);
+            // Ensure the rerandomized commitment opens to the same input with the combined randomizer.
+            assert_eq!(rerandomized, pedersen.commit_uncompressed(&input, &(randomizer + delta_randomness))?);
+            // Ensure rerandomizing by zero is the identity.
+            assert_eq!(commitment, pedersen.rerandomize(&commitment, &Scalar::zero()));
+        }
+        Ok(())
+    }
+}
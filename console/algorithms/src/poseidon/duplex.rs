@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns a duplex sponge, which interleaves absorbing and squeezing field elements.
+    ///
+    /// The sponge starts by absorbing the preimage prefix `[ DOMAIN || -1 || [0; RATE-2] ]`. The length slot
+    /// of the prefix is `-1`, which is not the length of any input, so the outputs are separated from those of
+    /// `hash_many` in the same domain.
+    #[inline]
+    pub fn duplex(&self) -> PoseidonDuplex<E, RATE> {
+        // Construct the prefix: [ DOMAIN || -1 || [0; RATE-2] ].
+        let mut prefix = Vec::with_capacity(RATE);
+        prefix.push(self.domain);
+        prefix.push(-Field::<E>::one());
+        prefix.resize(RATE, Field::<E>::zero()); // Pad up to RATE.
+
+        let mut sponge = PoseidonSponge::<E, RATE, CAPACITY>::new(&self.parameters);
+        sponge.absorb(&prefix);
+        PoseidonDuplex { sponge, num_absorbed: 0 }
+    }
+}
+
+/// A Poseidon sponge in duplex mode, for transcripts that interleave absorbing inputs and squeezing outputs.
+///
+/// Each phase of absorbing is terminated by its number of elements, once the next phase of squeezing begins,
+/// so the sequence of phases is absorbed injectively. For instance, absorbing `[a]` and then `[b]` is the same
+/// as absorbing `[a, b]`, while absorbing `[a]`, squeezing, and absorbing `[b]` is not.
+#[derive(Clone, Debug)]
+pub struct PoseidonDuplex<E: Environment, const RATE: usize> {
+    /// The sponge, which has absorbed the transcript so far.
+    sponge: PoseidonSponge<E, RATE, CAPACITY>,
+    /// The number of elements absorbed in the current phase.
+    num_absorbed: u64,
+}
+
+impl<E: Environment, const RATE: usize> PoseidonDuplex<E, RATE> {
+    /// Absorbs the given field elements into the sponge.
+    #[inline]
+    pub fn absorb(&mut self, input: &[Field<E>]) {
+        self.sponge.absorb(input);
+        self.num_absorbed = self.num_absorbed.saturating_add(input.len() as u64);
+    }
+
+    /// Squeezes the given number of field elements from the sponge.
+    #[inline]
+    pub fn squeeze(&mut self, num_outputs: u16) -> Vec<Field<E>> {
+        // Terminate the current phase of absorbing with its number of elements.
+        if self.num_absorbed > 0 {
+            self.sponge.absorb(&[Field::<E>::from_u64(self.num_absorbed)]);
+            self.num_absorbed = 0;
+        }
+        self.sponge.squeeze(num_outputs).to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_duplex() -> Result<()> {
+        let mut rng = TestRng::default();
+        let poseidon = Poseidon::<CurrentEnvironment, 4>::setup(DomainSeparator::new("PoseidonDuplexTest", 0))?;
+
+        let input = (0..10).map(|_| Uniform::rand(&mut rng)).collect::<Vec<Field<_>>>();
+
+        // Ensure absorbing in chunks and squeezing in chunks matches absorbing and squeezing at once.
+        let mut duplex = poseidon.duplex();
+        duplex.absorb(&input);
+        let expected = duplex.squeeze(7);
+
+        let mut duplex = poseidon.duplex();
+        duplex.absorb(&input[..3]);
+        duplex.absorb(&[]);
+        duplex.absorb(&input[3..]);
+        let candidate = [duplex.squeeze(2), duplex.squeeze(0), duplex.squeeze(5)].concat();
+        assert_eq!(expected, candidate);
+
+        // Ensure squeezing between the absorbs changes the outputs.
+        let mut duplex = poseidon.duplex();
+        duplex.absorb(&input[..3]);
+        let first = duplex.squeeze(1);
+        duplex.absorb(&input[3..]);
+        let second = duplex.squeeze(7);
+        assert_ne!(expected, second);
+
+        // Ensure the squeeze of the first phase does not depend on the later phases.
+        let mut duplex = poseidon.duplex();
+        duplex.absorb(&input[..3]);
+        assert_eq!(first, duplex.squeeze(1));
+
+        // Ensure the outputs are separated from those of `hash_many`.
+        assert_ne!(expected, poseidon.hash_many(&input, 7));
+        assert_ne!(poseidon.duplex().squeeze(7), poseidon.hash_many(&[], 7));
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns the cryptographic hash of each of the given inputs.
+    ///
+    /// The hashes are computed in parallel, and are the same as calling `hash` on each input.
+    /// Each permutation still runs on the scalar field arithmetic; the field elements of different
+    /// inputs are not vectorized into SIMD lanes, as the field backends have no SIMD representation.
+    pub fn hash_batch<I: AsRef<[Field<E>]> + Sync>(&self, inputs: &[I]) -> Result<Vec<Field<E>>> {
+        cfg_iter!(inputs).map(|input| self.hash(input.as_ref())).collect()
+    }
+
+    /// Returns the specified number of outputs of the cryptographic hash of each of the given inputs.
+    ///
+    /// The hashes are computed in parallel, and are the same as calling `hash_many` on each input.
+    pub fn hash_many_batch<I: AsRef<[Field<E>]> + Sync>(&self, inputs: &[I], num_outputs: u16) -> Vec<Vec<Field<E>>> {
+        cfg_iter!(inputs).map(|input| self.hash_many(input.as_ref(), num_outputs)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_hash_batch() -> Result<()> {
+        let mut rng = TestRng::default();
+        let poseidon = Poseidon::<CurrentEnvironment, 2>::setup(DomainSeparator::new("PoseidonBatchTest", 0))?;
+
+        let inputs = (0..100)
+            .map(|i| (0..i % 7).map(|_| Uniform::rand(&mut rng)).collect::<Vec<Field<_>>>())
+            .collect::<Vec<_>>();
+
+        // Ensure the batch matches hashing each input.
+        let expected = inputs.iter().map(|input| poseidon.hash(input)).collect::<Result<Vec<_>>>()?;
+        assert_eq!(expected, poseidon.hash_batch(&inputs)?);
+
+        let expected = inputs.iter().map(|input| poseidon.hash_many(input, 3)).collect::<Vec<_>>();
+        assert_eq!(expected, poseidon.hash_many_batch(&inputs, 3));
+
+        // Ensure an empty batch is supported.
+        assert!(poseidon.hash_batch::<Vec<_>>(&[])?.is_empty());
+        Ok(())
+    }
+}
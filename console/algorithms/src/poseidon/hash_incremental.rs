@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The length slot of the prefix of an incremental hash, which no input of `Poseidon::hash` can have.
+const INCREMENTAL_LENGTH_MARKER: u64 = u64::MAX;
+
+impl<E: Environment, const RATE: usize> HashIncremental for Poseidon<E, RATE> {
+    type Hasher = PoseidonIncremental<E, RATE>;
+    type Output = Field<E>;
+
+    /// Returns a hasher for a stream of bytes.
+    ///
+    /// The bytes are packed into field elements, where each field element holds
+    /// `Field::<E>::size_in_data_bits() / 8` bytes in little-endian order. As the length of the stream
+    /// is not known in advance, the length slot of the prefix holds a marker, and the number of bytes is
+    /// absorbed after the input: [ DOMAIN || MARKER || [0; RATE-2] || INPUT || NUM_BYTES(INPUT) ].
+    /// As the last field element may be partial, the suffix holds the number of bytes (rather than the
+    /// number of field elements), so that inputs which differ only in trailing zero bytes do not collide.
+    fn hasher(&self) -> Self::Hasher {
+        let bytes_per_field = Field::<E>::size_in_data_bits() / 8;
+
+        // Absorb the prefix of the preimage: [ DOMAIN || MARKER || [0; RATE-2] ].
+        let mut prefix = Vec::with_capacity(RATE);
+        prefix.push(self.domain);
+        prefix.push(Field::<E>::from_u64(INCREMENTAL_LENGTH_MARKER));
+        prefix.resize(RATE, Field::<E>::zero()); // Pad up to RATE.
+
+        let mut sponge = PoseidonSponge::<E, RATE, CAPACITY>::new(&self.parameters);
+        sponge.absorb(&prefix);
+
+        PoseidonIncremental { sponge, num_absorbed: 0, bytes_per_field, buffer: Vec::with_capacity(bytes_per_field) }
+    }
+}
+
+/// The state of the Poseidon hash of a stream of bytes, which holds at most one field element of the input.
+#[derive(Clone, Debug)]
+pub struct PoseidonIncremental<E: Environment, const RATE: usize> {
+    /// The sponge, which has absorbed the preimage so far.
+    sponge: PoseidonSponge<E, RATE, CAPACITY>,
+    /// The number of bytes absorbed so far.
+    num_absorbed: u64,
+    /// The number of bytes in each field element.
+    bytes_per_field: usize,
+    /// The bytes of the current field element.
+    buffer: Vec<u8>,
+}
+
+impl<E: Environment, const RATE: usize> Hasher for PoseidonIncremental<E, RATE> {
+    type Output = Field<E>;
+
+    /// Absorbs the given bytes into the hasher.
+    fn update(&mut self, data: &[u8]) -> Result<()> {
+        // Ensure the number of bytes fits in the suffix.
+        let Some(num_absorbed) = self.num_absorbed.checked_add(data.len() as u64) else {
+            bail!("The input exceeds the maximum length of the hasher")
+        };
+        self.num_absorbed = num_absorbed;
+
+        let mut data = data;
+        while !data.is_empty() {
+            // Fill the current field element.
+            let num_taken = data.len().min(self.bytes_per_field - self.buffer.len());
+            self.buffer.extend_from_slice(&data[..num_taken]);
+            data = &data[num_taken..];
+            // Absorb the current field element, once it is full.
+            if self.buffer.len() == self.bytes_per_field {
+                self.absorb_buffer()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the hash of the absorbed bytes.
+    fn finalize(mut self) -> Result<Self::Output> {
+        // Absorb the last field element.
+        if !self.buffer.is_empty() {
+            self.absorb_buffer()?;
+        }
+        // Absorb the number of bytes.
+        self.sponge.absorb(&[Field::<E>::from_u64(self.num_absorbed)]);
+        Ok(self.sponge.squeeze(1)[0])
+    }
+}
+
+impl<E: Environment, const RATE: usize> PoseidonIncremental<E, RATE> {
+    /// Absorbs the current field element, and clears it.
+    fn absorb_buffer(&mut self) -> Result<()> {
+        let field = Field::<E>::from_bits_le(&self.buffer.to_bits_le())?;
+        self.sponge.absorb(&[field]);
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    /// Returns the incremental hash of the given input, absorbed in chunks of the given size.
+    fn hash_in_chunks(
+        poseidon: &Poseidon<CurrentEnvironment, 4>,
+        input: &[u8],
+        chunk_size: usize,
+    ) -> Result<Field<CurrentEnvironment>> {
+        let mut hasher = poseidon.hasher();
+        for chunk in input.chunks(chunk_size) {
+            hasher.update(chunk)?;
+        }
+        hasher.finalize()
+    }
+
+    #[test]
+    fn test_hasher_matches_sponge() -> Result<()> {
+        let poseidon = Poseidon::<CurrentEnvironment, 4>::setup(DomainSeparator::new("PoseidonTest", 0))?;
+        let bytes_per_field = Field::<CurrentEnvironment>::size_in_data_bits() / 8;
+
+        let mut rng = TestRng::default();
+
+        for num_bytes in [0, 1, 30, 31, 32, 62, 63, 100, 257] {
+            let input = (0..num_bytes).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>();
+            let fields = input
+                .chunks(bytes_per_field)
+                .map(|bytes| Field::from_bits_le(&bytes.to_bits_le()))
+                .collect::<Result<Vec<_>>>()?;
+
+            // Compute the expected hash of [ DOMAIN || MARKER || [0; RATE-2] || INPUT || NUM_BYTES(INPUT) ].
+            let mut preimage = vec![poseidon.domain, Field::from_u64(INCREMENTAL_LENGTH_MARKER)];
+            preimage.resize(4, Field::zero());
+            preimage.extend(fields);
+            preimage.push(Field::from_u64(num_bytes as u64));
+            let mut sponge = PoseidonSponge::<CurrentEnvironment, 4, CAPACITY>::new(&poseidon.parameters);
+            sponge.absorb(&preimage);
+            let expected = sponge.squeeze(1)[0];
+
+            // Ensure the hash is the same for any split of the input.
+            for chunk_size in [1, 3, 32] {
+                assert_eq!(expected, hash_in_chunks(&poseidon, &input, chunk_size)?);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hasher_trailing_zeros() -> Result<()> {
+        let poseidon = Poseidon::<CurrentEnvironment, 4>::setup(DomainSeparator::new("PoseidonTest", 0))?;
+        let hash = |input: &[u8]| hash_in_chunks(&poseidon, input, 32);
+
+        // Ensure inputs that differ only in trailing zero bytes (within one field element) do not collide.
+        let input = [1u8, 2, 3];
+        assert_ne!(hash(&input)?, hash(&[input.as_slice(), &[0]].concat())?);
+        assert_ne!(hash(&input)?, hash(&[input.as_slice(), &[0; 20]].concat())?);
+        assert_ne!(hash(&[])?, hash(&[0])?);
+        Ok(())
+    }
+}
@@ -22,6 +22,15 @@ impl<E: Environment, const RATE: usize> HashMany for Poseidon<E, RATE> {
     /// and returns the specified number of field elements as output.
     #[inline]
     fn hash_many(&self, input: &[Self::Input], num_outputs: u16) -> Vec<Self::Output> {
+        self.hash_many_stream(input).squeeze(num_outputs)
+    }
+}
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns a stream of the outputs of the cryptographic hash for a list of field elements as input.
+    /// The outputs of the stream are the outputs of `hash_many`, without a bound on their number.
+    #[inline]
+    pub fn hash_many_stream(&self, input: &[Field<E>]) -> PoseidonStream<E, RATE> {
         // Construct the preimage: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] || INPUT ].
         let mut preimage = Vec::with_capacity(RATE + input.len());
         preimage.push(self.domain);
@@ -31,6 +40,21 @@ impl<E: Environment, const RATE: usize> HashMany for Poseidon<E, RATE> {
 
         let mut sponge = PoseidonSponge::<E, RATE, CAPACITY>::new(&self.parameters);
         sponge.absorb(&preimage);
-        sponge.squeeze(num_outputs).to_vec()
+        PoseidonStream { sponge }
+    }
+}
+
+/// A stream of the outputs of the extended Poseidon hash, which squeezes the sponge on demand.
+#[derive(Clone, Debug)]
+pub struct PoseidonStream<E: Environment, const RATE: usize> {
+    /// The sponge, which has absorbed the preimage.
+    sponge: PoseidonSponge<E, RATE, CAPACITY>,
+}
+
+impl<E: Environment, const RATE: usize> PoseidonStream<E, RATE> {
+    /// Returns the next `num_outputs` outputs of the stream.
+    #[inline]
+    pub fn squeeze(&mut self, num_outputs: u16) -> Vec<Field<E>> {
+        self.sponge.squeeze(num_outputs).to_vec()
     }
 }
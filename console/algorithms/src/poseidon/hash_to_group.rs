@@ -43,7 +43,7 @@ mod tests {
     macro_rules! check_hash_to_group {
         ($poseidon:ident) => {{
             // Initialize Poseidon.
-            let poseidon = $poseidon::<CurrentEnvironment>::setup("HashToGroupTest")?;
+            let poseidon = $poseidon::<CurrentEnvironment>::setup(DomainSeparator::new("HashToGroupTest", 0))?;
 
             // Ensure an empty input fails.
             assert!(poseidon.hash_to_group(&[]).is_err());
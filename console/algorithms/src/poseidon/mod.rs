@@ -14,8 +14,14 @@
 
 mod helpers;
 
+mod duplex;
+pub use duplex::PoseidonDuplex;
 mod hash;
+mod hash_batch;
+mod hash_incremental;
+pub use hash_incremental::PoseidonIncremental;
 mod hash_many;
+pub use hash_many::PoseidonStream;
 mod hash_to_group;
 mod hash_to_scalar;
 mod prf;
@@ -45,14 +51,14 @@ pub struct Poseidon<E: Environment, const RATE: usize> {
 
 impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
     /// Initializes a new instance of Poseidon.
-    pub fn setup(domain: &str) -> Result<Self> {
+    pub fn setup(domain: DomainSeparator) -> Result<Self> {
         // Ensure the given domain is within the allowed size in bits.
-        let num_bits = domain.len().saturating_mul(8);
+        let num_bits = domain.to_string().len().saturating_mul(8);
         let max_bits = Field::<E>::size_in_data_bits();
         ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
 
         Ok(Self {
-            domain: Field::<E>::new_domain_separator(domain),
+            domain: Field::<E>::from_domain_separator(domain),
             parameters: Arc::new(E::Field::default_poseidon_parameters::<RATE>()?),
         })
     }
@@ -154,6 +160,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_hash_many_stream() -> Result<()> {
+        let mut rng = TestRng::default();
+        let poseidon = Poseidon::<CurrentEnvironment, 8>::setup(DomainSeparator::new("PoseidonStreamTest", 0))?;
+
+        for num_inputs in 0..4 {
+            let input = (0..num_inputs).map(|_| Uniform::rand(&mut rng)).collect::<Vec<Field<_>>>();
+            let expected = poseidon.hash_many(&input, 25);
+
+            // Check that squeezing the stream in chunks matches squeezing it at once.
+            let mut stream = poseidon.hash_many_stream(&input);
+            let candidate = [0u16, 3, 8, 1, 13].iter().flat_map(|num| stream.squeeze(*num)).collect::<Vec<_>>();
+            assert_eq!(expected, candidate);
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_parameters() {
         fn single_rate_test<const RATE: usize>() {
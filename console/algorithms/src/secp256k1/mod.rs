@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod point;
+pub use point::Secp256k1Point;
+
+mod signature;
+pub use signature::Secp256k1Signature;
+
+use snarkvm_console_types::prelude::*;
+
+use num_bigint::BigUint;
+
+// Note: This module is only intended to verify signatures, which are public. The arithmetic on `BigUint`
+// is variable-time, so it must not be used to handle secret keys or nonces outside of tests.
+
+/// The modulus `p` of the base field of secp256k1, in hexadecimal.
+const BASE_MODULUS: &str = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+/// The order `n` of the prime-order group of secp256k1, in hexadecimal.
+const SCALAR_MODULUS: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+/// The x-coordinate of the generator `G` of secp256k1, in hexadecimal.
+const GENERATOR_X: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+/// The y-coordinate of the generator `G` of secp256k1, in hexadecimal.
+const GENERATOR_Y: &str = "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+/// Returns the given hexadecimal constant as an integer.
+fn from_hex(hex: &str) -> BigUint {
+    BigUint::parse_bytes(hex.as_bytes(), 16).expect("Invalid hexadecimal constant")
+}
+
+/// Returns the modulus `p` of the base field.
+fn base_modulus() -> BigUint {
+    from_hex(BASE_MODULUS)
+}
+
+/// Returns the order `n` of the group.
+fn scalar_modulus() -> BigUint {
+    from_hex(SCALAR_MODULUS)
+}
+
+/// Returns `(a - b) mod m`, for `a, b < m`.
+fn sub_mod(a: &BigUint, b: &BigUint, modulus: &BigUint) -> BigUint {
+    (a + modulus - b) % modulus
+}
+
+/// Returns `a^(-1) mod m` for a prime modulus `m`, or zero if `a` is zero.
+fn inverse_mod(a: &BigUint, modulus: &BigUint) -> BigUint {
+    a.modpow(&(modulus - 2u32), modulus)
+}
+
+/// Returns the given integer as 32 big-endian bytes.
+fn to_bytes_be(value: &BigUint) -> [u8; 32] {
+    let bytes = value.to_bytes_be();
+    let mut output = [0u8; 32];
+    output[32 - bytes.len()..].copy_from_slice(&bytes);
+    output
+}
@@ -0,0 +1,259 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A point on the secp256k1 curve `y^2 = x^3 + 7`, in affine coordinates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1Point {
+    /// The coordinates `(x, y)` of the point, or `None` for the point at infinity.
+    coordinates: Option<(BigUint, BigUint)>,
+}
+
+impl Secp256k1Point {
+    /// Returns the point at infinity.
+    pub fn zero() -> Self {
+        Self { coordinates: None }
+    }
+
+    /// Returns the generator `G` of the group.
+    pub fn generator() -> Self {
+        Self { coordinates: Some((from_hex(GENERATOR_X), from_hex(GENERATOR_Y))) }
+    }
+
+    /// Initializes a point from its big-endian coordinates, ensuring the point is on the curve.
+    pub fn from_coordinates(x: &[u8; 32], y: &[u8; 32]) -> Result<Self> {
+        let (x, y) = (BigUint::from_bytes_be(x), BigUint::from_bytes_be(y));
+        let p = base_modulus();
+        ensure!(x < p && y < p, "The coordinates of a secp256k1 point must be less than the field modulus");
+        ensure!((&y * &y) % &p == Self::curve_equation(&x), "The coordinates are not on the secp256k1 curve");
+        Ok(Self { coordinates: Some((x, y)) })
+    }
+
+    /// Initializes a point from its compressed or uncompressed SEC1 encoding.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self> {
+        match bytes {
+            [0x04, coordinates @ ..] if coordinates.len() == 64 => {
+                let (x, y) = coordinates.split_at(32);
+                Self::from_coordinates(x.try_into()?, y.try_into()?)
+            }
+            [prefix @ (0x02 | 0x03), x @ ..] if x.len() == 32 => {
+                let x = BigUint::from_bytes_be(x);
+                let p = base_modulus();
+                ensure!(x < p, "The x-coordinate of a secp256k1 point must be less than the field modulus");
+                // As `p = 3 mod 4`, the square root of `x^3 + 7` is `(x^3 + 7)^((p + 1) / 4)`, if it exists.
+                let y_squared = Self::curve_equation(&x);
+                let mut y = y_squared.modpow(&((&p + 1u32) >> 2), &p);
+                ensure!((&y * &y) % &p == y_squared, "The x-coordinate is not on the secp256k1 curve");
+                // Select the square root with the parity of the prefix.
+                if y.bit(0) != (*prefix == 0x03) {
+                    y = &p - y;
+                }
+                Ok(Self { coordinates: Some((x, y)) })
+            }
+            _ => bail!("Invalid SEC1 encoding of a secp256k1 point"),
+        }
+    }
+
+    /// Returns the big-endian coordinates of the point, or `None` for the point at infinity.
+    pub fn to_coordinates(&self) -> Option<([u8; 32], [u8; 32])> {
+        self.coordinates.as_ref().map(|(x, y)| (to_bytes_be(x), to_bytes_be(y)))
+    }
+
+    /// Returns the compressed or uncompressed SEC1 encoding of the point.
+    pub fn to_sec1_bytes(&self, compressed: bool) -> Vec<u8> {
+        match (&self.coordinates, compressed) {
+            (None, _) => vec![0x00],
+            (Some((x, y)), true) => [&[0x02 | y.bit(0) as u8][..], &to_bytes_be(x)[..]].concat(),
+            (Some((x, y)), false) => [&[0x04][..], &to_bytes_be(x)[..], &to_bytes_be(y)[..]].concat(),
+        }
+    }
+
+    /// Returns `true` if the point is the point at infinity.
+    pub fn is_zero(&self) -> bool {
+        self.coordinates.is_none()
+    }
+
+    /// Returns the sum of the point with itself.
+    pub fn double(&self) -> Self {
+        let p = base_modulus();
+        match &self.coordinates {
+            // As the group has odd order, only the point at infinity has `y = 0`.
+            Some((x, y)) if y.bits() != 0 => {
+                // λ = 3x^2 / 2y
+                let lambda = (3u32 * x * x * inverse_mod(&((2u32 * y) % &p), &p)) % &p;
+                Self::from_lambda(&lambda, (x, y), x)
+            }
+            _ => Self::zero(),
+        }
+    }
+
+    /// Returns the product of the point with the given big-endian scalar.
+    ///
+    /// This is variable-time, so it must only be used with public scalars, and never with a secret key.
+    pub fn mul(&self, scalar: &[u8; 32]) -> Self {
+        self.mul_biguint(&BigUint::from_bytes_be(scalar))
+    }
+
+    /// Returns the x-coordinate of the point, or `None` for the point at infinity.
+    pub(super) fn x(&self) -> Option<&BigUint> {
+        self.coordinates.as_ref().map(|(x, _)| x)
+    }
+
+    /// Returns the product of the point with the given scalar, using double-and-add.
+    pub(super) fn mul_biguint(&self, scalar: &BigUint) -> Self {
+        let mut output = Self::zero();
+        for i in (0..scalar.bits()).rev() {
+            output = output.double();
+            if scalar.bit(i) {
+                output = &output + self;
+            }
+        }
+        output
+    }
+
+    /// Returns `x^3 + 7 mod p`.
+    fn curve_equation(x: &BigUint) -> BigUint {
+        (x * x * x + 7u32) % base_modulus()
+    }
+
+    /// Returns the third point on the line of slope `λ` through the point `(x_1, y_1)` and a point
+    /// with x-coordinate `x_2`, negated.
+    fn from_lambda(lambda: &BigUint, (x_1, y_1): (&BigUint, &BigUint), x_2: &BigUint) -> Self {
+        let p = base_modulus();
+        // x_3 = λ^2 - x_1 - x_2
+        let x_3 = sub_mod(&sub_mod(&((lambda * lambda) % &p), x_1, &p), x_2, &p);
+        // y_3 = λ(x_1 - x_3) - y_1
+        let y_3 = sub_mod(&((lambda * sub_mod(x_1, &x_3, &p)) % &p), y_1, &p);
+        Self { coordinates: Some((x_3, y_3)) }
+    }
+}
+
+impl Add<&Secp256k1Point> for &Secp256k1Point {
+    type Output = Secp256k1Point;
+
+    /// Returns the sum of the two points.
+    fn add(self, other: &Secp256k1Point) -> Self::Output {
+        let p = base_modulus();
+        match (&self.coordinates, &other.coordinates) {
+            (None, _) => other.clone(),
+            (_, None) => self.clone(),
+            (Some((x_1, y_1)), Some((x_2, y_2))) => match x_1 == x_2 {
+                true if y_1 == y_2 => self.double(),
+                true => Secp256k1Point::zero(),
+                false => {
+                    // λ = (y_2 - y_1) / (x_2 - x_1)
+                    let lambda = (sub_mod(y_2, y_1, &p) * inverse_mod(&sub_mod(x_2, x_1, &p), &p)) % &p;
+                    Secp256k1Point::from_lambda(&lambda, (x_1, y_1), x_2)
+                }
+            },
+        }
+    }
+}
+
+impl Add for Secp256k1Point {
+    type Output = Secp256k1Point;
+
+    /// Returns the sum of the two points.
+    fn add(self, other: Secp256k1Point) -> Self::Output {
+        &self + &other
+    }
+}
+
+impl Neg for Secp256k1Point {
+    type Output = Secp256k1Point;
+
+    /// Returns the negation of the point.
+    fn neg(self) -> Self::Output {
+        let p = base_modulus();
+        Self { coordinates: self.coordinates.map(|(x, y)| (x, sub_mod(&BigUint::default(), &y, &p))) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 10;
+
+    /// Samples a random scalar, and returns it with its product with the generator.
+    fn sample_point(rng: &mut TestRng) -> (BigUint, Secp256k1Point) {
+        let scalar = BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()) % scalar_modulus();
+        let point = Secp256k1Point::generator().mul_biguint(&scalar);
+        (scalar, point)
+    }
+
+    #[test]
+    fn test_generator() {
+        let generator = Secp256k1Point::generator();
+        let (x, y) = generator.to_coordinates().unwrap();
+        assert_eq!(generator, Secp256k1Point::from_coordinates(&x, &y).unwrap());
+
+        // Ensure the generator has order `n`.
+        assert!(generator.mul(&to_bytes_be(&scalar_modulus())).is_zero());
+
+        // Ensure the x-coordinates of `2G` and `3G` match the known values.
+        let expected = [
+            "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+            "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+        ];
+        for (scalar, expected) in (2u32..).zip(expected) {
+            let (x, _) = generator.mul_biguint(&BigUint::from(scalar)).to_coordinates().unwrap();
+            assert_eq!(hex::encode(x), expected);
+        }
+    }
+
+    #[test]
+    fn test_add_and_double() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (a, point_a) = sample_point(rng);
+            let (b, point_b) = sample_point(rng);
+
+            // Ensure (a + b)G = aG + bG.
+            let sum = (&a + &b) % scalar_modulus();
+            assert_eq!(Secp256k1Point::generator().mul_biguint(&sum), &point_a + &point_b);
+            // Ensure 2aG = aG + aG.
+            assert_eq!(point_a.double(), &point_a + &point_a);
+            // Ensure aG - aG = 0, and aG + 0 = aG.
+            assert!((point_a.clone() + -point_a.clone()).is_zero());
+            assert_eq!(point_a.clone() + Secp256k1Point::zero(), point_a);
+        }
+    }
+
+    #[test]
+    fn test_sec1_bytes() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (_, point) = sample_point(rng);
+
+            let compressed = point.to_sec1_bytes(true);
+            assert_eq!(compressed.len(), 33);
+            assert_eq!(point, Secp256k1Point::from_sec1_bytes(&compressed).unwrap());
+
+            let uncompressed = point.to_sec1_bytes(false);
+            assert_eq!(uncompressed.len(), 65);
+            assert_eq!(point, Secp256k1Point::from_sec1_bytes(&uncompressed).unwrap());
+        }
+
+        // Ensure invalid encodings are rejected.
+        let mut bytes = Secp256k1Point::generator().to_sec1_bytes(false);
+        assert!(Secp256k1Point::from_sec1_bytes(&bytes[..64]).is_err());
+        bytes[64] ^= 1;
+        assert!(Secp256k1Point::from_sec1_bytes(&bytes).is_err());
+        assert!(Secp256k1Point::from_sec1_bytes(&[0x00]).is_err());
+    }
+}
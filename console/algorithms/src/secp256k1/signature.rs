@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An ECDSA signature `(r, s)` on secp256k1, as used by Bitcoin and Ethereum.
+///
+/// The message is signed as its 32-byte hash, which is interpreted as a big-endian integer.
+/// Signatures with a high `s` are accepted, so callers that require low-`s` signatures must check it separately.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1Signature {
+    /// The x-coordinate of the nonce commitment, modulo `n`.
+    r: BigUint,
+    /// The response `k^(-1) (z + r d) mod n`.
+    s: BigUint,
+}
+
+impl Secp256k1Signature {
+    /// Initializes a signature from `r || s`, where each is 32 big-endian bytes.
+    pub fn from_bytes_be(bytes: &[u8; 64]) -> Result<Self> {
+        let (r, s) = bytes.split_at(32);
+        let (r, s) = (BigUint::from_bytes_be(r), BigUint::from_bytes_be(s));
+        let n = scalar_modulus();
+        ensure!(r.bits() != 0 && r < n, "The 'r' of a secp256k1 signature must be in [1, n)");
+        ensure!(s.bits() != 0 && s < n, "The 's' of a secp256k1 signature must be in [1, n)");
+        Ok(Self { r, s })
+    }
+
+    /// Returns the signature as `r || s`, where each is 32 big-endian bytes.
+    pub fn to_bytes_be(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&to_bytes_be(&self.r));
+        bytes[32..].copy_from_slice(&to_bytes_be(&self.s));
+        bytes
+    }
+
+    /// Returns a signature for the given message hash, using the given big-endian secret key and a random nonce.
+    ///
+    /// The arithmetic is variable-time, and leaks the secret key and the nonce through timing,
+    /// so signing is only available to tests. Signatures should be produced by a constant-time signer.
+    #[cfg(test)]
+    pub fn sign<R: Rng + CryptoRng>(secret_key: &[u8; 32], message_hash: &[u8; 32], rng: &mut R) -> Result<Self> {
+        let n = scalar_modulus();
+        let d = BigUint::from_bytes_be(secret_key);
+        ensure!(d.bits() != 0 && d < n, "The secp256k1 secret key must be in [1, n)");
+        let z = BigUint::from_bytes_be(message_hash) % &n;
+
+        loop {
+            // Sample a random nonce `k` in [1, n).
+            let k = BigUint::from_bytes_be(&rng.gen::<[u8; 32]>());
+            if k.bits() == 0 || k >= n {
+                continue;
+            }
+            // Compute `r := x(k G) mod n`.
+            let r = match Secp256k1Point::generator().mul_biguint(&k).x() {
+                Some(x) => x % &n,
+                None => continue,
+            };
+            // Compute `s := k^(-1) (z + r d) mod n`.
+            let s = (inverse_mod(&k, &n) * ((&z + &r * &d) % &n)) % &n;
+            // Resample the nonce in the negligible event that `r` or `s` is zero.
+            if r.bits() != 0 && s.bits() != 0 {
+                return Ok(Self { r, s });
+            }
+        }
+    }
+
+    /// Returns `true` if the signature is valid for the given message hash and public key.
+    pub fn verify(&self, public_key: &Secp256k1Point, message_hash: &[u8; 32]) -> bool {
+        let n = scalar_modulus();
+        // Ensure the public key is not the point at infinity.
+        if public_key.is_zero() {
+            return false;
+        }
+        let z = BigUint::from_bytes_be(message_hash) % &n;
+
+        // Compute `u_1 := z s^(-1) mod n` and `u_2 := r s^(-1) mod n`.
+        let s_inverse = inverse_mod(&self.s, &n);
+        let u_1 = (&z * &s_inverse) % &n;
+        let u_2 = (&self.r * &s_inverse) % &n;
+
+        // Compute `R := u_1 G + u_2 Q`, and ensure `x(R) mod n == r`.
+        let candidate = Secp256k1Point::generator().mul_biguint(&u_1) + public_key.mul_biguint(&u_2);
+        match candidate.x() {
+            Some(x) => x % &n == self.r,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 10;
+
+    /// Samples a random secret key, and returns it with its public key.
+    fn sample_key_pair(rng: &mut TestRng) -> ([u8; 32], Secp256k1Point) {
+        let secret_key = to_bytes_be(&(BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()) % scalar_modulus()));
+        (secret_key, Secp256k1Point::generator().mul(&secret_key))
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (secret_key, public_key) = sample_key_pair(rng);
+            let message_hash = rng.gen::<[u8; 32]>();
+
+            let signature = Secp256k1Signature::sign(&secret_key, &message_hash, rng).unwrap();
+            assert!(signature.verify(&public_key, &message_hash));
+            assert_eq!(signature, Secp256k1Signature::from_bytes_be(&signature.to_bytes_be()).unwrap());
+
+            // Ensure the signature is invalid for a different message hash or public key.
+            assert!(!signature.verify(&public_key, &rng.gen::<[u8; 32]>()));
+            assert!(!signature.verify(&sample_key_pair(rng).1, &message_hash));
+            assert!(!signature.verify(&Secp256k1Point::zero(), &message_hash));
+        }
+    }
+
+    #[test]
+    fn test_verify_vector() {
+        // A signature of SHA-256("Aleo") produced by OpenSSL.
+        let public_key = Secp256k1Point::from_sec1_bytes(
+            &hex::decode("02200f3cbe7944b530375e920b7a097edae3c559ed218f8a6ac712ad981508e9b2").unwrap(),
+        )
+        .unwrap();
+        let message_hash: [u8; 32] = hex::decode("b7285d846386ed1a913328b9f6d695dca6a3962dd09e9fa99688762eeb6570e0")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let signature = Secp256k1Signature::from_bytes_be(
+            &hex::decode(
+                "0885a006eb986b04995f8411e8f7004ef51ff9322932f7ec98bcc2f9956a173c\
+                 e96160f3ba8e2145cc8080051b05e0300368aef1037c245813ce14caa9c3214d",
+            )
+            .unwrap()
+            .try_into()
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert!(signature.verify(&public_key, &message_hash));
+        let (_, y) = public_key.to_coordinates().unwrap();
+        assert_eq!(hex::encode(y), "1a1eefb7bb816d9a29574cdce38bc9fc20247adcf9f5493043de8ef3898a7eb2");
+    }
+
+    #[test]
+    fn test_from_bytes_be() {
+        let n = to_bytes_be(&scalar_modulus());
+        let one = to_bytes_be(&BigUint::from(1u32));
+        let zero = [0u8; 32];
+
+        assert!(Secp256k1Signature::from_bytes_be(&[one, one].concat().try_into().unwrap()).is_ok());
+        for (r, s) in [(zero, one), (one, zero), (n, one), (one, n)] {
+            assert!(Secp256k1Signature::from_bytes_be(&[r, s].concat().try_into().unwrap()).is_err());
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_utilities::{bits_from_bytes_le, bytes_from_bits_le};
+
+use sha2::Digest;
+
+impl Hash for Sha256 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the SHA-256 hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(bits_from_bytes_le(&sha256_native(&bytes_from_bits_le(input))).collect())
+    }
+}
+
+/// Computes the SHA-256 hash of the given preimage as bytes.
+fn sha256_native(preimage: &[u8]) -> [u8; 32] {
+    sha2::Sha256::digest(preimage).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_sha256_vectors() {
+        // The test vectors of FIPS 180-4.
+        let vectors = [
+            ("", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+            ("abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+            (
+                "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+            ),
+        ];
+        for (input, expected) in vectors {
+            let input = bits_from_bytes_le(input.as_bytes()).collect::<Vec<_>>();
+            let candidate = Sha256.hash(&input).unwrap();
+            assert_eq!(hex::encode(bytes_from_bits_le(&candidate)), expected);
+        }
+    }
+
+    #[test]
+    fn test_sha256_bits() {
+        let rng = &mut TestRng::default();
+
+        for num_bits in 0..ITERATIONS {
+            // Ensure the input is padded to whole bytes with zeros.
+            let input = (0..num_bits).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+            let mut padded = input.clone();
+            padded.resize((num_bits + 7) / 8 * 8, false);
+
+            let candidate = Sha256.hash(&input).unwrap();
+            assert_eq!(candidate.len(), 256);
+            assert_eq!(candidate, Sha256.hash(&padded).unwrap());
+        }
+    }
+}
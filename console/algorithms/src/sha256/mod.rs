@@ -0,0 +1,28 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+#[cfg(test)]
+use snarkvm_utilities::Uniform;
+
+use crate::Hash;
+use snarkvm_console_types::environment::prelude::*;
+
+/// The SHA-256 hash function of FIPS 180-4.
+///
+/// The input and output are bits, where each byte is in little-endian bit order,
+/// matching the convention of the Keccak hash functions.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Sha256;
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const WINDOW_SIZE: u8, const NUM_WINDOWS: u16> HashUncompressed
+    for Sinsemilla<E, WINDOW_SIZE, NUM_WINDOWS>
+{
+    type Input = bool;
+    type Output = Group<E>;
+
+    /// Returns the Sinsemilla hash of the given input as a group element.
+    ///
+    /// The input size must be a multiple of `WINDOW_SIZE`, as the input is not padded.
+    fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Ensure the input size is within the parameter size.
+        if input.len() > Self::MAX_BITS {
+            bail!("Invalid input size for Sinsemilla: expected <= {}, found {}", Self::MAX_BITS, input.len())
+        }
+        // Ensure the input size is a multiple of the window size, as a partial window
+        // would otherwise hash to the same output as its zero-extension.
+        if input.len() % WINDOW_SIZE as usize != 0 {
+            bail!("Invalid input size for Sinsemilla: expected a multiple of {WINDOW_SIZE}, found {}", input.len())
+        }
+
+        // Compute `acc := (acc + S[m_i]) + acc` for each window `m_i`, starting from `Q`.
+        let mut acc = self.q;
+        for window in input.chunks(WINDOW_SIZE as usize) {
+            // Compute the (little-endian) index of the window.
+            let index = window.iter().rev().fold(0usize, |index, bit| (index << 1) | *bit as usize);
+            let base = self.table[index];
+
+            // Ensure neither addition is an exceptional case of the incomplete addition law,
+            // as the circuit would be unsatisfiable for this input.
+            ensure!(acc != base && acc != -base, "Sinsemilla encountered an exceptional case in the first addition");
+            let sum = acc + base;
+            ensure!(sum != -acc, "Sinsemilla encountered an exceptional case in the second addition");
+
+            acc = sum + acc;
+        }
+        Ok(acc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_hash_uncompressed() -> Result<()> {
+        let sinsemilla = Sinsemilla256::<CurrentEnvironment>::setup("SinsemillaTest")?;
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random input of a random number of windows.
+            let num_windows = rng.gen_range(0..=64);
+            let input = (0..num_windows * 4).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Compute the expected hash, by accumulating the bases directly.
+            let mut expected = sinsemilla.q();
+            for window in input.chunks(4) {
+                let index = window.iter().enumerate().map(|(i, bit)| (*bit as usize) << i).sum::<usize>();
+                expected = expected.double() + sinsemilla.table()[index];
+            }
+            assert_eq!(expected, sinsemilla.hash_uncompressed(&input)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_uncompressed_partial_window() -> Result<()> {
+        let sinsemilla = Sinsemilla256::<CurrentEnvironment>::setup("SinsemillaTest")?;
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample an input which is not a multiple of the window size.
+            let mut input = (0..253).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Ensure the input is rejected, instead of colliding with its zero-extension.
+            assert!(sinsemilla.hash_uncompressed(&input).is_err());
+            input.resize(256, false);
+            assert!(sinsemilla.hash_uncompressed(&input).is_ok());
+        }
+
+        // Ensure inputs with a partial window are rejected.
+        for num_bits in [1, 2, 3, 5, 255] {
+            assert!(sinsemilla.hash_uncompressed(&vec![false; num_bits]).is_err());
+        }
+
+        // Ensure an input which exceeds the parameter size is rejected.
+        assert!(sinsemilla.hash_uncompressed(&[false; 257]).is_err());
+        Ok(())
+    }
+}
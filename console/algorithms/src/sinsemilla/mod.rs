@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+mod hash_uncompressed;
+
+use crate::Blake2Xs;
+use snarkvm_console_types::prelude::*;
+
+use std::sync::Arc;
+
+/// The maximum window size, which bounds the lookup table to 1024 bases.
+const MAX_WINDOW_SIZE: u8 = 10;
+
+/// Sinsemilla256 is a collision-resistant hash function that takes up to a 256-bit input.
+pub type Sinsemilla256<E> = Sinsemilla<E, 4, 64>;
+/// Sinsemilla512 is a collision-resistant hash function that takes up to a 512-bit input.
+pub type Sinsemilla512<E> = Sinsemilla<E, 4, 128>;
+
+/// Sinsemilla is a collision-resistant hash function that takes a variable-length input.
+///
+/// The input is split into `WINDOW_SIZE`-bit windows, and each window selects a base from a
+/// lookup table of `2^WINDOW_SIZE` bases. Starting from the base `Q`, the accumulator is updated
+/// as `acc := (acc + S[m_i]) + acc` for each window `m_i`, which only requires incomplete addition
+/// on the Montgomery form of the curve. In a circuit with lookup arguments, each window costs a
+/// single lookup and two incomplete additions, irrespective of the window size.
+///
+/// Like Pedersen, the Sinsemilla hash function does *not* behave like a random oracle.
+#[derive(Clone)]
+pub struct Sinsemilla<E: Environment, const WINDOW_SIZE: u8, const NUM_WINDOWS: u16> {
+    /// The initial base `Q` of the accumulator.
+    q: Group<E>,
+    /// The lookup table of bases, indexed by the (little-endian) value of each window.
+    table: Arc<Vec<Group<E>>>,
+}
+
+impl<E: Environment, const WINDOW_SIZE: u8, const NUM_WINDOWS: u16> Sinsemilla<E, WINDOW_SIZE, NUM_WINDOWS> {
+    /// The maximum number of input bits.
+    pub const MAX_BITS: usize = WINDOW_SIZE as usize * NUM_WINDOWS as usize;
+
+    /// Initializes a new instance of Sinsemilla with the given domain.
+    pub fn setup(domain: &str) -> Result<Self> {
+        // Ensure the window size is within the lookup table bounds.
+        ensure!(WINDOW_SIZE > 0, "The window size must be greater than 0");
+        ensure!(WINDOW_SIZE <= MAX_WINDOW_SIZE, "The window size must be at most {MAX_WINDOW_SIZE}");
+        // Ensure there is at least one window.
+        ensure!(NUM_WINDOWS > 0, "The number of windows must be greater than 0");
+
+        // Compute the initial base `Q`.
+        let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.Sinsemilla.Q.{domain}"));
+        let q = Group::<E>::new(generator);
+
+        // Compute the lookup table of bases.
+        let table = (0..1usize << WINDOW_SIZE)
+            .map(|index| {
+                let (generator, _, _) =
+                    Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.Sinsemilla.S.{domain}.{index}"));
+                Group::<E>::new(generator)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Self { q, table: Arc::new(table) })
+    }
+
+    /// Returns the initial base `Q`.
+    pub fn q(&self) -> Group<E> {
+        self.q
+    }
+
+    /// Returns the lookup table of bases.
+    pub fn table(&self) -> &Arc<Vec<Group<E>>> {
+        &self.table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_setup() -> Result<()> {
+        let sinsemilla = Sinsemilla256::<CurrentEnvironment>::setup("SinsemillaTest")?;
+        assert_eq!(sinsemilla.table().len(), 16);
+        assert_eq!(Sinsemilla256::<CurrentEnvironment>::MAX_BITS, 256);
+
+        // Ensure the bases are distinct.
+        let mut bases = sinsemilla.table().to_vec();
+        bases.push(sinsemilla.q());
+        assert!(!has_duplicates(bases));
+
+        // Ensure the bases depend on the domain.
+        let other = Sinsemilla256::<CurrentEnvironment>::setup("SinsemillaTest2")?;
+        assert_ne!(sinsemilla.q(), other.q());
+        assert_ne!(sinsemilla.table(), other.table());
+
+        // Ensure invalid parameters are rejected.
+        assert!(Sinsemilla::<CurrentEnvironment, 0, 64>::setup("SinsemillaTest").is_err());
+        assert!(Sinsemilla::<CurrentEnvironment, 11, 64>::setup("SinsemillaTest").is_err());
+        assert!(Sinsemilla::<CurrentEnvironment, 4, 0>::setup("SinsemillaTest").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> FromBytes for SphincsPlusPrivateKey<E> {
+    /// Reads the private key from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let secret_seed = Field::read_le(&mut reader)?;
+        let secret_prf = Field::read_le(&mut reader)?;
+        let public_key = SphincsPlusPublicKey::read_le(&mut reader)?;
+        Ok(Self::new(secret_seed, secret_prf, public_key))
+    }
+}
+
+impl<E: Environment> ToBytes for SphincsPlusPrivateKey<E> {
+    /// Writes the private key to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.secret_seed().write_le(&mut writer)?;
+        self.secret_prf().write_le(&mut writer)?;
+        self.public_key().write_le(&mut writer)
+    }
+}
+
+impl<E: Environment> FromBytes for SphincsPlusPublicKey<E> {
+    /// Reads the public key from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let seed = Field::read_le(&mut reader)?;
+        let root = Field::read_le(&mut reader)?;
+        Ok(Self::new(seed, root))
+    }
+}
+
+impl<E: Environment> ToBytes for SphincsPlusPublicKey<E> {
+    /// Writes the public key to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.seed().write_le(&mut writer)?;
+        self.root().write_le(&mut writer)
+    }
+}
+
+impl<E: Environment> FromBytes for SphincsPlusSignature<E> {
+    /// Reads the signature from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let randomizer = Field::read_le(&mut reader)?;
+        let fors_secrets = read_fields(&mut reader)?;
+        let fors_paths = read_field_vectors(&mut reader)?;
+        let wots_signatures = read_field_vectors(&mut reader)?;
+        let tree_paths = read_field_vectors(&mut reader)?;
+        Ok(Self::new(randomizer, fors_secrets, fors_paths, wots_signatures, tree_paths))
+    }
+}
+
+impl<E: Environment> ToBytes for SphincsPlusSignature<E> {
+    /// Writes the signature to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.randomizer().write_le(&mut writer)?;
+        write_fields(self.fors_secrets(), &mut writer)?;
+        write_field_vectors(self.fors_paths(), &mut writer)?;
+        write_field_vectors(self.wots_signatures(), &mut writer)?;
+        write_field_vectors(self.tree_paths(), &mut writer)
+    }
+}
+
+/// Reads a list of field elements, prefixed by its length, from a buffer.
+fn read_fields<E: Environment, R: Read>(mut reader: R) -> IoResult<Vec<Field<E>>> {
+    let num_fields = u16::read_le(&mut reader)?;
+    (0..num_fields).map(|_| Field::read_le(&mut reader)).collect()
+}
+
+/// Writes a list of field elements, prefixed by its length, to a buffer.
+fn write_fields<E: Environment, W: Write>(fields: &[Field<E>], mut writer: W) -> IoResult<()> {
+    let num_fields = u16::try_from(fields.len()).map_err(|_| error("Too many field elements to serialize"))?;
+    num_fields.write_le(&mut writer)?;
+    fields.iter().try_for_each(|field| field.write_le(&mut writer))
+}
+
+/// Reads a list of lists of field elements, prefixed by its length, from a buffer.
+fn read_field_vectors<E: Environment, R: Read>(mut reader: R) -> IoResult<Vec<Vec<Field<E>>>> {
+    let num_vectors = u16::read_le(&mut reader)?;
+    (0..num_vectors).map(|_| read_fields(&mut reader)).collect()
+}
+
+/// Writes a list of lists of field elements, prefixed by its length, to a buffer.
+fn write_field_vectors<E: Environment, W: Write>(vectors: &[Vec<Field<E>>], mut writer: W) -> IoResult<()> {
+    let num_vectors = u16::try_from(vectors.len()).map_err(|_| error("Too many field vectors to serialize"))?;
+    num_vectors.write_le(&mut writer)?;
+    vectors.iter().try_for_each(|fields| write_fields(fields, &mut writer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+    type CurrentSphincsPlus = SphincsPlus<CurrentEnvironment, 2, 2, 4, 3>;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let sphincs_plus = CurrentSphincsPlus::setup(DomainSeparator::new("SphincsPlusTest", 0))?;
+        let private_key = sphincs_plus.generate_private_key(rng)?;
+        let public_key = private_key.public_key();
+        let message: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
+        let signature = sphincs_plus.sign(&private_key, &message, rng)?;
+
+        // Check the byte representation of the private key.
+        let private_key_bytes = private_key.to_bytes_le()?;
+        assert_eq!(private_key, SphincsPlusPrivateKey::read_le(&private_key_bytes[..])?);
+        assert!(SphincsPlusPrivateKey::<CurrentEnvironment>::read_le(&private_key_bytes[1..]).is_err());
+
+        // Check the byte representation of the public key.
+        let public_key_bytes = public_key.to_bytes_le()?;
+        assert_eq!(public_key, SphincsPlusPublicKey::read_le(&public_key_bytes[..])?);
+        assert!(SphincsPlusPublicKey::<CurrentEnvironment>::read_le(&public_key_bytes[1..]).is_err());
+
+        // Check the byte representation of the signature.
+        let signature_bytes = signature.to_bytes_le()?;
+        let candidate = SphincsPlusSignature::read_le(&signature_bytes[..])?;
+        assert_eq!(signature, candidate);
+        assert!(sphincs_plus.verify(&public_key, &message, &candidate));
+        assert!(SphincsPlusSignature::<CurrentEnvironment>::read_le(&signature_bytes[1..]).is_err());
+        Ok(())
+    }
+}
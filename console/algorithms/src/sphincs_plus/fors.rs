@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<
+    E: Environment,
+    const NUM_LAYERS: usize,
+    const TREE_HEIGHT: usize,
+    const NUM_FORS_TREES: usize,
+    const FORS_HEIGHT: usize,
+> SphincsPlus<E, NUM_LAYERS, TREE_HEIGHT, NUM_FORS_TREES, FORS_HEIGHT>
+{
+    /// Returns the FORS signature of the given indices by the key pair at the given address, as the secret
+    /// leaf and authentication path of each tree, and the FORS public key.
+    pub(super) fn fors_sign(
+        &self,
+        secret_seed: Field<E>,
+        public_seed: Field<E>,
+        address: Address,
+        indices: &[u32],
+    ) -> Result<(Vec<Field<E>>, Vec<Vec<Field<E>>>, Field<E>)> {
+        let mut secrets = Vec::with_capacity(NUM_FORS_TREES);
+        let mut paths = Vec::with_capacity(NUM_FORS_TREES);
+        let mut roots = Vec::with_capacity(NUM_FORS_TREES);
+        for (tree, index) in (0..).zip(indices) {
+            // Derive the secret leaves of the tree.
+            let leaf_secrets = (0..1u32 << FORS_HEIGHT)
+                .map(|leaf| {
+                    let address = address.with_kind(ADDRESS_FORS_PRF).with_words(0, (tree << FORS_HEIGHT) + leaf);
+                    self.tweakable_hash(public_seed, address, &[secret_seed])
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let leaves = (0..)
+                .zip(&leaf_secrets)
+                .map(|(leaf, secret)| self.fors_leaf(public_seed, address, tree, leaf, *secret))
+                .collect::<Result<Vec<_>>>()?;
+
+            let (root, path) = merkle_root_and_path(leaves, *index as usize, |height, index, left, right| {
+                self.fors_node(public_seed, address, tree, height, index, left, right)
+            })?;
+            secrets.push(leaf_secrets[*index as usize]);
+            paths.push(path);
+            roots.push(root);
+        }
+        let public_key = self.tweakable_hash(public_seed, address.with_kind(ADDRESS_FORS_ROOTS), &roots)?;
+        Ok((secrets, paths, public_key))
+    }
+
+    /// Returns the FORS public key of the key pair at the given address, from a signature of the given indices.
+    pub(super) fn fors_public_key_from_signature(
+        &self,
+        public_seed: Field<E>,
+        address: Address,
+        indices: &[u32],
+        secrets: &[Field<E>],
+        paths: &[Vec<Field<E>>],
+    ) -> Result<Field<E>> {
+        ensure!(secrets.len() == NUM_FORS_TREES, "Expected {NUM_FORS_TREES} FORS secrets, found {}", secrets.len());
+        ensure!(paths.len() == NUM_FORS_TREES, "Expected {NUM_FORS_TREES} FORS paths, found {}", paths.len());
+
+        let roots = (0..)
+            .zip(indices)
+            .zip(secrets.iter().zip(paths))
+            .map(|((tree, index), (secret, path))| {
+                ensure!(
+                    path.len() == FORS_HEIGHT,
+                    "Expected a FORS path of length {FORS_HEIGHT}, found {}",
+                    path.len()
+                );
+                let leaf = self.fors_leaf(public_seed, address, tree, *index, *secret)?;
+                merkle_root_from_path(leaf, *index, path, |height, index, left, right| {
+                    self.fors_node(public_seed, address, tree, height, index, left, right)
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.tweakable_hash(public_seed, address.with_kind(ADDRESS_FORS_ROOTS), &roots)
+    }
+
+    /// Returns the given leaf of the given FORS tree, from its secret.
+    fn fors_leaf(
+        &self,
+        public_seed: Field<E>,
+        address: Address,
+        tree: u32,
+        leaf: u32,
+        secret: Field<E>,
+    ) -> Result<Field<E>> {
+        self.tweakable_hash(public_seed, address.with_words(0, (tree << FORS_HEIGHT) + leaf), &[secret])
+    }
+
+    /// Returns the node of the given FORS tree at the given height and index, from its children.
+    fn fors_node(
+        &self,
+        public_seed: Field<E>,
+        address: Address,
+        tree: u32,
+        height: u32,
+        index: u32,
+        left: Field<E>,
+        right: Field<E>,
+    ) -> Result<Field<E>> {
+        let index = (tree << (FORS_HEIGHT as u32 - height)) + index;
+        self.tweakable_hash(public_seed, address.with_words(height, index), &[left, right])
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<
+    E: Environment,
+    const NUM_LAYERS: usize,
+    const TREE_HEIGHT: usize,
+    const NUM_FORS_TREES: usize,
+    const FORS_HEIGHT: usize,
+> SphincsPlus<E, NUM_LAYERS, TREE_HEIGHT, NUM_FORS_TREES, FORS_HEIGHT>
+{
+    /// Returns the root of the given tree of the given layer of the hypertree,
+    /// and the authentication path of the given leaf.
+    pub(super) fn tree_root_and_path(
+        &self,
+        secret_seed: Field<E>,
+        public_seed: Field<E>,
+        layer: u8,
+        tree: u64,
+        leaf: u32,
+    ) -> Result<(Field<E>, Vec<Field<E>>)> {
+        let leaves = (0..1u32 << TREE_HEIGHT)
+            .map(|keypair| {
+                self.wots_public_key(secret_seed, public_seed, Address::new(layer, tree, ADDRESS_WOTS_HASH, keypair))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let address = Address::new(layer, tree, ADDRESS_TREE, 0);
+        merkle_root_and_path(leaves, leaf as usize, |height, index, left, right| {
+            self.tweakable_hash(public_seed, address.with_words(height, index), &[left, right])
+        })
+    }
+
+    /// Returns the root of the given tree of the given layer of the hypertree,
+    /// from the given leaf and its authentication path.
+    pub(super) fn tree_root_from_path(
+        &self,
+        public_seed: Field<E>,
+        layer: u8,
+        tree: u64,
+        leaf: u32,
+        node: Field<E>,
+        path: &[Field<E>],
+    ) -> Result<Field<E>> {
+        ensure!(path.len() == TREE_HEIGHT, "Expected a tree path of length {TREE_HEIGHT}, found {}", path.len());
+        let address = Address::new(layer, tree, ADDRESS_TREE, 0);
+        merkle_root_from_path(node, leaf, path, |height, index, left, right| {
+            self.tweakable_hash(public_seed, address.with_words(height, index), &[left, right])
+        })
+    }
+}
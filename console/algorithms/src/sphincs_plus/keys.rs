@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The private key of SPHINCS+.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SphincsPlusPrivateKey<E: Environment> {
+    /// The seed of the secrets of the hypertree.
+    secret_seed: Field<E>,
+    /// The seed of the message randomizers.
+    secret_prf: Field<E>,
+    /// The public key.
+    public_key: SphincsPlusPublicKey<E>,
+}
+
+impl<E: Environment> SphincsPlusPrivateKey<E> {
+    /// Returns the private key with the given seeds and public key.
+    pub(super) const fn new(secret_seed: Field<E>, secret_prf: Field<E>, public_key: SphincsPlusPublicKey<E>) -> Self {
+        Self { secret_seed, secret_prf, public_key }
+    }
+
+    /// Returns the seed of the secrets of the hypertree.
+    pub const fn secret_seed(&self) -> Field<E> {
+        self.secret_seed
+    }
+
+    /// Returns the seed of the message randomizers.
+    pub const fn secret_prf(&self) -> Field<E> {
+        self.secret_prf
+    }
+
+    /// Returns the public key.
+    pub const fn public_key(&self) -> SphincsPlusPublicKey<E> {
+        self.public_key
+    }
+}
+
+/// The public key of SPHINCS+.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SphincsPlusPublicKey<E: Environment> {
+    /// The public seed of the tweakable hashes.
+    seed: Field<E>,
+    /// The root of the top layer of the hypertree.
+    root: Field<E>,
+}
+
+impl<E: Environment> SphincsPlusPublicKey<E> {
+    /// Returns the public key with the given seed and root.
+    pub const fn new(seed: Field<E>, root: Field<E>) -> Self {
+        Self { seed, root }
+    }
+
+    /// Returns the public seed of the tweakable hashes.
+    pub const fn seed(&self) -> Field<E> {
+        self.seed
+    }
+
+    /// Returns the root of the top layer of the hypertree.
+    pub const fn root(&self) -> Field<E> {
+        self.root
+    }
+}
+
+impl<
+    E: Environment,
+    const NUM_LAYERS: usize,
+    const TREE_HEIGHT: usize,
+    const NUM_FORS_TREES: usize,
+    const FORS_HEIGHT: usize,
+> SphincsPlus<E, NUM_LAYERS, TREE_HEIGHT, NUM_FORS_TREES, FORS_HEIGHT>
+{
+    /// Samples a new private key.
+    pub fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<SphincsPlusPrivateKey<E>> {
+        self.private_key_from_seeds(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng))
+    }
+
+    /// Returns the private key with the given seeds, which computes the root of the top layer of the hypertree.
+    pub fn private_key_from_seeds(
+        &self,
+        secret_seed: Field<E>,
+        secret_prf: Field<E>,
+        public_seed: Field<E>,
+    ) -> Result<SphincsPlusPrivateKey<E>> {
+        let (root, _) = self.tree_root_and_path(secret_seed, public_seed, NUM_LAYERS as u8 - 1, 0, 0)?;
+        Ok(SphincsPlusPrivateKey::new(secret_seed, secret_prf, SphincsPlusPublicKey::new(public_seed, root)))
+    }
+}
@@ -0,0 +1,272 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod fors;
+mod hypertree;
+mod keys;
+pub use keys::{SphincsPlusPrivateKey, SphincsPlusPublicKey};
+mod sign;
+mod signature;
+pub use signature::SphincsPlusSignature;
+mod verify;
+mod wots;
+
+use crate::Poseidon2;
+use snarkvm_console_types::prelude::*;
+
+/// The base-2 logarithm of the Winternitz parameter.
+const WOTS_LOG_W: usize = 2;
+/// The Winternitz parameter, which is the number of values of a digit, and the length of each hash chain.
+const WOTS_W: usize = 1 << WOTS_LOG_W;
+/// The number of bits of the checksum of a WOTS+ message.
+const WOTS_CHECKSUM_BITS: usize = 10;
+/// The number of bits taken from each output of the message digest.
+const DIGEST_BITS_PER_OUTPUT: usize = 128;
+
+/// The address type of a hash in a WOTS+ chain.
+const ADDRESS_WOTS_HASH: u8 = 0;
+/// The address type of the compression of a WOTS+ public key.
+const ADDRESS_WOTS_PK: u8 = 1;
+/// The address type of a node of a hypertree layer.
+const ADDRESS_TREE: u8 = 2;
+/// The address type of a node of a FORS tree.
+const ADDRESS_FORS_TREE: u8 = 3;
+/// The address type of the compression of the FORS roots.
+const ADDRESS_FORS_ROOTS: u8 = 4;
+/// The address type of the derivation of a WOTS+ secret.
+const ADDRESS_WOTS_PRF: u8 = 5;
+/// The address type of the derivation of a FORS secret.
+const ADDRESS_FORS_PRF: u8 = 6;
+
+/// SPHINCS+ with the hypertree and FORS trees of SPHINCS+-128f, namely 22 layers of height 3, and 33 FORS trees
+/// of height 6. Note that this is not the SPHINCS+-128f parameter set, as WOTS+ uses a Winternitz parameter of 4.
+pub type SphincsPlus128W4<E> = SphincsPlus<E, 22, 3, 33, 6>;
+
+/// SPHINCS+ is a stateless hash-based signature scheme, which remains secure against quantum adversaries.
+///
+/// This instantiation is over the base field, so that its signatures are efficient to verify in a circuit:
+///   - Each hash is a tweakable Poseidon hash `H(public_seed, address, input)`, where the address packs the
+///     position of the hash in the hypertree into a single field element.
+///   - The message digest selects a FORS key pair at the bottom of the hypertree, whose few-time signature
+///     signs the digest. Each layer of the hypertree then signs the root of the layer below with WOTS+.
+///   - WOTS+ uses a Winternitz parameter of 4, so that a chain is cheap to evaluate from a secret digit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SphincsPlus<
+    E: Environment,
+    const NUM_LAYERS: usize,
+    const TREE_HEIGHT: usize,
+    const NUM_FORS_TREES: usize,
+    const FORS_HEIGHT: usize,
+> {
+    /// The tweakable hash function.
+    poseidon: Poseidon2<E>,
+    /// The domain separator of the message randomizer.
+    randomizer_domain: Field<E>,
+    /// The domain separator of the message digest.
+    digest_domain: Field<E>,
+}
+
+impl<
+    E: Environment,
+    const NUM_LAYERS: usize,
+    const TREE_HEIGHT: usize,
+    const NUM_FORS_TREES: usize,
+    const FORS_HEIGHT: usize,
+> SphincsPlus<E, NUM_LAYERS, TREE_HEIGHT, NUM_FORS_TREES, FORS_HEIGHT>
+{
+    /// The total height of the hypertree.
+    pub const HEIGHT: usize = NUM_LAYERS * TREE_HEIGHT;
+
+    /// Initializes a new instance of SPHINCS+ with the given domain.
+    pub fn setup(domain: DomainSeparator) -> Result<Self> {
+        ensure!(
+            NUM_LAYERS > 0 && TREE_HEIGHT > 0 && NUM_FORS_TREES > 0 && FORS_HEIGHT > 0,
+            "The parameters of SPHINCS+ must be nonzero"
+        );
+        ensure!(NUM_LAYERS <= u8::MAX as usize, "SPHINCS+ cannot exceed {} layers", u8::MAX);
+        ensure!(Self::HEIGHT - TREE_HEIGHT <= 64, "The tree index of SPHINCS+ cannot exceed 64 bits");
+        ensure!(TREE_HEIGHT < 32, "The height of a SPHINCS+ layer must be less than 32");
+        ensure!(
+            FORS_HEIGHT < 32 && (NUM_FORS_TREES << FORS_HEIGHT) <= u32::MAX as usize,
+            "The FORS leaf index of SPHINCS+ cannot exceed 32 bits"
+        );
+
+        Ok(Self {
+            poseidon: Poseidon2::setup(domain)?,
+            randomizer_domain: Field::from_domain_separator(DomainSeparator::new("SphincsPlusRandomizer", 0)),
+            digest_domain: Field::from_domain_separator(DomainSeparator::new("SphincsPlusDigest", 0)),
+        })
+    }
+
+    /// Returns the tweakable hash function.
+    pub const fn poseidon(&self) -> &Poseidon2<E> {
+        &self.poseidon
+    }
+
+    /// Returns the domain separator of the message randomizer.
+    pub const fn randomizer_domain(&self) -> Field<E> {
+        self.randomizer_domain
+    }
+
+    /// Returns the domain separator of the message digest.
+    pub const fn digest_domain(&self) -> Field<E> {
+        self.digest_domain
+    }
+
+    /// Returns the number of outputs of the message digest.
+    pub const fn num_digest_outputs() -> usize {
+        (NUM_FORS_TREES * FORS_HEIGHT + Self::HEIGHT + DIGEST_BITS_PER_OUTPUT - 1) / DIGEST_BITS_PER_OUTPUT
+    }
+
+    /// Returns the number of digits of a WOTS+ message, without the checksum.
+    pub fn wots_message_length() -> usize {
+        (Field::<E>::size_in_bits() + WOTS_LOG_W - 1) / WOTS_LOG_W
+    }
+
+    /// Returns the number of hash chains of a WOTS+ key pair.
+    pub fn wots_length() -> usize {
+        Self::wots_message_length() + WOTS_CHECKSUM_BITS / WOTS_LOG_W
+    }
+
+    /// Returns the tweakable hash `H(public_seed, address, input)`.
+    fn tweakable_hash(&self, public_seed: Field<E>, address: Address, input: &[Field<E>]) -> Result<Field<E>> {
+        let mut preimage = Vec::with_capacity(2 + input.len());
+        preimage.push(public_seed);
+        preimage.push(address.to_field());
+        preimage.extend_from_slice(input);
+        self.poseidon.hash(&preimage)
+    }
+
+    /// Returns the FORS indices, the tree index, and the leaf index selected by the message digest, where:
+    ///     digest := HashMany(randomizer, public_seed, root, message)
+    fn digest(
+        &self,
+        randomizer: Field<E>,
+        public_key: &SphincsPlusPublicKey<E>,
+        message: &[Field<E>],
+    ) -> Result<(Vec<u32>, u64, u32)> {
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([self.digest_domain, randomizer, public_key.seed(), public_key.root()]);
+        preimage.extend_from_slice(message);
+
+        let num_outputs = u16::try_from(Self::num_digest_outputs())?;
+        let bits = self
+            .poseidon
+            .hash_many(&preimage, num_outputs)
+            .iter()
+            .flat_map(|output| output.to_bits_le().into_iter().take(DIGEST_BITS_PER_OUTPUT))
+            .collect::<Vec<_>>();
+        let (fors_bits, index_bits) = bits.split_at(NUM_FORS_TREES * FORS_HEIGHT);
+
+        let fors_indices = fors_bits.chunks(FORS_HEIGHT).map(|bits| from_bits_le(bits) as u32).collect();
+        let leaf_index = from_bits_le(&index_bits[..TREE_HEIGHT]) as u32;
+        let tree_index = from_bits_le(&index_bits[TREE_HEIGHT..Self::HEIGHT]);
+        Ok((fors_indices, tree_index, leaf_index))
+    }
+}
+
+/// The address of a hash in the hypertree, which tweaks the hash to its position.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+struct Address {
+    /// The layer of the hypertree.
+    layer: u8,
+    /// The index of the tree in the layer.
+    tree: u64,
+    /// The type of the hash.
+    kind: u8,
+    /// The index of the key pair in the tree.
+    keypair: u32,
+    /// The index of the chain, or the height of the node.
+    word_1: u32,
+    /// The index of the hash in the chain, or the index of the node.
+    word_2: u32,
+}
+
+impl Address {
+    /// Returns the address of a key pair of the given type.
+    const fn new(layer: u8, tree: u64, kind: u8, keypair: u32) -> Self {
+        Self { layer, tree, kind, keypair, word_1: 0, word_2: 0 }
+    }
+
+    /// Returns the address with the given words.
+    const fn with_words(self, word_1: u32, word_2: u32) -> Self {
+        Self { word_1, word_2, ..self }
+    }
+
+    /// Returns the address with the given type.
+    const fn with_kind(self, kind: u8) -> Self {
+        Self { kind, ..self }
+    }
+
+    /// Returns the address as a field element, where:
+    ///     address := tree + 2^64 * layer + 2^72 * kind + 2^80 * keypair + 2^112 * word_1 + 2^144 * word_2
+    fn to_field<E: Environment>(self) -> Field<E> {
+        let high = self.layer as u128
+            | (self.kind as u128) << 8
+            | (self.keypair as u128) << 16
+            | (self.word_1 as u128) << 48
+            | (self.word_2 as u128) << 80;
+        Field::from_u64(self.tree) + Field::from_u128(high) * Field::from_u128(1 << 64)
+    }
+}
+
+/// Returns the integer of the given little-endian bits, which must be at most 64 bits.
+fn from_bits_le(bits: &[bool]) -> u64 {
+    bits.iter().rev().fold(0, |value, bit| (value << 1) | *bit as u64)
+}
+
+/// Returns the root of the Merkle tree with the given leaves, and the authentication path of the given leaf,
+/// where each parent is `hash_node(height, index, left, right)`.
+fn merkle_root_and_path<E: Environment>(
+    leaves: Vec<Field<E>>,
+    leaf_index: usize,
+    hash_node: impl Fn(u32, u32, Field<E>, Field<E>) -> Result<Field<E>>,
+) -> Result<(Field<E>, Vec<Field<E>>)> {
+    let mut nodes = leaves;
+    let mut index = leaf_index;
+    let mut path = Vec::new();
+    let mut height = 0;
+    while nodes.len() > 1 {
+        path.push(nodes[index ^ 1]);
+        height += 1;
+        nodes = nodes
+            .chunks(2)
+            .enumerate()
+            .map(|(i, pair)| hash_node(height, i as u32, pair[0], pair[1]))
+            .collect::<Result<Vec<_>>>()?;
+        index >>= 1;
+    }
+    Ok((nodes[0], path))
+}
+
+/// Returns the root of a Merkle tree from the given leaf and its authentication path,
+/// where each parent is `hash_node(height, index, left, right)`.
+fn merkle_root_from_path<E: Environment>(
+    leaf: Field<E>,
+    leaf_index: u32,
+    path: &[Field<E>],
+    hash_node: impl Fn(u32, u32, Field<E>, Field<E>) -> Result<Field<E>>,
+) -> Result<Field<E>> {
+    let mut node = leaf;
+    let mut index = leaf_index;
+    for (height, sibling) in (1..).zip(path) {
+        node = match index & 1 == 0 {
+            true => hash_node(height, index >> 1, node, *sibling)?,
+            false => hash_node(height, index >> 1, *sibling, node)?,
+        };
+        index >>= 1;
+    }
+    Ok(node)
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<
+    E: Environment,
+    const NUM_LAYERS: usize,
+    const TREE_HEIGHT: usize,
+    const NUM_FORS_TREES: usize,
+    const FORS_HEIGHT: usize,
+> SphincsPlus<E, NUM_LAYERS, TREE_HEIGHT, NUM_FORS_TREES, FORS_HEIGHT>
+{
+    /// Returns a signature of the given message, where:
+    ///     randomizer := Hash(secret_prf, nonce, message)
+    ///     (fors_indices, tree, leaf) := HashMany(randomizer, public_seed, root, message)
+    ///
+    /// The FORS key pair at the selected leaf signs the FORS indices, and each layer of the hypertree signs the root
+    /// of the layer below, up to the root of the top layer. Signing is stateless, as the leaf is selected by the digest.
+    pub fn sign<R: Rng + CryptoRng>(
+        &self,
+        private_key: &SphincsPlusPrivateKey<E>,
+        message: &[Field<E>],
+        rng: &mut R,
+    ) -> Result<SphincsPlusSignature<E>> {
+        let secret_seed = private_key.secret_seed();
+        let public_key = private_key.public_key();
+        let public_seed = public_key.seed();
+
+        // Compute the randomizer of the message digest.
+        let mut preimage = Vec::with_capacity(3 + message.len());
+        preimage.extend([self.randomizer_domain, private_key.secret_prf(), Uniform::rand(rng)]);
+        preimage.extend_from_slice(message);
+        let randomizer = self.poseidon.hash(&preimage)?;
+
+        // Compute the message digest, which selects the FORS indices and the key pair at the bottom of the hypertree.
+        let (fors_indices, mut tree, mut leaf) = self.digest(randomizer, &public_key, message)?;
+
+        // Sign the FORS indices.
+        let address = Address::new(0, tree, ADDRESS_FORS_TREE, leaf);
+        let (fors_secrets, fors_paths, mut root) = self.fors_sign(secret_seed, public_seed, address, &fors_indices)?;
+
+        // Sign the root of each layer with the layer above.
+        let mut wots_signatures = Vec::with_capacity(NUM_LAYERS);
+        let mut tree_paths = Vec::with_capacity(NUM_LAYERS);
+        for layer in 0..NUM_LAYERS as u8 {
+            let address = Address::new(layer, tree, ADDRESS_WOTS_HASH, leaf);
+            wots_signatures.push(self.wots_sign(secret_seed, public_seed, address, root)?);
+
+            let (tree_root, tree_path) = self.tree_root_and_path(secret_seed, public_seed, layer, tree, leaf)?;
+            tree_paths.push(tree_path);
+            root = tree_root;
+
+            // Select the key pair of the layer above.
+            leaf = (tree & ((1 << TREE_HEIGHT) - 1)) as u32;
+            tree >>= TREE_HEIGHT;
+        }
+        ensure!(root == public_key.root(), "The private key does not match its public key");
+
+        Ok(SphincsPlusSignature::new(randomizer, fors_secrets, fors_paths, wots_signatures, tree_paths))
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A signature of SPHINCS+.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SphincsPlusSignature<E: Environment> {
+    /// The randomizer of the message digest.
+    randomizer: Field<E>,
+    /// The secret leaf of each FORS tree.
+    fors_secrets: Vec<Field<E>>,
+    /// The authentication path of the secret leaf of each FORS tree.
+    fors_paths: Vec<Vec<Field<E>>>,
+    /// The WOTS+ signature of each layer of the hypertree.
+    wots_signatures: Vec<Vec<Field<E>>>,
+    /// The authentication path of the WOTS+ key pair of each layer of the hypertree.
+    tree_paths: Vec<Vec<Field<E>>>,
+}
+
+impl<E: Environment> SphincsPlusSignature<E> {
+    /// Returns the signature with the given components.
+    pub const fn new(
+        randomizer: Field<E>,
+        fors_secrets: Vec<Field<E>>,
+        fors_paths: Vec<Vec<Field<E>>>,
+        wots_signatures: Vec<Vec<Field<E>>>,
+        tree_paths: Vec<Vec<Field<E>>>,
+    ) -> Self {
+        Self { randomizer, fors_secrets, fors_paths, wots_signatures, tree_paths }
+    }
+
+    /// Returns the randomizer of the message digest.
+    pub const fn randomizer(&self) -> Field<E> {
+        self.randomizer
+    }
+
+    /// Returns the secret leaf of each FORS tree.
+    pub fn fors_secrets(&self) -> &[Field<E>] {
+        &self.fors_secrets
+    }
+
+    /// Returns the authentication path of the secret leaf of each FORS tree.
+    pub fn fors_paths(&self) -> &[Vec<Field<E>>] {
+        &self.fors_paths
+    }
+
+    /// Returns the WOTS+ signature of each layer of the hypertree.
+    pub fn wots_signatures(&self) -> &[Vec<Field<E>>] {
+        &self.wots_signatures
+    }
+
+    /// Returns the authentication path of the WOTS+ key pair of each layer of the hypertree.
+    pub fn tree_paths(&self) -> &[Vec<Field<E>>] {
+        &self.tree_paths
+    }
+}
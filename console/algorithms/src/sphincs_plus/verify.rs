@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<
+    E: Environment,
+    const NUM_LAYERS: usize,
+    const TREE_HEIGHT: usize,
+    const NUM_FORS_TREES: usize,
+    const FORS_HEIGHT: usize,
+> SphincsPlus<E, NUM_LAYERS, TREE_HEIGHT, NUM_FORS_TREES, FORS_HEIGHT>
+{
+    /// Returns `true` if the signature is valid for the given public key and message.
+    pub fn verify(
+        &self,
+        public_key: &SphincsPlusPublicKey<E>,
+        message: &[Field<E>],
+        signature: &SphincsPlusSignature<E>,
+    ) -> bool {
+        match self.root_from_signature(public_key, message, signature) {
+            Ok(root) => root == public_key.root(),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the root of the top layer of the hypertree, from the given signature of the given message.
+    fn root_from_signature(
+        &self,
+        public_key: &SphincsPlusPublicKey<E>,
+        message: &[Field<E>],
+        signature: &SphincsPlusSignature<E>,
+    ) -> Result<Field<E>> {
+        let public_seed = public_key.seed();
+        ensure!(
+            signature.wots_signatures().len() == NUM_LAYERS && signature.tree_paths().len() == NUM_LAYERS,
+            "Expected a signature of {NUM_LAYERS} layers"
+        );
+
+        // Compute the message digest, which selects the FORS indices and the key pair at the bottom of the hypertree.
+        let (fors_indices, mut tree, mut leaf) = self.digest(signature.randomizer(), public_key, message)?;
+
+        // Compute the FORS public key.
+        let address = Address::new(0, tree, ADDRESS_FORS_TREE, leaf);
+        let mut root = self.fors_public_key_from_signature(
+            public_seed,
+            address,
+            &fors_indices,
+            signature.fors_secrets(),
+            signature.fors_paths(),
+        )?;
+
+        // Compute the root of each layer from the root of the layer below.
+        for ((layer, wots_signature), tree_path) in
+            (0..NUM_LAYERS as u8).zip(signature.wots_signatures()).zip(signature.tree_paths())
+        {
+            let address = Address::new(layer, tree, ADDRESS_WOTS_HASH, leaf);
+            let node = self.wots_public_key_from_signature(public_seed, address, root, wots_signature)?;
+            root = self.tree_root_from_path(public_seed, layer, tree, leaf, node, tree_path)?;
+
+            // Select the key pair of the layer above.
+            leaf = (tree & ((1 << TREE_HEIGHT) - 1)) as u32;
+            tree >>= TREE_HEIGHT;
+        }
+        Ok(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+    /// A small instantiation of SPHINCS+, so that the tests are fast.
+    type CurrentSphincsPlus = SphincsPlus<CurrentEnvironment, 2, 2, 4, 3>;
+
+    const ITERATIONS: u64 = 5;
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let sphincs_plus = CurrentSphincsPlus::setup(DomainSeparator::new("SphincsPlusTest", 0))?;
+        let private_key = sphincs_plus.generate_private_key(rng)?;
+        let public_key = private_key.public_key();
+
+        for i in 0..ITERATIONS {
+            // Check that the signature is valid for the message.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = sphincs_plus.sign(&private_key, &message, rng)?;
+            assert!(sphincs_plus.verify(&public_key, &message, &signature));
+
+            // Check that the signature is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..i + 1).map(|_| Uniform::rand(rng)).collect();
+            assert!(!sphincs_plus.verify(&public_key, &failure_message, &signature));
+
+            // Check that the signature is invalid for another public key.
+            let other_public_key = sphincs_plus.generate_private_key(rng)?.public_key();
+            assert!(!sphincs_plus.verify(&other_public_key, &message, &signature));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_modified_signature() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let sphincs_plus = CurrentSphincsPlus::setup(DomainSeparator::new("SphincsPlusTest", 0))?;
+        let private_key = sphincs_plus.generate_private_key(rng)?;
+        let public_key = private_key.public_key();
+        let message: Vec<_> = (0..10).map(|_| Uniform::rand(rng)).collect();
+        let signature = sphincs_plus.sign(&private_key, &message, rng)?;
+
+        // Returns the components of the signature.
+        let components = || {
+            (
+                signature.randomizer(),
+                signature.fors_secrets().to_vec(),
+                signature.fors_paths().to_vec(),
+                signature.wots_signatures().to_vec(),
+                signature.tree_paths().to_vec(),
+            )
+        };
+        // Returns `true` if the signature with the given components is valid.
+        let verify = |(randomizer, fors_secrets, fors_paths, wots_signatures, tree_paths)| {
+            let signature =
+                SphincsPlusSignature::new(randomizer, fors_secrets, fors_paths, wots_signatures, tree_paths);
+            sphincs_plus.verify(&public_key, &message, &signature)
+        };
+        assert!(verify(components()));
+
+        // Check that modifying any component of the signature invalidates it.
+        let mut modified = components();
+        modified.0 += Field::one();
+        assert!(!verify(modified));
+
+        let mut modified = components();
+        modified.1[0] += Field::one();
+        assert!(!verify(modified));
+
+        let mut modified = components();
+        modified.2[1][0] += Field::one();
+        assert!(!verify(modified));
+
+        let mut modified = components();
+        modified.3[1][0] += Field::one();
+        assert!(!verify(modified));
+
+        let mut modified = components();
+        modified.4[0][1] += Field::one();
+        assert!(!verify(modified));
+
+        // Check that a signature of the wrong shape is rejected.
+        let mut modified = components();
+        modified.3[0].pop();
+        assert!(!verify(modified));
+        Ok(())
+    }
+
+    #[test]
+    fn test_wots_digits() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let digits = CurrentSphincsPlus::wots_digits(Uniform::rand(rng));
+            assert_eq!(digits.len(), CurrentSphincsPlus::wots_length());
+            assert!(digits.iter().all(|digit| *digit < WOTS_W as u32));
+        }
+        // Check that the checksum is maximal for the zero message.
+        let digits = CurrentSphincsPlus::wots_digits(Field::zero());
+        let checksum = (WOTS_W - 1) * CurrentSphincsPlus::wots_message_length();
+        let expected = (0..WOTS_CHECKSUM_BITS / WOTS_LOG_W).map(|i| ((checksum >> (i * WOTS_LOG_W)) % WOTS_W) as u32);
+        assert!(digits[CurrentSphincsPlus::wots_message_length()..].iter().copied().eq(expected));
+    }
+}
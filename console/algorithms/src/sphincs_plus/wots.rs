@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<
+    E: Environment,
+    const NUM_LAYERS: usize,
+    const TREE_HEIGHT: usize,
+    const NUM_FORS_TREES: usize,
+    const FORS_HEIGHT: usize,
+> SphincsPlus<E, NUM_LAYERS, TREE_HEIGHT, NUM_FORS_TREES, FORS_HEIGHT>
+{
+    /// Returns the digits of the given WOTS+ message, followed by the digits of its checksum, where:
+    ///     checksum := sum(W - 1 - digit_i)
+    ///
+    /// The checksum ensures that no digit of a signed message can be increased without decreasing another.
+    pub fn wots_digits(message: Field<E>) -> Vec<u32> {
+        let mut digits =
+            message.to_bits_le().chunks(WOTS_LOG_W).map(|bits| from_bits_le(bits) as u32).collect::<Vec<_>>();
+        let checksum = digits.iter().map(|digit| WOTS_W as u32 - 1 - digit).sum::<u32>();
+        digits
+            .extend((0..WOTS_CHECKSUM_BITS / WOTS_LOG_W).map(|i| (checksum >> (i * WOTS_LOG_W)) & (WOTS_W as u32 - 1)));
+        digits
+    }
+
+    /// Returns the WOTS+ public key of the key pair at the given address.
+    pub(super) fn wots_public_key(
+        &self,
+        secret_seed: Field<E>,
+        public_seed: Field<E>,
+        address: Address,
+    ) -> Result<Field<E>> {
+        let ends = (0..Self::wots_length() as u32)
+            .map(|chain| {
+                let secret = self.wots_secret(secret_seed, public_seed, address, chain)?;
+                self.wots_chain(public_seed, address, chain, secret, 0, WOTS_W as u32 - 1)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.tweakable_hash(public_seed, address.with_kind(ADDRESS_WOTS_PK), &ends)
+    }
+
+    /// Returns the WOTS+ signature of the given message by the key pair at the given address.
+    pub(super) fn wots_sign(
+        &self,
+        secret_seed: Field<E>,
+        public_seed: Field<E>,
+        address: Address,
+        message: Field<E>,
+    ) -> Result<Vec<Field<E>>> {
+        Self::wots_digits(message)
+            .into_iter()
+            .zip(0..)
+            .map(|(digit, chain)| {
+                let secret = self.wots_secret(secret_seed, public_seed, address, chain)?;
+                self.wots_chain(public_seed, address, chain, secret, 0, digit)
+            })
+            .collect()
+    }
+
+    /// Returns the WOTS+ public key of the key pair at the given address, from a signature of the given message.
+    pub(super) fn wots_public_key_from_signature(
+        &self,
+        public_seed: Field<E>,
+        address: Address,
+        message: Field<E>,
+        signature: &[Field<E>],
+    ) -> Result<Field<E>> {
+        let digits = Self::wots_digits(message);
+        ensure!(signature.len() == digits.len(), "Expected {} WOTS+ chains, found {}", digits.len(), signature.len());
+
+        let ends = digits
+            .into_iter()
+            .zip(signature)
+            .zip(0..)
+            .map(|((digit, value), chain)| {
+                self.wots_chain(public_seed, address, chain, *value, digit, WOTS_W as u32 - 1 - digit)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.tweakable_hash(public_seed, address.with_kind(ADDRESS_WOTS_PK), &ends)
+    }
+
+    /// Returns the secret at the start of the given chain of the key pair at the given address.
+    fn wots_secret(
+        &self,
+        secret_seed: Field<E>,
+        public_seed: Field<E>,
+        address: Address,
+        chain: u32,
+    ) -> Result<Field<E>> {
+        self.tweakable_hash(public_seed, address.with_kind(ADDRESS_WOTS_PRF).with_words(chain, 0), &[secret_seed])
+    }
+
+    /// Returns the value after the given number of steps of the given chain, from the value at the given position.
+    fn wots_chain(
+        &self,
+        public_seed: Field<E>,
+        address: Address,
+        chain: u32,
+        value: Field<E>,
+        start: u32,
+        steps: u32,
+    ) -> Result<Field<E>> {
+        (start..start + steps).try_fold(value, |value, position| {
+            self.tweakable_hash(public_seed, address.with_words(chain, position), &[value])
+        })
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(test)]
+mod tests;
+
+use crate::merkle_tree::{LeafHash, MerklePath, PathHash};
+use snarkvm_console_types::prelude::*;
+
+use std::collections::BTreeMap;
+
+/// An append-only Merkle tree that stores only its rightmost frontier, so appending a leaf takes `O(DEPTH)` hashes.
+/// The roots and Merkle paths coincide with those of a [`MerkleTree`](crate::merkle_tree::MerkleTree) over the same leaves.
+///
+/// As the frontier does not retain the leaves, Merkle paths are only available for leaves that are marked
+/// when they are appended. The tree retains the leaf hashes appended since the oldest marked leaf,
+/// so the cost of a Merkle path grows with the number of leaves appended after it.
+#[derive(Clone)]
+pub struct IncrementalMerkleTree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+> {
+    /// The leaf hasher for the Merkle tree.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle tree.
+    path_hasher: PH,
+    /// The computed root of the Merkle tree.
+    root: Field<E>,
+    /// The canonical empty hash.
+    empty_hash: Field<E>,
+    /// The hashes of the empty subtrees of each height, from the leaf level to the root.
+    empty_subtree_hashes: Vec<Field<E>>,
+    /// The hash of the rightmost left child at each height, from the leaf level to the root.
+    frontier: Vec<Field<E>>,
+    /// The number of hashed leaves in the tree.
+    number_of_leaves: u64,
+    /// The left siblings on the Merkle path of each marked leaf, from the leaf level to the root.
+    marked: BTreeMap<u64, Vec<Field<E>>>,
+    /// The leaf hashes appended since the oldest marked leaf.
+    retained_leaves: Vec<Field<E>>,
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    IncrementalMerkleTree<E, LH, PH, DEPTH>
+{
+    /// Initializes a new empty incremental Merkle tree.
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the empty hash.
+        let empty_hash = path_hasher.hash_empty()?;
+
+        // Compute the hashes of the empty subtrees.
+        let mut empty_subtree_hashes = Vec::with_capacity(DEPTH as usize);
+        empty_subtree_hashes.push(empty_hash);
+        for height in 1..DEPTH as usize {
+            let child = empty_subtree_hashes[height - 1];
+            empty_subtree_hashes.push(path_hasher.hash_children(&child, &child)?);
+        }
+
+        // Compute the root hash of the empty tree, by hashing the empty hash with itself up to `DEPTH`.
+        let mut root = empty_hash;
+        for _ in 0..DEPTH {
+            root = path_hasher.hash_children(&root, &empty_hash)?;
+        }
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root,
+            empty_hash,
+            empty_subtree_hashes,
+            frontier: vec![empty_hash; DEPTH as usize],
+            number_of_leaves: 0,
+            marked: Default::default(),
+            retained_leaves: Default::default(),
+        })
+    }
+
+    /// Appends the given leaf to the Merkle tree, and returns its leaf index.
+    pub fn append(&mut self, leaf: &LH::Leaf) -> Result<u64> {
+        self.append_leaf(leaf, false)
+    }
+
+    /// Appends the given leaf to the Merkle tree, retaining its Merkle path, and returns its leaf index.
+    pub fn append_and_mark(&mut self, leaf: &LH::Leaf) -> Result<u64> {
+        self.append_leaf(leaf, true)
+    }
+
+    /// Stops retaining the Merkle path of the given leaf index, returning `true` if it was marked.
+    pub fn unmark(&mut self, leaf_index: u64) -> bool {
+        if self.marked.remove(&leaf_index).is_none() {
+            return false;
+        }
+        // Discard the leaf hashes that precede the oldest remaining marked leaf.
+        let retained_start = self.retained_start();
+        match self.marked.keys().next() {
+            Some(oldest) => drop(self.retained_leaves.drain(..(oldest - retained_start) as usize)),
+            None => self.retained_leaves.clear(),
+        }
+        true
+    }
+
+    /// Returns the Merkle path for the given marked leaf index and leaf.
+    pub fn prove(&self, leaf_index: u64, leaf: &LH::Leaf) -> Result<MerklePath<E, DEPTH>> {
+        // Ensure the leaf index is marked.
+        let Some(left_siblings) = self.marked.get(&leaf_index) else {
+            bail!("The given Merkle leaf index is not marked")
+        };
+        // Ensure the leaf hash matches the one in the tree.
+        let leaf_hash = self.leaf_hasher.hash_leaf(leaf)?;
+        ensure!(
+            self.retained_leaves[(leaf_index - self.retained_start()) as usize] == leaf_hash,
+            "The given Merkle leaf does not match the one in the Merkle tree"
+        );
+
+        // Compute the sibling on each level, which is either a left sibling or the subtree to the right of the path.
+        let mut path = Vec::with_capacity(DEPTH as usize);
+        for height in 0..DEPTH as usize {
+            match (leaf_index >> height) & 1 == 1 {
+                true => path.push(left_siblings[height]),
+                false => path.push(self.subtree_hash(((leaf_index >> height) | 1) << height, height)?),
+            }
+        }
+
+        // Return the Merkle path.
+        MerklePath::try_from((U64::new(leaf_index), path))
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    pub fn verify(&self, path: &MerklePath<E, DEPTH>, root: &PH::Hash, leaf: &LH::Leaf) -> bool {
+        path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
+    }
+
+    /// Returns the Merkle root of the tree.
+    pub const fn root(&self) -> &PH::Hash {
+        &self.root
+    }
+
+    /// Returns the hash of the rightmost left child at each height, from the leaf level to the root.
+    pub fn frontier(&self) -> &[PH::Hash] {
+        &self.frontier
+    }
+
+    /// Returns the number of leaves in the Merkle tree.
+    pub const fn number_of_leaves(&self) -> u64 {
+        self.number_of_leaves
+    }
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    IncrementalMerkleTree<E, LH, PH, DEPTH>
+{
+    /// Appends the given leaf to the Merkle tree, retaining its Merkle path if `mark` is set.
+    fn append_leaf(&mut self, leaf: &LH::Leaf, mark: bool) -> Result<u64> {
+        // Ensure the Merkle tree is not full.
+        let leaf_index = self.number_of_leaves;
+        ensure!((leaf_index as u128) < (1u128 << DEPTH), "Merkle tree cannot exceed {} leaves", 1u128 << DEPTH);
+
+        // Compute the leaf hash.
+        let leaf_hash = self.leaf_hasher.hash_leaf(leaf)?;
+        // Compute the number of levels that are filled by the leaves, above which the root is padded.
+        let tree_depth = (leaf_index + 1).next_power_of_two().trailing_zeros() as usize;
+
+        // Update the frontier and the root, by hashing up from the new leaf.
+        let mut current_hash = leaf_hash;
+        for height in 0..DEPTH as usize {
+            let (left, right) = match (leaf_index >> height) & 1 == 1 {
+                // The current hash is a right child, so its left sibling is on the frontier.
+                true => (self.frontier[height], current_hash),
+                // The current hash is a left child, so its right sibling is empty.
+                false => {
+                    self.frontier[height] = current_hash;
+                    match height < tree_depth {
+                        true => (current_hash, self.empty_subtree_hashes[height]),
+                        false => (current_hash, self.empty_hash),
+                    }
+                }
+            };
+            current_hash = self.path_hasher.hash_children(&left, &right)?;
+        }
+        self.root = current_hash;
+        self.number_of_leaves += 1;
+
+        // Retain the leaf hash if any leaf is marked, and the left siblings if this leaf is marked.
+        if mark {
+            self.marked.insert(leaf_index, self.frontier.clone());
+        }
+        if !self.marked.is_empty() {
+            self.retained_leaves.push(leaf_hash);
+        }
+        Ok(leaf_index)
+    }
+
+    /// Returns the leaf index of the first retained leaf hash.
+    fn retained_start(&self) -> u64 {
+        self.number_of_leaves - self.retained_leaves.len() as u64
+    }
+
+    /// Returns the hash of the subtree of the given height, whose leftmost leaf is at the given index.
+    fn subtree_hash(&self, start: u64, height: usize) -> Result<Field<E>> {
+        // If the subtree is empty, return the hash of the empty subtree.
+        if start >= self.number_of_leaves {
+            // Above the levels filled by the leaves, the root is padded with the empty hash.
+            let tree_depth = self.number_of_leaves.next_power_of_two().trailing_zeros() as usize;
+            return match height < tree_depth {
+                true => Ok(self.empty_subtree_hashes[height]),
+                false => Ok(self.empty_hash),
+            };
+        }
+        match height {
+            0 => Ok(self.retained_leaves[(start - self.retained_start()) as usize]),
+            _ => {
+                let left = self.subtree_hash(start, height - 1)?;
+                let right = self.subtree_hash(start.saturating_add(1 << (height - 1)), height - 1)?;
+                self.path_hasher.hash_children(&left, &right)
+            }
+        }
+    }
+}
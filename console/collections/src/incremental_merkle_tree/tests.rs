@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::merkle_tree::MerkleTree;
+use snarkvm_console_algorithms::{Poseidon, BHP1024};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 40;
+
+/// Runs the following test:
+/// 1. Append the leaves to the incremental Merkle tree, marking every third leaf.
+/// 2. Check that each root matches the Merkle tree over the same leaves.
+/// 3. Check that the Merkle path of each marked leaf matches the Merkle tree, and is valid.
+fn check_incremental_merkle_tree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    let mut tree = IncrementalMerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher)?;
+    assert_eq!(MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[])?.root(), tree.root());
+
+    for (i, leaf) in leaves.iter().enumerate() {
+        // Append the leaf, marking every third leaf.
+        let leaf_index = match i % 3 == 0 {
+            true => tree.append_and_mark(leaf)?,
+            false => tree.append(leaf)?,
+        };
+        assert_eq!(i as u64, leaf_index);
+        assert_eq!(i as u64 + 1, tree.number_of_leaves());
+
+        // Check the root against the Merkle tree.
+        let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves[..=i])?;
+        assert_eq!(merkle_tree.root(), tree.root());
+
+        // Check the Merkle path of each marked leaf against the Merkle tree.
+        for (j, leaf) in leaves[..=i].iter().enumerate().step_by(3) {
+            let path = tree.prove(j as u64, leaf)?;
+            assert_eq!(merkle_tree.prove(j, leaf)?, path);
+            assert!(tree.verify(&path, tree.root(), leaf));
+        }
+        // Ensure an unmarked leaf has no Merkle path.
+        if i % 3 != 0 {
+            assert!(tree.prove(i as u64, leaf).is_err());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_incremental_merkle_tree_poseidon() -> Result<()> {
+    let leaf_hasher = Poseidon::<CurrentEnvironment, 4>::setup(DomainSeparator::new("IncrementalMerkleTreeTest", 0))?;
+    let path_hasher = Poseidon::<CurrentEnvironment, 2>::setup(DomainSeparator::new("IncrementalMerkleTreeTest", 1))?;
+
+    let mut rng = TestRng::default();
+    let leaves = (0..ITERATIONS).map(|_| vec![Field::rand(&mut rng)]).collect::<Vec<_>>();
+
+    check_incremental_merkle_tree::<CurrentEnvironment, _, _, 6>(&leaf_hasher, &path_hasher, &leaves)?;
+    check_incremental_merkle_tree::<CurrentEnvironment, _, _, 32>(&leaf_hasher, &path_hasher, &leaves)
+}
+
+#[test]
+fn test_incremental_merkle_tree_bhp() -> Result<()> {
+    let leaf_hasher = BHP1024::<CurrentEnvironment>::setup(DomainSeparator::new("IncrementalMerkleTreeTest", 0))?;
+    let path_hasher = Poseidon::<CurrentEnvironment, 2>::setup(DomainSeparator::new("IncrementalMerkleTreeTest", 1))?;
+
+    let mut rng = TestRng::default();
+    let leaves =
+        (0..ITERATIONS).map(|_| (0..512).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>()).collect::<Vec<_>>();
+
+    check_incremental_merkle_tree::<CurrentEnvironment, _, _, 16>(&leaf_hasher, &path_hasher, &leaves)
+}
+
+#[test]
+fn test_unmark_and_capacity() -> Result<()> {
+    let leaf_hasher = Poseidon::<CurrentEnvironment, 4>::setup(DomainSeparator::new("IncrementalMerkleTreeTest", 0))?;
+    let path_hasher = Poseidon::<CurrentEnvironment, 2>::setup(DomainSeparator::new("IncrementalMerkleTreeTest", 1))?;
+    let mut tree = IncrementalMerkleTree::<CurrentEnvironment, _, _, 2>::new(&leaf_hasher, &path_hasher)?;
+
+    let leaves = (0..4u64).map(|i| vec![Field::from_u64(i)]).collect::<Vec<_>>();
+    tree.append_and_mark(&leaves[0])?;
+    tree.append_and_mark(&leaves[1])?;
+    tree.append(&leaves[2])?;
+    tree.append(&leaves[3])?;
+
+    // Ensure the tree rejects a leaf beyond its capacity.
+    assert!(tree.append(&leaves[0]).is_err());
+
+    // Unmark the first leaf, and ensure the second leaf still has a valid Merkle path.
+    assert!(tree.unmark(0));
+    assert!(!tree.unmark(0));
+    assert!(tree.prove(0, &leaves[0]).is_err());
+    let path = tree.prove(1, &leaves[1])?;
+    assert!(tree.verify(&path, tree.root(), &leaves[1]));
+    // Ensure the Merkle path is invalid for another leaf.
+    assert!(!tree.verify(&path, tree.root(), &leaves[0]));
+    Ok(())
+}
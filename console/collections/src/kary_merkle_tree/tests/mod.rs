@@ -265,8 +265,8 @@ fn test_kary_merkle_tree_bhp() -> Result<()> {
         type LH = BHP1024<CurrentEnvironment>;
         type PH = BHP512<CurrentEnvironment>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         let max_leaves = (ARITY as u128).saturating_pow(DEPTH as u32);
 
@@ -311,8 +311,8 @@ fn test_kary_merkle_tree_poseidon() -> Result<()> {
         type LH = Poseidon<CurrentEnvironment, 4>;
         type PH = Poseidon<CurrentEnvironment, 2>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         let max_leaves = (ARITY as u128).saturating_pow(DEPTH as u32);
 
@@ -446,8 +446,8 @@ fn test_merkle_tree_depth_2_arity_3_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
     type PH = BHP512<CurrentEnvironment>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -464,8 +464,8 @@ fn test_merkle_tree_depth_2_arity_3_poseidon() -> Result<()> {
     type LH = Poseidon<CurrentEnvironment, 4>;
     type PH = Poseidon<CurrentEnvironment, 3>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -518,8 +518,8 @@ fn test_merkle_tree_depth_3_arity_3_padded_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
     type PH = BHP512<CurrentEnvironment>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -537,8 +537,8 @@ fn test_merkle_tree_depth_3_arity_3_poseidon() -> Result<()> {
     type LH = Poseidon<CurrentEnvironment, 4>;
     type PH = Poseidon<CurrentEnvironment, 3>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -598,8 +598,8 @@ fn test_kary_merkle_tree_size_is_within_bounds() -> Result<()> {
         println!("Testing a depth {DEPTH} arity {ARITY} tree with {num_leaves} leaves");
         // Check the Merkle tree.
         check_kary_merkle_tree::<LH, PH, DEPTH, ARITY>(
-            &LH::setup("AleoMerkleTreeTest0")?,
-            &PH::setup("AleoMerkleTreeTest1")?,
+            &LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?,
+            &PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?,
             &(0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<Vec<bool>>>(),
         )
     }
@@ -20,5 +20,7 @@
 pub use snarkvm_console_algorithms as algorithms;
 pub use snarkvm_console_types::prelude::*;
 
+pub mod incremental_merkle_tree;
 pub mod kary_merkle_tree;
 pub mod merkle_tree;
+pub mod sparse_merkle_tree;
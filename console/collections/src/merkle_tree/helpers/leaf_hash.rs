@@ -28,10 +28,13 @@ pub trait LeafHash: Clone + Send + Sync {
 
     /// Returns the hash for each leaf node.
     fn hash_leaves(&self, leaves: &[Self::Leaf]) -> Result<Vec<Self::Hash>> {
+        let threshold = ParallelismConfig::global().merkle_tree.sequential_threshold;
         match leaves.len() {
             0 => Ok(vec![]),
-            1..=100 => leaves.iter().map(|leaf| self.hash_leaf(leaf)).collect(),
-            _ => cfg_iter!(leaves).map(|leaf| self.hash_leaf(leaf)).collect(),
+            num_leaves if num_leaves < threshold => leaves.iter().map(|leaf| self.hash_leaf(leaf)).collect(),
+            num_leaves => try_execute_in(Subsystem::MerkleTree, num_leaves, || {
+                cfg_iter!(leaves).map(|leaf| self.hash_leaf(leaf)).collect()
+            })?,
         }
     }
 }
@@ -32,10 +32,15 @@ pub trait PathHash: Clone + Send + Sync {
 
     /// Returns the hash for each tuple of child nodes.
     fn hash_all_children(&self, child_nodes: &[(Self::Hash, Self::Hash)]) -> Result<Vec<Self::Hash>> {
+        let threshold = ParallelismConfig::global().merkle_tree.sequential_threshold;
         match child_nodes.len() {
             0 => Ok(vec![]),
-            1..=100 => child_nodes.iter().map(|(left, right)| self.hash_children(left, right)).collect(),
-            _ => cfg_iter!(child_nodes).map(|(left, right)| self.hash_children(left, right)).collect(),
+            num_nodes if num_nodes < threshold => {
+                child_nodes.iter().map(|(left, right)| self.hash_children(left, right)).collect()
+            }
+            num_nodes => try_execute_in(Subsystem::MerkleTree, num_nodes, || {
+                cfg_iter!(child_nodes).map(|(left, right)| self.hash_children(left, right)).collect()
+            })?,
         }
     }
 }
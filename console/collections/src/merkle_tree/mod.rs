@@ -15,6 +15,9 @@
 mod helpers;
 pub use helpers::*;
 
+mod multi_path;
+pub use multi_path::*;
+
 mod path;
 pub use path::*;
 
@@ -53,6 +56,8 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     /// Initializes a new Merkle tree with the given leaves.
     pub fn new(leaf_hasher: &LH, path_hasher: &PH, leaves: &[LH::Leaf]) -> Result<Self> {
         let timer = timer!("MerkleTree::new");
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("merkle_tree::new", depth = DEPTH, num_leaves = leaves.len()).entered();
 
         // Ensure the Merkle tree depth is greater than 0.
         ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
@@ -123,6 +128,14 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     /// Returns a new Merkle tree with the given new leaves appended to it.
     pub fn prepare_append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
         let timer = timer!("MerkleTree::prepare_append");
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "merkle_tree::append",
+            depth = DEPTH,
+            num_leaves = self.number_of_leaves,
+            num_new_leaves = new_leaves.len(),
+        )
+        .entered();
 
         // Compute the maximum number of leaves.
         let max_leaves = match (self.number_of_leaves + new_leaves.len()).checked_next_power_of_two() {
@@ -226,6 +239,14 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     /// Returns a new Merkle tree with updates at the location of the given leaf index with the new leaf.
     pub fn prepare_update(&self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<Self> {
         let timer = timer!("MerkleTree::prepare_update");
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "merkle_tree::update",
+            depth = DEPTH,
+            num_leaves = self.number_of_leaves,
+            num_updates = 1,
+        )
+        .entered();
 
         // Check that the leaf index is within the bounds of the Merkle tree.
         ensure!(
@@ -303,6 +324,14 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     /// Updates the Merkle tree at the location of the given leaf indices with the new leaves.
     pub fn update_many(&mut self, updates: &BTreeMap<usize, LH::Leaf>) -> Result<()> {
         let timer = timer!("MerkleTree::update_many");
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "merkle_tree::update",
+            depth = DEPTH,
+            num_leaves = self.number_of_leaves,
+            num_updates = updates.len(),
+        )
+        .entered();
 
         // Check that there are updates to perform.
         ensure!(!updates.is_empty(), "There must be at least one leaf to update in the Merkle tree");
@@ -332,6 +361,71 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         };
         lap!(timer, "Hashed {} new leaves", leaf_hashes.len());
 
+        // Update the path hashes, level by level.
+        self.update_leaf_hashes(leaf_hashes)?;
+
+        finish!(timer);
+        Ok(())
+    }
+
+    #[inline]
+    /// Updates the Merkle tree at the given `(leaf_index, leaf)` pairs, in a single batch.
+    ///
+    /// This is an adapter of `update_many` for updates in a slice, in any order. The leaves are hashed from
+    /// the slice, rather than cloned into a `BTreeMap`, and the path hashes are updated as in `update_many`.
+    pub fn update_leaves(&mut self, updates: &[(usize, LH::Leaf)]) -> Result<()> {
+        let timer = timer!("MerkleTree::update_leaves");
+
+        // Check that there are updates to perform.
+        ensure!(!updates.is_empty(), "There must be at least one leaf to update in the Merkle tree");
+
+        // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
+        let start = match self.number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves - 1,
+            None => bail!("Integer overflow when computing the Merkle tree start index"),
+        };
+
+        // A helper to check the leaf index, and compute the leaf hash.
+        let hash_update = |(leaf_index, leaf): &(usize, LH::Leaf)| {
+            ensure!(*leaf_index < self.number_of_leaves, "Leaf index must be less than the number of leaves");
+            self.leaf_hasher.hash_leaf(leaf).map(|hash| (start + leaf_index, hash))
+        };
+
+        // Hash the leaves.
+        let mut leaf_hashes: Vec<(usize, LH::Hash)> = match updates.len() {
+            0..=100 => updates.iter().map(hash_update).collect::<Result<Vec<_>>>()?,
+            _ => cfg_iter!(updates).map(hash_update).collect::<Result<Vec<_>>>()?,
+        };
+        lap!(timer, "Hashed {} new leaves", leaf_hashes.len());
+
+        // Order the leaf hashes by leaf index, ensuring each leaf is updated at most once.
+        leaf_hashes.sort_unstable_by_key(|(index, _)| *index);
+        if let Some(duplicate) = leaf_hashes.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            bail!("Found a duplicate leaf index ({}) in the Merkle tree batch update", duplicate[0].0 - start);
+        }
+
+        // Update the path hashes, level by level.
+        self.update_leaf_hashes(leaf_hashes)?;
+
+        finish!(timer);
+        Ok(())
+    }
+
+    #[inline]
+    /// Returns a new Merkle tree with the given `(leaf_index, leaf)` pairs updated, in a single batch.
+    pub fn prepare_update_leaves(&self, updates: &[(usize, LH::Leaf)]) -> Result<Self> {
+        // Clone the Merkle tree, so the original tree is not altered.
+        let mut updated_tree = self.clone();
+        // Apply the updates to the cloned tree.
+        updated_tree.update_leaves(updates)?;
+        Ok(updated_tree)
+    }
+
+    /// Updates the path hashes of the Merkle tree for the given leaf hashes,
+    /// which are given by their index in the Merkle tree, in increasing order.
+    fn update_leaf_hashes(&mut self, leaf_hashes: Vec<(usize, LH::Hash)>) -> Result<()> {
+        let timer = timer!("MerkleTree::update_leaf_hashes");
+
         // Store the updated hashes by level.
         let mut updated_hashes = Vec::new();
         updated_hashes.push(leaf_hashes);
@@ -373,7 +467,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
                 // Get the sibling hash.
                 // Note: This algorithm assumes that the sibling hash is either the next hash in the vector,
                 // or in the original Merkle tree. Consequently, updates need to be provided in sequential order.
-                // This is enforced by the type of `updates: `BTreeMap<usize, LH::Leaf>`.
+                // This is enforced by `update_many` and `update_leaves`, which order the leaf hashes by index.
                 // If this assumption is violated, then the algorithm will compute incorrect path hashes in the Merkle tree.
                 let sibling_leaf_hash = match sibling_is_next_hash {
                     true => updated_hashes[level][current + 1].1,
@@ -431,6 +525,14 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     /// Returns a new Merkle tree with the last 'n' leaves removed from it.
     pub fn prepare_remove_last_n(&self, n: usize) -> Result<Self> {
         let timer = timer!("MerkleTree::prepare_remove_last_n");
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "merkle_tree::remove",
+            depth = DEPTH,
+            num_leaves = self.number_of_leaves,
+            num_removed = n,
+        )
+        .entered();
 
         ensure!(n > 0, "Cannot remove zero leaves from the Merkle tree");
 
@@ -570,11 +672,80 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         MerklePath::try_from((U64::new(leaf_index as u64), path))
     }
 
+    #[inline]
+    /// Returns the Merkle multi-path for the given leaf indices and leaves.
+    pub fn prove_many(&self, leaves: &[(usize, LH::Leaf)]) -> Result<MerkleMultiPath<E, DEPTH>> {
+        // Ensure there is at least one leaf to prove.
+        ensure!(!leaves.is_empty(), "There must be at least one leaf to prove in the Merkle tree");
+
+        // Order the leaves by leaf index, ensuring each leaf is proven at most once.
+        let mut ordered_leaves = BTreeMap::new();
+        for (leaf_index, leaf) in leaves {
+            // Ensure the leaf index is valid.
+            ensure!(*leaf_index < self.number_of_leaves, "The given Merkle leaf index is out of bounds");
+            ensure!(
+                ordered_leaves.insert(*leaf_index, leaf).is_none(),
+                "Found a duplicate leaf index ({leaf_index}) in the Merkle multi-proof"
+            );
+        }
+
+        // Ensure the leaf hashes match the ones in the tree.
+        let leaf_hashes = self.leaf_hashes()?;
+        for (leaf_index, leaf) in &ordered_leaves {
+            ensure!(
+                leaf_hashes[*leaf_index] == self.leaf_hasher.hash_leaf(leaf)?,
+                "The given Merkle leaf does not match the one in the Merkle tree"
+            );
+        }
+
+        // Compute the number of levels in the Merkle tree (i.e. log2(tree_size)).
+        let tree_depth = tree_depth::<DEPTH>(self.tree.len())?;
+        // A helper to return the sibling hash at the given position on the given level, counting from the leaves.
+        // Note: Above the height of the Merkle tree, the sibling hashes are the empty hash, as in `prove`.
+        let sibling_hash = |level: u8, position: usize| match level < tree_depth {
+            true => self.tree[(1usize << (tree_depth - level)) - 1 + position],
+            false => self.empty_hash,
+        };
+
+        // Initialize the positions on the current level, starting from the leaf indices.
+        let mut positions = ordered_leaves.keys().copied().collect::<Vec<_>>();
+        // Initialize a vector for the Merkle multi-path siblings.
+        let mut siblings = Vec::new();
+
+        // Iterate from the leaf level to the root level, storing the sibling hashes that cannot be computed.
+        for level in 0..DEPTH {
+            let mut current = 0;
+            while current < positions.len() {
+                let position = positions[current];
+                // If the sibling is the next position on this level, the pair shares a parent.
+                match positions.get(current + 1) == Some(&(position ^ 1)) {
+                    true => current += 2,
+                    false => {
+                        // Append the sibling hash to the multi-path.
+                        siblings.push(sibling_hash(level, position ^ 1));
+                        current += 1;
+                    }
+                }
+            }
+            // Update the positions to the parent positions.
+            positions = positions.into_iter().map(|position| position >> 1).dedup().collect();
+        }
+
+        // Return the Merkle multi-path.
+        let leaf_indices = ordered_leaves.keys().map(|leaf_index| U64::new(*leaf_index as u64)).collect();
+        MerkleMultiPath::try_from((leaf_indices, siblings))
+    }
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
     pub fn verify(&self, path: &MerklePath<E, DEPTH>, root: &PH::Hash, leaf: &LH::Leaf) -> bool {
         path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
     }
 
+    /// Returns `true` if the given Merkle multi-path is valid for the given root and leaves.
+    pub fn verify_many(&self, path: &MerkleMultiPath<E, DEPTH>, root: &PH::Hash, leaves: &[LH::Leaf]) -> bool {
+        path.verify(&self.leaf_hasher, &self.path_hasher, root, leaves)
+    }
+
     /// Returns the Merkle root of the tree.
     pub const fn root(&self) -> &PH::Hash {
         &self.root
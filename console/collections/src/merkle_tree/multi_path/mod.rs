@@ -0,0 +1,258 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::ops::Range;
+
+/// A Merkle multi-path, which authenticates a set of leaves under one root.
+///
+/// The siblings are shared across the leaves, so each internal node that can be computed
+/// from the given leaves is omitted, and each sibling hash is included at most once.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MerkleMultiPath<E: Environment, const DEPTH: u8> {
+    /// The leaf indices for the multi-path, in strictly increasing order.
+    leaf_indices: Vec<U64<E>>,
+    /// The `siblings` contains the sibling hashes, ordered by level from the leaves to the root,
+    /// and by position from left to right within each level.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<(Vec<U64<E>>, Vec<Field<E>>)> for MerkleMultiPath<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle multi-path.
+    fn try_from((leaf_indices, siblings): (Vec<U64<E>>, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure there is at least one leaf index.
+        ensure!(!leaf_indices.is_empty(), "Merkle multi-path must contain at least one leaf index");
+        // Ensure the leaf indices are in strictly increasing order.
+        ensure!(
+            leaf_indices.windows(2).all(|pair| *pair[0] < *pair[1]),
+            "Merkle multi-path leaf indices must be in strictly increasing order"
+        );
+        // Ensure the leaf indices are within the tree depth.
+        // Note: This unwrap is safe, as the leaf indices are non-empty.
+        ensure!(
+            (**leaf_indices.last().unwrap() as u128) < (1u128 << DEPTH),
+            "Found an out of bounds Merkle leaf index"
+        );
+        // Ensure the Merkle multi-path has the correct number of siblings.
+        ensure!(
+            siblings.len() == num_siblings::<E, DEPTH>(&leaf_indices),
+            "Found an incorrect number of Merkle multi-path siblings"
+        );
+        // Return the Merkle multi-path.
+        Ok(Self { leaf_indices, siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> MerkleMultiPath<E, DEPTH> {
+    /// Returns the leaf indices for the multi-path.
+    pub fn leaf_indices(&self) -> &[U64<E>] {
+        &self.leaf_indices
+    }
+
+    /// Returns the siblings for the multi-path.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns the siblings of each leaf, from the leaf to the root. A sibling is `None`
+    /// if it is computed from the other leaves of the multi-path, instead of included in it.
+    pub fn siblings_by_leaf(&self) -> Vec<Vec<Option<Field<E>>>> {
+        let mut siblings_by_leaf = vec![vec![None; DEPTH as usize]; self.leaf_indices.len()];
+        for ((level, leaves), sibling) in
+            sibling_runs::<E, DEPTH>(&self.leaf_indices).into_iter().zip_eq(&self.siblings)
+        {
+            leaves.for_each(|leaf| siblings_by_leaf[leaf][level as usize] = Some(*sibling));
+        }
+        siblings_by_leaf
+    }
+
+    /// Returns a Merkle multi-path from the given leaf indices and the siblings of each leaf, from the leaf
+    /// to the root. The siblings that are computed from the other leaves of the multi-path are ignored.
+    pub fn from_siblings_by_leaf(leaf_indices: Vec<U64<E>>, siblings_by_leaf: &[Vec<Field<E>>]) -> Result<Self> {
+        // Ensure there are siblings for each leaf.
+        ensure!(
+            siblings_by_leaf.len() == leaf_indices.len(),
+            "Found an incorrect number of Merkle multi-path leaf siblings"
+        );
+        // Ensure the siblings of each leaf are the correct length.
+        ensure!(
+            siblings_by_leaf.iter().all(|siblings| siblings.len() == DEPTH as usize),
+            "Found an incorrect Merkle path length"
+        );
+        // Select the sibling of the first leaf beneath each node, for each sibling in the multi-path.
+        // Note: The leaf indices are validated when the multi-path is constructed.
+        let siblings = sibling_runs::<E, DEPTH>(&leaf_indices)
+            .into_iter()
+            .map(|(level, leaves)| siblings_by_leaf[leaves.start][level as usize])
+            .collect();
+        // Return the Merkle multi-path.
+        Self::try_from((leaf_indices, siblings))
+    }
+
+    /// Returns `true` if the Merkle multi-path is valid for the given root and leaves.
+    /// The leaves must be given in the same order as the leaf indices.
+    pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaves: &[LH::Leaf],
+    ) -> bool {
+        // Ensure the number of leaves matches the number of leaf indices.
+        if leaves.len() != self.leaf_indices.len() {
+            eprintln!("Found an incorrect number of Merkle leaves");
+            return false;
+        }
+
+        // Compute the leaf hashes.
+        let leaf_hashes = match leaf_hasher.hash_leaves(leaves) {
+            Ok(leaf_hashes) => leaf_hashes,
+            Err(error) => {
+                eprintln!("Failed to hash the Merkle leaves during verification: {error}");
+                return false;
+            }
+        };
+
+        // Initialize a tracker for the current nodes, as pairs of (position, hash) on the current level.
+        let mut current_nodes = self.leaf_indices.iter().map(|index| **index).zip_eq(leaf_hashes).collect::<Vec<_>>();
+        // Initialize an iterator over the sibling hashes.
+        let mut siblings = self.siblings.iter();
+
+        // Check levels between leaf level and root.
+        for _ in 0..DEPTH {
+            let mut parent_nodes = Vec::with_capacity(current_nodes.len());
+            let mut current = 0;
+            while current < current_nodes.len() {
+                let (position, current_hash) = current_nodes[current];
+                // Get the sibling hash, which is either the next node on this level, or the next sibling in the path.
+                let sibling_hash = match current_nodes.get(current + 1) {
+                    Some((next_position, next_hash)) if *next_position == position ^ 1 => {
+                        current += 2;
+                        *next_hash
+                    }
+                    _ => {
+                        current += 1;
+                        match siblings.next() {
+                            Some(sibling_hash) => *sibling_hash,
+                            None => {
+                                eprintln!("Found an insufficient number of Merkle multi-path siblings");
+                                return false;
+                            }
+                        }
+                    }
+                };
+                // Construct the ordering of the left & right child hash for this level.
+                let (left, right) = match position & 1 == 0 {
+                    true => (current_hash, sibling_hash),
+                    false => (sibling_hash, current_hash),
+                };
+                // Compute the parent hash for the next level.
+                match path_hasher.hash_children(&left, &right) {
+                    Ok(hash) => parent_nodes.push((position >> 1, hash)),
+                    Err(error) => {
+                        eprintln!("Failed to hash the Merkle multi-path during verification: {error}");
+                        return false;
+                    }
+                }
+            }
+            current_nodes = parent_nodes;
+        }
+
+        // Ensure every sibling hash was used.
+        if siblings.next().is_some() {
+            eprintln!("Found an excess number of Merkle multi-path siblings");
+            return false;
+        }
+
+        // Ensure the final hash matches the given root.
+        matches!(current_nodes.as_slice(), [(0, hash)] if hash == root)
+    }
+}
+
+/// Returns, for each sibling hash of a Merkle multi-path with the given (sorted) leaf indices, its level
+/// (counting from the leaves) and the range of leaves beneath the node that the sibling hash is paired with.
+/// The sibling hashes are returned in the order in which they appear in the Merkle multi-path.
+fn sibling_runs<E: Environment, const DEPTH: u8>(leaf_indices: &[U64<E>]) -> Vec<(u8, Range<usize>)> {
+    // A helper to return the end of the run of leaves that share the node at the given level with the given leaf.
+    let run_end = |start: usize, level: u8| {
+        let node = *leaf_indices[start] >> level;
+        (start..leaf_indices.len()).find(|i| *leaf_indices[*i] >> level != node).unwrap_or(leaf_indices.len())
+    };
+
+    let mut runs = Vec::new();
+    for level in 0..DEPTH {
+        let mut start = 0;
+        while start < leaf_indices.len() {
+            let end = run_end(start, level);
+            // If the sibling is the next node on this level, the pair shares a parent.
+            match end < leaf_indices.len() && *leaf_indices[end] >> level == (*leaf_indices[start] >> level) ^ 1 {
+                true => start = run_end(end, level),
+                false => {
+                    runs.push((level, start..end));
+                    start = end;
+                }
+            }
+        }
+    }
+    runs
+}
+
+/// Returns the number of sibling hashes in a Merkle multi-path for the given (sorted) leaf indices.
+fn num_siblings<E: Environment, const DEPTH: u8>(leaf_indices: &[U64<E>]) -> usize {
+    sibling_runs::<E, DEPTH>(leaf_indices).len()
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for MerkleMultiPath<E, DEPTH> {
+    /// Reads in a Merkle multi-path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of leaf indices.
+        let num_leaf_indices = u32::read_le(&mut reader)?;
+        // Read the leaf indices.
+        let leaf_indices =
+            (0..num_leaf_indices).map(|_| Ok(U64::new(u64::read_le(&mut reader)?))).collect::<IoResult<Vec<_>>>()?;
+        // Read the number of siblings.
+        let num_siblings = u32::read_le(&mut reader)?;
+        // Ensure the number of siblings is within bounds, before allocating.
+        if num_siblings as usize > leaf_indices.len().saturating_mul(DEPTH as usize) {
+            return Err(error("Found an out of bounds number of Merkle multi-path siblings"));
+        }
+        // Read the Merkle multi-path siblings.
+        let siblings = (0..num_siblings).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the Merkle multi-path.
+        Self::try_from((leaf_indices, siblings)).map_err(error)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for MerkleMultiPath<E, DEPTH> {
+    /// Writes the Merkle multi-path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the number of leaf indices.
+        u32::try_from(self.leaf_indices.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the leaf indices.
+        self.leaf_indices.iter().try_for_each(|leaf_index| leaf_index.write_le(&mut writer))?;
+        // Write the number of siblings.
+        u32::try_from(self.siblings.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the Merkle multi-path siblings.
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
@@ -369,8 +369,8 @@ fn test_merkle_tree_bhp() -> Result<()> {
         type LH = BHP1024<CurrentEnvironment>;
         type PH = BHP512<CurrentEnvironment>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for i in 0..ITERATIONS {
             for j in 0..ITERATIONS {
@@ -424,8 +424,8 @@ fn test_merkle_tree_poseidon() -> Result<()> {
         type LH = Poseidon<CurrentEnvironment, 4>;
         type PH = Poseidon<CurrentEnvironment, 2>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for i in 0..ITERATIONS {
             for j in 0..ITERATIONS {
@@ -474,8 +474,8 @@ fn test_merkle_tree_depth_2_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
     type PH = BHP512<CurrentEnvironment>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -492,8 +492,8 @@ fn test_merkle_tree_depth_2_poseidon() -> Result<()> {
     type LH = Poseidon<CurrentEnvironment, 4>;
     type PH = Poseidon<CurrentEnvironment, 2>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -510,8 +510,8 @@ fn test_merkle_tree_depth_3_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
     type PH = BHP512<CurrentEnvironment>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -529,8 +529,8 @@ fn test_merkle_tree_depth_3_poseidon() -> Result<()> {
     type LH = Poseidon<CurrentEnvironment, 4>;
     type PH = Poseidon<CurrentEnvironment, 2>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -548,8 +548,8 @@ fn test_merkle_tree_depth_4_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
     type PH = BHP512<CurrentEnvironment>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -567,8 +567,8 @@ fn test_merkle_tree_depth_4_poseidon() -> Result<()> {
     type LH = Poseidon<CurrentEnvironment, 4>;
     type PH = Poseidon<CurrentEnvironment, 2>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -596,8 +596,8 @@ fn test_profiler() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
     type PH = BHP512<CurrentEnvironment>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -652,7 +652,7 @@ fn test_profiler() -> Result<()> {
 // }
 //
 // fn run_merkle_path_serialization_test<P: MerkleParameters>() {
-//     let parameters = &P::setup("merkle_tree_test");
+//     let parameters = &P::setup(DomainSeparator::new("MerkleTreeTest", 0));
 //
 //     let leaves = generate_random_leaves!(4, 8);
 //     merkle_path_serialization_test::<P, _>(&leaves, parameters);
@@ -662,7 +662,7 @@ fn test_profiler() -> Result<()> {
 // }
 //
 // fn run_merkle_path_bincode_test<P: MerkleParameters>() {
-//     let parameters = &P::setup("merkle_tree_test");
+//     let parameters = &P::setup(DomainSeparator::new("MerkleTreeTest", 0));
 //
 //     let leaves = generate_random_leaves!(4, 8);
 //     merkle_path_bincode_test::<P, _>(&leaves, parameters);
@@ -691,7 +691,7 @@ fn test_profiler() -> Result<()> {
 //         type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 32>;
 //         let leaves = generate_random_leaves!(1000, 32);
 //
-//         let parameters = &MTParameters::setup("merkle_tree_test");
+//         let parameters = &MTParameters::setup(DomainSeparator::new("MerkleTreeTest", 0));
 //         let tree = MerkleTree::<MTParameters>::new(Arc::new(parameters.clone()), &leaves[..]).unwrap();
 //
 //         let mut new_tree_1 =
@@ -726,7 +726,7 @@ fn test_profiler() -> Result<()> {
 //         type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 32>;
 //         let leaves = generate_random_leaves!(4, 8);
 //
-//         let parameters = &MTParameters::setup("merkle_tree_test");
+//         let parameters = &MTParameters::setup(DomainSeparator::new("MerkleTreeTest", 0));
 //         let tree = MerkleTree::<MTParameters>::new(Arc::new(parameters.clone()), &leaves[..]).unwrap();
 //
 //         let _proof = tree.prove(leaf_hasher, 0, &leaves[0]).unwrap();
@@ -745,7 +745,7 @@ fn test_profiler() -> Result<()> {
 //         type MTParameters = MerkleTreeParameters<LeafCRH, TwoToOneCRH, 2>;
 //         let leaves = generate_random_leaves!(4, 64);
 //
-//         let parameters = &MTParameters::setup("merkle_tree_test");
+//         let parameters = &MTParameters::setup(DomainSeparator::new("MerkleTreeTest", 0));
 //         let leaf_crh = parameters.leaf_crh();
 //         let two_to_one_crh = parameters.two_to_one_crh();
 //
@@ -15,8 +15,10 @@
 use super::*;
 
 mod append;
+mod multi_path;
 mod remove;
 mod update;
+mod update_leaves;
 mod update_many;
 
 macro_rules! run_tests {
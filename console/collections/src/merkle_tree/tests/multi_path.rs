@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: u128 = 10;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Compute a Merkle multi-path for a random subset of the leaves.
+/// 3. Check that the Merkle multi-path is valid, and no larger than the individual Merkle paths.
+/// 4. Check that the Merkle multi-path is invalid for an incorrect root or incorrect leaves.
+/// 5. Check that the Merkle multi-path serializes and deserializes correctly.
+fn check_merkle_multi_path<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    rng: &mut TestRng,
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    // Select a random subset of the leaves, in reverse order, ensuring at least one leaf is selected.
+    let selected = leaves
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(index, _)| *index == 0 || bool::rand(rng))
+        .map(|(index, leaf)| (index, leaf.clone()))
+        .collect::<Vec<_>>();
+    // Order the selected leaves by leaf index, to match the Merkle multi-path.
+    let ordered_leaves = selected.iter().rev().map(|(_, leaf)| leaf.clone()).collect::<Vec<_>>();
+
+    // Compute the Merkle multi-path.
+    let multi_path = merkle_tree.prove_many(&selected)?;
+    assert_eq!(selected.len(), multi_path.leaf_indices().len());
+    assert!(multi_path.siblings().len() <= selected.len() * DEPTH as usize);
+
+    // Verify the Merkle multi-path succeeds.
+    assert!(merkle_tree.verify_many(&multi_path, merkle_tree.root(), &ordered_leaves));
+    assert!(multi_path.verify(leaf_hasher, path_hasher, merkle_tree.root(), &ordered_leaves));
+    // Verify the Merkle multi-path **fails** on an invalid root.
+    assert!(!multi_path.verify(leaf_hasher, path_hasher, &PH::Hash::zero(), &ordered_leaves));
+    assert!(!multi_path.verify(leaf_hasher, path_hasher, &PH::Hash::rand(rng), &ordered_leaves));
+    // Verify the Merkle multi-path **fails** on missing leaves.
+    assert!(!multi_path.verify(leaf_hasher, path_hasher, merkle_tree.root(), &ordered_leaves[1..]));
+    // Verify the Merkle multi-path **fails** on reordered leaves.
+    if ordered_leaves.len() > 1 {
+        let mut reordered_leaves = ordered_leaves.clone();
+        reordered_leaves.swap(0, 1);
+        assert!(!multi_path.verify(leaf_hasher, path_hasher, merkle_tree.root(), &reordered_leaves));
+    }
+
+    // Check the Merkle multi-path matches the individual Merkle paths for a single leaf.
+    if let [(leaf_index, leaf)] = selected.as_slice() {
+        let path = merkle_tree.prove(*leaf_index, leaf)?;
+        assert_eq!(path.siblings(), multi_path.siblings());
+    }
+
+    // Check the Merkle multi-path serializes and deserializes correctly.
+    let bytes = multi_path.to_bytes_le()?;
+    assert_eq!(multi_path, MerkleMultiPath::<E, DEPTH>::from_bytes_le(&bytes)?);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_multi_path() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
+
+        for i in 0..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i + 1);
+            // Construct the leaves.
+            let leaves = (0..num_leaves)
+                .map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le())
+                .collect::<Vec<Vec<bool>>>();
+            // Check the Merkle multi-path.
+            check_merkle_multi_path::<CurrentEnvironment, LH, PH, DEPTH>(&leaf_hasher, &path_hasher, &leaves, rng)?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 6, 7, 8, 16, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_multi_path() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
+
+        for i in 0..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i + 1);
+            // Construct the leaves.
+            let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+            // Check the Merkle multi-path.
+            check_merkle_multi_path::<CurrentEnvironment, LH, PH, DEPTH>(&leaf_hasher, &path_hasher, &leaves, rng)?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 6, 7, 8, 16, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_multi_path_shares_siblings() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
+
+    // Construct a depth-3 Merkle tree with 8 leaves.
+    let leaves = (0..8).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    // Ensure adjacent leaves share all of their siblings above the leaf level.
+    let multi_path = merkle_tree.prove_many(&[(2, leaves[2].clone()), (3, leaves[3].clone())])?;
+    assert_eq!(multi_path.siblings(), &[merkle_tree.tree()[1], merkle_tree.tree()[2]]);
+    // Ensure the leaves of every subtree only require the siblings outside of it.
+    let multi_path = merkle_tree.prove_many(&(0..4).map(|i| (i, leaves[i].clone())).collect::<Vec<_>>())?;
+    assert_eq!(multi_path.siblings(), &[merkle_tree.tree()[2]]);
+    // Ensure all leaves require no siblings.
+    let multi_path = merkle_tree.prove_many(&leaves.iter().cloned().enumerate().collect::<Vec<_>>())?;
+    assert!(multi_path.siblings().is_empty());
+    assert!(multi_path.verify(&leaf_hasher, &path_hasher, merkle_tree.root(), &leaves));
+
+    // Ensure an empty set of leaves fails.
+    assert!(merkle_tree.prove_many(&[]).is_err());
+    // Ensure a duplicate leaf index fails.
+    assert!(merkle_tree.prove_many(&[(1, leaves[1].clone()), (1, leaves[1].clone())]).is_err());
+    // Ensure an out of bounds leaf index fails.
+    assert!(merkle_tree.prove_many(&[(8, leaves[0].clone())]).is_err());
+    // Ensure an incorrect leaf fails.
+    assert!(merkle_tree.prove_many(&[(0, leaves[1].clone())]).is_err());
+
+    type MultiPath = MerkleMultiPath<CurrentEnvironment, 3>;
+
+    // Ensure a Merkle multi-path with unordered leaf indices fails.
+    let leaf_indices = vec![U64::new(3), U64::new(2)];
+    let siblings = vec![merkle_tree.tree()[1], merkle_tree.tree()[2]];
+    assert!(MultiPath::try_from((leaf_indices, siblings.clone())).is_err());
+    // Ensure a Merkle multi-path with an incorrect number of siblings fails.
+    let leaf_indices = vec![U64::new(2), U64::new(3)];
+    assert!(MultiPath::try_from((leaf_indices.clone(), siblings[..1].to_vec())).is_err());
+    assert!(MultiPath::try_from((leaf_indices, siblings)).is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_multi_path_siblings_by_leaf() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
+
+    // Construct the Merkle tree.
+    let leaves = (0..13).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    for _ in 0..ITERATIONS {
+        // Select a random subset of the leaves, ensuring at least one leaf is selected.
+        let selected = (0..leaves.len())
+            .filter(|index| *index == 0 || bool::rand(&mut rng))
+            .map(|index| (index, leaves[index].clone()))
+            .collect::<Vec<_>>();
+        let multi_path = merkle_tree.prove_many(&selected)?;
+
+        // Check that each included sibling matches the Merkle path of its leaf.
+        let siblings_by_leaf = multi_path.siblings_by_leaf();
+        for ((leaf_index, leaf), siblings) in selected.iter().zip_eq(&siblings_by_leaf) {
+            let path = merkle_tree.prove(*leaf_index, leaf)?;
+            for (sibling, expected) in siblings.iter().zip_eq(path.siblings()) {
+                if let Some(sibling) = sibling {
+                    assert_eq!(sibling, expected);
+                }
+            }
+        }
+        // Check that the number of included siblings matches the multi-path, counting each shared sibling once.
+        let num_included = siblings_by_leaf.iter().flatten().filter(|sibling| sibling.is_some()).count();
+        assert!(num_included >= multi_path.siblings().len());
+
+        // Check that the multi-path is recovered from the siblings of each leaf.
+        let siblings_by_leaf = siblings_by_leaf
+            .into_iter()
+            .map(|siblings| siblings.into_iter().map(|sibling| sibling.unwrap_or_else(Field::zero)).collect())
+            .collect::<Vec<_>>();
+        let candidate = MerkleMultiPath::from_siblings_by_leaf(multi_path.leaf_indices().to_vec(), &siblings_by_leaf)?;
+        assert_eq!(multi_path, candidate);
+    }
+    Ok(())
+}
@@ -74,8 +74,8 @@ fn test_merkle_tree_bhp_remove() -> Result<()> {
         type LH = BHP1024<CurrentEnvironment>;
         type PH = BHP512<CurrentEnvironment>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for i in 0..ITERATIONS {
             for j in 0..ITERATIONS {
@@ -156,8 +156,8 @@ fn test_merkle_tree_poseidon_remove() -> Result<()> {
         type LH = Poseidon<CurrentEnvironment, 4>;
         type PH = Poseidon<CurrentEnvironment, 2>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for i in 0..ITERATIONS {
             for j in 0..ITERATIONS {
@@ -217,8 +217,8 @@ fn test_merkle_tree_bhp() -> Result<()> {
         type LH = BHP1024<CurrentEnvironment>;
         type PH = BHP512<CurrentEnvironment>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for i in 0..ITERATIONS {
             for j in 0..ITERATIONS {
@@ -257,8 +257,8 @@ fn test_merkle_tree_poseidon() -> Result<()> {
         type LH = Poseidon<CurrentEnvironment, 4>;
         type PH = Poseidon<CurrentEnvironment, 2>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for i in 0..ITERATIONS {
             for j in 0..ITERATIONS {
@@ -296,8 +296,8 @@ fn test_merkle_tree_bhp_update_is_consistent() -> Result<()> {
         type LH = BHP1024<CurrentEnvironment>;
         type PH = BHP512<CurrentEnvironment>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for _ in 0..ITERATIONS {
             // Determine the leaves and additional leaves.
@@ -339,8 +339,8 @@ fn test_merkle_tree_poseidon_update_is_consistent() -> Result<()> {
         type LH = Poseidon<CurrentEnvironment, 4>;
         type PH = Poseidon<CurrentEnvironment, 2>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for _ in 0..ITERATIONS {
             // Determine the leaves and additional leaves.
@@ -379,8 +379,8 @@ fn test_merkle_tree_depth_3_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
     type PH = BHP512<CurrentEnvironment>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -398,8 +398,8 @@ fn test_merkle_tree_depth_3_poseidon() -> Result<()> {
     type LH = Poseidon<CurrentEnvironment, 4>;
     type PH = Poseidon<CurrentEnvironment, 2>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -427,8 +427,8 @@ fn test_profiler() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
     type PH = BHP512<CurrentEnvironment>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
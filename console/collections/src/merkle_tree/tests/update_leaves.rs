@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::Poseidon;
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+#[test]
+fn test_merkle_tree_update_leaves_matches_update_many() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
+
+    for num_leaves in [1, 2, 10, 150] {
+        let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+        let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 10>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+        for _ in 0..ITERATIONS {
+            // Sample updates at distinct leaf indices, out of order.
+            let mut indices = (0..num_leaves).filter(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            indices.push(num_leaves - 1);
+            indices.dedup();
+            indices.reverse();
+            indices.rotate_left(indices.len() / 2);
+            let updates = indices.iter().map(|index| (*index, vec![Uniform::rand(&mut rng)])).collect::<Vec<_>>();
+
+            // Ensure the slice adapter matches `update_many`.
+            let candidate = merkle_tree.prepare_update_leaves(&updates)?;
+            let mut expected = merkle_tree.clone();
+            expected.update_many(&updates.iter().cloned().collect())?;
+            assert_eq!(candidate.root(), expected.root());
+            assert_eq!(candidate.tree(), expected.tree());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_update_leaves_rejects_invalid_batches() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
+
+    // Construct the Merkle tree.
+    let leaves = (0..10).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let mut merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let expected_root = *merkle_tree.root();
+
+    // Ensure an empty batch fails.
+    assert!(merkle_tree.update_leaves(&[]).is_err());
+    // Ensure a duplicate leaf index fails.
+    let updates = vec![(3, vec![Uniform::rand(&mut rng)]), (3, vec![Uniform::rand(&mut rng)])];
+    assert!(merkle_tree.update_leaves(&updates).is_err());
+    // Ensure an out of bounds leaf index fails.
+    let updates = vec![(0, vec![Uniform::rand(&mut rng)]), (10, vec![Uniform::rand(&mut rng)])];
+    assert!(merkle_tree.update_leaves(&updates).is_err());
+    assert!(merkle_tree.prepare_update_leaves(&updates).is_err());
+
+    // Ensure the Merkle tree is unchanged.
+    assert_eq!(*merkle_tree.root(), expected_root);
+
+    // Ensure a valid batch can be prepared, without altering the original Merkle tree.
+    let updates = vec![(9, vec![Uniform::rand(&mut rng)]), (0, vec![Uniform::rand(&mut rng)])];
+    let updated_tree = merkle_tree.prepare_update_leaves(&updates)?;
+    assert_ne!(*updated_tree.root(), expected_root);
+    assert_eq!(*merkle_tree.root(), expected_root);
+
+    // Ensure the prepared Merkle tree matches the updated Merkle tree.
+    merkle_tree.update_leaves(&updates)?;
+    assert_eq!(merkle_tree.root(), updated_tree.root());
+    Ok(())
+}
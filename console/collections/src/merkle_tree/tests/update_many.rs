@@ -215,8 +215,8 @@ fn test_merkle_tree_bhp() -> Result<()> {
         type LH = BHP1024<CurrentEnvironment>;
         type PH = BHP512<CurrentEnvironment>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for i in 0..ITERATIONS {
             for j in 0..ITERATIONS {
@@ -256,8 +256,8 @@ fn test_merkle_tree_poseidon() -> Result<()> {
         type LH = Poseidon<CurrentEnvironment, 4>;
         type PH = Poseidon<CurrentEnvironment, 2>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for i in 0..ITERATIONS {
             for j in 0..ITERATIONS {
@@ -296,8 +296,8 @@ fn test_merkle_tree_bhp_update_many_is_consistent() -> Result<()> {
         type LH = BHP1024<CurrentEnvironment>;
         type PH = BHP512<CurrentEnvironment>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for _ in 0..ITERATIONS {
             // Determine the leaves and additional leaves.
@@ -339,8 +339,8 @@ fn test_merkle_tree_poseidon_update_many_is_consistent() -> Result<()> {
         type LH = Poseidon<CurrentEnvironment, 4>;
         type PH = Poseidon<CurrentEnvironment, 2>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for _ in 0..ITERATIONS {
             // Determine the leaves and additional leaves.
@@ -380,8 +380,8 @@ fn test_merkle_tree_bhp_update_and_update_many_match() -> Result<()> {
         type LH = BHP1024<CurrentEnvironment>;
         type PH = BHP512<CurrentEnvironment>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for _ in 0..ITERATIONS {
             // Determine the leaves and additional leaves.
@@ -438,8 +438,8 @@ fn test_merkle_tree_poseidon_update_and_update_many_match() -> Result<()> {
         type LH = Poseidon<CurrentEnvironment, 4>;
         type PH = Poseidon<CurrentEnvironment, 2>;
 
-        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+        let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+        let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
         for _ in 0..ITERATIONS {
             // Determine the number of leaves.
@@ -493,8 +493,8 @@ fn test_merkle_tree_depth_3_poseidon() -> Result<()> {
     type LH = Poseidon<CurrentEnvironment, 4>;
     type PH = Poseidon<CurrentEnvironment, 2>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -512,8 +512,8 @@ fn test_merkle_tree_depth_3_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
     type PH = BHP512<CurrentEnvironment>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -541,8 +541,8 @@ fn test_profiler() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
     type PH = BHP512<CurrentEnvironment>;
 
-    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
-    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let leaf_hasher = LH::setup(DomainSeparator::new("MerkleTreeTest", 0))?;
+    let path_hasher = PH::setup(DomainSeparator::new("MerkleTreeTest", 1))?;
 
     let mut rng = TestRng::default();
 
@@ -0,0 +1,212 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod proof;
+pub use proof::*;
+
+#[cfg(test)]
+mod tests;
+
+use crate::merkle_tree::{LeafHash, PathHash};
+use snarkvm_console_types::prelude::*;
+
+use std::collections::{BTreeMap, HashMap};
+
+/// A sparse Merkle tree, whose leaves are keyed by field elements.
+/// The depth `DEPTH` must be the number of bits in a field element, so that the leaf of a key is at the position
+/// given by all the bits of the key, and distinct keys never share a leaf. Each leaf holds the hash of its key
+/// and value, or the zero hash if it is empty.
+/// Only the non-empty nodes are stored, as the hashes of empty subtrees are cached per height.
+#[derive(Clone)]
+pub struct SparseMerkleTree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+> {
+    /// The leaf hasher for the Merkle tree.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle tree.
+    path_hasher: PH,
+    /// The computed root of the Merkle tree.
+    root: Field<E>,
+    /// The hashes of the empty subtrees, from the leaf level to the root.
+    empty_hashes: Vec<Field<E>>,
+    /// The hashes of the non-empty nodes, keyed by their height and the bits of their position above that height.
+    nodes: BTreeMap<(u8, E::BigInteger), Field<E>>,
+    /// The values of the non-empty leaves, keyed by their key.
+    leaves: HashMap<Field<E>, LH::Leaf>,
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    SparseMerkleTree<E, LH, PH, DEPTH>
+{
+    /// Initializes a new empty sparse Merkle tree.
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH) -> Result<Self> {
+        // Ensure the Merkle tree depth is the number of bits in a key, so that every key has its own leaf.
+        ensure!(
+            DEPTH as usize == Field::<E>::size_in_bits(),
+            "Sparse Merkle tree depth must be {}, found {DEPTH}",
+            Field::<E>::size_in_bits()
+        );
+
+        // Compute the hashes of the empty subtrees, starting from the empty leaf.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(Field::zero());
+        for height in 0..DEPTH as usize {
+            empty_hashes.push(path_hasher.hash_children(&empty_hashes[height], &empty_hashes[height])?);
+        }
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: empty_hashes[DEPTH as usize],
+            empty_hashes,
+            nodes: Default::default(),
+            leaves: Default::default(),
+        })
+    }
+
+    /// Returns the Merkle root.
+    pub const fn root(&self) -> &Field<E> {
+        &self.root
+    }
+
+    /// Returns the number of non-empty leaves in the Merkle tree.
+    pub fn number_of_leaves(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns the value of the given key, if it is in the Merkle tree.
+    pub fn get(&self, key: &Field<E>) -> Option<&LH::Leaf> {
+        self.leaves.get(key)
+    }
+
+    /// Returns `true` if the given key is in the Merkle tree.
+    pub fn contains(&self, key: &Field<E>) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Inserts the given key and value into the Merkle tree, replacing the previous value of the key.
+    pub fn insert(&mut self, key: &Field<E>, value: &LH::Leaf) -> Result<()> {
+        // Compute the hash of the leaf node, which binds the key to the value.
+        let leaf_hash = self.path_hasher.hash_children(key, &self.leaf_hasher.hash_leaf(value)?)?;
+        // Update the path from the leaf to the root.
+        self.update_path(key, leaf_hash)?;
+        self.leaves.insert(*key, value.clone());
+        Ok(())
+    }
+
+    /// Removes the given key from the Merkle tree, returning its value if it was in the Merkle tree.
+    pub fn remove(&mut self, key: &Field<E>) -> Result<Option<LH::Leaf>> {
+        if !self.contains(key) {
+            return Ok(None);
+        }
+        // Update the path from the now-empty leaf to the root.
+        self.update_path(key, self.empty_hashes[0])?;
+        Ok(self.leaves.remove(key))
+    }
+
+    /// Returns the Merkle path proving the given key and value are in the Merkle tree.
+    pub fn prove_membership(&self, key: &Field<E>, value: &LH::Leaf) -> Result<SparseMerklePath<E, DEPTH>> {
+        // Ensure the key and value are in the Merkle tree.
+        match self.get(key) {
+            Some(candidate) => {
+                let leaf_hash = self.leaf_hasher.hash_leaf(value)?;
+                ensure!(
+                    self.leaf_hasher.hash_leaf(candidate)? == leaf_hash,
+                    "The given value does not match the one in the Merkle tree"
+                );
+            }
+            None => bail!("The given key is not in the Merkle tree"),
+        }
+        SparseMerklePath::try_from(self.siblings(key))
+    }
+
+    /// Returns the proof that the given key is not in the Merkle tree.
+    pub fn prove_non_membership(&self, key: &Field<E>) -> Result<NonMembershipProof<E, DEPTH>> {
+        // Ensure the key is not in the Merkle tree.
+        ensure!(!self.contains(key), "The given key is in the Merkle tree");
+        // As every key has its own leaf, the leaf of the key is empty.
+        Ok(NonMembershipProof::new(SparseMerklePath::try_from(self.siblings(key))?))
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root, key, and value.
+    pub fn verify_membership(
+        &self,
+        path: &SparseMerklePath<E, DEPTH>,
+        root: &Field<E>,
+        key: &Field<E>,
+        value: &LH::Leaf,
+    ) -> bool {
+        path.verify_membership(&self.leaf_hasher, &self.path_hasher, root, key, value)
+    }
+
+    /// Returns `true` if the given non-membership proof is valid for the given root and key.
+    pub fn verify_non_membership(&self, proof: &NonMembershipProof<E, DEPTH>, root: &Field<E>, key: &Field<E>) -> bool {
+        proof.verify(&self.path_hasher, root, key)
+    }
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    SparseMerkleTree<E, LH, PH, DEPTH>
+{
+    /// Returns the identifier of the node at the given height on the path of the given key,
+    /// which is the height and the bits of the key above that height, as an integer.
+    fn node_id(key: &Field<E>, height: u8) -> (u8, E::BigInteger) {
+        let mut prefix = key.to_bigint();
+        prefix.divn(u32::from(height));
+        (height, prefix)
+    }
+
+    /// Returns the identifier of the sibling of the given node.
+    fn sibling_id((height, mut prefix): (u8, E::BigInteger)) -> (u8, E::BigInteger) {
+        prefix.as_mut()[0] ^= 1;
+        (height, prefix)
+    }
+
+    /// Returns the hash of the node with the given identifier.
+    fn node(&self, id: &(u8, E::BigInteger)) -> Field<E> {
+        self.nodes.get(id).copied().unwrap_or(self.empty_hashes[id.0 as usize])
+    }
+
+    /// Returns the sibling hashes on the path from the leaf of the given key to the root.
+    fn siblings(&self, key: &Field<E>) -> Vec<Field<E>> {
+        (0..DEPTH).map(|height| self.node(&Self::sibling_id(Self::node_id(key, height)))).collect()
+    }
+
+    /// Sets the hash of the leaf of the given key, and recomputes the hashes on its path to the root.
+    fn update_path(&mut self, key: &Field<E>, leaf_hash: Field<E>) -> Result<()> {
+        let mut current_hash = leaf_hash;
+        for (height, bit) in (0..DEPTH).zip_eq(key.to_bits_le().into_iter().take(DEPTH as usize)) {
+            // Store the current hash, unless it is the hash of an empty subtree.
+            let id = Self::node_id(key, height);
+            match current_hash == self.empty_hashes[height as usize] {
+                true => self.nodes.remove(&id),
+                false => self.nodes.insert(id, current_hash),
+            };
+
+            // Construct the ordering of the left & right child hash for this level.
+            let sibling_hash = self.node(&Self::sibling_id(id));
+            let (left, right) = match bit {
+                false => (current_hash, sibling_hash),
+                true => (sibling_hash, current_hash),
+            };
+            // Update the current hash for the next level.
+            current_hash = self.path_hasher.hash_children(&left, &right)?;
+        }
+        self.root = current_hash;
+        Ok(())
+    }
+}
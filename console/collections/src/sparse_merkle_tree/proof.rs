@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparseMerklePath<E: Environment, const DEPTH: u8> {
+    /// The `siblings` contains a list of sibling hashes from the leaf to the root.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<Vec<Field<E>>> for SparseMerklePath<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a sparse Merkle path.
+    fn try_from(siblings: Vec<Field<E>>) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle path is the correct length.
+        ensure!(siblings.len() == DEPTH as usize, "Found an incorrect Merkle path length");
+        // Return the Merkle path.
+        Ok(Self { siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> SparseMerklePath<E, DEPTH> {
+    /// Returns the siblings for the path.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root, key, and value.
+    pub fn verify_membership<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &Field<E>,
+        key: &Field<E>,
+        value: &LH::Leaf,
+    ) -> bool {
+        // Compute the hash of the leaf node, which binds the key to the value.
+        let leaf_hash = match leaf_hasher.hash_leaf(value).and_then(|hash| path_hasher.hash_children(key, &hash)) {
+            Ok(leaf_hash) => leaf_hash,
+            Err(error) => {
+                eprintln!("Failed to hash the sparse Merkle leaf during verification: {error}");
+                return false;
+            }
+        };
+        self.is_valid(path_hasher, root, key, leaf_hash)
+    }
+
+    /// Returns `true` if the given leaf hash is at the position of the given key, under the given root.
+    fn is_valid<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &Field<E>,
+        key: &Field<E>,
+        leaf_hash: Field<E>,
+    ) -> bool {
+        // Ensure the path length matches the expected depth.
+        if self.siblings.len() != DEPTH as usize {
+            eprintln!("Found an incorrect Merkle path length");
+            return false;
+        }
+
+        // Compute the ordering of the current hash and sibling hash on each level.
+        // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
+        // If the indicator bit is `false`, then the ordering is (sibling_hash, current_hash).
+        let indicators = key.to_bits_le().into_iter().take(DEPTH as usize).map(|bit| !bit);
+
+        // Check levels between leaf level and root.
+        let mut current_hash = leaf_hash;
+        for (indicator, sibling_hash) in indicators.zip_eq(&self.siblings) {
+            // Construct the ordering of the left & right child hash for this level.
+            let (left, right) = match indicator {
+                true => (current_hash, *sibling_hash),
+                false => (*sibling_hash, current_hash),
+            };
+            // Update the current hash for the next level.
+            match path_hasher.hash_children(&left, &right) {
+                Ok(hash) => current_hash = hash,
+                Err(error) => {
+                    eprintln!("Failed to hash the sparse Merkle path during verification: {error}");
+                    return false;
+                }
+            }
+        }
+
+        // Ensure the final hash matches the given root.
+        current_hash == *root
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonMembershipProof<E: Environment, const DEPTH: u8> {
+    /// The Merkle path to the (empty) leaf of the key.
+    path: SparseMerklePath<E, DEPTH>,
+}
+
+impl<E: Environment, const DEPTH: u8> NonMembershipProof<E, DEPTH> {
+    /// Returns a new instance of a non-membership proof.
+    pub const fn new(path: SparseMerklePath<E, DEPTH>) -> Self {
+        Self { path }
+    }
+
+    /// Returns the Merkle path to the leaf of the key.
+    pub const fn path(&self) -> &SparseMerklePath<E, DEPTH> {
+        &self.path
+    }
+
+    /// Returns `true` if the proof shows the given key is not in the Merkle tree with the given root.
+    pub fn verify<PH: PathHash<Hash = Field<E>>>(&self, path_hasher: &PH, root: &Field<E>, key: &Field<E>) -> bool {
+        // As every key has its own leaf, the key is absent if and only if its leaf is empty.
+        self.path.is_valid(path_hasher, root, key, Field::zero())
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for SparseMerklePath<E, DEPTH> {
+    /// Reads in a sparse Merkle path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the Merkle path siblings.
+        let siblings = (0..DEPTH).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the Merkle path.
+        Self::try_from(siblings).map_err(error)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for SparseMerklePath<E, DEPTH> {
+    /// Writes the sparse Merkle path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the Merkle path siblings.
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for NonMembershipProof<E, DEPTH> {
+    /// Reads in a non-membership proof from a buffer.
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        // Read the Merkle path.
+        Ok(Self { path: FromBytes::read_le(reader)? })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for NonMembershipProof<E, DEPTH> {
+    /// Writes the non-membership proof to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        // Write the Merkle path.
+        self.path.write_le(writer)
+    }
+}
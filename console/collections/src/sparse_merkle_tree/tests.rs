@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+/// Runs the following test:
+/// 1. Insert random keys into the sparse Merkle tree.
+/// 2. Check that the membership proof for every key is valid.
+/// 3. Check that the non-membership proof for random other keys is valid.
+/// 4. Remove the keys, and check that the sparse Merkle tree is empty again.
+fn check_sparse_merkle_tree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    entries: &[(Field<E>, LH::Leaf)],
+) -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Construct the sparse Merkle tree for the given entries.
+    let mut tree = SparseMerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher)?;
+    let empty_root = *tree.root();
+    for (key, value) in entries {
+        tree.insert(key, value)?;
+    }
+    assert_eq!(entries.len(), tree.number_of_leaves());
+
+    // Check each key in the sparse Merkle tree.
+    for (key, value) in entries {
+        let proof = tree.prove_membership(key, value)?;
+        assert!(tree.verify_membership(&proof, tree.root(), key, value));
+        // Verify the proof **fails** on an invalid root or key.
+        assert!(!tree.verify_membership(&proof, &Field::rand(&mut rng), key, value));
+        assert!(!tree.verify_membership(&proof, tree.root(), &Field::rand(&mut rng), value));
+        // Ensure the key has no non-membership proof.
+        assert!(tree.prove_non_membership(key).is_err());
+        // Check the bytes round trip.
+        assert_eq!(proof, SparseMerklePath::read_le(&proof.to_bytes_le()?[..])?);
+    }
+
+    // Check random keys that are not in the sparse Merkle tree.
+    for _ in 0..ITERATIONS {
+        let key = Field::rand(&mut rng);
+        let proof = tree.prove_non_membership(&key)?;
+        assert!(tree.verify_non_membership(&proof, tree.root(), &key));
+        // Verify the proof **fails** on an invalid root, or for a key in the tree.
+        assert!(!tree.verify_non_membership(&proof, &Field::rand(&mut rng), &key));
+        if let Some((key, _)) = entries.first() {
+            assert!(!tree.verify_non_membership(&proof, tree.root(), key));
+        }
+        // Check the bytes round trip.
+        assert_eq!(proof, NonMembershipProof::read_le(&proof.to_bytes_le()?[..])?);
+    }
+
+    // Remove each key, and check it is no longer in the sparse Merkle tree.
+    for (key, value) in entries {
+        assert!(tree.remove(key)?.is_some());
+        assert!(tree.prove_membership(key, value).is_err());
+        let proof = tree.prove_non_membership(key)?;
+        assert!(tree.verify_non_membership(&proof, tree.root(), key));
+    }
+    assert_eq!(empty_root, *tree.root());
+    Ok(())
+}
+
+#[test]
+fn test_sparse_merkle_tree_poseidon() -> Result<()> {
+    let leaf_hasher = Poseidon::<CurrentEnvironment, 4>::setup(DomainSeparator::new("SparseTreeTest", 0))?;
+    let path_hasher = Poseidon::<CurrentEnvironment, 2>::setup(DomainSeparator::new("SparseTreeTest", 1))?;
+
+    let mut rng = TestRng::default();
+    let entries = (0..ITERATIONS)
+        .map(|_| (Field::rand(&mut rng), (0..4).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+
+    check_sparse_merkle_tree::<CurrentEnvironment, _, _, 253>(&leaf_hasher, &path_hasher, &entries)
+}
+
+#[test]
+fn test_sparse_merkle_tree_bhp() -> Result<()> {
+    let leaf_hasher = BHP512::<CurrentEnvironment>::setup(DomainSeparator::new("SparseTreeTest", 0))?;
+    let path_hasher = Poseidon::<CurrentEnvironment, 2>::setup(DomainSeparator::new("SparseTreeTest", 1))?;
+
+    let mut rng = TestRng::default();
+    let entries = (0..ITERATIONS)
+        .map(|_| (Field::rand(&mut rng), (0..128).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+
+    check_sparse_merkle_tree::<CurrentEnvironment, _, _, 253>(&leaf_hasher, &path_hasher, &entries)
+}
+
+#[test]
+fn test_depth() -> Result<()> {
+    let leaf_hasher = Poseidon::<CurrentEnvironment, 4>::setup(DomainSeparator::new("SparseTreeTest", 0))?;
+    let path_hasher = Poseidon::<CurrentEnvironment, 2>::setup(DomainSeparator::new("SparseTreeTest", 1))?;
+
+    // Ensure the depth must be the number of bits in a key.
+    assert!(SparseMerkleTree::<CurrentEnvironment, _, _, 8>::new(&leaf_hasher, &path_hasher).is_err());
+    assert!(SparseMerkleTree::<CurrentEnvironment, _, _, 252>::new(&leaf_hasher, &path_hasher).is_err());
+    assert!(SparseMerkleTree::<CurrentEnvironment, _, _, 254>::new(&leaf_hasher, &path_hasher).is_err());
+    assert!(SparseMerkleTree::<CurrentEnvironment, _, _, 253>::new(&leaf_hasher, &path_hasher).is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_keys_with_common_low_bits() -> Result<()> {
+    let leaf_hasher = Poseidon::<CurrentEnvironment, 4>::setup(DomainSeparator::new("SparseTreeTest", 0))?;
+    let path_hasher = Poseidon::<CurrentEnvironment, 2>::setup(DomainSeparator::new("SparseTreeTest", 1))?;
+    let mut tree = SparseMerkleTree::<CurrentEnvironment, _, _, 253>::new(&leaf_hasher, &path_hasher)?;
+
+    // Insert keys that only differ in their high bits, including the highest bit of a key.
+    let key = Field::<CurrentEnvironment>::from_u64(5);
+    let other_keys = [key + Field::from_u64(1 << 8), key + Field::one().double().pow(Field::from_u64(252))];
+    tree.insert(&key, &vec![Field::one()])?;
+    for other_key in &other_keys {
+        tree.insert(other_key, &vec![Field::one().double()])?;
+    }
+    assert_eq!(3, tree.number_of_leaves());
+
+    // Ensure each key is proven to be in the tree.
+    let proof = tree.prove_membership(&key, &vec![Field::one()])?;
+    assert!(tree.verify_membership(&proof, tree.root(), &key, &vec![Field::one()]));
+    for other_key in &other_keys {
+        let proof = tree.prove_membership(other_key, &vec![Field::one().double()])?;
+        assert!(tree.verify_membership(&proof, tree.root(), other_key, &vec![Field::one().double()]));
+    }
+
+    // Ensure removing the key does not affect the other keys.
+    assert_eq!(tree.remove(&key)?, Some(vec![Field::one()]));
+    let proof = tree.prove_non_membership(&key)?;
+    assert!(tree.verify_non_membership(&proof, tree.root(), &key));
+    assert!(other_keys.iter().all(|other_key| tree.contains(other_key)));
+    Ok(())
+}
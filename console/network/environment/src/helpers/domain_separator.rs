@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+
+/// A structured domain separator for a hash, CRH, or sponge instantiation.
+///
+/// A domain separator is encoded as `Aleo || NAME || VERSION`, with an optional `.INDEX` suffix,
+/// where the name is an alphanumeric CamelCase identifier that does not end with a digit, and the
+/// version and index are decimal numbers. As the version is the trailing run of digits, the encoding
+/// is injective, so distinct domain separators never collide.
+///
+/// The base instantiations of the network primitives (e.g. `AleoBHP256` and `AleoPoseidon4`) predate
+/// this type, and encode the input size or rate of the primitive in place of the version. These are
+/// constructed with [`DomainSeparator::primitive`], and their names are reserved, so they never
+/// collide with a versioned domain separator.
+///
+/// The name is checked when the domain separator is constructed, which is at compile time for constants.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DomainSeparator {
+    /// The name of the domain.
+    name: &'static str,
+    /// The version of the domain.
+    version: u16,
+    /// The input size (or rate) of the primitive, if this is the base instantiation of a network primitive.
+    size: Option<u16>,
+    /// The index of the subdomain, if any.
+    index: Option<u16>,
+}
+
+impl DomainSeparator {
+    /// The prefix of every domain separator.
+    pub const PREFIX: &'static str = "Aleo";
+    /// The names of the network primitives, which are reserved for their base instantiations.
+    pub const PRIMITIVES: [&'static str; 3] = ["BHP", "Pedersen", "Poseidon"];
+
+    /// Initializes a new domain separator with the given name and version.
+    pub const fn new(name: &'static str, version: u16) -> Self {
+        Self::check_name(name);
+        assert!(!Self::is_primitive(name), "The name of a network primitive is reserved");
+        Self { name, version, size: None, index: None }
+    }
+
+    /// Initializes the domain separator of the base instantiation of the network primitive
+    /// with the given name and input size (or rate), e.g. `AleoBHP256`.
+    pub const fn primitive(name: &'static str, size: u16) -> Self {
+        Self::check_name(name);
+        assert!(Self::is_primitive(name), "The name must be one of the network primitives");
+        Self { name, version: 0, size: Some(size), index: None }
+    }
+
+    /// Returns the subdomain with the given index.
+    pub const fn with_index(self, index: u16) -> Self {
+        Self { name: self.name, version: self.version, size: self.size, index: Some(index) }
+    }
+
+    /// Returns the name of the domain.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the version of the domain.
+    pub const fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the input size (or rate) of the primitive, if this is the base instantiation of a network primitive.
+    pub const fn size(&self) -> Option<u16> {
+        self.size
+    }
+
+    /// Returns the index of the subdomain, if any.
+    pub const fn index(&self) -> Option<u16> {
+        self.index
+    }
+
+    /// Ensures the given name is a nonempty, alphanumeric CamelCase identifier that does not end with a digit.
+    const fn check_name(name: &'static str) {
+        let bytes = name.as_bytes();
+        assert!(!bytes.is_empty(), "The name of a domain separator must be nonempty");
+        assert!(bytes[0].is_ascii_uppercase(), "The name of a domain separator must start with an uppercase letter");
+        assert!(!bytes[bytes.len() - 1].is_ascii_digit(), "The name of a domain separator must not end with a digit");
+        let mut i = 0;
+        while i < bytes.len() {
+            assert!(bytes[i].is_ascii_alphanumeric(), "The name of a domain separator must be alphanumeric");
+            i += 1;
+        }
+    }
+
+    /// Returns `true` if the given name is the name of a network primitive.
+    const fn is_primitive(name: &'static str) -> bool {
+        let mut i = 0;
+        while i < Self::PRIMITIVES.len() {
+            let primitive = Self::PRIMITIVES[i].as_bytes();
+            let bytes = name.as_bytes();
+            if primitive.len() == bytes.len() {
+                let mut j = 0;
+                while j < bytes.len() && primitive[j] == bytes[j] {
+                    j += 1;
+                }
+                if j == bytes.len() {
+                    return true;
+                }
+            }
+            i += 1;
+        }
+        false
+    }
+}
+
+impl fmt::Display for DomainSeparator {
+    /// Writes the encoding of the domain separator.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.size {
+            Some(size) => write!(f, "{}{}{}", Self::PREFIX, self.name, size)?,
+            None => write!(f, "{}{}{}", Self::PREFIX, self.name, self.version)?,
+        }
+        match self.index {
+            Some(index) => write!(f, ".{index}"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encoding() {
+        assert_eq!(DomainSeparator::new("SymmetricEncryption", 0).to_string(), "AleoSymmetricEncryption0");
+        assert_eq!(DomainSeparator::primitive("BHP", 256).to_string(), "AleoBHP256");
+        assert_eq!(DomainSeparator::primitive("Poseidon", 4).to_string(), "AleoPoseidon4");
+        assert_eq!(
+            DomainSeparator::new("AccountSignatureRandomizer", 0).with_index(0).to_string(),
+            "AleoAccountSignatureRandomizer0.0"
+        );
+    }
+
+    #[test]
+    fn test_encoding_is_injective() {
+        // Ensure the boundary between the name and the version is unambiguous.
+        let domains = [
+            DomainSeparator::new("Key", 10),
+            DomainSeparator::new("Key", 1),
+            DomainSeparator::new("Key", 1).with_index(0),
+            DomainSeparator::new("KeyA", 1),
+            DomainSeparator::new("KeyA", 1).with_index(10),
+            DomainSeparator::new("KeyA", 11).with_index(0),
+            DomainSeparator::new("BHPTest", 0),
+            DomainSeparator::primitive("BHP", 256),
+            DomainSeparator::primitive("BHP", 512),
+            DomainSeparator::primitive("Pedersen", 64),
+        ];
+        for (i, first) in domains.iter().enumerate() {
+            for second in &domains[i + 1..] {
+                assert_ne!(first.to_string(), second.to_string());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_name_ends_with_digit() {
+        let _ = DomainSeparator::new("Key1", 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_name_is_not_alphanumeric() {
+        let _ = DomainSeparator::new("Key.Wrap", 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_name_is_empty() {
+        let _ = DomainSeparator::new("", 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_name_is_primitive() {
+        let _ = DomainSeparator::new("BHP", 256);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_primitive_is_unknown() {
+        let _ = DomainSeparator::primitive("Key", 256);
+    }
+}
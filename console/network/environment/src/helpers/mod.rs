@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod domain_separator;
+pub use domain_separator::DomainSeparator;
+
 mod or_halt;
 pub use or_halt::OrHalt;
 
@@ -38,12 +38,25 @@ pub mod prelude {
         cfg_reduce,
         cfg_values,
         error,
+        execute_in,
+        from_canonical_hex,
+        bounded_capacity,
         has_duplicates,
         io::{Read, Result as IoResult, Write},
+        to_canonical_hex,
+        try_execute_in,
+        BigInteger as _,
+        CanonicalJson,
+        ConstSerializedSize,
+        DEFAULT_CHUNK_SIZE,
+        DeserializationLimits,
         DeserializeExt,
         FromBits as _,
         FromBytes,
         FromBytesDeserializer,
+        ParallelismConfig,
+        SeededRng,
+        Subsystem,
         TestRng,
         ToBits as _,
         ToBytes,
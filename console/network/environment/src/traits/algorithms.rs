@@ -43,6 +43,26 @@ pub trait Hash {
     fn hash(&self, input: &[Self::Input]) -> Result<Self::Output>;
 }
 
+/// A trait for a hash function that hashes its input incrementally, as a stream of bytes.
+pub trait HashIncremental {
+    type Output;
+    type Hasher: Hasher<Output = Self::Output>;
+
+    /// Returns a hasher for a stream of bytes, whose length need not be known in advance.
+    fn hasher(&self) -> Self::Hasher;
+}
+
+/// A trait for the state of an incremental hash function.
+pub trait Hasher {
+    type Output;
+
+    /// Absorbs the given bytes into the hasher.
+    fn update(&mut self, data: &[u8]) -> Result<()>;
+
+    /// Returns the hash of the absorbed bytes.
+    fn finalize(self) -> Result<Self::Output>;
+}
+
 /// A trait for a hash function that produces multiple outputs.
 pub trait HashMany {
     type Input;
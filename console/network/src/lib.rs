@@ -28,6 +28,8 @@ pub use helpers::*;
 mod testnet3;
 pub use testnet3::*;
 
+pub use snarkvm_console_algorithms::PoseidonStream;
+
 pub mod prelude {
     pub use crate::{environment::prelude::*, Network};
 }
@@ -43,6 +45,7 @@ use snarkvm_console_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
 use snarkvm_console_collections::merkle_tree::{MerklePath, MerkleTree};
 use snarkvm_console_types::{Field, Group, Scalar};
 use snarkvm_curves::PairingEngine;
+use snarkvm_parameters::ParameterCache;
 
 use indexmap::IndexMap;
 use once_cell::sync::OnceCell;
@@ -171,25 +174,30 @@ pub trait Network:
     fn genesis_bytes() -> &'static [u8];
 
     /// Returns the proving key for the given function name in `credits.aleo`.
-    fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>>;
+    ///
+    /// The circuit keys are reloaded when the parameter store changes, so each call returns the current key.
+    fn get_credits_proving_key(function_name: String) -> Result<Arc<VarunaProvingKey<Self>>>;
 
     /// Returns the verifying key for the given function name in `credits.aleo`.
-    fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>>;
+    fn get_credits_verifying_key(function_name: String) -> Result<Arc<VarunaVerifyingKey<Self>>>;
 
     /// Returns the `proving key` for the inclusion circuit.
-    fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>>;
+    fn inclusion_proving_key() -> Result<Arc<VarunaProvingKey<Self>>>;
 
     /// Returns the `verifying key` for the inclusion circuit.
-    fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>>;
+    fn inclusion_verifying_key() -> Arc<VarunaVerifyingKey<Self>>;
 
     /// Returns the powers of `G`.
     fn g_powers() -> &'static Vec<Group<Self>>;
 
+    /// Returns the fixed-base table of `G`, in windows of 4 bits, where `table[i][j] := j * 2^(4i) * G`.
+    fn g_windows() -> &'static Vec<[Group<Self>; 16]>;
+
     /// Returns the scalar multiplication on the generator `G`.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self>;
 
     /// Returns the Varuna universal prover.
-    fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve>;
+    fn varuna_universal_prover() -> Result<&'static UniversalProver<Self::PairingCurve>>;
 
     /// Returns the Varuna universal verifier.
     fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve>;
@@ -242,6 +250,24 @@ pub trait Network:
     /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
     fn commit_to_group_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>>;
 
+    /// Returns the given BHP commitment with an input hasher of 256-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp256(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns the given BHP commitment with an input hasher of 512-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp512(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns the given BHP commitment with an input hasher of 768-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp768(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns the given BHP commitment with an input hasher of 1024-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp1024(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns the given Pedersen commitment for an (up to) 64-bit input, rerandomized by the given randomizer.
+    fn rerandomize_ped64(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self>;
+
+    /// Returns the given Pedersen commitment for an (up to) 128-bit input, rerandomized by the given randomizer.
+    fn rerandomize_ped128(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self>;
+
     /// Returns the BHP hash with an input hasher of 256-bits.
     fn hash_bhp256(input: &[bool]) -> Result<Field<Self>>;
 
@@ -296,6 +322,9 @@ pub trait Network:
     /// Returns the extended Poseidon hash with an input rate of 8.
     fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>>;
 
+    /// Returns a stream of the extended Poseidon hash with an input rate of 8.
+    fn hash_many_psd8_stream(input: &[Field<Self>]) -> PoseidonStream<Self, 8>;
+
     /// Returns the BHP hash with an input hasher of 256-bits.
     fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>>;
 
@@ -332,6 +361,15 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
     fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>>;
 
+    /// Returns the Poseidon PRF with an input rate of 2.
+    fn prf_psd2(seed: &Field<Self>, input: &[Field<Self>]) -> Result<Field<Self>>;
+
+    /// Returns the Poseidon PRF with an input rate of 4.
+    fn prf_psd4(seed: &Field<Self>, input: &[Field<Self>]) -> Result<Field<Self>>;
+
+    /// Returns the Poseidon PRF with an input rate of 8.
+    fn prf_psd8(seed: &Field<Self>, input: &[Field<Self>]) -> Result<Field<Self>>;
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>>;
 
@@ -34,50 +34,82 @@ use snarkvm_console_algorithms::{
 
 lazy_static! {
     /// The group bases for the Aleo signature and encryption schemes.
-    pub static ref GENERATOR_G: Vec<Group<Testnet3>> = Testnet3::new_bases("AleoAccountEncryptionAndSignatureScheme0");
+    pub static ref GENERATOR_G: Vec<Group<Testnet3>> = Testnet3::new_bases(&DomainSeparator::new("AccountEncryptionAndSignatureScheme", 0).to_string());
+    /// The fixed-base table of `G`, in windows of 4 bits, where `table[i][j] := j * 2^(4i) * G`.
+    pub static ref GENERATOR_G_WINDOWS: Vec<[Group<Testnet3>; 16]> = GENERATOR_G
+        .iter()
+        .step_by(4)
+        .map(|window_base| {
+            let mut window = [Group::zero(); 16];
+            for j in 1..16 {
+                window[j] = window[j - 1] + *window_base;
+            }
+            window
+        })
+        .collect();
 
     /// The Varuna sponge parameters.
     pub static ref VARUNA_FS_PARAMETERS: FiatShamirParameters<Testnet3> = FiatShamir::<Testnet3>::sample_parameters();
 
     /// The encryption domain as a constant field element.
-    pub static ref ENCRYPTION_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSymmetricEncryption0");
+    pub static ref ENCRYPTION_DOMAIN: Field<Testnet3> = Field::<Testnet3>::from_domain_separator(DomainSeparator::new("SymmetricEncryption", 0));
     /// The graph key domain as a constant field element.
-    pub static ref GRAPH_KEY_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoGraphKey0");
+    pub static ref GRAPH_KEY_DOMAIN: Field<Testnet3> = Field::<Testnet3>::from_domain_separator(DomainSeparator::new("GraphKey", 0));
     /// The serial number domain as a constant field element.
-    pub static ref SERIAL_NUMBER_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSerialNumber0");
+    pub static ref SERIAL_NUMBER_DOMAIN: Field<Testnet3> = Field::<Testnet3>::from_domain_separator(DomainSeparator::new("SerialNumber", 0));
 
     /// The BHP hash function, which can take an input of up to 256 bits.
-    pub static ref BHP_256: BHP256<Testnet3> = BHP256::<Testnet3>::setup("AleoBHP256").expect("Failed to setup BHP256");
+    pub static ref BHP_256: BHP256<Testnet3> = BHP256::<Testnet3>::setup(DomainSeparator::primitive("BHP", 256)).expect("Failed to setup BHP256");
     /// The BHP hash function, which can take an input of up to 512 bits.
-    pub static ref BHP_512: BHP512<Testnet3> = BHP512::<Testnet3>::setup("AleoBHP512").expect("Failed to setup BHP512");
+    pub static ref BHP_512: BHP512<Testnet3> = BHP512::<Testnet3>::setup(DomainSeparator::primitive("BHP", 512)).expect("Failed to setup BHP512");
     /// The BHP hash function, which can take an input of up to 768 bits.
-    pub static ref BHP_768: BHP768<Testnet3> = BHP768::<Testnet3>::setup("AleoBHP768").expect("Failed to setup BHP768");
+    pub static ref BHP_768: BHP768<Testnet3> = BHP768::<Testnet3>::setup(DomainSeparator::primitive("BHP", 768)).expect("Failed to setup BHP768");
     /// The BHP hash function, which can take an input of up to 1024 bits.
-    pub static ref BHP_1024: BHP1024<Testnet3> = BHP1024::<Testnet3>::setup("AleoBHP1024").expect("Failed to setup BHP1024");
+    pub static ref BHP_1024: BHP1024<Testnet3> = BHP1024::<Testnet3>::setup(DomainSeparator::primitive("BHP", 1024)).expect("Failed to setup BHP1024");
 
     /// The Pedersen hash function, which can take an input of up to 64 bits.
-    pub static ref PEDERSEN_64: Pedersen64<Testnet3> = Pedersen64::<Testnet3>::setup("AleoPedersen64");
+    pub static ref PEDERSEN_64: Pedersen64<Testnet3> = Pedersen64::<Testnet3>::setup(DomainSeparator::primitive("Pedersen", 64));
     /// The Pedersen hash function, which can take an input of up to 128 bits.
-    pub static ref PEDERSEN_128: Pedersen128<Testnet3> = Pedersen128::<Testnet3>::setup("AleoPedersen128");
+    pub static ref PEDERSEN_128: Pedersen128<Testnet3> = Pedersen128::<Testnet3>::setup(DomainSeparator::primitive("Pedersen", 128));
 
     /// The Poseidon hash function, using a rate of 2.
-    pub static ref POSEIDON_2: Poseidon2<Testnet3> = Poseidon2::<Testnet3>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2");
+    pub static ref POSEIDON_2: Poseidon2<Testnet3> = Poseidon2::<Testnet3>::setup(DomainSeparator::primitive("Poseidon", 2)).expect("Failed to setup Poseidon2");
     /// The Poseidon hash function, using a rate of 4.
-    pub static ref POSEIDON_4: Poseidon4<Testnet3> = Poseidon4::<Testnet3>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
+    pub static ref POSEIDON_4: Poseidon4<Testnet3> = Poseidon4::<Testnet3>::setup(DomainSeparator::primitive("Poseidon", 4)).expect("Failed to setup Poseidon4");
     /// The Poseidon hash function, using a rate of 8.
-    pub static ref POSEIDON_8: Poseidon8<Testnet3> = Poseidon8::<Testnet3>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
+    pub static ref POSEIDON_8: Poseidon8<Testnet3> = Poseidon8::<Testnet3>::setup(DomainSeparator::primitive("Poseidon", 8)).expect("Failed to setup Poseidon8");
+}
 
-    pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
+/// The `credits.aleo` proving keys, which are reloaded when the parameter store changes.
+#[cfg(not(feature = "low-memory"))]
+pub static CREDITS_PROVING_KEYS: ParameterCache<IndexMap<String, Arc<VarunaProvingKey<Console>>>> =
+    ParameterCache::new(|| {
         let mut map = IndexMap::new();
         snarkvm_parameters::insert_credit_keys!(map, VarunaProvingKey<Console>, Prover);
         map
-    };
-    pub static ref CREDITS_VERIFYING_KEYS: IndexMap<String, Arc<VarunaVerifyingKey<Console>>> = {
+    });
+/// The `credits.aleo` verifying keys, which are reloaded when the parameter store changes.
+pub static CREDITS_VERIFYING_KEYS: ParameterCache<IndexMap<String, Arc<VarunaVerifyingKey<Console>>>> =
+    ParameterCache::new(|| {
         let mut map = IndexMap::new();
         snarkvm_parameters::insert_credit_keys!(map, VarunaVerifyingKey<Console>, Verifier);
         map
-    };
-}
+    });
+/// The inclusion proving key, which is reloaded when the parameter store changes.
+#[cfg(not(feature = "low-memory"))]
+static INCLUSION_PROVING_KEY: ParameterCache<VarunaProvingKey<Console>> = ParameterCache::new(|| {
+    let bytes =
+        snarkvm_parameters::testnet3::InclusionProver::load_bytes().expect("Failed to load inclusion proving key");
+    // Skipping the first byte, which is the encoded version.
+    CircuitProvingKey::from_bytes_le(&bytes[1..]).expect("Failed to load inclusion proving key.")
+});
+/// The inclusion verifying key, which is reloaded when the parameter store changes.
+static INCLUSION_VERIFYING_KEY: ParameterCache<VarunaVerifyingKey<Console>> = ParameterCache::new(|| {
+    let bytes =
+        snarkvm_parameters::testnet3::InclusionVerifier::load_bytes().expect("Failed to load inclusion verifying key");
+    // Skipping the first byte, which is the encoded version.
+    CircuitVerifyingKey::from_bytes_le(&bytes[1..]).expect("Failed to load inclusion verifying key.")
+});
 
 pub const TRANSACTION_PREFIX: &str = "at";
 
@@ -146,41 +178,45 @@ impl Network for Testnet3 {
     }
 
     /// Returns the proving key for the given function name in `credits.aleo`.
-    fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>> {
+    #[cfg(not(feature = "low-memory"))]
+    fn get_credits_proving_key(function_name: String) -> Result<Arc<VarunaProvingKey<Self>>> {
         CREDITS_PROVING_KEYS
+            .get()
             .get(&function_name)
+            .cloned()
             .ok_or_else(|| anyhow!("Proving key for credits.aleo/{function_name}' not found"))
     }
 
+    /// Returns an error, as low-memory builds do not include the proving keys.
+    #[cfg(feature = "low-memory")]
+    fn get_credits_proving_key(function_name: String) -> Result<Arc<VarunaProvingKey<Self>>> {
+        bail!("Proving key for credits.aleo/{function_name}' is not available in low-memory builds")
+    }
+
     /// Returns the verifying key for the given function name in `credits.aleo`.
-    fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>> {
+    fn get_credits_verifying_key(function_name: String) -> Result<Arc<VarunaVerifyingKey<Self>>> {
         CREDITS_VERIFYING_KEYS
+            .get()
             .get(&function_name)
+            .cloned()
             .ok_or_else(|| anyhow!("Verifying key for credits.aleo/{function_name}' not found"))
     }
 
     /// Returns the `proving key` for the inclusion circuit.
-    fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>> {
-        static INSTANCE: OnceCell<Arc<VarunaProvingKey<Console>>> = OnceCell::new();
-        INSTANCE.get_or_init(|| {
-            // Skipping the first byte, which is the encoded version.
-            Arc::new(
-                CircuitProvingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_PROVING_KEY[1..])
-                    .expect("Failed to load inclusion proving key."),
-            )
-        })
+    #[cfg(not(feature = "low-memory"))]
+    fn inclusion_proving_key() -> Result<Arc<VarunaProvingKey<Self>>> {
+        Ok(INCLUSION_PROVING_KEY.get())
+    }
+
+    /// Returns an error, as low-memory builds do not include the proving keys.
+    #[cfg(feature = "low-memory")]
+    fn inclusion_proving_key() -> Result<Arc<VarunaProvingKey<Self>>> {
+        bail!("The inclusion proving key is not available in low-memory builds")
     }
 
     /// Returns the `verifying key` for the inclusion circuit.
-    fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>> {
-        static INSTANCE: OnceCell<Arc<VarunaVerifyingKey<Console>>> = OnceCell::new();
-        INSTANCE.get_or_init(|| {
-            // Skipping the first byte, which is the encoded version.
-            Arc::new(
-                CircuitVerifyingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_VERIFYING_KEY[1..])
-                    .expect("Failed to load inclusion verifying key."),
-            )
-        })
+    fn inclusion_verifying_key() -> Arc<VarunaVerifyingKey<Self>> {
+        INCLUSION_VERIFYING_KEY.get()
     }
 
     /// Returns the powers of `G`.
@@ -188,6 +224,11 @@ impl Network for Testnet3 {
         &GENERATOR_G
     }
 
+    /// Returns the fixed-base table of `G`, in windows of 4 bits, where `table[i][j] := j * 2^(4i) * G`.
+    fn g_windows() -> &'static Vec<[Group<Self>; 16]> {
+        &GENERATOR_G_WINDOWS
+    }
+
     /// Returns the scalar multiplication on the generator `G`.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
         GENERATOR_G
@@ -201,24 +242,29 @@ impl Network for Testnet3 {
     }
 
     /// Returns the Varuna universal prover.
-    fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
+    #[cfg(not(feature = "low-memory"))]
+    fn varuna_universal_prover() -> Result<&'static UniversalProver<Self::PairingCurve>> {
         static INSTANCE: OnceCell<UniversalProver<<Console as Environment>::PairingCurve>> = OnceCell::new();
-        INSTANCE.get_or_init(|| {
+        Ok(INSTANCE.get_or_init(|| {
             snarkvm_algorithms::polycommit::kzg10::UniversalParams::load()
                 .expect("Failed to load universal SRS (KZG10).")
                 .to_universal_prover()
                 .expect("Failed to convert universal SRS (KZG10) to the prover.")
-        })
+        }))
+    }
+
+    /// Returns an error, as low-memory builds do not load the universal SRS for the prover.
+    #[cfg(feature = "low-memory")]
+    fn varuna_universal_prover() -> Result<&'static UniversalProver<Self::PairingCurve>> {
+        bail!("The Varuna universal prover is not available in low-memory builds")
     }
 
     /// Returns the Varuna universal verifier.
     fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
         static INSTANCE: OnceCell<UniversalVerifier<<Console as Environment>::PairingCurve>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
-            snarkvm_algorithms::polycommit::kzg10::UniversalParams::load()
-                .expect("Failed to load universal SRS (KZG10).")
-                .to_universal_verifier()
-                .expect("Failed to convert universal SRS (KZG10) to the verifier.")
+            snarkvm_algorithms::polycommit::kzg10::UniversalParams::load_universal_verifier()
+                .expect("Failed to load universal SRS (KZG10) for the verifier.")
         })
     }
 
@@ -302,6 +348,36 @@ impl Network for Testnet3 {
         PEDERSEN_128.commit_uncompressed(input, randomizer)
     }
 
+    /// Returns the given BHP commitment with an input hasher of 256-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp256(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self> {
+        BHP_256.rerandomize(commitment, delta_randomness)
+    }
+
+    /// Returns the given BHP commitment with an input hasher of 512-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp512(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self> {
+        BHP_512.rerandomize(commitment, delta_randomness)
+    }
+
+    /// Returns the given BHP commitment with an input hasher of 768-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp768(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self> {
+        BHP_768.rerandomize(commitment, delta_randomness)
+    }
+
+    /// Returns the given BHP commitment with an input hasher of 1024-bits, rerandomized by the given randomizer.
+    fn rerandomize_bhp1024(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self> {
+        BHP_1024.rerandomize(commitment, delta_randomness)
+    }
+
+    /// Returns the given Pedersen commitment for an (up to) 64-bit input, rerandomized by the given randomizer.
+    fn rerandomize_ped64(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self> {
+        PEDERSEN_64.rerandomize(commitment, delta_randomness)
+    }
+
+    /// Returns the given Pedersen commitment for an (up to) 128-bit input, rerandomized by the given randomizer.
+    fn rerandomize_ped128(commitment: &Group<Self>, delta_randomness: &Scalar<Self>) -> Group<Self> {
+        PEDERSEN_128.rerandomize(commitment, delta_randomness)
+    }
+
     /// Returns the BHP hash with an input hasher of 256-bits.
     fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
         BHP_256.hash(input)
@@ -392,6 +468,11 @@ impl Network for Testnet3 {
         POSEIDON_8.hash_many(input, num_outputs)
     }
 
+    /// Returns a stream of the extended Poseidon hash with an input rate of 8.
+    fn hash_many_psd8_stream(input: &[Field<Self>]) -> PoseidonStream<Self, 8> {
+        POSEIDON_8.hash_many_stream(input)
+    }
+
     /// Returns the BHP hash with an input hasher of 256-bits.
     fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>> {
         BHP_256.hash_uncompressed(input)
@@ -452,6 +533,21 @@ impl Network for Testnet3 {
         POSEIDON_8.hash_to_scalar(input)
     }
 
+    /// Returns the Poseidon PRF with an input rate of 2.
+    fn prf_psd2(seed: &Field<Self>, input: &[Field<Self>]) -> Result<Field<Self>> {
+        POSEIDON_2.prf(seed, input)
+    }
+
+    /// Returns the Poseidon PRF with an input rate of 4.
+    fn prf_psd4(seed: &Field<Self>, input: &[Field<Self>]) -> Result<Field<Self>> {
+        POSEIDON_4.prf(seed, input)
+    }
+
+    /// Returns the Poseidon PRF with an input rate of 8.
+    fn prf_psd8(seed: &Field<Self>, input: &[Field<Self>]) -> Result<Field<Self>> {
+        POSEIDON_8.prf(seed, input)
+    }
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
         MerkleTree::new(&*BHP_1024, &*BHP_512, leaves)
@@ -487,6 +583,19 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    #[test]
+    fn test_credits_verifying_keys_reload() -> Result<()> {
+        let verifying_key = CurrentNetwork::get_credits_verifying_key("join".to_string())?;
+        assert!(Arc::ptr_eq(&verifying_key, &CurrentNetwork::get_credits_verifying_key("join".to_string())?));
+
+        // Check that the keys are reloaded when the parameter store changes.
+        snarkvm_parameters::ParameterStore::global().reload();
+        let reloaded = CurrentNetwork::get_credits_verifying_key("join".to_string())?;
+        assert!(!Arc::ptr_eq(&verifying_key, &reloaded));
+        assert_eq!(verifying_key, reloaded);
+        Ok(())
+    }
+
     #[test]
     fn test_g_scalar_multiply() {
         // Compute G^r.
@@ -494,4 +603,44 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_prf_psd_vectors() -> Result<()> {
+        // The expected outputs were computed with an independent transcription of the Poseidon sponge,
+        // which reproduces the sponge snapshots of the console algorithms, for the seed `1`.
+        const VECTORS: [(usize, u64, &str); 9] = [
+            (2, 0, "107338370619223092068132723896052301422983572279086036748479670139944641824field"),
+            (2, 2, "7200107266741300576287128422632566638252611441475937708605571633688427921904field"),
+            (2, 9, "6097704810018335053522063435537532026721173864405929333283623445754251926173field"),
+            (4, 0, "4118718987748259783013585284985897557152498697752820235066334744459981242055field"),
+            (4, 2, "8393672207741630055028942157821890981007845026669435324694477579426687904205field"),
+            (4, 9, "2106999547717860622662407507889883997770862845626559669869564741371816422769field"),
+            (8, 0, "5979392658650639904568495682277601692484903308279058440836580197467543126506field"),
+            (8, 2, "6997114219207164604936152705742629637102890770395303842448243806833877237446field"),
+            (8, 9, "5130062477044463882375323808941579230368813101119888044858859831705367012888field"),
+        ];
+
+        for (rate, num_inputs, expected) in VECTORS {
+            let seed = Field::<CurrentNetwork>::one();
+            let input = (2..2 + num_inputs).map(Field::from_u64).collect::<Vec<_>>();
+            let candidate = match rate {
+                2 => CurrentNetwork::prf_psd2(&seed, &input)?,
+                4 => CurrentNetwork::prf_psd4(&seed, &input)?,
+                _ => CurrentNetwork::prf_psd8(&seed, &input)?,
+            };
+            assert_eq!(candidate, Field::from_str(expected)?, "PRF with rate {rate} over {num_inputs} inputs");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "low-memory")]
+    #[test]
+    fn test_low_memory() {
+        // Check that the proving keys are unavailable, and the verifying keys are embedded.
+        assert!(CurrentNetwork::get_credits_proving_key("transfer_public".to_string()).is_err());
+        assert!(CurrentNetwork::get_credits_verifying_key("transfer_public".to_string()).is_ok());
+        assert!(CurrentNetwork::inclusion_proving_key().is_err());
+        assert!(CurrentNetwork::varuna_universal_prover().is_err());
+        assert!(!snarkvm_parameters::ParameterProfile::current().allows_proving());
+    }
 }
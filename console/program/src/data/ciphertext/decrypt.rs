@@ -16,7 +16,7 @@ use super::*;
 
 impl<N: Network> Ciphertext<N> {
     /// Decrypts `self` into plaintext using the given account view key & nonce.
-    pub fn decrypt(&self, view_key: ViewKey<N>, nonce: Group<N>) -> Result<Plaintext<N>> {
+    pub fn decrypt(&self, view_key: &ViewKey<N>, nonce: Group<N>) -> Result<Plaintext<N>> {
         // Compute the plaintext view key.
         let plaintext_view_key = (nonce * *view_key).to_x_coordinate();
         // Decrypt the record.
@@ -88,8 +88,8 @@ mod tests {
 
         // Sample a random address.
         let private_key = PrivateKey::<N>::new(rng)?;
-        let view_key = ViewKey::<N>::try_from(private_key)?;
-        let address = Address::<N>::try_from(view_key)?;
+        let view_key = ViewKey::<N>::try_from(&private_key)?;
+        let address = Address::<N>::try_from(&view_key)?;
 
         // Encrypt the plaintext.
         let randomizer = Uniform::rand(rng);
@@ -97,7 +97,7 @@ mod tests {
 
         // Decrypt the plaintext.
         let nonce = N::g_scalar_multiply(&randomizer);
-        assert_eq!(plaintext, ciphertext.decrypt(view_key, nonce)?);
+        assert_eq!(plaintext, ciphertext.decrypt(&view_key, nonce)?);
         Ok(())
     }
 
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Envelope<N> {
+    /// Reads the envelope from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid envelope version"));
+        }
+        // Read the suite and mode.
+        let suite = EnvelopeSuite::try_from(u16::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?;
+        let mode = EnvelopeMode::try_from(u8::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?;
+        // Read the encapsulation.
+        let encapsulation = Group::read_le(&mut reader)?;
+        // Read the payload.
+        let payload_length = u32::read_le(&mut reader)?;
+        // Ensure the payload does not exceed the maximum data size.
+        if payload_length > N::MAX_DATA_SIZE_IN_FIELDS.saturating_mul(32).saturating_add(32) {
+            return Err(error("Envelope payload is too large"));
+        }
+        let mut payload = vec![0u8; payload_length as usize];
+        reader.read_exact(&mut payload)?;
+        Ok(Self { suite, mode, encapsulation, payload })
+    }
+}
+
+impl<N: Network> ToBytes for Envelope<N> {
+    /// Writes the envelope to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the suite and mode.
+        (self.suite as u16).write_le(&mut writer)?;
+        (self.mode as u8).write_le(&mut writer)?;
+        // Write the encapsulation.
+        self.encapsulation.write_le(&mut writer)?;
+        // Write the payload.
+        u32::try_from(self.payload.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        writer.write_all(&self.payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::envelope::tests::{sample_account, sample_plaintext};
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for suite in [EnvelopeSuite::PoseidonField, EnvelopeSuite::ChaChaPoly] {
+            let (view_key, address) = sample_account(rng)?;
+            let (expected, _) = Envelope::seal(suite, &sample_plaintext(), &address, None, b"", rng)?;
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            let candidate = Envelope::read_le(&expected_bytes[..])?;
+            assert!(expected == candidate);
+            assert_eq!(candidate.open(&view_key, None, b"")?.0, sample_plaintext());
+            assert!(Envelope::<snarkvm_console_network::Testnet3>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod open;
+mod seal;
+mod serialize;
+mod string;
+
+use crate::{Ciphertext, Plaintext};
+use snarkvm_console_account::{ECIESPoseidonKem, KemScheme, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Group};
+
+const ENVELOPE_DOMAIN: DomainSeparator = DomainSeparator::new("Envelope", 0);
+const ENVELOPE_TAG_DOMAIN: DomainSeparator = DomainSeparator::new("EnvelopeTag", 0);
+
+/// The cipher suite used to encrypt the payload of an envelope.
+///
+/// Suite IDs are part of the stored format: new suites must use a new ID, and existing IDs must not change.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum EnvelopeSuite {
+    /// The plaintext is encrypted with Poseidon randomizers, and authenticated with a Poseidon tag.
+    /// This suite is field-native, and may be opened inside a circuit.
+    PoseidonField = 1,
+    /// The serialized plaintext is encrypted with ChaCha20-Poly1305.
+    ChaChaPoly = 2,
+}
+
+impl TryFrom<u16> for EnvelopeSuite {
+    type Error = Error;
+
+    /// Returns the suite with the given ID.
+    fn try_from(id: u16) -> Result<Self> {
+        match id {
+            1 => Ok(Self::PoseidonField),
+            2 => Ok(Self::ChaChaPoly),
+            _ => bail!("Unsupported envelope suite ID '{id}'"),
+        }
+    }
+}
+
+/// The mode of an envelope, which determines whether the sender is authenticated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum EnvelopeMode {
+    /// The sender is anonymous.
+    Base = 0,
+    /// The sender is authenticated by their account view key.
+    Auth = 2,
+}
+
+impl TryFrom<u8> for EnvelopeMode {
+    type Error = Error;
+
+    /// Returns the mode with the given ID.
+    fn try_from(id: u8) -> Result<Self> {
+        match id {
+            0 => Ok(Self::Base),
+            2 => Ok(Self::Auth),
+            _ => bail!("Unsupported envelope mode ID '{id}'"),
+        }
+    }
+}
+
+/// A self-describing ciphertext to an account address, in the style of HPKE (RFC 9180).
+///
+/// The envelope records its suite, its mode, and the encapsulated ephemeral key, from which the recipient
+/// derives the payload key and an exporter secret. The suite and mode are bound into the key schedule,
+/// so an envelope cannot be reinterpreted under a different suite.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Envelope<N: Network> {
+    /// The cipher suite of the payload.
+    suite: EnvelopeSuite,
+    /// The mode of the envelope.
+    mode: EnvelopeMode,
+    /// The ephemeral public key, from which the recipient derives the shared secret.
+    encapsulation: Group<N>,
+    /// The encrypted and authenticated payload.
+    payload: Vec<u8>,
+}
+
+impl<N: Network> Envelope<N> {
+    /// Returns the cipher suite of the payload.
+    pub const fn suite(&self) -> EnvelopeSuite {
+        self.suite
+    }
+
+    /// Returns the mode of the envelope.
+    pub const fn mode(&self) -> EnvelopeMode {
+        self.mode
+    }
+
+    /// Returns the ephemeral public key.
+    pub const fn encapsulation(&self) -> &Group<N> {
+        &self.encapsulation
+    }
+
+    /// Returns the encrypted payload.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+}
+
+impl<N: Network> Envelope<N> {
+    /// Returns the payload key and the exporter secret, derived from the shared secrets and the envelope context.
+    fn key_schedule(
+        suite: EnvelopeSuite,
+        encapsulation: &Group<N>,
+        recipient: &Address<N>,
+        sender: Option<&Address<N>>,
+        shared_secrets: &[Field<N>],
+        info: &[u8],
+    ) -> Result<(Field<N>, Field<N>)> {
+        let mode = match sender {
+            Some(_) => EnvelopeMode::Auth,
+            None => EnvelopeMode::Base,
+        };
+
+        // Bind the suite, mode, and public keys into the key schedule.
+        let mut preimage = vec![
+            Field::from_domain_separator(ENVELOPE_DOMAIN),
+            Field::from_u16(suite as u16),
+            Field::from_u8(mode as u8),
+            encapsulation.to_x_coordinate(),
+            recipient.to_x_coordinate(),
+        ];
+        if let Some(sender) = sender {
+            preimage.push(sender.to_x_coordinate());
+        }
+        preimage.extend_from_slice(shared_secrets);
+        // Bind the application info, prefixed by its length.
+        preimage.push(Field::from_u64(info.len() as u64));
+        for chunk in info.to_bits_le().chunks(Field::<N>::size_in_data_bits()) {
+            preimage.push(Field::from_bits_le(chunk)?);
+        }
+
+        // Derive the payload key and the exporter secret.
+        match N::hash_many_psd8(&preimage, 2).as_slice() {
+            [key, exporter_secret] => Ok((*key, *exporter_secret)),
+            _ => bail!("Failed to derive the envelope key schedule"),
+        }
+    }
+
+    /// Returns the authentication tag for a payload in the Poseidon-field suite.
+    fn poseidon_tag(key: Field<N>, ciphertext: &Ciphertext<N>) -> Result<Field<N>> {
+        let mut preimage = vec![Field::from_domain_separator(ENVELOPE_TAG_DOMAIN), key];
+        preimage.extend_from_slice(ciphertext);
+        N::hash_psd8(&preimage)
+    }
+
+    /// Returns the ChaCha20-Poly1305 cipher for the given payload key.
+    fn chacha_cipher(key: Field<N>) -> Result<chacha20poly1305::ChaCha20Poly1305> {
+        use chacha20poly1305::KeyInit;
+        chacha20poly1305::ChaCha20Poly1305::new_from_slice(&key.to_bytes_le()?)
+            .map_err(|_| anyhow!("Invalid envelope key length"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    const SUITES: [EnvelopeSuite; 2] = [EnvelopeSuite::PoseidonField, EnvelopeSuite::ChaChaPoly];
+
+    pub(super) fn sample_account(rng: &mut TestRng) -> Result<(ViewKey<CurrentNetwork>, Address<CurrentNetwork>)> {
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        Ok((ViewKey::try_from(&private_key)?, Address::try_from(&private_key)?))
+    }
+
+    pub(super) fn sample_plaintext() -> Plaintext<CurrentNetwork> {
+        Plaintext::from_str("{ amount: 100u64, memo: 42field, flag: true }").unwrap()
+    }
+
+    #[test]
+    fn test_seal_and_open() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            for suite in SUITES {
+                let (view_key, address) = sample_account(rng)?;
+                let plaintext = sample_plaintext();
+
+                let (envelope, sender_exporter) = Envelope::seal(suite, &plaintext, &address, None, b"info", rng)?;
+                assert_eq!(envelope.suite(), suite);
+                assert_eq!(envelope.mode(), EnvelopeMode::Base);
+
+                let (candidate, recipient_exporter) = envelope.open(&view_key, None, b"info")?;
+                assert_eq!(candidate, plaintext);
+                assert_eq!(sender_exporter, recipient_exporter);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_seal_and_open_auth() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for suite in SUITES {
+            let (sender_view_key, sender) = sample_account(rng)?;
+            let (view_key, address) = sample_account(rng)?;
+            let (_, other) = sample_account(rng)?;
+            let plaintext = sample_plaintext();
+
+            let (envelope, _) = Envelope::seal(suite, &plaintext, &address, Some(&sender_view_key), b"", rng)?;
+            assert_eq!(envelope.mode(), EnvelopeMode::Auth);
+
+            assert_eq!(envelope.open(&view_key, Some(&sender), b"")?.0, plaintext);
+            // Check that the envelope does not open for a different sender, or without a sender.
+            assert!(envelope.open(&view_key, Some(&other), b"").is_err());
+            assert!(envelope.open(&view_key, None, b"").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_rejects_mismatches() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for suite in SUITES {
+            let (view_key, address) = sample_account(rng)?;
+            let (other_view_key, _) = sample_account(rng)?;
+            let (envelope, _) = Envelope::seal(suite, &sample_plaintext(), &address, None, b"info", rng)?;
+
+            // Check that the envelope does not open for a different recipient or info.
+            assert!(envelope.open(&other_view_key, None, b"info").is_err());
+            assert!(envelope.open(&view_key, None, b"other").is_err());
+
+            // Check that a modified payload is rejected.
+            let mut tampered = envelope.clone();
+            let last = tampered.payload.len() - 1;
+            tampered.payload[last] ^= 1;
+            assert!(tampered.open(&view_key, None, b"info").is_err());
+
+            // Check that the envelope does not open under a different suite.
+            let mut tampered = envelope.clone();
+            tampered.suite = match suite {
+                EnvelopeSuite::PoseidonField => EnvelopeSuite::ChaChaPoly,
+                EnvelopeSuite::ChaChaPoly => EnvelopeSuite::PoseidonField,
+            };
+            assert!(tampered.open(&view_key, None, b"info").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ids() {
+        for suite in SUITES {
+            assert_eq!(EnvelopeSuite::try_from(suite as u16).unwrap(), suite);
+        }
+        for mode in [EnvelopeMode::Base, EnvelopeMode::Auth] {
+            assert_eq!(EnvelopeMode::try_from(mode as u8).unwrap(), mode);
+        }
+        assert!(EnvelopeSuite::try_from(0u16).is_err());
+        assert!(EnvelopeMode::try_from(1u8).is_err());
+    }
+}
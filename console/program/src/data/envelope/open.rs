@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Envelope<N> {
+    /// Decrypts the envelope with the recipient's view key, and returns the plaintext along with
+    /// the exporter secret. Envelopes in `Auth` mode require the sender's address.
+    pub fn open(
+        &self,
+        view_key: &ViewKey<N>,
+        sender: Option<&Address<N>>,
+        info: &[u8],
+    ) -> Result<(Plaintext<N>, Field<N>)> {
+        // Compute the shared secrets with the sender.
+        let mut shared_secrets = vec![ECIESPoseidonKem::<N>::decapsulate(view_key, &self.encapsulation)?];
+        match (self.mode, sender) {
+            (EnvelopeMode::Base, None) => (),
+            (EnvelopeMode::Auth, Some(sender)) => shared_secrets.push((**sender * **view_key).to_x_coordinate()),
+            (EnvelopeMode::Base, Some(_)) => bail!("The envelope does not authenticate its sender"),
+            (EnvelopeMode::Auth, None) => bail!("The envelope requires the sender's address to open"),
+        }
+        let recipient = view_key.to_address();
+        let (key, exporter_secret) =
+            Self::key_schedule(self.suite, &self.encapsulation, &recipient, sender, &shared_secrets, info)?;
+
+        // Decrypt the payload.
+        let plaintext = match self.suite {
+            EnvelopeSuite::PoseidonField => {
+                let mut reader = self.payload.as_slice();
+                let ciphertext = Ciphertext::<N>::read_le(&mut reader)?;
+                let tag = Field::<N>::read_le(&mut reader)?;
+                ensure!(reader.is_empty(), "The envelope payload contains trailing bytes");
+                ensure!(tag == Self::poseidon_tag(key, &ciphertext)?, "Failed to authenticate the envelope payload");
+                ciphertext.decrypt_symmetric(key)?
+            }
+            EnvelopeSuite::ChaChaPoly => {
+                use chacha20poly1305::{aead::Aead, Nonce};
+                let bytes = Self::chacha_cipher(key)?
+                    .decrypt(&Nonce::default(), self.payload.as_slice())
+                    .map_err(|_| anyhow!("Failed to authenticate the envelope payload"))?;
+                Plaintext::from_bytes_le(&bytes)?
+            }
+        };
+        Ok((plaintext, exporter_secret))
+    }
+}
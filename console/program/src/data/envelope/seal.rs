@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Envelope<N> {
+    /// Encrypts the plaintext to the given address under the given suite, and returns the envelope
+    /// along with the exporter secret. If the sender's view key is given, the envelope is sealed in
+    /// `Auth` mode, and only opens for the corresponding sender address.
+    pub fn seal<R: Rng + CryptoRng>(
+        suite: EnvelopeSuite,
+        plaintext: &Plaintext<N>,
+        recipient: &Address<N>,
+        sender: Option<&ViewKey<N>>,
+        info: &[u8],
+        rng: &mut R,
+    ) -> Result<(Self, Field<N>)> {
+        // Encapsulate a shared secret to the recipient.
+        let (encapsulation, shared_secret) = ECIESPoseidonKem::<N>::encapsulate(recipient, rng)?;
+
+        // Compute the shared secrets with the recipient.
+        let mut shared_secrets = vec![shared_secret];
+        let sender_address = match sender {
+            Some(sender) => {
+                shared_secrets.push((**recipient * **sender).to_x_coordinate());
+                Some(sender.to_address())
+            }
+            None => None,
+        };
+        let (key, exporter_secret) =
+            Self::key_schedule(suite, &encapsulation, recipient, sender_address.as_ref(), &shared_secrets, info)?;
+
+        // Encrypt the payload.
+        let payload = match suite {
+            EnvelopeSuite::PoseidonField => {
+                let ciphertext = plaintext.encrypt_symmetric(key)?;
+                let tag = Self::poseidon_tag(key, &ciphertext)?;
+                let mut payload = ciphertext.to_bytes_le()?;
+                payload.extend(tag.to_bytes_le()?);
+                payload
+            }
+            EnvelopeSuite::ChaChaPoly => {
+                use chacha20poly1305::{aead::Aead, Nonce};
+                // The key is used for a single message, so the nonce is fixed.
+                Self::chacha_cipher(key)?
+                    .encrypt(&Nonce::default(), plaintext.to_bytes_le()?.as_slice())
+                    .map_err(|_| anyhow!("Failed to encrypt the envelope payload"))?
+            }
+        };
+
+        let mode = match sender {
+            Some(_) => EnvelopeMode::Auth,
+            None => EnvelopeMode::Base,
+        };
+        Ok((Self { suite, mode, encapsulation, payload }, exporter_secret))
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static ENVELOPE_PREFIX: &str = "envelope";
+
+impl<N: Network> FromStr for Envelope<N> {
+    type Err = Error;
+
+    /// Reads in the envelope string.
+    fn from_str(envelope: &str) -> Result<Self, Self::Err> {
+        // Decode the envelope string from bech32m.
+        let (hrp, data, variant) = bech32::decode(envelope)?;
+        if hrp != ENVELOPE_PREFIX {
+            bail!("Failed to decode envelope: '{hrp}' is an invalid prefix")
+        } else if data.is_empty() {
+            bail!("Failed to decode envelope: data field is empty")
+        } else if variant != bech32::Variant::Bech32m {
+            bail!("Found an envelope that is not bech32m encoded: {envelope}");
+        }
+        // Decode the envelope data from u5 to u8, and into the envelope.
+        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+    }
+}
+
+impl<N: Network> Debug for Envelope<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Envelope<N> {
+    /// Writes the envelope as a bech32m string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Convert the envelope to bytes.
+        let bytes = self.to_bytes_le().map_err(|_| fmt::Error)?;
+        // Encode the bytes into bech32m.
+        let string =
+            bech32::encode(ENVELOPE_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m).map_err(|_| fmt::Error)?;
+        // Output the string.
+        Display::fmt(&string, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::envelope::tests::{sample_account, sample_plaintext};
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for suite in [EnvelopeSuite::PoseidonField, EnvelopeSuite::ChaChaPoly] {
+            let (_, address) = sample_account(rng)?;
+            let (expected, _) = Envelope::seal(suite, &sample_plaintext(), &address, None, b"info", rng)?;
+
+            // Check the string representation.
+            let candidate = format!("{expected}");
+            assert_eq!(expected, Envelope::from_str(&candidate)?);
+            assert_eq!(ENVELOPE_PREFIX, candidate.split('1').next().unwrap());
+        }
+        assert!(Envelope::<snarkvm_console_network::Testnet3>::from_str("ciphertext1").is_err());
+        Ok(())
+    }
+}
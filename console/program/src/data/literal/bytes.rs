@@ -143,7 +143,7 @@ mod tests {
             let private_key = snarkvm_console_account::PrivateKey::<CurrentNetwork>::new(rng)?;
 
             // Address
-            check_bytes(Literal::<CurrentNetwork>::Address(Address::try_from(private_key)?))?;
+            check_bytes(Literal::<CurrentNetwork>::Address(Address::try_from(&private_key)?))?;
             // Boolean
             check_bytes(Literal::<CurrentNetwork>::Boolean(Boolean::new(Uniform::rand(rng))))?;
             // Field
@@ -116,7 +116,7 @@ mod tests {
             let private_key = snarkvm_console_account::PrivateKey::<CurrentNetwork>::new(rng)?;
 
             // Address
-            check_serialization(Literal::<CurrentNetwork>::Address(Address::try_from(private_key)?))?;
+            check_serialization(Literal::<CurrentNetwork>::Address(Address::try_from(&private_key)?))?;
             // Boolean
             check_serialization(Literal::<CurrentNetwork>::Boolean(Boolean::new(Uniform::rand(rng))))?;
             // Field
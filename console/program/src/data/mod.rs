@@ -18,6 +18,9 @@ pub use access::Access;
 mod ciphertext;
 pub use ciphertext::Ciphertext;
 
+mod envelope;
+pub use envelope::{Envelope, EnvelopeMode, EnvelopeSuite};
+
 mod future;
 pub use future::{Argument, Future};
 
@@ -143,7 +143,7 @@ mod tests {
 
             // Address
             check_bytes(Plaintext::Literal(
-                Literal::<CurrentNetwork>::Address(Address::try_from(private_key)?),
+                Literal::<CurrentNetwork>::Address(Address::try_from(&private_key)?),
                 Default::default(),
             ))?;
             // Boolean
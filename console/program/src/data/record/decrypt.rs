@@ -104,7 +104,7 @@ mod tests {
     const ITERATIONS: u64 = 1000;
 
     fn check_encrypt_and_decrypt<N: Network>(
-        view_key: ViewKey<N>,
+        view_key: &ViewKey<N>,
         owner: Owner<N, Plaintext<N>>,
         rng: &mut TestRng,
     ) -> Result<()> {
@@ -124,7 +124,7 @@ mod tests {
         // Encrypt the record.
         let ciphertext = record.encrypt(randomizer)?;
         // Decrypt the record.
-        assert_eq!(record, ciphertext.decrypt(&view_key)?);
+        assert_eq!(record, ciphertext.decrypt(view_key)?);
 
         // Generate a new random private key.
         let incorrect_private_key = PrivateKey::<N>::new(rng)?;
@@ -148,11 +148,11 @@ mod tests {
 
             // Public owner.
             let owner = Owner::Public(address);
-            check_encrypt_and_decrypt::<CurrentNetwork>(view_key, owner, &mut rng)?;
+            check_encrypt_and_decrypt::<CurrentNetwork>(&view_key, owner, &mut rng)?;
 
             // Private owner.
             let owner = Owner::Private(Plaintext::from(Literal::Address(address)));
-            check_encrypt_and_decrypt::<CurrentNetwork>(view_key, owner, &mut rng)?;
+            check_encrypt_and_decrypt::<CurrentNetwork>(&view_key, owner, &mut rng)?;
         }
         Ok(())
     }
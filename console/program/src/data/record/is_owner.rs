@@ -70,7 +70,7 @@ mod tests {
     const ITERATIONS: u64 = 1_000;
 
     fn check_is_owner<N: Network>(
-        view_key: ViewKey<N>,
+        view_key: &ViewKey<N>,
         owner: Owner<N, Plaintext<N>>,
         rng: &mut TestRng,
     ) -> Result<()> {
@@ -92,7 +92,7 @@ mod tests {
         let ciphertext = record.encrypt(randomizer)?;
 
         // Ensure the record belongs to the owner.
-        assert!(ciphertext.is_owner(&view_key));
+        assert!(ciphertext.is_owner(view_key));
 
         // Sample a random view key and address.
         let private_key = PrivateKey::<N>::new(rng)?;
@@ -116,11 +116,11 @@ mod tests {
 
             // Public owner.
             let owner = Owner::Public(address);
-            check_is_owner::<CurrentNetwork>(view_key, owner, &mut rng)?;
+            check_is_owner::<CurrentNetwork>(&view_key, owner, &mut rng)?;
 
             // Private owner.
             let owner = Owner::Private(Plaintext::from(Literal::Address(address)));
-            check_is_owner::<CurrentNetwork>(view_key, owner, &mut rng)?;
+            check_is_owner::<CurrentNetwork>(&view_key, owner, &mut rng)?;
         }
         Ok(())
     }
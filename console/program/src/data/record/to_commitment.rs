@@ -22,6 +22,26 @@ impl<N: Network> Record<N, Plaintext<N>> {
         // Compute the BHP hash of the program record.
         N::hash_bhp1024(&input)
     }
+
+    /// Returns a hiding record commitment with the given randomizer.
+    ///
+    /// Unlike the record commitment, it can be rerandomized with `N::rerandomize_bhp1024`,
+    /// without revealing or changing the record.
+    ///
+    /// Note: The ledger stores the record commitment of `Record::to_commitment`, which is a BHP hash,
+    /// and cannot be rerandomized. The hiding record commitment is not a substitute for it,
+    /// and is only bound to a ledger record by a proof that both commit to the same record.
+    pub fn to_hiding_commitment(
+        &self,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+        randomizer: &Scalar<N>,
+    ) -> Result<Group<N>> {
+        // Construct the input as `(program_id || record_name || record)`.
+        let input = to_bits_le![program_id, record_name, self];
+        // Compute the BHP commitment of the program record.
+        N::commit_to_group_bhp1024(&input, randomizer)
+    }
 }
 
 impl<N: Network> Record<N, Ciphertext<N>> {
@@ -29,4 +49,48 @@ impl<N: Network> Record<N, Ciphertext<N>> {
     pub fn to_commitment(&self, _program_id: &ProgramID<N>, _record_name: &Identifier<N>) -> Result<Field<N>> {
         bail!("Illegal operation: Record::to_commitment() cannot be invoked on the `Ciphertext` variant.")
     }
+
+    /// Returns a hiding record commitment with the given randomizer.
+    pub fn to_hiding_commitment(
+        &self,
+        _program_id: &ProgramID<N>,
+        _record_name: &Identifier<N>,
+        _randomizer: &Scalar<N>,
+    ) -> Result<Group<N>> {
+        bail!("Illegal operation: Record::to_hiding_commitment() cannot be invoked on the `Ciphertext` variant.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_rerandomize_commitment() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, foo: 5u8.private, _nonce: 0group.public }",
+        )?;
+        let program_id = ProgramID::from_str("token.aleo")?;
+        let record_name = Identifier::from_str("token")?;
+
+        for _ in 0..ITERATIONS {
+            let randomizer = Scalar::rand(&mut rng);
+            let delta_randomness = Scalar::rand(&mut rng);
+
+            let commitment = record.to_hiding_commitment(&program_id, &record_name, &randomizer)?;
+            let rerandomized = CurrentNetwork::rerandomize_bhp1024(&commitment, &delta_randomness);
+            assert_ne!(commitment, rerandomized);
+            // Ensure the rerandomized commitment is to the same record, with the combined randomizer.
+            let expected = record.to_hiding_commitment(&program_id, &record_name, &(randomizer + delta_randomness))?;
+            assert_eq!(expected, rerandomized);
+        }
+        Ok(())
+    }
 }
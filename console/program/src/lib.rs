@@ -39,6 +39,9 @@ pub use locator::*;
 mod owner;
 pub use owner::*;
 
+#[cfg(any(test, feature = "prop-tests"))]
+pub mod prop_tests;
+
 mod request;
 pub use request::*;
 
@@ -47,3 +50,6 @@ pub use response::*;
 
 pub mod state_path;
 pub use state_path::*;
+
+#[cfg(any(test, feature = "test-vectors"))]
+pub mod test_vectors;
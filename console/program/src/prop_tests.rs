@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Ciphertext, Entry, Identifier, Literal, Owner, Plaintext, Record};
+use snarkvm_console_account::PrivateKey;
+use snarkvm_console_network::Network;
+use snarkvm_console_types::{Address, Field, Scalar, U64};
+use snarkvm_utilities::TestRng;
+
+use indexmap::IndexMap;
+use proptest::{
+    collection::vec,
+    prelude::{any, Arbitrary, BoxedStrategy, Strategy},
+};
+use std::str::FromStr;
+
+/// The maximum number of fields in an arbitrary ciphertext.
+const MAX_CIPHERTEXT_FIELDS: usize = 16;
+/// The maximum number of data entries in an arbitrary record.
+const MAX_RECORD_ENTRIES: usize = 8;
+
+pub fn any_valid_private_key<N: Network>() -> BoxedStrategy<PrivateKey<N>> {
+    any::<u64>().prop_map(|seed| PrivateKey::new(&mut TestRng::from_seed(seed)).unwrap()).boxed()
+}
+
+pub fn any_valid_address<N: Network>() -> BoxedStrategy<Address<N>> {
+    any_valid_private_key().prop_map(|private_key| Address::try_from(private_key).unwrap()).boxed()
+}
+
+/// Returns a strategy for record entries, with a random visibility and a field or `u64` value.
+fn any_entry<N: Network>() -> BoxedStrategy<Entry<N, Plaintext<N>>> {
+    (0..3u8, any::<Field<N>>(), any::<u64>(), any::<bool>())
+        .prop_map(|(visibility, field, integer, is_field)| {
+            let plaintext = match is_field {
+                true => Plaintext::from(Literal::Field(field)),
+                false => Plaintext::from(Literal::U64(U64::new(integer))),
+            };
+            match visibility {
+                0 => Entry::Constant(plaintext),
+                1 => Entry::Public(plaintext),
+                _ => Entry::Private(plaintext),
+            }
+        })
+        .boxed()
+}
+
+/// Returns a strategy for plaintext records, along with the randomizer of the record nonce.
+pub fn any_record_with_randomizer<N: Network>() -> BoxedStrategy<(Record<N, Plaintext<N>>, Scalar<N>)> {
+    (any_valid_address::<N>(), any::<bool>(), any::<Scalar<N>>(), vec(any_entry::<N>(), 0..=MAX_RECORD_ENTRIES))
+        .prop_map(|(address, is_private, randomizer, entries)| {
+            let owner = match is_private {
+                true => Owner::Private(Plaintext::from(Literal::Address(address))),
+                false => Owner::Public(address),
+            };
+            let data = entries
+                .into_iter()
+                .enumerate()
+                .map(|(i, entry)| (Identifier::from_str(&format!("entry_{i}")).unwrap(), entry))
+                .collect::<IndexMap<_, _>>();
+            // The nonce must correspond to the randomizer, so that the record can be encrypted.
+            let nonce = N::g_scalar_multiply(&randomizer);
+            (Record::<N, Plaintext<N>>::from_plaintext(owner, data, nonce).unwrap(), randomizer)
+        })
+        .boxed()
+}
+
+impl<N: Network> Arbitrary for Ciphertext<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Ciphertext<N>>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        vec(any::<Field<N>>(), 1..=MAX_CIPHERTEXT_FIELDS)
+            .prop_map(|fields| Ciphertext::try_from(fields).unwrap())
+            .boxed()
+    }
+}
+
+impl<N: Network> Arbitrary for Record<N, Plaintext<N>> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Record<N, Plaintext<N>>>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any_record_with_randomizer().prop_map(|(record, _)| record).boxed()
+    }
+}
+
+impl<N: Network> Arbitrary for Record<N, Ciphertext<N>> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Record<N, Ciphertext<N>>>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any_record_with_randomizer().prop_map(|(record, randomizer)| record.encrypt(randomizer).unwrap()).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{FromBytes, ToBytes};
+
+    use proptest::prelude::*;
+
+    type CurrentNetwork = Testnet3;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn test_arbitrary_ciphertext(ciphertext in any::<Ciphertext<CurrentNetwork>>()) {
+            let bytes = ciphertext.to_bytes_le().unwrap();
+            prop_assert_eq!(ciphertext, Ciphertext::read_le(&bytes[..]).unwrap());
+        }
+
+        #[test]
+        fn test_arbitrary_record((record, randomizer) in any_record_with_randomizer::<CurrentNetwork>()) {
+            // Check that the record encrypts, and that its ciphertext round-trips.
+            let ciphertext = record.encrypt(randomizer).unwrap();
+            let bytes = ciphertext.to_bytes_le().unwrap();
+            prop_assert_eq!(ciphertext, Record::read_le(&bytes[..]).unwrap());
+        }
+    }
+}
@@ -48,7 +48,7 @@ impl<N: Network> Request<N> {
         // Derive the view key.
         let view_key = ViewKey::try_from((private_key, &compute_key))?;
         // Derive `sk_tag` from the graph key.
-        let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
+        let sk_tag = GraphKey::try_from(&view_key)?.sk_tag();
 
         // Sample a random nonce.
         let nonce = Field::<N>::rand(rng);
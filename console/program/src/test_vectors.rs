@@ -0,0 +1,262 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic test vectors for cross-implementation compatibility.
+//!
+//! Given a seed, `TestVectors::generate` derives accounts, signatures, and encrypted records,
+//! which serialize to a JSON fixture. `TestVectors::verify` re-checks every vector with the
+//! verification algorithms, and ensures the fixture is reproducible from its seed.
+
+use crate::{Ciphertext, Entry, Identifier, Literal, Owner, Plaintext, Record};
+use snarkvm_console_account::{Address, PrivateKey, Signature, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, U64};
+
+use indexmap::IndexMap;
+use serde_json::{json, Value};
+
+/// An account, as derived from its private key.
+#[derive(Clone, Debug)]
+pub struct AccountVector<N: Network> {
+    pub private_key: PrivateKey<N>,
+    pub view_key: ViewKey<N>,
+    pub address: Address<N>,
+}
+
+/// A signature on a message, under the key of the given address.
+#[derive(Clone, Debug)]
+pub struct SignatureVector<N: Network> {
+    pub address: Address<N>,
+    pub message: Vec<Field<N>>,
+    pub signature: Signature<N>,
+}
+
+/// A record, and its encryption for the owner of the given view key.
+#[derive(Clone, Debug)]
+pub struct RecordVector<N: Network> {
+    pub view_key: ViewKey<N>,
+    pub plaintext: Record<N, Plaintext<N>>,
+    pub ciphertext: Record<N, Ciphertext<N>>,
+}
+
+/// A set of test vectors, generated deterministically from a seed.
+#[derive(Clone, Debug)]
+pub struct TestVectors<N: Network> {
+    /// The seed of the test vectors.
+    seed: u64,
+    /// The account vectors.
+    accounts: Vec<AccountVector<N>>,
+    /// The signature vectors.
+    signatures: Vec<SignatureVector<N>>,
+    /// The record vectors.
+    records: Vec<RecordVector<N>>,
+}
+
+impl<N: Network> TestVectors<N> {
+    /// Generates `num_vectors` of each kind of test vector from the given seed.
+    pub fn generate(seed: u64, num_vectors: usize) -> Result<Self> {
+        // Note: `TestRng` is a portable, seedable RNG, so the vectors are identical across platforms.
+        let rng = &mut TestRng::from_seed(seed);
+
+        let mut accounts = Vec::with_capacity(num_vectors);
+        let mut signatures = Vec::with_capacity(num_vectors);
+        let mut records = Vec::with_capacity(num_vectors);
+
+        for i in 0..num_vectors {
+            // Sample an account.
+            let private_key = PrivateKey::<N>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+            accounts.push(AccountVector { private_key: private_key.clone(), view_key: view_key.clone(), address });
+
+            // Sign a message of increasing length.
+            let message = (0..=i).map(|_| Uniform::rand(rng)).collect::<Vec<Field<N>>>();
+            let signature = Signature::sign(&private_key, &message, rng)?;
+            signatures.push(SignatureVector { address, message, signature });
+
+            // Encrypt a record for the account.
+            let randomizer = Uniform::rand(rng);
+            let data = IndexMap::from([
+                (Identifier::from_str("amount")?, Entry::Private(Plaintext::from(Literal::U64(U64::new(rng.gen()))))),
+                (Identifier::from_str("memo")?, Entry::Public(Plaintext::from(Literal::Field(Uniform::rand(rng))))),
+            ]);
+            let owner = Owner::Private(Plaintext::from(Literal::Address(address)));
+            let plaintext = Record::<N, Plaintext<N>>::from_plaintext(owner, data, N::g_scalar_multiply(&randomizer))?;
+            let ciphertext = plaintext.encrypt(randomizer)?;
+            records.push(RecordVector { view_key, plaintext, ciphertext });
+        }
+
+        Ok(Self { seed, accounts, signatures, records })
+    }
+
+    /// Returns the seed of the test vectors.
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the account vectors.
+    pub fn accounts(&self) -> &[AccountVector<N>] {
+        &self.accounts
+    }
+
+    /// Returns the signature vectors.
+    pub fn signatures(&self) -> &[SignatureVector<N>] {
+        &self.signatures
+    }
+
+    /// Returns the record vectors.
+    pub fn records(&self) -> &[RecordVector<N>] {
+        &self.records
+    }
+
+    /// Ensures every test vector is valid, and that the test vectors are reproducible from their seed.
+    pub fn verify(&self) -> Result<()> {
+        for (i, account) in self.accounts.iter().enumerate() {
+            ensure!(ViewKey::try_from(&account.private_key)? == account.view_key, "Invalid view key in account {i}");
+            ensure!(Address::try_from(&account.private_key)? == account.address, "Invalid address in account {i}");
+        }
+        for (i, vector) in self.signatures.iter().enumerate() {
+            ensure!(vector.signature.verify(&vector.address, &vector.message), "Invalid signature {i}");
+        }
+        for (i, vector) in self.records.iter().enumerate() {
+            ensure!(vector.ciphertext.is_owner(&vector.view_key), "Invalid owner in record {i}");
+            let plaintext = vector.ciphertext.decrypt(&vector.view_key)?;
+            ensure!(plaintext == vector.plaintext, "Invalid ciphertext in record {i}");
+        }
+
+        // Ensure the test vectors are reproducible from the seed.
+        let num_vectors = self.accounts.len();
+        ensure!(
+            self.signatures.len() == num_vectors && self.records.len() == num_vectors,
+            "Mismatched number of test vectors"
+        );
+        let expected = Self::generate(self.seed, num_vectors)?;
+        ensure!(expected.to_json()? == self.to_json()?, "The test vectors do not match their seed");
+        Ok(())
+    }
+
+    /// Returns the test vectors as a JSON fixture.
+    pub fn to_json(&self) -> Result<String> {
+        let accounts = self.accounts.iter().map(|account| {
+            json!({
+                "private_key": account.private_key,
+                "view_key": account.view_key,
+                "address": account.address,
+            })
+        });
+        let signatures = self.signatures.iter().map(|vector| {
+            json!({
+                "address": vector.address,
+                "message": vector.message,
+                "signature": vector.signature,
+            })
+        });
+        let records = self.records.iter().map(|vector| {
+            json!({
+                "view_key": vector.view_key,
+                "plaintext": vector.plaintext,
+                "ciphertext": vector.ciphertext,
+            })
+        });
+        Ok(serde_json::to_string_pretty(&json!({
+            "network": N::NAME,
+            "seed": self.seed,
+            "accounts": accounts.collect::<Vec<_>>(),
+            "signatures": signatures.collect::<Vec<_>>(),
+            "records": records.collect::<Vec<_>>(),
+        }))?)
+    }
+
+    /// Returns the test vectors from a JSON fixture.
+    pub fn from_json(fixture: &str) -> Result<Self> {
+        let mut fixture: Value = serde_json::from_str(fixture)?;
+
+        // Ensure the fixture is for this network.
+        let network: String = take(&mut fixture, "network")?;
+        ensure!(network == N::NAME, "Expected test vectors for '{}', found '{network}'", N::NAME);
+
+        let seed = take(&mut fixture, "seed")?;
+        let accounts = take::<Vec<Value>>(&mut fixture, "accounts")?
+            .into_iter()
+            .map(|mut vector| {
+                Ok(AccountVector {
+                    private_key: take(&mut vector, "private_key")?,
+                    view_key: take(&mut vector, "view_key")?,
+                    address: take(&mut vector, "address")?,
+                })
+            })
+            .collect::<Result<_>>()?;
+        let signatures = take::<Vec<Value>>(&mut fixture, "signatures")?
+            .into_iter()
+            .map(|mut vector| {
+                Ok(SignatureVector {
+                    address: take(&mut vector, "address")?,
+                    message: take(&mut vector, "message")?,
+                    signature: take(&mut vector, "signature")?,
+                })
+            })
+            .collect::<Result<_>>()?;
+        let records = take::<Vec<Value>>(&mut fixture, "records")?
+            .into_iter()
+            .map(|mut vector| {
+                Ok(RecordVector {
+                    view_key: take(&mut vector, "view_key")?,
+                    plaintext: take(&mut vector, "plaintext")?,
+                    ciphertext: take(&mut vector, "ciphertext")?,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { seed, accounts, signatures, records })
+    }
+}
+
+/// Removes and deserializes the given field from the given JSON object.
+fn take<T: DeserializeOwned>(value: &mut Value, field: &str) -> Result<T> {
+    match value.get_mut(field) {
+        Some(value) => Ok(serde_json::from_value(value.take())?),
+        None => bail!("Missing field '{field}' in the test vectors"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_test_vectors() -> Result<()> {
+        let vectors = TestVectors::<CurrentNetwork>::generate(1234, 3)?;
+        vectors.verify()?;
+
+        // Check that the test vectors are deterministic.
+        let fixture = vectors.to_json()?;
+        assert_eq!(fixture, TestVectors::<CurrentNetwork>::generate(1234, 3)?.to_json()?);
+        assert_ne!(fixture, TestVectors::<CurrentNetwork>::generate(4321, 3)?.to_json()?);
+
+        // Check that the fixture round-trips, and re-verifies.
+        let candidate = TestVectors::<CurrentNetwork>::from_json(&fixture)?;
+        candidate.verify()?;
+        assert_eq!(fixture, candidate.to_json()?);
+
+        // Check that a tampered fixture is rejected.
+        let mut tampered: Value = serde_json::from_str(&fixture)?;
+        tampered["signatures"][0]["message"] = json!([Field::<CurrentNetwork>::from_u64(7)]);
+        let tampered = TestVectors::<CurrentNetwork>::from_json(&tampered.to_string())?;
+        assert!(tampered.verify().is_err());
+        Ok(())
+    }
+}
@@ -22,3 +22,7 @@ impl<E: Environment> SizeInBytes for Address<E> {
         Field::<E>::size_in_bytes()
     }
 }
+
+impl<E: Environment> ConstSerializedSize for Address<E> {
+    const SIZE: usize = Group::<E>::SIZE;
+}
@@ -21,3 +21,7 @@ impl<E: Environment> SizeInBytes for Boolean<E> {
         1
     }
 }
+
+impl<E: Environment> ConstSerializedSize for Boolean<E> {
+    const SIZE: usize = 1;
+}
@@ -60,6 +60,11 @@ impl<E: Environment> Field<E> {
         Self::new(E::Field::from_bytes_le_mod_order(domain.as_bytes()))
     }
 
+    /// Initializes a new field from the encoding of the given domain separator.
+    pub fn from_domain_separator(domain: DomainSeparator) -> Self {
+        Self::new_domain_separator(&domain.to_string())
+    }
+
     /// Initializes a new field from a `u8`.
     pub fn from_u8(value: u8) -> Self {
         Self { field: E::Field::from(value as u128) }
@@ -21,6 +21,18 @@ impl<E: Environment> Distribution<Field<E>> for Standard {
     }
 }
 
+#[cfg(any(test, feature = "prop-tests"))]
+impl<E: Environment> proptest::arbitrary::Arbitrary for Field<E> {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    /// Samples from a seeded RNG, so that `proptest` can shrink and replay the seed.
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::{any, Strategy};
+        any::<u64>().prop_map(|seed| Uniform::rand(&mut TestRng::from_seed(seed))).boxed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,6 +44,14 @@ mod tests {
 
     const ITERATIONS: usize = 100;
 
+    proptest::proptest! {
+        #[test]
+        fn test_arbitrary(value in proptest::prelude::any::<Field<CurrentEnvironment>>()) {
+            // Ensure the sampled value round-trips through its byte representation.
+            proptest::prop_assert_eq!(value, Field::from_bytes_le(&value.to_bytes_le().unwrap()).unwrap());
+        }
+    }
+
     #[test]
     fn test_random() {
         // Initialize a set to store all seen random elements.
@@ -21,3 +21,7 @@ impl<E: Environment> SizeInBytes for Field<E> {
         Self::SIZE_IN_BYTES
     }
 }
+
+impl<E: Environment> ConstSerializedSize for Field<E> {
+    const SIZE: usize = Self::SIZE_IN_BYTES;
+}
@@ -22,3 +22,8 @@ impl<E: Environment> SizeInBytes for Group<E> {
         Field::<E>::size_in_bytes()
     }
 }
+
+impl<E: Environment> ConstSerializedSize for Group<E> {
+    // As we serialize into the affine **x-coordinate**, we only require the size of a field element.
+    const SIZE: usize = Field::<E>::SIZE;
+}
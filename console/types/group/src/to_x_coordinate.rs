@@ -19,4 +19,34 @@ impl<E: Environment> Group<E> {
     pub fn to_x_coordinate(&self) -> Field<E> {
         Field::new(self.group.to_affine().to_x_coordinate())
     }
+
+    /// Returns the *x-coordinate* in the affine coordinates of each of the given groups.
+    ///
+    /// This is faster than calling `to_x_coordinate` on each group, as the groups are normalized in a batch.
+    pub fn batch_to_x_coordinates(groups: &[Self]) -> Vec<Field<E>> {
+        let groups = groups.iter().map(|group| group.group).collect();
+        E::Projective::batch_normalization_into_affine(groups)
+            .iter()
+            .map(|group| Field::new(group.to_x_coordinate()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_batch_to_x_coordinates() {
+        let mut rng = TestRng::default();
+
+        for num_groups in 0..10 {
+            let groups = (0..num_groups).map(|_| Group::<CurrentEnvironment>::rand(&mut rng)).collect::<Vec<_>>();
+            let expected = groups.iter().map(|group| group.to_x_coordinate()).collect::<Vec<_>>();
+            assert_eq!(expected, Group::batch_to_x_coordinates(&groups));
+        }
+    }
 }
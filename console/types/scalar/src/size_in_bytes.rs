@@ -21,3 +21,7 @@ impl<E: Environment> SizeInBytes for Scalar<E> {
         Self::SIZE_IN_BYTES
     }
 }
+
+impl<E: Environment> ConstSerializedSize for Scalar<E> {
+    const SIZE: usize = Self::SIZE_IN_BYTES;
+}
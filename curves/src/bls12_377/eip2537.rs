@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The encoding of the EIP-2537 precompiles, for BLS12-377 points and pairing checks.
+//!
+//! A base field element is 64 bytes: 16 zero bytes, followed by the 48-byte big-endian element.
+//! A G1 point is `x || y` (128 bytes), and a G2 point is `x.c0 || x.c1 || y.c0 || y.c1` (256 bytes).
+//! The point at infinity is encoded as zeros, and there are no compression or infinity flags.
+//! The input of a pairing check is the concatenation of `(G1, G2)` pairs, and its output is
+//! a 32-byte word, which is `1` if the product of the pairings is the identity and `0` otherwise.
+//!
+//! Note: the EIP-2537 precompiles of Ethereum are defined over BLS12-381. This encoding is also used
+//! by EIP-2539, which defines the precompiles over BLS12-377, and by the verifiers that are compatible with it.
+
+use crate::{
+    bls12_377::{Bls12_377, Fq, Fq2, G1Affine, G2Affine},
+    errors::GroupError,
+    traits::{AffineCurve, PairingCurve, PairingEngine},
+};
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_utilities::biginteger::BigInteger384;
+
+/// The size of an encoded base field element, in bytes.
+pub const FIELD_SIZE: usize = 64;
+/// The size of an encoded G1 point, in bytes.
+pub const G1_SIZE: usize = 2 * FIELD_SIZE;
+/// The size of an encoded G2 point, in bytes.
+pub const G2_SIZE: usize = 4 * FIELD_SIZE;
+/// The size of an encoded pair of the input of a pairing check, in bytes.
+pub const PAIR_SIZE: usize = G1_SIZE + G2_SIZE;
+/// The size of the output of a pairing check, in bytes.
+pub const PAIRING_OUTPUT_SIZE: usize = 32;
+
+/// The number of leading zero bytes of an encoded base field element.
+const PADDING_SIZE: usize = 16;
+
+/// Returns the encoding of the given G1 point.
+pub fn encode_g1(point: &G1Affine) -> [u8; G1_SIZE] {
+    let mut bytes = [0u8; G1_SIZE];
+    if !point.is_zero() {
+        encode_fq(&point.x, &mut bytes[..FIELD_SIZE]);
+        encode_fq(&point.y, &mut bytes[FIELD_SIZE..]);
+    }
+    bytes
+}
+
+/// Returns the G1 point of the given encoding, and checks that it is on the curve and in the prime-order subgroup.
+pub fn decode_g1(bytes: &[u8]) -> Result<G1Affine, GroupError> {
+    check_size(bytes, G1_SIZE)?;
+    let (x, y) = (decode_fq(&bytes[..FIELD_SIZE])?, decode_fq(&bytes[FIELD_SIZE..])?);
+    match x.is_zero() && y.is_zero() {
+        true => Ok(G1Affine::zero()),
+        false => G1Affine::from_coordinates((x, y, false)).ok_or(GroupError::InvalidGroupElement),
+    }
+}
+
+/// Returns the encoding of the given G2 point.
+pub fn encode_g2(point: &G2Affine) -> [u8; G2_SIZE] {
+    let mut bytes = [0u8; G2_SIZE];
+    if !point.is_zero() {
+        encode_fq2(&point.x, &mut bytes[..2 * FIELD_SIZE]);
+        encode_fq2(&point.y, &mut bytes[2 * FIELD_SIZE..]);
+    }
+    bytes
+}
+
+/// Returns the G2 point of the given encoding, and checks that it is on the curve and in the prime-order subgroup.
+pub fn decode_g2(bytes: &[u8]) -> Result<G2Affine, GroupError> {
+    check_size(bytes, G2_SIZE)?;
+    let (x, y) = (decode_fq2(&bytes[..2 * FIELD_SIZE])?, decode_fq2(&bytes[2 * FIELD_SIZE..])?);
+    match x.is_zero() && y.is_zero() {
+        true => Ok(G2Affine::zero()),
+        false => G2Affine::from_coordinates((x, y, false)).ok_or(GroupError::InvalidGroupElement),
+    }
+}
+
+/// Returns the input of a pairing check of the given pairs.
+pub fn encode_pairing_input(pairs: &[(G1Affine, G2Affine)]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(pairs.len() * PAIR_SIZE);
+    for (g1, g2) in pairs {
+        bytes.extend_from_slice(&encode_g1(g1));
+        bytes.extend_from_slice(&encode_g2(g2));
+    }
+    bytes
+}
+
+/// Returns the pairs of the given input of a pairing check, which must contain at least one pair.
+pub fn decode_pairing_input(bytes: &[u8]) -> Result<Vec<(G1Affine, G2Affine)>, GroupError> {
+    if bytes.is_empty() || bytes.len() % PAIR_SIZE != 0 {
+        return Err(GroupError::Message(format!("Invalid pairing input length ({} bytes)", bytes.len())));
+    }
+    bytes.chunks_exact(PAIR_SIZE).map(|pair| Ok((decode_g1(&pair[..G1_SIZE])?, decode_g2(&pair[G1_SIZE..])?))).collect()
+}
+
+/// Returns the output of a pairing check with the given result.
+pub fn encode_pairing_output(is_identity: bool) -> [u8; PAIRING_OUTPUT_SIZE] {
+    let mut bytes = [0u8; PAIRING_OUTPUT_SIZE];
+    bytes[PAIRING_OUTPUT_SIZE - 1] = is_identity as u8;
+    bytes
+}
+
+/// Returns the result of the given output of a pairing check.
+pub fn decode_pairing_output(bytes: &[u8]) -> Result<bool, GroupError> {
+    check_size(bytes, PAIRING_OUTPUT_SIZE)?;
+    match (bytes[..PAIRING_OUTPUT_SIZE - 1].iter().all(|byte| *byte == 0), bytes[PAIRING_OUTPUT_SIZE - 1]) {
+        (true, 0) => Ok(false),
+        (true, 1) => Ok(true),
+        _ => Err(GroupError::Message("Invalid pairing output".to_string())),
+    }
+}
+
+/// Returns the output of the pairing check of the given input, as computed by the precompile.
+pub fn pairing_check(input: &[u8]) -> Result<[u8; PAIRING_OUTPUT_SIZE], GroupError> {
+    let pairs = decode_pairing_input(input)?;
+    let prepared: Vec<_> = pairs.iter().map(|(g1, g2)| (g1.prepare(), g2.prepare())).collect();
+    let result = Bls12_377::product_of_pairings(prepared.iter().map(|(g1, g2)| (g1, g2)));
+    Ok(encode_pairing_output(result.is_one()))
+}
+
+/// Checks that the given encoding has the expected size.
+fn check_size(bytes: &[u8], expected: usize) -> Result<(), GroupError> {
+    match bytes.len() == expected {
+        true => Ok(()),
+        false => Err(GroupError::Message(format!("Expected {expected} bytes, found {}", bytes.len()))),
+    }
+}
+
+/// Writes the given base field element into the given 64-byte buffer.
+fn encode_fq(element: &Fq, bytes: &mut [u8]) {
+    for (chunk, limb) in bytes[PADDING_SIZE..].chunks_exact_mut(8).zip(element.to_bigint().0.iter().rev()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+}
+
+/// Returns the base field element of the given 64-byte encoding, and checks that it is padded and reduced.
+fn decode_fq(bytes: &[u8]) -> Result<Fq, GroupError> {
+    if bytes[..PADDING_SIZE].iter().any(|byte| *byte != 0) {
+        return Err(GroupError::Message("The base field element is not padded with zeros".to_string()));
+    }
+    let mut limbs = [0u64; 6];
+    for (limb, chunk) in limbs.iter_mut().rev().zip(bytes[PADDING_SIZE..].chunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    Fq::from_bigint(BigInteger384::new(limbs))
+        .ok_or_else(|| GroupError::Message("The base field element is not reduced".to_string()))
+}
+
+/// Writes the given quadratic extension field element into the given 128-byte buffer.
+fn encode_fq2(element: &Fq2, bytes: &mut [u8]) {
+    encode_fq(&element.c0, &mut bytes[..FIELD_SIZE]);
+    encode_fq(&element.c1, &mut bytes[FIELD_SIZE..]);
+}
+
+/// Returns the quadratic extension field element of the given 128-byte encoding.
+fn decode_fq2(bytes: &[u8]) -> Result<Fq2, GroupError> {
+    Ok(Fq2::new(decode_fq(&bytes[..FIELD_SIZE])?, decode_fq(&bytes[FIELD_SIZE..])?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_377::Fr;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_points() {
+        let rng = &mut TestRng::default();
+        for _ in 0..10 {
+            let g1 = G1Affine::rand(rng);
+            assert_eq!(decode_g1(&encode_g1(&g1)).unwrap(), g1);
+            let g2 = G2Affine::rand(rng);
+            assert_eq!(decode_g2(&encode_g2(&g2)).unwrap(), g2);
+        }
+
+        // Check the encoding of the point at infinity.
+        assert_eq!(encode_g1(&G1Affine::zero()), [0u8; G1_SIZE]);
+        assert!(decode_g1(&[0u8; G1_SIZE]).unwrap().is_zero());
+        assert!(decode_g2(&[0u8; G2_SIZE]).unwrap().is_zero());
+
+        // Check that invalid encodings are rejected.
+        let mut bytes = encode_g1(&G1Affine::prime_subgroup_generator());
+        assert!(decode_g1(&bytes[..G1_SIZE - 1]).is_err());
+        bytes[0] = 1;
+        assert!(decode_g1(&bytes).is_err());
+        bytes[0] = 0;
+        bytes[G1_SIZE - 1] ^= 1;
+        assert!(decode_g1(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_pairing_check() {
+        let rng = &mut TestRng::default();
+        let (g1, g2) = (G1Affine::prime_subgroup_generator(), G2Affine::prime_subgroup_generator());
+        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+
+        // Check that e(a * G1, b * G2) * e(-(a * b) * G1, G2) is the identity.
+        let pairs: [(G1Affine, G2Affine); 2] = [((g1 * a).into(), (g2 * b).into()), ((g1 * -(a * b)).into(), g2)];
+        let input = encode_pairing_input(&pairs);
+        assert_eq!(decode_pairing_input(&input).unwrap(), pairs);
+        assert!(decode_pairing_output(&pairing_check(&input).unwrap()).unwrap());
+
+        let pairs: [(G1Affine, G2Affine); 2] = [((g1 * a).into(), (g2 * b).into()), ((g1 * a).into(), g2)];
+        assert!(!decode_pairing_output(&pairing_check(&encode_pairing_input(&pairs)).unwrap()).unwrap());
+        assert!(pairing_check(&[]).is_err());
+    }
+}
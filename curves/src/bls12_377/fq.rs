@@ -148,6 +148,8 @@ impl FieldParameters for FqParameters {
     ]);
     #[rustfmt::skip]
     const REPR_SHAVE_BITS: u32 = 7;
+    #[rustfmt::skip]
+    const SMALLEST_PRIMITIVE_ELEMENT: u64 = 15;
     // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
 
     /// T = (MODULUS - 1) // 2^S =
@@ -168,6 +168,8 @@ impl FieldParameters for FrParameters {
     ]);
     #[rustfmt::skip]
     const REPR_SHAVE_BITS: u32 = 3;
+    #[rustfmt::skip]
+    const SMALLEST_PRIMITIVE_ELEMENT: u64 = 22;
     // T and T_MINUS_ONE_DIV_TWO, where r - 1 = 2^s * t
 
     /// t = (r - 1) / 2^s =
@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hashing to G2 of BLS12-377, following the construction of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380).
+//!
+//! A message is expanded with `expand_message_xmd` using SHA-256, and hashed to two elements of `Fq2`.
+//! Each element is mapped to the curve with the Shallue-van de Woestijne method, as G2 of BLS12-377
+//! has `a = 0`, and no isogeny is defined for the simplified SWU method. The sum of the two points is
+//! multiplied by the cofactor, so that the output is in the prime-order subgroup.
+//!
+//! Note: RFC 9380 does not define a suite for BLS12-377, so the outputs are not interoperable
+//! with the suites for BLS12-381.
+
+use crate::{
+    bls12_377::{g2::Bls12_377G2Parameters, Fq, Fq2, G2Affine},
+    traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_utilities::BigInteger;
+
+use once_cell::sync::OnceCell;
+use sha2::{Digest, Sha256};
+
+/// The number of bytes used to sample a base field element, which is `ceil((ceil(log2(p)) + 128) / 8)`.
+const FIELD_SAMPLE_SIZE: usize = 64;
+/// The output size of SHA-256, in bytes.
+const HASH_SIZE: usize = 32;
+/// The block size of SHA-256, in bytes.
+const HASH_BLOCK_SIZE: usize = 64;
+/// The maximum size of a domain separation tag, in bytes.
+const MAX_DST_SIZE: usize = 255;
+
+/// Returns the hash of the given message to G2, with the given domain separation tag.
+///
+/// The output is a uniformly-distributed element of the prime-order subgroup,
+/// as required to hash messages for BLS signatures.
+pub fn hash_to_g2(dst: &[u8], message: &[u8]) -> G2Affine {
+    let [u0, u1] = hash_to_fq2(dst, message);
+    let point = map_to_g2(&u0).to_projective() + map_to_g2(&u1).to_projective();
+    point.to_affine().mul_by_cofactor()
+}
+
+/// Returns the two elements of `Fq2` that the given message hashes to, with the given domain separation tag.
+fn hash_to_fq2(dst: &[u8], message: &[u8]) -> [Fq2; 2] {
+    let bytes = expand_message_xmd(dst, message, 2 * 2 * FIELD_SAMPLE_SIZE);
+    let mut elements = bytes.chunks_exact(FIELD_SAMPLE_SIZE).map(Fq::from_bytes_be_mod_order);
+    let mut next = || elements.next().expect("The expanded message has four field samples");
+    [Fq2::new(next(), next()), Fq2::new(next(), next())]
+}
+
+/// Returns `len_in_bytes` bytes expanded from the given message with SHA-256, with the given domain separation tag,
+/// as in section 5.3.1 of RFC 9380.
+pub fn expand_message_xmd(dst: &[u8], message: &[u8], len_in_bytes: usize) -> Vec<u8> {
+    // Hash an oversized domain separation tag, as in section 5.3.3 of RFC 9380.
+    let dst = match dst.len() > MAX_DST_SIZE {
+        true => Sha256::new().chain_update(b"H2C-OVERSIZE-DST-").chain_update(dst).finalize().to_vec(),
+        false => dst.to_vec(),
+    };
+    let ell = (len_in_bytes + HASH_SIZE - 1) / HASH_SIZE;
+    debug_assert!(ell <= 255 && len_in_bytes <= u16::MAX as usize);
+
+    // Compute `DST_prime = DST || I2OSP(len(DST), 1)`.
+    let mut dst_prime = dst;
+    dst_prime.push(dst_prime.len() as u8);
+
+    // Compute `b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)`.
+    let b_0 = Sha256::new()
+        .chain_update([0u8; HASH_BLOCK_SIZE])
+        .chain_update(message)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    // Compute `b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)`, where `b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)`.
+    let mut output = Vec::with_capacity(ell * HASH_SIZE);
+    let mut b_i = Sha256::new().chain_update(b_0).chain_update([1u8]).chain_update(&dst_prime).finalize();
+    output.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let xor = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect::<Vec<_>>();
+        b_i = Sha256::new().chain_update(xor).chain_update([i as u8]).chain_update(&dst_prime).finalize();
+        output.extend_from_slice(&b_i);
+    }
+    output.truncate(len_in_bytes);
+    output
+}
+
+/// Returns the point of G2 that the given element maps to, with the Shallue-van de Woestijne method.
+///
+/// The output is on the curve, but not necessarily in the prime-order subgroup.
+fn map_to_g2(u: &Fq2) -> G2Affine {
+    // The constants are computed once, as finding `Z` requires several square root checks.
+    static SVDW_CONSTANTS: OnceCell<SvdwConstants> = OnceCell::new();
+    let SvdwConstants { z, c1, c2, c3, c4 } = *SVDW_CONSTANTS.get_or_init(SvdwConstants::new);
+
+    let tv1 = u.square() * c1;
+    let tv2 = Fq2::one() + tv1;
+    let tv1 = Fq2::one() - tv1;
+    let tv3 = (tv1 * tv2).inverse().unwrap_or_else(Fq2::zero);
+    let tv4 = *u * tv1 * tv3 * c3;
+
+    // Select the first candidate `x` such that `g(x)` is a square, which is guaranteed for the third candidate.
+    let x1 = c2 - tv4;
+    let x2 = c2 + tv4;
+    let x3 = (tv2.square() * tv3).square() * c4 + z;
+    let (x, y) = match (curve_equation(&x1).sqrt(), curve_equation(&x2).sqrt()) {
+        (Some(y), _) => (x1, y),
+        (None, Some(y)) => (x2, y),
+        (None, None) => (x3, curve_equation(&x3).sqrt().expect("g(x3) is a square")),
+    };
+
+    // Ensure the sign of `y` matches the sign of `u`.
+    let y = if sgn0(u) != sgn0(&y) { -y } else { y };
+
+    let point = G2Affine::new(x, y, false);
+    debug_assert!(point.is_on_curve());
+    point
+}
+
+/// The constants of the Shallue-van de Woestijne method, as in section 6.6.1 of RFC 9380.
+#[derive(Copy, Clone)]
+struct SvdwConstants {
+    z: Fq2,
+    c1: Fq2,
+    c2: Fq2,
+    c3: Fq2,
+    c4: Fq2,
+}
+
+impl SvdwConstants {
+    /// Returns the constants for G2, where `Z` is found as in appendix H.1 of RFC 9380.
+    fn new() -> Self {
+        // Note: As `a = 0`, the conditions on `Z` simplify to the following:
+        // - `g(Z) != 0`,
+        // - `-3 * Z^2 / (4 * g(Z))` is a nonzero square,
+        // - `g(Z)` or `g(-Z / 2)` is a square.
+        let is_square = |element: &Fq2| !element.legendre().is_qnr();
+        let z = (1u64..)
+            .flat_map(|counter| [Fq2::from(counter), -Fq2::from(counter)])
+            .find(|z| {
+                let g_z = curve_equation(z);
+                if g_z.is_zero() {
+                    return false;
+                }
+                let t1 = -(z.square().double() + z.square()) / g_z.double().double();
+                !t1.is_zero()
+                    && is_square(&t1)
+                    && (is_square(&g_z) || is_square(&curve_equation(&(-*z / Fq2::from(2u64)))))
+            })
+            .expect("The Shallue-van de Woestijne constant Z exists");
+
+        let g_z = curve_equation(&z);
+        let h_z = z.square().double() + z.square();
+        let c3 = (-g_z * h_z).sqrt().expect("-g(Z) * (3 * Z^2) is a square");
+        let c3 = if sgn0(&c3) { -c3 } else { c3 };
+        Self { z, c1: g_z, c2: -z / Fq2::from(2u64), c3, c4: -g_z.double().double() / h_z }
+    }
+}
+
+/// Returns `g(x) = x^3 + b`, the right-hand side of the curve equation of G2.
+fn curve_equation(x: &Fq2) -> Fq2 {
+    x.square() * x + Bls12_377G2Parameters::WEIERSTRASS_B
+}
+
+/// Returns the sign of the given element, as in section 4.1 of RFC 9380.
+fn sgn0(element: &Fq2) -> bool {
+    let sign_0 = element.c0.to_bigint().is_odd();
+    let zero_0 = element.c0.is_zero();
+    let sign_1 = element.c1.to_bigint().is_odd();
+    sign_0 || (zero_0 && sign_1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: usize = 10;
+    const DST: &[u8] = b"BLS_SIG_BLS12377G2_XMD:SHA-256_SVDW_RO_NUL_";
+
+    /// Returns the bytes of the given hexadecimal string.
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // The test vectors of appendix K.1 of RFC 9380.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(
+            expand_message_xmd(dst, b"", 0x20),
+            from_hex("68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235")
+        );
+        assert_eq!(
+            expand_message_xmd(dst, b"abc", 0x20),
+            from_hex("d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615")
+        );
+        assert_eq!(
+            expand_message_xmd(dst, b"", 0x80),
+            from_hex(concat!(
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd",
+                "4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc",
+                "c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_map_to_g2() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let u = Fq2::rand(rng);
+            let point = map_to_g2(&u);
+            assert!(point.is_on_curve());
+            assert_eq!(sgn0(&u), sgn0(&point.y));
+        }
+        // Ensure the exceptional cases map to the curve.
+        assert!(map_to_g2(&Fq2::zero()).is_on_curve());
+        assert!(map_to_g2(&Fq2::one()).is_on_curve());
+    }
+
+    #[test]
+    fn test_hash_to_g2() {
+        for i in 0..ITERATIONS {
+            let message = format!("message {i}");
+            let point = hash_to_g2(DST, message.as_bytes());
+            assert!(point.is_on_curve());
+            assert!(point.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!point.is_zero());
+
+            // Ensure the hash is deterministic.
+            assert_eq!(point, hash_to_g2(DST, message.as_bytes()));
+            // Ensure the hash depends on the message and the domain separation tag.
+            assert_ne!(point, hash_to_g2(DST, format!("message {}", i + 1).as_bytes()));
+            assert_ne!(point, hash_to_g2(b"BLS_POP_BLS12377G2_XMD:SHA-256_SVDW_RO_POP_", message.as_bytes()));
+        }
+    }
+}
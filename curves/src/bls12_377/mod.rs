@@ -18,6 +18,8 @@ pub mod fr;
 #[doc(inline)]
 pub use fr::*;
 
+pub mod eip2537;
+
 pub mod fq;
 #[doc(inline)]
 pub use fq::*;
@@ -34,6 +36,8 @@ pub mod fq12;
 #[doc(inline)]
 pub use fq12::*;
 
+pub mod hash_to_curve;
+
 pub mod g1;
 #[doc(inline)]
 pub use g1::*;
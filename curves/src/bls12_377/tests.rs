@@ -66,7 +66,7 @@ use snarkvm_fields::{
 };
 use snarkvm_utilities::{
     biginteger::{BigInteger, BigInteger256, BigInteger384},
-    rand::{TestRng, Uniform},
+    rand::{SeededRng, Uniform},
     BitIteratorBE,
 };
 
@@ -80,7 +80,7 @@ pub(crate) const ITERATIONS: usize = 10;
 
 #[test]
 fn test_bls12_377_fr() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..ITERATIONS {
         let a: Fr = rng.gen();
@@ -94,7 +94,7 @@ fn test_bls12_377_fr() {
 
 #[test]
 fn test_bls12_377_fq() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..ITERATIONS {
         let a: Fq = rng.gen();
@@ -108,7 +108,7 @@ fn test_bls12_377_fq() {
 
 #[test]
 fn test_bls12_377_fq2() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..ITERATIONS {
         let a: Fq2 = rng.gen();
@@ -122,7 +122,7 @@ fn test_bls12_377_fq2() {
 
 #[test]
 fn test_bls12_377_fq6() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..ITERATIONS {
         let g: Fq6 = rng.gen();
@@ -135,7 +135,7 @@ fn test_bls12_377_fq6() {
 
 #[test]
 fn test_bls12_377_fq12() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..ITERATIONS {
         let g: Fq12 = rng.gen();
@@ -177,7 +177,7 @@ fn test_fq_is_half() {
 
 #[test]
 fn test_fr_sum_of_products() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
     for i in [2, 4, 8, 16, 32] {
         let a = (0..i).map(|_| rng.gen()).collect::<Vec<_>>();
         let b = (0..i).map(|_| rng.gen()).collect::<Vec<_>>();
@@ -187,7 +187,7 @@ fn test_fr_sum_of_products() {
 
 #[test]
 fn test_fq_sum_of_products() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
     for i in [2, 4, 8, 16, 32] {
         let a = (0..i).map(|_| rng.gen()).collect::<Vec<_>>();
         let b = (0..i).map(|_| rng.gen()).collect::<Vec<_>>();
@@ -211,7 +211,7 @@ fn test_fq_repr_num_bits() {
 fn test_fq_add_assign() {
     // Test associativity
 
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..1000 {
         // Generate a, b, c and ensure (a + b) + c == a + (b + c).
@@ -235,7 +235,7 @@ fn test_fq_add_assign() {
 
 #[test]
 fn test_fq_sub_assign() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..1000 {
         // Ensure that (a - b) + (b - a) = 0.
@@ -255,7 +255,7 @@ fn test_fq_sub_assign() {
 
 #[test]
 fn test_fq_mul_assign() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..1000000 {
         // Ensure that (a * b) * c = a * (b * c)
@@ -300,7 +300,7 @@ fn test_fq_mul_assign() {
 
 #[test]
 fn test_fq_squaring() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..1000000 {
         // Ensure that (a * a) = a^2
@@ -320,7 +320,7 @@ fn test_fq_squaring() {
 fn test_fq_inverse() {
     assert!(Fq::zero().inverse().is_none());
 
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     let one = Fq::one();
 
@@ -335,7 +335,7 @@ fn test_fq_inverse() {
 
 #[test]
 fn test_fq_double_in_place() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..1000 {
         // Ensure doubling a is equivalent to adding a to itself.
@@ -355,7 +355,7 @@ fn test_fq_negate() {
         assert!(a.is_zero());
     }
 
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..1000 {
         // Ensure (a - (-a)) = 0.
@@ -369,7 +369,7 @@ fn test_fq_negate() {
 
 #[test]
 fn test_fq_pow() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for i in 0..1000 {
         // Exponentiate by various small numbers and ensure it consists with repeated
@@ -393,7 +393,7 @@ fn test_fq_pow() {
 
 #[test]
 fn test_fq_sqrt() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     assert_eq!(Fq::zero().sqrt().unwrap(), Fq::zero());
 
@@ -423,28 +423,28 @@ fn test_fq_sqrt() {
 
 #[test]
 fn test_fq_sqrt_tonelli() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     random_sqrt_tonelli_tests::<Fq>(&mut rng);
 }
 
 #[test]
 fn test_fr_sqrt_tonelli() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     random_sqrt_tonelli_tests::<Fr>(&mut rng);
 }
 
 #[test]
 fn test_fq_bench_sqrt() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     bench_sqrt::<Fq>(&mut rng);
 }
 
 #[test]
 fn test_fr_bench_sqrt() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     bench_sqrt::<Fr>(&mut rng);
 }
@@ -533,7 +533,7 @@ fn test_fq2_legendre() {
 
 #[test]
 fn test_fq2_mul_nonresidue() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     let nqr = Fq2::new(Fq::zero(), Fq::one());
 
@@ -550,7 +550,7 @@ fn test_fq2_mul_nonresidue() {
 
 #[test]
 fn test_fq6_mul_by_1() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..1000 {
         let c1 = Fq2::rand(&mut rng);
@@ -566,7 +566,7 @@ fn test_fq6_mul_by_1() {
 
 #[test]
 fn test_fq6_mul_by_01() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..1000 {
         let c0 = Fq2::rand(&mut rng);
@@ -583,7 +583,7 @@ fn test_fq6_mul_by_01() {
 
 #[test]
 fn test_fq12_mul_by_014() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..1000 {
         let c0 = Fq2::rand(&mut rng);
@@ -601,7 +601,7 @@ fn test_fq12_mul_by_014() {
 
 #[test]
 fn test_fq12_mul_by_034() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _ in 0..1000 {
         let c0 = Fq2::rand(&mut rng);
@@ -619,7 +619,7 @@ fn test_fq12_mul_by_034() {
 
 #[test]
 fn test_g1_projective_glv() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     let point = G1Projective::rand(&mut rng);
     let scalar = Fr::rand(&mut rng);
@@ -630,7 +630,7 @@ fn test_g1_projective_glv() {
 
 #[test]
 fn test_g1_projective_curve() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     curve_tests::<G1Projective>(&mut rng);
     sw_tests::<Bls12_377G1Parameters>(&mut rng);
@@ -638,7 +638,7 @@ fn test_g1_projective_curve() {
 
 #[test]
 fn test_g1_projective_group() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     let a: G1Projective = rng.gen();
     let b: G1Projective = rng.gen();
@@ -654,7 +654,7 @@ fn test_g1_generator() {
 
 #[test]
 fn test_g2_projective_curve() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     curve_tests::<G2Projective>(&mut rng);
     sw_tests::<Bls12_377G2Parameters>(&mut rng);
@@ -662,7 +662,7 @@ fn test_g2_projective_curve() {
 
 #[test]
 fn test_g2_projective_group() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     let a: G2Projective = rng.gen();
     let b: G2Projective = rng.gen();
@@ -678,7 +678,7 @@ fn test_g2_generator() {
 
 #[test]
 fn test_bilinearity() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     let a: G1Projective = rng.gen();
     let b: G2Projective = rng.gen();
@@ -89,6 +89,8 @@ impl FieldParameters for FrParameters {
     #[rustfmt::skip]
     const REPR_SHAVE_BITS: u32 = 5;
     #[rustfmt::skip]
+    const SMALLEST_PRIMITIVE_ELEMENT: u64 = 5;
+    #[rustfmt::skip]
     const T: BigInteger = BigInteger([
         6678124996694371583,
         2975139753996731775,
@@ -27,7 +27,7 @@ use crate::{
 };
 use snarkvm_fields::{Field, LegendreSymbol, One, SquareRootField, Zero};
 use snarkvm_utilities::{
-    rand::{TestRng, Uniform},
+    rand::{SeededRng, Uniform},
     to_bytes_le,
     ToBytes,
 };
@@ -36,7 +36,7 @@ use rand::Rng;
 
 #[test]
 fn test_edwards_bls12_fr() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     let a: Fr = rng.gen();
     let b: Fr = rng.gen();
@@ -47,7 +47,7 @@ fn test_edwards_bls12_fr() {
 
 #[test]
 fn test_edwards_bls12_fq() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     let a: Fq = rng.gen();
     let b: Fq = rng.gen();
@@ -58,7 +58,7 @@ fn test_edwards_bls12_fq() {
 
 #[test]
 fn test_projective_curve() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     curve_tests::<EdwardsProjective>(&mut rng);
     edwards_test::<EdwardsParameters>(&mut rng);
@@ -66,7 +66,7 @@ fn test_projective_curve() {
 
 #[test]
 fn test_projective_group() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _i in 0..10 {
         let a = rng.gen();
@@ -77,7 +77,7 @@ fn test_projective_group() {
 
 #[test]
 fn test_affine_group() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     for _i in 0..10 {
         let a: EdwardsAffine = rng.gen();
@@ -94,7 +94,7 @@ fn test_generator() {
 
 #[test]
 fn test_conversion() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     let a: EdwardsAffine = rng.gen();
     let b: EdwardsAffine = rng.gen();
@@ -110,7 +110,7 @@ fn test_montgomery_conversion() {
 #[test]
 #[allow(clippy::many_single_char_names)]
 fn test_edwards_to_montgomery_point() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     let a: EdwardsAffine = rng.gen();
     let (x, y) = (a.x, a.y);
@@ -184,7 +184,7 @@ fn print_montgomery_to_weierstrass_parameters() {
 #[test]
 #[allow(clippy::many_single_char_names)]
 fn test_isomorphism() {
-    let mut rng = TestRng::default();
+    let mut rng = SeededRng::default();
 
     // Sample a random Fr element.
     let fr_element: Fr = Fr::rand(&mut rng);
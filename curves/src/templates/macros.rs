@@ -141,6 +141,26 @@ macro_rules! impl_sw_curve_serializer {
                 Ok(point)
             }
         }
+        impl<P: $params> snarkvm_utilities::serialize::ZeroCopy for Affine<P> {
+            #[inline]
+            fn size_in_bytes() -> usize {
+                Self::zero().uncompressed_size()
+            }
+
+            #[inline]
+            fn decode(mut bytes: &[u8]) -> Result<Self, snarkvm_utilities::serialize::SerializationError> {
+                let point = Self::deserialize_with_mode(&mut bytes, Compress::No, Validate::No)?;
+                match bytes.is_empty() {
+                    true => Ok(point),
+                    false => Err(snarkvm_utilities::serialize::SerializationError::InvalidData),
+                }
+            }
+
+            #[inline]
+            fn validate(bytes: &[u8]) -> Result<(), snarkvm_utilities::serialize::SerializationError> {
+                Self::decode(bytes)?.check()
+            }
+        }
     };
 }
 #[macro_export]
@@ -260,5 +280,25 @@ macro_rules! impl_edwards_curve_serializer {
                 Ok(point)
             }
         }
+        impl<P: $params> snarkvm_utilities::serialize::ZeroCopy for Affine<P> {
+            #[inline]
+            fn size_in_bytes() -> usize {
+                Self::zero().uncompressed_size()
+            }
+
+            #[inline]
+            fn decode(mut bytes: &[u8]) -> Result<Self, snarkvm_utilities::serialize::SerializationError> {
+                let point = Self::deserialize_with_mode(&mut bytes, Compress::No, Validate::No)?;
+                match bytes.is_empty() {
+                    true => Ok(point),
+                    false => Err(snarkvm_utilities::serialize::SerializationError::InvalidData),
+                }
+            }
+
+            #[inline]
+            fn validate(bytes: &[u8]) -> Result<(), snarkvm_utilities::serialize::SerializationError> {
+                Self::decode(bytes)?.check()
+            }
+        }
     };
 }
@@ -18,20 +18,21 @@ use snarkvm_fields::Zero;
 use snarkvm_utilities::{
     io::Cursor,
     rand::Uniform,
-    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, ZeroCopySlice},
     Compress,
-    TestRng,
+    SeededRng,
     Validate,
 };
 
 pub const ITERATIONS: usize = 10;
 
-pub fn sw_tests<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+pub fn sw_tests<P: ShortWeierstrassParameters>(rng: &mut SeededRng) {
     sw_curve_serialization_test::<P>(rng);
     sw_from_random_bytes::<P>(rng);
+    sw_zero_copy_test::<P>(rng);
 }
 
-pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>(rng: &mut SeededRng) {
     let modes = [
         (Compress::Yes, Validate::Yes),
         (Compress::No, Validate::No),
@@ -120,7 +121,23 @@ pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>(rng: &mut Test
     }
 }
 
-pub fn sw_from_random_bytes<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+pub fn sw_zero_copy_test<P: ShortWeierstrassParameters>(rng: &mut SeededRng) {
+    let mut expected: Vec<_> = (0..ITERATIONS).map(|_| Projective::<P>::rand(rng).to_affine()).collect();
+    expected.push(Affine::<P>::zero());
+
+    let mut serialized = vec![];
+    expected.serialize_uncompressed(&mut serialized).unwrap();
+
+    let (slice, remaining) = ZeroCopySlice::<Affine<P>>::deserialize(&serialized, Validate::Yes).unwrap();
+    assert!(remaining.is_empty());
+    assert_eq!(slice.to_vec().unwrap(), expected);
+
+    let mut candidate = vec![];
+    slice.extend_vec(&mut candidate).unwrap();
+    assert_eq!(candidate, expected);
+}
+
+pub fn sw_from_random_bytes<P: ShortWeierstrassParameters>(rng: &mut SeededRng) {
     let buf_size = Affine::<P>::zero().compressed_size();
 
     for _ in 0..ITERATIONS {
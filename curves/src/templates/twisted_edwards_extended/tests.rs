@@ -20,7 +20,7 @@ use snarkvm_utilities::{
     serialize::{CanonicalDeserialize, CanonicalSerialize},
     to_bytes_le,
     Compress,
-    TestRng,
+    SeededRng,
     ToBytes,
     Validate,
 };
@@ -44,7 +44,7 @@ where
     assert_eq!(b, P::MontgomeryParameters::MONTGOMERY_B);
 }
 
-pub fn edwards_test<P: TwistedEdwardsParameters>(rng: &mut TestRng)
+pub fn edwards_test<P: TwistedEdwardsParameters>(rng: &mut SeededRng)
 where
     P::BaseField: PrimeField,
 {
@@ -53,7 +53,7 @@ where
     edwards_from_x_and_y_coordinates::<P>(rng);
 }
 
-pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>(rng: &mut SeededRng) {
     let modes = [
         (Compress::Yes, Validate::Yes),
         (Compress::No, Validate::No),
@@ -154,7 +154,7 @@ pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>(rng: &mut T
     }
 }
 
-pub fn edwards_from_random_bytes<P: TwistedEdwardsParameters>(rng: &mut TestRng)
+pub fn edwards_from_random_bytes<P: TwistedEdwardsParameters>(rng: &mut SeededRng)
 where
     P::BaseField: PrimeField,
 {
@@ -187,7 +187,7 @@ where
     }
 }
 
-pub fn edwards_from_x_and_y_coordinates<P: TwistedEdwardsParameters>(rng: &mut TestRng)
+pub fn edwards_from_x_and_y_coordinates<P: TwistedEdwardsParameters>(rng: &mut SeededRng)
 where
     P::BaseField: PrimeField,
 {
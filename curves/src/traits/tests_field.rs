@@ -22,7 +22,7 @@ use snarkvm_fields::{
 };
 use snarkvm_utilities::{
     io::Cursor,
-    rand::TestRng,
+    rand::SeededRng,
     serialize::{CanonicalDeserialize, Flags, SWFlags},
 };
 
@@ -176,7 +176,7 @@ fn random_expansion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
-fn random_string_tests<F: PrimeField>(rng: &mut TestRng) {
+fn random_string_tests<F: PrimeField>(rng: &mut SeededRng) {
     {
         let a = "84395729384759238745923745892374598234705297301958723458712394587103249587213984572934750213947582345792304758273458972349582734958273495872304598234";
         let b = "38495729084572938457298347502349857029384609283450692834058293405982304598230458230495820394850293845098234059823049582309485203948502938452093482039";
@@ -214,7 +214,7 @@ fn random_string_tests<F: PrimeField>(rng: &mut TestRng) {
     }
 }
 
-fn random_sqrt_tests<F: SquareRootField>(rng: &mut TestRng) {
+fn random_sqrt_tests<F: SquareRootField>(rng: &mut SeededRng) {
     for _ in 0..ITERATIONS {
         let a = F::rand(rng);
         let b = a.square();
@@ -241,7 +241,7 @@ fn random_sqrt_tests<F: SquareRootField>(rng: &mut TestRng) {
     }
 }
 
-pub fn random_sqrt_tonelli_tests<F: PrimeField + SquareRootField>(rng: &mut TestRng) {
+pub fn random_sqrt_tonelli_tests<F: PrimeField + SquareRootField>(rng: &mut SeededRng) {
     // Randomly check field elements on the `sqrt` operation.
     for _ in 0..10_000 {
         // Sample the expected square root.
@@ -327,7 +327,7 @@ pub fn random_sqrt_tonelli_tests<F: PrimeField + SquareRootField>(rng: &mut Test
 }
 
 #[cfg(test)]
-pub(crate) fn bench_sqrt<F: PrimeField + SquareRootField>(rng: &mut TestRng) {
+pub(crate) fn bench_sqrt<F: PrimeField + SquareRootField>(rng: &mut SeededRng) {
     const ITERATIONS: usize = 100_000;
 
     let mut profile_a = Vec::with_capacity(ITERATIONS);
@@ -425,7 +425,7 @@ fn check_tonelli_shanks_sqrt_for_testing<F: PrimeField + SquareRootField>(field:
 }
 
 #[allow(clippy::eq_op)]
-pub fn field_test<F: Field>(a: F, b: F, rng: &mut TestRng) {
+pub fn field_test<F: Field>(a: F, b: F, rng: &mut SeededRng) {
     let zero = F::zero();
     assert!(zero == zero);
     assert!(zero.is_zero()); // true
@@ -556,7 +556,7 @@ pub fn fft_field_test<F: PrimeField + FftField>() {
     assert_eq!(generator.pow(trace.to_bigint().as_ref()), two_adic_root_of_unity);
 }
 
-pub fn primefield_test<F: PrimeField>(rng: &mut TestRng) {
+pub fn primefield_test<F: PrimeField>(rng: &mut SeededRng) {
     let one = F::one();
     assert_eq!(F::from_bigint(one.to_bigint()).unwrap(), one);
     assert_eq!(F::from_str("1").ok().unwrap(), one);
@@ -571,7 +571,7 @@ pub fn primefield_test<F: PrimeField>(rng: &mut TestRng) {
     fft_field_test::<F>();
 }
 
-pub fn sqrt_field_test<F: SquareRootField>(elem: F, rng: &mut TestRng) {
+pub fn sqrt_field_test<F: SquareRootField>(elem: F, rng: &mut SeededRng) {
     let square = elem.square();
     let sqrt = square.sqrt().unwrap();
     assert!(sqrt == elem || sqrt == -elem);
@@ -581,7 +581,7 @@ pub fn sqrt_field_test<F: SquareRootField>(elem: F, rng: &mut TestRng) {
     random_sqrt_tests::<F>(rng);
 }
 
-pub fn frobenius_test<F: Field, C: AsRef<[u64]>>(characteristic: C, maxpower: usize, rng: &mut TestRng) {
+pub fn frobenius_test<F: Field, C: AsRef<[u64]>>(characteristic: C, maxpower: usize, rng: &mut SeededRng) {
     for _ in 0..ITERATIONS {
         let a = F::rand(rng);
 
@@ -599,7 +599,7 @@ pub fn frobenius_test<F: Field, C: AsRef<[u64]>>(characteristic: C, maxpower: us
         }
     }
 }
-pub fn field_serialization_test<F: Field>(rng: &mut TestRng) {
+pub fn field_serialization_test<F: Field>(rng: &mut SeededRng) {
     use snarkvm_utilities::serialize::{Compress, Validate};
     let modes = [
         (Compress::No, Validate::No),
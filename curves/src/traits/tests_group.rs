@@ -14,7 +14,7 @@
 
 use crate::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
-use snarkvm_utilities::rand::{TestRng, Uniform};
+use snarkvm_utilities::rand::{SeededRng, Uniform};
 
 #[allow(clippy::eq_op)]
 pub fn affine_test<G: AffineCurve>(a: G) {
@@ -33,7 +33,7 @@ pub fn affine_test<G: AffineCurve>(a: G) {
 }
 
 #[allow(clippy::eq_op)]
-pub fn projective_test<G: ProjectiveCurve>(a: G, mut b: G, rng: &mut TestRng) {
+pub fn projective_test<G: ProjectiveCurve>(a: G, mut b: G, rng: &mut SeededRng) {
     let zero = G::zero();
     let fr_zero = G::ScalarField::zero();
     let fr_one = G::ScalarField::one();
@@ -14,13 +14,13 @@
 
 use crate::traits::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::Zero;
-use snarkvm_utilities::rand::{TestRng, Uniform};
+use snarkvm_utilities::rand::{SeededRng, Uniform};
 
 use std::ops::Mul;
 
 pub const ITERATIONS: usize = 5;
 
-fn random_addition_test<G: ProjectiveCurve>(rng: &mut TestRng) {
+fn random_addition_test<G: ProjectiveCurve>(rng: &mut SeededRng) {
     for _ in 0..ITERATIONS {
         let a = G::rand(rng);
         let b = G::rand(rng);
@@ -93,7 +93,7 @@ fn random_addition_test<G: ProjectiveCurve>(rng: &mut TestRng) {
     }
 }
 
-fn random_multiplication_test<G: ProjectiveCurve>(rng: &mut TestRng) {
+fn random_multiplication_test<G: ProjectiveCurve>(rng: &mut SeededRng) {
     for _ in 0..ITERATIONS {
         let mut a = G::rand(rng);
         let mut b = G::rand(rng);
@@ -123,7 +123,7 @@ fn random_multiplication_test<G: ProjectiveCurve>(rng: &mut TestRng) {
     }
 }
 
-fn random_doubling_test<G: ProjectiveCurve>(rng: &mut TestRng) {
+fn random_doubling_test<G: ProjectiveCurve>(rng: &mut SeededRng) {
     for _ in 0..ITERATIONS {
         let mut a = G::rand(rng);
         let mut b = G::rand(rng);
@@ -148,7 +148,7 @@ fn random_doubling_test<G: ProjectiveCurve>(rng: &mut TestRng) {
     }
 }
 
-fn random_negation_test<G: ProjectiveCurve>(rng: &mut TestRng) {
+fn random_negation_test<G: ProjectiveCurve>(rng: &mut SeededRng) {
     for _ in 0..ITERATIONS {
         let r = G::rand(rng);
 
@@ -176,7 +176,7 @@ fn random_negation_test<G: ProjectiveCurve>(rng: &mut TestRng) {
     }
 }
 
-fn random_transformation_test<G: ProjectiveCurve>(rng: &mut TestRng) {
+fn random_transformation_test<G: ProjectiveCurve>(rng: &mut SeededRng) {
     for _ in 0..ITERATIONS {
         let g = G::rand(rng);
         let g_affine = g.to_affine();
@@ -214,7 +214,7 @@ fn random_transformation_test<G: ProjectiveCurve>(rng: &mut TestRng) {
     }
 }
 
-pub fn curve_tests<G: ProjectiveCurve>(rng: &mut TestRng) {
+pub fn curve_tests<G: ProjectiveCurve>(rng: &mut SeededRng) {
     // Negation edge case with zero.
     {
         let z = -G::zero();
@@ -0,0 +1,99 @@
+/*
+ * Copyright (C) 2019-2023 Aleo Systems Inc.
+ * This file is part of the snarkVM library.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at:
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#ifndef SNARKVM_H
+#define SNARKVM_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The status code returned by every fallible function. */
+typedef enum SnarkvmStatus {
+    SNARKVM_STATUS_OK = 0,
+    SNARKVM_STATUS_NULL_POINTER = 1,
+    SNARKVM_STATUS_INVALID_STRING = 2,
+    SNARKVM_STATUS_INVALID_ARGUMENT = 3,
+    SNARKVM_STATUS_FAILURE = 4,
+    SNARKVM_STATUS_PANIC = 5,
+} SnarkvmStatus;
+
+/* Opaque handles, which must be freed with their corresponding free function. */
+typedef struct SnarkvmPrivateKey SnarkvmPrivateKey;
+typedef struct SnarkvmViewKey SnarkvmViewKey;
+typedef struct SnarkvmAddress SnarkvmAddress;
+typedef struct SnarkvmSignature SnarkvmSignature;
+typedef struct SnarkvmVerifyingKey SnarkvmVerifyingKey;
+typedef struct SnarkvmProof SnarkvmProof;
+
+/* Errors and strings. */
+const char *snarkvm_last_error(void);
+void snarkvm_string_free(char *string);
+
+/* Accounts. */
+SnarkvmStatus snarkvm_private_key_new(SnarkvmPrivateKey **out);
+SnarkvmStatus snarkvm_private_key_from_string(const char *string, SnarkvmPrivateKey **out);
+SnarkvmStatus snarkvm_private_key_to_string(const SnarkvmPrivateKey *private_key, char **out);
+SnarkvmStatus snarkvm_private_key_to_view_key(const SnarkvmPrivateKey *private_key, SnarkvmViewKey **out);
+SnarkvmStatus snarkvm_private_key_to_address(const SnarkvmPrivateKey *private_key, SnarkvmAddress **out);
+void snarkvm_private_key_free(SnarkvmPrivateKey *private_key);
+
+SnarkvmStatus snarkvm_view_key_from_string(const char *string, SnarkvmViewKey **out);
+SnarkvmStatus snarkvm_view_key_to_string(const SnarkvmViewKey *view_key, char **out);
+SnarkvmStatus snarkvm_view_key_to_address(const SnarkvmViewKey *view_key, SnarkvmAddress **out);
+void snarkvm_view_key_free(SnarkvmViewKey *view_key);
+
+SnarkvmStatus snarkvm_address_from_string(const char *string, SnarkvmAddress **out);
+SnarkvmStatus snarkvm_address_to_string(const SnarkvmAddress *address, char **out);
+void snarkvm_address_free(SnarkvmAddress *address);
+
+/* Signatures. */
+SnarkvmStatus snarkvm_sign(const SnarkvmPrivateKey *private_key, const uint8_t *message, size_t message_len,
+                           SnarkvmSignature **out);
+SnarkvmStatus snarkvm_verify(const SnarkvmSignature *signature, const SnarkvmAddress *address, const uint8_t *message,
+                             size_t message_len, bool *out);
+SnarkvmStatus snarkvm_signature_from_string(const char *string, SnarkvmSignature **out);
+SnarkvmStatus snarkvm_signature_to_string(const SnarkvmSignature *signature, char **out);
+void snarkvm_signature_free(SnarkvmSignature *signature);
+
+/* Encryption. */
+SnarkvmStatus snarkvm_encrypt(const SnarkvmAddress *address, const char *plaintext, char **out_ciphertext,
+                              char **out_nonce);
+SnarkvmStatus snarkvm_decrypt(const SnarkvmViewKey *view_key, const char *ciphertext, const char *nonce,
+                              char **out_plaintext);
+
+/* Proof verification. */
+SnarkvmStatus snarkvm_verifying_key_from_string(const char *string, SnarkvmVerifyingKey **out);
+SnarkvmStatus snarkvm_verifying_key_to_string(const SnarkvmVerifyingKey *verifying_key, char **out);
+void snarkvm_verifying_key_free(SnarkvmVerifyingKey *verifying_key);
+
+SnarkvmStatus snarkvm_proof_from_string(const char *string, SnarkvmProof **out);
+SnarkvmStatus snarkvm_proof_to_string(const SnarkvmProof *proof, char **out);
+void snarkvm_proof_free(SnarkvmProof *proof);
+
+SnarkvmStatus snarkvm_verify_proof(const SnarkvmVerifyingKey *verifying_key, const char *function_name,
+                                   const char *const *inputs, size_t num_inputs, const SnarkvmProof *proof,
+                                   bool *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SNARKVM_H */
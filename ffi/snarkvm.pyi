@@ -0,0 +1,77 @@
+# Copyright (C) 2019-2023 Aleo Systems Inc.
+# This file is part of the snarkVM library.
+
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at:
+# http://www.apache.org/licenses/LICENSE-2.0
+
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+"""Type stubs for the `snarkvm` module. Every fallible method raises `ValueError`."""
+
+from typing import List
+
+class PrivateKey:
+    def __init__(self) -> None: ...
+    @staticmethod
+    def from_string(private_key: str) -> PrivateKey: ...
+    def view_key(self) -> ViewKey: ...
+    def address(self) -> Address: ...
+    def sign(self, message: bytes) -> Signature: ...
+
+class ViewKey:
+    @staticmethod
+    def from_string(view_key: str) -> ViewKey: ...
+    def address(self) -> Address: ...
+    def decrypt(self, message: EncryptedMessage) -> str: ...
+
+class Address:
+    @staticmethod
+    def from_string(address: str) -> Address: ...
+    def verify(self, message: bytes, signature: Signature) -> bool: ...
+    def encrypt(self, plaintext: str) -> EncryptedMessage: ...
+
+class Signature:
+    @staticmethod
+    def from_string(signature: str) -> Signature: ...
+    @staticmethod
+    def from_bytes(bytes: bytes) -> Signature: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_hex(hex: str) -> Signature: ...
+    def to_hex(self) -> str: ...
+    def verify(self, address: Address, message: bytes) -> bool: ...
+
+class EncryptedMessage:
+    def __init__(self, ciphertext: str, nonce: str) -> None: ...
+    @property
+    def ciphertext(self) -> str: ...
+    @property
+    def nonce(self) -> str: ...
+
+class Transaction:
+    @staticmethod
+    def from_json(json: str) -> Transaction: ...
+    def to_canonical_json(self) -> str: ...
+    @staticmethod
+    def from_bytes(bytes: bytes) -> Transaction: ...
+    def to_bytes(self) -> bytes: ...
+    @property
+    def id(self) -> str: ...
+    @property
+    def kind(self) -> str: ...
+    @property
+    def fee_amount(self) -> int: ...
+    @property
+    def transition_ids(self) -> List[str]: ...
+    @property
+    def functions(self) -> List[str]: ...
+    @property
+    def serial_numbers(self) -> List[str]: ...
+    @property
+    def commitments(self) -> List[str]: ...
@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ffi_call,
+    helpers::{read_handle, write_handle},
+    CurrentNetwork,
+    FfiError,
+    SnarkvmStatus,
+};
+use snarkvm_console::account::{Address, PrivateKey, ViewKey};
+
+impl_handle!(
+    PrivateKey<CurrentNetwork>,
+    snarkvm_private_key_from_string,
+    snarkvm_private_key_to_string,
+    snarkvm_private_key_free
+);
+impl_handle!(ViewKey<CurrentNetwork>, snarkvm_view_key_from_string, snarkvm_view_key_to_string, snarkvm_view_key_free);
+impl_handle!(Address<CurrentNetwork>, snarkvm_address_from_string, snarkvm_address_to_string, snarkvm_address_free);
+
+/// Samples a new private key, and writes its handle to the out-pointer.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_private_key_new(out: *mut *mut PrivateKey<CurrentNetwork>) -> SnarkvmStatus {
+    ffi_call(|| {
+        let private_key = PrivateKey::new(&mut rand::thread_rng()).map_err(FfiError::Failure)?;
+        write_handle(out, private_key)
+    })
+}
+
+/// Writes a handle to the view key of the given private key to the out-pointer.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_private_key_to_view_key(
+    private_key: *const PrivateKey<CurrentNetwork>,
+    out: *mut *mut ViewKey<CurrentNetwork>,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let view_key = ViewKey::try_from(read_handle(private_key)?).map_err(FfiError::Failure)?;
+        write_handle(out, view_key)
+    })
+}
+
+/// Writes a handle to the address of the given private key to the out-pointer.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_private_key_to_address(
+    private_key: *const PrivateKey<CurrentNetwork>,
+    out: *mut *mut Address<CurrentNetwork>,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let address = Address::try_from(read_handle(private_key)?).map_err(FfiError::Failure)?;
+        write_handle(out, address)
+    })
+}
+
+/// Writes a handle to the address of the given view key to the out-pointer.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_view_key_to_address(
+    view_key: *const ViewKey<CurrentNetwork>,
+    out: *mut *mut Address<CurrentNetwork>,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let address = Address::try_from(read_handle(view_key)?).map_err(FfiError::Failure)?;
+        write_handle(out, address)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        ffi::{CStr, CString},
+        os::raw::c_char,
+        ptr,
+    };
+
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+    const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+    /// Returns the string that was written by the library, and frees it.
+    unsafe fn take_string(string: *mut c_char) -> String {
+        let result = CStr::from_ptr(string).to_str().unwrap().to_string();
+        crate::snarkvm_string_free(string);
+        result
+    }
+
+    #[test]
+    fn test_account() {
+        unsafe {
+            let string = CString::new(ALEO_PRIVATE_KEY).unwrap();
+            let mut private_key = ptr::null_mut();
+            assert_eq!(snarkvm_private_key_from_string(string.as_ptr(), &mut private_key), SnarkvmStatus::Ok);
+
+            let mut view_key = ptr::null_mut();
+            assert_eq!(snarkvm_private_key_to_view_key(private_key, &mut view_key), SnarkvmStatus::Ok);
+            let mut address = ptr::null_mut();
+            assert_eq!(snarkvm_view_key_to_address(view_key, &mut address), SnarkvmStatus::Ok);
+
+            let mut string = ptr::null_mut();
+            assert_eq!(snarkvm_private_key_to_string(private_key, &mut string), SnarkvmStatus::Ok);
+            assert_eq!(take_string(string), ALEO_PRIVATE_KEY);
+            assert_eq!(snarkvm_view_key_to_string(view_key, &mut string), SnarkvmStatus::Ok);
+            assert_eq!(take_string(string), ALEO_VIEW_KEY);
+            assert_eq!(snarkvm_address_to_string(address, &mut string), SnarkvmStatus::Ok);
+            assert_eq!(take_string(string), ALEO_ADDRESS);
+
+            snarkvm_private_key_free(private_key);
+            snarkvm_view_key_free(view_key);
+            snarkvm_address_free(address);
+        }
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        unsafe {
+            let mut private_key = ptr::null_mut();
+            assert_eq!(snarkvm_private_key_from_string(ptr::null(), &mut private_key), SnarkvmStatus::NullPointer);
+            let string = CString::new("APrivateKey1").unwrap();
+            assert_eq!(
+                snarkvm_private_key_from_string(string.as_ptr(), &mut private_key),
+                SnarkvmStatus::InvalidArgument
+            );
+            assert!(private_key.is_null());
+
+            let mut address = ptr::null_mut();
+            assert_eq!(snarkvm_private_key_to_address(ptr::null(), &mut address), SnarkvmStatus::NullPointer);
+            assert_eq!(snarkvm_private_key_new(ptr::null_mut()), SnarkvmStatus::NullPointer);
+        }
+    }
+}
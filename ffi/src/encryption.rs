@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ffi_call,
+    helpers::{read_handle, read_str, write_string},
+    CurrentNetwork,
+    FfiError,
+    SnarkvmStatus,
+};
+use snarkvm_console::{
+    account::{Address, ViewKey},
+    network::Network,
+    prelude::Uniform,
+    program::{Ciphertext, Plaintext},
+    types::{Group, Scalar},
+};
+
+use core::str::FromStr;
+use std::os::raw::c_char;
+
+/// Encrypts the given plaintext to the given address, and writes the ciphertext and the nonce to the
+/// out-pointers. The strings must be freed with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_encrypt(
+    address: *const Address<CurrentNetwork>,
+    plaintext: *const c_char,
+    out_ciphertext: *mut *mut c_char,
+    out_nonce: *mut *mut c_char,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let address = read_handle(address)?;
+        let plaintext =
+            Plaintext::<CurrentNetwork>::from_str(read_str(plaintext)?).map_err(FfiError::InvalidArgument)?;
+        if out_ciphertext.is_null() || out_nonce.is_null() {
+            return Err(FfiError::NullPointer);
+        }
+        // Sample the randomizer, and compute the nonce.
+        let randomizer = Scalar::rand(&mut rand::thread_rng());
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let ciphertext = plaintext.encrypt(address, randomizer).map_err(FfiError::Failure)?;
+        write_string(out_ciphertext, ciphertext.to_string())?;
+        write_string(out_nonce, nonce.to_string())
+    })
+}
+
+/// Decrypts the given ciphertext with the given view key and nonce, and writes the plaintext to the
+/// out-pointer. The string must be freed with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_decrypt(
+    view_key: *const ViewKey<CurrentNetwork>,
+    ciphertext: *const c_char,
+    nonce: *const c_char,
+    out_plaintext: *mut *mut c_char,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let view_key = read_handle(view_key)?;
+        let ciphertext =
+            Ciphertext::<CurrentNetwork>::from_str(read_str(ciphertext)?).map_err(FfiError::InvalidArgument)?;
+        let nonce = Group::<CurrentNetwork>::from_str(read_str(nonce)?).map_err(FfiError::InvalidArgument)?;
+        let plaintext = ciphertext.decrypt(view_key, nonce).map_err(FfiError::Failure)?;
+        write_string(out_plaintext, plaintext.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        snarkvm_address_free,
+        snarkvm_private_key_free,
+        snarkvm_private_key_new,
+        snarkvm_private_key_to_view_key,
+        snarkvm_string_free,
+        snarkvm_view_key_free,
+        snarkvm_view_key_to_address,
+    };
+
+    use std::{
+        ffi::{CStr, CString},
+        ptr,
+    };
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        unsafe {
+            let mut private_key = ptr::null_mut();
+            assert_eq!(snarkvm_private_key_new(&mut private_key), SnarkvmStatus::Ok);
+            let mut view_key = ptr::null_mut();
+            assert_eq!(snarkvm_private_key_to_view_key(private_key, &mut view_key), SnarkvmStatus::Ok);
+            let mut address = ptr::null_mut();
+            assert_eq!(snarkvm_view_key_to_address(view_key, &mut address), SnarkvmStatus::Ok);
+
+            let message = "{ amount: 100u64, memo: 7field }";
+            let plaintext = CString::new(message).unwrap();
+            let (mut ciphertext, mut nonce) = (ptr::null_mut(), ptr::null_mut());
+            assert_eq!(snarkvm_encrypt(address, plaintext.as_ptr(), &mut ciphertext, &mut nonce), SnarkvmStatus::Ok);
+
+            let mut decrypted = ptr::null_mut();
+            assert_eq!(snarkvm_decrypt(view_key, ciphertext, nonce, &mut decrypted), SnarkvmStatus::Ok);
+            let expected = Plaintext::<CurrentNetwork>::from_str(message).unwrap();
+            assert_eq!(CStr::from_ptr(decrypted).to_str().unwrap(), expected.to_string());
+
+            // Check that an invalid plaintext is rejected.
+            let invalid = CString::new("{ amount: }").unwrap();
+            let mut other = ptr::null_mut();
+            assert_eq!(
+                snarkvm_encrypt(address, invalid.as_ptr(), &mut other, &mut ptr::null_mut()),
+                SnarkvmStatus::InvalidArgument
+            );
+            assert!(other.is_null());
+
+            snarkvm_string_free(decrypted);
+            snarkvm_string_free(ciphertext);
+            snarkvm_string_free(nonce);
+            snarkvm_address_free(address);
+            snarkvm_view_key_free(view_key);
+            snarkvm_private_key_free(private_key);
+        }
+    }
+}
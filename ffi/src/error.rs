@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    cell::RefCell,
+    ffi::CString,
+    os::raw::c_char,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+};
+use thiserror::Error;
+
+std::thread_local! {
+    /// The error message of the last failed call on the current thread.
+    static LAST_ERROR: RefCell<Option<CString>> = RefCell::new(None);
+}
+
+/// The status that is returned by every function of the C ABI.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SnarkvmStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument is null.
+    NullPointer = 1,
+    /// A string argument is not valid UTF-8.
+    InvalidString = 2,
+    /// An argument is malformed or invalid.
+    InvalidArgument = 3,
+    /// The operation failed.
+    Failure = 4,
+    /// The library panicked.
+    Panic = 5,
+}
+
+/// An error of a call to the C ABI.
+#[derive(Debug, Error)]
+pub(crate) enum FfiError {
+    #[error("A required pointer argument is null")]
+    NullPointer,
+    #[error("A string argument is not valid UTF-8")]
+    InvalidString,
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(anyhow::Error),
+    #[error("{0}")]
+    Failure(anyhow::Error),
+}
+
+impl FfiError {
+    /// Returns the status of the error.
+    const fn status(&self) -> SnarkvmStatus {
+        match self {
+            Self::NullPointer => SnarkvmStatus::NullPointer,
+            Self::InvalidString => SnarkvmStatus::InvalidString,
+            Self::InvalidArgument(_) => SnarkvmStatus::InvalidArgument,
+            Self::Failure(_) => SnarkvmStatus::Failure,
+        }
+    }
+}
+
+/// Sets the error message of the last failed call on the current thread.
+fn set_last_error(message: Option<String>) {
+    let message = message.map(|message| CString::new(message.replace('\0', "")).unwrap_or_default());
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message);
+}
+
+/// Executes the given call, and returns its status. If the call fails (or panics),
+/// its error message is recorded for `snarkvm_last_error`.
+pub(crate) fn ffi_call(call: impl FnOnce() -> Result<(), FfiError>) -> SnarkvmStatus {
+    // Note: a panic must not unwind across the C ABI, which is undefined behavior.
+    match catch_unwind(AssertUnwindSafe(call)) {
+        Ok(Ok(())) => {
+            set_last_error(None);
+            SnarkvmStatus::Ok
+        }
+        Ok(Err(error)) => {
+            set_last_error(Some(error.to_string()));
+            error.status()
+        }
+        Err(_) => {
+            set_last_error(Some("The library panicked".to_string()));
+            SnarkvmStatus::Panic
+        }
+    }
+}
+
+/// Returns the error message of the last failed call on the current thread, or null if the last call succeeded.
+/// The message is owned by the library, and is valid until the next call on the current thread.
+#[no_mangle]
+pub extern "C" fn snarkvm_last_error() -> *const c_char {
+    LAST_ERROR.with(|last_error| last_error.borrow().as_ref().map_or(ptr::null(), |message| message.as_ptr()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_ffi_call() {
+        assert_eq!(ffi_call(|| Ok(())), SnarkvmStatus::Ok);
+        assert!(snarkvm_last_error().is_null());
+
+        // Check that an error is recorded.
+        assert_eq!(ffi_call(|| Err(FfiError::NullPointer)), SnarkvmStatus::NullPointer);
+        let message = unsafe { CStr::from_ptr(snarkvm_last_error()) };
+        assert_eq!(message.to_str().unwrap(), "A required pointer argument is null");
+
+        // Check that a panic is caught.
+        assert_eq!(ffi_call(|| panic!("bug")), SnarkvmStatus::Panic);
+        assert!(!snarkvm_last_error().is_null());
+    }
+}
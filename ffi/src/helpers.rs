@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::FfiError;
+
+use std::{
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    slice,
+};
+
+/// Returns the string at the given pointer.
+pub(crate) unsafe fn read_str<'a>(string: *const c_char) -> Result<&'a str, FfiError> {
+    if string.is_null() {
+        return Err(FfiError::NullPointer);
+    }
+    CStr::from_ptr(string).to_str().map_err(|_| FfiError::InvalidString)
+}
+
+/// Returns the buffer of the given length at the given pointer, which may be null if the length is zero.
+pub(crate) unsafe fn read_bytes<'a>(bytes: *const u8, len: usize) -> Result<&'a [u8], FfiError> {
+    match (bytes.is_null(), len) {
+        (_, 0) => Ok(&[]),
+        (true, _) => Err(FfiError::NullPointer),
+        (false, _) => Ok(slice::from_raw_parts(bytes, len)),
+    }
+}
+
+/// Returns the object of the given handle.
+pub(crate) unsafe fn read_handle<'a, T>(handle: *const T) -> Result<&'a T, FfiError> {
+    handle.as_ref().ok_or(FfiError::NullPointer)
+}
+
+/// Writes the given value to the given out-pointer.
+pub(crate) unsafe fn write_out<T>(out: *mut T, value: T) -> Result<(), FfiError> {
+    if out.is_null() {
+        return Err(FfiError::NullPointer);
+    }
+    out.write(value);
+    Ok(())
+}
+
+/// Writes a new handle to the given object to the given out-pointer.
+pub(crate) unsafe fn write_handle<T>(out: *mut *mut T, object: T) -> Result<(), FfiError> {
+    if out.is_null() {
+        return Err(FfiError::NullPointer);
+    }
+    write_out(out, Box::into_raw(Box::new(object)))
+}
+
+/// Writes a new string, which must be freed with `snarkvm_string_free`, to the given out-pointer.
+pub(crate) unsafe fn write_string(out: *mut *mut c_char, string: String) -> Result<(), FfiError> {
+    if out.is_null() {
+        return Err(FfiError::NullPointer);
+    }
+    let string = CString::new(string).map_err(|error| FfiError::Failure(error.into()))?;
+    write_out(out, string.into_raw())
+}
+
+/// Frees the given handle, if it is not null.
+pub(crate) unsafe fn free_handle<T>(handle: *mut T) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Frees a string that was returned by this library. Null is ignored.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Implements the `from_string`, `to_string`, and `free` functions for the handles of the given type.
+macro_rules! impl_handle {
+    ($type:ty, $from_string:ident, $to_string:ident, $free:ident) => {
+        /// Parses the given string, and writes a new handle to the out-pointer.
+        #[no_mangle]
+        pub unsafe extern "C" fn $from_string(
+            string: *const std::os::raw::c_char,
+            out: *mut *mut $type,
+        ) -> $crate::SnarkvmStatus {
+            $crate::ffi_call(|| {
+                let string = $crate::helpers::read_str(string)?;
+                let object = core::str::FromStr::from_str(string).map_err($crate::FfiError::InvalidArgument)?;
+                $crate::helpers::write_handle(out, object)
+            })
+        }
+
+        /// Writes the string representation of the given handle to the out-pointer.
+        /// The string must be freed with `snarkvm_string_free`.
+        #[no_mangle]
+        pub unsafe extern "C" fn $to_string(
+            handle: *const $type,
+            out: *mut *mut std::os::raw::c_char,
+        ) -> $crate::SnarkvmStatus {
+            $crate::ffi_call(|| {
+                let object = $crate::helpers::read_handle(handle)?;
+                $crate::helpers::write_string(out, object.to_string())
+            })
+        }
+
+        /// Frees the given handle. Null is ignored.
+        #[no_mangle]
+        pub unsafe extern "C" fn $free(handle: *mut $type) {
+            $crate::helpers::free_handle(handle)
+        }
+    };
+}
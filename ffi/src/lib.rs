@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A stable C ABI for key generation, signing, verification, encryption, and proof verification.
+//! With the `python` feature, the crate also builds the `snarkvm` Python module (see `pyproject.toml`).
+//!
+//! # Safety
+//!
+//! Every pointer that is passed to this library must either be null, in which case the function
+//! returns `SnarkvmStatus::NullPointer`, or be valid for its documented use: handles must have been
+//! returned by this library and not yet freed, strings must be NUL-terminated, and buffers must be
+//! valid for reads of the given length. Handles may be shared across threads, but must not be freed
+//! while they are in use.
+
+#![allow(clippy::missing_safety_doc)]
+#![warn(clippy::cast_possible_truncation)]
+
+#[macro_use]
+mod helpers;
+pub use helpers::snarkvm_string_free;
+
+mod account;
+pub use account::*;
+
+mod encryption;
+pub use encryption::*;
+
+mod error;
+pub use error::*;
+
+mod proof;
+pub use proof::*;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+mod signature;
+pub use signature::*;
+
+/// The network of the C bindings.
+type CurrentNetwork = snarkvm_console::network::Testnet3;
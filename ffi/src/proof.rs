@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ffi_call,
+    helpers::{read_handle, read_str, write_out},
+    CurrentNetwork,
+    FfiError,
+    SnarkvmStatus,
+};
+use snarkvm_console::types::Field;
+use snarkvm_synthesizer_snark::{Proof, VerifyingKey};
+
+use core::str::FromStr;
+use std::{os::raw::c_char, slice};
+
+impl_handle!(
+    VerifyingKey<CurrentNetwork>,
+    snarkvm_verifying_key_from_string,
+    snarkvm_verifying_key_to_string,
+    snarkvm_verifying_key_free
+);
+impl_handle!(Proof<CurrentNetwork>, snarkvm_proof_from_string, snarkvm_proof_to_string, snarkvm_proof_free);
+
+/// Verifies the given proof for the given function and public inputs, and writes the result to the out-pointer.
+/// The inputs are an array of `num_inputs` field element strings, e.g. `"1field"`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_verify_proof(
+    verifying_key: *const VerifyingKey<CurrentNetwork>,
+    function_name: *const c_char,
+    inputs: *const *const c_char,
+    num_inputs: usize,
+    proof: *const Proof<CurrentNetwork>,
+    out: *mut bool,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let verifying_key = read_handle(verifying_key)?;
+        let function_name = read_str(function_name)?;
+        let proof = read_handle(proof)?;
+        let inputs = match (inputs.is_null(), num_inputs) {
+            (_, 0) => &[][..],
+            (true, _) => return Err(FfiError::NullPointer),
+            (false, _) => slice::from_raw_parts(inputs, num_inputs),
+        };
+        let inputs = inputs
+            .iter()
+            .map(|input| {
+                let field = Field::<CurrentNetwork>::from_str(read_str(*input)?).map_err(FfiError::InvalidArgument)?;
+                Ok(*field)
+            })
+            .collect::<Result<Vec<_>, FfiError>>()?;
+        write_out(out, verifying_key.verify(function_name, &inputs, proof))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use snarkvm_circuit::environment::Circuit;
+    use snarkvm_synthesizer_snark::test_vectors::{ProofVectors, FUNCTION_NAME};
+
+    use std::{ffi::CString, ptr};
+
+    #[test]
+    fn test_verify_proof() {
+        let vectors = ProofVectors::<CurrentNetwork>::generate::<Circuit>(1234, 1).unwrap();
+        let vector = &vectors.proofs()[0];
+
+        unsafe {
+            let mut verifying_key = ptr::null_mut();
+            let string = CString::new(vectors.verifying_key().to_string()).unwrap();
+            assert_eq!(snarkvm_verifying_key_from_string(string.as_ptr(), &mut verifying_key), SnarkvmStatus::Ok);
+
+            let mut proof = ptr::null_mut();
+            let string = CString::new(vector.proof.to_string()).unwrap();
+            assert_eq!(snarkvm_proof_from_string(string.as_ptr(), &mut proof), SnarkvmStatus::Ok);
+
+            let function_name = CString::new(FUNCTION_NAME).unwrap();
+            let inputs = vector.inputs.iter().map(|input| CString::new(input.to_string()).unwrap()).collect::<Vec<_>>();
+            let mut input_pointers = inputs.iter().map(|input| input.as_ptr()).collect::<Vec<_>>();
+
+            let mut is_valid = false;
+            assert_eq!(
+                snarkvm_verify_proof(
+                    verifying_key,
+                    function_name.as_ptr(),
+                    input_pointers.as_ptr(),
+                    input_pointers.len(),
+                    proof,
+                    &mut is_valid
+                ),
+                SnarkvmStatus::Ok
+            );
+            assert!(is_valid);
+
+            // Check that a different public input is rejected.
+            let other = CString::new("7field").unwrap();
+            input_pointers[1] = other.as_ptr();
+            assert_eq!(
+                snarkvm_verify_proof(
+                    verifying_key,
+                    function_name.as_ptr(),
+                    input_pointers.as_ptr(),
+                    input_pointers.len(),
+                    proof,
+                    &mut is_valid
+                ),
+                SnarkvmStatus::Ok
+            );
+            assert!(!is_valid);
+
+            snarkvm_proof_free(proof);
+            snarkvm_verifying_key_free(verifying_key);
+        }
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        unsafe {
+            let mut verifying_key = ptr::null_mut();
+            let string = CString::new("verifier1invalid").unwrap();
+            assert_eq!(
+                snarkvm_verifying_key_from_string(string.as_ptr(), &mut verifying_key),
+                SnarkvmStatus::InvalidArgument
+            );
+            assert!(verifying_key.is_null());
+
+            let mut proof = ptr::null_mut();
+            let string = CString::new("proof1invalid").unwrap();
+            assert_eq!(snarkvm_proof_from_string(string.as_ptr(), &mut proof), SnarkvmStatus::InvalidArgument);
+
+            let mut is_valid = false;
+            let function_name = CString::new("main").unwrap();
+            assert_eq!(
+                snarkvm_verify_proof(ptr::null(), function_name.as_ptr(), ptr::null(), 0, ptr::null(), &mut is_valid),
+                SnarkvmStatus::NullPointer
+            );
+            assert!(!is_valid);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::py_error;
+use crate::CurrentNetwork;
+use snarkvm_console::{account, prelude::*};
+
+use pyo3::{prelude::*, types::PyBytes};
+
+/// An account private key.
+#[pyclass(module = "snarkvm", frozen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrivateKey(pub(crate) account::PrivateKey<CurrentNetwork>);
+
+#[pymethods]
+impl PrivateKey {
+    /// Samples a new private key.
+    #[new]
+    pub fn new() -> PyResult<Self> {
+        Ok(Self(account::PrivateKey::new(&mut rand::thread_rng()).map_err(py_error)?))
+    }
+
+    /// Returns the private key from its string representation (`APrivateKey1...`).
+    #[staticmethod]
+    pub fn from_string(private_key: &str) -> PyResult<Self> {
+        Ok(Self(account::PrivateKey::from_str(private_key).map_err(py_error)?))
+    }
+
+    /// Returns the string representation of the private key.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the view key of the private key.
+    pub fn view_key(&self) -> PyResult<ViewKey> {
+        Ok(ViewKey(account::ViewKey::try_from(&self.0).map_err(py_error)?))
+    }
+
+    /// Returns the address of the private key.
+    pub fn address(&self) -> PyResult<Address> {
+        Ok(Address(account::Address::try_from(&self.0).map_err(py_error)?))
+    }
+
+    /// Returns a signature for the given message.
+    pub fn sign(&self, message: &[u8]) -> PyResult<Signature> {
+        Ok(Signature(self.0.sign_bytes(message, &mut rand::thread_rng()).map_err(py_error)?))
+    }
+}
+
+/// An account view key.
+#[pyclass(module = "snarkvm", frozen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ViewKey(pub(crate) account::ViewKey<CurrentNetwork>);
+
+#[pymethods]
+impl ViewKey {
+    /// Returns the view key from its string representation (`AViewKey1...`).
+    #[staticmethod]
+    pub fn from_string(view_key: &str) -> PyResult<Self> {
+        Ok(Self(account::ViewKey::from_str(view_key).map_err(py_error)?))
+    }
+
+    /// Returns the string representation of the view key.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the address of the view key.
+    pub fn address(&self) -> PyResult<Address> {
+        Ok(Address(account::Address::try_from(&self.0).map_err(py_error)?))
+    }
+}
+
+/// An account address.
+#[pyclass(module = "snarkvm", frozen)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Address(pub(crate) account::Address<CurrentNetwork>);
+
+#[pymethods]
+impl Address {
+    /// Returns the address from its string representation (`aleo1...`).
+    #[staticmethod]
+    pub fn from_string(address: &str) -> PyResult<Self> {
+        Ok(Self(account::Address::from_str(address).map_err(py_error)?))
+    }
+
+    /// Returns the string representation of the address.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `True` if the signature is valid for the given message and this address.
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
+        signature.0.verify_bytes(&self.0, message)
+    }
+}
+
+/// An account signature.
+#[pyclass(module = "snarkvm", frozen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature(pub(crate) account::Signature<CurrentNetwork>);
+
+#[pymethods]
+impl Signature {
+    /// Returns the signature from its string representation (`sign1...`).
+    #[staticmethod]
+    pub fn from_string(signature: &str) -> PyResult<Self> {
+        Ok(Self(account::Signature::from_str(signature).map_err(py_error)?))
+    }
+
+    /// Returns the string representation of the signature.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the signature from its little-endian bytes.
+    #[staticmethod]
+    pub fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        Ok(Self(account::Signature::from_bytes_le(bytes).map_err(py_error)?))
+    }
+
+    /// Returns the little-endian bytes of the signature.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        Ok(PyBytes::new(py, &self.0.to_bytes_le().map_err(py_error)?))
+    }
+
+    /// Returns the signature from the hex encoding of its little-endian bytes.
+    #[staticmethod]
+    pub fn from_hex(hex: &str) -> PyResult<Self> {
+        Self::from_bytes(&from_canonical_hex(hex).map_err(py_error)?)
+    }
+
+    /// Returns the hex encoding of the little-endian bytes of the signature.
+    pub fn to_hex(&self) -> PyResult<String> {
+        Ok(to_canonical_hex(&self.0.to_bytes_le().map_err(py_error)?))
+    }
+
+    /// Returns `True` if the signature is valid for the given address and message.
+    pub fn verify(&self, address: &Address, message: &[u8]) -> bool {
+        address.verify(message, self)
+    }
+}
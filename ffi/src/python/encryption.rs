@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{py_error, Address, ViewKey};
+use crate::CurrentNetwork;
+use snarkvm_console::{
+    network::Network,
+    prelude::*,
+    program::{Ciphertext, Plaintext},
+    types::{Group, Scalar},
+};
+
+use pyo3::prelude::*;
+
+/// A plaintext that is encrypted to an address, with the nonce that its recipient needs to decrypt it.
+#[pyclass(module = "snarkvm", frozen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncryptedMessage {
+    /// The ciphertext.
+    ciphertext: Ciphertext<CurrentNetwork>,
+    /// The nonce, which is the generator multiplied by the randomizer of the encryption.
+    nonce: Group<CurrentNetwork>,
+}
+
+#[pymethods]
+impl EncryptedMessage {
+    /// Returns the encrypted message from the string representations of its ciphertext and nonce.
+    #[new]
+    pub fn new(ciphertext: &str, nonce: &str) -> PyResult<Self> {
+        let ciphertext = Ciphertext::from_str(ciphertext).map_err(py_error)?;
+        let nonce = Group::from_str(nonce).map_err(py_error)?;
+        Ok(Self { ciphertext, nonce })
+    }
+
+    /// Returns the string representation of the ciphertext (`ciphertext1...`).
+    #[getter]
+    pub fn ciphertext(&self) -> String {
+        self.ciphertext.to_string()
+    }
+
+    /// Returns the string representation of the nonce.
+    #[getter]
+    pub fn nonce(&self) -> String {
+        self.nonce.to_string()
+    }
+}
+
+#[pymethods]
+impl Address {
+    /// Encrypts the given plaintext (e.g. `{ amount: 100u64 }`) to this address.
+    pub fn encrypt(&self, plaintext: &str) -> PyResult<EncryptedMessage> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(plaintext).map_err(py_error)?;
+        // Sample the randomizer, and compute the nonce.
+        let randomizer = Scalar::rand(&mut rand::thread_rng());
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        // Encrypt the plaintext.
+        let ciphertext = plaintext.encrypt(&self.0, randomizer).map_err(py_error)?;
+        Ok(EncryptedMessage { ciphertext, nonce })
+    }
+}
+
+#[pymethods]
+impl ViewKey {
+    /// Decrypts the given message, which was encrypted to the address of this view key, into a plaintext string.
+    pub fn decrypt(&self, message: &EncryptedMessage) -> PyResult<String> {
+        Ok(message.ciphertext.decrypt(&self.0, message.nonce).map_err(py_error)?.to_string())
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod account;
+pub use account::*;
+
+mod encryption;
+pub use encryption::*;
+
+mod transaction;
+pub use transaction::*;
+
+use core::fmt;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+/// Returns a Python `ValueError` with the given message.
+pub(crate) fn py_error(error: impl fmt::Display) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// The `snarkvm` Python module.
+#[pymodule]
+fn snarkvm(_py: Python, module: &PyModule) -> PyResult<()> {
+    module.add_class::<PrivateKey>()?;
+    module.add_class::<ViewKey>()?;
+    module.add_class::<Address>()?;
+    module.add_class::<Signature>()?;
+    module.add_class::<EncryptedMessage>()?;
+    module.add_class::<Transaction>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+    const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+    #[test]
+    fn test_account() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        assert_eq!(private_key.__str__(), ALEO_PRIVATE_KEY);
+        assert_eq!(private_key.view_key().unwrap().__str__(), ALEO_VIEW_KEY);
+        assert_eq!(private_key.address().unwrap().__str__(), ALEO_ADDRESS);
+        assert_eq!(ViewKey::from_string(ALEO_VIEW_KEY).unwrap().address().unwrap().__str__(), ALEO_ADDRESS);
+        assert!(PrivateKey::from_string("APrivateKey1").is_err());
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let private_key = PrivateKey::new().unwrap();
+        let address = private_key.address().unwrap();
+
+        let signature = private_key.sign(b"hello world").unwrap();
+        assert!(address.verify(b"hello world", &signature));
+        assert!(!address.verify(b"hello there", &signature));
+
+        // Check that the signature round-trips through its encodings.
+        let candidate = Signature::from_string(&signature.__str__()).unwrap();
+        assert!(candidate.verify(&address, b"hello world"));
+        let candidate = Signature::from_hex(&signature.to_hex().unwrap()).unwrap();
+        assert!(candidate.verify(&address, b"hello world"));
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        let private_key = PrivateKey::new().unwrap();
+        let view_key = private_key.view_key().unwrap();
+
+        let message = view_key.address().unwrap().encrypt("{ amount: 100u64 }").unwrap();
+        let message = EncryptedMessage::new(&message.ciphertext(), &message.nonce()).unwrap();
+        let expected = snarkvm_console::program::Plaintext::<crate::CurrentNetwork>::from_str("{ amount: 100u64 }");
+        assert_eq!(view_key.decrypt(&message).unwrap(), expected.unwrap().to_string());
+
+        // Check that another view key cannot decrypt the message.
+        let other = PrivateKey::new().unwrap().view_key().unwrap();
+        assert_ne!(other.decrypt(&message).ok(), view_key.decrypt(&message).ok());
+    }
+
+    #[test]
+    fn test_transaction() {
+        use proptest::{
+            prelude::any,
+            strategy::{Strategy, ValueTree},
+            test_runner::TestRunner,
+        };
+        use snarkvm_console::prelude::{CanonicalJson, ToBytes};
+        use snarkvm_ledger_block::Transaction as LedgerTransaction;
+
+        // Sample an execution transaction.
+        let strategy = any::<LedgerTransaction<crate::CurrentNetwork>>();
+        let expected = strategy.new_tree(&mut TestRunner::deterministic()).unwrap().current();
+        let transaction = Transaction(expected.clone());
+
+        // Check the inspection methods.
+        assert_eq!(transaction.id(), expected.id().to_string());
+        assert_eq!(transaction.kind(), "execute");
+        assert_eq!(transaction.fee_amount().unwrap(), 0);
+        assert_eq!(transaction.transition_ids().len(), expected.transitions().count());
+        assert!(transaction.functions().iter().all(|function| function == "token.aleo/transfer"));
+        assert_eq!(transaction.serial_numbers().len(), expected.serial_numbers().count());
+        assert_eq!(transaction.commitments().len(), expected.commitments().count());
+
+        // Check that the transaction round-trips through its encodings.
+        assert_eq!(Transaction::from_json(&transaction.__str__()).unwrap(), transaction);
+        assert_eq!(Transaction::from_bytes(&expected.to_bytes_le().unwrap()).unwrap(), transaction);
+        let canonical_json = transaction.to_canonical_json().unwrap();
+        assert_eq!(LedgerTransaction::from_canonical_json(&canonical_json).unwrap(), expected);
+
+        // Check that malformed encodings are rejected.
+        assert!(Transaction::from_json("{}").is_err());
+        assert!(Transaction::from_bytes(&[0u8; 8]).is_err());
+    }
+}
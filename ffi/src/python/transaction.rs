@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::py_error;
+use crate::CurrentNetwork;
+use snarkvm_console::prelude::*;
+use snarkvm_ledger_block as block;
+
+use pyo3::{prelude::*, types::PyBytes};
+
+/// A transaction, which may be inspected without executing it.
+#[pyclass(module = "snarkvm", frozen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transaction(pub(crate) block::Transaction<CurrentNetwork>);
+
+#[pymethods]
+impl Transaction {
+    /// Returns the transaction from its JSON representation.
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        Ok(Self(block::Transaction::from_str(json).map_err(py_error)?))
+    }
+
+    /// Returns the JSON representation of the transaction.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the canonical JSON encoding of the transaction, which may be compared or hashed as a string.
+    pub fn to_canonical_json(&self) -> PyResult<String> {
+        self.0.to_canonical_json().map_err(py_error)
+    }
+
+    /// Returns the transaction from its little-endian bytes.
+    #[staticmethod]
+    pub fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        Ok(Self(block::Transaction::from_bytes_le(bytes).map_err(py_error)?))
+    }
+
+    /// Returns the little-endian bytes of the transaction.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        Ok(PyBytes::new(py, &self.0.to_bytes_le().map_err(py_error)?))
+    }
+
+    /// Returns the transaction ID (`at1...`).
+    #[getter]
+    pub fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Returns the type of the transaction, which is `deploy`, `execute`, or `fee`.
+    #[getter]
+    pub fn kind(&self) -> &'static str {
+        match &self.0 {
+            block::Transaction::Deploy(..) => "deploy",
+            block::Transaction::Execute(..) => "execute",
+            block::Transaction::Fee(..) => "fee",
+        }
+    }
+
+    /// Returns the total fee amount, in microcredits.
+    #[getter]
+    pub fn fee_amount(&self) -> PyResult<u64> {
+        Ok(*self.0.fee_amount().map_err(py_error)?)
+    }
+
+    /// Returns the transition IDs (`au1...`).
+    #[getter]
+    pub fn transition_ids(&self) -> Vec<String> {
+        self.0.transition_ids().map(ToString::to_string).collect()
+    }
+
+    /// Returns the functions that are called by the transitions, as `program_id/function_name`.
+    #[getter]
+    pub fn functions(&self) -> Vec<String> {
+        self.0
+            .transitions()
+            .map(|transition| format!("{}/{}", transition.program_id(), transition.function_name()))
+            .collect()
+    }
+
+    /// Returns the serial numbers of the records that are consumed by the transaction.
+    #[getter]
+    pub fn serial_numbers(&self) -> Vec<String> {
+        self.0.serial_numbers().map(ToString::to_string).collect()
+    }
+
+    /// Returns the commitments of the records that are created by the transaction.
+    #[getter]
+    pub fn commitments(&self) -> Vec<String> {
+        self.0.commitments().map(ToString::to_string).collect()
+    }
+}
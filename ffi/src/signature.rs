@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ffi_call,
+    helpers::{read_bytes, read_handle, write_handle, write_out},
+    CurrentNetwork,
+    FfiError,
+    SnarkvmStatus,
+};
+use snarkvm_console::account::{Address, PrivateKey, Signature};
+
+impl_handle!(
+    Signature<CurrentNetwork>,
+    snarkvm_signature_from_string,
+    snarkvm_signature_to_string,
+    snarkvm_signature_free
+);
+
+/// Signs the given message with the given private key, and writes the signature handle to the out-pointer.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_sign(
+    private_key: *const PrivateKey<CurrentNetwork>,
+    message: *const u8,
+    message_len: usize,
+    out: *mut *mut Signature<CurrentNetwork>,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let private_key = read_handle(private_key)?;
+        let message = read_bytes(message, message_len)?;
+        let signature = private_key.sign_bytes(message, &mut rand::thread_rng()).map_err(FfiError::Failure)?;
+        write_handle(out, signature)
+    })
+}
+
+/// Verifies the given signature on the given message for the given address, and writes the result to the out-pointer.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_verify(
+    signature: *const Signature<CurrentNetwork>,
+    address: *const Address<CurrentNetwork>,
+    message: *const u8,
+    message_len: usize,
+    out: *mut bool,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let signature = read_handle(signature)?;
+        let address = read_handle(address)?;
+        let message = read_bytes(message, message_len)?;
+        write_out(out, signature.verify_bytes(address, message))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        snarkvm_address_free,
+        snarkvm_private_key_free,
+        snarkvm_private_key_new,
+        snarkvm_private_key_to_address,
+    };
+
+    use std::ptr;
+
+    #[test]
+    fn test_sign_and_verify() {
+        unsafe {
+            let mut private_key = ptr::null_mut();
+            assert_eq!(snarkvm_private_key_new(&mut private_key), SnarkvmStatus::Ok);
+            let mut address = ptr::null_mut();
+            assert_eq!(snarkvm_private_key_to_address(private_key, &mut address), SnarkvmStatus::Ok);
+
+            let message = b"hello world";
+            let mut signature = ptr::null_mut();
+            assert_eq!(snarkvm_sign(private_key, message.as_ptr(), message.len(), &mut signature), SnarkvmStatus::Ok);
+
+            let mut is_valid = false;
+            assert_eq!(
+                snarkvm_verify(signature, address, message.as_ptr(), message.len(), &mut is_valid),
+                SnarkvmStatus::Ok
+            );
+            assert!(is_valid);
+
+            // Check that a different message is rejected.
+            let other = b"hello there";
+            assert_eq!(
+                snarkvm_verify(signature, address, other.as_ptr(), other.len(), &mut is_valid),
+                SnarkvmStatus::Ok
+            );
+            assert!(!is_valid);
+
+            // Check that a null message with a nonzero length is rejected.
+            assert_eq!(snarkvm_verify(signature, address, ptr::null(), 1, &mut is_valid), SnarkvmStatus::NullPointer);
+
+            snarkvm_signature_free(signature);
+            snarkvm_address_free(address);
+            snarkvm_private_key_free(private_key);
+        }
+    }
+}
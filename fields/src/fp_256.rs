@@ -30,6 +30,7 @@ use crate::{
 use snarkvm_utilities::{
     biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger256 as BigInteger},
     serialize::CanonicalDeserialize,
+    ConstSerializedSize,
     FromBytes,
     ToBits,
     ToBytes,
@@ -606,6 +607,10 @@ impl<P: Fp256Parameters> FromBytes for Fp256<P> {
     }
 }
 
+impl<P: Fp256Parameters> ConstSerializedSize for Fp256<P> {
+    const SIZE: usize = BigInteger::SIZE;
+}
+
 /// `Fp` elements are ordered lexicographically.
 impl<P: Fp256Parameters> Ord for Fp256<P> {
     #[inline(always)]
@@ -30,6 +30,7 @@ use crate::{
 use snarkvm_utilities::{
     biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger384 as BigInteger},
     serialize::CanonicalDeserialize,
+    ConstSerializedSize,
     FromBytes,
     ToBits,
     ToBytes,
@@ -635,6 +636,10 @@ impl<P: Fp384Parameters> FromBytes for Fp384<P> {
     }
 }
 
+impl<P: Fp384Parameters> ConstSerializedSize for Fp384<P> {
+    const SIZE: usize = BigInteger::SIZE;
+}
+
 impl<P: Fp384Parameters> FromStr for Fp384<P> {
     type Err = FieldError;
 
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{FieldParameters, PrimeField};
+
+use anyhow::{bail, Result};
+
+/// The S-box exponents of the default Anemoi parameters, in order of preference, with their number of rounds
+/// for a single column at a security level of 128 bits.
+///
+/// The rounds are those of the Anemoi reference implementation, including its security margin.
+const ALPHAS_AND_ROUNDS: [(u64, usize); 4] = [(3, 21), (5, 21), (7, 20), (11, 19)];
+
+/// The first 100 decimal digits of `pi - 3`, from which the round constants are derived.
+const PI_0: &str =
+    "1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679";
+
+/// The parameters of the Anemoi permutation of [BBC+22][anemoi] over a single column `(x, y)`.
+///
+/// A round adds the round constants, applies the Pseudo-Hadamard transform `(x, y) -> (2x + y, x + y)`,
+/// which is the linear layer of a single column, and applies the open Flystel:
+/// `x -= beta * y^2`, `y -= x^(1 / alpha)`, and `x += beta * y^2 + delta`.
+/// The permutation ends with the linear layer.
+///
+/// [anemoi]: https://eprint.iacr.org/2022/840
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnemoiParameters<F: PrimeField> {
+    /// The number of rounds.
+    pub rounds: usize,
+    /// The exponent of the S-box of the open Flystel.
+    pub alpha: u64,
+    /// The exponent of the inverse S-box, `alpha^{-1} mod (p - 1)`, as little-endian limbs.
+    pub alpha_inv: Vec<u64>,
+    /// The coefficient of the quadratic functions of the open Flystel, which is the smallest primitive element `g`.
+    pub beta: F,
+    /// The constant of the second quadratic function of the open Flystel, which is `g^{-1}`.
+    pub delta: F,
+    /// The round constants that are added to `x`, indexed by round.
+    pub c: Vec<F>,
+    /// The round constants that are added to `y`, indexed by round.
+    pub d: Vec<F>,
+}
+
+impl<F: PrimeField> AnemoiParameters<F> {
+    /// Returns the default Anemoi parameters for the field.
+    ///
+    /// The S-box exponent is the smallest exponent of the reference implementation that is coprime to `p - 1`,
+    /// and the round constants are those of the reference implementation for a single column:
+    /// `c_r = g * pi_0^(2r) + (pi_0^r + 1)^alpha` and `d_r = g + (pi_0^r + 1)^alpha + g^{-1}`.
+    pub fn default_parameters() -> Result<Self> {
+        let Some((alpha, rounds, alpha_inv)) = ALPHAS_AND_ROUNDS
+            .iter()
+            .find_map(|(alpha, rounds)| Some((*alpha, *rounds, inverse_sbox_exponent::<F>(*alpha)?)))
+        else {
+            bail!("No Anemoi S-box exponent was found for this field")
+        };
+
+        let beta = F::from(F::Parameters::SMALLEST_PRIMITIVE_ELEMENT);
+        let Some(delta) = beta.inverse() else { bail!("The primitive element must not be zero") };
+
+        // Compute `pi_0` from its decimal digits.
+        let ten = F::from(10u64);
+        let pi_0 = PI_0.bytes().fold(F::zero(), |pi_0, digit| pi_0 * ten + F::from(u64::from(digit - b'0')));
+
+        let (mut c, mut d) = (Vec::with_capacity(rounds), Vec::with_capacity(rounds));
+        let mut pi_0_r = F::one();
+        for _ in 0..rounds {
+            let pow_alpha = (pi_0_r + F::one()).pow([alpha]);
+            c.push(beta * pi_0_r.square() + pow_alpha);
+            d.push(beta + pow_alpha + delta);
+            pi_0_r *= pi_0;
+        }
+
+        Ok(Self { rounds, alpha, alpha_inv, beta, delta, c, d })
+    }
+}
+
+/// Returns `alpha^{-1} mod (p - 1)` as little-endian limbs, or `None` if `alpha` is not coprime to `p - 1`.
+///
+/// The exponent inverts the S-box `x^alpha`, which is a permutation of the field iff `alpha` is coprime to `p - 1`.
+pub fn inverse_sbox_exponent<F: PrimeField>(alpha: u64) -> Option<Vec<u64>> {
+    // Compute `p - 1`, whose lowest limb is non-zero, as `p` is odd.
+    let mut modulus_minus_one = F::Parameters::MODULUS.as_ref().to_vec();
+    modulus_minus_one[0] -= 1;
+
+    // Find `k` such that `alpha` divides `k * (p - 1) + 1`, which exists iff `alpha` is coprime to `p - 1`.
+    let remainder = modulus_minus_one
+        .iter()
+        .rev()
+        .fold(0u128, |remainder, limb| ((remainder << 64) | *limb as u128) % alpha as u128);
+    let k = (1..alpha as u128).find(|k| (k * remainder + 1) % alpha as u128 == 0)?;
+
+    // Compute `(k * (p - 1) + 1) / alpha`.
+    let mut exponent = Vec::with_capacity(modulus_minus_one.len() + 1);
+    let mut carry = 1u128;
+    for limb in &modulus_minus_one {
+        let product = *limb as u128 * k + carry;
+        exponent.push(product as u64);
+        carry = product >> 64;
+    }
+    exponent.push(carry as u64);
+    let mut remainder = 0u128;
+    for limb in exponent.iter_mut().rev() {
+        let dividend = (remainder << 64) | *limb as u128;
+        *limb = (dividend / alpha as u128) as u64;
+        remainder = dividend % alpha as u128;
+    }
+    debug_assert_eq!(remainder, 0);
+    Some(exponent)
+}
@@ -42,6 +42,10 @@ pub trait FieldParameters: 'static + FftParameters + PoseidonDefaultParameters {
     /// Self::MODULUS`
     const GENERATOR: Self::BigInteger;
 
+    /// The smallest primitive element of the field, which is the multiplicative generator that the
+    /// reference implementations of Anemoi and Rescue-Prime derive their constants from.
+    const SMALLEST_PRIMITIVE_ELEMENT: u64;
+
     /// The number of bits that can be reliably stored.
     /// (Should equal `SELF::MODULUS_BITS - 1`)
     const CAPACITY: u32;
@@ -14,6 +14,9 @@
 
 pub use num_traits::One;
 
+mod anemoi_parameters;
+pub use anemoi_parameters::*;
+
 mod fft_field;
 pub use fft_field::*;
 
@@ -32,6 +35,9 @@ pub use poseidon_grain_lfsr::*;
 mod poseidon_default;
 pub use poseidon_default::*;
 
+mod poseidon_mds;
+pub use poseidon_mds::*;
+
 mod prime_field;
 pub use prime_field::*;
 
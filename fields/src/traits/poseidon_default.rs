@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{serial_batch_inversion_and_mul, PoseidonGrainLFSR, PrimeField};
+use crate::{
+    inverse_sbox_exponent,
+    is_secure_poseidon_mds,
+    serial_batch_inversion_and_mul,
+    FieldParameters,
+    PoseidonGrainLFSR,
+    PrimeField,
+};
 use aleo_std::{end_timer, start_timer};
 use itertools::Itertools;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, ensure, Result};
 
 /// Parameters and RNG used
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -34,6 +41,37 @@ pub struct PoseidonParameters<F: PrimeField, const RATE: usize, const CAPACITY:
     pub mds: Vec<Vec<F>>,
 }
 
+impl<F: PrimeField, const RATE: usize> PoseidonParameters<F, RATE, 1> {
+    /// Generates Poseidon parameters for this rate over any prime field, for 128 bits of security.
+    ///
+    /// The parameters follow the [reference implementation](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage):
+    /// - `alpha` is the smallest of 3, 5, 7, 11, 13, or 17 that is coprime to `p - 1`,
+    /// - the number of rounds is the cheapest that satisfies the security inequalities, plus the security margin,
+    /// - the round constants and the Cauchy MDS matrix are sampled from the Grain LFSR,
+    ///   and the MDS matrix is resampled until it passes the checks against invariant subspace trails.
+    pub fn generate() -> Result<Self> {
+        ensure!(RATE > 0, "The Poseidon rate must be greater than zero");
+
+        // Select the smallest S-box exponent that is a permutation of the field.
+        let alpha = match [3, 5, 7, 11, 13, 17].into_iter().find(|alpha| inverse_sbox_exponent::<F>(*alpha).is_some()) {
+            Some(alpha) => alpha,
+            None => bail!("No Poseidon S-box exponent was found for this field"),
+        };
+        // Compute the number of rounds.
+        let (full_rounds, partial_rounds) = find_poseidon_round_numbers::<F>(RATE + 1, alpha)?;
+        // Sample the round constants and the MDS matrix.
+        let (ark, mds) = find_poseidon_ark_and_mds::<F, RATE>(full_rounds, partial_rounds, None)?;
+
+        Ok(PoseidonParameters {
+            full_rounds: full_rounds as usize,
+            partial_rounds: partial_rounds as usize,
+            alpha,
+            ark,
+            mds,
+        })
+    }
+}
+
 /// A field with Poseidon parameters associated
 pub trait PoseidonDefaultField {
     /// Obtain the default Poseidon parameters for this rate and for this prime field,
@@ -42,59 +80,12 @@ pub trait PoseidonDefaultField {
     where
         Self: PrimeField,
     {
-        /// Internal function that computes the ark and mds from the Poseidon Grain LFSR.
-        #[allow(clippy::type_complexity)]
-        fn find_poseidon_ark_and_mds<F: PrimeField, const RATE: usize>(
-            full_rounds: u64,
-            partial_rounds: u64,
-            skip_matrices: u64,
-        ) -> Result<(Vec<Vec<F>>, Vec<Vec<F>>)> {
-            let lfsr_time = start_timer!(|| "LFSR Init");
-            let mut lfsr =
-                PoseidonGrainLFSR::new(false, F::size_in_bits() as u64, (RATE + 1) as u64, full_rounds, partial_rounds);
-            end_timer!(lfsr_time);
-
-            let ark_time = start_timer!(|| "Constructing ARK");
-            let mut ark = Vec::with_capacity((full_rounds + partial_rounds) as usize);
-            for _ in 0..(full_rounds + partial_rounds) {
-                ark.push(lfsr.get_field_elements_rejection_sampling(RATE + 1)?);
-            }
-            end_timer!(ark_time);
-
-            let skip_time = start_timer!(|| "Skipping matrices");
-            for _ in 0..skip_matrices {
-                let _ = lfsr.get_field_elements_mod_p::<F>(2 * (RATE + 1))?;
-            }
-            end_timer!(skip_time);
-
-            // A qualifying matrix must satisfy the following requirements:
-            // - There is no duplication among the elements in x or y.
-            // - There is no i and j such that x[i] + y[j] = p.
-            // - There resultant MDS passes all three tests.
-
-            let xs = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
-            let ys = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
-
-            let mds_time = start_timer!(|| "Construct MDS");
-            let mut mds_flattened = vec![F::zero(); (RATE + 1) * (RATE + 1)];
-            for (x, mds_row_i) in xs.iter().take(RATE + 1).zip_eq(mds_flattened.chunks_mut(RATE + 1)) {
-                for (y, e) in ys.iter().take(RATE + 1).zip_eq(mds_row_i) {
-                    *e = *x + y;
-                }
-            }
-            serial_batch_inversion_and_mul(&mut mds_flattened, &F::one());
-            let mds = mds_flattened.chunks(RATE + 1).map(|row| row.to_vec()).collect();
-            end_timer!(mds_time);
-
-            Ok((ark, mds))
-        }
-
         match Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS.iter().find(|entry| entry.rate == RATE) {
             Some(entry) => {
                 let (ark, mds) = find_poseidon_ark_and_mds::<Self, RATE>(
                     entry.full_rounds as u64,
                     entry.partial_rounds as u64,
-                    entry.skip_matrices as u64,
+                    Some(entry.skip_matrices as u64),
                 )?;
                 Ok(PoseidonParameters {
                     full_rounds: entry.full_rounds,
@@ -149,3 +140,139 @@ impl PoseidonDefaultParametersEntry {
         Self { rate, alpha, full_rounds, partial_rounds, skip_matrices }
     }
 }
+
+/// Returns the ark and mds from the Poseidon Grain LFSR.
+///
+/// If the number of matrices to skip is given, the MDS matrix is the one after the skipped matrices.
+/// Otherwise, the MDS matrix is the first one that qualifies, as in the reference implementation.
+#[allow(clippy::type_complexity)]
+fn find_poseidon_ark_and_mds<F: PrimeField, const RATE: usize>(
+    full_rounds: u64,
+    partial_rounds: u64,
+    skip_matrices: Option<u64>,
+) -> Result<(Vec<Vec<F>>, Vec<Vec<F>>)> {
+    let lfsr_time = start_timer!(|| "LFSR Init");
+    let mut lfsr =
+        PoseidonGrainLFSR::new(false, F::size_in_bits() as u64, (RATE + 1) as u64, full_rounds, partial_rounds);
+    end_timer!(lfsr_time);
+
+    let ark_time = start_timer!(|| "Constructing ARK");
+    let mut ark = Vec::with_capacity((full_rounds + partial_rounds) as usize);
+    for _ in 0..(full_rounds + partial_rounds) {
+        ark.push(lfsr.get_field_elements_rejection_sampling(RATE + 1)?);
+    }
+    end_timer!(ark_time);
+
+    let skip_time = start_timer!(|| "Skipping matrices");
+    if let Some(skip_matrices) = skip_matrices {
+        for _ in 0..skip_matrices {
+            let _ = lfsr.get_field_elements_mod_p::<F>(2 * (RATE + 1))?;
+        }
+    }
+    end_timer!(skip_time);
+
+    // A qualifying matrix must satisfy the following requirements:
+    // - There is no duplication among the elements in x or y.
+    // - There is no i and j such that x[i] + y[j] = p.
+    // - The resultant MDS passes the three checks against invariant subspace trails.
+
+    let mds_time = start_timer!(|| "Construct MDS");
+    let mds = loop {
+        let xs = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
+        let ys = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
+        // Ensure the elements are distinct, and that no sum is zero.
+        let is_distinct = xs.iter().chain(&ys).all_unique();
+        let is_invertible = xs.iter().cartesian_product(&ys).all(|(x, y)| !(*x + y).is_zero());
+        if skip_matrices.is_none() && !(is_distinct && is_invertible) {
+            continue;
+        }
+
+        let mut mds_flattened = vec![F::zero(); (RATE + 1) * (RATE + 1)];
+        for (x, mds_row_i) in xs.iter().take(RATE + 1).zip_eq(mds_flattened.chunks_mut(RATE + 1)) {
+            for (y, e) in ys.iter().take(RATE + 1).zip_eq(mds_row_i) {
+                *e = *x + y;
+            }
+        }
+        serial_batch_inversion_and_mul(&mut mds_flattened, &F::one());
+        let mds = mds_flattened.chunks(RATE + 1).map(|row| row.to_vec()).collect::<Vec<_>>();
+
+        if skip_matrices.is_some() || is_secure_poseidon_mds(&mds) {
+            break mds;
+        }
+    };
+    end_timer!(mds_time);
+
+    Ok((ark, mds))
+}
+
+/// The security level of the generated Poseidon parameters, in bits.
+const SECURITY_LEVEL: f64 = 128.0;
+
+/// Returns the number of full rounds and partial rounds for the given width and S-box exponent,
+/// which minimizes the number of S-boxes, as in the reference implementation.
+fn find_poseidon_round_numbers<F: PrimeField>(width: usize, alpha: u64) -> Result<(u64, u64)> {
+    // Compute `log2(p)` from the most significant limbs of the modulus.
+    let log2_p =
+        F::Parameters::MODULUS.as_ref().iter().rev().fold(0f64, |acc, limb| acc * 2f64.powi(64) + *limb as f64).log2();
+    let n = F::size_in_bits() as f64;
+    let t = width as f64;
+
+    let mut best: Option<(u64, u64)> = None;
+    let mut min_cost = u64::MAX;
+    for partial_rounds in 1..500u64 {
+        // Note: As in the reference implementation, the security margin of a candidate
+        // is carried over to the remaining candidates with the same number of partial rounds.
+        let mut partial_rounds = partial_rounds;
+        for full_rounds in (4..100u64).step_by(2) {
+            if is_secure_round_numbers(log2_p, n, t, full_rounds as f64, partial_rounds as f64, alpha as f64) {
+                // Add the security margin.
+                let full_rounds = full_rounds + 2;
+                partial_rounds = (partial_rounds as f64 * 1.075).ceil() as u64;
+
+                // Minimize the number of S-boxes, preferring fewer full rounds.
+                let cost = width as u64 * full_rounds + partial_rounds;
+                let is_better = match best {
+                    Some((best_full_rounds, _)) => {
+                        cost < min_cost || (cost == min_cost && full_rounds < best_full_rounds)
+                    }
+                    None => true,
+                };
+                if is_better {
+                    best = Some((full_rounds, partial_rounds));
+                    min_cost = cost;
+                }
+            }
+        }
+    }
+
+    match best {
+        Some(round_numbers) => Ok(round_numbers),
+        None => bail!("No secure Poseidon round numbers were found for this field"),
+    }
+}
+
+/// Returns `true` if the given round numbers satisfy the security inequalities against
+/// statistical, interpolation, and Gröbner basis attacks (including the attack of ePrint 2023/537).
+fn is_secure_round_numbers(log2_p: f64, n: f64, t: f64, full_rounds: f64, partial_rounds: f64, alpha: f64) -> bool {
+    let m = SECURITY_LEVEL;
+    let log_alpha_2 = 2f64.ln() / alpha.ln();
+
+    // Statistical attacks.
+    let r_f_1 = if m <= (log2_p - (alpha - 1.0) / 2.0).floor() * (t + 1.0) { 6.0 } else { 10.0 };
+    // Interpolation attacks.
+    let r_f_2 = 1.0 + (log_alpha_2 * m.min(n)).ceil() + (t.ln() / alpha.ln()).ceil() - partial_rounds;
+    // Gröbner basis attacks.
+    let r_f_3 = log_alpha_2 * m.min(log2_p) - partial_rounds;
+    let r_f_4 = t - 1.0 + log_alpha_2 * (m / (t + 1.0)).min(log2_p / 2.0) - partial_rounds;
+    let r_f_5 = (t - 2.0 + m / (2.0 * alpha.log2()) - partial_rounds) / (t - 1.0);
+    let r_f_max = [r_f_1, r_f_2, r_f_3, r_f_4, r_f_5].into_iter().map(f64::ceil).fold(f64::MIN, f64::max);
+
+    // The Gröbner basis attack of ePrint 2023/537, whose cost is `2 * log2(binomial(over, under))`.
+    let r = (t / 3.0).floor();
+    let over = (full_rounds - 1.0) * t + partial_rounds + r + r * (full_rounds / 2.0) + partial_rounds + alpha;
+    let under = r * (full_rounds / 2.0) + partial_rounds + alpha;
+    let log2_binomial = (1..=under as u64).map(|i| ((over - under + i as f64) / i as f64).log2()).sum::<f64>();
+    let cost_gb4 = (2.0 * log2_binomial).ceil();
+
+    full_rounds >= r_f_max && cost_gb4 >= m
+}
@@ -0,0 +1,294 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{FieldParameters, PrimeField};
+use itertools::Itertools;
+
+/// Returns `true` if the given Poseidon MDS matrix passes the checks against invariant subspace trails
+/// of the [reference implementation](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage),
+/// for a capacity of one element.
+///
+/// These are `algorithm_1`, which checks that no subspace of states keeps the S-box of the partial rounds
+/// inactive for up to `t - 1` rounds, as well as `algorithm_2` and `algorithm_3`, which check that the
+/// first unit vector generates the whole state under `M^r` for every `1 <= r <= 4t`.
+pub fn is_secure_poseidon_mds<F: PrimeField>(mds: &[Vec<F>]) -> bool {
+    let width = mds.len();
+    if width == 0 || mds.iter().any(|row| row.len() != width) {
+        return false;
+    }
+
+    // Compute the powers `M^1, ..., M^{4t}`.
+    let mut powers = vec![mds.to_vec()];
+    for _ in 1..4 * width {
+        powers.push(matrix_mul(&powers[powers.len() - 1], mds));
+    }
+
+    has_no_invariant_subspace_trail(&powers, width) && powers.iter().all(|power| is_cyclic(power, width))
+}
+
+/// Returns `true` if the matrix passes `algorithm_1` of the reference implementation, given its powers.
+///
+/// For every `1 <= i < t`, let `S_i` be the subspace of states whose first element stays zero for `i` rounds.
+/// The check fails if `M^i` is a multiple of the identity, if `S_i` contains an eigenvector of `M^i`,
+/// or if `S_i` is invariant under `M^j` for some `j <= i`.
+fn has_no_invariant_subspace_trail<F: PrimeField>(powers: &[Vec<Vec<F>>], width: usize) -> bool {
+    // The linear constraints on the states of `S_i`, which are the first rows of `I, M, ..., M^{i - 1}`.
+    let mut constraints = vec![(0..width).map(|j| if j == 0 { F::one() } else { F::zero() }).collect_vec()];
+
+    for i in 1..width {
+        let power = &powers[i - 1];
+        // Ensure `M^i` is not a multiple of the identity.
+        let is_scalar = power.iter().enumerate().all(|(row_index, row)| {
+            row.iter()
+                .enumerate()
+                .all(|(j, entry)| if row_index == j { *entry == power[0][0] } else { entry.is_zero() })
+        });
+        if is_scalar {
+            return false;
+        }
+
+        let subspace = kernel(&constraints, width);
+        // Ensure the subspace contains no eigenvector of `M^i`.
+        if has_eigenvector_in(power, &subspace, width) {
+            return false;
+        }
+        // Ensure the subspace is not invariant under `M^j`, for any `j <= i`.
+        let is_invariant = |power: &Vec<Vec<F>>| {
+            let image = subspace.iter().map(|vector| matrix_vector_mul(power, vector));
+            rank(&subspace.iter().cloned().chain(image).collect_vec()) == subspace.len()
+        };
+        if powers[..i].iter().any(is_invariant) {
+            return false;
+        }
+
+        constraints.push(power[0].clone());
+    }
+    true
+}
+
+/// Returns `true` if the first unit vector generates the whole state under the matrix,
+/// which is `algorithm_2` of the reference implementation for a capacity of one element.
+fn is_cyclic<F: PrimeField>(matrix: &[Vec<F>], width: usize) -> bool {
+    let mut vector = (0..width).map(|j| if j == 0 { F::one() } else { F::zero() }).collect_vec();
+    let mut vectors = Vec::with_capacity(width);
+    for _ in 0..width {
+        let next = matrix_vector_mul(matrix, &vector);
+        vectors.push(std::mem::replace(&mut vector, next));
+    }
+    rank(&vectors) == width
+}
+
+/// Returns `true` if the subspace spanned by the given independent vectors contains an eigenvector of the matrix,
+/// for an eigenvalue in the field.
+///
+/// A vector `B * a` of the subspace is an eigenvector of eigenvalue `lambda` iff `(M * B - lambda * B) * a = 0`.
+/// A nonzero `a` exists iff every maximal minor of `M * B - lambda * B` vanishes at `lambda`, so the eigenvalues
+/// are the roots in the field of the greatest common divisor of the minors, as polynomials in `lambda`.
+fn has_eigenvector_in<F: PrimeField>(matrix: &[Vec<F>], subspace: &[Vec<F>], width: usize) -> bool {
+    let dimension = subspace.len();
+    if dimension == 0 {
+        return false;
+    }
+    let image = subspace.iter().map(|vector| matrix_vector_mul(matrix, vector)).collect_vec();
+
+    // Each minor has degree at most `dimension`, so it is interpolated from `dimension + 1` evaluations.
+    let points = (0..=dimension as u64).map(F::from).collect_vec();
+    let mut gcd = Vec::new();
+    for rows in (0..width).combinations(dimension) {
+        let evaluations = points
+            .iter()
+            .map(|lambda| {
+                determinant(
+                    rows.iter()
+                        .map(|row| (0..dimension).map(|col| image[col][*row] - *lambda * subspace[col][*row]).collect())
+                        .collect(),
+                )
+            })
+            .collect_vec();
+        gcd = poly_gcd(gcd, interpolate(&points, &evaluations));
+    }
+
+    match gcd.len() {
+        // Every `lambda` is an eigenvalue.
+        0 => true,
+        // No `lambda` is an eigenvalue.
+        1 => false,
+        _ => has_root(&gcd),
+    }
+}
+
+/// Returns `true` if the given polynomial of positive degree has a root in the field,
+/// which is the case iff it shares a factor with `x^p - x`.
+fn has_root<F: PrimeField>(polynomial: &[F]) -> bool {
+    // Compute `x^p mod f`, from the most significant bit of `p`.
+    let x = vec![F::zero(), F::one()];
+    let mut power = vec![F::one()];
+    for limb in F::Parameters::MODULUS.as_ref().iter().rev() {
+        for i in (0..64).rev() {
+            power = poly_rem(poly_mul(&power, &power), polynomial);
+            if (limb >> i) & 1 == 1 {
+                power = poly_rem(poly_mul(&power, &x), polynomial);
+            }
+        }
+    }
+    // Compute `gcd(f, x^p - x)`.
+    let difference = trim(
+        (0..power.len().max(2))
+            .map(|i| power.get(i).copied().unwrap_or_else(F::zero) - x.get(i).copied().unwrap_or_else(F::zero)),
+    );
+    poly_gcd(polynomial.to_vec(), difference).len() > 1
+}
+
+/// Returns the product of the two matrices.
+fn matrix_mul<F: PrimeField>(a: &[Vec<F>], b: &[Vec<F>]) -> Vec<Vec<F>> {
+    a.iter()
+        .map(|row| (0..b[0].len()).map(|j| row.iter().zip_eq(b).map(|(x, b_row)| *x * b_row[j]).sum()).collect())
+        .collect()
+}
+
+/// Returns the product of the matrix and the vector.
+fn matrix_vector_mul<F: PrimeField>(matrix: &[Vec<F>], vector: &[F]) -> Vec<F> {
+    matrix.iter().map(|row| row.iter().zip_eq(vector).map(|(x, y)| *x * y).sum()).collect()
+}
+
+/// Returns the nonzero rows of the reduced row echelon form of the given rows, and their pivot columns.
+fn row_reduce<F: PrimeField>(rows: &[Vec<F>]) -> (Vec<Vec<F>>, Vec<usize>) {
+    let mut rows = rows.to_vec();
+    let mut pivots = Vec::new();
+    let num_columns = rows.first().map_or(0, |row| row.len());
+    for column in 0..num_columns {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..rows.len()).find(|i| !rows[*i][column].is_zero()) else { continue };
+        rows.swap(rank, pivot);
+        let inverse = rows[rank][column].inverse().unwrap();
+        rows[rank].iter_mut().for_each(|entry| *entry *= inverse);
+        // Eliminate the column from every other row.
+        let pivot_row = rows[rank].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+            if i != rank && !factor.is_zero() {
+                row.iter_mut().zip_eq(&pivot_row).for_each(|(entry, pivot_entry)| *entry -= factor * pivot_entry);
+            }
+        }
+        pivots.push(column);
+    }
+    rows.truncate(pivots.len());
+    (rows, pivots)
+}
+
+/// Returns the dimension of the subspace spanned by the given vectors.
+fn rank<F: PrimeField>(vectors: &[Vec<F>]) -> usize {
+    row_reduce(vectors).1.len()
+}
+
+/// Returns a basis of the vectors of the given width that are orthogonal to every given row.
+fn kernel<F: PrimeField>(rows: &[Vec<F>], width: usize) -> Vec<Vec<F>> {
+    let (reduced, pivots) = row_reduce(rows);
+    (0..width)
+        .filter(|column| !pivots.contains(column))
+        .map(|free| {
+            let mut vector = vec![F::zero(); width];
+            vector[free] = F::one();
+            for (row, pivot) in reduced.iter().zip_eq(&pivots) {
+                vector[*pivot] = -row[free];
+            }
+            vector
+        })
+        .collect()
+}
+
+/// Returns the determinant of the given square matrix.
+fn determinant<F: PrimeField>(mut matrix: Vec<Vec<F>>) -> F {
+    let mut determinant = F::one();
+    for column in 0..matrix.len() {
+        let Some(pivot) = (column..matrix.len()).find(|i| !matrix[*i][column].is_zero()) else { return F::zero() };
+        if pivot != column {
+            matrix.swap(column, pivot);
+            determinant = -determinant;
+        }
+        determinant *= matrix[column][column];
+        let inverse = matrix[column][column].inverse().unwrap();
+        // Eliminate the column from the rows below.
+        let pivot_row = matrix[column].clone();
+        for row in matrix.iter_mut().skip(column + 1) {
+            let factor = row[column] * inverse;
+            row.iter_mut().zip_eq(&pivot_row).for_each(|(entry, pivot_entry)| *entry -= factor * pivot_entry);
+        }
+    }
+    determinant
+}
+
+/// Returns the polynomial without its leading zero coefficients.
+fn trim<F: PrimeField>(coefficients: impl IntoIterator<Item = F>) -> Vec<F> {
+    let mut coefficients = coefficients.into_iter().collect_vec();
+    while coefficients.last().map_or(false, |coefficient| coefficient.is_zero()) {
+        coefficients.pop();
+    }
+    coefficients
+}
+
+/// Returns the product of the two polynomials, whose coefficients are in increasing order of degree.
+fn poly_mul<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] += *x * y;
+        }
+    }
+    trim(product)
+}
+
+/// Returns the remainder of the division of `a` by the nonzero polynomial `b`.
+fn poly_rem<F: PrimeField>(a: Vec<F>, b: &[F]) -> Vec<F> {
+    let mut remainder = trim(a);
+    let leading_inverse = b[b.len() - 1].inverse().unwrap();
+    while remainder.len() >= b.len() {
+        let factor = remainder[remainder.len() - 1] * leading_inverse;
+        let shift = remainder.len() - b.len();
+        remainder[shift..].iter_mut().zip_eq(b).for_each(|(coefficient, b)| *coefficient -= factor * b);
+        remainder = trim(remainder);
+    }
+    remainder
+}
+
+/// Returns the greatest common divisor of the two polynomials, up to a constant factor.
+fn poly_gcd<F: PrimeField>(mut a: Vec<F>, mut b: Vec<F>) -> Vec<F> {
+    while !b.is_empty() {
+        let remainder = poly_rem(a, &b);
+        a = std::mem::replace(&mut b, remainder);
+    }
+    a
+}
+
+/// Returns the polynomial of degree less than the number of points that has the given evaluations.
+fn interpolate<F: PrimeField>(points: &[F], evaluations: &[F]) -> Vec<F> {
+    let mut polynomial = vec![F::zero(); points.len()];
+    for (i, (x_i, y_i)) in points.iter().zip_eq(evaluations).enumerate() {
+        // Compute the Lagrange basis polynomial of `x_i`, scaled by `y_i`.
+        let mut basis = vec![F::one()];
+        let mut denominator = F::one();
+        for (j, x_j) in points.iter().enumerate() {
+            if i != j {
+                basis = poly_mul(&basis, &[-*x_j, F::one()]);
+                denominator *= *x_i - x_j;
+            }
+        }
+        let scale = *y_i * denominator.inverse().unwrap();
+        polynomial.iter_mut().zip(basis).for_each(|(coefficient, basis)| *coefficient += scale * basis);
+    }
+    trim(polynomial)
+}
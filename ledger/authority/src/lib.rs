@@ -20,7 +20,7 @@ mod serialize;
 mod string;
 
 use console::{
-    account::{Address, PrivateKey, Signature},
+    account::{Address, Signature, Signer},
     network::Network,
     prelude::{
         de,
@@ -60,14 +60,10 @@ pub enum Authority<N: Network> {
 }
 
 impl<N: Network> Authority<N> {
-    /// Initializes a new beacon authority.
-    pub fn new_beacon<R: Rng + CryptoRng>(
-        private_key: &PrivateKey<N>,
-        block_hash: Field<N>,
-        rng: &mut R,
-    ) -> Result<Self> {
+    /// Initializes a new beacon authority, signing the block hash with the given signer.
+    pub fn new_beacon<S: Signer<N>, R: Rng + CryptoRng>(signer: &S, block_hash: Field<N>, rng: &mut R) -> Result<Self> {
         // Sign the block hash.
-        let signature = private_key.sign(&[block_hash], rng)?;
+        let signature = signer.sign(&[block_hash], rng)?;
         // Return the beacon authority.
         Ok(Self::Beacon(signature))
     }
@@ -117,13 +113,16 @@ impl<N: Network> Authority<N> {
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod test_helpers {
     use super::*;
-    use console::prelude::{TestRng, Uniform};
+    use console::{
+        account::PrivateKey,
+        prelude::{TestRng, Uniform},
+    };
 
     pub type CurrentNetwork = console::network::Testnet3;
 
     /// Returns a sample beacon authority.
     pub fn sample_beacon_authority(rng: &mut TestRng) -> Authority<CurrentNetwork> {
-        Authority::new_beacon(&PrivateKey::new(rng).unwrap(), Field::rand(rng), rng).unwrap()
+        Authority::new_beacon(&PrivateKey::<CurrentNetwork>::new(rng).unwrap(), Field::rand(rng), rng).unwrap()
     }
 
     /// Returns a sample quorum authority.
@@ -149,4 +149,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_limits() -> Result<()> {
+        // Load the genesis block.
+        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let expected_bytes = genesis_block.to_bytes_le()?;
+
+        // Check that the block is read within the network limits.
+        assert_eq!(genesis_block, Block::read_le_with_limits(&expected_bytes[..], DeserializationLimits::NETWORK)?);
+
+        // Check that the block is rejected if it exceeds the byte budget.
+        let limits = DeserializationLimits::new(DeserializationLimits::NETWORK.max_elements, expected_bytes.len() - 1);
+        assert!(Block::<CurrentNetwork>::read_le_with_limits(&expected_bytes[..], limits).is_err());
+        assert!(Block::<CurrentNetwork>::from_bytes_le_with_limits(&expected_bytes, limits).is_err());
+
+        Ok(())
+    }
 }
@@ -23,6 +23,9 @@ pub use header::*;
 mod helpers;
 pub use helpers::*;
 
+#[cfg(any(test, feature = "prop-tests"))]
+pub mod prop_tests;
+
 pub mod ratifications;
 pub use ratifications::*;
 
@@ -45,7 +48,7 @@ mod string;
 mod verify;
 
 use console::{
-    account::PrivateKey,
+    account::Signer,
     network::prelude::*,
     program::{Ciphertext, Record},
     types::{Field, Group, U64},
@@ -78,9 +81,9 @@ pub struct Block<N: Network> {
 
 impl<N: Network> Block<N> {
     /// Initializes a new beacon block from the given previous block hash, block header,
-    /// ratifications, solutions, transactions, and aborted transaction IDs.
-    pub fn new_beacon<R: Rng + CryptoRng>(
-        private_key: &PrivateKey<N>,
+    /// ratifications, solutions, transactions, and aborted transaction IDs, signed by the given signer.
+    pub fn new_beacon<S: Signer<N>, R: Rng + CryptoRng>(
+        signer: &S,
         previous_hash: N::BlockHash,
         header: Header<N>,
         ratifications: Ratifications<N>,
@@ -92,7 +95,7 @@ impl<N: Network> Block<N> {
         // Compute the block hash.
         let block_hash = N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?;
         // Construct the beacon authority.
-        let authority = Authority::new_beacon(private_key, block_hash, rng)?;
+        let authority = Authority::new_beacon(signer, block_hash, rng)?;
         // Construct the block.
         Self::from(previous_hash, header, authority, ratifications, solutions, transactions, aborted_transaction_ids)
     }
@@ -603,7 +606,7 @@ pub mod test_helpers {
     ) -> (Block<CurrentNetwork>, Transaction<CurrentNetwork>, PrivateKey<CurrentNetwork>) {
         // Sample the genesis private key.
         let private_key = PrivateKey::new(rng).unwrap();
-        let address = Address::<CurrentNetwork>::try_from(private_key).unwrap();
+        let address = Address::<CurrentNetwork>::try_from(&private_key).unwrap();
 
         // Prepare the locator.
         let locator = ("credits.aleo", "transfer_public_to_private");
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::{prop_tests::any_record_with_randomizer, Identifier, ProgramID};
+
+use proptest::{
+    collection::vec,
+    prelude::{any, Arbitrary, BoxedStrategy, Strategy},
+};
+
+/// The maximum number of record inputs and record outputs in an arbitrary transition.
+const MAX_TRANSITION_RECORDS: usize = 4;
+/// The maximum number of transitions in an arbitrary execution.
+const MAX_EXECUTION_TRANSITIONS: usize = 2;
+
+/// Returns a strategy for transitions that consume and produce records.
+/// The transition ID and output checksums are computed from the sampled values, so the transition is well-formed,
+/// although it does not carry a proof.
+pub fn any_transition<N: Network>() -> BoxedStrategy<Transition<N>> {
+    (
+        vec((any::<Field<N>>(), any::<Field<N>>()), 1..=MAX_TRANSITION_RECORDS),
+        vec(any_record_with_randomizer::<N>(), 1..=MAX_TRANSITION_RECORDS),
+        any::<Group<N>>(),
+        any::<Field<N>>(),
+    )
+        .prop_map(|(inputs, records, tpk, tcm)| {
+            let program_id = ProgramID::from_str("token.aleo").unwrap();
+            let function_name = Identifier::from_str("transfer").unwrap();
+            let record_name = Identifier::from_str("token").unwrap();
+
+            let inputs = inputs.into_iter().map(|(serial_number, tag)| Input::Record(serial_number, tag)).collect();
+            let outputs = records
+                .into_iter()
+                .map(|(record, randomizer)| {
+                    let commitment = record.to_commitment(&program_id, &record_name).unwrap();
+                    let record = record.encrypt(randomizer).unwrap();
+                    let checksum = N::hash_bhp1024(&record.to_bits_le()).unwrap();
+                    Output::Record(commitment, checksum, Some(record))
+                })
+                .collect();
+            Transition::new(program_id, function_name, inputs, outputs, tpk, tcm).unwrap()
+        })
+        .boxed()
+}
+
+impl<N: Network> Arbitrary for Transaction<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Transaction<N>>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (vec(any_transition::<N>(), 1..=MAX_EXECUTION_TRANSITIONS), any::<Field<N>>())
+            .prop_map(|(transitions, global_state_root)| {
+                let execution = Execution::from(transitions.into_iter(), global_state_root.into(), None).unwrap();
+                Transaction::from_execution(execution, None).unwrap()
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(8))]
+
+        #[test]
+        fn test_arbitrary_transaction(transaction in any::<Transaction<CurrentNetwork>>()) {
+            // Check that the transaction ID matches its contents.
+            let execution = transaction.execution().unwrap();
+            let expected = Transaction::from_execution(execution.clone(), None).unwrap();
+            prop_assert_eq!(transaction.id(), expected.id());
+
+            // Check that every input and output is well-formed.
+            for transition in transaction.transitions() {
+                let function_id = Field::zero();
+                for (index, input) in transition.inputs().iter().enumerate() {
+                    prop_assert!(input.verify(function_id, transition.tcm(), index));
+                }
+                for (index, output) in transition.outputs().iter().enumerate() {
+                    prop_assert!(output.verify(function_id, transition.tcm(), index));
+                }
+            }
+
+            // Check that the transaction round-trips through its byte representation.
+            let bytes = transaction.to_bytes_le().unwrap();
+            prop_assert_eq!(transaction, Transaction::read_le(&bytes[..]).unwrap());
+        }
+    }
+}
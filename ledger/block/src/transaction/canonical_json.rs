@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde_json::Value;
+
+impl<N: Network> CanonicalJson for Transaction<N> {
+    const TYPE: &'static str = "transaction";
+    const VERSION: u8 = 1;
+
+    /// Returns the JSON of the transaction, in which the IDs, keys, and proofs retain their string encodings.
+    fn to_json_body(&self) -> Result<Value> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    /// Returns the transaction from its JSON, via the `serde` encoding of the transaction.
+    ///
+    /// Note: This rebuilds the transaction from its contents, and rejects a mismatching transaction ID,
+    /// but it does not verify the transaction (e.g. its proofs or fee).
+    fn from_json_body(body: Value) -> Result<Self> {
+        Ok(serde_json::from_value(body)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_canonical_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+        ]
+        .into_iter()
+        {
+            // Check the round trip.
+            let candidate = expected.to_canonical_json()?;
+            assert!(candidate.starts_with(r#"{"body":{"#));
+            assert!(candidate.ends_with(r#"},"type":"transaction","version":1}"#));
+            assert_eq!(expected, Transaction::from_canonical_json(&candidate)?);
+
+            // Check that the serde JSON, whose keys are not sorted, is rejected.
+            let json = format!(r#"{{"body":{expected},"type":"transaction","version":1}}"#);
+            assert_ne!(candidate, json);
+            assert!(Transaction::<CurrentNetwork>::from_canonical_json(&json).is_err());
+
+            // Check that a mismatching transaction ID is rejected.
+            let id = <CurrentNetwork as Network>::TransactionID::default();
+            let json = candidate.replace(&expected.id().to_string(), &id.to_string());
+            assert_ne!(candidate, json);
+            assert!(Transaction::<CurrentNetwork>::from_canonical_json(&json).is_err());
+        }
+        Ok(())
+    }
+}
@@ -233,7 +233,7 @@ pub mod test_helpers {
         // Retrieve a credits record.
         let credits = transaction.records().next().unwrap().1.clone();
         // Decrypt the record.
-        let credits = credits.decrypt(&private_key.try_into().unwrap()).unwrap();
+        let credits = credits.decrypt(&(&private_key).try_into().unwrap()).unwrap();
         // Sample a base fee in microcredits.
         let base_fee_in_microcredits = 10_000_000;
         // Sample a priority fee in microcredits.
@@ -22,9 +22,10 @@ use console::{
     network::{Network, Testnet3},
 };
 use snarkvm_ledger_coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, PuzzleConfig};
+use snarkvm_utilities::SeededRng;
 
 use criterion::Criterion;
-use rand::{self, thread_rng, CryptoRng, RngCore};
+use rand::{self, CryptoRng, RngCore};
 
 type CoinbasePuzzleInst = CoinbasePuzzle<Testnet3>;
 
@@ -43,7 +44,7 @@ fn sample_epoch_challenge(degree: u32, rng: &mut (impl CryptoRng + RngCore)) ->
 
 fn sample_address_and_nonce(rng: &mut (impl CryptoRng + RngCore)) -> (Address<Testnet3>, u64) {
     let private_key = PrivateKey::new(rng).unwrap();
-    let address = Address::try_from(private_key).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
     let nonce = rng.next_u64();
     (address, nonce)
 }
@@ -65,7 +66,7 @@ fn coinbase_puzzle_trim(c: &mut Criterion) {
 
 #[cfg(feature = "setup")]
 fn coinbase_puzzle_prove(c: &mut Criterion) {
-    let rng = &mut thread_rng();
+    let rng = &mut SeededRng::default();
 
     let max_degree = 1 << 15;
     let max_config = PuzzleConfig { degree: max_degree };
@@ -84,7 +85,7 @@ fn coinbase_puzzle_prove(c: &mut Criterion) {
 
 #[cfg(feature = "setup")]
 fn coinbase_puzzle_verify(c: &mut Criterion) {
-    let rng = &mut thread_rng();
+    let rng = &mut SeededRng::default();
 
     let max_degree = 1 << 15;
     let max_config = PuzzleConfig { degree: max_degree };
@@ -55,7 +55,7 @@ pub(super) mod tests {
         let mut prover_solutions = vec![];
         for _ in 0..rng.gen_range(1..10) {
             let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-            let address = Address::try_from(private_key).unwrap();
+            let address = Address::try_from(&private_key).unwrap();
 
             let partial_solution = PartialSolution::new(address, u64::rand(rng), KZGCommitment(rng.gen()));
             prover_solutions.push(ProverSolution::new(partial_solution, KZGProof { w: rng.gen(), random_v: None }));
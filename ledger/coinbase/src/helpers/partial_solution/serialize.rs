@@ -60,7 +60,7 @@ mod tests {
     fn test_serde_json() -> Result<()> {
         let mut rng = TestRng::default();
         let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-        let address = Address::try_from(private_key)?;
+        let address = Address::try_from(&private_key)?;
 
         // Sample a new partial solution.
         let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
@@ -81,7 +81,7 @@ mod tests {
     fn test_bincode() -> Result<()> {
         let mut rng = TestRng::default();
         let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-        let address = Address::try_from(private_key)?;
+        let address = Address::try_from(&private_key)?;
 
         // Sample a new partial solution.
         let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
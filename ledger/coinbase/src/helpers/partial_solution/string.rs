@@ -48,7 +48,7 @@ mod tests {
     fn test_string() -> Result<()> {
         let mut rng = TestRng::default();
         let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
-        let address = Address::try_from(private_key)?;
+        let address = Address::try_from(&private_key)?;
 
         // Sample a new partial solution.
         let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
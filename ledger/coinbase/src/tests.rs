@@ -14,7 +14,7 @@
 
 use super::*;
 use console::{account::*, network::Testnet3};
-use snarkvm_utilities::Uniform;
+use snarkvm_utilities::{SeededRng, Uniform};
 
 use rand::RngCore;
 
@@ -38,7 +38,7 @@ fn test_coinbase_puzzle() {
             let solutions = (0..batch_size)
                 .map(|_| {
                     let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
-                    let address = Address::try_from(private_key).unwrap();
+                    let address = Address::try_from(&private_key).unwrap();
                     let nonce = u64::rand(&mut rng);
                     puzzle.prove(&epoch_challenge, address, nonce, None).unwrap()
                 })
@@ -68,7 +68,7 @@ fn test_prover_solution_minimum_target() {
 
         for _ in 0..ITERATIONS {
             let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
-            let address = Address::try_from(private_key).unwrap();
+            let address = Address::try_from(&private_key).unwrap();
             let nonce = u64::rand(&mut rng);
 
             let solution = puzzle.prove(&epoch_challenge, address, nonce, None).unwrap();
@@ -85,7 +85,7 @@ fn test_prover_solution_minimum_target() {
 
 #[test]
 fn test_edge_case_for_degree() {
-    let mut rng = rand::thread_rng();
+    let mut rng = SeededRng::default();
 
     // Generate srs.
     let max_degree = 1 << 15;
@@ -98,7 +98,7 @@ fn test_edge_case_for_degree() {
 
     // Generate proof inputs
     let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
-    let address = Address::try_from(private_key).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
     let epoch_challenge = EpochChallenge::new(rng.gen(), Default::default(), degree).unwrap();
 
     // Generate a prover solution.
@@ -113,12 +113,12 @@ fn test_edge_case_for_degree() {
 fn test_profiler() -> Result<()> {
     fn sample_address_and_nonce(rng: &mut (impl CryptoRng + RngCore)) -> (Address<Testnet3>, u64) {
         let private_key = PrivateKey::new(rng).unwrap();
-        let address = Address::try_from(private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
         let nonce = rng.next_u64();
         (address, nonce)
     }
 
-    let mut rng = rand::thread_rng();
+    let mut rng = SeededRng::default();
 
     // Generate srs.
     let max_degree = 1 << 15;
@@ -228,7 +228,7 @@ impl<N: Network> Committee<N> {
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod test_helpers {
     use super::*;
-    use console::{account::Address, prelude::TestRng};
+    use console::account::Address;
 
     use indexmap::IndexMap;
     use rand_distr::{Distribution, Exp};
@@ -236,7 +236,7 @@ pub mod test_helpers {
     type CurrentNetwork = console::network::Testnet3;
 
     /// Samples a list of random committees.
-    pub fn sample_committees(rng: &mut TestRng) -> Vec<Committee<CurrentNetwork>> {
+    pub fn sample_committees(rng: &mut (impl Rng + CryptoRng)) -> Vec<Committee<CurrentNetwork>> {
         // Sample the number of committees.
         let num_committees = rng.gen_range(10..=100);
         // Sample the committees.
@@ -244,12 +244,12 @@ pub mod test_helpers {
     }
 
     /// Samples a random committee.
-    pub fn sample_committee(rng: &mut TestRng) -> Committee<CurrentNetwork> {
+    pub fn sample_committee(rng: &mut (impl Rng + CryptoRng)) -> Committee<CurrentNetwork> {
         sample_committee_for_round(1, rng)
     }
 
     /// Samples a random committee for a given round.
-    pub fn sample_committee_for_round(round: u64, rng: &mut TestRng) -> Committee<CurrentNetwork> {
+    pub fn sample_committee_for_round(round: u64, rng: &mut (impl Rng + CryptoRng)) -> Committee<CurrentNetwork> {
         sample_committee_for_round_and_size(round, 4, rng)
     }
 
@@ -257,7 +257,7 @@ pub mod test_helpers {
     pub fn sample_committee_for_round_and_size(
         round: u64,
         num_members: u16,
-        rng: &mut TestRng,
+        rng: &mut (impl Rng + CryptoRng),
     ) -> Committee<CurrentNetwork> {
         // Sample the members.
         let mut members = IndexMap::new();
@@ -273,7 +273,7 @@ pub mod test_helpers {
     pub fn sample_committee_for_round_and_members(
         round: u64,
         members: Vec<Address<CurrentNetwork>>,
-        rng: &mut TestRng,
+        rng: &mut (impl Rng + CryptoRng),
     ) -> Committee<CurrentNetwork> {
         // Sample the members.
         let mut committee_members = IndexMap::new();
@@ -287,7 +287,7 @@ pub mod test_helpers {
 
     /// Samples a random committee.
     #[allow(clippy::cast_possible_truncation)]
-    pub fn sample_committee_custom(num_members: u16, rng: &mut TestRng) -> Committee<CurrentNetwork> {
+    pub fn sample_committee_custom(num_members: u16, rng: &mut (impl Rng + CryptoRng)) -> Committee<CurrentNetwork> {
         assert!(num_members >= 4);
         // Set the maximum amount staked in the node.
         const MAX_STAKE: u64 = 100_000_000_000_000;
@@ -318,7 +318,7 @@ pub mod test_helpers {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::prelude::TestRng;
+    use console::prelude::SeededRng;
 
     use parking_lot::RwLock;
     use rayon::prelude::*;
@@ -364,7 +364,7 @@ mod tests {
     #[test]
     fn test_get_leader_distribution_simple() {
         // Initialize the RNG.
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         // Set the number of rounds.
         const NUM_ROUNDS: u64 = 256 * 100;
         // Sample a committee.
@@ -376,7 +376,7 @@ mod tests {
     #[test]
     fn test_get_leader_distribution() {
         // Initialize the RNG.
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         // Set the number of rounds.
         const NUM_ROUNDS: u64 = 256 * 2_000;
         // Sample the number of members.
@@ -390,7 +390,7 @@ mod tests {
     #[test]
     fn test_sorted_members() {
         // Initialize the RNG.
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         // Sample a committee.
         let committee = crate::test_helpers::sample_committee_custom(200, rng);
 
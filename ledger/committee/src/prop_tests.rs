@@ -111,7 +111,7 @@ impl Default for ValidatorSet {
                 .map(|i| {
                     let rng = &mut rand_chacha::ChaChaRng::seed_from_u64(i);
                     let private_key = PrivateKey::new(rng).unwrap();
-                    let address = Address::try_from(private_key).unwrap();
+                    let address = Address::try_from(&private_key).unwrap();
                     Validator { private_key, address, stake: MIN_VALIDATOR_STAKE, is_open: false }
                 })
                 .collect(),
@@ -132,7 +132,7 @@ impl Arbitrary for ValidatorSet {
 pub fn any_valid_validator() -> BoxedStrategy<Validator> {
     (MIN_VALIDATOR_STAKE..100_000_000_000_000, any_valid_private_key(), any::<bool>())
         .prop_map(|(stake, private_key, is_open)| {
-            let address = Address::try_from(private_key).unwrap();
+            let address = Address::try_from(&private_key).unwrap();
             Validator { private_key, address, stake, is_open }
         })
         .boxed()
@@ -161,7 +161,7 @@ fn too_low_stake_committee() -> BoxedStrategy<Result<Committee<CurrentNetwork>>>
 fn invalid_stake_validator() -> BoxedStrategy<Validator> {
     (0..MIN_VALIDATOR_STAKE, any_valid_private_key(), any::<bool>())
         .prop_map(|(stake, private_key, is_open)| {
-            let address = Address::try_from(private_key).unwrap();
+            let address = Address::try_from(&private_key).unwrap();
             Validator { private_key, address, stake, is_open }
         })
         .boxed()
@@ -32,7 +32,8 @@ impl<N: Network> FromBytes for BatchCertificate<N> {
             // Read the number of signatures.
             let num_signatures = u32::read_le(&mut reader)?;
             // Read the signatures.
-            let mut signatures = IndexMap::with_capacity(num_signatures as usize);
+            let mut signatures =
+                IndexMap::with_capacity(bounded_capacity::<(Signature<N>, i64)>(num_signatures as usize));
             for _ in 0..num_signatures {
                 // Read the signature.
                 let signature = Signature::read_le(&mut reader)?;
@@ -21,7 +21,7 @@ mod string;
 mod to_id;
 
 use console::{
-    account::{Address, PrivateKey, Signature},
+    account::{Address, Signature, Signer},
     prelude::*,
     types::Field,
 };
@@ -58,9 +58,9 @@ impl<N: Network> BatchHeader<N> {
 }
 
 impl<N: Network> BatchHeader<N> {
-    /// Initializes a new batch header.
-    pub fn new<R: Rng + CryptoRng>(
-        private_key: &PrivateKey<N>,
+    /// Initializes a new batch header, signed by the given signer.
+    pub fn new<S: Signer<N>, R: Rng + CryptoRng>(
+        signer: &S,
         round: u64,
         timestamp: i64,
         transmission_ids: IndexSet<TransmissionID<N>>,
@@ -74,11 +74,11 @@ impl<N: Network> BatchHeader<N> {
             _ => ensure!(!previous_certificate_ids.is_empty(), "Invalid round number, must have certificates"),
         }
         // Retrieve the address.
-        let author = Address::try_from(private_key)?;
+        let author = signer.address()?;
         // Compute the batch ID.
         let batch_id = Self::compute_batch_id(author, round, timestamp, &transmission_ids, &previous_certificate_ids)?;
         // Sign the preimage.
-        let signature = private_key.sign(&[batch_id], rng)?;
+        let signature = signer.sign(&[batch_id], rng)?;
         // Return the batch header.
         Ok(Self { author, batch_id, round, timestamp, transmission_ids, previous_certificate_ids, signature })
     }
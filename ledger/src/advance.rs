@@ -44,10 +44,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         )
     }
 
-    /// Returns a candidate for the next block in the ledger.
-    pub fn prepare_advance_to_next_beacon_block<R: Rng + CryptoRng>(
+    /// Returns a candidate for the next block in the ledger, signed by the given signer.
+    pub fn prepare_advance_to_next_beacon_block<S: Signer<N>, R: Rng + CryptoRng>(
         &self,
-        private_key: &PrivateKey<N>,
+        signer: &S,
         candidate_ratifications: Vec<Ratify<N>>,
         candidate_solutions: Vec<ProverSolution<N>>,
         candidate_transactions: Vec<Transaction<N>>,
@@ -70,7 +70,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         // Construct the new beacon block.
         Block::new_beacon(
-            private_key,
+            signer,
             previous_block.hash(),
             header,
             ratifications,
@@ -19,6 +19,18 @@ use rand::{rngs::StdRng, SeedableRng};
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
     pub fn check_next_block<R: CryptoRng + Rng>(&self, block: &Block<N>, rng: &mut R) -> Result<()> {
+        self.check_next_block_internal(block, false, rng)
+    }
+
+    /// Checks the given block is valid next block. If `are_proofs_verified` is `true`, the transaction IDs,
+    /// fees, and proofs must already have been verified with `VM::check_transaction_proofs`, and only the
+    /// transaction state is checked here.
+    pub(crate) fn check_next_block_internal<R: CryptoRng + Rng>(
+        &self,
+        block: &Block<N>,
+        are_proofs_verified: bool,
+        rng: &mut R,
+    ) -> Result<()> {
         let height = block.height();
 
         // Ensure the block hash does not already exist.
@@ -45,8 +57,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let transactions = block.transactions().iter().collect::<Vec<_>>();
         let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
         cfg_iter!(transactions).zip(rngs).try_for_each(|(transaction, mut rng)| {
-            self.check_transaction_basic(*transaction, transaction.to_rejected_id()?, &mut rng)
-                .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
+            match are_proofs_verified {
+                true => self.vm().check_transaction_state(transaction),
+                false => self.check_transaction_basic(*transaction, transaction.to_rejected_id()?, &mut rng),
+            }
+            .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
         })?;
 
         // TODO (howardwu): Remove this after moving the total supply into credits.aleo.
@@ -73,7 +73,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             };
 
             // Determine whether to decrypt this record (or not), based on the filter.
-            let commitment = match filter {
+            let commitment = match &filter {
                 RecordsFilter::All => Ok(Some(commitment)),
                 RecordsFilter::Spent => Record::<N, Plaintext<N>>::tag(sk_tag, commitment).and_then(|tag| {
                     // Determine if the record is spent.
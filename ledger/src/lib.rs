@@ -41,12 +41,14 @@ mod contains;
 mod find;
 mod get;
 mod iterators;
+mod pipeline;
+pub use pipeline::*;
 
 #[cfg(test)]
 mod tests;
 
 use console::{
-    account::{Address, GraphKey, PrivateKey, ViewKey},
+    account::{Address, GraphKey, PrivateKey, Signer, ViewKey},
     network::prelude::*,
     program::{Ciphertext, Entry, Identifier, Literal, Plaintext, ProgramID, Record, StatePath, Value},
     types::{Field, Group},
@@ -77,7 +79,7 @@ use rayon::prelude::*;
 
 pub type RecordMap<N> = IndexMap<Field<N>, Record<N, Plaintext<N>>>;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub enum RecordsFilter<N: Network> {
     /// Returns all records associated with the account.
     All,
@@ -421,7 +423,7 @@ pub(crate) mod test_helpers {
         let view_key = ViewKey::try_from(&private_key).unwrap();
         let address = Address::try_from(&private_key).unwrap();
         // Sample the ledger.
-        let ledger = sample_ledger(private_key, rng);
+        let ledger = sample_ledger(&private_key, rng);
         // Return the test environment.
         TestEnv { ledger, private_key, view_key, address }
     }
@@ -431,13 +433,13 @@ pub(crate) mod test_helpers {
     }
 
     pub(crate) fn sample_ledger(
-        private_key: PrivateKey<CurrentNetwork>,
+        private_key: &PrivateKey<CurrentNetwork>,
         rng: &mut (impl Rng + CryptoRng),
     ) -> CurrentLedger {
         // Initialize the store.
         let store = CurrentConsensusStore::open(None).unwrap();
         // Create a genesis block.
-        let genesis = VM::from(store).unwrap().genesis_beacon(&private_key, rng).unwrap();
+        let genesis = VM::from(store).unwrap().genesis_beacon(private_key, rng).unwrap();
         // Initialize the ledger with the genesis block.
         let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
         // Ensure the genesis block is correct.
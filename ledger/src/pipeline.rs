@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use rand::{rngs::StdRng, SeedableRng};
+use std::{
+    sync::mpsc::{sync_channel, Receiver, SyncSender},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The configuration of the block validation pipeline.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PipelineConfig {
+    /// The maximum number of blocks buffered between two stages.
+    /// A stage blocks once its output queue is full, which bounds the memory held by the pipeline.
+    pub queue_depth: usize,
+}
+
+impl Default for PipelineConfig {
+    /// Returns the default configuration, which buffers up to 4 blocks between stages.
+    fn default() -> Self {
+        Self { queue_depth: 4 }
+    }
+}
+
+/// The metrics of a single pipeline stage.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StageMetrics {
+    /// The number of blocks processed by the stage.
+    pub num_blocks: u64,
+    /// The time spent processing blocks.
+    pub busy_time: Duration,
+    /// The time spent waiting for input from the previous stage.
+    pub input_wait_time: Duration,
+    /// The time spent waiting for the next stage to accept output, due to back-pressure.
+    pub output_wait_time: Duration,
+}
+
+/// The metrics of a block validation pipeline run.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PipelineMetrics {
+    /// The deserialization stage, which also verifies the batch certificates of quorum blocks.
+    pub deserialize: StageMetrics,
+    /// The proof stage, which verifies the transaction IDs, fees, and proofs.
+    pub proofs: StageMetrics,
+    /// The ledger stage, which checks each block, including its signature, against the ledger state,
+    /// and advances the ledger.
+    pub ledger: StageMetrics,
+}
+
+/// A block in flight, with the flag indicating whether its proofs were verified.
+type Item<N> = Result<(Block<N>, bool)>;
+
+/// Receives the next item, recording the time spent waiting for it.
+fn receive<T>(receiver: &Receiver<T>, metrics: &mut StageMetrics) -> Option<T> {
+    let start = Instant::now();
+    let item = receiver.recv().ok();
+    metrics.input_wait_time += start.elapsed();
+    item
+}
+
+/// Sends the item, recording the time spent waiting for capacity. Returns `false` if the next stage has stopped.
+fn send<T>(sender: &SyncSender<T>, item: T, metrics: &mut StageMetrics) -> bool {
+    let start = Instant::now();
+    let is_sent = sender.send(item).is_ok();
+    metrics.output_wait_time += start.elapsed();
+    is_sent
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Validates and adds the given serialized blocks to the ledger, in order.
+    ///
+    /// The blocks flow through three stages, each on its own thread, so that deserializing and verifying
+    /// later blocks overlaps with checking earlier blocks against the ledger state. Proofs are verified
+    /// ahead of the ledger state, so a block whose proofs fail to verify (for example, because it executes
+    /// a program deployed in the preceding block) is re-checked in full by the ledger stage.
+    ///
+    /// Returns the metrics of each stage, or the first error, after which no further blocks are added.
+    pub fn advance_with_pipeline<I, R>(&self, blocks: I, config: PipelineConfig, rng: &mut R) -> Result<PipelineMetrics>
+    where
+        I: IntoIterator<Item = Vec<u8>>,
+        I::IntoIter: Send,
+        R: CryptoRng + Rng,
+    {
+        ensure!(config.queue_depth > 0, "The pipeline queue depth must be at least 1");
+
+        let blocks = blocks.into_iter();
+        let mut proofs_rng = StdRng::from_seed(rng.gen());
+        let mut ledger_rng = StdRng::from_seed(rng.gen());
+
+        let (deserialized_sender, deserialized_receiver) = sync_channel::<Item<N>>(config.queue_depth);
+        let (proven_sender, proven_receiver) = sync_channel::<Item<N>>(config.queue_depth);
+
+        thread::scope(|scope| {
+            // Deserialize the blocks.
+            let deserialize = scope.spawn(move || {
+                let mut metrics = StageMetrics::default();
+                for bytes in blocks {
+                    let start = Instant::now();
+                    let block = Block::from_bytes_le(&bytes).map(|block| (block, false));
+                    metrics.busy_time += start.elapsed();
+                    metrics.num_blocks += 1;
+
+                    let is_err = block.is_err();
+                    if !send(&deserialized_sender, block, &mut metrics) || is_err {
+                        break;
+                    }
+                }
+                metrics
+            });
+
+            // Verify the transaction proofs.
+            let proofs = scope.spawn(move || {
+                let mut metrics = StageMetrics::default();
+                while let Some(item) = receive(&deserialized_receiver, &mut metrics) {
+                    let start = Instant::now();
+                    let item = item.map(|(block, _)| {
+                        let transactions = block.transactions().iter().collect::<Vec<_>>();
+                        let rngs =
+                            (0..transactions.len()).map(|_| StdRng::from_seed(proofs_rng.gen())).collect::<Vec<_>>();
+                        let are_proofs_verified = cfg_iter!(transactions).zip(rngs).all(|(transaction, mut rng)| {
+                            transaction.to_rejected_id().map_or(false, |rejected_id| {
+                                self.vm().check_transaction_proofs(transaction, rejected_id, &mut rng).is_ok()
+                            })
+                        });
+                        (block, are_proofs_verified)
+                    });
+                    metrics.busy_time += start.elapsed();
+                    metrics.num_blocks += 1;
+
+                    let is_err = item.is_err();
+                    if !send(&proven_sender, item, &mut metrics) || is_err {
+                        break;
+                    }
+                }
+                metrics
+            });
+
+            // Check the blocks against the ledger state, and advance the ledger.
+            let mut metrics = StageMetrics::default();
+            let mut result = Ok(());
+            while let Some(item) = receive(&proven_receiver, &mut metrics) {
+                let start = Instant::now();
+                result = item.and_then(|(block, are_proofs_verified)| {
+                    self.check_next_block_internal(&block, are_proofs_verified, &mut ledger_rng)?;
+                    self.advance_to_next_block(&block)
+                });
+                metrics.busy_time += start.elapsed();
+                metrics.num_blocks += 1;
+
+                if result.is_err() {
+                    break;
+                }
+            }
+            // Stop the earlier stages, which exit once their next send fails.
+            drop(proven_receiver);
+
+            let join = |handle: thread::ScopedJoinHandle<'_, StageMetrics>| {
+                handle.join().map_err(|_| anyhow!("A block validation pipeline stage panicked"))
+            };
+            let metrics = PipelineMetrics { deserialize: join(deserialize)?, proofs: join(proofs)?, ledger: metrics };
+            result.map(|_| metrics)
+        })
+    }
+}
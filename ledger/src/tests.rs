@@ -69,7 +69,7 @@ fn test_state_path() {
     let rng = &mut TestRng::default();
 
     // Initialize the ledger.
-    let ledger = crate::test_helpers::sample_ledger(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
+    let ledger = crate::test_helpers::sample_ledger(&PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
     // Retrieve the genesis block.
     let block = ledger.get_block(0).unwrap();
 
@@ -92,7 +92,7 @@ fn test_insufficient_private_fees() {
     let find_records = || {
         let microcredits = Identifier::from_str("microcredits").unwrap();
         ledger
-            .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+            .find_records(&view_key, RecordsFilter::SlowUnspent(private_key.clone()))
             .unwrap()
             .filter(|(_, record)| match record.data().get(&microcredits) {
                 Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => !amount.is_zero(),
@@ -266,7 +266,7 @@ finalize foo:
     let find_records = || {
         let microcredits = Identifier::from_str("microcredits").unwrap();
         ledger
-            .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+            .find_records(&view_key, RecordsFilter::SlowUnspent(private_key.clone()))
             .unwrap()
             .filter(|(_, record)| match record.data().get(&microcredits) {
                 Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => !amount.is_zero(),
@@ -368,7 +368,7 @@ finalize failed_assert:
     let find_records = || {
         let microcredits = Identifier::from_str("microcredits").unwrap();
         ledger
-            .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+            .find_records(&view_key, RecordsFilter::SlowUnspent(private_key.clone()))
             .unwrap()
             .filter(|(_, record)| match record.data().get(&microcredits) {
                 Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => !amount.is_zero(),
@@ -482,6 +482,55 @@ finalize foo:
     assert_eq!(program, ledger.get_program(program_id).unwrap())
 }
 
+#[test]
+fn test_advance_with_pipeline() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Initialize a second ledger from the same genesis block.
+    let replica = CurrentLedger::load(ledger.get_block(0).unwrap(), None).unwrap();
+
+    // Deploy a test program to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("dummy_program.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+function foo:
+    input r0 as u8.private;
+    add r0 r0 into r1;
+    output r1 as u8.private;",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let deploy_block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&deploy_block).unwrap();
+
+    // Execute the deployed program, in the block immediately after the deployment.
+    let inputs = [Value::<CurrentNetwork>::from_str("1u8").unwrap()];
+    let transaction = ledger.vm.execute(&private_key, (program_id, "foo"), inputs.iter(), None, 0, None, rng).unwrap();
+    let execute_block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&execute_block).unwrap();
+
+    // Replay the blocks on the second ledger through the pipeline.
+    let blocks = vec![deploy_block.to_bytes_le().unwrap(), execute_block.to_bytes_le().unwrap()];
+    let metrics = replica.advance_with_pipeline(blocks, crate::PipelineConfig::default(), rng).unwrap();
+    assert_eq!(metrics.deserialize.num_blocks, 2);
+    assert_eq!(metrics.ledger.num_blocks, 2);
+    assert_eq!(replica.latest_height(), 2);
+    assert_eq!(replica.latest_hash(), execute_block.hash());
+    assert_eq!(program, replica.get_program(program_id).unwrap());
+
+    // Ensure a malformed block is rejected, and the ledger is left unchanged.
+    let mut bytes = execute_block.to_bytes_le().unwrap();
+    bytes.truncate(bytes.len() / 2);
+    assert!(replica.advance_with_pipeline(vec![bytes], crate::PipelineConfig::default(), rng).is_err());
+    assert_eq!(replica.latest_height(), 2);
+}
+
 #[test]
 fn test_bond_and_unbond_validator() {
     let rng = &mut TestRng::default();
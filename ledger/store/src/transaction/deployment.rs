@@ -341,7 +341,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         if program_id == &ProgramID::from_str("credits.aleo")? {
             // Load the verifying key.
             let verifying_key = N::get_credits_verifying_key(function_name.to_string())?;
-            return Ok(Some(VerifyingKey::new(verifying_key.clone())));
+            return Ok(Some(VerifyingKey::new(verifying_key)));
         }
 
         // Retrieve the edition.
@@ -22,6 +22,7 @@ use snarkvm_console::{
     types::Field,
 };
 use snarkvm_ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
+use snarkvm_parameters::{testnet3::TESTNET3_CURVE_ID, ParameterHeader};
 use snarkvm_synthesizer::{process::InclusionAssignment, snark::UniversalSRS, VM};
 
 use anyhow::{anyhow, Result};
@@ -44,6 +45,11 @@ fn versioned_filename(filename: &str, checksum: &str) -> String {
     }
 }
 
+/// Returns the given parameter bytes, prefixed with a versioned parameter header.
+fn with_header(circuit_id: &str, bytes: &[u8]) -> Result<Vec<u8>> {
+    Ok(ParameterHeader::new(TESTNET3_CURVE_ID, circuit_id, bytes)?.encode(bytes))
+}
+
 /// Writes the given bytes to the given versioned filename.
 fn write_remote(filename: &str, version: &str, bytes: &[u8]) -> Result<()> {
     let mut file = BufWriter::new(File::create(PathBuf::from(&versioned_filename(filename, version)))?);
@@ -126,10 +132,10 @@ pub fn inclusion<N: Network, A: Aleo<Network = N>>() -> Result<()> {
     // Initialize a vector for the commands.
     let mut commands = vec![];
 
-    let proving_key_bytes = proving_key.to_bytes_le()?;
+    let proving_key_bytes = with_header(inclusion_function_name, &proving_key.to_bytes_le()?)?;
     let proving_key_checksum = checksum(&proving_key_bytes);
 
-    let verifying_key_bytes = verifying_key.to_bytes_le()?;
+    let verifying_key_bytes = with_header(inclusion_function_name, &verifying_key.to_bytes_le()?)?;
     let verifying_key_checksum = checksum(&verifying_key_bytes);
 
     let metadata = json!({
@@ -15,6 +15,7 @@
 use snarkvm_algorithms::crypto_hash::sha256::sha256;
 use snarkvm_circuit::Aleo;
 use snarkvm_console::network::{prelude::ToBytes, Network, Testnet3};
+use snarkvm_parameters::{testnet3::TESTNET3_CURVE_ID, upgrade_format, ParameterHeader};
 use snarkvm_synthesizer::{Process, Program};
 
 use anyhow::Result;
@@ -37,6 +38,11 @@ fn versioned_filename(filename: &str, checksum: &str) -> String {
     }
 }
 
+/// Returns the given parameter bytes, prefixed with a versioned parameter header.
+fn with_header(circuit_id: &str, bytes: &[u8]) -> Result<Vec<u8>> {
+    Ok(ParameterHeader::new(TESTNET3_CURVE_ID, circuit_id, bytes)?.encode(bytes))
+}
+
 /// Writes the given bytes to the given versioned filename.
 fn write_remote(filename: &str, version: &str, bytes: &[u8]) -> Result<()> {
     let mut file = BufWriter::new(File::create(PathBuf::from(&versioned_filename(filename, version)))?);
@@ -58,7 +64,7 @@ fn write_metadata(filename: &str, metadata: &Value) -> Result<()> {
     Ok(())
 }
 
-/// (Do not use) Writes the metadata files. (cargo run --release --example setup usrs)
+/// (Do not use) Writes the parameter headers and metadata files. (cargo run --release --example setup usrs)
 pub fn usrs() -> Result<()> {
     let paths = fs::read_dir("../src/testnet3/resources/").unwrap();
     for path in paths {
@@ -66,16 +72,20 @@ pub fn usrs() -> Result<()> {
         if let Some("usrs") = path.extension().and_then(|s| s.to_str()) {
             let metadata_path = path.with_extension("metadata");
             let mut file = File::open(&path)?;
-            let file_size = file.metadata().unwrap().len() as usize;
-            let mut file_bytes = Vec::with_capacity(file_size);
+            let mut file_bytes = Vec::with_capacity(file.metadata().unwrap().len() as usize);
             file.read_to_end(&mut file_bytes)?;
+
+            // Prefix the header, or validate the existing header, which identifies the SRS by its file name.
+            let circuit_id = path.file_stem().and_then(|s| s.to_str()).unwrap();
+            let file_bytes = upgrade_format(&file_bytes, TESTNET3_CURVE_ID, circuit_id)?;
             let checksum = checksum(&file_bytes);
 
             let metadata = json!({
                 "checksum": checksum,
-                "size": file_size,
+                "size": file_bytes.len(),
             });
 
+            write_local(path.to_str().unwrap(), &file_bytes)?;
             write_metadata(metadata_path.to_str().unwrap(), &metadata)?;
             write_remote(path.to_str().unwrap(), &checksum, &file_bytes)?;
         }
@@ -103,11 +113,11 @@ pub fn credits_program<N: Network, A: Aleo<Network = N>>() -> Result<()> {
         // println!("Synthesized '{}': {} ms", function_name, timer.elapsed().as_millis());
 
         let proving_key = process.get_proving_key(program_id, function_name)?;
-        let proving_key_bytes = proving_key.to_bytes_le()?;
+        let proving_key_bytes = with_header(&function_name.to_string(), &proving_key.to_bytes_le()?)?;
         let proving_key_checksum = checksum(&proving_key_bytes);
 
         let verifying_key = process.get_verifying_key(program_id, function_name)?;
-        let verifying_key_bytes = verifying_key.to_bytes_le()?;
+        let verifying_key_bytes = with_header(&function_name.to_string(), &verifying_key.to_bytes_le()?)?;
         let verifying_key_checksum = checksum(&verifying_key_bytes);
 
         let metadata = json!({
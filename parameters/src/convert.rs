@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{errors::ParameterError, header::ParameterHeader};
+use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+
+use sha2::Digest;
+
+/// Converts the given parameter bytes from one encoding to another.
+///
+/// The parameters are deserialized as `T` using the `from` encoding, and re-serialized using the `to` encoding.
+/// If `validate` is set, every element is checked during deserialization (e.g. curve points are checked to be
+/// in the correct subgroup), which is slower but guards against corrupt inputs.
+///
+/// If the given bytes contain a parameter header, the output contains a header of the newest format version,
+/// for the same curve and circuit. Note that the consumer of the output must read it with the `to` encoding.
+pub fn convert_encoding<T: CanonicalSerialize + CanonicalDeserialize>(
+    bytes: &[u8],
+    from: Compress,
+    to: Compress,
+    validate: Validate,
+) -> Result<Vec<u8>, ParameterError> {
+    // Re-serializes the given payload from the `from` encoding into the `to` encoding.
+    let convert = |payload: &[u8]| -> Result<Vec<u8>, ParameterError> {
+        let mut reader = payload;
+        let parameters = T::deserialize_with_mode(&mut reader, from, validate)?;
+        if !reader.is_empty() {
+            return Err(ParameterError::Message(format!("found {} trailing bytes in the parameters", reader.len())));
+        }
+        let mut output = Vec::with_capacity(parameters.serialized_size(to));
+        parameters.serialize_with_mode(&mut output, to)?;
+        Ok(output)
+    };
+
+    match ParameterHeader::is_present(bytes) {
+        true => {
+            let (header, payload) = ParameterHeader::decode(bytes)?;
+            ensure_checksum(&header, payload)?;
+            let output = convert(payload)?;
+            Ok(ParameterHeader::new(header.curve(), header.circuit_id(), &output)?.encode(&output))
+        }
+        false => convert(bytes),
+    }
+}
+
+/// Re-serializes the given parameter bytes with a parameter header of the newest format version.
+///
+/// If the given bytes contain a header (of any format version), its curve and circuit ID must match the given
+/// curve and circuit ID, and the payload must match its checksum. If the given bytes do not contain a header,
+/// a new header is prefixed to the bytes.
+pub fn upgrade_format(bytes: &[u8], curve: crate::CurveId, circuit_id: &str) -> Result<Vec<u8>, ParameterError> {
+    let payload = match ParameterHeader::is_present(bytes) {
+        true => {
+            let (header, payload) = ParameterHeader::decode(bytes)?;
+            if header.curve() != curve {
+                return Err(ParameterError::CurveMismatch(curve.to_string(), header.curve().to_string()));
+            }
+            if header.circuit_id() != circuit_id {
+                return Err(ParameterError::CircuitMismatch(circuit_id.to_string(), header.circuit_id().to_string()));
+            }
+            ensure_checksum(&header, payload)?;
+            payload
+        }
+        false => bytes,
+    };
+    Ok(ParameterHeader::new(curve, circuit_id, payload)?.encode(payload))
+}
+
+/// Ensures the given payload matches the checksum in the given header, regardless of its format version.
+fn ensure_checksum(header: &ParameterHeader, payload: &[u8]) -> Result<(), ParameterError> {
+    let candidate: [u8; 32] = sha2::Sha256::digest(payload).into();
+    match *header.checksum() == candidate {
+        true => Ok(()),
+        false => Err(ParameterError::ChecksumMismatch(hex::encode(header.checksum()), hex::encode(candidate))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CurveId;
+    use snarkvm_curves::bls12_377::G1Affine;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    fn sample_powers() -> Vec<G1Affine> {
+        let rng = &mut TestRng::default();
+        (0..16).map(|_| G1Affine::rand(rng)).collect()
+    }
+
+    #[test]
+    fn test_convert_encoding() {
+        let powers = sample_powers();
+        let mut uncompressed = vec![];
+        powers.serialize_uncompressed(&mut uncompressed).unwrap();
+
+        // Convert the uncompressed powers to compressed powers.
+        let compressed =
+            convert_encoding::<Vec<G1Affine>>(&uncompressed, Compress::No, Compress::Yes, Validate::Yes).unwrap();
+        assert!(compressed.len() < uncompressed.len());
+        assert_eq!(powers, Vec::<G1Affine>::deserialize_compressed(&*compressed).unwrap());
+
+        // Convert the compressed powers back to uncompressed powers.
+        let candidate =
+            convert_encoding::<Vec<G1Affine>>(&compressed, Compress::Yes, Compress::No, Validate::No).unwrap();
+        assert_eq!(uncompressed, candidate);
+
+        // Check that trailing bytes are rejected.
+        let mut trailing = uncompressed.clone();
+        trailing.push(0);
+        assert!(convert_encoding::<Vec<G1Affine>>(&trailing, Compress::No, Compress::Yes, Validate::No).is_err());
+    }
+
+    #[test]
+    fn test_convert_encoding_with_header() {
+        let powers = sample_powers();
+        let mut uncompressed = vec![];
+        powers.serialize_uncompressed(&mut uncompressed).unwrap();
+        let bytes = upgrade_format(&uncompressed, CurveId::Bls12_377, "powers-of-beta").unwrap();
+
+        // Check that the header is preserved across the conversion.
+        let compressed = convert_encoding::<Vec<G1Affine>>(&bytes, Compress::No, Compress::Yes, Validate::Yes).unwrap();
+        let payload = ParameterHeader::strip(&compressed, CurveId::Bls12_377, "powers-of-beta").unwrap();
+        assert_eq!(powers, Vec::<G1Affine>::deserialize_compressed(&*payload).unwrap());
+    }
+
+    #[test]
+    fn test_upgrade_format() {
+        let payload = b"parameter payload";
+
+        // Check that a header is added to headerless parameters.
+        let bytes = upgrade_format(payload, CurveId::Bls12_377, "join").unwrap();
+        assert_eq!(payload.to_vec(), ParameterHeader::strip(&bytes, CurveId::Bls12_377, "join").unwrap());
+
+        // Check that an outdated header is upgraded to the newest format version.
+        let mut outdated = bytes.clone();
+        outdated[crate::PARAMETER_MAGIC.len()] = 0;
+        assert!(ParameterHeader::strip(&outdated, CurveId::Bls12_377, "join").is_err());
+        let upgraded = upgrade_format(&outdated, CurveId::Bls12_377, "join").unwrap();
+        assert_eq!(bytes, upgraded);
+
+        // Check that a mismatched circuit is rejected.
+        assert!(matches!(
+            upgrade_format(&bytes, CurveId::Bls12_377, "split"),
+            Err(ParameterError::CircuitMismatch(..))
+        ));
+    }
+}
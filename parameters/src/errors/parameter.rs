@@ -19,23 +19,44 @@ pub enum ParameterError {
     #[error("expected checksum of {}, found checksum of {}", _0, _1)]
     ChecksumMismatch(String, String),
 
+    #[error("expected parameters for circuit '{}', found parameters for circuit '{}'", _0, _1)]
+    CircuitMismatch(String, String),
+
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("expected parameters over the {} curve, found parameters over the {} curve", _0, _1)]
+    CurveMismatch(String, String),
+
+    #[error("malformed parameter header: {}", _0)]
+    MalformedHeader(String),
+
     #[error("{}", _0)]
     Message(String),
 
+    #[error("the parameters for circuit '{}' have no parameter header (the parameter file may be stale)", _0)]
+    MissingHeader(String),
+
+    #[error("'{}' is not embedded in this build, and must be inserted into the parameter store", _0)]
+    NotEmbedded(String),
+
     #[error("Remote fetch is disabled, enable compiler flag for feature")]
     RemoteFetchDisabled,
 
     #[error("Expected size of {}, found size of {}", _0, _1)]
     SizeMismatch(usize, usize),
 
+    #[error("cannot load '{}' under the verifier-only parameter profile", _0)]
+    VerifierOnlyProfile(String),
+
+    #[error("expected parameter format version {}, found version {} (the parameter file may be stale)", _0, _1)]
+    VersionMismatch(u16, u16),
+
     #[error("{}", _0)]
     Wasm(String),
 }
 
-#[cfg(not(feature = "wasm"))]
+#[cfg(all(feature = "remote", not(feature = "wasm")))]
 impl From<curl::Error> for ParameterError {
     fn from(error: curl::Error) -> Self {
         ParameterError::Crate("curl::error", format!("{error:?}"))
@@ -48,6 +69,12 @@ impl From<std::io::Error> for ParameterError {
     }
 }
 
+impl From<snarkvm_utilities::SerializationError> for ParameterError {
+    fn from(error: snarkvm_utilities::SerializationError) -> Self {
+        ParameterError::Crate("snarkvm_utilities::serialize", format!("{error:?}"))
+    }
+}
+
 impl From<std::path::StripPrefixError> for ParameterError {
     fn from(error: std::path::StripPrefixError) -> Self {
         ParameterError::Crate("std::path", format!("{error:?}"))
@@ -0,0 +1,276 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ParameterError;
+
+use sha2::Digest;
+
+/// The magic bytes that prefix every versioned parameter file.
+pub const PARAMETER_MAGIC: [u8; 4] = *b"SVMP";
+
+/// The current format version of the parameter file header.
+pub const PARAMETER_FORMAT_VERSION: u16 = 1;
+
+/// The maximum number of bytes in a circuit ID.
+const MAX_CIRCUIT_ID_LENGTH: usize = u8::MAX as usize;
+
+/// The curve that the contents of a parameter file are defined over.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum CurveId {
+    Bls12_377 = 0,
+    EdwardsBls12 = 1,
+}
+
+impl CurveId {
+    /// Returns the curve ID for the given byte, if it is valid.
+    pub const fn from_u8(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::Bls12_377),
+            1 => Some(Self::EdwardsBls12),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for CurveId {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Bls12_377 => write!(f, "BLS12-377"),
+            Self::EdwardsBls12 => write!(f, "Edwards-BLS12"),
+        }
+    }
+}
+
+/// The header that is prefixed to a parameter file.
+///
+/// The header is encoded as:
+/// `MAGIC (4 bytes) || version (u16) || curve ID (u8) || circuit ID length (u8) || circuit ID || checksum (32 bytes)`,
+/// where the checksum is the SHA-256 digest of the payload that follows the header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParameterHeader {
+    /// The format version of the parameter file.
+    version: u16,
+    /// The curve that the parameters are defined over.
+    curve: CurveId,
+    /// The ID of the circuit (or SRS) that the parameters belong to.
+    circuit_id: String,
+    /// The SHA-256 checksum of the payload.
+    checksum: [u8; 32],
+}
+
+impl ParameterHeader {
+    /// Initializes a new header for the given payload, using the current format version.
+    pub fn new(curve: CurveId, circuit_id: &str, payload: &[u8]) -> Result<Self, ParameterError> {
+        if circuit_id.len() > MAX_CIRCUIT_ID_LENGTH {
+            return Err(ParameterError::MalformedHeader(format!(
+                "circuit ID '{circuit_id}' exceeds {MAX_CIRCUIT_ID_LENGTH} bytes"
+            )));
+        }
+        Ok(Self {
+            version: PARAMETER_FORMAT_VERSION,
+            curve,
+            circuit_id: circuit_id.to_string(),
+            checksum: sha2::Sha256::digest(payload).into(),
+        })
+    }
+
+    /// Returns the format version of the parameter file.
+    pub const fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the curve that the parameters are defined over.
+    pub const fn curve(&self) -> CurveId {
+        self.curve
+    }
+
+    /// Returns the ID of the circuit that the parameters belong to.
+    pub fn circuit_id(&self) -> &str {
+        &self.circuit_id
+    }
+
+    /// Returns the SHA-256 checksum of the payload.
+    pub const fn checksum(&self) -> &[u8; 32] {
+        &self.checksum
+    }
+
+    /// Returns the number of bytes in the encoded header.
+    pub fn size_in_bytes(&self) -> usize {
+        PARAMETER_MAGIC.len() + 2 + 1 + 1 + self.circuit_id.len() + 32
+    }
+
+    /// Returns `true` if the given bytes begin with a parameter header.
+    pub fn is_present(bytes: &[u8]) -> bool {
+        bytes.starts_with(&PARAMETER_MAGIC)
+    }
+
+    /// Returns the encoded header followed by the given payload.
+    pub fn encode(&self, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.size_in_bytes() + payload.len());
+        bytes.extend_from_slice(&PARAMETER_MAGIC);
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+        bytes.push(self.curve as u8);
+        bytes.push(self.circuit_id.len() as u8);
+        bytes.extend_from_slice(self.circuit_id.as_bytes());
+        bytes.extend_from_slice(&self.checksum);
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    /// Decodes the header from the given bytes, returning the header and the remaining payload.
+    pub fn decode(bytes: &[u8]) -> Result<(Self, &[u8]), ParameterError> {
+        // Splits off the next `length` bytes, or returns an error with the given reason.
+        fn take<'a>(bytes: &mut &'a [u8], length: usize, reason: &str) -> Result<&'a [u8], ParameterError> {
+            if bytes.len() < length {
+                return Err(ParameterError::MalformedHeader(reason.to_string()));
+            }
+            let (head, tail) = bytes.split_at(length);
+            *bytes = tail;
+            Ok(head)
+        }
+
+        let mut bytes = bytes;
+        // Ensure the magic bytes are present.
+        if take(&mut bytes, PARAMETER_MAGIC.len(), "missing magic bytes")? != PARAMETER_MAGIC {
+            return Err(ParameterError::MalformedHeader("missing magic bytes".to_string()));
+        }
+        // Read the format version.
+        let version = take(&mut bytes, 2, "missing format version")?;
+        let version = u16::from_le_bytes([version[0], version[1]]);
+        // Read the curve ID.
+        let curve = take(&mut bytes, 1, "missing curve ID")?[0];
+        let curve = CurveId::from_u8(curve)
+            .ok_or_else(|| ParameterError::MalformedHeader(format!("unknown curve ID {curve}")))?;
+        // Read the circuit ID.
+        let length = take(&mut bytes, 1, "missing circuit ID length")?[0] as usize;
+        let circuit_id = take(&mut bytes, length, "truncated circuit ID")?;
+        let circuit_id = String::from_utf8(circuit_id.to_vec())
+            .map_err(|_| ParameterError::MalformedHeader("circuit ID is not valid UTF-8".to_string()))?;
+        // Read the checksum.
+        let mut checksum = [0u8; 32];
+        checksum.copy_from_slice(take(&mut bytes, 32, "truncated checksum")?);
+
+        Ok((Self { version, curve, circuit_id, checksum }, bytes))
+    }
+
+    /// Ensures the header matches the expected curve and circuit ID, and that the payload matches the checksum.
+    pub fn validate(&self, curve: CurveId, circuit_id: &str, payload: &[u8]) -> Result<(), ParameterError> {
+        // Ensure the format version is supported.
+        if self.version != PARAMETER_FORMAT_VERSION {
+            return Err(ParameterError::VersionMismatch(PARAMETER_FORMAT_VERSION, self.version));
+        }
+        // Ensure the curve matches.
+        if self.curve != curve {
+            return Err(ParameterError::CurveMismatch(curve.to_string(), self.curve.to_string()));
+        }
+        // Ensure the circuit ID matches.
+        if self.circuit_id != circuit_id {
+            return Err(ParameterError::CircuitMismatch(circuit_id.to_string(), self.circuit_id.clone()));
+        }
+        // Ensure the checksum matches.
+        let candidate: [u8; 32] = sha2::Sha256::digest(payload).into();
+        if self.checksum != candidate {
+            return Err(ParameterError::ChecksumMismatch(hex::encode(self.checksum), hex::encode(candidate)));
+        }
+        Ok(())
+    }
+
+    /// Validates the header on the given parameter bytes, and returns the payload that follows it.
+    pub fn strip<'a>(bytes: &'a [u8], curve: CurveId, circuit_id: &str) -> Result<&'a [u8], ParameterError> {
+        // Ensure the header is present.
+        if !Self::is_present(bytes) {
+            return Err(ParameterError::MissingHeader(circuit_id.to_string()));
+        }
+        let (header, payload) = Self::decode(bytes)?;
+        header.validate(curve, circuit_id, payload)?;
+        Ok(payload)
+    }
+
+    /// Validates the header on the given parameter bytes (if one is present), and returns the payload.
+    ///
+    /// This is only for the remote parameter files that predate the header, which are hosted under their
+    /// checksum and cannot be replaced in place. A file without a header is returned unchanged, with a warning.
+    pub fn strip_legacy<'a>(bytes: &'a [u8], curve: CurveId, circuit_id: &str) -> Result<&'a [u8], ParameterError> {
+        match Self::is_present(bytes) {
+            true => Self::strip(bytes, curve, circuit_id),
+            false => {
+                #[cfg(not(feature = "no_std_out"))]
+                eprintln!(
+                    "⚠️  The parameters for '{circuit_id}' have no header, and are only checked against the metadata."
+                );
+                Ok(bytes)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAYLOAD: &[u8] = b"parameter payload";
+
+    #[test]
+    fn test_header_round_trip() {
+        let header = ParameterHeader::new(CurveId::Bls12_377, "transfer_public", PAYLOAD).unwrap();
+        let bytes = header.encode(PAYLOAD);
+        assert_eq!(bytes.len(), header.size_in_bytes() + PAYLOAD.len());
+
+        let (candidate, payload) = ParameterHeader::decode(&bytes).unwrap();
+        assert_eq!(header, candidate);
+        assert_eq!(PAYLOAD, payload);
+        assert_eq!(PAYLOAD, ParameterHeader::strip(&bytes, CurveId::Bls12_377, "transfer_public").unwrap());
+        assert_eq!(PAYLOAD, ParameterHeader::strip_legacy(&bytes, CurveId::Bls12_377, "transfer_public").unwrap());
+    }
+
+    #[test]
+    fn test_header_mismatch() {
+        let header = ParameterHeader::new(CurveId::Bls12_377, "transfer_public", PAYLOAD).unwrap();
+        let bytes = header.encode(PAYLOAD);
+
+        // Check that a mismatched curve is rejected.
+        let result = ParameterHeader::strip(&bytes, CurveId::EdwardsBls12, "transfer_public");
+        assert!(matches!(result, Err(ParameterError::CurveMismatch(..))));
+        // Check that a mismatched circuit is rejected.
+        let result = ParameterHeader::strip(&bytes, CurveId::Bls12_377, "transfer_private");
+        assert!(matches!(result, Err(ParameterError::CircuitMismatch(..))));
+        // Check that a corrupted payload is rejected.
+        let mut corrupted = bytes.clone();
+        *corrupted.last_mut().unwrap() ^= 1;
+        let result = ParameterHeader::strip(&corrupted, CurveId::Bls12_377, "transfer_public");
+        assert!(matches!(result, Err(ParameterError::ChecksumMismatch(..))));
+        // Check that an unsupported version is rejected.
+        let mut outdated = bytes.clone();
+        outdated[PARAMETER_MAGIC.len()] = 0;
+        let result = ParameterHeader::strip(&outdated, CurveId::Bls12_377, "transfer_public");
+        assert!(matches!(result, Err(ParameterError::VersionMismatch(..))));
+        // Check that a truncated header is rejected.
+        let result = ParameterHeader::strip(&bytes[..10], CurveId::Bls12_377, "transfer_public");
+        assert!(matches!(result, Err(ParameterError::MalformedHeader(..))));
+    }
+
+    #[test]
+    fn test_legacy_parameters() {
+        // Check that parameter files without a header are rejected, unless they are legacy files.
+        let result = ParameterHeader::strip(PAYLOAD, CurveId::Bls12_377, "transfer_public");
+        assert!(matches!(result, Err(ParameterError::MissingHeader(..))));
+        assert_eq!(PAYLOAD, ParameterHeader::strip_legacy(PAYLOAD, CurveId::Bls12_377, "transfer_public").unwrap());
+
+        // Check that a legacy file with a mismatched header is still rejected.
+        let bytes = ParameterHeader::new(CurveId::Bls12_377, "transfer_public", PAYLOAD).unwrap().encode(PAYLOAD);
+        let result = ParameterHeader::strip_legacy(&bytes, CurveId::Bls12_377, "transfer_private");
+        assert!(matches!(result, Err(ParameterError::CircuitMismatch(..))));
+    }
+}
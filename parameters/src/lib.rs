@@ -27,11 +27,23 @@ extern crate thiserror;
 #[macro_use]
 pub mod macros;
 
+pub mod convert;
+pub use convert::*;
+
 pub mod errors;
 pub use errors::*;
 
+pub mod header;
+pub use header::*;
+
+pub mod profile;
+pub use profile::*;
+
+pub mod store;
+pub use store::*;
+
 pub mod testnet3;
 
 pub mod prelude {
-    pub use crate::errors::*;
+    pub use crate::{convert::*, errors::*, header::*, profile::*, store::*};
 }
@@ -43,7 +43,7 @@ macro_rules! remove_file {
 
 macro_rules! impl_store_and_remote_fetch {
     () => {
-        #[cfg(not(feature = "wasm"))]
+        #[cfg(all(feature = "remote", not(feature = "wasm")))]
         fn store_bytes(buffer: &[u8], file_path: &std::path::Path) -> Result<(), $crate::errors::ParameterError> {
             use snarkvm_utilities::Write;
 
@@ -67,7 +67,7 @@ macro_rules! impl_store_and_remote_fetch {
             Ok(())
         }
 
-        #[cfg(not(feature = "wasm"))]
+        #[cfg(all(feature = "remote", not(feature = "wasm")))]
         fn remote_fetch(buffer: &mut Vec<u8>, url: &str) -> Result<(), $crate::errors::ParameterError> {
             let mut easy = curl::easy::Easy::new();
             easy.follow_location(true)?;
@@ -149,7 +149,16 @@ macro_rules! impl_store_and_remote_fetch {
 }
 
 macro_rules! impl_load_bytes_logic_local {
-    ($filepath: expr, $buffer: expr, $expected_size: expr, $expected_checksum: expr) => {
+    ($filepath: expr, $buffer: expr, $expected_size: expr, $expected_checksum: expr, $circuit_id: expr) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "parameters::load",
+            circuit_id = $circuit_id,
+            size = $expected_size,
+            source = "embedded",
+        )
+        .entered();
+
         // Ensure the size matches.
         if $expected_size != $buffer.len() {
             remove_file!($filepath);
@@ -162,18 +171,27 @@ macro_rules! impl_load_bytes_logic_local {
             return checksum_error!($expected_checksum, candidate_checksum);
         }
 
-        return Ok($buffer.to_vec());
+        // Ensure the header matches, and return the payload.
+        return $crate::header::ParameterHeader::strip($buffer, $crate::testnet3::TESTNET3_CURVE_ID, $circuit_id)
+            .map(<[u8]>::to_vec);
     };
 }
 
 macro_rules! impl_load_bytes_logic_remote {
-    ($remote_url: expr, $local_dir: expr, $filename: expr, $metadata: expr, $expected_checksum: expr, $expected_size: expr) => {
+    ($remote_url: expr, $local_dir: expr, $filename: expr, $metadata: expr, $expected_checksum: expr, $expected_size: expr, $circuit_id: expr) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "parameters::load",
+            circuit_id = $circuit_id,
+            size = $expected_size,
+            source = "remote",
+        )
+        .entered();
+
         // Compose the correct file path for the parameter file.
-        let mut file_path = aleo_std::aleo_dir();
-        file_path.push($local_dir);
-        file_path.push($filename);
+        let file_path = $crate::store::ParameterStore::global().file_path($local_dir, $filename);
 
-        let buffer = if file_path.exists() {
+        let mut buffer = if file_path.exists() {
             // Attempts to load the parameter file locally with an absolute path.
             std::fs::read(&file_path)?
         } else {
@@ -193,7 +211,7 @@ macro_rules! impl_load_bytes_logic_remote {
 
             // Load remote file
             cfg_if::cfg_if! {
-                if #[cfg(not(feature = "wasm"))] {
+                if #[cfg(all(feature = "remote", not(feature = "wasm")))] {
                     let mut buffer = vec![];
                     Self::remote_fetch(&mut buffer, &url)?;
 
@@ -241,6 +259,11 @@ macro_rules! impl_load_bytes_logic_remote {
             return checksum_error!($expected_checksum, candidate_checksum)
         }
 
+        // Ensure the header (if present) matches, and remove it in place to return the payload.
+        let payload =
+            $crate::header::ParameterHeader::strip_legacy(&buffer, $crate::testnet3::TESTNET3_CURVE_ID, $circuit_id)?;
+        let header_size = buffer.len() - payload.len();
+        buffer.drain(..header_size);
         return Ok(buffer)
     }
 }
@@ -255,6 +278,12 @@ macro_rules! impl_local {
             pub const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
 
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                // Use the parameters in the parameter store, if they were injected.
+                if let Some(buffer) = $crate::store::ParameterStore::global().get(concat!($fname, ".", "usrs")) {
+                    return $crate::header::ParameterHeader::strip(&buffer, $crate::testnet3::TESTNET3_CURVE_ID, $fname)
+                        .map(<[u8]>::to_vec);
+                }
+
                 let metadata: serde_json::Value =
                     serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
                 let expected_checksum: String =
@@ -265,7 +294,7 @@ macro_rules! impl_local {
                 let _filepath = concat!($local_dir, $fname, ".", "usrs");
                 let buffer = include_bytes!(concat!($local_dir, $fname, ".", "usrs"));
 
-                impl_load_bytes_logic_local!(_filepath, buffer, expected_size, expected_checksum);
+                impl_load_bytes_logic_local!(_filepath, buffer, expected_size, expected_checksum, $fname);
             }
         }
 
@@ -285,6 +314,12 @@ macro_rules! impl_local {
             pub const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
 
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                // Use the parameters in the parameter store, if they were injected.
+                if let Some(buffer) = $crate::store::ParameterStore::global().get(concat!($fname, ".", $ftype)) {
+                    return $crate::header::ParameterHeader::strip(&buffer, $crate::testnet3::TESTNET3_CURVE_ID, $fname)
+                        .map(<[u8]>::to_vec);
+                }
+
                 let metadata: serde_json::Value =
                     serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
                 let expected_checksum: String =
@@ -295,7 +330,7 @@ macro_rules! impl_local {
                 let _filepath = concat!($local_dir, $fname, ".", $ftype);
                 let buffer = include_bytes!(concat!($local_dir, $fname, ".", $ftype));
 
-                impl_load_bytes_logic_local!(_filepath, buffer, expected_size, expected_checksum);
+                impl_load_bytes_logic_local!(_filepath, buffer, expected_size, expected_checksum, $fname);
             }
         }
 
@@ -309,6 +344,36 @@ macro_rules! impl_local {
     };
 }
 
+/// Implements a parameter that is embedded in the binary, unless the `embedded` feature is enabled.
+///
+/// The `embedded` feature omits parameters that are only required by the prover, which may still be
+/// provided at runtime through the [`ParameterStore`](crate::store::ParameterStore).
+#[macro_export]
+macro_rules! impl_local_prover {
+    ($name: ident, $local_dir: expr, $fname: tt, $ftype: tt) => {
+        #[cfg(not(feature = "embedded"))]
+        impl_local!($name, $local_dir, $fname, $ftype);
+
+        #[cfg(feature = "embedded")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $name;
+
+        #[cfg(feature = "embedded")]
+        impl $name {
+            pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                // Use the parameters in the parameter store, if they were injected.
+                match $crate::store::ParameterStore::global().get(concat!($fname, ".", $ftype)) {
+                    Some(buffer) => {
+                        $crate::header::ParameterHeader::strip(&buffer, $crate::testnet3::TESTNET3_CURVE_ID, $fname)
+                            .map(<[u8]>::to_vec)
+                    }
+                    None => Err($crate::errors::ParameterError::NotEmbedded(concat!($fname, ".", $ftype).to_string())),
+                }
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! impl_remote {
     ($name: ident, $remote_url: expr, $local_dir: expr, $fname: tt, "usrs") => {
@@ -320,6 +385,19 @@ macro_rules! impl_remote {
             impl_store_and_remote_fetch!();
 
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                // Ensure the parameter profile permits loading the proving parameters.
+                $crate::profile::ParameterProfile::current().ensure_proving_allowed(concat!($fname, ".", "usrs"))?;
+
+                // Use the parameters in the parameter store, if they were injected.
+                if let Some(buffer) = $crate::store::ParameterStore::global().get(concat!($fname, ".", "usrs")) {
+                    return $crate::header::ParameterHeader::strip_legacy(
+                        &buffer,
+                        $crate::testnet3::TESTNET3_CURVE_ID,
+                        $fname,
+                    )
+                    .map(<[u8]>::to_vec);
+                }
+
                 let metadata: serde_json::Value =
                     serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
                 let expected_checksum: String =
@@ -339,7 +417,8 @@ macro_rules! impl_remote {
                     &filename,
                     metadata,
                     expected_checksum,
-                    expected_size
+                    expected_size,
+                    $fname
                 );
             }
         }
@@ -360,6 +439,19 @@ macro_rules! impl_remote {
             impl_store_and_remote_fetch!();
 
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                // Ensure the parameter profile permits loading the proving parameters.
+                $crate::profile::ParameterProfile::current().ensure_proving_allowed(concat!($fname, ".", $ftype))?;
+
+                // Use the parameters in the parameter store, if they were injected.
+                if let Some(buffer) = $crate::store::ParameterStore::global().get(concat!($fname, ".", $ftype)) {
+                    return $crate::header::ParameterHeader::strip_legacy(
+                        &buffer,
+                        $crate::testnet3::TESTNET3_CURVE_ID,
+                        $fname,
+                    )
+                    .map(<[u8]>::to_vec);
+                }
+
                 let metadata: serde_json::Value =
                     serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
                 let expected_checksum: String =
@@ -379,7 +471,8 @@ macro_rules! impl_remote {
                     &filename,
                     metadata,
                     expected_checksum,
-                    expected_size
+                    expected_size,
+                    $fname
                 );
             }
         }
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ParameterError;
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// The parameter profile of the current process.
+static PROFILE: AtomicU8 = AtomicU8::new(ParameterProfile::DEFAULT as u8);
+
+/// The set of parameters that the current process is permitted to load.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ParameterProfile {
+    /// All parameters may be loaded, including the proving keys and the full universal SRS.
+    Full = 0,
+    /// Only the verifying keys and the verifier portion of the universal SRS may be loaded.
+    ///
+    /// Under this profile, the proving keys and the remote powers of the universal SRS
+    /// are never downloaded or read from disk.
+    VerifierOnly = 1,
+}
+
+impl ParameterProfile {
+    /// The default parameter profile.
+    #[cfg(not(feature = "embedded"))]
+    pub const DEFAULT: Self = Self::Full;
+    /// The default parameter profile, which is verifier-only for embedded builds.
+    #[cfg(feature = "embedded")]
+    pub const DEFAULT: Self = Self::VerifierOnly;
+
+    /// Returns the parameter profile of the current process.
+    pub fn current() -> Self {
+        match PROFILE.load(Ordering::Relaxed) {
+            1 => Self::VerifierOnly,
+            _ => Self::Full,
+        }
+    }
+
+    /// Sets the parameter profile of the current process.
+    pub fn set(profile: Self) {
+        PROFILE.store(profile as u8, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the profile permits loading proving parameters.
+    pub const fn allows_proving(&self) -> bool {
+        matches!(self, Self::Full)
+    }
+
+    /// Ensures the profile permits loading the given proving parameter.
+    pub fn ensure_proving_allowed(&self, filename: &str) -> Result<(), ParameterError> {
+        match self.allows_proving() {
+            true => Ok(()),
+            false => Err(ParameterError::VerifierOnlyProfile(filename.to_string())),
+        }
+    }
+}
+
+impl Default for ParameterProfile {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile() {
+        assert_eq!(ParameterProfile::default().allows_proving(), !cfg!(feature = "embedded"));
+
+        assert!(ParameterProfile::Full.allows_proving());
+        assert!(ParameterProfile::Full.ensure_proving_allowed("transfer_public.prover").is_ok());
+
+        assert!(!ParameterProfile::VerifierOnly.allows_proving());
+        let result = ParameterProfile::VerifierOnly.ensure_proving_allowed("transfer_public.prover");
+        assert!(matches!(result, Err(ParameterError::VerifierOnlyProfile(..))));
+    }
+}
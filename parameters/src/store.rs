@@ -0,0 +1,262 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{errors::ParameterError, profile::ParameterProfile};
+
+use indexmap::IndexMap;
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+        RwLock,
+    },
+};
+
+/// The environment variable that overrides the parameter directories.
+/// The value is a list of directories, separated in the same manner as `PATH`.
+pub const PARAMETER_PATH_ENV: &str = "SNARKVM_PARAMETERS_PATH";
+
+lazy_static! {
+    /// The parameter store of the current process.
+    static ref PARAMETER_STORE: ParameterStore = ParameterStore::from_env();
+}
+
+/// The parameter store determines where parameter files are read from and stored to,
+/// and holds any parameters that were injected into memory at runtime.
+///
+/// Parameters that are injected into the store take precedence over the files on disk and
+/// the parameters embedded in the binary. This allows tests to provide their own parameters.
+///
+/// Every change to the store advances its generation. Consumers that cache the parameters they load
+/// compare the generation against the one they loaded at, and reload the parameters when it has advanced,
+/// so updated keys can be swapped in without restarting the process.
+#[derive(Debug)]
+pub struct ParameterStore {
+    /// The directories to search for parameter files, in order of precedence.
+    directories: RwLock<Vec<PathBuf>>,
+    /// The parameters that were injected into memory, keyed by filename.
+    parameters: RwLock<IndexMap<String, Arc<Vec<u8>>>>,
+    /// The number of changes to the store.
+    generation: AtomicU64,
+}
+
+impl ParameterStore {
+    /// Initializes a new parameter store with the given directories.
+    pub fn new(directories: Vec<PathBuf>) -> Self {
+        Self { directories: RwLock::new(directories), parameters: Default::default(), generation: AtomicU64::new(0) }
+    }
+
+    /// Initializes a new parameter store, using the directories in `SNARKVM_PARAMETERS_PATH` if it is set,
+    /// and otherwise the Aleo directory.
+    pub fn from_env() -> Self {
+        match std::env::var_os(PARAMETER_PATH_ENV) {
+            Some(paths) if !paths.is_empty() => Self::new(std::env::split_paths(&paths).collect()),
+            _ => Self::new(vec![aleo_std::aleo_dir()]),
+        }
+    }
+
+    /// Returns the parameter store of the current process.
+    pub fn global() -> &'static Self {
+        &PARAMETER_STORE
+    }
+
+    /// Returns the directories to search for parameter files, in order of precedence.
+    pub fn directories(&self) -> Vec<PathBuf> {
+        self.directories.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Sets the directories to search for parameter files, in order of precedence.
+    pub fn set_directories(&self, directories: Vec<PathBuf>) {
+        *self.directories.write().unwrap_or_else(|e| e.into_inner()) = directories;
+        self.reload();
+    }
+
+    /// Returns the generation of the store, which advances whenever the parameters may have changed.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Advances the generation of the store, so consumers reload their parameters.
+    ///
+    /// This is called by every change to the store, and should be called after updated parameter files
+    /// are written to the parameter directories.
+    pub fn reload(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Returns the path of the given parameter file.
+    ///
+    /// If the file exists in one of the directories, the first such path is returned.
+    /// Otherwise, the path in the first directory is returned, which is where the file should be stored.
+    pub fn file_path(&self, local_dir: impl AsRef<Path>, filename: &str) -> PathBuf {
+        let directories = self.directories();
+        let candidates = directories.iter().map(|directory| directory.join(local_dir.as_ref()).join(filename));
+        let mut first = None;
+        for candidate in candidates {
+            if candidate.exists() {
+                return candidate;
+            }
+            first.get_or_insert(candidate);
+        }
+        first.unwrap_or_else(|| aleo_std::aleo_dir().join(local_dir.as_ref()).join(filename))
+    }
+
+    /// Returns the injected parameter bytes for the given filename, if they exist.
+    pub fn get(&self, filename: &str) -> Option<Arc<Vec<u8>>> {
+        self.parameters.read().unwrap_or_else(|e| e.into_inner()).get(filename).cloned()
+    }
+
+    /// Injects the given parameter bytes for the given filename, replacing any existing bytes.
+    ///
+    /// The bytes must match the given SHA-256 checksum, in hex, and proving keys are rejected
+    /// if the parameter profile does not permit proving.
+    pub fn insert(&self, filename: &str, bytes: Vec<u8>, expected_checksum: &str) -> Result<(), ParameterError> {
+        // Ensure the parameter profile permits loading the proving parameters.
+        if filename.ends_with(".prover") {
+            ParameterProfile::current().ensure_proving_allowed(filename)?;
+        }
+
+        // Ensure the checksum matches.
+        let candidate_checksum = checksum!(&bytes);
+        if expected_checksum != candidate_checksum {
+            return checksum_error!(expected_checksum.to_string(), candidate_checksum);
+        }
+
+        self.parameters.write().unwrap_or_else(|e| e.into_inner()).insert(filename.to_string(), Arc::new(bytes));
+        self.reload();
+        Ok(())
+    }
+
+    /// Removes the injected parameter bytes for the given filename, returning them if they existed.
+    pub fn remove(&self, filename: &str) -> Option<Arc<Vec<u8>>> {
+        let bytes = self.parameters.write().unwrap_or_else(|e| e.into_inner()).shift_remove(filename);
+        if bytes.is_some() {
+            self.reload();
+        }
+        bytes
+    }
+}
+
+/// A value loaded from the parameters, which is reloaded when the generation of the parameter store advances.
+pub struct ParameterCache<T> {
+    /// The function that loads the value.
+    load: fn() -> T,
+    /// The generation of the parameter store, and the value loaded at that generation.
+    value: RwLock<Option<(u64, Arc<T>)>>,
+}
+
+impl<T> ParameterCache<T> {
+    /// Initializes a new cache, which loads the value with the given function when it is first requested.
+    pub const fn new(load: fn() -> T) -> Self {
+        Self { load, value: RwLock::new(None) }
+    }
+
+    /// Returns the value, reloading it if the parameter store has changed since it was loaded.
+    pub fn get(&self) -> Arc<T> {
+        let generation = ParameterStore::global().generation();
+        if let Some((cached, value)) = &*self.value.read().unwrap_or_else(|e| e.into_inner()) {
+            if *cached == generation {
+                return value.clone();
+            }
+        }
+        let value = Arc::new((self.load)());
+        *self.value.write().unwrap_or_else(|e| e.into_inner()) = Some((generation, value.clone()));
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inject_and_remove() {
+        let store = ParameterStore::new(vec![]);
+        assert!(store.get("join.verifier").is_none());
+
+        store.insert("join.verifier", vec![1, 2, 3], &checksum!([1u8, 2, 3])).unwrap();
+        assert_eq!(*store.get("join.verifier").unwrap(), vec![1, 2, 3]);
+
+        // Check that the parameters can be replaced.
+        store.insert("join.verifier", vec![4, 5, 6], &checksum!([4u8, 5, 6])).unwrap();
+        assert_eq!(*store.get("join.verifier").unwrap(), vec![4, 5, 6]);
+
+        assert_eq!(*store.remove("join.verifier").unwrap(), vec![4, 5, 6]);
+        assert!(store.get("join.verifier").is_none());
+    }
+
+    #[test]
+    fn test_generation() {
+        let store = ParameterStore::new(vec![]);
+        let generation = store.generation();
+
+        // Check that each change advances the generation.
+        store.insert("join.verifier", vec![1, 2, 3], &checksum!([1u8, 2, 3])).unwrap();
+        assert_eq!(store.generation(), generation + 1);
+        store.remove("join.verifier").unwrap();
+        assert_eq!(store.generation(), generation + 2);
+        store.set_directories(vec![]);
+        assert_eq!(store.generation(), generation + 3);
+        store.reload();
+        assert_eq!(store.generation(), generation + 4);
+
+        // Check that a rejected insertion, and the removal of a missing file, do not advance the generation.
+        assert!(store.insert("join.verifier", vec![1, 2, 3], &checksum!([4u8, 5, 6])).is_err());
+        assert!(store.remove("join.verifier").is_none());
+        assert_eq!(store.generation(), generation + 4);
+    }
+
+    #[test]
+    fn test_cache_reloads() {
+        static CACHE: ParameterCache<Option<Vec<u8>>> =
+            ParameterCache::new(|| ParameterStore::global().get("test_cache_reloads.verifier").map(|b| b.to_vec()));
+
+        assert_eq!(*CACHE.get(), None);
+
+        // Check that the cache picks up injected parameters, and their replacement.
+        let store = ParameterStore::global();
+        store.insert("test_cache_reloads.verifier", vec![1, 2, 3], &checksum!([1u8, 2, 3])).unwrap();
+        assert_eq!(*CACHE.get(), Some(vec![1, 2, 3]));
+        store.insert("test_cache_reloads.verifier", vec![4, 5, 6], &checksum!([4u8, 5, 6])).unwrap();
+        assert_eq!(*CACHE.get(), Some(vec![4, 5, 6]));
+
+        store.remove("test_cache_reloads.verifier").unwrap();
+        assert_eq!(*CACHE.get(), None);
+    }
+
+    #[test]
+    fn test_insert_checks_checksum() {
+        let store = ParameterStore::new(vec![]);
+        let result = store.insert("join.verifier", vec![1, 2, 3], &checksum!([4u8, 5, 6]));
+        assert!(matches!(result, Err(ParameterError::ChecksumMismatch(..))));
+        assert!(store.get("join.verifier").is_none());
+    }
+
+    #[test]
+    fn test_file_path() {
+        let first = std::env::temp_dir().join("snarkvm-parameter-store-first");
+        let second = std::env::temp_dir().join("snarkvm-parameter-store-second");
+        let store = ParameterStore::new(vec![first.clone(), second.clone()]);
+
+        // Check that a missing file resolves to the first directory.
+        assert_eq!(store.file_path("resources/", "missing.prover"), first.join("resources/missing.prover"));
+
+        // Check that an existing file resolves to the directory that contains it.
+        std::fs::create_dir_all(second.join("resources/")).unwrap();
+        std::fs::write(second.join("resources/present.prover"), [0u8]).unwrap();
+        assert_eq!(store.file_path("resources/", "present.prover"), second.join("resources/present.prover"));
+        std::fs::remove_dir_all(&second).unwrap();
+    }
+}
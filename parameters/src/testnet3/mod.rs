@@ -20,9 +20,12 @@ pub use powers::*;
 
 const REMOTE_URL: &str = "https://s3-us-west-1.amazonaws.com/testnet3.parameters";
 
+/// The curve that the testnet3 parameters are defined over.
+pub const TESTNET3_CURVE_ID: crate::CurveId = crate::CurveId::Bls12_377;
+
 // Degrees
 impl_local!(Degree15, "resources/", "powers-of-beta-15", "usrs");
-impl_local!(Degree16, "resources/", "powers-of-beta-16", "usrs");
+impl_local_prover!(Degree16, "resources/", "powers-of-beta-16", "usrs");
 impl_remote!(Degree17, REMOTE_URL, "resources/", "powers-of-beta-17", "usrs");
 impl_remote!(Degree18, REMOTE_URL, "resources/", "powers-of-beta-18", "usrs");
 impl_remote!(Degree19, REMOTE_URL, "resources/", "powers-of-beta-19", "usrs");
@@ -37,7 +40,7 @@ impl_remote!(Degree27, REMOTE_URL, "resources/", "powers-of-beta-27", "usrs");
 impl_remote!(Degree28, REMOTE_URL, "resources/", "powers-of-beta-28", "usrs");
 
 // Shifted Degrees
-impl_local!(ShiftedDegree15, "resources/", "shifted-powers-of-beta-15", "usrs");
+impl_local_prover!(ShiftedDegree15, "resources/", "shifted-powers-of-beta-15", "usrs");
 impl_remote!(ShiftedDegree16, REMOTE_URL, "resources/", "shifted-powers-of-beta-16", "usrs");
 impl_remote!(ShiftedDegree17, REMOTE_URL, "resources/", "shifted-powers-of-beta-17", "usrs");
 impl_remote!(ShiftedDegree18, REMOTE_URL, "resources/", "shifted-powers-of-beta-18", "usrs");
@@ -146,6 +149,28 @@ lazy_static! {
         InclusionVerifier::load_bytes().expect("Failed to load inclusion verifying key");
 }
 
+/// Returns the verifying key bytes for the `credits.aleo` functions and the inclusion circuit.
+///
+/// This method does not load any proving keys, and is suitable for the verifier-only parameter profile.
+pub fn load_verifying_key_bytes() -> Result<indexmap::IndexMap<&'static str, Vec<u8>>, crate::ParameterError> {
+    Ok(indexmap::IndexMap::from([
+        ("bond_public", BondPublicVerifier::load_bytes()?),
+        ("unbond_public", UnbondPublicVerifier::load_bytes()?),
+        ("unbond_delegator_as_validator", UnbondDelegatorAsValidatorVerifier::load_bytes()?),
+        ("claim_unbond_public", ClaimUnbondPublicVerifier::load_bytes()?),
+        ("set_validator_state", SetValidatorStateVerifier::load_bytes()?),
+        ("transfer_private", TransferPrivateVerifier::load_bytes()?),
+        ("transfer_public", TransferPublicVerifier::load_bytes()?),
+        ("transfer_private_to_public", TransferPrivateToPublicVerifier::load_bytes()?),
+        ("transfer_public_to_private", TransferPublicToPrivateVerifier::load_bytes()?),
+        ("join", JoinVerifier::load_bytes()?),
+        ("split", SplitVerifier::load_bytes()?),
+        ("fee_private", FeePrivateVerifier::load_bytes()?),
+        ("fee_public", FeePublicVerifier::load_bytes()?),
+        (TESTNET3_INCLUSION_FUNCTION_NAME, InclusionVerifier::load_bytes()?),
+    ]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -176,4 +201,11 @@ mod tests {
         InclusionProver::load_bytes().expect("Failed to load inclusion prover");
         InclusionVerifier::load_bytes().expect("Failed to load inclusion verifier");
     }
+
+    #[test]
+    fn test_load_verifying_key_bytes() {
+        let verifying_keys = load_verifying_key_bytes().expect("Failed to load verifying keys");
+        assert_eq!(verifying_keys.len(), 14);
+        assert_eq!(verifying_keys[TESTNET3_INCLUSION_FUNCTION_NAME], InclusionVerifier::load_bytes().unwrap());
+    }
 }
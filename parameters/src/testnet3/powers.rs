@@ -15,6 +15,7 @@
 use super::*;
 use snarkvm_curves::traits::{PairingCurve, PairingEngine};
 use snarkvm_utilities::{
+    deserialize_vec_in_parallel,
     CanonicalDeserialize,
     CanonicalSerialize,
     Compress,
@@ -104,6 +105,33 @@ impl<E: PairingEngine> PowersOfG<E> {
         })
     }
 
+    /// Initializes the portion of the hard-coded powers that is required by the verifier.
+    ///
+    /// Returns `(G, \gamma G, \beta H, { \beta^{max_degree - i} H })`, without deserializing
+    /// the remaining powers of beta G.
+    #[allow(clippy::type_complexity)]
+    pub fn load_verifier_powers() -> Result<(E::G1Affine, E::G1Affine, E::G2Affine, Arc<BTreeMap<usize, E::G2Affine>>)>
+    {
+        // Deserialize only the first power of beta G, which is the generator `G`.
+        let mut reader = &POWERS_OF_BETA_G_15[..];
+        let num_powers = u64::deserialize_uncompressed_unchecked(&mut reader)?;
+        ensure!(num_powers as usize == NUM_POWERS_15, "Incorrect number of powers in the recovered SRS");
+        let g = E::G1Affine::deserialize_uncompressed_unchecked(&mut reader)?;
+
+        // Reconstruct the first power of beta_times_gamma_g.
+        let powers_of_beta_times_gamma_g: BTreeMap<usize, E::G1Affine> =
+            BTreeMap::deserialize_uncompressed_unchecked(&**POWERS_OF_BETA_GAMMA_G)?;
+        let gamma_g = *powers_of_beta_times_gamma_g.get(&0).ok_or_else(|| anyhow!("Missing gamma G in the SRS"))?;
+
+        // Reconstruct negative powers of beta_h.
+        let negative_powers_of_beta_h =
+            Arc::new(BTreeMap::deserialize_uncompressed_unchecked(&**NEG_POWERS_OF_BETA_H)?);
+
+        let beta_h = E::G2Affine::deserialize_uncompressed_unchecked(&**BETA_H)?;
+
+        Ok((g, gamma_g, beta_h, negative_powers_of_beta_h))
+    }
+
     /// Download the powers of beta G specified by `range`.
     pub fn download_powers_for(&mut self, range: Range<usize>) -> Result<()> {
         self.powers_of_beta_g.download_powers_for(&range)
@@ -242,12 +270,13 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
     /// Initializes the hard-coded instance of the powers.
     fn load() -> Result<Self> {
         // Deserialize the group elements.
-        let powers_of_beta_g = Vec::deserialize_uncompressed_unchecked(&**POWERS_OF_BETA_G_15)?;
+        let powers_of_beta_g = deserialize_vec_in_parallel(&**POWERS_OF_BETA_G_15, Compress::No, Validate::No)?;
 
         // Ensure the number of elements is correct.
         ensure!(powers_of_beta_g.len() == NUM_POWERS_15, "Incorrect number of powers in the recovered SRS");
 
-        let shifted_powers_of_beta_g = Vec::deserialize_uncompressed_unchecked(&**SHIFTED_POWERS_OF_BETA_G_15)?;
+        let shifted_powers_of_beta_g =
+            deserialize_vec_in_parallel(&**SHIFTED_POWERS_OF_BETA_G_15, Compress::No, Validate::No)?;
         ensure!(shifted_powers_of_beta_g.len() == NUM_POWERS_15, "Incorrect number of powers in the recovered SRS");
         Ok(PowersOfBetaG { powers_of_beta_g, shifted_powers_of_beta_g })
     }
@@ -420,7 +449,8 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             };
 
             // Deserialize the group elements.
-            let additional_powers = Vec::deserialize_uncompressed_unchecked(&*additional_bytes)?;
+            let additional_powers: Vec<E::G1Affine> =
+                deserialize_vec_in_parallel(&*additional_bytes, Compress::No, Validate::No)?;
             // Extend the powers.
             self.powers_of_beta_g.extend(&additional_powers);
         }
@@ -497,7 +527,7 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             };
 
             // Deserialize the group elements.
-            let additional_powers = Vec::deserialize_uncompressed_unchecked(&*additional_bytes)?;
+            let additional_powers = deserialize_vec_in_parallel(&*additional_bytes, Compress::No, Validate::No)?;
 
             if final_powers.is_empty() {
                 final_powers = additional_powers;
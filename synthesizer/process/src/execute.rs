@@ -71,7 +71,7 @@ mod tests {
 
         // Sample a private key.
         let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-        let owner = Address::try_from(private_key).unwrap();
+        let owner = Address::try_from(&private_key).unwrap();
 
         // Sample a base fee in microcredits.
         let base_fee_in_microcredits = rng.gen_range(1_000_000..u64::MAX / 2);
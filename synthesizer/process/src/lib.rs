@@ -155,7 +155,7 @@ impl<N: Network> Process<N> {
         for function_name in program.functions().keys() {
             // Load the verifying key.
             let verifying_key = N::get_credits_verifying_key(function_name.to_string())?;
-            stack.insert_verifying_key(function_name, VerifyingKey::new(verifying_key.clone()))?;
+            stack.insert_verifying_key(function_name, VerifyingKey::new(verifying_key))?;
             lap!(timer, "Load verifying key for {function_name}");
         }
         lap!(timer, "Load circuit keys");
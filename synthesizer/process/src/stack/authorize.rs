@@ -40,7 +40,7 @@ impl<N: Network> Stack<N> {
         // Initialize the authorization.
         let authorization = Authorization::new(request.clone());
         // Construct the call stack.
-        let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone());
+        let call_stack = CallStack::Authorize(vec![request], private_key.clone(), authorization.clone());
         // Construct the authorization from the function.
         let _response = self.execute_function::<A, R>(call_stack, None, rng)?;
         finish!(timer, "Construct the authorization from the function");
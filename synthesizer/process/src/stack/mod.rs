@@ -102,23 +102,25 @@ impl<N: Network> CallStack<N> {
     pub fn replicate(&self) -> Self {
         match self {
             CallStack::Authorize(requests, private_key, authorization) => {
-                CallStack::Authorize(requests.clone(), *private_key, authorization.replicate())
+                CallStack::Authorize(requests.clone(), private_key.clone(), authorization.replicate())
             }
             CallStack::Synthesize(requests, private_key, authorization) => {
-                CallStack::Synthesize(requests.clone(), *private_key, authorization.replicate())
+                CallStack::Synthesize(requests.clone(), private_key.clone(), authorization.replicate())
             }
             CallStack::CheckDeployment(requests, private_key, assignments) => CallStack::CheckDeployment(
                 requests.clone(),
-                *private_key,
+                private_key.clone(),
                 Arc::new(RwLock::new(assignments.read().clone())),
             ),
             CallStack::Evaluate(authorization) => CallStack::Evaluate(authorization.replicate()),
             CallStack::Execute(authorization, trace) => {
                 CallStack::Execute(authorization.replicate(), Arc::new(RwLock::new(trace.read().clone())))
             }
-            CallStack::PackageRun(requests, private_key, assignments) => {
-                CallStack::PackageRun(requests.clone(), *private_key, Arc::new(RwLock::new(assignments.read().clone())))
-            }
+            CallStack::PackageRun(requests, private_key, assignments) => CallStack::PackageRun(
+                requests.clone(),
+                private_key.clone(),
+                Arc::new(RwLock::new(assignments.read().clone())),
+            ),
         }
     }
 
@@ -396,7 +398,7 @@ impl<N: Network> Stack<N> {
             // Load the 'credits.aleo' function proving key.
             let proving_key = N::get_credits_proving_key(function_name.to_string())?;
             // Insert the 'credits.aleo' function proving key.
-            self.insert_proving_key(function_name, ProvingKey::new(proving_key.clone()))?;
+            self.insert_proving_key(function_name, ProvingKey::new(proving_key))?;
         }
         Ok(())
     }
@@ -64,7 +64,7 @@ pub fn sample_fee<N: Network, A: Aleo<Network = N>, B: BlockStorage<N>, P: Final
 
     // Sample a random private key.
     let private_key = PrivateKey::<N>::new(rng).unwrap();
-    let address = Address::try_from(private_key).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
 
     // Construct the key.
     let key = Plaintext::from(Literal::Address(address));
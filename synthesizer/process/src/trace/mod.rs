@@ -284,7 +284,7 @@ impl<N: Network> Trace<N> {
 
         if !batch_inclusions.is_empty() {
             // Fetch the inclusion proving key.
-            let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
+            let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key()?);
             // Insert the inclusion proving key and assignments.
             proving_tasks.push((proving_key, batch_inclusions));
         }
@@ -309,7 +309,7 @@ impl<N: Network> Trace<N> {
         // Insert the batch of inclusion verifier inputs to the verifier inputs.
         if !batch_inclusion_inputs.is_empty() {
             // Fetch the inclusion verifying key.
-            let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key().clone());
+            let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key());
             // Insert the inclusion verifier inputs.
             verifier_inputs.push((verifying_key, batch_inclusion_inputs));
         }
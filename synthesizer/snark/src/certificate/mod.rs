@@ -40,7 +40,7 @@ impl<N: Network> Certificate<N> {
         let timer = std::time::Instant::now();
 
         // Retrieve the proving parameters.
-        let universal_prover = N::varuna_universal_prover();
+        let universal_prover = N::varuna_universal_prover()?;
         let fiat_shamir = N::varuna_fs_parameters();
 
         // Compute the certificate.
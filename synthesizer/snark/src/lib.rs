@@ -43,6 +43,9 @@ pub use universal_srs::UniversalSRS;
 mod verifying_key;
 pub use verifying_key::VerifyingKey;
 
+#[cfg(any(test, feature = "test-vectors"))]
+pub mod test_vectors;
+
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
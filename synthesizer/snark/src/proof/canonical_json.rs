@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde_json::{json, Value};
+
+impl<N: Network> CanonicalJson for Proof<N> {
+    const TYPE: &'static str = "proof";
+    const VERSION: u8 = 1;
+
+    /// Returns the batch sizes and the hex-encoded bytes of the proof.
+    fn to_json_body(&self) -> Result<Value> {
+        Ok(json!({
+            "batch_sizes": self.batch_sizes(),
+            "proof": to_canonical_hex(&self.to_bytes_le()?),
+        }))
+    }
+
+    /// Returns the proof from its batch sizes and hex-encoded bytes.
+    fn from_json_body(body: Value) -> Result<Self> {
+        // Recover the proof.
+        let Some(proof) = body.get("proof").and_then(Value::as_str) else {
+            bail!("The proof is missing the \"proof\" field")
+        };
+        let proof = Self::read_le(&from_canonical_hex(proof)?[..])?;
+        // Ensure the batch sizes match.
+        let batch_sizes: Vec<usize> = serde_json::from_value(body.get("batch_sizes").cloned().unwrap_or_default())?;
+        ensure!(batch_sizes == proof.batch_sizes(), "Mismatching batch sizes in the proof");
+        Ok(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_canonical_json() -> Result<()> {
+        // Sample the proof.
+        let expected = crate::test_helpers::sample_proof();
+
+        // Check the round trip.
+        let candidate = expected.to_canonical_json()?;
+        assert!(candidate.starts_with(r#"{"body":{"batch_sizes":[1],"proof":""#));
+        assert!(candidate.ends_with(r#""},"type":"proof","version":1}"#));
+        assert_eq!(expected, Proof::<CurrentNetwork>::from_canonical_json(&candidate)?);
+
+        // Check that mismatching batch sizes and non-canonical hex are rejected.
+        let mismatching = candidate.replace(r#""batch_sizes":[1]"#, r#""batch_sizes":[2]"#);
+        assert!(Proof::<CurrentNetwork>::from_canonical_json(&mismatching).is_err());
+        let hex = to_canonical_hex(&expected.to_bytes_le()?);
+        let uppercase = candidate.replace(&hex, &hex.to_uppercase());
+        assert!(Proof::<CurrentNetwork>::from_canonical_json(&uppercase).is_err());
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@
 use super::*;
 
 mod bytes;
+mod canonical_json;
 mod parse;
 mod serialize;
 
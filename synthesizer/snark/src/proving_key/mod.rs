@@ -43,7 +43,7 @@ impl<N: Network> ProvingKey<N> {
         let timer = std::time::Instant::now();
 
         // Retrieve the proving parameters.
-        let universal_prover = N::varuna_universal_prover();
+        let universal_prover = N::varuna_universal_prover()?;
         let fiat_shamir = N::varuna_fs_parameters();
 
         // Compute the proof.
@@ -71,7 +71,7 @@ impl<N: Network> ProvingKey<N> {
             .collect();
 
         // Retrieve the proving parameters.
-        let universal_prover = N::varuna_universal_prover();
+        let universal_prover = N::varuna_universal_prover()?;
         let fiat_shamir = N::varuna_fs_parameters();
 
         // Compute the proof.
@@ -0,0 +1,199 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic proof test vectors for cross-implementation compatibility.
+//!
+//! Given a seed, `ProofVectors::generate` proves a fixed circuit on seeded inputs, and serializes
+//! the verifying key, inputs, and proofs to a JSON fixture. `ProofVectors::verify` re-checks every
+//! proof with the verifying key, and ensures the fixture is reproducible from its seed.
+
+use super::*;
+use circuit::{
+    environment::{Assignment, Eject, Environment, Inject, Mode},
+    types::Field as CircuitField,
+};
+use console::types::Field;
+
+use serde_json::{json, Value};
+
+/// The exponent of the test-vector circuit, which proves that `y = x^EXPONENT` for public `x` and `y`.
+const EXPONENT: usize = 16;
+/// The function name used to prove and verify the test vectors.
+pub const FUNCTION_NAME: &str = "test_vectors";
+
+/// A proof, and the public inputs it was verified against.
+#[derive(Clone, Debug)]
+pub struct ProofVector<N: Network> {
+    pub inputs: Vec<Field<N>>,
+    pub proof: Proof<N>,
+}
+
+/// A set of proof test vectors, generated deterministically from a seed.
+#[derive(Clone, Debug)]
+pub struct ProofVectors<N: Network> {
+    /// The seed of the test vectors.
+    seed: u64,
+    /// The verifying key of the test-vector circuit.
+    verifying_key: VerifyingKey<N>,
+    /// The proof vectors.
+    proofs: Vec<ProofVector<N>>,
+}
+
+impl<N: Network> ProofVectors<N> {
+    /// Generates `num_vectors` proofs from the given seed, using the circuit environment `A`.
+    pub fn generate<A: Environment<Network = N>>(seed: u64, num_vectors: usize) -> Result<Self> {
+        ensure!(num_vectors > 0, "Expected at least one proof test vector");
+        // Note: `TestRng` is a portable, seedable RNG, so the vectors are identical across platforms.
+        let rng = &mut TestRng::from_seed(seed);
+
+        // Sample the public inputs, and synthesize each assignment.
+        let assignments = (0..num_vectors)
+            .map(|_| {
+                let x = Uniform::rand(rng);
+                let assignment = Self::synthesize::<A>(x);
+                (vec![Field::one(), x, x.pow(Field::from_u64(EXPONENT as u64))], assignment)
+            })
+            .collect::<Vec<_>>();
+
+        // Note: Circuit key derivation is deterministic, so only the proofs depend on the RNG.
+        let srs = UniversalSRS::<N>::load()?;
+        let (proving_key, verifying_key) = srs.to_circuit_key(FUNCTION_NAME, &assignments[0].1)?;
+
+        let proofs = assignments
+            .into_iter()
+            .map(|(inputs, assignment)| {
+                let proof = proving_key.prove(FUNCTION_NAME, &assignment, rng)?;
+                Ok(ProofVector { inputs, proof })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { seed, verifying_key, proofs })
+    }
+
+    /// Returns the assignment of the test-vector circuit on the given input.
+    fn synthesize<A: Environment<Network = N>>(x: Field<N>) -> Assignment<N::Field> {
+        let x = CircuitField::<A>::new(Mode::Public, x);
+        let mut y = x.clone();
+        for _ in 1..EXPONENT {
+            y *= &x;
+        }
+        let expected = CircuitField::<A>::new(Mode::Public, y.eject_value());
+        A::assert_eq(&expected, &y);
+        A::eject_assignment_and_reset()
+    }
+
+    /// Returns the seed of the test vectors.
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the verifying key of the test-vector circuit.
+    pub const fn verifying_key(&self) -> &VerifyingKey<N> {
+        &self.verifying_key
+    }
+
+    /// Returns the proof vectors.
+    pub fn proofs(&self) -> &[ProofVector<N>] {
+        &self.proofs
+    }
+
+    /// Ensures every proof verifies, and that the test vectors are reproducible from their seed.
+    pub fn verify<A: Environment<Network = N>>(&self) -> Result<()> {
+        for (i, vector) in self.proofs.iter().enumerate() {
+            let inputs = vector.inputs.iter().map(|input| **input).collect::<Vec<_>>();
+            ensure!(self.verifying_key.verify(FUNCTION_NAME, &inputs, &vector.proof), "Invalid proof {i}");
+        }
+
+        // Ensure the test vectors are reproducible from the seed.
+        let expected = Self::generate::<A>(self.seed, self.proofs.len())?;
+        ensure!(expected.to_json()? == self.to_json()?, "The test vectors do not match their seed");
+        Ok(())
+    }
+
+    /// Returns the test vectors as a JSON fixture.
+    pub fn to_json(&self) -> Result<String> {
+        let proofs = self.proofs.iter().map(|vector| {
+            json!({
+                "inputs": vector.inputs,
+                "proof": vector.proof,
+            })
+        });
+        Ok(serde_json::to_string_pretty(&json!({
+            "network": N::NAME,
+            "seed": self.seed,
+            "verifying_key": self.verifying_key,
+            "proofs": proofs.collect::<Vec<_>>(),
+        }))?)
+    }
+
+    /// Returns the test vectors from a JSON fixture.
+    pub fn from_json(fixture: &str) -> Result<Self> {
+        let mut fixture: Value = serde_json::from_str(fixture)?;
+
+        // Ensure the fixture is for this network.
+        let network: String = take(&mut fixture, "network")?;
+        ensure!(network == N::NAME, "Expected test vectors for '{}', found '{network}'", N::NAME);
+
+        let seed = take(&mut fixture, "seed")?;
+        let verifying_key = take(&mut fixture, "verifying_key")?;
+        let proofs = take::<Vec<Value>>(&mut fixture, "proofs")?
+            .into_iter()
+            .map(|mut vector| {
+                Ok(ProofVector { inputs: take(&mut vector, "inputs")?, proof: take(&mut vector, "proof")? })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { seed, verifying_key, proofs })
+    }
+}
+
+/// Removes and deserializes the given field from the given JSON object.
+fn take<T: DeserializeOwned>(value: &mut Value, field: &str) -> Result<T> {
+    match value.get_mut(field) {
+        Some(value) => Ok(serde_json::from_value(value.take())?),
+        None => bail!("Missing field '{field}' in the test vectors"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::environment::Circuit;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_proof_vectors() -> Result<()> {
+        let vectors = ProofVectors::<CurrentNetwork>::generate::<Circuit>(1234, 2)?;
+        vectors.verify::<Circuit>()?;
+
+        // Check that the test vectors are deterministic.
+        let fixture = vectors.to_json()?;
+        assert_eq!(fixture, ProofVectors::<CurrentNetwork>::generate::<Circuit>(1234, 2)?.to_json()?);
+        assert_ne!(fixture, ProofVectors::<CurrentNetwork>::generate::<Circuit>(4321, 2)?.to_json()?);
+
+        // Check that the fixture round-trips, and re-verifies.
+        let candidate = ProofVectors::<CurrentNetwork>::from_json(&fixture)?;
+        candidate.verify::<Circuit>()?;
+        assert_eq!(fixture, candidate.to_json()?);
+
+        // Check that a tampered fixture is rejected.
+        let mut tampered: Value = serde_json::from_str(&fixture)?;
+        tampered["proofs"][0]["inputs"][1] = json!(Field::<CurrentNetwork>::from_u64(7));
+        let tampered = ProofVectors::<CurrentNetwork>::from_json(&tampered.to_string())?;
+        assert!(tampered.verify::<Circuit>().is_err());
+        Ok(())
+    }
+}
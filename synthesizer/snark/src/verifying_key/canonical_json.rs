@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde_json::{json, Value};
+
+impl<N: Network> CanonicalJson for VerifyingKey<N> {
+    const TYPE: &'static str = "verifying_key";
+    const VERSION: u8 = 1;
+
+    /// Returns the circuit ID, the circuit info, and the hex-encoded bytes of the verifying key.
+    fn to_json_body(&self) -> Result<Value> {
+        let info = &self.circuit_info;
+        Ok(json!({
+            "circuit_id": to_canonical_hex(&self.id.0),
+            "circuit_info": {
+                "num_constraints": info.num_constraints,
+                "num_non_zero_a": info.num_non_zero_a,
+                "num_non_zero_b": info.num_non_zero_b,
+                "num_non_zero_c": info.num_non_zero_c,
+                "num_public_inputs": info.num_public_inputs,
+                "num_variables": info.num_variables,
+            },
+            "verifying_key": to_canonical_hex(&self.to_bytes_le()?),
+        }))
+    }
+
+    /// Returns the verifying key from its hex-encoded bytes, after checking its circuit ID and circuit info.
+    fn from_json_body(body: Value) -> Result<Self> {
+        // Recover the verifying key.
+        let Some(verifying_key) = body.get("verifying_key").and_then(Value::as_str) else {
+            bail!("The verifying key is missing the \"verifying_key\" field")
+        };
+        let verifying_key = Self::read_le(&from_canonical_hex(verifying_key)?[..])?;
+        // Ensure the circuit ID and circuit info match.
+        let expected = verifying_key.to_json_body()?;
+        ensure!(body.get("circuit_id") == expected.get("circuit_id"), "Mismatching circuit ID in the verifying key");
+        ensure!(
+            body.get("circuit_info") == expected.get("circuit_info"),
+            "Mismatching circuit info in the verifying key"
+        );
+        Ok(verifying_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_canonical_json() -> Result<()> {
+        // Sample the verifying key.
+        let (_, expected) = crate::test_helpers::sample_keys();
+
+        // Check the round trip.
+        let candidate = expected.to_canonical_json()?;
+        let circuit_id = format!(r#"{{"body":{{"circuit_id":"{}","circuit_info":{{"#, expected.id);
+        assert!(candidate.starts_with(&circuit_id));
+        assert!(candidate.ends_with(r#""},"type":"verifying_key","version":1}"#));
+        assert_eq!(expected, VerifyingKey::<CurrentNetwork>::from_canonical_json(&candidate)?);
+
+        // Check that a mismatching circuit info is rejected.
+        let num_constraints = format!(r#""num_constraints":{}"#, expected.circuit_info.num_constraints);
+        let mismatching = candidate.replace(&num_constraints, r#""num_constraints":0"#);
+        assert!(VerifyingKey::<CurrentNetwork>::from_canonical_json(&mismatching).is_err());
+
+        // Check that a proof is rejected.
+        let proof = crate::test_helpers::sample_proof().to_canonical_json()?;
+        assert!(VerifyingKey::<CurrentNetwork>::from_canonical_json(&proof).is_err());
+        Ok(())
+    }
+}
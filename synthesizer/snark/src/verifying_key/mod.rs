@@ -15,6 +15,7 @@
 use super::*;
 
 mod bytes;
+mod canonical_json;
 mod parse;
 mod serialize;
 
@@ -74,7 +75,12 @@ impl<N: Network> VerifyingKey<N> {
         let fiat_shamir = N::varuna_fs_parameters();
 
         // Verify the batch proof.
-        match Varuna::<N>::verify_batch(universal_verifier, fiat_shamir, &keys_to_inputs, proof) {
+        let num_instances = inputs.iter().map(|(_, inputs)| inputs.len()).sum();
+        match try_execute_in(Subsystem::BatchVerification, num_instances, || {
+            Varuna::<N>::verify_batch(universal_verifier, fiat_shamir, &keys_to_inputs, proof)
+        })
+        .and_then(|result| result)
+        {
             Ok(is_valid) => {
                 #[cfg(feature = "aleo-cli")]
                 println!("{}", format!(" • Verified '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
@@ -1028,7 +1028,7 @@ finalize transfer_public:
     /// Create an execution transaction.
     fn create_execution(
         vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>,
-        caller_private_key: PrivateKey<CurrentNetwork>,
+        caller_private_key: &PrivateKey<CurrentNetwork>,
         program_id: &str,
         function_name: &str,
         inputs: Vec<Value<CurrentNetwork>>,
@@ -1043,7 +1043,7 @@ finalize transfer_public:
 
         // Execute.
         let transaction = vm
-            .execute(&caller_private_key, (program_id, function_name), inputs.into_iter(), credits, 1, None, rng)
+            .execute(caller_private_key, (program_id, function_name), inputs.into_iter(), credits, 1, None, rng)
             .unwrap();
         // Verify.
         vm.check_transaction(&transaction, None, rng).unwrap();
@@ -1055,7 +1055,7 @@ finalize transfer_public:
     /// Sample a public mint transaction.
     fn sample_mint_public(
         vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>,
-        caller_private_key: PrivateKey<CurrentNetwork>,
+        caller_private_key: &PrivateKey<CurrentNetwork>,
         program_id: &str,
         recipient: Address<CurrentNetwork>,
         amount: u64,
@@ -1073,7 +1073,7 @@ finalize transfer_public:
     /// Sample a public transfer transaction.
     fn sample_transfer_public(
         vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>,
-        caller_private_key: PrivateKey<CurrentNetwork>,
+        caller_private_key: &PrivateKey<CurrentNetwork>,
         program_id: &str,
         recipient: Address<CurrentNetwork>,
         amount: u64,
@@ -1194,7 +1194,7 @@ finalize transfer_public:
 
         // Construct the initial mint.
         let initial_mint =
-            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
+            sample_mint_public(&vm, &caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
         let initial_mint_block =
             sample_next_block(&vm, &caller_private_key, &[initial_mint], &splits_block, &mut unspent_records, rng)
                 .unwrap();
@@ -1204,12 +1204,12 @@ finalize transfer_public:
 
         // Construct a mint and a transfer.
         let mint_10 =
-            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 10, &mut unspent_records, rng);
+            sample_mint_public(&vm, &caller_private_key, &program_id, caller_address, 10, &mut unspent_records, rng);
         let mint_20 =
-            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
+            sample_mint_public(&vm, &caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
         let transfer_10 = sample_transfer_public(
             &vm,
-            caller_private_key,
+            &caller_private_key,
             &program_id,
             recipient_address,
             10,
@@ -1218,7 +1218,7 @@ finalize transfer_public:
         );
         let transfer_20 = sample_transfer_public(
             &vm,
-            caller_private_key,
+            &caller_private_key,
             &program_id,
             recipient_address,
             20,
@@ -1227,7 +1227,7 @@ finalize transfer_public:
         );
         let transfer_30 = sample_transfer_public(
             &vm,
-            caller_private_key,
+            &caller_private_key,
             &program_id,
             recipient_address,
             30,
@@ -1403,7 +1403,7 @@ function ped_hash:
             // Construct a transaction that will cause a E::halt in the finalize execution.
             let inputs = vec![Value::<CurrentNetwork>::from_str("1u128").unwrap()];
             let transaction =
-                create_execution(&vm, caller_private_key, program_id, "ped_hash", inputs, &mut unspent_records, rng);
+                create_execution(&vm, &caller_private_key, program_id, "ped_hash", inputs, &mut unspent_records, rng);
 
             // Speculatively execute the transaction. Ensure that this call does not panic and returns a rejected transaction.
             let (_, confirmed_transactions, aborted_transaction_ids, _) =
@@ -1486,7 +1486,7 @@ finalize compute:
             .unwrap();
 
             // Prepare the additional fee.
-            let view_key = ViewKey::<CurrentNetwork>::try_from(private_key).unwrap();
+            let view_key = ViewKey::<CurrentNetwork>::try_from(&private_key).unwrap();
             let credits = Some(unspent_records.pop().unwrap().decrypt(&view_key).unwrap());
 
             // Deploy.
@@ -1507,7 +1507,7 @@ finalize compute:
 
         // Create an execution transaction, that will be rejected.
         let r0 = Value::<CurrentNetwork>::from_str("100u8").unwrap();
-        let first = create_execution(&vm, private_key, "testing.aleo", "compute", vec![r0], &mut unspent_records, rng);
+        let first = create_execution(&vm, &private_key, "testing.aleo", "compute", vec![r0], &mut unspent_records, rng);
 
         // Construct the next block.
         let next_block =
@@ -1535,11 +1535,12 @@ finalize compute:
 
         // Create an execution transaction, that will be rejected.
         let r0 = Value::<CurrentNetwork>::from_str("100u8").unwrap();
-        let first = create_execution(&vm, private_key, "testing.aleo", "compute", vec![r0], &mut unspent_records, rng);
+        let first = create_execution(&vm, &private_key, "testing.aleo", "compute", vec![r0], &mut unspent_records, rng);
 
         // Create an execution transaction, that will be accepted.
         let r0 = Value::<CurrentNetwork>::from_str("1u8").unwrap();
-        let second = create_execution(&vm, private_key, "testing.aleo", "compute", vec![r0], &mut unspent_records, rng);
+        let second =
+            create_execution(&vm, &private_key, "testing.aleo", "compute", vec![r0], &mut unspent_records, rng);
 
         // Construct the next block.
         let next_block =
@@ -1609,8 +1610,15 @@ finalize compute:
         let mut excess_transaction_ids = Vec::new();
 
         for _ in 0..VM::<CurrentNetwork, ConsensusMemory<_>>::MAXIMUM_CONFIRMED_TRANSACTIONS + 1 {
-            let transaction =
-                sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 10, &mut unspent_records, rng);
+            let transaction = sample_mint_public(
+                &vm,
+                &caller_private_key,
+                &program_id,
+                caller_address,
+                10,
+                &mut unspent_records,
+                rng,
+            );
             // Abort the transaction if the block is full.
             if transactions.len() >= VM::<CurrentNetwork, ConsensusMemory<_>>::MAXIMUM_CONFIRMED_TRANSACTIONS {
                 excess_transaction_ids.push(transaction.id());
@@ -232,16 +232,16 @@ pub fn to_next_commitee_map_and_bonded_map<N: Network>(
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
-    use crate::vm::TestRng;
+    use console::prelude::SeededRng;
     use ledger_committee::MIN_VALIDATOR_STAKE;
 
-    use rand::{CryptoRng, Rng};
+    use rand::Rng;
 
     /// Returns the stakers, given the map of `(validator, (microcredits, is_open))` entries.
     /// This method simulates the existence of delegators for the members.
-    pub(crate) fn to_stakers<N: Network, R: Rng + CryptoRng>(
+    pub(crate) fn to_stakers<N: Network>(
         members: &IndexMap<Address<N>, (u64, bool)>,
-        rng: &mut R,
+        rng: &mut SeededRng,
     ) -> IndexMap<Address<N>, (Address<N>, u64)> {
         members
             .into_iter()
@@ -254,7 +254,7 @@ pub(crate) mod test_helpers {
                 let num_iterations = (remaining_microcredits / staker_amount).saturating_sub(1);
 
                 // Construct the map of stakers.
-                let rngs = (0..num_iterations).map(|_| TestRng::from_seed(rng.gen())).collect::<Vec<_>>();
+                let rngs = (0..num_iterations).map(|_| rng.fork()).collect::<Vec<_>>();
                 let mut stakers: IndexMap<_, _> = cfg_into_iter!(rngs)
                     .map(|mut rng| {
                         // Sample a random staker.
@@ -283,7 +283,7 @@ pub(crate) mod test_helpers {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::prelude::TestRng;
+    use console::prelude::SeededRng;
 
     #[allow(unused_imports)]
     use rayon::prelude::*;
@@ -329,7 +329,7 @@ mod tests {
 
     #[test]
     fn test_committee_map_into_committee() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
 
         // Sample a committee.
         let committee = ledger_committee::test_helpers::sample_committee_for_round_and_size(1, 100, rng);
@@ -347,7 +347,7 @@ mod tests {
 
     #[test]
     fn test_bonded_map_into_stakers() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
 
         // Sample a committee.
         let committee = ledger_committee::test_helpers::sample_committee_for_round_and_size(1, 100, rng);
@@ -367,7 +367,7 @@ mod tests {
 
     #[test]
     fn test_ensure_stakers_matches() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
 
         // Sample a committee.
         let committee = ledger_committee::test_helpers::sample_committee_for_round_and_size(1, 100, rng);
@@ -384,7 +384,7 @@ mod tests {
 
     #[test]
     fn test_to_next_committee() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
 
         // Sample a committee.
         let committee = ledger_committee::test_helpers::sample_committee_for_round_and_size(1, 100, rng);
@@ -403,7 +403,7 @@ mod tests {
 
     #[test]
     fn test_to_next_commitee_map_and_bonded_map() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
 
         // Sample a committee.
         let committee = ledger_committee::test_helpers::sample_committee(rng);
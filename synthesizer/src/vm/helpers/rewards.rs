@@ -127,7 +127,7 @@ pub fn proving_rewards<N: Network>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::prelude::TestRng;
+    use console::prelude::SeededRng;
 
     use indexmap::indexmap;
 
@@ -137,7 +137,7 @@ mod tests {
 
     #[test]
     fn test_staking_rewards() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         // Sample a random committee.
         let committee = ledger_committee::test_helpers::sample_committee(rng);
         // Sample a random block reward.
@@ -162,7 +162,7 @@ mod tests {
 
     #[test]
     fn test_staking_rewards_large() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
 
         // Sample a random block reward.
         let block_reward = rng.gen_range(0..MAX_COINBASE_REWARD);
@@ -189,7 +189,7 @@ mod tests {
 
     #[test]
     fn test_staking_rewards_when_staker_is_under_min_yields_no_reward() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         // Sample a random committee.
         let committee = ledger_committee::test_helpers::sample_committee(rng);
         // Sample a random block reward.
@@ -213,7 +213,7 @@ mod tests {
 
     #[test]
     fn test_staking_rewards_cannot_exceed_coinbase_reward() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         // Sample a random committee.
         let committee = ledger_committee::test_helpers::sample_committee(rng);
         // Retrieve an address.
@@ -241,7 +241,7 @@ mod tests {
 
     #[test]
     fn test_staking_rewards_is_empty() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         // Sample a random committee.
         let committee = ledger_committee::test_helpers::sample_committee(rng);
 
@@ -252,7 +252,7 @@ mod tests {
 
     #[test]
     fn test_proving_rewards() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
 
         for _ in 0..ITERATIONS {
             // Sample a random address.
@@ -270,7 +270,7 @@ mod tests {
 
     #[test]
     fn test_proving_rewards_cannot_exceed_coinbase_reward() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
 
         // Ensure a proving reward that is too large, renders no rewards.
         for _ in 0..ITERATIONS {
@@ -288,7 +288,7 @@ mod tests {
 
     #[test]
     fn test_proving_rewards_is_empty() {
-        let rng = &mut TestRng::default();
+        let rng = &mut SeededRng::default();
         // Sample a random address.
         let address = Address::rand(rng);
 
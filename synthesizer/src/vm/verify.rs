@@ -40,7 +40,18 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         rejected_id: Option<Field<N>>,
         rng: &mut R,
     ) -> Result<()> {
-        let timer = timer!("VM::check_transaction");
+        // Ensure the transaction is unique, and consistent with the ledger state.
+        self.check_transaction_state(transaction)?;
+        // Verify the transaction ID, fee, and proofs.
+        self.check_transaction_proofs(transaction, rejected_id, rng)
+    }
+
+    /// Verifies the transaction does not conflict with the ledger state. On failure, returns an error.
+    ///
+    /// This check is cheap, and must be repeated against the latest state before the transaction is
+    /// added to the ledger, even if `check_transaction_proofs` was performed against an earlier state.
+    pub fn check_transaction_state(&self, transaction: &Transaction<N>) -> Result<()> {
+        let timer = timer!("VM::check_transaction_state");
 
         /* Transaction */
 
@@ -49,17 +60,6 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             bail!("Transaction '{}' already exists in the ledger", transaction.id())
         }
 
-        // Compute the Merkle root of the transaction.
-        match transaction.to_root() {
-            // Ensure the transaction ID is correct.
-            Ok(root) if *transaction.id() != root => bail!("Incorrect transaction ID ({})", transaction.id()),
-            Ok(_) => (),
-            Err(error) => {
-                bail!("Failed to compute the Merkle root of the transaction: {error}\n{transaction}");
-            }
-        };
-        lap!(timer, "Verify the transaction ID");
-
         /* Transition */
 
         // Ensure the transition IDs are unique.
@@ -92,6 +92,51 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         lap!(timer, "Check for duplicate elements");
 
+        match transaction {
+            Transaction::Deploy(_, _, deployment, _) => {
+                // Ensure the program ID does not already exist..
+                if self.transaction_store().contains_program_id(deployment.program_id())? {
+                    bail!("Program ID '{}' is already deployed", deployment.program_id())
+                }
+            }
+            Transaction::Execute(id, execution, _) => {
+                // Compute the execution ID.
+                let Ok(execution_id) = execution.to_execution_id() else {
+                    bail!("Failed to compute the Merkle root for an execution transaction '{id}'")
+                };
+                // Ensure the execution was not previously rejected (replay attack prevention).
+                if self.block_store().contains_rejected_deployment_or_execution_id(&execution_id)? {
+                    bail!("Transaction '{id}' contains a previously rejected execution")
+                }
+            }
+            Transaction::Fee(..) => { /* no-op */ }
+        }
+
+        finish!(timer, "Check the transaction state");
+        Ok(())
+    }
+
+    /// Verifies the transaction ID, fee, and the deployment or execution in the transaction,
+    /// without checking for conflicts with the ledger state. On failure, returns an error.
+    pub fn check_transaction_proofs<R: CryptoRng + Rng>(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("VM::check_transaction_proofs");
+
+        // Compute the Merkle root of the transaction.
+        match transaction.to_root() {
+            // Ensure the transaction ID is correct.
+            Ok(root) if *transaction.id() != root => bail!("Incorrect transaction ID ({})", transaction.id()),
+            Ok(_) => (),
+            Err(error) => {
+                bail!("Failed to compute the Merkle root of the transaction: {error}\n{transaction}");
+            }
+        };
+        lap!(timer, "Verify the transaction ID");
+
         // First, verify the fee.
         self.check_fee(transaction, rejected_id)?;
 
@@ -108,22 +153,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if deployment.edition() != N::EDITION {
                     bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
                 }
-                // Ensure the program ID does not already exist..
-                if self.transaction_store().contains_program_id(deployment.program_id())? {
-                    bail!("Program ID '{}' is already deployed", deployment.program_id())
-                }
                 // Verify the deployment.
                 self.check_deployment_internal(deployment, rng)?;
             }
-            Transaction::Execute(id, execution, _) => {
-                // Compute the execution ID.
-                let Ok(execution_id) = execution.to_execution_id() else {
-                    bail!("Failed to compute the Merkle root for an execution transaction '{id}'")
-                };
-                // Ensure the execution was not previously rejected (replay attack prevention).
-                if self.block_store().contains_rejected_deployment_or_execution_id(&execution_id)? {
-                    bail!("Transaction '{id}' contains a previously rejected execution")
-                }
+            Transaction::Execute(_, execution, _) => {
                 // Verify the execution.
                 self.check_execution_internal(execution)?;
             }
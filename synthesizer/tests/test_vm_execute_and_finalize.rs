@@ -185,7 +185,7 @@ fn run_test(test: &ProgramTest) -> serde_yaml::Mapping {
                 PrivateKey::<CurrentNetwork>::from_str(private_key.as_str().expect("expected string for private key"))
                     .expect("unable to parse private key")
             }
-            None => genesis_private_key,
+            None => genesis_private_key.clone(),
         };
 
         // A helper function to run the test and extract the outputs as YAML, to be compared against the expectation.
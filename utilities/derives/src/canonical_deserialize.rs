@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::canonical_serialize::IdentOrIndex;
+use crate::canonical_serialize::{variant_pattern, variant_tags, IdentOrIndex};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Data, Index, Type};
@@ -20,6 +20,8 @@ use syn::{Data, Index, Type};
 fn impl_valid_field(
     check_body: &mut Vec<TokenStream>,
     batch_check_body: &mut Vec<TokenStream>,
+    base: &TokenStream,
+    base_is_ref: bool,
     idents: &mut Vec<IdentOrIndex>,
     ty: &Type,
 ) {
@@ -29,14 +31,18 @@ fn impl_valid_field(
             for (i, elem_ty) in tuple.elems.iter().enumerate() {
                 let index = Index::from(i);
                 idents.push(IdentOrIndex::Index(index));
-                impl_valid_field(check_body, batch_check_body, idents, elem_ty);
+                impl_valid_field(check_body, batch_check_body, base, base_is_ref, idents, elem_ty);
                 idents.pop();
             }
         }
         _ => {
-            check_body.push(quote! { snarkvm_utilities::Valid::check(&self.#(#idents).*)?; });
+            // If the base is already a reference (e.g. an enum match binding), pass it through as is.
+            match base_is_ref && idents.is_empty() {
+                true => check_body.push(quote! { snarkvm_utilities::Valid::check(#base)?; }),
+                false => check_body.push(quote! { snarkvm_utilities::Valid::check(&#base #(.#idents)*)?; }),
+            }
             batch_check_body
-                .push(quote! { snarkvm_utilities::Valid::batch_check(batch.iter().map(|v| &v.#(#idents).*))?; });
+                .push(quote! { snarkvm_utilities::Valid::batch_check(batch.iter().map(|v| &v #(.#idents)*))?; });
         }
     }
 }
@@ -46,17 +52,12 @@ fn impl_valid(ast: &syn::DeriveInput) -> TokenStream {
 
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
-    let len = if let Data::Struct(ref data_struct) = ast.data {
-        data_struct.fields.len()
-    } else {
-        panic!("`Valid` can only be derived for structs, {name} is not a struct");
-    };
-
-    let mut check_body = Vec::<TokenStream>::with_capacity(len);
-    let mut batch_body = Vec::<TokenStream>::with_capacity(len);
+    let mut check_body = Vec::<TokenStream>::new();
+    let mut batch_body = Vec::<TokenStream>::new();
 
     match ast.data {
         Data::Struct(ref data_struct) => {
+            let base = quote! { self };
             let mut idents = Vec::<IdentOrIndex>::new();
 
             for (i, field) in data_struct.fields.iter().enumerate() {
@@ -70,12 +71,36 @@ fn impl_valid(ast: &syn::DeriveInput) -> TokenStream {
                     }
                 }
 
-                impl_valid_field(&mut check_body, &mut batch_body, &mut idents, &field.ty);
+                impl_valid_field(&mut check_body, &mut batch_body, &base, false, &mut idents, &field.ty);
 
                 idents.clear();
             }
         }
-        _ => panic!("`Valid` can only be derived for structs, {name} is not a struct"),
+        Data::Enum(ref data_enum) => {
+            let mut check_cases = Vec::<TokenStream>::with_capacity(data_enum.variants.len());
+
+            for variant in data_enum.variants.iter() {
+                let (pattern, bindings) = variant_pattern(variant);
+
+                // Check the fields of the variant. The batch check of the variants is not
+                // vectorized, as the variants in a batch may differ.
+                let mut variant_check_body = Vec::<TokenStream>::with_capacity(bindings.len());
+                for (binding, field) in bindings.iter().zip(variant.fields.iter()) {
+                    let base = quote! { #binding };
+                    impl_valid_field(&mut variant_check_body, &mut vec![], &base, true, &mut vec![], &field.ty);
+                }
+
+                check_cases.push(quote! { #pattern => { #(#variant_check_body)* } });
+            }
+
+            check_body.push(quote! { match self { #(#check_cases)* } });
+            batch_body.push(quote! {
+                for v in batch {
+                    snarkvm_utilities::Valid::check(v)?;
+                }
+            });
+        }
+        Data::Union(_) => panic!("`Valid` cannot be derived for unions, {name} is a union"),
     };
 
     let gen = quote! {
@@ -153,13 +178,43 @@ pub(super) fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> TokenStream
                 })
             };
         }
-        _ => panic!("`CanonicalDeserialize` can only be derived for structs, {name} is not a Struct"),
+        Data::Enum(ref data_enum) => {
+            let tags = variant_tags(name, data_enum);
+
+            let mut variant_cases = Vec::<TokenStream>::with_capacity(tags.len());
+            for (variant, tag) in data_enum.variants.iter().zip(tags) {
+                let ident = &variant.ident;
+                let field_cases = variant.fields.iter().map(|field| {
+                    let compressed = impl_deserialize_field(&field.ty);
+                    match &field.ident {
+                        None => compressed,
+                        Some(ident) => quote! { #ident: #compressed },
+                    }
+                });
+                let variant_case = match variant.fields {
+                    syn::Fields::Named(_) => quote! { Self::#ident { #(#field_cases)* } },
+                    syn::Fields::Unnamed(_) => quote! { Self::#ident ( #(#field_cases)* ) },
+                    syn::Fields::Unit => quote! { Self::#ident },
+                };
+                variant_cases.push(quote! { #tag => Ok(#variant_case), });
+            }
+
+            // Deserialize the tag, and reject any tag that does not correspond to a variant.
+            deserialize_body = quote!({
+                let tag: u8 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+                match tag {
+                    #(#variant_cases)*
+                    _ => Err(snarkvm_utilities::serialize::SerializationError::InvalidData),
+                }
+            });
+        }
+        Data::Union(_) => panic!("`CanonicalDeserialize` cannot be derived for unions, {name} is a union"),
     };
 
     let mut gen = quote! {
         impl #impl_generics CanonicalDeserialize for #name #ty_generics #where_clause {
-            fn deserialize_with_mode<R: snarkvm_utilities::io::Read>(
-                mut reader: R,
+            fn deserialize_with_mode<__R: snarkvm_utilities::io::Read>(
+                mut reader: __R,
                 compress: snarkvm_utilities::serialize::Compress,
                 validate: snarkvm_utilities::serialize::Validate,
             ) -> Result<Self, snarkvm_utilities::serialize::SerializationError> {
@@ -13,8 +13,8 @@
 // limitations under the License.
 
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
-use syn::{Data, Index, Type};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Data, DataEnum, Expr, ExprLit, Fields, Ident, Index, Lit, Type, Variant};
 
 pub(crate) enum IdentOrIndex {
     Ident(proc_macro2::Ident),
@@ -33,6 +33,8 @@ impl ToTokens for IdentOrIndex {
 fn impl_serialize_field(
     serialize_body: &mut Vec<TokenStream>,
     serialized_size_body: &mut Vec<TokenStream>,
+    base: &TokenStream,
+    base_is_ref: bool,
     idents: &mut Vec<IdentOrIndex>,
     ty: &Type,
 ) {
@@ -42,35 +44,69 @@ fn impl_serialize_field(
             for (i, elem_ty) in tuple.elems.iter().enumerate() {
                 let index = Index::from(i);
                 idents.push(IdentOrIndex::Index(index));
-                impl_serialize_field(serialize_body, serialized_size_body, idents, elem_ty);
+                impl_serialize_field(serialize_body, serialized_size_body, base, base_is_ref, idents, elem_ty);
                 idents.pop();
             }
         }
         _ => {
-            serialize_body
-                .push(quote! { CanonicalSerialize::serialize_with_mode(&self.#(#idents).*, &mut writer, compress)?; });
-            serialized_size_body
-                .push(quote! { size += CanonicalSerialize::serialized_size(&self.#(#idents).*, compress); });
+            // If the base is already a reference (e.g. an enum match binding), pass it through as is.
+            let field = match base_is_ref && idents.is_empty() {
+                true => quote! { #base },
+                false => quote! { &#base #(.#idents)* },
+            };
+            serialize_body.push(quote! { CanonicalSerialize::serialize_with_mode(#field, &mut writer, compress)?; });
+            serialized_size_body.push(quote! { size += CanonicalSerialize::serialized_size(#field, compress); });
         }
     }
 }
 
+/// Returns the tag of each variant of the given enum, which is its explicit discriminant if one is given,
+/// and its index otherwise. The tags are encoded as a `u8`, and must be unique.
+pub(crate) fn variant_tags(name: &Ident, data_enum: &DataEnum) -> Vec<u8> {
+    let mut tags = Vec::<u8>::with_capacity(data_enum.variants.len());
+    for (i, variant) in data_enum.variants.iter().enumerate() {
+        let tag = match &variant.discriminant {
+            Some((_, Expr::Lit(ExprLit { lit: Lit::Int(int), .. }))) => int
+                .base10_parse::<u8>()
+                .unwrap_or_else(|_| panic!("The discriminant of {name}::{} must fit in a `u8`", variant.ident)),
+            Some(_) => panic!("The discriminant of {name}::{} must be an integer literal", variant.ident),
+            None => u8::try_from(i).unwrap_or_else(|_| panic!("{name} must have at most 256 variants")),
+        };
+        if tags.contains(&tag) {
+            panic!("The discriminant of {name}::{} is not unique", variant.ident);
+        }
+        tags.push(tag);
+    }
+    tags
+}
+
+/// Returns the pattern that binds the fields of the given variant, and the bindings of its fields.
+pub(crate) fn variant_pattern(variant: &Variant) -> (TokenStream, Vec<Ident>) {
+    let ident = &variant.ident;
+    match &variant.fields {
+        Fields::Named(fields) => {
+            let bindings: Vec<_> = fields.named.iter().filter_map(|field| field.ident.clone()).collect();
+            (quote! { Self::#ident { #(#bindings),* } }, bindings)
+        }
+        Fields::Unnamed(fields) => {
+            let bindings: Vec<_> = (0..fields.unnamed.len()).map(|i| format_ident!("__field{}", i)).collect();
+            (quote! { Self::#ident ( #(#bindings),* ) }, bindings)
+        }
+        Fields::Unit => (quote! { Self::#ident }, vec![]),
+    }
+}
+
 pub(super) fn impl_canonical_serialize(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
 
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
-    let len = if let Data::Struct(ref data_struct) = ast.data {
-        data_struct.fields.len()
-    } else {
-        panic!("`CanonicalSerialize` can only be derived for structs, {name} is not a struct");
-    };
-
-    let mut serialize_body = Vec::<TokenStream>::with_capacity(len);
-    let mut serialized_size_body = Vec::<TokenStream>::with_capacity(len);
+    let mut serialize_body = Vec::<TokenStream>::new();
+    let mut serialized_size_body = Vec::<TokenStream>::new();
 
     match ast.data {
         Data::Struct(ref data_struct) => {
+            let base = quote! { self };
             let mut idents = Vec::<IdentOrIndex>::new();
 
             for (i, field) in data_struct.fields.iter().enumerate() {
@@ -84,17 +120,56 @@ pub(super) fn impl_canonical_serialize(ast: &syn::DeriveInput) -> TokenStream {
                     }
                 }
 
-                impl_serialize_field(&mut serialize_body, &mut serialized_size_body, &mut idents, &field.ty);
+                impl_serialize_field(
+                    &mut serialize_body,
+                    &mut serialized_size_body,
+                    &base,
+                    false,
+                    &mut idents,
+                    &field.ty,
+                );
 
                 idents.clear();
             }
         }
-        _ => panic!("`CanonicalSerialize` can only be derived for structs, {name} is not a struct"),
+        Data::Enum(ref data_enum) => {
+            let tags = variant_tags(name, data_enum);
+
+            let mut serialize_cases = Vec::<TokenStream>::with_capacity(tags.len());
+            let mut serialized_size_cases = Vec::<TokenStream>::with_capacity(tags.len());
+
+            for (variant, tag) in data_enum.variants.iter().zip(tags) {
+                let (pattern, bindings) = variant_pattern(variant);
+
+                // Serialize the tag, followed by the fields of the variant.
+                let mut variant_serialize_body =
+                    vec![quote! { CanonicalSerialize::serialize_with_mode(&#tag, &mut writer, compress)?; }];
+                let mut variant_serialized_size_body =
+                    vec![quote! { size += CanonicalSerialize::serialized_size(&#tag, compress); }];
+                for (binding, field) in bindings.iter().zip(variant.fields.iter()) {
+                    impl_serialize_field(
+                        &mut variant_serialize_body,
+                        &mut variant_serialized_size_body,
+                        &quote! { #binding },
+                        true,
+                        &mut vec![],
+                        &field.ty,
+                    );
+                }
+
+                serialize_cases.push(quote! { #pattern => { #(#variant_serialize_body)* } });
+                serialized_size_cases.push(quote! { #pattern => { #(#variant_serialized_size_body)* } });
+            }
+
+            serialize_body.push(quote! { match self { #(#serialize_cases)* } });
+            serialized_size_body.push(quote! { match self { #(#serialized_size_cases)* } });
+        }
+        Data::Union(_) => panic!("`CanonicalSerialize` cannot be derived for unions, {name} is a union"),
     };
 
     let gen = quote! {
         impl #impl_generics snarkvm_utilities::CanonicalSerialize for #name #ty_generics #where_clause {
-            fn serialize_with_mode<W: snarkvm_utilities::io::Write>(&self, mut writer: W, compress: snarkvm_utilities::serialize::Compress) -> Result<(), snarkvm_utilities::serialize::SerializationError> {
+            fn serialize_with_mode<__W: snarkvm_utilities::io::Write>(&self, mut writer: __W, compress: snarkvm_utilities::serialize::Compress) -> Result<(), snarkvm_utilities::serialize::SerializationError> {
                 #(#serialize_body)*
                 Ok(())
             }
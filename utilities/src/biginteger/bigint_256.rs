@@ -16,6 +16,7 @@ use crate::{
     biginteger::BigInteger,
     bititerator::{BitIteratorBE, BitIteratorLE},
     io::{Read, Result as IoResult, Write},
+    ConstSerializedSize,
     FromBits,
     FromBytes,
     ToBits,
@@ -288,6 +289,10 @@ impl FromBytes for BigInteger256 {
     }
 }
 
+impl ConstSerializedSize for BigInteger256 {
+    const SIZE: usize = <[u64; 4]>::SIZE;
+}
+
 impl Debug for BigInteger256 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for i in self.0.iter().rev() {
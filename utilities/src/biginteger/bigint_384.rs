@@ -16,6 +16,7 @@ use crate::{
     biginteger::BigInteger,
     bititerator::{BitIteratorBE, BitIteratorLE},
     io::{Read, Result as IoResult, Write},
+    ConstSerializedSize,
     FromBits,
     FromBytes,
     ToBits,
@@ -290,6 +291,11 @@ impl FromBytes for BigInteger384 {
         <[u64; 6]>::read_le(reader).map(Self::new)
     }
 }
+
+impl ConstSerializedSize for BigInteger384 {
+    const SIZE: usize = <[u64; 6]>::SIZE;
+}
+
 impl Debug for BigInteger384 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for i in self.0.iter().rev() {
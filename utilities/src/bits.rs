@@ -149,10 +149,9 @@ macro_rules! impl_bits_for_integer {
             /// Returns `self` as a boolean array in little-endian order.
             #[inline]
             fn write_bits_le(&self, vec: &mut Vec<bool>) {
-                let mut value = *self;
-                for _ in 0..<$int>::BITS {
-                    vec.push(value & 1 == 1);
-                    value = value.wrapping_shr(1u32);
+                vec.reserve(<$int>::BITS as usize);
+                for byte in self.to_le_bytes() {
+                    vec.extend_from_slice(&bits_from_byte_le(byte));
                 }
             }
 
@@ -301,7 +300,7 @@ impl FromBits for Vec<u8> {
     #[inline]
     fn from_bits_le(bits: &[bool]) -> Result<Self> {
         // The vector is order-preserving, meaning the first variable in is the first variable bits out.
-        bits.chunks(8).map(u8::from_bits_le).collect::<Result<Vec<_>>>()
+        Ok(crate::bytes_from_bits_le(bits))
     }
 
     /// A helper method to return `Self` from a concatenated list of big-endian bits.
@@ -312,6 +311,32 @@ impl FromBits for Vec<u8> {
     }
 }
 
+/********************/
+/***** Packing ******/
+/********************/
+
+/// The mask that selects the least significant bit of every byte in a word.
+const LSB_MASK: u64 = 0x0101_0101_0101_0101;
+
+/// Packs 8 little-endian bits into a byte, operating on all 8 bits at once as a single word.
+#[inline]
+pub fn byte_from_bits_le(bits: [bool; 8]) -> u8 {
+    // Place each bit in the least significant bit of its own byte.
+    let word = u64::from_le_bytes(bits.map(u8::from));
+    // The multiplication shifts bit `i` (at position `8i`) into position `56 + i`, without any carries.
+    (word.wrapping_mul(0x0102_0408_1020_4080) >> 56) as u8
+}
+
+/// Unpacks a byte into 8 little-endian bits, operating on all 8 bits at once as a single word.
+#[inline]
+pub fn bits_from_byte_le(byte: u8) -> [bool; 8] {
+    // Broadcast the byte into every byte of the word, and keep bit `i` in byte `i`.
+    let word = (u64::from(byte) * LSB_MASK) & 0x8040_2010_0804_0201;
+    // Move any set bit into the least significant bit of its byte (each byte is at most 0x80 + 0x7F).
+    let word = ((word + 0x7F7F_7F7F_7F7F_7F7F) >> 7) & LSB_MASK;
+    word.to_le_bytes().map(|bit| bit != 0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -445,4 +470,17 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bit_packing() {
+        for byte in 0..=u8::MAX {
+            // Check that the bits match the scalar decomposition.
+            let bits = bits_from_byte_le(byte);
+            for (i, bit) in bits.iter().enumerate() {
+                assert_eq!(*bit, (byte >> i) & 1 == 1);
+            }
+            // Check that the bits pack back into the byte.
+            assert_eq!(byte, byte_from_bits_le(bits));
+        }
+    }
 }
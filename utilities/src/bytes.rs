@@ -13,10 +13,14 @@
 // limitations under the License.
 
 use crate::{
+    bits_from_byte_le,
+    byte_from_bits_le,
     error,
     fmt,
     io::{Read, Result as IoResult, Write},
     marker::PhantomData,
+    DeserializationLimits,
+    LimitedReader,
     Vec,
 };
 use serde::{
@@ -63,6 +67,51 @@ pub trait ToBytes {
     {
         Ok(to_bytes_le![self]?)
     }
+
+    /// Writes `self` into `writer` as little-endian bytes, buffering at most `chunk_size` bytes at a time.
+    ///
+    /// Unlike `to_bytes_le`, this never holds the full byte representation in memory, so that large
+    /// objects (e.g. blocks, proving keys, and the SRS) can be exported without doubling peak memory.
+    #[cfg(feature = "std")]
+    fn write_le_chunked<W: Write>(&self, writer: W, chunk_size: usize) -> IoResult<()>
+    where
+        Self: Sized,
+    {
+        let mut writer = std::io::BufWriter::with_capacity(chunk_size, writer);
+        self.write_le(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Returns the number of bytes in the little-endian representation of `self`, without allocating it.
+    #[cfg(feature = "std")]
+    fn size_in_bytes_le(&self) -> IoResult<u64>
+    where
+        Self: Sized,
+    {
+        let mut counter = ByteCounter(0);
+        self.write_le(&mut counter)?;
+        Ok(counter.0)
+    }
+}
+
+/// The default number of bytes buffered by `ToBytes::write_le_chunked`.
+#[cfg(feature = "std")]
+pub const DEFAULT_CHUNK_SIZE: usize = 1 << 16;
+
+/// A writer that discards its input, and counts the number of bytes written.
+#[cfg(feature = "std")]
+struct ByteCounter(u64);
+
+#[cfg(feature = "std")]
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.0 += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
 }
 
 pub trait FromBytes {
@@ -78,6 +127,59 @@ pub trait FromBytes {
     {
         Ok(Self::read_le(bytes)?)
     }
+
+    /// Reads `Self` from `reader` as little-endian bytes, enforcing the given deserialization limits.
+    /// This should be used for untrusted input, such as proofs, transactions, and blocks from the network.
+    fn read_le_with_limits<R: Read>(reader: R, limits: DeserializationLimits) -> IoResult<Self>
+    where
+        Self: Sized,
+    {
+        limits.scope(|| Self::read_le(LimitedReader::new(reader, limits.max_bytes)))
+    }
+
+    /// Returns `Self` from a byte array in little-endian order, enforcing the given deserialization limits.
+    fn from_bytes_le_with_limits(bytes: &[u8], limits: DeserializationLimits) -> anyhow::Result<Self>
+    where
+        Self: Sized,
+    {
+        anyhow::ensure!(bytes.len() <= limits.max_bytes, "The input exceeds the limit of {} bytes", limits.max_bytes);
+        Ok(limits.scope(|| Self::read_le(bytes))?)
+    }
+}
+
+/// A type whose little-endian bytes have a size that is known at compile time.
+///
+/// This allows fixed-size types (e.g. field elements, points, and signatures) to be written to and read
+/// from `[u8; N]` buffers, instead of allocating a `Vec` with `to_bytes_le`, and allows network framing
+/// to check the length of a message before it is deserialized.
+pub trait ConstSerializedSize {
+    /// The number of bytes in the little-endian representation of `Self`.
+    const SIZE: usize;
+
+    /// Returns `self` as an array of `N` little-endian bytes, where `N` must be `Self::SIZE`.
+    fn to_bytes_le_array<const N: usize>(&self) -> anyhow::Result<[u8; N]>
+    where
+        Self: ToBytes + Sized,
+    {
+        anyhow::ensure!(N == Self::SIZE, "Expected an array of {} bytes, found {N} bytes", Self::SIZE);
+        let mut bytes = [0u8; N];
+        let mut writer = &mut bytes[..];
+        self.write_le(&mut writer)?;
+        anyhow::ensure!(writer.is_empty(), "Expected to write {} bytes, wrote {}", N, N - writer.len());
+        Ok(bytes)
+    }
+
+    /// Returns `Self` from an array of `N` little-endian bytes, where `N` must be `Self::SIZE`.
+    fn from_bytes_le_array<const N: usize>(bytes: &[u8; N]) -> anyhow::Result<Self>
+    where
+        Self: FromBytes + Sized,
+    {
+        anyhow::ensure!(N == Self::SIZE, "Expected an array of {} bytes, found {N} bytes", Self::SIZE);
+        let mut reader = &bytes[..];
+        let object = Self::read_le(&mut reader)?;
+        anyhow::ensure!(reader.is_empty(), "Found {} trailing bytes", reader.len());
+        Ok(object)
+    }
 }
 
 pub struct ToBytesSerializer<T: ToBytes>(String, Option<usize>, PhantomData<T>);
@@ -296,6 +398,10 @@ impl FromBytes for bool {
     }
 }
 
+impl ConstSerializedSize for bool {
+    const SIZE: usize = 1;
+}
+
 impl ToBytes for SocketAddr {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -348,6 +454,10 @@ macro_rules! impl_bytes_for_integer {
                 Ok(<$int>::from_le_bytes(bytes))
             }
         }
+
+        impl ConstSerializedSize for $int {
+            const SIZE: usize = core::mem::size_of::<$int>();
+        }
     };
 }
 
@@ -379,6 +489,10 @@ impl<const N: usize> FromBytes for [u8; N] {
     }
 }
 
+impl<const N: usize> ConstSerializedSize for [u8; N] {
+    const SIZE: usize = N;
+}
+
 macro_rules! impl_bytes_for_integer_array {
     ($int:ty) => {
         impl<const N: usize> ToBytes for [$int; N] {
@@ -403,6 +517,10 @@ macro_rules! impl_bytes_for_integer_array {
                 Ok(res)
             }
         }
+
+        impl<const N: usize> ConstSerializedSize for [$int; N] {
+            const SIZE: usize = N * core::mem::size_of::<$int>();
+        }
     };
 }
 
@@ -428,6 +546,10 @@ impl<L: FromBytes, R: FromBytes> FromBytes for (L, R) {
     }
 }
 
+impl<L: ConstSerializedSize, R: ConstSerializedSize> ConstSerializedSize for (L, R) {
+    const SIZE: usize = L::SIZE + R::SIZE;
+}
+
 impl<T: ToBytes> ToBytes for Vec<T> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -457,7 +579,7 @@ impl<'a, T: 'a + ToBytes> ToBytes for &'a T {
 
 #[inline]
 pub fn bits_from_bytes_le(bytes: &[u8]) -> impl Iterator<Item = bool> + DoubleEndedIterator<Item = bool> + '_ {
-    bytes.iter().flat_map(|byte| (0..8).map(move |i| (*byte >> i) & 1 == 1))
+    bytes.iter().flat_map(|byte| bits_from_byte_le(*byte))
 }
 
 #[inline]
@@ -465,19 +587,68 @@ pub fn bytes_from_bits_le(bits: &[bool]) -> Vec<u8> {
     let desired_size = if bits.len() % 8 == 0 { bits.len() / 8 } else { bits.len() / 8 + 1 };
 
     let mut bytes = Vec::with_capacity(desired_size);
-    for bits in bits.chunks(8) {
-        let mut result = 0u8;
-        for (i, bit) in bits.iter().enumerate() {
-            let bit_value = *bit as u8;
-            result += bit_value << i as u8;
-        }
-
-        bytes.push(result);
+    let mut chunks = bits.chunks_exact(8);
+    for chunk in &mut chunks {
+        bytes.push(byte_from_bits_le([chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5], chunk[6], chunk[7]]));
+    }
+    // Pad the remaining bits with zeros.
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        let mut last = [false; 8];
+        last[..remainder.len()].copy_from_slice(remainder);
+        bytes.push(byte_from_bits_le(last));
     }
 
     bytes
 }
 
+/// Reads `len` elements from `reader` and deserializes them in parallel.
+/// Every element must have the same encoded size, as is the case for elliptic curve points.
+pub fn read_vec_without_len_in_parallel<T: ToBytes + FromBytes + Send, R: Read>(
+    mut reader: R,
+    len: usize,
+) -> IoResult<Vec<T>> {
+    /// The maximum number of elements that are read into memory at once.
+    const CHUNK_SIZE: usize = 1 << 16;
+
+    #[cfg(not(feature = "serial"))]
+    use rayon::prelude::*;
+
+    // Capture the limits of this thread, as they are not inherited by the worker threads.
+    let limits = DeserializationLimits::current();
+    if len > limits.max_elements {
+        return Err(error("The number of elements exceeds the deserialization limit"));
+    }
+    if len == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Read the first element, to determine the encoded size of each element.
+    let first = T::read_le(&mut reader)?;
+    let size_in_bytes = first.to_bytes_le().map_err(|e| error(e.to_string()))?.len();
+    if size_in_bytes == 0 {
+        return Err(error("Cannot read zero-sized elements in parallel"));
+    }
+
+    let mut elements = Vec::with_capacity(len.min(CHUNK_SIZE));
+    elements.push(first);
+
+    // Read the remaining elements in bounded chunks, and deserialize each chunk in parallel.
+    let mut buffer = Vec::new();
+    let mut remaining = len - 1;
+    while remaining > 0 {
+        let num_elements = remaining.min(CHUNK_SIZE);
+        buffer.resize(num_elements * size_in_bytes, 0u8);
+        reader.read_exact(&mut buffer)?;
+        let chunk = crate::cfg_chunks!(buffer, size_in_bytes)
+            .map(|bytes| limits.scope(|| T::read_le(bytes)))
+            .collect::<IoResult<Vec<_>>>()?;
+        elements.extend(chunk);
+        remaining -= num_elements;
+    }
+    Ok(elements)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -514,6 +685,84 @@ mod test {
         assert_eq!(bytes, actual_bytes);
     }
 
+    #[test]
+    fn test_const_serialized_size() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected: (u64, [u32; 3]) = (rng.gen(), rng.gen());
+            assert_eq!(expected.to_bytes_le().unwrap().len(), <(u64, [u32; 3])>::SIZE);
+
+            let bytes = expected.to_bytes_le_array::<20>().unwrap();
+            assert_eq!(expected, <(u64, [u32; 3])>::from_bytes_le_array(&bytes).unwrap());
+        }
+
+        // Check that arrays of the wrong size are rejected.
+        assert!(7u64.to_bytes_le_array::<4>().is_err());
+        assert!(u64::from_bytes_le_array(&[0u8; 9]).is_err());
+    }
+
+    #[test]
+    fn test_write_le_chunked() {
+        let mut rng = TestRng::default();
+
+        let expected: Vec<u64> = (0..10_000).map(|_| rng.gen()).collect();
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected.size_in_bytes_le().unwrap(), expected_bytes.len() as u64);
+
+        for chunk_size in [1, 7, 1024, DEFAULT_CHUNK_SIZE] {
+            let mut candidate_bytes = Vec::new();
+            expected.write_le_chunked(&mut candidate_bytes, chunk_size).unwrap();
+            assert_eq!(expected_bytes, candidate_bytes);
+        }
+    }
+
+    #[test]
+    fn test_read_vec_without_len_in_parallel() {
+        let mut rng = TestRng::default();
+
+        // Use enough elements to span several chunks.
+        let expected: Vec<u64> = (0..200_000).map(|_| rng.gen()).collect();
+        let bytes = expected.iter().flat_map(|element| element.to_le_bytes()).collect::<Vec<_>>();
+        let candidate: Vec<u64> = read_vec_without_len_in_parallel(&bytes[..], expected.len()).unwrap();
+        assert_eq!(expected, candidate);
+
+        // Check that truncated input is rejected.
+        assert!(read_vec_without_len_in_parallel::<u64, _>(&bytes[..100], expected.len()).is_err());
+    }
+
+    #[test]
+    fn test_read_vec_without_len_in_parallel_with_limits() {
+        /// An element that is rejected if its value exceeds the deserialization limits of the reading thread.
+        #[derive(Debug, PartialEq)]
+        struct Bounded(u64);
+
+        impl ToBytes for Bounded {
+            fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+                self.0.write_le(writer)
+            }
+        }
+
+        impl FromBytes for Bounded {
+            fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+                let value = u64::read_le(reader)?;
+                DeserializationLimits::check_num_elements(value)?;
+                Ok(Self(value))
+            }
+        }
+
+        // Only the elements after the first are read on the worker threads, so only they exceed the limits.
+        let values = (0..200_000u64).map(|i| if i == 0 { 1u64 } else { 250_000 });
+        let bytes = values.flat_map(u64::to_le_bytes).collect::<Vec<_>>();
+        let read = || read_vec_without_len_in_parallel::<Bounded, _>(&bytes[..], 200_000);
+
+        // Check that the elements are read without limits.
+        assert_eq!(read().unwrap().len(), 200_000);
+        // Check that the limits of the calling thread are enforced on the worker threads.
+        assert!(DeserializationLimits::new(200_000, usize::MAX).scope(read).is_err());
+        assert_eq!(DeserializationLimits::new(250_000, usize::MAX).scope(read).unwrap().len(), 200_000);
+    }
+
     #[test]
     fn test_bits_from_bytes_le() {
         assert_eq!(bits_from_bytes_le(&[204, 76]).collect::<Vec<bool>>(), [
@@ -0,0 +1,181 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{bail, ensure, Result};
+use serde_json::{Map, Value};
+
+/// The key of the type tag in a canonical JSON object.
+const TYPE_KEY: &str = "type";
+/// The key of the version tag in a canonical JSON object.
+const VERSION_KEY: &str = "version";
+/// The key of the body in a canonical JSON object.
+const BODY_KEY: &str = "body";
+
+/// A canonical JSON encoding, in which an object is tagged with its type and encoding version,
+/// byte fields are encoded as lowercase hex, the keys of every object are sorted, and there is no
+/// insignificant whitespace. As a result, every object has exactly one encoding, which may be
+/// compared, hashed, or signed as a string.
+pub trait CanonicalJson: Sized {
+    /// The type tag of the encoding.
+    const TYPE: &'static str;
+    /// The version of the encoding.
+    const VERSION: u8;
+
+    /// Returns the body of the canonical JSON encoding.
+    fn to_json_body(&self) -> Result<Value>;
+
+    /// Returns the object from the body of a canonical JSON encoding.
+    fn from_json_body(body: Value) -> Result<Self>;
+
+    /// Returns the canonical JSON encoding of the object.
+    fn to_canonical_json(&self) -> Result<String> {
+        let mut object = Map::new();
+        object.insert(BODY_KEY.to_string(), self.to_json_body()?);
+        object.insert(TYPE_KEY.to_string(), Value::String(Self::TYPE.to_string()));
+        object.insert(VERSION_KEY.to_string(), Value::from(Self::VERSION));
+        Ok(serde_json::to_string(&canonicalize(Value::Object(object)))?)
+    }
+
+    /// Returns the object from its canonical JSON encoding.
+    /// This method rejects any encoding that is not canonical.
+    fn from_canonical_json(json: &str) -> Result<Self> {
+        let Value::Object(mut object) = serde_json::from_str(json)? else {
+            bail!("Canonical JSON for '{}' must be an object", Self::TYPE)
+        };
+        // Ensure the object contains exactly the tags and the body.
+        ensure!(
+            object.len() == 3 && [TYPE_KEY, VERSION_KEY, BODY_KEY].iter().all(|key| object.contains_key(*key)),
+            "Canonical JSON for '{}' must contain exactly the '{TYPE_KEY}', '{VERSION_KEY}', and '{BODY_KEY}' keys",
+            Self::TYPE
+        );
+        // Ensure the tags match.
+        match object.get(TYPE_KEY).and_then(Value::as_str) {
+            Some(type_) if type_ == Self::TYPE => (),
+            _ => bail!("Canonical JSON is not of type '{}'", Self::TYPE),
+        }
+        match object.get(VERSION_KEY).and_then(Value::as_u64) {
+            Some(version) if version == Self::VERSION as u64 => (),
+            _ => bail!("Canonical JSON for '{}' is not of version {}", Self::TYPE, Self::VERSION),
+        }
+        // Recover the object.
+        let candidate = match object.remove(BODY_KEY) {
+            Some(body) => Self::from_json_body(body)?,
+            None => bail!("Canonical JSON for '{}' is missing the body", Self::TYPE),
+        };
+        // Ensure the encoding is canonical, which rejects unsorted or duplicate keys, whitespace,
+        // and non-canonical encodings of the fields.
+        ensure!(candidate.to_canonical_json()? == json, "JSON for '{}' is not in canonical form", Self::TYPE);
+        Ok(candidate)
+    }
+}
+
+/// Returns the given JSON value, with the keys of every object in sorted order.
+pub fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<_> = object.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(entries.into_iter().map(|(key, value)| (key, canonicalize(value))).collect())
+        }
+        Value::Array(array) => Value::Array(array.into_iter().map(canonicalize).collect()),
+        value => value,
+    }
+}
+
+/// Returns the canonical (lowercase) hex encoding of the given bytes.
+pub fn to_canonical_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut string = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        string.push(DIGITS[(byte >> 4) as usize] as char);
+        string.push(DIGITS[(byte & 0x0f) as usize] as char);
+    }
+    string
+}
+
+/// Returns the bytes of the given canonical (lowercase) hex encoding.
+pub fn from_canonical_hex(string: &str) -> Result<Vec<u8>> {
+    /// Returns the value of the given lowercase hex digit.
+    fn digit(c: u8) -> Result<u8> {
+        match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            _ => bail!("Invalid canonical hex digit '{}'", c as char),
+        }
+    }
+
+    ensure!(string.len() % 2 == 0, "Canonical hex must have an even length");
+    string.as_bytes().chunks(2).map(|pair| Ok((digit(pair[0])? << 4) | digit(pair[1])?)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Sample {
+        bytes: Vec<u8>,
+        size: u64,
+    }
+
+    impl CanonicalJson for Sample {
+        const TYPE: &'static str = "sample";
+        const VERSION: u8 = 1;
+
+        fn to_json_body(&self) -> Result<Value> {
+            Ok(serde_json::json!({ "size": self.size, "bytes": to_canonical_hex(&self.bytes) }))
+        }
+
+        fn from_json_body(body: Value) -> Result<Self> {
+            let bytes = from_canonical_hex(body["bytes"].as_str().unwrap_or_default())?;
+            let size = body["size"].as_u64().unwrap_or_default();
+            Ok(Self { bytes, size })
+        }
+    }
+
+    #[test]
+    fn test_canonical_hex() {
+        let bytes = [0u8, 1, 0x7f, 0xab, 0xff];
+        assert_eq!(to_canonical_hex(&bytes), "00017fabff");
+        assert_eq!(from_canonical_hex("00017fabff").unwrap(), bytes);
+        assert!(from_canonical_hex("00017FABFF").is_err());
+        assert!(from_canonical_hex("0").is_err());
+        assert!(from_canonical_hex("0g").is_err());
+    }
+
+    #[test]
+    fn test_canonical_json() -> Result<()> {
+        let expected = Sample { bytes: vec![0xde, 0xad], size: 7 };
+        let json = expected.to_canonical_json()?;
+        assert_eq!(json, r#"{"body":{"bytes":"dead","size":7},"type":"sample","version":1}"#);
+        assert_eq!(expected, Sample::from_canonical_json(&json)?);
+
+        // Check that non-canonical encodings are rejected.
+        for candidate in [
+            r#"{"type":"sample","version":1,"body":{"bytes":"dead","size":7}}"#,
+            r#"{"body":{"size":7,"bytes":"dead"},"type":"sample","version":1}"#,
+            r#"{"body":{"bytes":"DEAD","size":7},"type":"sample","version":1}"#,
+            r#"{"body": {"bytes":"dead","size":7},"type":"sample","version":1}"#,
+            r#"{"body":{"bytes":"dead","size":7,"extra":0},"type":"sample","version":1}"#,
+            r#"{"body":{"bytes":"dead","size":7},"extra":0,"type":"sample","version":1}"#,
+            r#"{"body":{"bytes":"dead","size":7},"type":"other","version":1}"#,
+            r#"{"body":{"bytes":"dead","size":7},"type":"sample","version":2}"#,
+            r#"{"body":{"bytes":"dead","size":7},"type":"sample"}"#,
+            r#"["sample"]"#,
+        ] {
+            assert!(Sample::from_canonical_json(candidate).is_err(), "{candidate}");
+        }
+        Ok(())
+    }
+}
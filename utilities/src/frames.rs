@@ -0,0 +1,262 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An adapter layer that splits serialized objects into length-prefixed frames, and reassembles them.
+//!
+//! This allows asynchronous code to stream large objects (e.g. proofs and blocks) by writing and reading
+//! one bounded frame at a time with its own non-blocking I/O, while the (blocking) serialization traits
+//! only ever operate on in-memory buffers.
+//!
+//! Each frame is encoded as `length (u32) || payload`, and the last frame of an object is empty.
+
+use crate::{
+    error,
+    io::Result as IoResult,
+    CanonicalDeserialize,
+    CanonicalSerialize,
+    Compress,
+    FromBytes,
+    ToBytes,
+    Validate,
+    Vec,
+};
+
+/// The default maximum number of bytes in the payload of a frame.
+pub const DEFAULT_FRAME_SIZE: usize = 1 << 16;
+
+/// The number of bytes in the length prefix of a frame.
+const FRAME_PREFIX_SIZE: usize = 4;
+
+/// Splits a serialized object into frames.
+#[derive(Clone, Debug)]
+pub struct FrameEncoder {
+    /// The serialized object.
+    bytes: Vec<u8>,
+    /// The maximum number of bytes in the payload of a frame.
+    frame_size: usize,
+    /// The number of bytes that have been framed.
+    position: usize,
+    /// Whether the last (empty) frame has been emitted.
+    is_finished: bool,
+}
+
+impl FrameEncoder {
+    /// Initializes a new frame encoder for the given serialized object.
+    pub fn new(bytes: Vec<u8>, frame_size: usize) -> IoResult<Self> {
+        if frame_size == 0 || frame_size > u32::MAX as usize {
+            return Err(error(format!("Invalid frame size of {frame_size} bytes")));
+        }
+        Ok(Self { bytes, frame_size, position: 0, is_finished: false })
+    }
+
+    /// Initializes a new frame encoder for the little-endian bytes of the given object.
+    pub fn from_bytes_le<T: ToBytes>(object: &T, frame_size: usize) -> IoResult<Self> {
+        let bytes = object.to_bytes_le().map_err(|e| error(e.to_string()))?;
+        Self::new(bytes, frame_size)
+    }
+
+    /// Initializes a new frame encoder for the canonical serialization of the given object.
+    pub fn from_canonical<T: CanonicalSerialize>(object: &T, compress: Compress, frame_size: usize) -> IoResult<Self> {
+        let mut bytes = Vec::with_capacity(object.serialized_size(compress));
+        object.serialize_with_mode(&mut bytes, compress)?;
+        Self::new(bytes, frame_size)
+    }
+
+    /// Returns the number of bytes in the serialized object.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the serialized object is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+impl Iterator for FrameEncoder {
+    type Item = Vec<u8>;
+
+    /// Returns the next frame, including its length prefix.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished {
+            return None;
+        }
+        let end = self.bytes.len().min(self.position + self.frame_size);
+        let payload = &self.bytes[self.position..end];
+        let mut frame = Vec::with_capacity(FRAME_PREFIX_SIZE + payload.len());
+        frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        frame.extend_from_slice(payload);
+
+        self.position = end;
+        self.is_finished = payload.is_empty();
+        Some(frame)
+    }
+}
+
+/// Reassembles a serialized object from frames, which may arrive in chunks of any size.
+#[derive(Clone, Debug)]
+pub struct FrameDecoder {
+    /// The reassembled payloads.
+    bytes: Vec<u8>,
+    /// The bytes of the current frame that have not been reassembled yet.
+    pending: Vec<u8>,
+    /// The maximum number of bytes in the reassembled object.
+    max_size: usize,
+    /// Whether the last (empty) frame has been received.
+    is_finished: bool,
+}
+
+impl FrameDecoder {
+    /// Initializes a new frame decoder, which accepts objects of up to `max_size` bytes.
+    pub const fn new(max_size: usize) -> Self {
+        Self { bytes: Vec::new(), pending: Vec::new(), max_size, is_finished: false }
+    }
+
+    /// Returns `true` if the last frame of the object has been received.
+    pub const fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    /// Adds the given chunk of frames to the decoder, and returns `true` if the object is complete.
+    ///
+    /// A frame is rejected as soon as its length prefix exceeds the remaining size, so at most one
+    /// incomplete frame of up to `max_size` bytes is buffered between calls.
+    pub fn push(&mut self, chunk: &[u8]) -> IoResult<bool> {
+        if self.is_finished && !chunk.is_empty() {
+            return Err(error("Received bytes after the last frame"));
+        }
+        self.pending.extend_from_slice(chunk);
+
+        // Reassemble every complete frame in the pending bytes.
+        let mut position = 0;
+        while !self.is_finished {
+            let Some(prefix) = self.pending.get(position..position + FRAME_PREFIX_SIZE) else { break };
+            let length = u32::from_le_bytes([prefix[0], prefix[1], prefix[2], prefix[3]]) as usize;
+            // Check the declared length before buffering its payload, so the pending bytes stay bounded.
+            if self.bytes.len().saturating_add(length) > self.max_size {
+                return Err(error(format!("Framed object exceeds the maximum size of {} bytes", self.max_size)));
+            }
+            let start = position + FRAME_PREFIX_SIZE;
+            let Some(payload) = self.pending.get(start..start + length) else { break };
+            self.bytes.extend_from_slice(payload);
+            self.is_finished = length == 0;
+            position += FRAME_PREFIX_SIZE + length;
+        }
+        self.pending.drain(..position);
+
+        if self.is_finished && !self.pending.is_empty() {
+            return Err(error("Received bytes after the last frame"));
+        }
+        Ok(self.is_finished)
+    }
+
+    /// Returns the reassembled object bytes, if the last frame has been received.
+    pub fn into_bytes(self) -> IoResult<Vec<u8>> {
+        match self.is_finished {
+            true => Ok(self.bytes),
+            false => Err(error("The framed object is incomplete")),
+        }
+    }
+
+    /// Deserializes the reassembled object from its little-endian bytes.
+    pub fn decode_le<T: FromBytes>(self) -> IoResult<T> {
+        let bytes = self.into_bytes()?;
+        let mut reader = &bytes[..];
+        let object = T::read_le(&mut reader)?;
+        match reader.is_empty() {
+            true => Ok(object),
+            false => Err(error("Found trailing bytes in the framed object")),
+        }
+    }
+
+    /// Deserializes the reassembled object from its canonical serialization.
+    pub fn decode_canonical<T: CanonicalDeserialize>(self, compress: Compress, validate: Validate) -> IoResult<T> {
+        let bytes = self.into_bytes()?;
+        let mut reader = &bytes[..];
+        let object = T::deserialize_with_mode(&mut reader, compress, validate)?;
+        match reader.is_empty() {
+            true => Ok(object),
+            false => Err(error("Found trailing bytes in the framed object")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestRng;
+
+    use rand::Rng;
+
+    #[test]
+    fn test_frames_round_trip() {
+        let mut rng = TestRng::default();
+
+        for frame_size in [1, 7, 64, DEFAULT_FRAME_SIZE] {
+            let expected: Vec<u64> = (0..1000).map(|_| rng.gen()).collect();
+            let frames = FrameEncoder::from_canonical(&expected, Compress::No, frame_size).unwrap();
+            let bytes = frames.flatten().collect::<Vec<_>>();
+
+            // Push the frames in chunks of random sizes.
+            let mut decoder = FrameDecoder::new(1 << 20);
+            let mut remaining = &bytes[..];
+            while !remaining.is_empty() {
+                let (chunk, rest) = remaining.split_at(rng.gen_range(1..=remaining.len().min(100)));
+                decoder.push(chunk).unwrap();
+                remaining = rest;
+            }
+            assert!(decoder.is_finished());
+            let candidate: Vec<u64> = decoder.decode_canonical(Compress::No, Validate::Yes).unwrap();
+            assert_eq!(expected, candidate);
+        }
+    }
+
+    #[test]
+    fn test_frames_bytes_le() {
+        let expected = [7u8; 100];
+        let bytes = FrameEncoder::from_bytes_le(&expected, 32).unwrap().flatten().collect::<Vec<_>>();
+        let mut decoder = FrameDecoder::new(100);
+        assert!(decoder.push(&bytes).unwrap());
+        assert_eq!(expected, decoder.decode_le::<[u8; 100]>().unwrap());
+    }
+
+    #[test]
+    fn test_frames_reject_invalid() {
+        let bytes = FrameEncoder::new(vec![1u8; 100], 32).unwrap().flatten().collect::<Vec<_>>();
+
+        // Check that an oversized object is rejected.
+        let mut decoder = FrameDecoder::new(99);
+        assert!(decoder.push(&bytes).is_err());
+
+        // Check that an oversized frame is rejected from its length prefix, before its payload is buffered.
+        let mut decoder = FrameDecoder::new(100);
+        assert!(decoder.push(&u32::MAX.to_le_bytes()).is_err());
+        let mut decoder = FrameDecoder::new(100);
+        assert!(!decoder.push(&bytes[..36]).unwrap());
+        assert!(decoder.push(&101u32.to_le_bytes()).is_err());
+
+        // Check that an incomplete object is rejected.
+        let mut decoder = FrameDecoder::new(100);
+        assert!(!decoder.push(&bytes[..bytes.len() - 1]).unwrap());
+        assert!(decoder.into_bytes().is_err());
+
+        // Check that trailing bytes are rejected.
+        let mut decoder = FrameDecoder::new(100);
+        assert!(decoder.push(&bytes).unwrap());
+        assert!(decoder.push(&[0]).is_err());
+
+        // Check that a frame size of zero is rejected.
+        assert!(FrameEncoder::new(vec![], 0).is_err());
+    }
+}
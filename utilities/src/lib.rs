@@ -41,6 +41,10 @@ pub use std::{boxed::Box, vec::Vec};
 #[macro_use]
 extern crate thiserror;
 
+// Allows the derive macros, which refer to `snarkvm_utilities`, to be tested in this crate.
+#[cfg(all(test, feature = "derive"))]
+extern crate self as snarkvm_utilities;
+
 pub mod biginteger;
 pub use biginteger::*;
 
@@ -55,9 +59,19 @@ pub use bits::*;
 pub mod bytes;
 pub use bytes::*;
 
+#[cfg(feature = "std")]
+pub mod canonical_json;
+#[cfg(feature = "std")]
+pub use canonical_json::*;
+
 pub mod error;
 pub use error::*;
 
+#[cfg(feature = "std")]
+pub mod frames;
+#[cfg(feature = "std")]
+pub use frames::*;
+
 pub mod iterator;
 pub use iterator::*;
 
@@ -65,6 +79,11 @@ pub use iterator::*;
 pub mod parallel;
 pub use parallel::*;
 
+#[cfg(feature = "std")]
+pub mod parallelism;
+#[cfg(feature = "std")]
+pub use parallelism::*;
+
 pub mod rand;
 pub use self::rand::*;
 
@@ -35,12 +35,13 @@ impl<'a, T> ExecutionPool<'a, T> {
     where
         T: Send + Sync,
     {
-        #[cfg(not(feature = "serial"))]
+        // Note: `wasm32-unknown-unknown` has no threads, so the jobs are executed sequentially.
+        #[cfg(not(any(feature = "serial", feature = "wasm")))]
         {
             use rayon::prelude::*;
             execute_with_max_available_threads(|| self.jobs.into_par_iter().map(|f| f()).collect())
         }
-        #[cfg(feature = "serial")]
+        #[cfg(any(feature = "serial", feature = "wasm"))]
         {
             self.jobs.into_iter().map(|f| f()).collect()
         }
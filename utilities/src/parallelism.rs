@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+#[cfg(not(any(feature = "serial", feature = "wasm")))]
+use std::sync::OnceLock;
+use std::sync::RwLock;
+
+/// The parallelism configuration of the current process.
+static PARALLELISM_CONFIG: RwLock<ParallelismConfig> = RwLock::new(ParallelismConfig::DEFAULT);
+
+/// The largest thread pool that is built for `execute_in`.
+/// A subsystem with more threads runs on the current thread pool.
+#[cfg(not(any(feature = "serial", feature = "wasm")))]
+const MAX_POOLED_THREADS: usize = 256;
+
+/// The thread pools that were built for `execute_in`, where the pool at index `i` has `i + 1` threads.
+/// Each pool is built once, so finding it does not take a lock.
+#[cfg(not(any(feature = "serial", feature = "wasm")))]
+static THREAD_POOLS: [OnceLock<rayon::ThreadPool>; MAX_POOLED_THREADS] = {
+    // The constant is only the initializer of each (separate) element of the array.
+    #[allow(clippy::declare_interior_mutable_const)]
+    const POOL: OnceLock<rayon::ThreadPool> = OnceLock::new();
+    [POOL; MAX_POOLED_THREADS]
+};
+
+/// The maximum number of threads of each subsystem in low-memory builds.
+#[cfg(feature = "low-memory")]
+const LOW_MEMORY_MAX_THREADS: usize = 2;
+
+/// A subsystem whose parallelism is configured by the `ParallelismConfig`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Subsystem {
+    /// Multi-scalar multiplication.
+    Msm,
+    /// Fast Fourier transforms.
+    Fft,
+    /// Merkle tree construction and updates.
+    MerkleTree,
+    /// Batch proof verification.
+    BatchVerification,
+}
+
+/// The parallelism configuration of a subsystem.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SubsystemConfig {
+    /// The maximum number of threads, or `None` to use the maximum available threads.
+    pub max_threads: Option<usize>,
+    /// The input size below which the subsystem runs sequentially, as the overhead of
+    /// parallelism outweighs its benefit for small inputs.
+    pub sequential_threshold: usize,
+}
+
+impl SubsystemConfig {
+    /// Initializes a new subsystem configuration.
+    pub const fn new(max_threads: Option<usize>, sequential_threshold: usize) -> Self {
+        Self { max_threads, sequential_threshold }
+    }
+}
+
+/// The runtime parallelism configuration, which is consulted by each `Subsystem` in addition to
+/// the compile-time `serial` feature. This allows a process to bound the threads of large jobs
+/// (so they do not monopolize every core), and to run small jobs sequentially.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParallelismConfig {
+    pub msm: SubsystemConfig,
+    pub fft: SubsystemConfig,
+    pub merkle_tree: SubsystemConfig,
+    pub batch_verification: SubsystemConfig,
+}
+
+impl ParallelismConfig {
+    /// The default configuration, which uses the maximum available threads in every subsystem.
+    #[cfg(not(feature = "low-memory"))]
+    pub const DEFAULT: Self = Self {
+        msm: SubsystemConfig::new(None, 0),
+        fft: SubsystemConfig::new(None, 0),
+        merkle_tree: SubsystemConfig::new(None, 101),
+        batch_verification: SubsystemConfig::new(None, 0),
+    };
+    /// The default configuration for low-memory builds, which bounds the threads of every subsystem,
+    /// as the peak memory of a job grows with the number of threads that hold its buffers.
+    #[cfg(feature = "low-memory")]
+    pub const DEFAULT: Self = Self {
+        msm: SubsystemConfig::new(Some(LOW_MEMORY_MAX_THREADS), 0),
+        fft: SubsystemConfig::new(Some(LOW_MEMORY_MAX_THREADS), 0),
+        merkle_tree: SubsystemConfig::new(Some(LOW_MEMORY_MAX_THREADS), 101),
+        batch_verification: SubsystemConfig::new(Some(LOW_MEMORY_MAX_THREADS), 0),
+    };
+
+    /// Returns the parallelism configuration of the current process.
+    pub fn global() -> Self {
+        *PARALLELISM_CONFIG.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Sets the parallelism configuration of the current process.
+    pub fn set_global(config: Self) {
+        *PARALLELISM_CONFIG.write().unwrap_or_else(|e| e.into_inner()) = config;
+    }
+
+    /// Returns the configuration of the given subsystem.
+    pub const fn get(&self, subsystem: Subsystem) -> SubsystemConfig {
+        match subsystem {
+            Subsystem::Msm => self.msm,
+            Subsystem::Fft => self.fft,
+            Subsystem::MerkleTree => self.merkle_tree,
+            Subsystem::BatchVerification => self.batch_verification,
+        }
+    }
+
+    /// Sets the configuration of the given subsystem.
+    pub fn set(&mut self, subsystem: Subsystem, config: SubsystemConfig) {
+        match subsystem {
+            Subsystem::Msm => self.msm = config,
+            Subsystem::Fft => self.fft = config,
+            Subsystem::MerkleTree => self.merkle_tree = config,
+            Subsystem::BatchVerification => self.batch_verification = config,
+        }
+    }
+
+    /// Returns the number of threads the given subsystem may use for an input of the given size.
+    #[cfg(not(feature = "serial"))]
+    pub fn num_threads(&self, subsystem: Subsystem, input_size: usize) -> usize {
+        let config = self.get(subsystem);
+        match input_size < config.sequential_threshold {
+            true => 1,
+            false => config.max_threads.unwrap_or_else(crate::max_available_threads).max(1),
+        }
+    }
+
+    /// Returns the number of threads the given subsystem may use for an input of the given size,
+    /// which is always one in serial builds.
+    #[cfg(feature = "serial")]
+    pub fn num_threads(&self, _subsystem: Subsystem, _input_size: usize) -> usize {
+        1
+    }
+}
+
+impl Default for ParallelismConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Executes the given function for the given subsystem, on a thread pool sized by the global
+/// `ParallelismConfig` for an input of the given size.
+///
+/// Returns an error if the thread pool could not be built.
+#[cfg(not(any(feature = "serial", feature = "wasm")))]
+pub fn try_execute_in<T: Send>(subsystem: Subsystem, input_size: usize, f: impl FnOnce() -> T + Send) -> Result<T> {
+    let num_threads = ParallelismConfig::global().num_threads(subsystem, input_size);
+    Ok(match thread_pool(num_threads)? {
+        Some(pool) => pool.install(f),
+        None => f(),
+    })
+}
+
+/// Executes the given function for the given subsystem, on a thread pool sized by the global
+/// `ParallelismConfig` for an input of the given size.
+///
+/// If the thread pool could not be built, the function runs on the current thread pool instead,
+/// as the bound on its threads is a resource limit rather than a requirement.
+#[cfg(not(any(feature = "serial", feature = "wasm")))]
+pub fn execute_in<T: Send>(subsystem: Subsystem, input_size: usize, f: impl FnOnce() -> T + Send) -> T {
+    let num_threads = ParallelismConfig::global().num_threads(subsystem, input_size);
+    match thread_pool(num_threads) {
+        Ok(Some(pool)) => pool.install(f),
+        Ok(None) | Err(_) => f(),
+    }
+}
+
+/// Executes the given function for the given subsystem, on a thread pool sized by the global
+/// `ParallelismConfig` for an input of the given size.
+#[cfg(any(feature = "serial", feature = "wasm"))]
+pub fn try_execute_in<T>(_subsystem: Subsystem, _input_size: usize, f: impl FnOnce() -> T) -> Result<T> {
+    Ok(f())
+}
+
+/// Executes the given function for the given subsystem, on a thread pool sized by the global
+/// `ParallelismConfig` for an input of the given size.
+#[cfg(any(feature = "serial", feature = "wasm"))]
+pub fn execute_in<T>(_subsystem: Subsystem, _input_size: usize, f: impl FnOnce() -> T) -> T {
+    f()
+}
+
+/// Returns a thread pool with the given number of threads, building it on first use,
+/// or `None` if the current thread pool is to be used, as it has no more than that many threads.
+#[cfg(not(any(feature = "serial", feature = "wasm")))]
+fn thread_pool(num_threads: usize) -> Result<Option<&'static rayon::ThreadPool>> {
+    if num_threads >= rayon::current_num_threads() || num_threads > MAX_POOLED_THREADS {
+        return Ok(None);
+    }
+    let slot = &THREAD_POOLS[num_threads.max(1) - 1];
+    if let Some(pool) = slot.get() {
+        return Ok(Some(pool));
+    }
+    // Build the pool outside of the slot, so a failure is returned rather than cached.
+    // If another thread sets the slot first, the pool built here is dropped.
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(num_threads).build()?;
+    Ok(Some(slot.get_or_init(|| pool)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_num_threads() {
+        let mut config = ParallelismConfig::DEFAULT;
+        config.set(Subsystem::Fft, SubsystemConfig::new(Some(3), 1024));
+        assert_eq!(config.get(Subsystem::Fft), SubsystemConfig::new(Some(3), 1024));
+
+        // Check that small inputs are sequential, and large inputs are bounded.
+        assert_eq!(config.num_threads(Subsystem::Fft, 1023), 1);
+        #[cfg(not(feature = "serial"))]
+        assert_eq!(config.num_threads(Subsystem::Fft, 1024), 3);
+        #[cfg(feature = "serial")]
+        assert_eq!(config.num_threads(Subsystem::Fft, 1024), 1);
+        assert_eq!(config.num_threads(Subsystem::MerkleTree, 100), 1);
+    }
+
+    #[cfg(feature = "low-memory")]
+    #[test]
+    fn test_low_memory_default() {
+        let config = ParallelismConfig::DEFAULT;
+        assert!(config.num_threads(Subsystem::Msm, 1 << 20) <= LOW_MEMORY_MAX_THREADS);
+        assert!(config.num_threads(Subsystem::BatchVerification, 1 << 20) <= LOW_MEMORY_MAX_THREADS);
+    }
+
+    #[cfg(not(any(feature = "serial", feature = "wasm")))]
+    #[test]
+    fn test_execute_in() {
+        // Note: the global configuration is not modified, as tests run concurrently.
+        let num_threads = execute_in(Subsystem::MerkleTree, 1, rayon::current_num_threads);
+        assert_eq!(num_threads, 1);
+        assert_eq!(execute_in(Subsystem::Msm, 1 << 20, || 7), 7);
+        assert_eq!(try_execute_in(Subsystem::MerkleTree, 1, || 7).unwrap(), 7);
+    }
+
+    #[cfg(not(any(feature = "serial", feature = "wasm")))]
+    #[test]
+    fn test_thread_pool() {
+        // Check that a pool is built once, and reused.
+        if rayon::current_num_threads() > 1 {
+            let pool = thread_pool(1).unwrap().unwrap();
+            assert_eq!(pool.current_num_threads(), 1);
+            assert!(std::ptr::eq(pool, thread_pool(1).unwrap().unwrap()));
+        }
+        // Check that a subsystem with at least as many threads as the current pool runs on it.
+        assert!(thread_pool(rayon::current_num_threads()).unwrap().is_none());
+        assert!(thread_pool(MAX_POOLED_THREADS + 1).unwrap().is_none());
+    }
+}
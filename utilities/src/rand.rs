@@ -18,8 +18,22 @@ use rand::{
     Rng,
     SeedableRng,
 };
+use rand_chacha::ChaCha20Rng;
 use rand_xorshift::XorShiftRng;
 
+/// The environment variable that, if set, overrides the seed of every `TestRng` and `SeededRng`
+/// that is initialized with `default`, so that a failing randomized test can be reproduced.
+pub const TEST_SEED_ENV: &str = "SNARKVM_TEST_SEED";
+
+/// Returns the seed given by the `TEST_SEED_ENV` environment variable, if it is set,
+/// and otherwise a seed sampled using entropy provided by the OS.
+fn initial_seed() -> u64 {
+    match std::env::var(TEST_SEED_ENV) {
+        Ok(seed) => seed.trim().parse().unwrap_or_else(|_| panic!("'{TEST_SEED_ENV}' must be a u64, found '{seed}'")),
+        Err(_) => StdRng::from_entropy().gen(),
+    }
+}
+
 /// A trait for a uniform random number generator.
 pub trait Uniform: Sized {
     /// Samples a random value from a uniform distribution.
@@ -41,8 +55,8 @@ pub struct TestRng(XorShiftRng);
 
 impl Default for TestRng {
     fn default() -> Self {
-        // Obtain the initial seed using entropy provided by the OS.
-        let seed = StdRng::from_entropy().gen();
+        // Obtain the initial seed from the environment, or using entropy provided by the OS.
+        let seed = initial_seed();
 
         // Use it as the basis for the underlying Rng.
         Self::fixed(seed)
@@ -147,3 +161,92 @@ impl rand::RngCore for TestRng {
 }
 
 impl rand::CryptoRng for TestRng {}
+
+/// A seeded ChaCha RNG for reproducible tests and simulations, **not** for any real world purposes.
+///
+/// Unlike `TestRng`, the underlying RNG is of cryptographic quality, so it is suitable for simulations
+/// whose outcomes depend on the distribution of the samples. If the current thread panics while the RNG
+/// is alive, its seed is printed, so the failure can be reproduced by setting `TEST_SEED_ENV` to the seed.
+pub struct SeededRng {
+    rng: ChaCha20Rng,
+    seed: u64,
+}
+
+impl Default for SeededRng {
+    fn default() -> Self {
+        // Obtain the initial seed from the environment, or using entropy provided by the OS.
+        Self::from_seed(initial_seed())
+    }
+}
+
+impl SeededRng {
+    /// Initializes a new RNG from the given seed.
+    pub fn from_seed(seed: u64) -> Self {
+        Self { rng: ChaCha20Rng::seed_from_u64(seed), seed }
+    }
+
+    /// Returns the seed of the RNG.
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns a new RNG, whose seed is sampled from this RNG.
+    /// This is useful for handing an independent, yet reproducible, RNG to each thread of a simulation.
+    pub fn fork(&mut self) -> Self {
+        Self::from_seed(self.gen())
+    }
+}
+
+impl Drop for SeededRng {
+    fn drop(&mut self) {
+        // Print the seed, so the failure can be reproduced.
+        if std::thread::panicking() {
+            eprintln!("\n'SeededRng' was initialized with seed '{}' (set '{TEST_SEED_ENV}' to reproduce)\n", self.seed);
+        }
+    }
+}
+
+impl rand::RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+impl rand::CryptoRng for SeededRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_rng_is_reproducible() {
+        let mut rng = SeededRng::default();
+        let seed = rng.seed();
+        let expected: [u64; 4] = rng.gen();
+
+        // Check that an RNG with the same seed produces the same samples.
+        let mut candidate = SeededRng::from_seed(seed);
+        assert_eq!(candidate.seed(), seed);
+        assert_eq!(expected, candidate.gen::<[u64; 4]>());
+
+        // Check that forked RNGs are reproducible, and independent of their parent.
+        let mut a = SeededRng::from_seed(seed);
+        let mut b = SeededRng::from_seed(seed);
+        let (fork_a, fork_b) = (a.fork(), b.fork());
+        assert_eq!(fork_a.seed(), fork_b.seed());
+        assert_ne!(fork_a.seed(), seed);
+        assert_eq!(a.gen::<u64>(), b.gen::<u64>());
+    }
+}
@@ -25,6 +25,9 @@ pub enum SerializationError {
     /// During serialization, the data was invalid.
     #[error("the input buffer contained invalid data")]
     InvalidData,
+    /// During deserialization, the input exceeded the deserialization limits.
+    #[error("the input exceeds the deserialization limit on the {0}")]
+    LimitExceeded(&'static str),
     /// During serialization, we countered an I/O error.
     #[error("IoError: {0}")]
     IoError(#[from] crate::io::Error),
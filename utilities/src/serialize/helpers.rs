@@ -18,7 +18,7 @@ pub use crate::{
     ToBytes,
     Vec,
 };
-use crate::{serialize::traits::*, SerializationError};
+use crate::{bounded_capacity, serialize::traits::*, DeserializationLimits, SerializationError};
 
 /// Serialize a Vector's elements without serializing the Vector's length
 /// If you want to serialize the full Vector, use `CanonicalSerialize for Vec<T>`
@@ -47,5 +47,75 @@ pub fn deserialize_vec_without_len<T: CanonicalDeserialize>(
     validate: Validate,
     len: usize,
 ) -> Result<Vec<T>, SerializationError> {
-    (0..len).map(|_| CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)).collect()
+    let len = DeserializationLimits::check_num_elements(len as u64)?;
+    let mut elements = Vec::with_capacity(bounded_capacity::<T>(len));
+    for _ in 0..len {
+        elements.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+    }
+    Ok(elements)
+}
+
+/// The maximum number of elements that are read into memory at once when deserializing in parallel.
+const PARALLEL_DESERIALIZATION_CHUNK_SIZE: usize = 1 << 16;
+
+/// Deserialize a Vector's elements in parallel, without deserializing the Vector's length.
+/// Every element must have the same serialized size, as is the case for elliptic curve points.
+/// If `validate` is set, the elements are validated together in a single (parallel) batch check.
+/// If you want to deserialize the full Vector, use `deserialize_vec_in_parallel`.
+pub fn deserialize_vec_without_len_in_parallel<T: CanonicalSerialize + CanonicalDeserialize + Send>(
+    mut reader: impl Read,
+    compress: Compress,
+    validate: Validate,
+    len: usize,
+) -> Result<Vec<T>, SerializationError> {
+    #[cfg(not(feature = "serial"))]
+    use rayon::prelude::*;
+
+    let len = DeserializationLimits::check_num_elements(len as u64)?;
+    // Capture the limits of this thread, as they are not inherited by the worker threads.
+    let limits = DeserializationLimits::current();
+    if len == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Deserialize the first element, to determine the serialized size of each element.
+    let first = T::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+    let size_in_bytes = first.serialized_size(compress);
+    if size_in_bytes == 0 {
+        return Err(SerializationError::InvalidData);
+    }
+
+    let mut elements = Vec::with_capacity(len.min(PARALLEL_DESERIALIZATION_CHUNK_SIZE));
+    elements.push(first);
+
+    // Read the remaining elements in bounded chunks, and deserialize each chunk in parallel.
+    let mut buffer = Vec::new();
+    let mut remaining = len - 1;
+    while remaining > 0 {
+        let num_elements = remaining.min(PARALLEL_DESERIALIZATION_CHUNK_SIZE);
+        buffer.resize(num_elements * size_in_bytes, 0u8);
+        reader.read_exact(&mut buffer)?;
+        let chunk = cfg_chunks!(buffer, size_in_bytes)
+            .map(|mut bytes| limits.scope(|| T::deserialize_with_mode(&mut bytes, compress, Validate::No)))
+            .collect::<Result<Vec<_>, _>>()?;
+        elements.extend(chunk);
+        remaining -= num_elements;
+    }
+
+    if let Validate::Yes = validate {
+        T::batch_check(elements.iter())?;
+    }
+    Ok(elements)
+}
+
+/// Deserialize a Vector in parallel, which is encoded in the same manner as `CanonicalDeserialize for Vec<T>`.
+/// Every element must have the same serialized size, as is the case for elliptic curve points.
+pub fn deserialize_vec_in_parallel<T: CanonicalSerialize + CanonicalDeserialize + Send>(
+    mut reader: impl Read,
+    compress: Compress,
+    validate: Validate,
+) -> Result<Vec<T>, SerializationError> {
+    let len = u64::deserialize_with_mode(&mut reader, compress, validate)?;
+    let len = DeserializationLimits::check_num_elements(len)?;
+    deserialize_vec_without_len_in_parallel(reader, compress, validate, len)
 }
@@ -18,7 +18,7 @@ pub use crate::{
     ToBytes,
     Vec,
 };
-use crate::{serialize::traits::*, SerializationError};
+use crate::{bounded_capacity, serialize::traits::*, DeserializationLimits, SerializationError};
 
 use bincode::Options;
 
@@ -198,6 +198,8 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
         Ok(())
     }
 
+    /// Returns 8 bytes for the tag, although it is serialized as a single byte.
+    /// This is kept as is, because buffers sized from it are part of existing encodings.
     #[inline]
     fn serialized_size(&self, compress: Compress) -> usize {
         8 + self.as_ref().map(|s| s.serialized_size(compress)).unwrap_or(0)
@@ -423,8 +425,8 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Vec<T> {
         validate: Validate,
     ) -> Result<Self, SerializationError> {
         let len = u64::deserialize_with_mode(&mut reader, compress, validate)?;
-        let mut values = Vec::new();
-        let _ = values.try_reserve(len as usize);
+        let len = DeserializationLimits::check_num_elements(len)?;
+        let mut values = Vec::with_capacity(bounded_capacity::<T>(len));
         for _ in 0..len {
             values.push(T::deserialize_with_mode(&mut reader, compress, Validate::No)?);
         }
@@ -631,33 +633,31 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{deserialize_vec_without_len, serialize_vec_without_len, serialized_vec_size_without_len};
+    use crate::{
+        deserialize_vec_in_parallel,
+        deserialize_vec_without_len,
+        serialize_vec_without_len,
+        serialized_vec_size_without_len,
+    };
 
     fn test_serialize<T: PartialEq + std::fmt::Debug + CanonicalSerialize + CanonicalDeserialize>(data: T) {
-        let combinations = [
-            (Compress::No, Validate::No),
-            (Compress::Yes, Validate::No),
-            (Compress::No, Validate::Yes),
-            (Compress::Yes, Validate::Yes),
-        ];
-        for (compress, validate) in combinations {
+        for SerializationMode { compress, validate } in SerializationMode::ALL {
             let mut serialized = vec![0; data.serialized_size(compress)];
             data.serialize_with_mode(&mut serialized[..], compress).unwrap();
             let de = T::deserialize_with_mode(&serialized[..], compress, validate).unwrap();
             assert_eq!(data, de);
+
+            let mode = SerializationMode::new(compress, validate);
+            let bytes = data.to_bytes_with_mode(mode).unwrap();
+            assert_eq!(&serialized[..bytes.len()], &bytes[..]);
+            assert_eq!(data, T::from_bytes_with_mode(&bytes, mode).unwrap());
         }
     }
 
     fn test_serialize_without_len<T: PartialEq + std::fmt::Debug + CanonicalSerialize + CanonicalDeserialize>(
         data: Vec<T>,
     ) {
-        let combinations = [
-            (Compress::No, Validate::No),
-            (Compress::Yes, Validate::No),
-            (Compress::No, Validate::Yes),
-            (Compress::Yes, Validate::Yes),
-        ];
-        for (compress, validate) in combinations {
+        for SerializationMode { compress, validate } in SerializationMode::ALL {
             let len = serialized_vec_size_without_len(&data, compress);
             let mut serialized = vec![0; len];
             serialize_vec_without_len(data.iter(), serialized.as_mut_slice(), compress).unwrap();
@@ -699,6 +699,25 @@ mod test {
         test_serialize_without_len(Vec::<u64>::new());
     }
 
+    #[test]
+    fn test_vec_in_parallel() {
+        // Use enough elements to span several chunks.
+        let data = (0..200_000u64).collect::<Vec<_>>();
+        for compress in [Compress::No, Compress::Yes] {
+            for validate in [Validate::No, Validate::Yes] {
+                let mut serialized = vec![0; data.serialized_size(compress)];
+                data.serialize_with_mode(&mut serialized[..], compress).unwrap();
+                let de: Vec<u64> = deserialize_vec_in_parallel(&serialized[..], compress, validate).unwrap();
+                assert_eq!(data, de);
+            }
+        }
+        // Check that truncated input is rejected.
+        let mut serialized = vec![0; data.uncompressed_size()];
+        data.serialize_uncompressed(&mut serialized[..]).unwrap();
+        let result: Result<Vec<u64>, _> = deserialize_vec_in_parallel(&serialized[..100], Compress::No, Validate::No);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_tuple() {
         test_serialize((123u64, 234u32, 999u16));
@@ -719,4 +738,60 @@ mod test {
     fn test_phantomdata() {
         test_serialize(std::marker::PhantomData::<u64>);
     }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_generic_enum() {
+        use crate::serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+        trait Wrapper: Sync {
+            type Inner: CanonicalSerialize + CanonicalDeserialize + std::fmt::Debug + PartialEq;
+        }
+
+        impl Wrapper for u64 {
+            type Inner = u32;
+        }
+
+        #[derive(Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+        #[repr(u8)]
+        enum Sample<T: CanonicalSerialize + CanonicalDeserialize, W: Wrapper> {
+            Unit,
+            Tuple(T, (u8, T)),
+            Named { inner: Vec<W::Inner>, flag: bool } = 7,
+        }
+
+        test_serialize(Sample::<u64, u64>::Unit);
+        test_serialize(Sample::<u64, u64>::Tuple(1, (2, 3)));
+        test_serialize(Sample::<u64, u64>::Named { inner: vec![4, 5], flag: true });
+
+        // Check that the tag precedes the fields.
+        let bytes = Sample::<u16, u64>::Tuple(1, (2, 3)).to_bytes_with_mode(SerializationMode::UNCOMPRESSED).unwrap();
+        assert_eq!(bytes, [1, 1, 0, 2, 3, 0]);
+        let size = Sample::<u16, u64>::Named { inner: vec![], flag: false }.uncompressed_size();
+        assert_eq!(size, 1 + 8 + 1);
+
+        // Check that unknown tags are rejected.
+        for tag in [3u8, 2, 255] {
+            assert!(Sample::<u64, u64>::deserialize_uncompressed(&[tag][..]).is_err());
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_generic_enum_with_owned_fields() {
+        use crate::serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+        #[derive(Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+        enum Sample<T: CanonicalSerialize + CanonicalDeserialize> {
+            Empty,
+            Items(Vec<T>),
+            Labelled { label: String, value: Option<T> },
+        }
+
+        test_serialize(Sample::<u64>::Empty);
+        test_serialize(Sample::<u64>::Items(vec![1, 2, 3]));
+        test_serialize(Sample::<Vec<u8>>::Items(vec![vec![4], vec![]]));
+        test_serialize(Sample::<u64>::Labelled { label: "label".to_owned(), value: Some(5) });
+        test_serialize(Sample::<Vec<u8>>::Labelled { label: String::new(), value: None });
+    }
 }
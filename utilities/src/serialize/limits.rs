@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    io::{self, Read},
+    SerializationError,
+};
+
+use std::cell::Cell;
+
+/// The maximum number of bytes that are preallocated for a collection whose length is read from the input.
+/// Larger collections are grown as their elements are read, so that the allocation is bounded by the input.
+pub const MAX_PREALLOCATION_BYTES: usize = 1 << 20;
+
+std::thread_local! {
+    /// The deserialization limits of the current thread.
+    static LIMITS: Cell<DeserializationLimits> = Cell::new(DeserializationLimits::UNLIMITED);
+}
+
+/// The limits that are enforced while deserializing untrusted input, such as proofs, transactions,
+/// and blocks received from the network, so that an attacker-controlled length prefix cannot cause
+/// the deserializer to allocate (or read) an unbounded amount of memory.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DeserializationLimits {
+    /// The maximum number of elements in a length-prefixed collection.
+    pub max_elements: usize,
+    /// The maximum number of bytes that are read from the input.
+    pub max_bytes: usize,
+}
+
+impl DeserializationLimits {
+    /// The default limits for input that is received from the network.
+    pub const NETWORK: Self = Self::new(1 << 20, 1 << 27);
+    /// No limits, which is the default for trusted input (e.g. parameters and local storage).
+    pub const UNLIMITED: Self = Self::new(usize::MAX, usize::MAX);
+
+    /// Initializes new deserialization limits.
+    pub const fn new(max_elements: usize, max_bytes: usize) -> Self {
+        Self { max_elements, max_bytes }
+    }
+
+    /// Returns the deserialization limits of the current thread.
+    pub fn current() -> Self {
+        LIMITS.with(Cell::get)
+    }
+
+    /// Executes the given function with these limits applied to the current thread.
+    /// Note that the byte budget is only enforced on readers that are wrapped in a `LimitedReader`.
+    /// As the limits are thread-local, deserializers that spawn work on other threads (e.g. with rayon)
+    /// must capture the `current` limits and re-apply them in each worker with this method.
+    pub fn scope<T>(self, f: impl FnOnce() -> T) -> T {
+        /// Restores the previous limits, even if the function panics.
+        struct Restore(DeserializationLimits);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                LIMITS.with(|limits| limits.set(self.0));
+            }
+        }
+
+        let _restore = Restore(LIMITS.with(|limits| limits.replace(self)));
+        f()
+    }
+
+    /// Returns the given number of elements of a collection, if it is within the limits of the current thread.
+    pub fn check_num_elements(num_elements: u64) -> Result<usize, SerializationError> {
+        match usize::try_from(num_elements) {
+            Ok(num_elements) if num_elements <= Self::current().max_elements => Ok(num_elements),
+            _ => Err(SerializationError::LimitExceeded("number of elements")),
+        }
+    }
+}
+
+impl Default for DeserializationLimits {
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
+/// Returns the capacity to preallocate for a collection of the given (untrusted) number of elements.
+pub fn bounded_capacity<T>(num_elements: usize) -> usize {
+    num_elements.min(MAX_PREALLOCATION_BYTES / std::mem::size_of::<T>().max(1))
+}
+
+/// A reader that fails once more than the given number of bytes are read.
+pub struct LimitedReader<R> {
+    reader: R,
+    remaining: usize,
+}
+
+impl<R: Read> LimitedReader<R> {
+    /// Initializes a new reader, which reads at most `max_bytes` from the given reader.
+    pub const fn new(reader: R, max_bytes: usize) -> Self {
+        Self { reader, remaining: max_bytes }
+    }
+}
+
+impl<R: Read> Read for LimitedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.remaining == 0 {
+            return Err(SerializationError::LimitExceeded("number of bytes").into());
+        }
+        let len = buf.len().min(self.remaining);
+        let num_bytes = self.reader.read(&mut buf[..len])?;
+        self.remaining -= num_bytes;
+        Ok(num_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CanonicalDeserialize, CanonicalSerialize, FromBytes, ToBytes};
+
+    #[test]
+    fn test_scope() {
+        let limits = DeserializationLimits::new(3, 100);
+        assert_eq!(DeserializationLimits::current(), DeserializationLimits::UNLIMITED);
+        limits.scope(|| {
+            assert_eq!(DeserializationLimits::current(), limits);
+            assert_eq!(DeserializationLimits::check_num_elements(3).unwrap(), 3);
+            assert!(DeserializationLimits::check_num_elements(4).is_err());
+        });
+        assert_eq!(DeserializationLimits::current(), DeserializationLimits::UNLIMITED);
+    }
+
+    #[test]
+    fn test_bounded_capacity() {
+        assert_eq!(bounded_capacity::<u8>(7), 7);
+        assert_eq!(bounded_capacity::<u8>(usize::MAX), MAX_PREALLOCATION_BYTES);
+        assert_eq!(bounded_capacity::<u64>(usize::MAX), MAX_PREALLOCATION_BYTES / 8);
+    }
+
+    #[test]
+    fn test_vec_length_prefix() {
+        // Check that a huge length prefix does not allocate, and fails on the truncated input.
+        let bytes = u64::MAX.to_le_bytes();
+        assert!(Vec::<u64>::deserialize_uncompressed(&bytes[..]).is_err());
+
+        // Check that a length prefix beyond the limits is rejected.
+        let mut bytes = vec![];
+        vec![1u64, 2, 3, 4].serialize_uncompressed(&mut bytes).unwrap();
+        let limits = DeserializationLimits::new(3, usize::MAX);
+        assert!(limits.scope(|| Vec::<u64>::deserialize_uncompressed(&bytes[..])).is_err());
+        let limits = DeserializationLimits::new(4, usize::MAX);
+        assert_eq!(limits.scope(|| Vec::<u64>::deserialize_uncompressed(&bytes[..])).unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_read_le_with_limits() {
+        let bytes = [7u64, 8].to_bytes_le().unwrap();
+        let limits = DeserializationLimits::new(usize::MAX, bytes.len());
+        assert_eq!(<[u64; 2]>::read_le_with_limits(&bytes[..], limits).unwrap(), [7, 8]);
+        assert_eq!(<[u64; 2]>::from_bytes_le_with_limits(&bytes, limits).unwrap(), [7, 8]);
+
+        let limits = DeserializationLimits::new(usize::MAX, bytes.len() - 1);
+        assert!(<[u64; 2]>::read_le_with_limits(&bytes[..], limits).is_err());
+        assert!(<[u64; 2]>::from_bytes_le_with_limits(&bytes, limits).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_with_limits() {
+        let mut bytes = vec![];
+        vec![1u64, 2, 3].serialize_uncompressed(&mut bytes).unwrap();
+        let mode = crate::SerializationMode::UNCOMPRESSED;
+
+        let limits = DeserializationLimits::new(3, bytes.len());
+        assert_eq!(Vec::<u64>::deserialize_with_limits(&bytes[..], mode, limits).unwrap(), vec![1, 2, 3]);
+        for limits in [DeserializationLimits::new(2, bytes.len()), DeserializationLimits::new(3, bytes.len() - 1)] {
+            assert!(Vec::<u64>::deserialize_with_limits(&bytes[..], mode, limits).is_err());
+        }
+        // Check that the limits are restored.
+        assert_eq!(DeserializationLimits::current(), DeserializationLimits::UNLIMITED);
+    }
+}
@@ -21,12 +21,18 @@ pub use helpers::*;
 mod impls;
 pub use impls::*;
 
+mod limits;
+pub use limits::*;
+
 mod flags;
 pub use flags::*;
 
 mod traits;
 pub use traits::*;
 
+mod zero_copy;
+pub use zero_copy::*;
+
 #[cfg(feature = "derive")]
 pub use snarkvm_utilities_derives::*;
 
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::SerializationError;
+use crate::{DeserializationLimits, LimitedReader, SerializationError};
 pub use crate::{
     io::{self, Read, Write},
     FromBytes,
@@ -62,18 +62,47 @@ pub trait Flags: Default + Clone + Copy + Sized {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Compress {
     Yes,
     No,
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Validate {
     Yes,
     No,
 }
 
+/// The compression and validation modes for a single serialization call.
+///
+/// Call sites have different trade-offs: data from untrusted peers should always be validated,
+/// while trusted local data (e.g. cached parameters) may skip validation to load faster.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SerializationMode {
+    pub compress: Compress,
+    pub validate: Validate,
+}
+
+impl SerializationMode {
+    /// All of the serialization modes.
+    pub const ALL: [Self; 4] =
+        [Self::UNCOMPRESSED_UNCHECKED, Self::COMPRESSED_UNCHECKED, Self::UNCOMPRESSED, Self::COMPRESSED];
+    /// Compressed, and validated on deserialization.
+    pub const COMPRESSED: Self = Self::new(Compress::Yes, Validate::Yes);
+    /// Compressed, and not validated on deserialization.
+    pub const COMPRESSED_UNCHECKED: Self = Self::new(Compress::Yes, Validate::No);
+    /// Uncompressed, and validated on deserialization.
+    pub const UNCOMPRESSED: Self = Self::new(Compress::No, Validate::Yes);
+    /// Uncompressed, and not validated on deserialization.
+    pub const UNCOMPRESSED_UNCHECKED: Self = Self::new(Compress::No, Validate::No);
+
+    /// Initializes a new serialization mode.
+    pub const fn new(compress: Compress, validate: Validate) -> Self {
+        Self { compress, validate }
+    }
+}
+
 pub trait Valid: Sized + Sync {
     fn check(&self) -> Result<(), SerializationError>;
 
@@ -97,8 +126,10 @@ pub trait Valid: Sized + Sync {
 }
 
 /// Serializer in little endian format.
-/// This trait can be derived if all fields of a struct implement
-/// `CanonicalSerialize` and the `derive` feature is enabled.
+/// This trait can be derived if all fields of a struct or enum implement
+/// `CanonicalSerialize` and the `derive` feature is enabled. The variant of an enum is
+/// encoded as a `u8` tag, which is its explicit discriminant if it has one,
+/// and its index otherwise.
 ///
 /// # Example
 /// ```
@@ -132,11 +163,20 @@ pub trait CanonicalSerialize {
     fn uncompressed_size(&self) -> usize {
         self.serialized_size(Compress::No)
     }
+
+    /// Serializes `self` into a new vector of bytes, using the compression of the given mode.
+    fn to_bytes_with_mode(&self, mode: SerializationMode) -> Result<Vec<u8>, SerializationError> {
+        let mut bytes = Vec::with_capacity(self.serialized_size(mode.compress));
+        self.serialize_with_mode(&mut bytes, mode.compress)?;
+        Ok(bytes)
+    }
 }
 
 /// Deserializer in little endian format.
-/// This trait can be derived if all fields of a struct implement
-/// `CanonicalDeserialize` and the `derive` feature is enabled.
+/// This trait can be derived if all fields of a struct or enum implement
+/// `CanonicalDeserialize` and the `derive` feature is enabled. The variant of an enum is
+/// encoded as a `u8` tag, which is its explicit discriminant if it has one,
+/// and its index otherwise.
 ///
 /// # Example
 /// ```
@@ -172,6 +212,28 @@ pub trait CanonicalDeserialize: Valid {
     fn deserialize_uncompressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
         Self::deserialize_with_mode(reader, Compress::No, Validate::No)
     }
+
+    /// Deserializes `Self` from the given bytes, using the given mode.
+    /// Returns an error if the bytes are not fully consumed.
+    fn from_bytes_with_mode(bytes: &[u8], mode: SerializationMode) -> Result<Self, SerializationError> {
+        let mut reader = bytes;
+        let object = Self::deserialize_with_mode(&mut reader, mode.compress, mode.validate)?;
+        match reader.is_empty() {
+            true => Ok(object),
+            false => Err(SerializationError::InvalidData),
+        }
+    }
+
+    /// Deserializes `Self` from `reader`, using the given mode, and enforcing the given deserialization limits.
+    /// This should be used for untrusted input, such as proofs, transactions, and blocks from the network.
+    fn deserialize_with_limits<R: Read>(
+        reader: R,
+        mode: SerializationMode,
+        limits: DeserializationLimits,
+    ) -> Result<Self, SerializationError> {
+        let reader = LimitedReader::new(reader, limits.max_bytes);
+        limits.scope(|| Self::deserialize_with_mode(reader, mode.compress, mode.validate))
+    }
 }
 
 /// Serializer in little endian format allowing to encode flags.
@@ -0,0 +1,249 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{serialize::traits::Validate, BigInteger256, BigInteger384, SerializationError, Vec};
+
+use core::marker::PhantomData;
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A type with a fixed-size serialized form, which can be decoded directly from a borrowed slice of bytes.
+///
+/// The serialized form must match `CanonicalSerialize` (in uncompressed mode), so that a
+/// `ZeroCopySlice` can borrow the bytes of a serialized `Vec<T>` in place.
+pub trait ZeroCopy: Sized {
+    /// Returns the number of bytes in the serialized form of `Self`.
+    fn size_in_bytes() -> usize;
+
+    /// Decodes `Self` from exactly `size_in_bytes` bytes.
+    fn decode(bytes: &[u8]) -> Result<Self, SerializationError>;
+
+    /// Ensures the given `size_in_bytes` bytes encode a valid `Self`.
+    fn validate(bytes: &[u8]) -> Result<(), SerializationError> {
+        Self::decode(bytes).map(|_| ())
+    }
+}
+
+macro_rules! impl_zero_copy_uint {
+    ($type:ty) => {
+        impl ZeroCopy for $type {
+            #[inline]
+            fn size_in_bytes() -> usize {
+                core::mem::size_of::<$type>()
+            }
+
+            #[inline]
+            fn decode(bytes: &[u8]) -> Result<Self, SerializationError> {
+                let bytes = bytes.try_into().map_err(|_| SerializationError::InvalidData)?;
+                Ok(<$type>::from_le_bytes(bytes))
+            }
+        }
+    };
+}
+
+impl_zero_copy_uint!(u8);
+impl_zero_copy_uint!(u16);
+impl_zero_copy_uint!(u32);
+impl_zero_copy_uint!(u64);
+
+impl<const N: usize> ZeroCopy for [u64; N] {
+    #[inline]
+    fn size_in_bytes() -> usize {
+        8 * N
+    }
+
+    #[inline]
+    fn decode(bytes: &[u8]) -> Result<Self, SerializationError> {
+        if bytes.len() != Self::size_in_bytes() {
+            return Err(SerializationError::InvalidData);
+        }
+        let mut limbs = [0u64; N];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::decode(chunk)?;
+        }
+        Ok(limbs)
+    }
+}
+
+impl ZeroCopy for BigInteger256 {
+    #[inline]
+    fn size_in_bytes() -> usize {
+        <[u64; 4]>::size_in_bytes()
+    }
+
+    #[inline]
+    fn decode(bytes: &[u8]) -> Result<Self, SerializationError> {
+        <[u64; 4]>::decode(bytes).map(Self::new)
+    }
+}
+
+impl ZeroCopy for BigInteger384 {
+    #[inline]
+    fn size_in_bytes() -> usize {
+        <[u64; 6]>::size_in_bytes()
+    }
+
+    #[inline]
+    fn decode(bytes: &[u8]) -> Result<Self, SerializationError> {
+        <[u64; 6]>::decode(bytes).map(Self::new)
+    }
+}
+
+/// A borrowed view over a serialized sequence of `T`, which decodes each element on access.
+///
+/// The serialized bytes are borrowed rather than copied, and no vector of deserialized elements is
+/// allocated unless one is requested. This avoids holding both the serialized bytes and the deserialized
+/// elements in memory at once, which matters for multi-gigabyte structures such as precomputed tables.
+#[derive(Debug)]
+pub struct ZeroCopySlice<'a, T: ZeroCopy> {
+    /// The serialized elements (without the length prefix).
+    bytes: &'a [u8],
+    /// PhantomData.
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T: ZeroCopy> Clone for ZeroCopySlice<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: ZeroCopy> Copy for ZeroCopySlice<'a, T> {}
+
+impl<'a, T: ZeroCopy + 'a> ZeroCopySlice<'a, T> {
+    /// Borrows a serialized `Vec<T>` (with its `u64` length prefix) from the given bytes,
+    /// returning the view and the remaining bytes.
+    pub fn deserialize(bytes: &'a [u8], validate: Validate) -> Result<(Self, &'a [u8]), SerializationError> {
+        let (len, bytes) = bytes.split_at(8.min(bytes.len()));
+        let len = u64::decode(len)?;
+        let len = usize::try_from(len).map_err(|_| SerializationError::InvalidData)?;
+        Self::deserialize_without_len(bytes, len, validate)
+    }
+
+    /// Borrows `len` serialized elements from the given bytes, returning the view and the remaining bytes.
+    pub fn deserialize_without_len(
+        bytes: &'a [u8],
+        len: usize,
+        validate: Validate,
+    ) -> Result<(Self, &'a [u8]), SerializationError> {
+        let size_in_bytes = len.checked_mul(T::size_in_bytes()).ok_or(SerializationError::InvalidData)?;
+        if bytes.len() < size_in_bytes {
+            return Err(SerializationError::InvalidData);
+        }
+        let (bytes, remaining) = bytes.split_at(size_in_bytes);
+        let slice = Self { bytes, _phantom: PhantomData };
+        if let Validate::Yes = validate {
+            slice.chunks().try_for_each(T::validate)?;
+        }
+        Ok((slice, remaining))
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        match T::size_in_bytes() {
+            0 => 0,
+            size => self.bytes.len() / size,
+        }
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the element at the given index, if it exists.
+    pub fn get(&self, index: usize) -> Option<T> {
+        let start = index.checked_mul(T::size_in_bytes())?;
+        let end = start.checked_add(T::size_in_bytes())?;
+        T::decode(self.bytes.get(start..end)?).ok()
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> impl Iterator<Item = Result<T, SerializationError>> + 'a {
+        self.chunks().map(T::decode)
+    }
+
+    /// Returns the elements as an owned vector.
+    pub fn to_vec(&self) -> Result<Vec<T>, SerializationError> {
+        self.iter().collect()
+    }
+
+    /// Decodes the elements, appending them to the given vector.
+    ///
+    /// The elements are decoded (in parallel) directly into `vec`, so no intermediate
+    /// vector of deserialized elements is allocated. On failure, `vec` is left unchanged.
+    pub fn extend_vec(&self, vec: &mut Vec<T>) -> Result<(), SerializationError>
+    where
+        T: Clone + Default + Send + Sync,
+    {
+        let start = vec.len();
+        vec.resize(start + self.len(), T::default());
+        let result = cfg_iter_mut!(vec[start..]).zip(cfg_chunks!(self.bytes, T::size_in_bytes().max(1))).try_for_each(
+            |(element, bytes)| {
+                *element = T::decode(bytes)?;
+                Ok(())
+            },
+        );
+        if result.is_err() {
+            vec.truncate(start);
+        }
+        result
+    }
+
+    /// Returns the serialized elements.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns an iterator over the serialized elements.
+    fn chunks(&self) -> impl Iterator<Item = &'a [u8]> + 'a {
+        // Note: `chunks_exact` panics on a chunk size of zero.
+        self.bytes.chunks_exact(T::size_in_bytes().max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestRng, ToBytes, Uniform};
+
+    #[test]
+    fn test_zero_copy_slice() {
+        let rng = &mut TestRng::default();
+        let expected: Vec<BigInteger256> = (0..100).map(|_| BigInteger256::rand(rng)).collect();
+
+        // Serialize the integers with a length prefix, and append trailing bytes.
+        let mut bytes = (expected.len() as u64).to_le_bytes().to_vec();
+        for integer in &expected {
+            integer.write_le(&mut bytes).unwrap();
+        }
+        bytes.extend_from_slice(&[7u8; 3]);
+
+        let (slice, remaining) = ZeroCopySlice::<BigInteger256>::deserialize(&bytes, Validate::Yes).unwrap();
+        assert_eq!(slice.len(), expected.len());
+        assert_eq!(remaining, &[7u8; 3]);
+        assert_eq!(slice.get(42), Some(expected[42]));
+        assert_eq!(slice.get(expected.len()), None);
+        assert_eq!(slice.to_vec().unwrap(), expected);
+
+        // Check that the elements can be appended in place.
+        let mut candidate = vec![expected[0]];
+        slice.extend_vec(&mut candidate).unwrap();
+        assert_eq!(candidate[1..], expected[..]);
+
+        // Check that truncated input is rejected.
+        assert!(ZeroCopySlice::<BigInteger256>::deserialize(&bytes[..100], Validate::No).is_err());
+        assert!(ZeroCopySlice::<u64>::deserialize(&bytes[..4], Validate::No).is_err());
+    }
+}
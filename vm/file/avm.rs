@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    prelude::{FromBytes, Network, ProgramID, ToBytes},
+    prelude::{FromBytes, Network, ProgramID, ToBytes, DEFAULT_CHUNK_SIZE},
     synthesizer::Program,
 };
 
@@ -50,7 +50,7 @@ impl<N: Network> AVMFile<N> {
         // Construct the file path.
         let path = directory.join(file_name);
         // Write the file (overwriting if it already exists).
-        File::create(&path)?.write_all(&program.to_bytes_le()?)?;
+        program.write_le_chunked(File::create(&path)?, DEFAULT_CHUNK_SIZE)?;
 
         Self::from_filepath(&path)
     }
@@ -172,7 +172,7 @@ impl<N: Network> AVMFile<N> {
         // Ensure the file name matches the expected file name.
         ensure!(file_name == self.file_name, "File name does not match.");
 
-        Ok(File::create(path)?.write_all(&self.program.to_bytes_le()?)?)
+        Ok(self.program.write_le_chunked(File::create(path)?, DEFAULT_CHUNK_SIZE)?)
     }
 }
 
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    prelude::{FromBytes, Identifier, IoResult, Network, Read, ToBytes},
+    prelude::{FromBytes, Identifier, IoResult, Network, Read, ToBytes, DEFAULT_CHUNK_SIZE},
     synthesizer::{snark::ProvingKey, Program},
 };
 
@@ -49,7 +49,7 @@ impl<N: Network> ProverFile<N> {
         // Construct the file path.
         let path = directory.join(file_name);
         // Write the file (overwriting if it already exists).
-        File::create(&path)?.write_all(&prover_file.to_bytes_le()?)?;
+        prover_file.write_le_chunked(File::create(&path)?, DEFAULT_CHUNK_SIZE)?;
 
         // Attempt to load the prover file.
         Self::from_filepath(&path)
@@ -169,7 +169,7 @@ impl<N: Network> ProverFile<N> {
         ensure!(self.function_name.to_string() == file_stem, "Function name does not match file stem.");
 
         // Write to the file (overwriting if it already exists).
-        Ok(File::create(path)?.write_all(&self.to_bytes_le()?)?)
+        Ok(self.write_le_chunked(File::create(path)?, DEFAULT_CHUNK_SIZE)?)
     }
 }
 
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::CurrentNetwork;
+use snarkvm_console::{account, prelude::*};
+
+use wasm_bindgen::prelude::*;
+
+/// Returns a JavaScript error with the given message.
+pub(crate) fn js_error(error: impl fmt::Display) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+/// An account private key.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrivateKey(pub(crate) account::PrivateKey<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl PrivateKey {
+    /// Samples a new private key, using the randomness of the browser.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<PrivateKey, JsError> {
+        Ok(Self(account::PrivateKey::new(&mut rand::rngs::OsRng).map_err(js_error)?))
+    }
+
+    /// Returns the private key from its string representation (`APrivateKey1...`).
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(private_key: &str) -> Result<PrivateKey, JsError> {
+        Ok(Self(account::PrivateKey::from_str(private_key).map_err(js_error)?))
+    }
+
+    /// Returns the string representation of the private key.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the view key of the private key.
+    #[wasm_bindgen(js_name = toViewKey)]
+    pub fn to_view_key(&self) -> Result<ViewKey, JsError> {
+        Ok(ViewKey(account::ViewKey::try_from(&self.0).map_err(js_error)?))
+    }
+
+    /// Returns the address of the private key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Result<Address, JsError> {
+        Ok(Address(account::Address::try_from(&self.0).map_err(js_error)?))
+    }
+
+    /// Returns a signature for the given message, using the randomness of the browser.
+    pub fn sign(&self, message: &[u8]) -> Result<Signature, JsError> {
+        Ok(Signature(self.0.sign_bytes(message, &mut rand::rngs::OsRng).map_err(js_error)?))
+    }
+}
+
+/// An account view key.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ViewKey(pub(crate) account::ViewKey<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl ViewKey {
+    /// Returns the view key from its string representation (`AViewKey1...`).
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(view_key: &str) -> Result<ViewKey, JsError> {
+        Ok(Self(account::ViewKey::from_str(view_key).map_err(js_error)?))
+    }
+
+    /// Returns the string representation of the view key.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the address of the view key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Result<Address, JsError> {
+        Ok(Address(account::Address::try_from(&self.0).map_err(js_error)?))
+    }
+}
+
+/// An account address.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Address(pub(crate) account::Address<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Address {
+    /// Returns the address from its string representation (`aleo1...`).
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(address: &str) -> Result<Address, JsError> {
+        Ok(Self(account::Address::from_str(address).map_err(js_error)?))
+    }
+
+    /// Returns the string representation of the address.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the signature is valid for the given message and this address.
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
+        signature.0.verify_bytes(&self.0, message)
+    }
+}
+
+/// An account signature.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature(pub(crate) account::Signature<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Signature {
+    /// Returns the signature from its string representation (`sign1...`).
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(signature: &str) -> Result<Signature, JsError> {
+        Ok(Self(account::Signature::from_str(signature).map_err(js_error)?))
+    }
+
+    /// Returns the string representation of the signature.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the signature from its little-endian bytes.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Signature, JsError> {
+        Ok(Self(account::Signature::from_bytes_le(bytes).map_err(js_error)?))
+    }
+
+    /// Returns the little-endian bytes of the signature.
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, JsError> {
+        self.0.to_bytes_le().map_err(js_error)
+    }
+
+    /// Returns the signature from the hex encoding of its little-endian bytes.
+    #[wasm_bindgen(js_name = fromHex)]
+    pub fn from_hex(hex: &str) -> Result<Signature, JsError> {
+        Self::from_bytes(&from_canonical_hex(hex).map_err(js_error)?)
+    }
+
+    /// Returns the hex encoding of the little-endian bytes of the signature.
+    #[wasm_bindgen(js_name = toHex)]
+    pub fn to_hex(&self) -> Result<String, JsError> {
+        Ok(to_canonical_hex(&self.to_bytes()?))
+    }
+
+    /// Returns `true` if the signature is valid for the given message and address.
+    pub fn verify(&self, address: &Address, message: &[u8]) -> bool {
+        address.verify(message, self)
+    }
+}
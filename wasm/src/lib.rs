@@ -23,5 +23,24 @@ pub use snarkvm_synthesizer as synthesizer;
 #[cfg(feature = "utilities")]
 pub use snarkvm_utilities as utilities;
 
+#[cfg(feature = "console")]
+pub mod account;
+#[cfg(feature = "console")]
+pub use account::*;
+
+#[cfg(feature = "console")]
+pub mod encryption;
+#[cfg(feature = "console")]
+pub use encryption::*;
+
+#[cfg(all(feature = "console", feature = "synthesizer"))]
+pub mod verification;
+#[cfg(all(feature = "console", feature = "synthesizer"))]
+pub use verification::*;
+
+/// The network of the JavaScript bindings.
+#[cfg(feature = "console")]
+pub(crate) type CurrentNetwork = snarkvm_console::network::Testnet3;
+
 #[cfg(test)]
 mod tests;
@@ -14,9 +14,11 @@
 
 use snarkvm_console::{
     account::{Address, PrivateKey, ViewKey},
-    network::Testnet3,
+    network::{Network, Testnet3},
+    program::{Plaintext, Record},
+    types::Scalar,
 };
-use snarkvm_utilities::TestRng;
+use snarkvm_utilities::{TestRng, ToBytes, Uniform};
 
 use core::str::FromStr;
 use wasm_bindgen_test::*;
@@ -58,3 +60,116 @@ fn test_account_sign() {
         assert!(result, "Failed to execute signature verification");
     }
 }
+
+#[wasm_bindgen_test]
+fn test_record_decrypt() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..10 {
+        // Sample a new private key, view key, and address.
+        let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Encrypt a record for the address.
+        let randomizer = Scalar::rand(&mut rng);
+        let nonce = Testnet3::g_scalar_multiply(&randomizer);
+        let record = Record::<Testnet3, Plaintext<Testnet3>>::from_str(&format!(
+            "{{ owner: {address}.private, amount: 100u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let ciphertext = record.encrypt(randomizer).unwrap();
+
+        // Decrypt the record with the view key.
+        assert!(ciphertext.is_owner(&view_key));
+        assert_eq!(record, ciphertext.decrypt(&view_key).unwrap());
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_account() {
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+    const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+    let private_key = crate::PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+    assert_eq!(ALEO_PRIVATE_KEY, private_key.to_js_string());
+
+    let view_key = private_key.to_view_key().unwrap();
+    assert_eq!(ALEO_VIEW_KEY, view_key.to_js_string());
+
+    let address = view_key.to_address().unwrap();
+    assert_eq!(ALEO_ADDRESS, address.to_js_string());
+    assert_eq!(address, private_key.to_address().unwrap());
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_sign() {
+    for _ in 0..10 {
+        let private_key = crate::PrivateKey::new().unwrap();
+        let address = private_key.to_address().unwrap();
+
+        // Sign a message, and check the signature round trips through its encodings.
+        let signature = private_key.sign(b"hello world!").unwrap();
+        assert_eq!(signature, crate::Signature::from_string(&signature.to_js_string()).unwrap());
+        assert_eq!(signature, crate::Signature::from_hex(&signature.to_hex().unwrap()).unwrap());
+        assert_eq!(signature, crate::Signature::from_bytes(&signature.to_bytes().unwrap()).unwrap());
+
+        // Verify the signature.
+        assert!(address.verify(b"hello world!", &signature));
+        assert!(signature.verify(&address, b"hello world!"));
+        assert!(!address.verify(b"hello world?", &signature));
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_encrypt() {
+    for _ in 0..10 {
+        let private_key = crate::PrivateKey::new().unwrap();
+        let view_key = private_key.to_view_key().unwrap();
+        let address = private_key.to_address().unwrap();
+
+        // Encrypt a plaintext to the address, and decrypt it with the view key.
+        let plaintext = "{ amount: 100u64 }";
+        let message = address.encrypt(plaintext).unwrap();
+        let message = crate::EncryptedMessage::new(&message.ciphertext(), &message.nonce()).unwrap();
+        let expected = Plaintext::<Testnet3>::from_str(plaintext).unwrap();
+        assert_eq!(expected.to_string(), view_key.decrypt(&message).unwrap());
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_verification_batch() {
+    // Sample a batch of signatures.
+    let mut batch = crate::VerificationBatch::new();
+    for i in 0..10u8 {
+        let private_key = crate::PrivateKey::new().unwrap();
+        let signature = private_key.sign(&[i]).unwrap();
+        batch.add_signature(&private_key.to_address().unwrap(), &[i], &signature).unwrap();
+    }
+    assert_eq!(batch.length(), 10);
+    assert!(batch.verify());
+
+    // Check that every shard verifies, and that the shards cover the batch.
+    for num_shards in 1..5 {
+        let shards = (0..num_shards).map(|index| batch.shard(index, num_shards).unwrap()).collect::<Vec<_>>();
+        assert!(shards.iter().all(|shard| crate::verify_shard(shard).unwrap()));
+        assert_eq!(shards.concat().len(), batch.shard(0, 1).unwrap().len());
+    }
+    assert!(batch.shard(2, 2).is_err());
+
+    // Check that an invalid signature fails its shard.
+    let private_key = crate::PrivateKey::new().unwrap();
+    let signature = private_key.sign(b"hello world!").unwrap();
+    batch.add_signature(&private_key.to_address().unwrap(), b"hello world?", &signature).unwrap();
+    assert!(!batch.verify());
+    let shards = (0..3).map(|index| batch.shard(index, 3).unwrap()).collect::<Vec<_>>();
+    assert!(!shards.iter().all(|shard| crate::verify_shard(shard).unwrap()));
+
+    // Check that an oversized message is rejected, and that a shard claiming one is rejected before allocating.
+    assert!(batch.add_signature(&private_key.to_address().unwrap(), &vec![0u8; 128 * 1024 + 1], &signature).is_err());
+    let mut shard = vec![0u8];
+    shard.extend_from_slice(&private_key.to_address().unwrap().0.to_bytes_le().unwrap());
+    shard.extend_from_slice(&u32::MAX.to_le_bytes());
+    assert!(crate::verify_shard(&shard).is_err());
+}
@@ -0,0 +1,235 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    account::{js_error, Address, Signature},
+    CurrentNetwork,
+};
+use snarkvm_console::{account, prelude::*, types::Field};
+use snarkvm_synthesizer::snark::{Proof, VerifyingKey};
+
+use wasm_bindgen::prelude::*;
+
+/// The relative cost of verifying a proof, in units of signature verifications.
+const PROOF_COST: u64 = 500;
+/// The maximum length of a signed message, in bytes.
+const MAX_MESSAGE_SIZE: usize = 128 * 1024;
+/// The maximum number of public inputs to a proof.
+const MAX_PROOF_INPUTS: usize = CurrentNetwork::MAX_DATA_SIZE_IN_FIELDS as usize;
+
+/// A single verification in a batch.
+#[derive(Clone)]
+enum VerificationTask {
+    /// A signature over a message, by an address.
+    Signature {
+        address: account::Address<CurrentNetwork>,
+        message: Vec<u8>,
+        signature: account::Signature<CurrentNetwork>,
+    },
+    /// A proof for a function, with its public inputs.
+    Proof {
+        verifying_key: VerifyingKey<CurrentNetwork>,
+        function_name: String,
+        inputs: Vec<Field<CurrentNetwork>>,
+        proof: Proof<CurrentNetwork>,
+    },
+}
+
+impl VerificationTask {
+    /// Returns the relative cost of the task, used to balance the shards.
+    const fn cost(&self) -> u64 {
+        match self {
+            Self::Signature { .. } => 1,
+            Self::Proof { .. } => PROOF_COST,
+        }
+    }
+
+    /// Returns `true` if the task verifies.
+    fn verify(&self) -> bool {
+        match self {
+            Self::Signature { address, message, signature } => signature.verify_bytes(address, message),
+            Self::Proof { verifying_key, function_name, inputs, proof } => {
+                let inputs = inputs.iter().map(|input| **input).collect::<Vec<_>>();
+                verifying_key.verify(function_name, &inputs, proof)
+            }
+        }
+    }
+}
+
+impl FromBytes for VerificationTask {
+    /// Reads the verification task from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => {
+                let address = FromBytes::read_le(&mut reader)?;
+                let message_length = u32::read_le(&mut reader)? as usize;
+                // Ensure the message length is bounded before allocating, as a shard may be untrusted.
+                if message_length > MAX_MESSAGE_SIZE {
+                    return Err(error(format!("Message length {message_length} exceeds {MAX_MESSAGE_SIZE} bytes")));
+                }
+                let mut message = vec![0u8; message_length];
+                reader.read_exact(&mut message)?;
+                let signature = FromBytes::read_le(&mut reader)?;
+                Ok(Self::Signature { address, message, signature })
+            }
+            1 => {
+                let verifying_key = FromBytes::read_le(&mut reader)?;
+                let name_length = u8::read_le(&mut reader)?;
+                let mut function_name = vec![0u8; name_length as usize];
+                reader.read_exact(&mut function_name)?;
+                let function_name = String::from_utf8(function_name).map_err(|e| error(e.to_string()))?;
+                let num_inputs = u32::read_le(&mut reader)?;
+                if num_inputs as usize > MAX_PROOF_INPUTS {
+                    return Err(error(format!("Number of inputs {num_inputs} exceeds {MAX_PROOF_INPUTS}")));
+                }
+                let inputs = (0..num_inputs).map(|_| FromBytes::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+                let proof = FromBytes::read_le(&mut reader)?;
+                Ok(Self::Proof { verifying_key, function_name, inputs, proof })
+            }
+            variant => Err(error(format!("Invalid verification task variant '{variant}'"))),
+        }
+    }
+}
+
+impl ToBytes for VerificationTask {
+    /// Writes the verification task to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Signature { address, message, signature } => {
+                0u8.write_le(&mut writer)?;
+                address.write_le(&mut writer)?;
+                u32::try_from(message.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                writer.write_all(message)?;
+                signature.write_le(&mut writer)
+            }
+            Self::Proof { verifying_key, function_name, inputs, proof } => {
+                1u8.write_le(&mut writer)?;
+                verifying_key.write_le(&mut writer)?;
+                u8::try_from(function_name.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                writer.write_all(function_name.as_bytes())?;
+                u32::try_from(inputs.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                inputs.write_le(&mut writer)?;
+                proof.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+/// A batch of signature and proof verifications, which may be split into shards and verified
+/// across Web Workers, so that the main thread stays responsive.
+///
+/// Each shard is a `Uint8Array`, which may be transferred to a worker without copying,
+/// and verified there with `verifyShard`. The batch is valid if every shard is valid.
+#[wasm_bindgen]
+#[derive(Clone, Default)]
+pub struct VerificationBatch {
+    /// The verification tasks.
+    tasks: Vec<VerificationTask>,
+}
+
+#[wasm_bindgen]
+impl VerificationBatch {
+    /// Initializes an empty batch.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> VerificationBatch {
+        Self::default()
+    }
+
+    /// Returns the number of verifications in the batch.
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Adds a signature over the given message to the batch.
+    #[wasm_bindgen(js_name = addSignature)]
+    pub fn add_signature(&mut self, address: &Address, message: &[u8], signature: &Signature) -> Result<(), JsError> {
+        if message.len() > MAX_MESSAGE_SIZE {
+            return Err(js_error(format!("Message length {} exceeds {MAX_MESSAGE_SIZE} bytes", message.len())));
+        }
+        let (address, message, signature) = (address.0, message.to_vec(), signature.0);
+        self.tasks.push(VerificationTask::Signature { address, message, signature });
+        Ok(())
+    }
+
+    /// Adds a proof to the batch, given the bytes of the verifying key and proof,
+    /// and the public inputs as field strings (e.g. `1field`).
+    #[wasm_bindgen(js_name = addProof)]
+    pub fn add_proof(
+        &mut self,
+        verifying_key: &[u8],
+        function_name: &str,
+        inputs: Vec<String>,
+        proof: &[u8],
+    ) -> Result<(), JsError> {
+        let verifying_key = VerifyingKey::from_bytes_le(verifying_key).map_err(js_error)?;
+        if inputs.len() > MAX_PROOF_INPUTS {
+            return Err(js_error(format!("Number of inputs {} exceeds {MAX_PROOF_INPUTS}", inputs.len())));
+        }
+        let inputs = inputs.iter().map(|input| Field::from_str(input)).collect::<Result<Vec<_>>>().map_err(js_error)?;
+        let proof = Proof::from_bytes_le(proof).map_err(js_error)?;
+        let function_name = function_name.to_string();
+        self.tasks.push(VerificationTask::Proof { verifying_key, function_name, inputs, proof });
+        Ok(())
+    }
+
+    /// Returns the bytes of the shard at the given index, out of the given number of shards.
+    /// The shards are balanced by their estimated verification cost.
+    pub fn shard(&self, index: usize, num_shards: usize) -> Result<Vec<u8>, JsError> {
+        if index >= num_shards {
+            return Err(js_error(format!("Shard index {index} is out of range for {num_shards} shards")));
+        }
+        let mut bytes = Vec::new();
+        for (task, _) in self.assign(num_shards).into_iter().filter(|(_, shard)| *shard == index) {
+            task.write_le(&mut bytes).map_err(js_error)?;
+        }
+        Ok(bytes)
+    }
+
+    /// Returns `true` if every verification in the batch succeeds, verifying on the current thread.
+    pub fn verify(&self) -> bool {
+        self.tasks.iter().all(VerificationTask::verify)
+    }
+}
+
+impl VerificationBatch {
+    /// Returns each task with the index of its shard, assigning the costliest tasks first to the least loaded shard.
+    fn assign(&self, num_shards: usize) -> Vec<(&VerificationTask, usize)> {
+        let mut order = (0..self.tasks.len()).collect::<Vec<_>>();
+        order.sort_by_key(|index| core::cmp::Reverse(self.tasks[*index].cost()));
+
+        let mut loads = vec![0u64; num_shards];
+        let mut assignment = vec![0usize; self.tasks.len()];
+        for index in order {
+            // Select the least loaded shard, preferring the lowest index on ties.
+            let (shard, _) = loads.iter().enumerate().min_by_key(|(_, load)| **load).unwrap_or((0, &0));
+            loads[shard] += self.tasks[index].cost();
+            assignment[index] = shard;
+        }
+        self.tasks.iter().zip(assignment).collect()
+    }
+}
+
+/// Returns `true` if every verification in the given shard succeeds.
+/// This is intended to be called from a Web Worker, on the bytes produced by `VerificationBatch.shard`.
+#[wasm_bindgen(js_name = verifyShard)]
+pub fn verify_shard(shard: &[u8]) -> Result<bool, JsError> {
+    let mut reader = shard;
+    let mut is_valid = true;
+    while !reader.is_empty() {
+        let task = VerificationTask::read_le(&mut reader).map_err(js_error)?;
+        is_valid &= task.verify();
+    }
+    Ok(is_valid)
+}